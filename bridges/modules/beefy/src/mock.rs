@@ -105,6 +105,8 @@ impl Chain for TestBridgedChain {
 }
 
 impl ChainWithBeefy for TestBridgedChain {
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str = "Beefy";
+
 	type CommitmentHasher = Keccak256;
 	type MmrHashing = Keccak256;
 	type MmrHash = <Keccak256 as Hash>::Output;
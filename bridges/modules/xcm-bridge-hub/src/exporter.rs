@@ -35,7 +35,7 @@ use pallet_bridge_messages::{
 };
 use xcm::prelude::*;
 use xcm_builder::{HaulBlob, HaulBlobError, HaulBlobExporter};
-use xcm_executor::traits::ExportXcm;
+use xcm_executor::traits::{ExportXcm, MessageStatus, RecordMessageStatus};
 
 /// Maximal number of messages in the outbound bridge queue. Once we reach this limit, we
 /// suspend a bridge.
@@ -196,6 +196,11 @@ where
 			"XCM message has been enqueued"
 		);
 
+		T::MessageStatusRecorder::record_message_status(
+			id,
+			MessageStatus::Exported { nonce: artifacts.nonce },
+		);
+
 		// maybe we need switch to congested state
 		Self::on_bridge_message_enqueued(bridge_id, bridge, artifacts.enqueued_messages);
 
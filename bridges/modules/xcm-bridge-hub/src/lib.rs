@@ -156,7 +156,7 @@ use sp_runtime::traits::Zero;
 use sp_std::{boxed::Box, vec::Vec};
 use xcm::prelude::*;
 use xcm_builder::DispatchBlob;
-use xcm_executor::traits::ConvertLocation;
+use xcm_executor::traits::{ConvertLocation, RecordMessageStatus};
 
 pub use bp_xcm_bridge_hub::XcmAsPlainPayload;
 pub use dispatcher::XcmBlobMessageDispatchResult;
@@ -247,6 +247,8 @@ pub mod pallet {
 		type LocalXcmChannelManager: LocalXcmChannelManager;
 		/// XCM-level dispatcher for inbound bridge messages.
 		type BlobDispatcher: DispatchBlob;
+		/// Records that an outbound XCM was put onto the bridge lane.
+		type MessageStatusRecorder: RecordMessageStatus;
 	}
 
 	/// An alias for the bridge metadata.
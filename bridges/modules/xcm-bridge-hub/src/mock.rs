@@ -206,6 +206,7 @@ impl pallet_xcm_bridge_hub::Config for TestRuntime {
 	type LocalXcmChannelManager = TestLocalXcmChannelManager;
 
 	type BlobDispatcher = TestBlobDispatcher;
+	type MessageStatusRecorder = ();
 }
 
 /// A router instance simulates a scenario where the router is deployed on a different chain than
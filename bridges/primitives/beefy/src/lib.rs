@@ -22,7 +22,10 @@
 pub use binary_merkle_tree::merkle_root;
 pub use pallet_beefy_mmr::BeefyEcdsaToEthereum;
 pub use pallet_mmr::{
-	primitives::{DataOrHash as MmrDataOrHash, LeafProof as MmrProof},
+	primitives::{
+		DataOrHash as MmrDataOrHash, EncodableOpaqueLeaf as MmrEncodableOpaqueLeaf,
+		Error as MmrError, LeafProof as MmrProof,
+	},
 	verify_leaves_proof as verify_mmr_leaves_proof,
 };
 pub use sp_consensus_beefy::{
@@ -31,10 +34,12 @@ pub use sp_consensus_beefy::{
 	},
 	known_payloads::MMR_ROOT_ID as MMR_ROOT_PAYLOAD_ID,
 	mmr::{BeefyAuthoritySet, MmrLeafVersion},
-	BeefyAuthorityId, Commitment, Payload as BeefyPayload, SignedCommitment, ValidatorSet,
-	ValidatorSetId, BEEFY_ENGINE_ID,
+	BeefyAuthorityId, Commitment, ConsensusLog as BeefyConsensusLog, Payload as BeefyPayload,
+	SignedCommitment, ValidatorSet, ValidatorSetId, VersionedFinalityProof, BEEFY_ENGINE_ID,
 };
 
+pub mod storage_keys;
+
 use bp_runtime::{BasicOperatingMode, BlockNumberOf, Chain, HashOf};
 use codec::{Decode, Encode};
 use frame_support::Parameter;
@@ -52,6 +57,13 @@ use sp_std::prelude::*;
 /// primitives. Some of types can be configured in low-level pallets, but are constrained
 /// when BEEFY+MMR bundle is used.
 pub trait ChainWithBeefy: Chain {
+	/// Name of the bridge BEEFY pallet (used in `construct_runtime` macro call) that is deployed
+	/// at some other chain to bridge with this `ChainWithBeefy`.
+	///
+	/// We assume that all chains that are bridging with this `ChainWithBeefy` are using
+	/// the same name.
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str;
+
 	/// The hashing algorithm used to compute the digest of the BEEFY commitment.
 	///
 	/// Corresponds to the hashing algorithm, used by `sc_consensus_beefy::BeefyKeystore`.
@@ -76,15 +88,20 @@ pub trait ChainWithBeefy: Chain {
 		+ AsRef<[u8]>
 		+ Default
 		+ MaybeSerializeDeserialize
-		+ PartialOrd;
+		+ PartialOrd
+		+ Send
+		+ Sync;
 
 	/// The type expected for the MMR leaf extra data.
-	type BeefyMmrLeafExtra: Parameter;
+	type BeefyMmrLeafExtra: Parameter + Send + Sync;
 
 	/// A way to identify a BEEFY validator.
 	///
 	/// Corresponds to the `BeefyId` field of the `pallet-beefy` configuration.
-	type AuthorityId: BeefyAuthorityId<Self::CommitmentHasher> + Parameter;
+	type AuthorityId: BeefyAuthorityId<Self::CommitmentHasher, Signature: Send + Sync>
+		+ Parameter
+		+ Send
+		+ Sync;
 
 	/// A way to convert validator id to its raw representation in the BEEFY merkle tree.
 	///
@@ -149,3 +166,13 @@ pub struct ImportedCommitment<BlockNumber, BlockHash, MmrHash> {
 	/// MMR root at the imported block.
 	pub mmr_root: MmrHash,
 }
+
+/// Some high level info about the imported commitments.
+#[derive(Encode, Decode, Debug, PartialEq, TypeInfo)]
+pub struct ImportedCommitmentsInfoData<BlockNumber> {
+	/// Best known block number, provided in a BEEFY commitment. However this is not
+	/// the best proven block. The best proven block is this block's parent.
+	pub best_block_number: BlockNumber,
+	/// The head of the `ImportedBlockNumbers` ring buffer.
+	pub next_block_number_index: u32,
+}
@@ -0,0 +1,138 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage keys of bridge BEEFY pallet.
+
+/// Name of the `PalletOperatingMode` storage value.
+pub const PALLET_OPERATING_MODE_VALUE_NAME: &str = "PalletOperatingMode";
+/// Name of the `ImportedCommitmentsInfo` storage value.
+pub const IMPORTED_COMMITMENTS_INFO_VALUE_NAME: &str = "ImportedCommitmentsInfo";
+/// Name of the `ImportedCommitments` storage map.
+pub const IMPORTED_COMMITMENTS_MAP_NAME: &str = "ImportedCommitments";
+/// Name of the `CurrentAuthoritySetInfo` storage value.
+pub const CURRENT_AUTHORITY_SET_INFO_VALUE_NAME: &str = "CurrentAuthoritySetInfo";
+
+use codec::Encode;
+use frame_support::Blake2_128Concat;
+use sp_core::storage::StorageKey;
+
+/// Storage key of the `PalletOperatingMode` variable in the runtime storage.
+pub fn pallet_operating_mode_key(pallet_prefix: &str) -> StorageKey {
+	StorageKey(
+		bp_runtime::storage_value_final_key(
+			pallet_prefix.as_bytes(),
+			PALLET_OPERATING_MODE_VALUE_NAME.as_bytes(),
+		)
+		.to_vec(),
+	)
+}
+
+/// Storage key of the `ImportedCommitmentsInfo` variable in the runtime storage.
+pub fn imported_commitments_info_key(pallet_prefix: &str) -> StorageKey {
+	StorageKey(
+		bp_runtime::storage_value_final_key(
+			pallet_prefix.as_bytes(),
+			IMPORTED_COMMITMENTS_INFO_VALUE_NAME.as_bytes(),
+		)
+		.to_vec(),
+	)
+}
+
+/// Storage key of the commitment, imported for the block with given number, in the
+/// `ImportedCommitments` map.
+pub fn imported_commitment_key<BlockNumber: Encode>(
+	pallet_prefix: &str,
+	block_number: BlockNumber,
+) -> StorageKey {
+	bp_runtime::storage_map_final_key::<Blake2_128Concat>(
+		pallet_prefix,
+		IMPORTED_COMMITMENTS_MAP_NAME,
+		&block_number.encode(),
+	)
+}
+
+/// Storage key of the `CurrentAuthoritySetInfo` variable in the runtime storage.
+pub fn current_authority_set_info_key(pallet_prefix: &str) -> StorageKey {
+	StorageKey(
+		bp_runtime::storage_value_final_key(
+			pallet_prefix.as_bytes(),
+			CURRENT_AUTHORITY_SET_INFO_VALUE_NAME.as_bytes(),
+		)
+		.to_vec(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hex_literal::hex;
+
+	#[test]
+	fn pallet_operating_mode_key_computed_properly() {
+		// If this test fails, then something has been changed in module storage that is breaking
+		// compatibility with previous pallet.
+		let storage_key = pallet_operating_mode_key("BridgeBeefy").0;
+		assert_eq!(
+			storage_key,
+			hex!("4f7f4a8cac631a4b74ef80b03aace4170f4cf0917788d791142ff6c1f216e7b3").to_vec(),
+			"Unexpected storage key: {}",
+			hex::encode(&storage_key),
+		);
+	}
+
+	#[test]
+	fn imported_commitments_info_key_computed_properly() {
+		// If this test fails, then something has been changed in module storage that is breaking
+		// compatibility with previous pallet.
+		let storage_key = imported_commitments_info_key("BridgeBeefy").0;
+		assert_eq!(
+			storage_key,
+			hex!("4f7f4a8cac631a4b74ef80b03aace4170dbf98fefab29246d46877b50d0e345c").to_vec(),
+			"Unexpected storage key: {}",
+			hex::encode(&storage_key),
+		);
+	}
+
+	#[test]
+	fn imported_commitment_key_computed_properly() {
+		// If this test fails, then something has been changed in module storage that is breaking
+		// compatibility with previous pallet.
+		let storage_key = imported_commitment_key("BridgeBeefy", 42u32).0;
+		assert_eq!(
+			storage_key,
+			hex!(
+				"4f7f4a8cac631a4b74ef80b03aace4172bc519b1e28927247fb9835fbb79b3a4"
+				"9017d1c733ec2aeaa6fd0230614e397e2a000000"
+			)
+			.to_vec(),
+			"Unexpected storage key: {}",
+			hex::encode(&storage_key),
+		);
+	}
+
+	#[test]
+	fn current_authority_set_info_key_computed_properly() {
+		// If this test fails, then something has been changed in module storage that is breaking
+		// compatibility with previous pallet.
+		let storage_key = current_authority_set_info_key("BridgeBeefy").0;
+		assert_eq!(
+			storage_key,
+			hex!("4f7f4a8cac631a4b74ef80b03aace41755524da6417bf34db6eabedaacf369e4").to_vec(),
+			"Unexpected storage key: {}",
+			hex::encode(&storage_key),
+		);
+	}
+}
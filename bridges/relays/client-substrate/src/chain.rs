@@ -17,6 +17,7 @@
 use crate::calls::UtilityCall;
 
 use crate::SimpleRuntimeVersion;
+use bp_beefy::ChainWithBeefy as ChainWithBeefyBase;
 use bp_header_chain::ChainWithGrandpa as ChainWithGrandpaBase;
 use bp_messages::ChainWithMessages as ChainWithMessagesBase;
 use bp_runtime::{
@@ -107,6 +108,12 @@ pub trait ChainWithGrandpa: Chain + ChainWithGrandpaBase {
 	type KeyOwnerProof: Decode + TypeInfo + Send;
 }
 
+/// Substrate-based chain with BEEFY && MMR pallets deployed from minimal relay-client point of
+/// view.
+pub trait ChainWithBeefy: Chain + ChainWithBeefyBase {}
+
+impl<T> ChainWithBeefy for T where T: Chain + ChainWithBeefyBase {}
+
 /// Substrate-based parachain from minimal relay-client point of view.
 pub trait Parachain: Chain + ParachainBase {}
 
@@ -20,9 +20,10 @@
 use crate::{
 	client::{Client, SubscriptionBroadcaster},
 	error::{Error, Result},
-	AccountIdOf, AccountKeyPairOf, BlockNumberOf, Chain, ChainWithGrandpa, ChainWithTransactions,
-	HashOf, HeaderIdOf, HeaderOf, NonceOf, SignedBlockOf, SimpleRuntimeVersion, Subscription,
-	TransactionTracker, UnsignedTransaction, ANCIENT_BLOCK_THRESHOLD,
+	AccountIdOf, AccountKeyPairOf, BlockNumberOf, Chain, ChainWithBeefy, ChainWithGrandpa,
+	ChainWithTransactions, HashOf, HeaderIdOf, HeaderOf, NonceOf, SignedBlockOf,
+	SimpleRuntimeVersion, Subscription, TransactionTracker, UnsignedTransaction,
+	ANCIENT_BLOCK_THRESHOLD,
 };
 use std::{cmp::Ordering, future::Future, task::Poll};
 
@@ -355,7 +356,10 @@ impl<C: Chain, B: Client<C>> Client<C> for CachingClient<C, B> {
 			.await
 	}
 
-	async fn subscribe_beefy_finality_justifications(&self) -> Result<Subscription<Bytes>> {
+	async fn subscribe_beefy_finality_justifications(&self) -> Result<Subscription<Bytes>>
+	where
+		C: ChainWithBeefy,
+	{
 		self.subscribe_finality_justifications(
 			&self.data.beefy_justifications,
 			self.backend.subscribe_beefy_finality_justifications(),
@@ -16,9 +16,9 @@
 
 use crate::{
 	error::{Error, Result},
-	AccountIdOf, AccountKeyPairOf, BlockNumberOf, Chain, ChainWithGrandpa, ChainWithTransactions,
-	HashOf, HeaderIdOf, HeaderOf, NonceOf, SignedBlockOf, SimpleRuntimeVersion, Subscription,
-	TransactionTracker, UnsignedTransaction,
+	AccountIdOf, AccountKeyPairOf, BlockNumberOf, Chain, ChainWithBeefy, ChainWithGrandpa,
+	ChainWithTransactions, HashOf, HeaderIdOf, HeaderOf, NonceOf, SignedBlockOf,
+	SimpleRuntimeVersion, Subscription, TransactionTracker, UnsignedTransaction,
 };
 
 use async_trait::async_trait;
@@ -93,7 +93,9 @@ pub trait Client<C: Chain>: 'static + Send + Sync + Clone + Debug {
 	) -> Result<Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof>>;
 
 	/// Subscribe to BEEFY finality justifications.
-	async fn subscribe_beefy_finality_justifications(&self) -> Result<Subscription<Bytes>>;
+	async fn subscribe_beefy_finality_justifications(&self) -> Result<Subscription<Bytes>>
+	where
+		C: ChainWithBeefy;
 
 	/// Return `tokenDecimals` property from the set of chain properties.
 	async fn token_decimals(&self) -> Result<Option<u64>>;
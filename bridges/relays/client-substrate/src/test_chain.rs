@@ -22,9 +22,11 @@
 #![cfg(any(feature = "test-helpers", test))]
 
 use crate::{
-	Chain, ChainWithBalances, ChainWithMessages, ChainWithRewards, ChainWithTransactions,
-	Error as SubstrateError, SignParam, UnsignedTransaction,
+	Chain, ChainWithBalances, ChainWithMessages, ChainWithRewards, ChainWithRuntimeVersion,
+	ChainWithTransactions, Error as SubstrateError, SignParam, SimpleRuntimeVersion,
+	UnsignedTransaction,
 };
+use bp_beefy::ChainWithBeefy as ChainWithBeefyBase;
 use bp_messages::{ChainWithMessages as ChainWithMessagesBase, MessageNonce};
 use bp_runtime::ChainId;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
@@ -72,6 +74,21 @@ impl Chain for TestChain {
 	type Call = TestRuntimeCall;
 }
 
+impl ChainWithRuntimeVersion for TestChain {
+	const RUNTIME_VERSION: Option<SimpleRuntimeVersion> = None;
+}
+
+impl ChainWithBeefyBase for TestChain {
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str = "TestBeefy";
+
+	type CommitmentHasher = sp_runtime::traits::Keccak256;
+	type MmrHashing = sp_runtime::traits::Keccak256;
+	type MmrHash = sp_core::H256;
+	type BeefyMmrLeafExtra = ();
+	type AuthorityId = bp_beefy::EcdsaValidatorId;
+	type AuthorityIdToMerkleLeaf = bp_beefy::BeefyEcdsaToEthereum;
+}
+
 impl ChainWithBalances for TestChain {
 	fn account_info_storage_key(_account_id: &u32) -> sp_core::storage::StorageKey {
 		unreachable!()
@@ -19,6 +19,7 @@
 use crate::{
 	equivocation::SubstrateEquivocationDetectionPipeline,
 	finality::SubstrateFinalitySyncPipeline,
+	finality_base::engine::Beefy,
 	messages::{MessagesRelayLimits, SubstrateMessageLane},
 	parachains::SubstrateParachainsPipeline,
 };
@@ -46,6 +47,17 @@ pub trait RelayToRelayHeadersCliBridge: CliBridgeBase {
 	>;
 }
 
+/// Bridge representation that can be used from the CLI for relaying BEEFY commitments
+/// from a relay chain to a relay chain.
+pub trait RelayToRelayBeefyHeadersCliBridge: CliBridgeBase {
+	/// BEEFY commitments synchronization pipeline.
+	type BeefyFinality: SubstrateFinalitySyncPipeline<
+		SourceChain = Self::Source,
+		TargetChain = Self::Target,
+		FinalityEngine = Beefy<Self::Source>,
+	>;
+}
+
 /// Convenience trait that adds bounds to `CliBridgeBase`.
 pub trait RelayToRelayEquivocationDetectionCliBridgeBase: CliBridgeBase {
 	/// The source chain with extra bounds.
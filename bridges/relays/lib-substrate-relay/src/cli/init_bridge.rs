@@ -49,6 +49,9 @@ where
 	<Self::Target as ChainBase>::AccountId: From<<AccountKeyPairOf<Self::Target> as Pair>::Public>,
 {
 	/// The finality engine used by the source chain.
+	///
+	/// It is either `Grandpa<Self::Source>` for the GRANDPA bridge pallet, or
+	/// `Beefy<Self::Source>` for the BEEFY bridge pallet.
 	type Engine: Engine<Self::Source>;
 
 	/// Get the encoded call to init the bridge.
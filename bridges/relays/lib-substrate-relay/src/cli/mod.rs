@@ -25,6 +25,7 @@ pub mod bridge;
 pub mod chain_schema;
 pub mod detect_equivocations;
 pub mod init_bridge;
+pub mod relay_beefy_headers;
 pub mod relay_headers;
 pub mod relay_headers_and_messages;
 pub mod relay_messages;
@@ -0,0 +1,217 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Primitives for exposing the BEEFY commitments relaying functionality in the CLI.
+
+use async_trait::async_trait;
+use clap::Parser;
+
+use relay_utils::metrics::{GlobalMetrics, StandaloneMetric};
+
+use crate::{
+	cli::{bridge::*, chain_schema::*, PrometheusParams},
+	finality::SubstrateFinalitySyncPipeline,
+	HeadersToRelay,
+};
+use relay_substrate_client::Client;
+
+/// BEEFY commitments relaying params.
+#[derive(Parser)]
+pub struct RelayBeefyHeadersParams {
+	#[command(flatten)]
+	source: SourceConnectionParams,
+	#[command(flatten)]
+	target: TargetConnectionParams,
+	#[command(flatten)]
+	target_sign: TargetSigningParams,
+	#[command(flatten)]
+	prometheus_params: PrometheusParams,
+}
+
+/// Trait used for relaying BEEFY commitments between 2 chains.
+#[async_trait]
+pub trait BeefyHeadersRelayer: RelayToRelayBeefyHeadersCliBridge {
+	/// Relay BEEFY commitments.
+	async fn relay_beefy_headers(data: RelayBeefyHeadersParams) -> anyhow::Result<()> {
+		let source_client = data.source.into_client::<Self::Source>().await?;
+		let target_client = data.target.into_client::<Self::Target>().await?;
+		let target_transactions_mortality = data.target_sign.target_transactions_mortality;
+		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
+
+		let metrics_params: relay_utils::metrics::MetricsParams =
+			data.prometheus_params.into_metrics_params()?;
+		GlobalMetrics::new()?.register_and_spawn(&metrics_params.registry)?;
+
+		let target_transactions_params = crate::TransactionParams {
+			signer: target_sign,
+			mortality: target_transactions_mortality,
+		};
+
+		Self::BeefyFinality::start_relay_guards(
+			&target_client,
+			target_client.can_start_version_guard(),
+		)
+		.await?;
+
+		// the BEEFY pallet only accepts commitments, signed by its current validator set, and
+		// switches to the next set right after importing a commitment. So we can't limit
+		// relay to mandatory commitments: the first commitment after initialization is
+		// usually a regular one
+		crate::finality::run::<Self::BeefyFinality>(
+			source_client,
+			target_client,
+			HeadersToRelay::All,
+			target_transactions_params,
+			metrics_params,
+		)
+		.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		finality::SubmitFinalityProofCallBuilder,
+		finality_base::{engine::Beefy, SubstrateFinalityPipeline, SubstrateFinalityProof},
+	};
+	use bp_runtime::ChainId;
+	use relay_substrate_client::{
+		test_chain::{TestChain, TestRuntimeCall},
+		CallOf, Chain, ChainWithRuntimeVersion, ChainWithTransactions, Error as SubstrateError,
+		HeaderOf, SignParam, SimpleRuntimeVersion, SyncHeader, UnsignedTransaction,
+	};
+	use sp_core::crypto::AccountId32;
+	use sp_runtime::StateVersion;
+	use std::time::Duration;
+
+	/// Chain with the BEEFY bridge pallet, that is accepting transactions from the relayer.
+	#[derive(Clone, Debug, PartialEq, Eq)]
+	struct TestTargetChain;
+
+	impl bp_runtime::Chain for TestTargetChain {
+		const ID: ChainId = *b"tgtc";
+
+		type BlockNumber = u32;
+		type Hash = sp_core::H256;
+		type Hasher = sp_runtime::traits::BlakeTwo256;
+		type Header = sp_runtime::generic::Header<u32, sp_runtime::traits::BlakeTwo256>;
+
+		type AccountId = AccountId32;
+		type Balance = u64;
+		type Nonce = u32;
+		type Signature = sp_runtime::testing::TestSignature;
+
+		const STATE_VERSION: StateVersion = StateVersion::V1;
+
+		fn max_extrinsic_size() -> u32 {
+			unreachable!()
+		}
+
+		fn max_extrinsic_weight() -> frame_support::weights::Weight {
+			unreachable!()
+		}
+	}
+
+	impl Chain for TestTargetChain {
+		const NAME: &'static str = "TestTarget";
+		const BEST_FINALIZED_HEADER_ID_METHOD: &'static str = "TestMethod";
+		const FREE_HEADERS_INTERVAL_METHOD: &'static str = "TestMethod";
+		const AVERAGE_BLOCK_INTERVAL: Duration = Duration::from_millis(0);
+
+		type SignedBlock = <TestChain as Chain>::SignedBlock;
+		type Call = TestRuntimeCall;
+	}
+
+	impl ChainWithRuntimeVersion for TestTargetChain {
+		const RUNTIME_VERSION: Option<SimpleRuntimeVersion> = None;
+	}
+
+	impl ChainWithTransactions for TestTargetChain {
+		type AccountKeyPair = sp_core::sr25519::Pair;
+		type SignedTransaction = <TestChain as ChainWithTransactions>::SignedTransaction;
+
+		fn sign_transaction(
+			_param: SignParam<Self>,
+			_unsigned: UnsignedTransaction<Self>,
+		) -> Result<Self::SignedTransaction, SubstrateError> {
+			unreachable!()
+		}
+	}
+
+	/// BEEFY commitments synchronization pipeline from the `TestChain` to the `TestTargetChain`.
+	#[derive(Clone, Debug)]
+	struct TestChainToTestTargetChain;
+
+	impl SubstrateFinalityPipeline for TestChainToTestTargetChain {
+		type SourceChain = TestChain;
+		type TargetChain = TestTargetChain;
+		type FinalityEngine = Beefy<TestChain>;
+	}
+
+	impl SubstrateFinalitySyncPipeline for TestChainToTestTargetChain {
+		type SubmitFinalityProofCallBuilder = TestSubmitCallBuilder;
+	}
+
+	struct TestSubmitCallBuilder;
+
+	impl SubmitFinalityProofCallBuilder<TestChainToTestTargetChain> for TestSubmitCallBuilder {
+		fn build_submit_finality_proof_call(
+			_header: SyncHeader<HeaderOf<TestChain>>,
+			_proof: SubstrateFinalityProof<TestChainToTestTargetChain>,
+			_is_free_execution_expected: bool,
+			_context: bp_beefy::BeefyAuthoritySetInfoOf<TestChain>,
+		) -> CallOf<TestTargetChain> {
+			TestRuntimeCall::Dummy
+		}
+	}
+
+	/// BEEFY bridge from the `TestChain` to the `TestTargetChain`.
+	struct TestChainToTestTargetChainCliBridge;
+
+	impl CliBridgeBase for TestChainToTestTargetChainCliBridge {
+		type Source = TestChain;
+		type Target = TestTargetChain;
+	}
+
+	impl RelayToRelayBeefyHeadersCliBridge for TestChainToTestTargetChainCliBridge {
+		type BeefyFinality = TestChainToTestTargetChain;
+	}
+
+	impl BeefyHeadersRelayer for TestChainToTestTargetChainCliBridge {}
+
+	#[test]
+	fn relay_beefy_headers_params_are_parsed() {
+		let params = RelayBeefyHeadersParams::parse_from([
+			"relay-beefy-headers",
+			"--source-uri",
+			"ws://localhost:9944",
+			"--target-uri",
+			"ws://localhost:9945",
+			"--target-signer",
+			"//Alice",
+			"--no-prometheus",
+		]);
+
+		assert_eq!(params.source.source_uri, "ws://localhost:9944");
+		assert_eq!(params.target.target_uri, "ws://localhost:9945");
+		assert!(params.prometheus_params.no_prometheus);
+		assert!(params
+			.target_sign
+			.to_keypair::<<TestChainToTestTargetChainCliBridge as CliBridgeBase>::Target>()
+			.is_ok());
+	}
+}
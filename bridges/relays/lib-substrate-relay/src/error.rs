@@ -55,6 +55,18 @@ pub enum Error<Hash: Debug + MaybeDisplay, HeaderNumber: Debug + MaybeDisplay> {
 	/// Failed to retrieve header by the hash from the source chain.
 	#[error("Failed to retrieve {0} header with hash {1}: {2:?}")]
 	RetrieveHeader(&'static str, Hash, client::Error),
+	/// Failed to retrieve hash of the header with given number from the source chain.
+	#[error("Failed to retrieve {0} header hash at height {1}: {2:?}")]
+	RetrieveHeaderHash(&'static str, HeaderNumber, client::Error),
+	/// Failed to retrieve BEEFY authority set at the given header from the source chain.
+	#[error("Failed to retrieve {0} BEEFY authority set at header {1}: {2:?}")]
+	RetrieveBeefyAuthoritySet(&'static str, Hash, client::Error),
+	/// BEEFY authority set at the given header of the source chain is not the set that has
+	/// signed the commitment.
+	#[error(
+		"Read unexpected {0} BEEFY authority set at header {1}: expected set {2}, read set {3}"
+	)]
+	UnexpectedBeefyAuthoritySet(&'static str, Hash, u64, u64),
 	/// Failed to submit signed extrinsic from to the target chain.
 	#[error(
 		"Failed to retrieve `is_initialized` flag of the with-{0} finality pallet at {1}: {2:?}"
@@ -238,6 +238,48 @@ macro_rules! generate_submit_finality_proof_ex_call_builder {
 	};
 }
 
+/// Macro that generates `SubmitFinalityProofCallBuilder` implementation for the case when
+/// you only have an access to the mocked version of target chain runtime. In this case you
+/// should provide "name" of the call variant for the bridge BEEFY calls and the "name" of
+/// the variant for the `submit_commitment` call within that first option.
+#[rustfmt::skip]
+#[macro_export]
+macro_rules! generate_submit_beefy_commitment_call_builder {
+	($pipeline:ident, $mocked_builder:ident, $bridge_beefy:path, $submit_commitment:path) => {
+		pub struct $mocked_builder;
+
+		impl $crate::finality::SubmitFinalityProofCallBuilder<$pipeline>
+			for $mocked_builder
+		{
+			fn build_submit_finality_proof_call(
+				_header: relay_substrate_client::SyncHeader<
+					relay_substrate_client::HeaderOf<
+						<$pipeline as $crate::finality_base::SubstrateFinalityPipeline>::SourceChain
+					>
+				>,
+				proof: $crate::finality_base::engine::BeefyFinalityProof<
+					<$pipeline as $crate::finality_base::SubstrateFinalityPipeline>::SourceChain
+				>,
+				_is_free_execution_expected: bool,
+				_context: bp_beefy::BeefyAuthoritySetInfoOf<
+					<$pipeline as $crate::finality_base::SubstrateFinalityPipeline>::SourceChain
+				>,
+			) -> relay_substrate_client::CallOf<
+				<$pipeline as $crate::finality_base::SubstrateFinalityPipeline>::TargetChain
+			> {
+				bp_runtime::paste::item! {
+					$bridge_beefy($submit_commitment {
+						commitment: proof.commitment,
+						validator_set: proof.validator_set,
+						mmr_leaf: Box::new(proof.mmr_leaf),
+						mmr_proof: proof.mmr_proof,
+					})
+				}
+			}
+		}
+	};
+}
+
 /// Run Substrate-to-Substrate finality sync loop.
 pub async fn run<P: SubstrateFinalitySyncPipeline>(
 	source_client: impl Client<P::SourceChain>,
@@ -255,8 +297,12 @@ pub async fn run<P: SubstrateFinalitySyncPipeline>(
 	);
 
 	finality_relay::run(
-		SubstrateFinalitySource::<P, _>::new(source_client, None),
-		SubstrateFinalityTarget::<P, _>::new(target_client, transaction_params.clone()),
+		SubstrateFinalitySource::<P, _>::new(source_client.clone(), None),
+		SubstrateFinalityTarget::<P, _, _>::new(
+			source_client,
+			target_client,
+			transaction_params.clone(),
+		),
 		finality_relay::FinalitySyncParams {
 			tick: std::cmp::max(
 				P::SourceChain::AVERAGE_BLOCK_INTERVAL,
@@ -284,7 +330,7 @@ pub async fn relay_single_header<P: SubstrateFinalitySyncPipeline>(
 	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
 	header_number: BlockNumberOf<P::SourceChain>,
 ) -> anyhow::Result<()> {
-	let finality_source = SubstrateFinalitySource::<P, _>::new(source_client, None);
+	let finality_source = SubstrateFinalitySource::<P, _>::new(source_client.clone(), None);
 	let (header, proof) = finality_source.header_and_finality_proof(header_number).await?;
 	let Some(proof) = proof else {
 		return Err(anyhow::format_err!(
@@ -295,7 +341,8 @@ pub async fn relay_single_header<P: SubstrateFinalitySyncPipeline>(
 		));
 	};
 
-	let finality_target = SubstrateFinalityTarget::<P, _>::new(target_client, transaction_params);
+	let finality_target =
+		SubstrateFinalityTarget::<P, _, _>::new(source_client, target_client, transaction_params);
 	let tx_tracker = finality_target.submit_finality_proof(header, proof, false).await?;
 	match tx_tracker.wait().await {
 		TrackedTransactionStatus::Finalized(_) => Ok(()),
@@ -26,7 +26,6 @@ use crate::{
 use async_std::sync::{Arc, Mutex};
 use async_trait::async_trait;
 use bp_header_chain::FinalityProof;
-use codec::Decode;
 use finality_relay::{SourceClient, SourceClientBase};
 use futures::{
 	select,
@@ -253,13 +252,12 @@ async fn header_and_finality_proof<P: SubstrateFinalitySyncPipeline>(
 	let header_hash = client.header_hash_by_number(number).await?;
 	let signed_block = client.block_by_hash(header_hash).await?;
 
-	let justification = signed_block
-		.justification(P::FinalityEngine::ID)
-		.map(|raw_justification| {
-			SubstrateFinalityProof::<P>::decode(&mut raw_justification.as_slice())
-		})
-		.transpose()
-		.map_err(Error::ResponseParseFailed)?;
+	let justification = match signed_block.justification(P::FinalityEngine::ID) {
+		Some(raw_justification) => Some(
+			P::FinalityEngine::finality_proof_from_justification(client, raw_justification).await?,
+		),
+		None => None,
+	};
 
 	Ok((signed_block.header().into(), justification))
 }
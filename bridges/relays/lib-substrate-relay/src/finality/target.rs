@@ -20,7 +20,7 @@ use crate::{
 	finality::{
 		FinalitySyncPipelineAdapter, SubmitFinalityProofCallBuilder, SubstrateFinalitySyncPipeline,
 	},
-	finality_base::{best_synced_header_id, engine::Engine, SubstrateFinalityProof},
+	finality_base::{engine::Engine, SubstrateFinalityProof},
 	TransactionParams,
 };
 
@@ -36,20 +36,28 @@ use sp_core::Pair;
 use sp_runtime::traits::Header;
 
 /// Substrate client as Substrate finality target.
-pub struct SubstrateFinalityTarget<P: SubstrateFinalitySyncPipeline, TargetClnt> {
+pub struct SubstrateFinalityTarget<P: SubstrateFinalitySyncPipeline, SourceClnt, TargetClnt> {
+	source_client: SourceClnt,
 	client: TargetClnt,
 	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
 }
 
-impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Client<P::TargetChain>>
-	SubstrateFinalityTarget<P, TargetClnt>
+impl<
+		P: SubstrateFinalitySyncPipeline,
+		SourceClnt: Client<P::SourceChain>,
+		TargetClnt: Client<P::TargetChain>,
+	> SubstrateFinalityTarget<P, SourceClnt, TargetClnt>
 {
 	/// Create new Substrate headers target.
+	///
+	/// The source client is only used to read source headers, which some finality engines
+	/// are unable to read from the target chain storage.
 	pub fn new(
+		source_client: SourceClnt,
 		client: TargetClnt,
 		transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
 	) -> Self {
-		SubstrateFinalityTarget { client, transaction_params }
+		SubstrateFinalityTarget { source_client, client, transaction_params }
 	}
 
 	/// Ensure that the bridge pallet at target chain is active.
@@ -68,11 +76,12 @@ impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Client<P::TargetChain>>
 	}
 }
 
-impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Clone> Clone
-	for SubstrateFinalityTarget<P, TargetClnt>
+impl<P: SubstrateFinalitySyncPipeline, SourceClnt: Clone, TargetClnt: Clone> Clone
+	for SubstrateFinalityTarget<P, SourceClnt, TargetClnt>
 {
 	fn clone(&self) -> Self {
 		SubstrateFinalityTarget {
+			source_client: self.source_client.clone(),
 			client: self.client.clone(),
 			transaction_params: self.transaction_params.clone(),
 		}
@@ -80,8 +89,11 @@ impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Clone> Clone
 }
 
 #[async_trait]
-impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Client<P::TargetChain>> RelayClient
-	for SubstrateFinalityTarget<P, TargetClnt>
+impl<
+		P: SubstrateFinalitySyncPipeline,
+		SourceClnt: Client<P::SourceChain>,
+		TargetClnt: Client<P::TargetChain>,
+	> RelayClient for SubstrateFinalityTarget<P, SourceClnt, TargetClnt>
 {
 	type Error = Error;
 
@@ -91,8 +103,12 @@ impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Client<P::TargetChain>> Relay
 }
 
 #[async_trait]
-impl<P: SubstrateFinalitySyncPipeline, TargetClnt: Client<P::TargetChain>>
-	TargetClient<FinalitySyncPipelineAdapter<P>> for SubstrateFinalityTarget<P, TargetClnt>
+impl<
+		P: SubstrateFinalitySyncPipeline,
+		SourceClnt: Client<P::SourceChain>,
+		TargetClnt: Client<P::TargetChain>,
+	> TargetClient<FinalitySyncPipelineAdapter<P>>
+	for SubstrateFinalityTarget<P, SourceClnt, TargetClnt>
 where
 	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
 {
@@ -105,7 +121,8 @@ where
 		// we can't relay finality if bridge pallet at target chain is halted
 		self.ensure_pallet_active().await?;
 
-		Ok(best_synced_header_id::<P::SourceChain, P::TargetChain>(
+		Ok(P::FinalityEngine::best_synced_header_id(
+			&self.source_client,
 			&self.client,
 			self.client.best_header().await?.hash(),
 		)
@@ -18,6 +18,12 @@
 
 use crate::error::Error;
 use async_trait::async_trait;
+use bp_beefy::{
+	BeefyAuthorityIdOf, BeefyAuthoritySetInfoOf, BeefyAuthoritySetOf, BeefyConsensusLog,
+	BeefyMmrLeafOf, BeefySignedCommitmentOf, ImportedCommitment, ImportedCommitmentsInfoData,
+	MmrEncodableOpaqueLeaf, MmrError, MmrHashOf, MmrProofOf, VersionedFinalityProof,
+	BEEFY_ENGINE_ID,
+};
 use bp_header_chain::{
 	justification::{
 		verify_and_optimize_justification, GrandpaEquivocationsFinder, GrandpaJustification,
@@ -26,17 +32,18 @@ use bp_header_chain::{
 	AuthoritySet, ConsensusLogReader, FinalityProof, FindEquivocations, GrandpaConsensusLogReader,
 	HeaderFinalityInfo, HeaderGrandpaInfo, StoredHeaderGrandpaInfo, SubmitFinalityProofCallExtras,
 };
-use bp_runtime::{BasicOperatingMode, HeaderIdProvider, OperatingMode};
-use codec::{Decode, Encode};
+use bp_runtime::{BasicOperatingMode, HeaderId, HeaderIdProvider, OperatingMode};
+use codec::{Codec, Decode, Encode};
+use frame_support::{CloneNoBound, DebugNoBound};
 use futures::stream::StreamExt;
 use num_traits::{One, Zero};
 use relay_substrate_client::{
-	BlockNumberOf, Chain, ChainWithGrandpa, Client, Error as SubstrateError, HashOf, HeaderOf,
-	Subscription,
+	BlockNumberOf, Chain, ChainWithBeefy, ChainWithGrandpa, Client, Error as SubstrateError,
+	HashOf, HeaderIdOf, HeaderOf, Subscription,
 };
 use sp_consensus_grandpa::{AuthorityList as GrandpaAuthoritiesSet, GRANDPA_ENGINE_ID};
 use sp_core::{storage::StorageKey, Bytes};
-use sp_runtime::{scale_info::TypeInfo, traits::Header, ConsensusEngineId};
+use sp_runtime::{scale_info::TypeInfo, traits::Header, ConsensusEngineId, Digest};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Finality engine, used by the Substrate chain.
@@ -104,6 +111,27 @@ pub trait Engine<C: Chain>: Send {
 		source_client: &impl Client<C>,
 	) -> Result<Subscription<Bytes>, SubstrateError>;
 
+	/// Build finality proof from the encoded justification, generated by the source node.
+	///
+	/// The justification is either read from the stream, returned by the
+	/// `source_finality_proofs`, or from the source block.
+	async fn finality_proof_from_justification(
+		_source_client: &impl Client<C>,
+		justification: &[u8],
+	) -> Result<Self::FinalityProof, SubstrateError> {
+		Self::FinalityProof::decode(&mut &justification[..])
+			.map_err(SubstrateError::ResponseParseFailed)
+	}
+
+	/// Returns id of the best source header, known to the finality pallet at the target chain.
+	async fn best_synced_header_id<TargetChain: Chain>(
+		_source_client: &impl Client<C>,
+		target_client: &impl Client<TargetChain>,
+		at: HashOf<TargetChain>,
+	) -> Result<Option<HeaderIdOf<C>>, SubstrateError> {
+		crate::finality_base::best_synced_header_id::<C, TargetChain>(target_client, at).await
+	}
+
 	/// Verify and optimize finality proof before sending it to the target node.
 	///
 	/// Apart from optimization, we expect this method to perform all required checks
@@ -443,3 +471,790 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 		Ok(key_owner_proof)
 	}
 }
+
+/// BEEFY finality proof of the source chain header.
+///
+/// BEEFY justification is a signed commitment to the MMR root. So apart from the commitment
+/// itself, the proof contains everything that the BEEFY bridge pallet needs to verify it: the
+/// validator set that has signed the commitment and the MMR leaf (with its proof), appended by
+/// the committed header.
+#[derive(CloneNoBound, DebugNoBound, Decode, Encode)]
+pub struct BeefyFinalityProof<C: ChainWithBeefy> {
+	/// Hash of the header, committed by the `commitment`.
+	pub header_hash: HashOf<C>,
+	/// Signed BEEFY commitment.
+	pub commitment: BeefySignedCommitmentOf<C>,
+	/// Validator set that has signed the `commitment`.
+	pub validator_set: BeefyAuthoritySetOf<C>,
+	/// MMR leaf, appended by the committed header.
+	pub mmr_leaf: BeefyMmrLeafOf<C>,
+	/// Proof of `mmr_leaf` membership in the MMR, committed by the `commitment`.
+	pub mmr_proof: MmrProofOf<C>,
+}
+
+impl<C: ChainWithBeefy> FinalityProof<HashOf<C>, BlockNumberOf<C>> for BeefyFinalityProof<C> {
+	fn target_header_hash(&self) -> HashOf<C> {
+		self.header_hash
+	}
+
+	fn target_header_number(&self) -> BlockNumberOf<C> {
+		self.commitment.commitment.block_number
+	}
+}
+
+/// A struct that provides helper methods for querying the BEEFY consensus log.
+pub struct BeefyConsensusLogReader<AuthorityId>(PhantomData<AuthorityId>);
+
+impl<AuthorityId: Codec> ConsensusLogReader for BeefyConsensusLogReader<AuthorityId> {
+	fn schedules_authorities_change(digest: &Digest) -> bool {
+		// BEEFY validator set change is enacted immediately and the header, that is changing
+		// the set, is always finalized by BEEFY
+		digest.logs().iter().any(|log| {
+			matches!(
+				log.consensus_try_to(&BEEFY_ENGINE_ID),
+				Some(BeefyConsensusLog::<AuthorityId>::AuthoritiesChange(_))
+			)
+		})
+	}
+}
+
+/// BEEFY equivocations finder.
+///
+/// BEEFY equivocations detection is not supported yet, so it never finds anything.
+pub struct BeefyEquivocationsFinder<C>(PhantomData<C>);
+
+impl<C: ChainWithBeefy> FindEquivocations<BeefyFinalityProof<C>, BeefyAuthoritySetInfoOf<C>, ()>
+	for BeefyEquivocationsFinder<C>
+{
+	type Error = ();
+
+	fn find_equivocations(
+		_verification_context: &BeefyAuthoritySetInfoOf<C>,
+		_synced_proof: &BeefyFinalityProof<C>,
+		_source_proofs: &[BeefyFinalityProof<C>],
+	) -> Result<Vec<()>, Self::Error> {
+		Ok(vec![])
+	}
+}
+
+/// BEEFY finality engine.
+pub struct Beefy<C>(PhantomData<C>);
+
+impl<C: ChainWithBeefy> Beefy<C> {
+	/// Read hash of the header with given number from the source client.
+	async fn source_header_hash(
+		source_client: &impl Client<C>,
+		header_number: BlockNumberOf<C>,
+	) -> Result<HashOf<C>, Error<HashOf<C>, BlockNumberOf<C>>> {
+		source_client
+			.header_hash_by_number(header_number)
+			.await
+			.map_err(|err| Error::RetrieveHeaderHash(C::NAME, header_number, err))
+	}
+
+	/// Read BEEFY authority set, that is finalizing descendants of the given header.
+	async fn source_authority_set(
+		source_client: &impl Client<C>,
+		header_hash: C::Hash,
+	) -> Result<BeefyAuthoritySetInfoOf<C>, Error<HashOf<C>, BlockNumberOf<C>>> {
+		const SUB_API_BEEFY_MMR_AUTHORITY_SET_PROOF: &str = "BeefyMmrApi_authority_set_proof";
+
+		source_client
+			.state_call(header_hash, SUB_API_BEEFY_MMR_AUTHORITY_SET_PROOF.to_string(), ())
+			.await
+			.map_err(|err| Error::RetrieveBeefyAuthoritySet(C::NAME, header_hash, err))
+	}
+}
+
+#[async_trait]
+impl<C: ChainWithBeefy> Engine<C> for Beefy<C> {
+	const ID: ConsensusEngineId = BEEFY_ENGINE_ID;
+	type ConsensusLogReader = BeefyConsensusLogReader<BeefyAuthorityIdOf<C>>;
+	type FinalityProof = BeefyFinalityProof<C>;
+	type FinalityVerificationContext = BeefyAuthoritySetInfoOf<C>;
+	type EquivocationProof = ();
+	type EquivocationsFinder = BeefyEquivocationsFinder<C>;
+	type KeyOwnerProof = ();
+	type InitializationData = bp_beefy::InitializationData<BlockNumberOf<C>, MmrHashOf<C>>;
+	type OperatingMode = BasicOperatingMode;
+
+	fn is_initialized_key() -> StorageKey {
+		bp_beefy::storage_keys::imported_commitments_info_key(C::WITH_CHAIN_BEEFY_PALLET_NAME)
+	}
+
+	fn pallet_operating_mode_key() -> StorageKey {
+		bp_beefy::storage_keys::pallet_operating_mode_key(C::WITH_CHAIN_BEEFY_PALLET_NAME)
+	}
+
+	async fn source_finality_proofs(
+		client: &impl Client<C>,
+	) -> Result<Subscription<Bytes>, SubstrateError> {
+		client.subscribe_beefy_finality_justifications().await
+	}
+
+	async fn finality_proof_from_justification(
+		source_client: &impl Client<C>,
+		justification: &[u8],
+	) -> Result<Self::FinalityProof, SubstrateError> {
+		const SUB_API_BEEFY_VALIDATOR_SET: &str = "BeefyApi_validator_set";
+		const SUB_API_MMR_GENERATE_PROOF: &str = "MmrApi_generate_proof";
+
+		let commitment: BeefySignedCommitmentOf<C> =
+			match VersionedFinalityProof::decode(&mut &justification[..])
+				.map_err(SubstrateError::ResponseParseFailed)?
+			{
+				VersionedFinalityProof::V1(commitment) => commitment,
+			};
+		let header_number = commitment.commitment.block_number;
+		let header_hash = source_client.header_hash_by_number(header_number).await?;
+
+		// read validator set that has signed the commitment
+		let validator_set_id = commitment.commitment.validator_set_id;
+		let validator_set: Option<BeefyAuthoritySetOf<C>> = source_client
+			.state_call(header_hash, SUB_API_BEEFY_VALIDATOR_SET.to_string(), ())
+			.await?;
+		let validator_set = validator_set
+			.filter(|validator_set| validator_set.id() == validator_set_id)
+			.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"{} BEEFY validator set {validator_set_id} is not active at header {:?}",
+					C::NAME,
+					HeaderId(header_number, header_hash),
+				))
+			})?;
+
+		// generate proof of the MMR leaf, appended by the committed header
+		let (mmr_leaves, mmr_proof): (Vec<MmrEncodableOpaqueLeaf>, MmrProofOf<C>) = source_client
+			.state_call::<_, Result<_, MmrError>>(
+				header_hash,
+				SUB_API_MMR_GENERATE_PROOF.to_string(),
+				(vec![header_number], Some(header_number)),
+			)
+			.await?
+			.map_err(|e| {
+				SubstrateError::Custom(format!(
+					"Failed to generate {} MMR proof for header {:?}: {e:?}",
+					C::NAME,
+					HeaderId(header_number, header_hash),
+				))
+			})?;
+		let mmr_leaf = mmr_leaves.into_iter().next().ok_or_else(|| {
+			SubstrateError::Custom(format!(
+				"{} MMR proof for header {:?} has no leaves",
+				C::NAME,
+				HeaderId(header_number, header_hash),
+			))
+		})?;
+		let mmr_leaf = BeefyMmrLeafOf::<C>::decode(&mut &mmr_leaf.into_opaque_leaf().0[..])
+			.map_err(SubstrateError::ResponseParseFailed)?;
+
+		Ok(BeefyFinalityProof { header_hash, commitment, validator_set, mmr_leaf, mmr_proof })
+	}
+
+	async fn best_synced_header_id<TargetChain: Chain>(
+		source_client: &impl Client<C>,
+		target_client: &impl Client<TargetChain>,
+		at: HashOf<TargetChain>,
+	) -> Result<Option<HeaderIdOf<C>>, SubstrateError> {
+		let commitments_info: Option<ImportedCommitmentsInfoData<BlockNumberOf<C>>> = target_client
+			.storage_value(
+				at,
+				bp_beefy::storage_keys::imported_commitments_info_key(
+					C::WITH_CHAIN_BEEFY_PALLET_NAME,
+				),
+			)
+			.await?;
+		let Some(commitments_info) = commitments_info else { return Ok(None) };
+
+		// the pallet doesn't know hash of the best committed header - only the hash of its
+		// parent, which is proven by the MMR leaf. So we read the hash from the source chain
+		// and use the parent hash to check that the pallet is on the same fork. The commitment
+		// is missing when pallet has just been initialized
+		let best_header_number = commitments_info.best_block_number;
+		let best_header = source_client.header_by_number(best_header_number).await?;
+		let imported_commitment: Option<
+			ImportedCommitment<BlockNumberOf<C>, HashOf<C>, MmrHashOf<C>>,
+		> = target_client
+			.storage_value(
+				at,
+				bp_beefy::storage_keys::imported_commitment_key(
+					C::WITH_CHAIN_BEEFY_PALLET_NAME,
+					best_header_number,
+				),
+			)
+			.await?;
+		let is_on_same_fork = imported_commitment
+			.map(|imported_commitment| {
+				imported_commitment.parent_number_and_hash.1 == *best_header.parent_hash()
+			})
+			.unwrap_or(true);
+		if !is_on_same_fork {
+			return Err(SubstrateError::Custom(format!(
+				"Parent of the best {} header {:?}, known to the BEEFY pallet at {}, is not on the \
+				source fork",
+				C::NAME,
+				best_header.id(),
+				TargetChain::NAME,
+			)))
+		}
+
+		Ok(Some(best_header.id()))
+	}
+
+	async fn verify_and_optimize_proof<TargetChain: Chain>(
+		target_client: &impl Client<TargetChain>,
+		header: &C::Header,
+		proof: &mut Self::FinalityProof,
+	) -> Result<Self::FinalityVerificationContext, SubstrateError> {
+		let verification_context = Beefy::<C>::finality_verification_context(
+			target_client,
+			target_client.best_header().await?.hash(),
+		)
+		.await?;
+		// signatures and the MMR proof are verified by the pallet, but it only accepts
+		// commitments that are signed by its current authority set. BEEFY commitments have
+		// no redundant data, so there's nothing to optimize here
+		let validator_set_id = proof.commitment.commitment.validator_set_id;
+		if validator_set_id != verification_context.id {
+			return Err(SubstrateError::Custom(format!(
+				"{} BEEFY commitment for header {:?} is signed by validator set {validator_set_id}, \
+				while the pallet at {} expects set {}",
+				C::NAME,
+				header.id(),
+				TargetChain::NAME,
+				verification_context.id,
+			)))
+		}
+
+		Ok(verification_context)
+	}
+
+	fn check_max_expected_call_limits(
+		_header: &C::Header,
+		_proof: &Self::FinalityProof,
+	) -> SubmitFinalityProofCallExtras {
+		// BEEFY pallet doesn't support free execution, so there are no limits to check
+		SubmitFinalityProofCallExtras {
+			is_weight_limit_exceeded: false,
+			extra_size: 0,
+			is_mandatory_finality_target: false,
+		}
+	}
+
+	/// Prepare initialization data for the BEEFY bridge pallet.
+	async fn prepare_initialization_data(
+		source_client: impl Client<C>,
+	) -> Result<Self::InitializationData, Error<HashOf<C>, BlockNumberOf<C>>> {
+		// BEEFY authority set is known to the runtime (`BeefyMmrApi::authority_set_proof()`),
+		// so we only need to wait for the next justification and read the set at the header
+		// that it commits.
+		let mut justifications = Self::source_finality_proofs(&source_client)
+			.await
+			.map_err(|err| Error::Subscribe(C::NAME, err))?;
+		// Read next justification - the header that it commits will be used as initial header.
+		let justification = justifications
+			.next()
+			.await
+			.ok_or(Error::ReadJustificationStreamEnded(C::NAME))?;
+
+		let commitment: BeefySignedCommitmentOf<C> =
+			match VersionedFinalityProof::decode(&mut &justification.0[..])
+				.map_err(|err| Error::DecodeJustification(C::NAME, err))?
+			{
+				VersionedFinalityProof::V1(commitment) => commitment,
+			};
+
+		let initial_header_number = commitment.commitment.block_number;
+		let initial_header_hash =
+			Self::source_header_hash(&source_client, initial_header_number).await?;
+		tracing::trace!(
+			target: "bridge",
+			node=%C::NAME,
+			%initial_header_number,
+			%initial_header_hash,
+			"Selected initial header"
+		);
+
+		// Read BEEFY authority set at initial header. It must be the set that has signed the
+		// commitment.
+		let initial_authority_set =
+			Self::source_authority_set(&source_client, initial_header_hash).await?;
+		tracing::trace!(
+			target: "bridge",
+			node=%C::NAME,
+			?initial_authority_set,
+			"Selected"
+		);
+		if initial_authority_set.id != commitment.commitment.validator_set_id {
+			return Err(Error::UnexpectedBeefyAuthoritySet(
+				C::NAME,
+				initial_header_hash,
+				commitment.commitment.validator_set_id,
+				initial_authority_set.id,
+			))
+		}
+
+		Ok(bp_beefy::InitializationData {
+			operating_mode: BasicOperatingMode::Normal,
+			best_block_number: initial_header_number,
+			authority_set: initial_authority_set,
+		})
+	}
+
+	async fn finality_verification_context<TargetChain: Chain>(
+		target_client: &impl Client<TargetChain>,
+		at: HashOf<TargetChain>,
+	) -> Result<Self::FinalityVerificationContext, SubstrateError> {
+		let current_authority_set_info_key =
+			bp_beefy::storage_keys::current_authority_set_info_key(C::WITH_CHAIN_BEEFY_PALLET_NAME);
+		target_client
+			.storage_value(at, current_authority_set_info_key)
+			.await?
+			.map(Ok)
+			.unwrap_or(Err(SubstrateError::Custom(format!(
+				"{} `CurrentAuthoritySetInfo` is missing from the {} storage",
+				C::NAME,
+				TargetChain::NAME,
+			))))
+	}
+
+	async fn synced_headers_finality_info<TargetChain: Chain>(
+		_target_client: &impl Client<TargetChain>,
+		_at: TargetChain::Hash,
+	) -> Result<
+		Vec<HeaderFinalityInfo<Self::FinalityProof, Self::FinalityVerificationContext>>,
+		SubstrateError,
+	> {
+		// BEEFY bridge pallet doesn't expose synced commitments, so there's nothing to look at
+		Ok(vec![])
+	}
+
+	async fn generate_source_key_ownership_proof(
+		_source_client: &impl Client<C>,
+		_at: C::Hash,
+		_equivocation: &Self::EquivocationProof,
+	) -> Result<Self::KeyOwnerProof, SubstrateError> {
+		Err(SubstrateError::Custom(format!(
+			"BEEFY equivocations reporting is not supported for {}",
+			C::NAME,
+		)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bp_beefy::{
+		BeefyPayload, Commitment, EcdsaValidatorId, EcdsaValidatorSignature, MmrLeafVersion,
+		MmrProof, SignedCommitment, ValidatorSet, MMR_ROOT_PAYLOAD_ID,
+	};
+	use frame_support::weights::Weight;
+	use relay_substrate_client::{
+		test_chain::TestChain, AccountKeyPairOf, NonceOf, SignedBlockOf, SimpleRuntimeVersion,
+		StreamDescription, TransactionTracker, UnsignedTransaction,
+	};
+	use sp_core::storage::StorageData;
+	use sp_runtime::transaction_validity::TransactionValidity;
+	use sp_trie::StorageProof;
+	use sp_version::RuntimeVersion;
+	use std::collections::HashMap;
+
+	type TestHeader = HeaderOf<TestChain>;
+
+	/// Client of a chain, that is both the BEEFY source and the target with the BEEFY pallet.
+	#[derive(Clone, Debug, Default)]
+	struct TestClient {
+		/// Headers of the chain, indexed by number.
+		headers: Vec<TestHeader>,
+		/// Storage of the chain.
+		storage: HashMap<StorageKey, Vec<u8>>,
+		/// Encoded results of the runtime calls.
+		state_calls: HashMap<String, Vec<u8>>,
+		/// BEEFY justifications, generated by the chain.
+		justifications: Vec<Bytes>,
+	}
+
+	impl TestClient {
+		fn new() -> Self {
+			let mut headers = vec![];
+			for number in 0..4u32 {
+				let parent_hash = headers.last().map(TestHeader::hash).unwrap_or_default();
+				headers.push(TestHeader::new(
+					number,
+					Default::default(),
+					Default::default(),
+					parent_hash,
+					Default::default(),
+				));
+			}
+			TestClient { headers, ..Default::default() }
+		}
+
+		fn with_storage(mut self, key: StorageKey, value: impl Encode) -> Self {
+			self.storage.insert(key, value.encode());
+			self
+		}
+
+		fn with_state_call(mut self, method: &str, result: impl Encode) -> Self {
+			self.state_calls.insert(method.into(), result.encode());
+			self
+		}
+
+		fn with_justification(mut self, justification: impl Encode) -> Self {
+			self.justifications.push(Bytes(justification.encode()));
+			self
+		}
+	}
+
+	#[async_trait]
+	impl Client<TestChain> for TestClient {
+		async fn ensure_synced(&self) -> relay_substrate_client::Result<()> {
+			Ok(())
+		}
+
+		async fn reconnect(&self) -> relay_substrate_client::Result<()> {
+			Ok(())
+		}
+
+		fn genesis_hash(&self) -> HashOf<TestChain> {
+			self.headers[0].hash()
+		}
+
+		async fn header_hash_by_number(
+			&self,
+			number: BlockNumberOf<TestChain>,
+		) -> relay_substrate_client::Result<HashOf<TestChain>> {
+			self.headers
+				.get(number as usize)
+				.map(TestHeader::hash)
+				.ok_or_else(|| SubstrateError::Custom(format!("Unknown header {number}")))
+		}
+
+		async fn header_by_hash(
+			&self,
+			hash: HashOf<TestChain>,
+		) -> relay_substrate_client::Result<TestHeader> {
+			self.headers
+				.iter()
+				.find(|header| header.hash() == hash)
+				.cloned()
+				.ok_or_else(|| SubstrateError::Custom(format!("Unknown header {hash}")))
+		}
+
+		async fn block_by_hash(
+			&self,
+			_hash: HashOf<TestChain>,
+		) -> relay_substrate_client::Result<SignedBlockOf<TestChain>> {
+			unimplemented!()
+		}
+
+		async fn best_finalized_header_hash(
+			&self,
+		) -> relay_substrate_client::Result<HashOf<TestChain>> {
+			self.best_header_hash().await
+		}
+
+		async fn best_header(&self) -> relay_substrate_client::Result<TestHeader> {
+			Ok(self.headers.last().expect("the chain has headers").clone())
+		}
+
+		async fn subscribe_best_headers(
+			&self,
+		) -> relay_substrate_client::Result<Subscription<TestHeader>> {
+			unimplemented!()
+		}
+
+		async fn subscribe_finalized_headers(
+			&self,
+		) -> relay_substrate_client::Result<Subscription<TestHeader>> {
+			unimplemented!()
+		}
+
+		async fn subscribe_grandpa_finality_justifications(
+			&self,
+		) -> relay_substrate_client::Result<Subscription<Bytes>> {
+			unimplemented!()
+		}
+
+		async fn generate_grandpa_key_ownership_proof(
+			&self,
+			_at: HashOf<TestChain>,
+			_set_id: sp_consensus_grandpa::SetId,
+			_authority_id: sp_consensus_grandpa::AuthorityId,
+		) -> relay_substrate_client::Result<Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof>>
+		{
+			unimplemented!()
+		}
+
+		async fn subscribe_beefy_finality_justifications(
+			&self,
+		) -> relay_substrate_client::Result<Subscription<Bytes>> {
+			Ok(Subscription::new_broadcasted(
+				StreamDescription::new("BEEFY justifications".into(), TestChain::NAME.into()),
+				futures::stream::iter(self.justifications.clone()),
+			))
+		}
+
+		async fn token_decimals(&self) -> relay_substrate_client::Result<Option<u64>> {
+			unimplemented!()
+		}
+
+		async fn runtime_version(&self) -> relay_substrate_client::Result<RuntimeVersion> {
+			unimplemented!()
+		}
+
+		async fn simple_runtime_version(
+			&self,
+		) -> relay_substrate_client::Result<SimpleRuntimeVersion> {
+			unimplemented!()
+		}
+
+		fn can_start_version_guard(&self) -> bool {
+			false
+		}
+
+		async fn raw_storage_value(
+			&self,
+			_at: HashOf<TestChain>,
+			storage_key: StorageKey,
+		) -> relay_substrate_client::Result<Option<StorageData>> {
+			Ok(self.storage.get(&storage_key).cloned().map(StorageData))
+		}
+
+		async fn pending_extrinsics(&self) -> relay_substrate_client::Result<Vec<Bytes>> {
+			unimplemented!()
+		}
+
+		async fn submit_unsigned_extrinsic(
+			&self,
+			_transaction: Bytes,
+		) -> relay_substrate_client::Result<HashOf<TestChain>> {
+			unimplemented!()
+		}
+
+		async fn submit_signed_extrinsic(
+			&self,
+			_signer: &AccountKeyPairOf<TestChain>,
+			_prepare_extrinsic: impl FnOnce(
+					HeaderIdOf<TestChain>,
+					NonceOf<TestChain>,
+				) -> relay_substrate_client::Result<UnsignedTransaction<TestChain>>
+				+ Send
+				+ 'static,
+		) -> relay_substrate_client::Result<HashOf<TestChain>> {
+			unimplemented!()
+		}
+
+		async fn submit_and_watch_signed_extrinsic(
+			&self,
+			_signer: &AccountKeyPairOf<TestChain>,
+			_prepare_extrinsic: impl FnOnce(
+					HeaderIdOf<TestChain>,
+					NonceOf<TestChain>,
+				) -> relay_substrate_client::Result<UnsignedTransaction<TestChain>>
+				+ Send
+				+ 'static,
+		) -> relay_substrate_client::Result<TransactionTracker<TestChain, Self>> {
+			unimplemented!()
+		}
+
+		async fn validate_transaction<SignedTransaction: Encode + Send + 'static>(
+			&self,
+			_at: HashOf<TestChain>,
+			_transaction: SignedTransaction,
+		) -> relay_substrate_client::Result<TransactionValidity> {
+			unimplemented!()
+		}
+
+		async fn estimate_extrinsic_weight<SignedTransaction: Encode + Send + 'static>(
+			&self,
+			_at: HashOf<TestChain>,
+			_transaction: SignedTransaction,
+		) -> relay_substrate_client::Result<Weight> {
+			unimplemented!()
+		}
+
+		async fn raw_state_call<Args: Encode + Send>(
+			&self,
+			_at: HashOf<TestChain>,
+			method: String,
+			_arguments: Args,
+		) -> relay_substrate_client::Result<Bytes> {
+			self.state_calls
+				.get(&method)
+				.cloned()
+				.map(Bytes)
+				.ok_or_else(|| SubstrateError::Custom(format!("Unknown runtime call {method}")))
+		}
+
+		async fn prove_storage(
+			&self,
+			_at: HashOf<TestChain>,
+			_keys: Vec<StorageKey>,
+		) -> relay_substrate_client::Result<(StorageProof, HashOf<TestChain>)> {
+			unimplemented!()
+		}
+	}
+
+	const PALLET_NAME: &str = <TestChain as bp_beefy::ChainWithBeefy>::WITH_CHAIN_BEEFY_PALLET_NAME;
+
+	fn validator_set(id: u64) -> BeefyAuthoritySetOf<TestChain> {
+		let validator: EcdsaValidatorId = sp_core::ecdsa::Public::from_raw([1; 33]).into();
+		ValidatorSet::new(vec![validator], id).expect("the set is not empty")
+	}
+
+	fn authority_set_info(id: u64) -> BeefyAuthoritySetInfoOf<TestChain> {
+		BeefyAuthoritySetInfoOf::<TestChain> { id, len: 1, keyset_commitment: Default::default() }
+	}
+
+	fn justification(
+		block_number: u32,
+		validator_set_id: u64,
+	) -> VersionedFinalityProof<u32, EcdsaValidatorSignature> {
+		VersionedFinalityProof::V1(SignedCommitment {
+			commitment: Commitment {
+				payload: BeefyPayload::from_single_entry(MMR_ROOT_PAYLOAD_ID, vec![]),
+				block_number,
+				validator_set_id,
+			},
+			signatures: vec![None],
+		})
+	}
+
+	fn mmr_leaf(client: &TestClient, block_number: u32) -> BeefyMmrLeafOf<TestChain> {
+		let parent = &client.headers[block_number as usize - 1];
+		BeefyMmrLeafOf::<TestChain> {
+			version: MmrLeafVersion::new(0, 0),
+			parent_number_and_hash: (*parent.number(), parent.hash()),
+			beefy_next_authority_set: authority_set_info(1),
+			leaf_extra: (),
+		}
+	}
+
+	fn mmr_proof(block_number: u32) -> MmrProofOf<TestChain> {
+		MmrProof { leaf_indices: vec![block_number as u64], leaf_count: 4, items: vec![] }
+	}
+
+	#[async_std::test]
+	async fn finality_proof_from_justification_works() {
+		let client = TestClient::new();
+		let mmr_leaf = mmr_leaf(&client, 2);
+		let client = client
+			.with_state_call("BeefyApi_validator_set", Some(validator_set(0)))
+			.with_state_call(
+				"MmrApi_generate_proof",
+				Ok::<_, MmrError>((
+					vec![MmrEncodableOpaqueLeaf::from_leaf(&mmr_leaf)],
+					mmr_proof(2),
+				)),
+			);
+
+		let proof = Beefy::<TestChain>::finality_proof_from_justification(
+			&client,
+			&justification(2, 0).encode(),
+		)
+		.await
+		.unwrap();
+
+		assert_eq!(proof.header_hash, client.headers[2].hash());
+		assert_eq!(proof.commitment.commitment.block_number, 2);
+		assert_eq!(proof.validator_set, validator_set(0));
+		assert_eq!(proof.mmr_leaf, mmr_leaf);
+		assert_eq!(proof.mmr_proof, mmr_proof(2));
+	}
+
+	#[async_std::test]
+	async fn finality_proof_from_justification_fails_if_signed_by_inactive_set() {
+		let client =
+			TestClient::new().with_state_call("BeefyApi_validator_set", Some(validator_set(1)));
+
+		assert!(Beefy::<TestChain>::finality_proof_from_justification(
+			&client,
+			&justification(2, 0).encode(),
+		)
+		.await
+		.is_err());
+	}
+
+	#[async_std::test]
+	async fn best_synced_header_id_is_none_before_initialization() {
+		let client = TestClient::new();
+		let at = client.best_header_hash().await.unwrap();
+
+		assert_eq!(
+			Beefy::<TestChain>::best_synced_header_id(&client, &client, at).await.unwrap(),
+			None
+		);
+	}
+
+	#[async_std::test]
+	async fn best_synced_header_id_works() {
+		let client = TestClient::new();
+		let parent = client.headers[1].clone();
+		let client = client
+			.with_storage(
+				bp_beefy::storage_keys::imported_commitments_info_key(PALLET_NAME),
+				ImportedCommitmentsInfoData { best_block_number: 2u32, next_block_number_index: 0 },
+			)
+			.with_storage(
+				bp_beefy::storage_keys::imported_commitment_key(PALLET_NAME, 2u32),
+				ImportedCommitment {
+					parent_number_and_hash: (1u32, parent.hash()),
+					mmr_root: sp_core::H256::default(),
+				},
+			);
+		let at = client.best_header_hash().await.unwrap();
+
+		assert_eq!(
+			Beefy::<TestChain>::best_synced_header_id(&client, &client, at).await.unwrap(),
+			Some(client.headers[2].id()),
+		);
+	}
+
+	#[async_std::test]
+	async fn best_synced_header_id_fails_if_pallet_is_on_another_fork() {
+		let client = TestClient::new()
+			.with_storage(
+				bp_beefy::storage_keys::imported_commitments_info_key(PALLET_NAME),
+				ImportedCommitmentsInfoData { best_block_number: 2u32, next_block_number_index: 0 },
+			)
+			.with_storage(
+				bp_beefy::storage_keys::imported_commitment_key(PALLET_NAME, 2u32),
+				ImportedCommitment {
+					parent_number_and_hash: (1u32, sp_core::H256::repeat_byte(1)),
+					mmr_root: sp_core::H256::default(),
+				},
+			);
+		let at = client.best_header_hash().await.unwrap();
+
+		assert!(Beefy::<TestChain>::best_synced_header_id(&client, &client, at).await.is_err());
+	}
+
+	#[async_std::test]
+	async fn prepare_initialization_data_works() {
+		let client = TestClient::new()
+			.with_justification(justification(3, 0))
+			.with_state_call("BeefyMmrApi_authority_set_proof", authority_set_info(0));
+
+		assert_eq!(
+			Beefy::<TestChain>::prepare_initialization_data(client).await.unwrap(),
+			bp_beefy::InitializationData {
+				operating_mode: BasicOperatingMode::Normal,
+				best_block_number: 3,
+				authority_set: authority_set_info(0),
+			},
+		);
+	}
+
+	#[async_std::test]
+	async fn prepare_initialization_data_fails_if_commitment_is_signed_by_another_set() {
+		let client = TestClient::new()
+			.with_justification(justification(3, 0))
+			.with_state_call("BeefyMmrApi_authority_set_proof", authority_set_info(1));
+
+		assert!(matches!(
+			Beefy::<TestChain>::prepare_initialization_data(client).await,
+			Err(Error::UnexpectedBeefyAuthoritySet(_, _, 0, 1)),
+		));
+	}
+}
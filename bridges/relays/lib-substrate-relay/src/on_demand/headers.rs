@@ -220,8 +220,11 @@ async fn background_task<P: SubstrateFinalitySyncPipeline>(
 		source_client.clone(),
 		Some(required_header_number.clone()),
 	);
-	let mut finality_target =
-		SubstrateFinalityTarget::new(target_client.clone(), target_transaction_params);
+	let mut finality_target = SubstrateFinalityTarget::new(
+		source_client.clone(),
+		target_client.clone(),
+		target_transaction_params,
+	);
 	let mut latest_non_mandatory_at_source = Zero::zero();
 
 	let mut restart_relay = true;
@@ -253,7 +256,7 @@ async fn background_task<P: SubstrateFinalitySyncPipeline>(
 
 		// read best finalized source header number from target
 		let best_finalized_source_header_at_target =
-			best_finalized_source_header_at_target::<P, _>(&finality_target, &relay_task_name)
+			best_finalized_source_header_at_target::<P, _, _>(&finality_target, &relay_task_name)
 				.await;
 		if matches!(best_finalized_source_header_at_target, Err(ref e) if e.is_connection_error()) {
 			relay_utils::relay_loop::reconnect_failed_client(
@@ -483,15 +486,16 @@ where
 /// Read best finalized source block number from target client.
 ///
 /// Returns `None` if we have failed to read the number.
-async fn best_finalized_source_header_at_target<P, TargetClnt>(
-	finality_target: &SubstrateFinalityTarget<P, TargetClnt>,
+async fn best_finalized_source_header_at_target<P, SourceClnt, TargetClnt>(
+	finality_target: &SubstrateFinalityTarget<P, SourceClnt, TargetClnt>,
 	relay_task_name: &str,
 ) -> Result<
 	BlockNumberOf<P::SourceChain>,
-	<SubstrateFinalityTarget<P, TargetClnt> as RelayClient>::Error,
+	<SubstrateFinalityTarget<P, SourceClnt, TargetClnt> as RelayClient>::Error,
 >
 where
 	P: SubstrateFinalitySyncPipeline,
+	SourceClnt: Client<P::SourceChain>,
 	TargetClnt: Client<P::TargetChain>,
 	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as sp_core::Pair>::Public>,
 {
@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+
+/// Generalized Indices
+/// related to Merkle proofs
+/// get_generalized_index(BeaconState, 'historical_summaries')
+pub const HISTORICAL_SUMMARIES_INDEX: usize = 59;
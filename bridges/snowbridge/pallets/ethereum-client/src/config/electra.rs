@@ -5,6 +5,8 @@
 /// related to Merkle proofs
 /// get_generalized_index(BeaconState, 'block_roots')
 pub const BLOCK_ROOTS_INDEX: usize = 69;
+/// get_generalized_index(BeaconState, 'historical_summaries')
+pub const HISTORICAL_SUMMARIES_INDEX: usize = 91;
 /// get_generalized_index(BeaconState, 'finalized_checkpoint', 'root')
 pub const FINALIZED_ROOT_INDEX: usize = 169;
 /// get_generalized_index(BeaconState, 'current_sync_committee')
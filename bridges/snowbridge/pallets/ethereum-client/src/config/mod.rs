@@ -3,6 +3,7 @@
 use static_assertions::const_assert;
 
 pub mod altair;
+pub mod capella;
 pub mod electra;
 
 /// Sizes related to SSZ encoding
@@ -38,3 +39,12 @@ pub const SYNC_COMMITTEE_BITS_SIZE: usize = SYNC_COMMITTEE_SIZE / 8;
 pub const SLOTS_PER_HISTORICAL_ROOT: usize = 8192;
 /// The index of the block_roots field in the beacon state tree.
 pub const BLOCK_ROOT_AT_INDEX_DEPTH: usize = 13;
+/// The maximum number of entries in the `historical_summaries` list of the beacon state.
+pub const HISTORICAL_ROOTS_LIMIT: usize = 16_777_216;
+/// The depth of the `block_summary_root` of a `historical_summaries` entry below the list root:
+/// the length mix-in, the list entries (`log2(HISTORICAL_ROOTS_LIMIT)`) and the
+/// `HistoricalSummary` container.
+pub const HISTORICAL_SUMMARY_BLOCK_ROOT_DEPTH: usize = 26;
+
+// Sanity check for the historical summaries depth (see HISTORICAL_ROOTS_LIMIT).
+const_assert!(1 << (HISTORICAL_SUMMARY_BLOCK_ROOT_DEPTH - 2) == HISTORICAL_ROOTS_LIMIT);
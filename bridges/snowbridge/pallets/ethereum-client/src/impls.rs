@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use super::*;
-use frame_support::ensure;
-use snowbridge_beacon_primitives::ExecutionProof;
+use frame_support::{ensure, pallet_prelude::DispatchError};
+use snowbridge_beacon_primitives::{AncestryProof, ExecutionProof, HistoricalSummaryProof};
 
 use snowbridge_beacon_primitives::{
 	merkle_proof::{generalized_index_length, subtree_index},
@@ -21,7 +21,26 @@ impl<T: Config> Verifier for Pallet<T> {
 	/// is also sent with the message, to check if the header is an ancestor of a finalized
 	/// header.
 	fn verify(event_log: &Log, proof: &Proof) -> Result<(), VerificationError> {
-		Self::verify_execution_proof(&proof.execution_proof)
+		Self::verify_execution_proof(&proof.execution_proof, None)
+			.map_err(|e| InvalidExecutionProof(e.into()))?;
+
+		Self::verify_receipt_inclusion(
+			proof.execution_proof.execution_header.receipts_root(),
+			&proof.receipt_proof.1,
+			event_log,
+		)?;
+
+		Ok(())
+	}
+
+	/// Verify a message like [`Self::verify`], proving the beacon header against the
+	/// `block_roots` archived in the `historical_summaries` of the finalized state.
+	fn verify_historical(
+		event_log: &Log,
+		proof: &Proof,
+		historical_summary_proof: &HistoricalSummaryProof,
+	) -> Result<(), VerificationError> {
+		Self::verify_execution_proof(&proof.execution_proof, Some(historical_summary_proof))
 			.map_err(|e| InvalidExecutionProof(e.into()))?;
 
 		Self::verify_receipt_inclusion(
@@ -71,8 +90,13 @@ impl<T: Config> Pallet<T> {
 
 	/// Validates an execution header with ancestry_proof against a finalized checkpoint on
 	/// chain.The beacon header containing the execution header is sent, plus the execution header,
-	/// along with a proof that the execution header is rooted in the beacon header body.
-	pub(crate) fn verify_execution_proof(execution_proof: &ExecutionProof) -> DispatchResult {
+	/// along with a proof that the execution header is rooted in the beacon header body. Headers
+	/// older than the `block_roots` window of the finalized state require a
+	/// `historical_summary_proof`.
+	pub(crate) fn verify_execution_proof(
+		execution_proof: &ExecutionProof,
+		historical_summary_proof: Option<&HistoricalSummaryProof>,
+	) -> DispatchResult {
 		let latest_finalized_state =
 			FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
 				.ok_or(Error::<T>::NotBootstrapped)?;
@@ -92,11 +116,14 @@ impl<T: Config> Pallet<T> {
 				Self::verify_ancestry_proof(
 					beacon_block_root,
 					execution_proof.header.slot,
-					&proof.header_branch,
-					proof.finalized_block_root,
+					proof,
+					historical_summary_proof,
 				)?;
 			},
 			None => {
+				// A historical summary proof is only meaningful for an ancestor of the
+				// finalized header.
+				ensure!(historical_summary_proof.is_none(), Error::<T>::MissingAncestryProof);
 				// If the ancestry proof is not provided, we expect this beacon header to be a
 				// finalized beacon header. We need to check that the header hash matches the
 				// finalized header root at the expected slot.
@@ -132,32 +159,103 @@ impl<T: Config> Pallet<T> {
 
 	/// Verify that `block_root` is an ancestor of `finalized_block_root` Used to prove that
 	/// an execution header is an ancestor of a finalized header (i.e. the blocks are
-	/// on the same chain).
+	/// on the same chain). Headers still within the `block_roots` window of the finalized state
+	/// are proven against its `block_roots`, older headers against the `block_roots` archived in
+	/// its `historical_summaries`.
 	fn verify_ancestry_proof(
 		block_root: H256,
 		block_slot: u64,
-		block_root_proof: &[H256],
-		finalized_block_root: H256,
+		ancestry_proof: &AncestryProof,
+		historical_summary_proof: Option<&HistoricalSummaryProof>,
 	) -> DispatchResult {
-		let state = <FinalizedBeaconState<T>>::get(finalized_block_root)
+		let state = <FinalizedBeaconState<T>>::get(ancestry_proof.finalized_block_root)
 			.ok_or(Error::<T>::ExpectedFinalizedHeaderNotStored)?;
 
 		ensure!(block_slot < state.slot, Error::<T>::HeaderNotFinalized);
 
+		let block_roots_root = match historical_summary_proof {
+			Some(proof) => Self::verify_historical_summary_proof(
+				block_slot,
+				ancestry_proof.finalized_block_root,
+				proof,
+			)?,
+			None => state.block_roots_root,
+		};
+
 		let index_in_array = block_slot % (SLOTS_PER_HISTORICAL_ROOT as u64);
 		let leaf_index = (SLOTS_PER_HISTORICAL_ROOT as u64) + index_in_array;
 
 		ensure!(
 			verify_merkle_branch(
 				block_root,
-				block_root_proof,
+				&ancestry_proof.header_branch,
 				leaf_index as usize,
 				config::BLOCK_ROOT_AT_INDEX_DEPTH,
-				state.block_roots_root
+				block_roots_root
 			),
 			Error::<T>::InvalidAncestryMerkleProof
 		);
 
 		Ok(())
 	}
+
+	/// Verify that the `block_roots` of the period containing `block_slot` were archived in the
+	/// `historical_summaries` of the state of the finalized header `finalized_block_root`.
+	/// Returns the archived `block_roots` root, against which the header can then be proven.
+	fn verify_historical_summary_proof(
+		block_slot: u64,
+		finalized_block_root: H256,
+		proof: &HistoricalSummaryProof,
+	) -> Result<H256, DispatchError> {
+		let finalized_header_root: H256 = proof
+			.finalized_header
+			.hash_tree_root()
+			.map_err(|_| Error::<T>::HeaderHashTreeRootFailed)?;
+		ensure!(finalized_header_root == finalized_block_root, Error::<T>::FinalizedHeaderMismatch);
+
+		// Historical summaries are accumulated from the Capella fork onwards, one for every
+		// `SLOTS_PER_HISTORICAL_ROOT` slots, appended once the period has ended.
+		let fork_versions = T::ForkVersions::get();
+		let capella_period =
+			fork_versions.capella.epoch.saturating_mul(config::SLOTS_PER_EPOCH as u64) /
+				SLOTS_PER_HISTORICAL_ROOT as u64;
+		let block_period = block_slot / SLOTS_PER_HISTORICAL_ROOT as u64;
+		let finalized_period = proof.finalized_header.slot / SLOTS_PER_HISTORICAL_ROOT as u64;
+		ensure!(
+			block_period >= capella_period && block_period < finalized_period,
+			Error::<T>::HistoricalSummaryNotAvailable
+		);
+		let summary_index = block_period - capella_period;
+		ensure!(
+			summary_index < config::HISTORICAL_ROOTS_LIMIT as u64,
+			Error::<T>::HistoricalSummaryNotAvailable
+		);
+
+		// The `block_summary_root` is the first field of the `HistoricalSummary` container.
+		ensure!(
+			verify_merkle_branch(
+				proof.block_summary_root,
+				&proof.block_summary_root_branch,
+				(summary_index * 2) as usize,
+				config::HISTORICAL_SUMMARY_BLOCK_ROOT_DEPTH,
+				proof.historical_summaries_root
+			),
+			Error::<T>::InvalidBlockSummaryRootMerkleProof
+		);
+
+		let historical_summaries_gindex =
+			Self::historical_summaries_gindex_at_slot(proof.finalized_header.slot, fork_versions);
+		ensure!(
+			verify_merkle_branch(
+				proof.historical_summaries_root,
+				&proof.historical_summaries_branch,
+				subtree_index(historical_summaries_gindex),
+				generalized_index_length(historical_summaries_gindex),
+				proof.finalized_header.state_root
+			),
+			Error::<T>::InvalidHistoricalSummariesMerkleProof
+		);
+
+		Ok(proof.block_summary_root)
+	}
 }
@@ -143,6 +143,16 @@ pub mod pallet {
 		ExecutionHeaderTooFarBehind,
 		ExecutionHeaderSkippedBlock,
 		Halted,
+		InvalidHistoricalSummariesMerkleProof,
+		InvalidBlockSummaryRootMerkleProof,
+		/// The finalized header in the historical summary proof does not match the finalized
+		/// block root of the ancestry proof.
+		FinalizedHeaderMismatch,
+		/// The header predates the Capella fork, or its period has not been archived into the
+		/// historical summaries of the finalized state yet.
+		HistoricalSummaryNotAvailable,
+		/// A historical summary proof was given for a header without an ancestry proof.
+		MissingAncestryProof,
 	}
 
 	/// Latest imported checkpoint root
@@ -757,6 +767,19 @@ pub mod pallet {
 			config::altair::BLOCK_ROOTS_INDEX
 		}
 
+		pub fn historical_summaries_gindex_at_slot(
+			slot: u64,
+			fork_versions: ForkVersions,
+		) -> usize {
+			let epoch = compute_epoch(slot, config::SLOTS_PER_EPOCH as u64);
+
+			if epoch >= fork_versions.electra.epoch {
+				return config::electra::HISTORICAL_SUMMARIES_INDEX;
+			}
+
+			config::capella::HISTORICAL_SUMMARIES_INDEX
+		}
+
 		pub fn execution_header_gindex() -> usize {
 			config::altair::EXECUTION_HEADER_INDEX
 		}
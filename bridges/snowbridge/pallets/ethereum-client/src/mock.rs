@@ -27,6 +27,15 @@ pub fn load_execution_proof_fixture() -> snowbridge_beacon_primitives::Execution
 	load_fixture("execution-proof.json".to_string()).unwrap()
 }
 
+pub fn load_historical_execution_proof_fixture() -> snowbridge_beacon_primitives::ExecutionProof {
+	load_fixture("historical-execution-proof.json".to_string()).unwrap()
+}
+
+pub fn load_historical_summary_proof_fixture(
+) -> snowbridge_beacon_primitives::HistoricalSummaryProof {
+	load_fixture("historical-summary-proof.json".to_string()).unwrap()
+}
+
 pub fn load_checkpoint_update_fixture(
 ) -> snowbridge_beacon_primitives::CheckpointUpdate<{ config::SYNC_COMMITTEE_SIZE }> {
 	load_fixture("initial-checkpoint.json".to_string()).unwrap()
@@ -18,7 +18,8 @@ use hex_literal::hex;
 use snowbridge_beacon_primitives::{
 	merkle_proof::{generalized_index_length, subtree_index},
 	types::deneb,
-	Fork, ForkVersions, NextSyncCommitteeUpdate, VersionedExecutionPayloadHeader,
+	ExecutionProof, Fork, ForkVersions, HistoricalSummaryProof, NextSyncCommitteeUpdate,
+	VersionedExecutionPayloadHeader,
 };
 use snowbridge_verification_primitives::{VerificationError, Verifier};
 use sp_core::H256;
@@ -869,7 +870,7 @@ fn verify_execution_proof_invalid_ancestry_proof() {
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
 		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
 		assert_err!(
-			EthereumBeaconClient::verify_execution_proof(&execution_header_update),
+			EthereumBeaconClient::verify_execution_proof(&execution_header_update, None),
 			Error::<Test>::InvalidAncestryMerkleProof
 		);
 	});
@@ -886,7 +887,7 @@ fn verify_execution_proof_invalid_execution_header_proof() {
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
 		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
 		assert_err!(
-			EthereumBeaconClient::verify_execution_proof(&execution_header_update),
+			EthereumBeaconClient::verify_execution_proof(&execution_header_update, None),
 			Error::<Test>::InvalidExecutionHeaderProof
 		);
 	});
@@ -903,7 +904,7 @@ fn verify_execution_proof_that_is_also_finalized_header_which_is_not_stored() {
 		assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
 		assert_ok!(EthereumBeaconClient::submit(RuntimeOrigin::signed(1), finalized_header_update));
 		assert_err!(
-			EthereumBeaconClient::verify_execution_proof(&execution_header_update),
+			EthereumBeaconClient::verify_execution_proof(&execution_header_update, None),
 			Error::<Test>::ExpectedFinalizedHeaderNotStored
 		);
 	});
@@ -932,7 +933,7 @@ fn submit_execution_proof_that_is_also_finalized_header_which_is_stored_but_slot
 		LatestFinalizedBlockRoot::<Test>::set(block_root);
 
 		assert_err!(
-			EthereumBeaconClient::verify_execution_proof(&execution_header_update),
+			EthereumBeaconClient::verify_execution_proof(&execution_header_update, None),
 			Error::<Test>::ExpectedFinalizedHeaderNotStored
 		);
 	});
@@ -954,12 +955,172 @@ fn verify_execution_proof_not_finalized() {
 		});
 
 		assert_err!(
-			EthereumBeaconClient::verify_execution_proof(&update),
+			EthereumBeaconClient::verify_execution_proof(&update, None),
 			Error::<Test>::HeaderNotFinalized
 		);
 	});
 }
 
+/// Stores the finalized header referenced by `historical_summary_proof`, as the finalized
+/// header of the ancestry proof of `proof`.
+fn store_historical_summary_proof_finalized_header(
+	proof: &ExecutionProof,
+	historical_summary_proof: &HistoricalSummaryProof,
+) {
+	let finalized_block_root = proof.ancestry_proof.as_ref().unwrap().finalized_block_root;
+	<FinalizedBeaconState<Test>>::insert(
+		finalized_block_root,
+		CompactBeaconState {
+			slot: historical_summary_proof.finalized_header.slot,
+			block_roots_root: TEST_HASH.into(),
+		},
+	);
+	LatestFinalizedBlockRoot::<Test>::set(finalized_block_root);
+}
+
+#[test]
+fn verify_execution_proof_with_historical_summary_proof() {
+	let execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		assert_ok!(EthereumBeaconClient::verify_execution_proof(
+			&execution_proof,
+			Some(&historical_summary_proof)
+		));
+	});
+}
+
+#[test]
+fn verify_execution_proof_for_archived_header_without_historical_summary_proof() {
+	let execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		// Without the historical summary proof, the header is proven against the block roots
+		// of the finalized state, which no longer contain it.
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(&execution_proof, None),
+			Error::<Test>::InvalidAncestryMerkleProof
+		);
+	});
+}
+
+#[test]
+fn verify_execution_proof_with_historical_summary_proof_without_ancestry_proof() {
+	let mut execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		execution_proof.ancestry_proof = None;
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(
+				&execution_proof,
+				Some(&historical_summary_proof)
+			),
+			Error::<Test>::MissingAncestryProof
+		);
+	});
+}
+
+#[test]
+fn verify_execution_proof_with_invalid_block_summary_root_proof() {
+	let execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let mut historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		historical_summary_proof.block_summary_root_branch[0] = TEST_HASH.into();
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(
+				&execution_proof,
+				Some(&historical_summary_proof)
+			),
+			Error::<Test>::InvalidBlockSummaryRootMerkleProof
+		);
+	});
+}
+
+#[test]
+fn verify_execution_proof_with_invalid_historical_summaries_proof() {
+	let execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let mut historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		historical_summary_proof.historical_summaries_branch[0] = TEST_HASH.into();
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(
+				&execution_proof,
+				Some(&historical_summary_proof)
+			),
+			Error::<Test>::InvalidHistoricalSummariesMerkleProof
+		);
+	});
+}
+
+#[test]
+fn verify_execution_proof_with_historical_summary_proof_for_other_finalized_header() {
+	let execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let mut historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		historical_summary_proof.finalized_header.state_root = TEST_HASH.into();
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(
+				&execution_proof,
+				Some(&historical_summary_proof)
+			),
+			Error::<Test>::FinalizedHeaderMismatch
+		);
+	});
+}
+
+#[test]
+fn verify_execution_proof_with_historical_summary_proof_for_header_not_yet_archived() {
+	let mut execution_proof = Box::new(load_historical_execution_proof_fixture());
+	let historical_summary_proof = load_historical_summary_proof_fixture();
+
+	new_tester().execute_with(|| {
+		store_historical_summary_proof_finalized_header(
+			&execution_proof,
+			&historical_summary_proof,
+		);
+		// Move the header into the period of the finalized header, which is not archived in
+		// its historical summaries yet.
+		execution_proof.header.slot = historical_summary_proof.finalized_header.slot - 1;
+		assert_err!(
+			EthereumBeaconClient::verify_execution_proof(
+				&execution_proof,
+				Some(&historical_summary_proof)
+			),
+			Error::<Test>::HistoricalSummaryNotAvailable
+		);
+	});
+}
+
 #[test]
 fn verify_message_invalid_topic() {
 	let (event_log, proof) = get_message_verification_payload();
@@ -38,6 +38,7 @@ mod test;
 pub use crate::weights::WeightInfo;
 use bp_relayers::RewardLedger;
 use frame_system::ensure_signed;
+use snowbridge_beacon_primitives::HistoricalSummaryProof;
 use snowbridge_core::{
 	reward::{AddTip, AddTipError},
 	sparse_bitmap::{SparseBitmap, SparseBitmapImpl},
@@ -209,6 +210,33 @@ pub mod pallet {
 			Self::deposit_event(Event::OperatingModeChanged { mode });
 			Ok(())
 		}
+
+		/// Submit an inbound message whose execution header has fallen out of the `block_roots`
+		/// window of the finalized beacon state, proven through its `historical_summaries`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::submit_with_historical_proof())]
+		pub fn submit_with_historical_proof(
+			origin: OriginFor<T>,
+			event: Box<EventProof>,
+			historical_summary_proof: Box<HistoricalSummaryProof>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!OperatingMode::<T>::get().is_halted(), Error::<T>::Halted);
+
+			// submit message for verification
+			T::Verifier::verify_historical(
+				&event.event_log,
+				&event.proof,
+				&historical_summary_proof,
+			)
+			.map_err(|e| Error::<T>::Verification(e))?;
+
+			// Decode event log into a bridge message
+			let message =
+				Message::try_from(&event.event_log).map_err(|_| Error::<T>::InvalidMessage)?;
+
+			Self::process_message(who, message)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
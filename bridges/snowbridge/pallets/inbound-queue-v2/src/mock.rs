@@ -6,7 +6,8 @@ use crate::{self as inbound_queue_v2};
 use frame_support::{derive_impl, parameter_types};
 use hex_literal::hex;
 use snowbridge_beacon_primitives::{
-	types::deneb, BeaconHeader, ExecutionProof, VersionedExecutionPayloadHeader,
+	types::deneb, BeaconHeader, ExecutionProof, HistoricalSummaryProof,
+	VersionedExecutionPayloadHeader,
 };
 use snowbridge_core::{ParaId, TokenId};
 use snowbridge_inbound_queue_primitives::{
@@ -65,11 +66,40 @@ impl pallet_balances::Config for Test {
 // Mock verifier
 pub struct MockVerifier;
 
+/// The number of slots covered by the `block_roots` of a beacon state.
+pub(crate) const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+
+parameter_types! {
+	/// Slot of the latest finalized beacon header known to the mock verifier.
+	pub storage FinalizedSlot: u64 = 0;
+}
+
 impl Verifier for MockVerifier {
-	fn verify(log: &Log, _: &Proof) -> Result<(), VerificationError> {
+	fn verify(log: &Log, proof: &Proof) -> Result<(), VerificationError> {
+		if log.address == ERROR_ADDRESS.into() {
+			return Err(VerificationError::InvalidProof)
+		}
+		// Headers out of the `block_roots` window of the finalized state need a historical
+		// summary proof.
+		if proof.execution_proof.header.slot + SLOTS_PER_HISTORICAL_ROOT < FinalizedSlot::get() {
+			return Err(VerificationError::InvalidExecutionProof("InvalidAncestryMerkleProof"))
+		}
+		Ok(())
+	}
+
+	fn verify_historical(
+		log: &Log,
+		proof: &Proof,
+		historical_summary_proof: &HistoricalSummaryProof,
+	) -> Result<(), VerificationError> {
 		if log.address == ERROR_ADDRESS.into() {
 			return Err(VerificationError::InvalidProof)
 		}
+		if historical_summary_proof.finalized_header.slot > FinalizedSlot::get() ||
+			proof.execution_proof.header.slot >= historical_summary_proof.finalized_header.slot
+		{
+			return Err(VerificationError::InvalidExecutionProof("HistoricalSummaryNotAvailable"))
+		}
 		Ok(())
 	}
 }
@@ -5,6 +5,7 @@ use super::*;
 use crate::{mock::*, Error};
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
+use snowbridge_beacon_primitives::{BeaconHeader, HistoricalSummaryProof};
 use snowbridge_inbound_queue_primitives::{v2::Payload, EventProof, Proof};
 use snowbridge_test_utils::{
 	mock_rewards::{RegisteredRewardAmount, RegisteredRewardsCount},
@@ -92,6 +93,55 @@ fn test_submit_verification_fails_with_invalid_proof() {
 	});
 }
 
+#[test]
+fn test_submit_with_historical_proof_for_archived_header() {
+	new_tester().execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = RuntimeOrigin::signed(relayer);
+
+		// The header has fallen out of the `block_roots` window of the finalized state.
+		let finalized_slot = 2 * SLOTS_PER_HISTORICAL_ROOT;
+		FinalizedSlot::set(&finalized_slot);
+		let mut event = EventProof {
+			event_log: mock_event_log(),
+			proof: Proof {
+				receipt_proof: Default::default(),
+				execution_proof: mock_execution_proof(),
+			},
+		};
+		event.proof.execution_proof.header.slot = 1;
+		let historical_summary_proof = HistoricalSummaryProof {
+			finalized_header: BeaconHeader { slot: finalized_slot, ..Default::default() },
+			historical_summaries_root: Default::default(),
+			historical_summaries_branch: vec![],
+			block_summary_root: Default::default(),
+			block_summary_root_branch: vec![],
+		};
+
+		assert_noop!(
+			InboundQueue::submit(origin.clone(), Box::new(event.clone())),
+			Error::<Test>::Verification(VerificationError::InvalidExecutionProof(
+				"InvalidAncestryMerkleProof"
+			))
+		);
+
+		assert_ok!(InboundQueue::submit_with_historical_proof(
+			origin,
+			Box::new(event),
+			Box::new(historical_summary_proof)
+		));
+		let events = frame_system::Pallet::<Test>::events();
+		assert!(
+			events.iter().any(|event| matches!(
+				event.event,
+				RuntimeEvent::InboundQueue(Event::MessageReceived { nonce, ..})
+					if nonce == 1
+			)),
+			"no message received event emitted."
+		);
+	});
+}
+
 #[test]
 fn test_submit_fails_with_malformed_message() {
 	new_tester().execute_with(|| {
@@ -18,6 +18,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for ethereum_beacon_client.
 pub trait WeightInfo {
     fn submit() -> Weight;
+    fn submit_with_historical_proof() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -32,5 +33,9 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+    /// Not benchmarked yet, bounded by `submit` plus the verification of the two merkle
+    /// branches of the historical summary proof.
+    fn submit_with_historical_proof() -> Weight {
+        Self::submit().saturating_add(Weight::from_parts(10_000_000, 0))
+    }
 }
-
@@ -5,7 +5,8 @@ use super::*;
 use frame_support::{derive_impl, parameter_types, traits::ConstU32, weights::IdentityFee};
 use hex_literal::hex;
 use snowbridge_beacon_primitives::{
-	types::deneb, BeaconHeader, ExecutionProof, Fork, ForkVersions, VersionedExecutionPayloadHeader,
+	types::deneb, BeaconHeader, ExecutionProof, Fork, ForkVersions, HistoricalSummaryProof,
+	VersionedExecutionPayloadHeader,
 };
 use snowbridge_core::{
 	gwei, meth, Channel, ChannelId, PricingParameters, Rewards, StaticLookup, TokenId,
@@ -113,6 +114,14 @@ impl Verifier for MockVerifier {
 	fn verify(_: &Log, _: &Proof) -> Result<(), VerificationError> {
 		Ok(())
 	}
+
+	fn verify_historical(
+		_: &Log,
+		_: &Proof,
+		_: &HistoricalSummaryProof,
+	) -> Result<(), VerificationError> {
+		Ok(())
+	}
 }
 
 const GATEWAY_ADDRESS: [u8; 20] = hex!["eda338e4dc46038493b885327842fd3e301cab39"];
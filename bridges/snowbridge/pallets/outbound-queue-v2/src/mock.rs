@@ -12,6 +12,7 @@ use frame_support::{
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use hex_literal::hex;
 use scale_info::TypeInfo;
+use snowbridge_beacon_primitives::HistoricalSummaryProof;
 use snowbridge_core::{AgentId, AgentIdOf, ChannelId, ParaId};
 use snowbridge_outbound_queue_primitives::{v2::*, Log, Proof, VerificationError, Verifier};
 use snowbridge_test_utils::mock_rewards::{BridgeReward, MockRewardLedger};
@@ -69,6 +70,7 @@ impl pallet_message_queue::Config for Test {
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 }
 
 // Mock verifier
@@ -78,6 +80,14 @@ impl Verifier for MockVerifier {
 	fn verify(_: &Log, _: &Proof) -> Result<(), VerificationError> {
 		Ok(())
 	}
+
+	fn verify_historical(
+		_: &Log,
+		_: &Proof,
+		_: &HistoricalSummaryProof,
+	) -> Result<(), VerificationError> {
+		Ok(())
+	}
 }
 
 const GATEWAY_ADDRESS: [u8; 20] = hex!["b1185ede04202fe62d38f5db72f71e38ff3e8305"];
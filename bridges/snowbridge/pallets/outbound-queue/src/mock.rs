@@ -66,6 +66,7 @@ impl pallet_message_queue::Config for Test {
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 }
 
 parameter_types! {
@@ -16,8 +16,8 @@ mod serde_utils;
 
 pub use types::{
 	AncestryProof, BeaconHeader, CompactBeaconState, ExecutionPayloadHeader, ExecutionProof,
-	FinalizedHeaderState, Fork, ForkData, ForkVersion, ForkVersions, Mode, PublicKey, Signature,
-	SigningData, SyncAggregate, SyncCommittee, SyncCommitteePrepared,
+	FinalizedHeaderState, Fork, ForkData, ForkVersion, ForkVersions, HistoricalSummaryProof, Mode,
+	PublicKey, Signature, SigningData, SyncAggregate, SyncCommittee, SyncCommitteePrepared,
 	VersionedExecutionPayloadHeader,
 };
 pub use updates::{CheckpointUpdate, NextSyncCommitteeUpdate, Update};
@@ -465,6 +465,30 @@ pub struct AncestryProof {
 	pub finalized_block_root: H256,
 }
 
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, CloneNoBound, PartialEqNoBound, DebugNoBound, TypeInfo,
+)]
+#[cfg_attr(
+	feature = "std",
+	derive(serde::Deserialize),
+	serde(deny_unknown_fields, bound(serialize = ""), bound(deserialize = ""))
+)]
+pub struct HistoricalSummaryProof {
+	/// The finalized header referenced by `finalized_block_root`. Its `state_root` commits to
+	/// the `historical_summaries` list.
+	pub finalized_header: BeaconHeader,
+	/// Root of the `historical_summaries` list in the finalized beacon state
+	pub historical_summaries_root: H256,
+	/// Merkle proof that `historical_summaries_root` is contained within the state of
+	/// `finalized_header`
+	pub historical_summaries_branch: Vec<H256>,
+	/// The `block_summary_root` of the historical summary covering `header`, i.e. the root of
+	/// the `block_roots` vector at the end of its period
+	pub block_summary_root: H256,
+	/// Merkle proof that `block_summary_root` is contained within `historical_summaries_root`
+	pub block_summary_root_branch: Vec<H256>,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -5,13 +5,21 @@
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::PalletError;
 use scale_info::TypeInfo;
-use snowbridge_beacon_primitives::{BeaconHeader, ExecutionProof};
+use snowbridge_beacon_primitives::{BeaconHeader, ExecutionProof, HistoricalSummaryProof};
 use sp_core::{H160, H256};
 use sp_std::prelude::*;
 
 /// A trait for verifying inbound messages from Ethereum.
 pub trait Verifier {
 	fn verify(event: &Log, proof: &Proof) -> Result<(), VerificationError>;
+
+	/// Verify an inbound message whose execution header has fallen out of the `block_roots`
+	/// window of the finalized beacon state, using the `historical_summaries` it was archived in.
+	fn verify_historical(
+		event: &Log,
+		proof: &Proof,
+		historical_summary_proof: &HistoricalSummaryProof,
+	) -> Result<(), VerificationError>;
 }
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, PalletError, TypeInfo)]
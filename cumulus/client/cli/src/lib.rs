@@ -31,7 +31,7 @@ use sc_chain_spec::ChainSpec;
 use sc_cli::RpcEndpoint;
 use sc_client_api::HeaderBackend;
 use sc_service::{
-	config::{PrometheusConfig, RpcBatchRequestConfig, TelemetryEndpoints},
+	config::{ExecutionLimit, PrometheusConfig, RpcBatchRequestConfig, TelemetryEndpoints},
 	BasePath, TransactionPoolOptions,
 };
 use sp_core::hexdisplay::HexDisplay;
@@ -464,6 +464,12 @@ impl sc_cli::CliConfiguration for NormalizedRunCmd {
 		Ok(self.base.rpc_params.rpc_rate_limit_trust_proxy_headers)
 	}
 
+	fn rpc_rate_limit_overrides(
+		&self,
+	) -> sc_cli::Result<Option<sc_service::config::RpcRateLimitOverrides>> {
+		self.base.rpc_params.rpc_rate_limit_overrides()
+	}
+
 	fn rpc_max_request_size(&self) -> sc_cli::Result<u32> {
 		self.base.rpc_max_request_size()
 	}
@@ -496,6 +502,18 @@ impl sc_cli::CliConfiguration for NormalizedRunCmd {
 		self.base.runtime_cache_size()
 	}
 
+	fn rpc_execution_limit(&self) -> sc_cli::Result<Option<ExecutionLimit>> {
+		self.base.rpc_execution_limit()
+	}
+
+	fn offchain_execution_limit(&self) -> sc_cli::Result<Option<ExecutionLimit>> {
+		self.base.offchain_execution_limit()
+	}
+
+	fn onchain_execution_limit(&self) -> sc_cli::Result<Option<ExecutionLimit>> {
+		self.base.onchain_execution_limit()
+	}
+
 	fn base_path(&self) -> sc_cli::Result<Option<BasePath>> {
 		self.base.base_path()
 	}
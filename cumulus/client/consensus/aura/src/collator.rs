@@ -170,6 +170,8 @@ where
 	/// the timestamp inherent data with the one provided, if any. Additionally allows to specify
 	/// relay parent descendants that can be used to prevent authoring at the tip of the relay
 	/// chain.
+	///
+	/// The values of `additional_relay_state_keys` are included in the relay chain state proof.
 	pub async fn create_inherent_data_with_rp_offset(
 		&self,
 		relay_parent: PHash,
@@ -177,6 +179,7 @@ where
 		parent_hash: Block::Hash,
 		timestamp: impl Into<Option<Timestamp>>,
 		relay_parent_descendants: Option<RelayParentData>,
+		additional_relay_state_keys: Vec<Vec<u8>>,
 		collator_peer_id: PeerId,
 	) -> Result<(ParachainInherentData, InherentData), Box<dyn Error + Send + Sync + 'static>> {
 		let paras_inherent_data = ParachainInherentDataProvider::create_at(
@@ -187,7 +190,7 @@ where
 			relay_parent_descendants
 				.map(RelayParentData::into_inherent_descendant_list)
 				.unwrap_or_default(),
-			Vec::new(),
+			additional_relay_state_keys,
 			collator_peer_id,
 		)
 		.await;
@@ -218,12 +221,15 @@ where
 
 	/// Explicitly creates the inherent data for parachain block authoring and overrides
 	/// the timestamp inherent data with the one provided, if any.
+	///
+	/// The values of `additional_relay_state_keys` are included in the relay chain state proof.
 	pub async fn create_inherent_data(
 		&self,
 		relay_parent: PHash,
 		validation_data: &PersistedValidationData,
 		parent_hash: Block::Hash,
 		timestamp: impl Into<Option<Timestamp>>,
+		additional_relay_state_keys: Vec<Vec<u8>>,
 		collator_peer_id: PeerId,
 	) -> Result<(ParachainInherentData, InherentData), Box<dyn Error + Send + Sync + 'static>> {
 		self.create_inherent_data_with_rp_offset(
@@ -232,6 +238,7 @@ where
 			parent_hash,
 			timestamp,
 			None,
+			additional_relay_state_keys,
 			collator_peer_id,
 		)
 		.await
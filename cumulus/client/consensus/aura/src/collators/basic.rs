@@ -51,7 +51,7 @@ use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Member};
 use sp_state_machine::Backend as _;
 use std::{sync::Arc, time::Duration};
 
-use crate::collator as collator_util;
+use crate::{collator as collator_util, collators::relay_state_keys};
 
 /// Parameters for [`run`].
 pub struct Params<BI, CIDP, Client, RClient, Proposer, CS> {
@@ -238,6 +238,7 @@ where
 						&validation_data,
 						parent_hash,
 						claim.timestamp(),
+						relay_state_keys(&*params.para_client, parent_hash),
 						params.collator_peer_id,
 					)
 					.await
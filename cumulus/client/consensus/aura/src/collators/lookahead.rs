@@ -47,14 +47,14 @@ use polkadot_primitives::{CollatorPair, Id as ParaId, OccupiedCoreAssumption};
 
 use crate::{
 	collator as collator_util,
-	collators::{claim_queue_at, BackingGroupConnectionHelper},
+	collators::{claim_queue_at, relay_state_keys, BackingGroupConnectionHelper},
 	export_pov_to_path,
 };
 use futures::prelude::*;
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf};
 use sc_consensus::BlockImport;
 use sc_network_types::PeerId;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{CallApiAt, ProvideRuntimeApi};
 use sp_application_crypto::AppPublic;
 use sp_blockchain::HeaderBackend;
 use sp_consensus_aura::{AuraApi, Slot};
@@ -157,6 +157,7 @@ pub fn run<Block, P, BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS>(
 where
 	Block: BlockT,
 	Client: ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>
 		+ BlockOf
 		+ AuxStore
 		+ HeaderBackend<Block>
@@ -209,6 +210,7 @@ pub fn run_with_export<Block, P, BI, CIDP, Client, Backend, RClient, CHP, Propos
 where
 	Block: BlockT,
 	Client: ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>
 		+ BlockOf
 		+ AuxStore
 		+ HeaderBackend<Block>
@@ -398,6 +400,7 @@ where
 						&validation_data,
 						parent_hash,
 						slot_claim.timestamp(),
+						relay_state_keys(para_client, parent_hash),
 						params.collator_peer_id,
 					)
 					.await
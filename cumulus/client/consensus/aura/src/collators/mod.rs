@@ -22,10 +22,10 @@
 //! builds on parachain blocks which have not yet been included in the relay chain.
 
 use crate::collator::SlotClaim;
-use codec::Codec;
+use codec::{Codec, Decode};
 use cumulus_client_consensus_common::{self as consensus_common, ParentSearchParams};
 use cumulus_primitives_aura::{AuraUnincludedSegmentApi, Slot};
-use cumulus_primitives_core::{relay_chain::Header as RelayHeader, BlockT};
+use cumulus_primitives_core::{relay_chain::Header as RelayHeader, BlockT, RelayStateKeysApi};
 use cumulus_relay_chain_interface::{OverseerHandle, RelayChainInterface};
 use polkadot_node_subsystem::messages::{CollatorProtocolMessage, RuntimeApiRequest};
 use polkadot_node_subsystem_util::runtime::ClaimQueueSnapshot;
@@ -34,7 +34,7 @@ use polkadot_primitives::{
 	DEFAULT_SCHEDULING_LOOKAHEAD,
 };
 use sc_consensus_aura::{standalone as aura_internal, AuraApi};
-use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
+use sp_api::{ApiExt, CallApiAt, CallApiAtParams, CallContext, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_core::Pair;
 use sp_keystore::KeystorePtr;
 use sp_timestamp::Timestamp;
@@ -267,6 +267,51 @@ where
 		.then(|| SlotClaim::unchecked::<P>(author_pub, para_slot, timestamp))
 }
 
+/// Returns the additional relay chain storage keys the runtime at `parent_hash` wants to be
+/// included in the relay chain state proof.
+///
+/// Returns no keys if the runtime doesn't implement [`RelayStateKeysApi`]. The runtime API is
+/// called directly, so the runtime API type of the client doesn't need to declare it.
+fn relay_state_keys<Block: BlockT, Client>(
+	client: &Client,
+	parent_hash: Block::Hash,
+) -> Vec<Vec<u8>>
+where
+	Client: CallApiAt<Block>,
+{
+	let has_api = client
+		.runtime_version_at(parent_hash)
+		.map(|version| {
+			version.has_api_with(&<dyn RelayStateKeysApi<Block>>::ID, |version| version >= 1)
+		})
+		.unwrap_or(false);
+	if !has_api {
+		return Vec::new()
+	}
+
+	client
+		.call_api_at(CallApiAtParams {
+			at: parent_hash,
+			function: "RelayStateKeysApi_relay_state_keys",
+			arguments: Vec::new(),
+			overlayed_changes: &Default::default(),
+			call_context: CallContext::Offchain,
+			recorder: &None,
+			extensions: &Default::default(),
+		})
+		.map_err(|error| error.to_string())
+		.and_then(|keys| Vec::<Vec<u8>>::decode(&mut &keys[..]).map_err(|error| error.to_string()))
+		.unwrap_or_else(|error| {
+			tracing::warn!(
+				target: crate::LOG_TARGET,
+				?parent_hash,
+				%error,
+				"Failed to fetch the relay state keys requested by the runtime",
+			);
+			Vec::new()
+		})
+}
+
 /// Use [`cumulus_client_consensus_common::find_potential_parents`] to find parachain blocks that
 /// we can build on. Once a list of potential parents is retrieved, return the last one of the
 /// longest chain.
@@ -21,7 +21,7 @@ use super::CollatorMessage;
 use crate::{
 	collator::{self as collator_util, BuildBlockAndImportParams},
 	collators::{
-		check_validation_code_or_log,
+		check_validation_code_or_log, relay_state_keys,
 		slot_based::{
 			relay_chain_data_cache::{RelayChainData, RelayChainDataCache},
 			slot_timer::{SlotInfo, SlotTimer},
@@ -46,7 +46,7 @@ use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf, UsageProvider};
 use sc_consensus::BlockImport;
 use sc_consensus_aura::SlotDuration;
 use sc_network_types::PeerId;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{CallApiAt, ProvideRuntimeApi};
 use sp_application_crypto::AppPublic;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::Environment;
@@ -119,6 +119,7 @@ pub fn run_block_builder<Block, P, BI, CIDP, Client, Backend, RelayClient, CHP,
 where
 	Block: BlockT,
 	Client: ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>
 		+ UsageProvider<Block>
 		+ BlockOf
 		+ AuxStore
@@ -367,6 +368,7 @@ where
 					parent_hash,
 					slot_claim.timestamp(),
 					Some(rp_data),
+					relay_state_keys(&*para_client, parent_hash),
 					collator_peer_id,
 				)
 				.await
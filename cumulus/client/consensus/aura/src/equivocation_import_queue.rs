@@ -73,11 +73,22 @@ impl<N: std::hash::Hash + PartialEq> NaiveEquivocationDefender<N> {
 	}
 }
 
+/// Handler for collators exceeding the equivocation limit of a slot.
+///
+/// This can be used to report the author to the runtime, e.g. through
+/// `pallet_collator_selection::Call::report_equivocation`.
+pub trait EquivocationHandler<Block: BlockT, AuthorityId>: Send + Sync {
+	/// Called for every block of `author` at `slot` rejected due to excessive equivocations.
+	/// `parent_hash` is the parent of the rejected block.
+	fn on_equivocation(&self, parent_hash: Block::Hash, slot: Slot, author: AuthorityId);
+}
+
 /// A parachain block import verifier that checks for equivocation limits within each slot.
 pub struct Verifier<P: Pair, Client, Block: BlockT, CIDP> {
 	client: Arc<Client>,
 	create_inherent_data_providers: CIDP,
 	defender: Mutex<NaiveEquivocationDefender<NumberFor<Block>>>,
+	equivocation_handler: Option<Arc<dyn EquivocationHandler<Block, P::Public>>>,
 	telemetry: Option<TelemetryHandle>,
 	// Unused for now. Will be plugged in with a later PR.
 	_authorities_tracker: AuthoritiesTracker<P, Block, Client>,
@@ -105,10 +116,20 @@ where
 			client: client.clone(),
 			create_inherent_data_providers: inherent_data_provider,
 			defender: Mutex::new(NaiveEquivocationDefender::default()),
+			equivocation_handler: None,
 			telemetry,
 			_authorities_tracker: AuthoritiesTracker::new(client),
 		}
 	}
+
+	/// Notify `handler` about the authors of blocks rejected due to excessive equivocations.
+	pub fn with_equivocation_handler(
+		mut self,
+		handler: Arc<dyn EquivocationHandler<Block, P::Public>>,
+	) -> Self {
+		self.equivocation_handler = Some(handler);
+		self
+	}
 }
 
 #[async_trait::async_trait]
@@ -198,6 +219,13 @@ where
 						relay_parent,
 					) && !matches!(block_params.origin, BlockOrigin::ConsensusBroadcast)
 					{
+						if let (Some(handler), Some(author)) = (
+							&self.equivocation_handler,
+							aura_internal::slot_author::<P>(slot, &authorities),
+						) {
+							handler.on_equivocation(parent_hash, slot, author.clone());
+						}
+
 						return Err(format!(
 							"Rejecting block {:?} due to excessive equivocations at slot",
 							post_hash,
@@ -294,6 +322,7 @@ where
 		client: client.clone(),
 		create_inherent_data_providers,
 		defender: Mutex::new(NaiveEquivocationDefender::default()),
+		equivocation_handler: None,
 		telemetry,
 		_authorities_tracker: AuthoritiesTracker::new(client.clone()),
 	};
@@ -317,11 +346,26 @@ mod test {
 	use sp_tracing::try_init_simple;
 	use std::{collections::HashSet, sync::Arc};
 
+	#[derive(Default)]
+	struct RecordingHandler(Mutex<Vec<(Slot, sr25519::AuthorityId)>>);
+
+	impl EquivocationHandler<Block, sr25519::AuthorityId> for RecordingHandler {
+		fn on_equivocation(
+			&self,
+			_: <Block as BlockT>::Hash,
+			slot: Slot,
+			author: sr25519::AuthorityId,
+		) {
+			self.0.lock().push((slot, author));
+		}
+	}
+
 	#[test]
 	fn import_equivocated_blocks_from_recovery() {
 		try_init_simple();
 
 		let client = Arc::new(TestClientBuilder::default().build());
+		let handler = Arc::new(RecordingHandler::default());
 
 		let verifier = Verifier::<sr25519::AuthorityPair, Client, Block, _> {
 			client: client.clone(),
@@ -329,6 +373,7 @@ mod test {
 				Ok(sp_timestamp::InherentDataProvider::from_system_time())
 			},
 			defender: Mutex::new(NaiveEquivocationDefender::default()),
+			equivocation_handler: Some(handler.clone()),
 			telemetry: None,
 			_authorities_tracker: AuthoritiesTracker::new(client.clone()),
 		};
@@ -388,5 +433,10 @@ mod test {
 			params.body = Some(block.extrinsics().to_vec());
 			assert!(verifier.verify(params).now_or_never().unwrap().is_ok());
 		});
+
+		// The author is reported for both rejected blocks, but not for the accepted ones.
+		let reported = handler.0.lock().clone();
+		assert_eq!(reported.len(), 2);
+		assert_eq!(reported[0], reported[1]);
 	}
 }
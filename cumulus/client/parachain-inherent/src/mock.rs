@@ -74,6 +74,11 @@ pub struct MockValidationDataInherentDataProvider<R = ()> {
 	pub additional_key_values: Option<Vec<(Vec<u8>, Vec<u8>)>>,
 	/// Whether upgrade go ahead should be set.
 	pub upgrade_go_ahead: Option<UpgradeGoAhead>,
+	/// The relay chain slot to put into the relay state proof.
+	///
+	/// Defaults to the mocked relay block number, which only matches the parachain slot if the
+	/// chain started from genesis.
+	pub relay_slot: Option<Slot>,
 }
 
 /// Something that can generate randomness.
@@ -175,7 +180,8 @@ impl<R: Send + Sync + GenerateRandomness<u64>> InherentDataProvider
 		// Calculate the mocked relay block based on the current para block
 		let relay_parent_number =
 			self.relay_offset + self.relay_blocks_per_para_block * self.current_para_block;
-		sproof_builder.current_slot = Slot::from(relay_parent_number as u64);
+		sproof_builder.current_slot =
+			self.relay_slot.unwrap_or_else(|| Slot::from(relay_parent_number as u64));
 
 		sproof_builder.upgrade_go_ahead = self.upgrade_go_ahead;
 		// Process the downward messages and set up the correct head
@@ -24,7 +24,10 @@ use futures::{channel::oneshot, stream::FuturesUnordered, Future, FutureExt, Str
 
 use std::{pin::Pin, sync::Arc};
 
-use crate::RecoveryHandle;
+use crate::{
+	fallback::{fetch_from_fallback_sources, PoVFallbackSource},
+	RecoveryHandle,
+};
 
 /// The active candidate recovery.
 ///
@@ -34,11 +37,22 @@ pub(crate) struct ActiveCandidateRecovery<Block: BlockT> {
 	recoveries:
 		FuturesUnordered<Pin<Box<dyn Future<Output = (Block::Hash, Option<Arc<PoV>>)> + Send>>>,
 	recovery_handle: Box<dyn RecoveryHandle>,
+	/// The sources queried when the availability recovery fails.
+	fallback_sources: Arc<Vec<Arc<dyn PoVFallbackSource<Block>>>>,
 }
 
 impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 	pub fn new(recovery_handle: Box<dyn RecoveryHandle>) -> Self {
-		Self { recoveries: Default::default(), recovery_handle }
+		Self {
+			recoveries: Default::default(),
+			recovery_handle,
+			fallback_sources: Default::default(),
+		}
+	}
+
+	/// Set the sources queried when the availability recovery fails.
+	pub fn set_fallback_sources(&mut self, sources: Vec<Arc<dyn PoVFallbackSource<Block>>>) {
+		self.fallback_sources = Arc::new(sources);
 	}
 
 	/// Recover the given `candidate`.
@@ -48,6 +62,8 @@ impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 		candidate: &crate::Candidate<Block>,
 	) {
 		let (tx, rx) = oneshot::channel();
+		let pov_hash = candidate.receipt.descriptor.pov_hash();
+		let fallback_sources = self.fallback_sources.clone();
 
 		self.recovery_handle
 			.send_recovery_msg(
@@ -65,7 +81,7 @@ impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 		self.recoveries.push(
 			async move {
 				match rx.await {
-					Ok(Ok(res)) => (block_hash, Some(res.pov)),
+					Ok(Ok(res)) => return (block_hash, Some(res.pov)),
 					Ok(Err(error)) => {
 						tracing::debug!(
 							target: crate::LOG_TARGET,
@@ -73,16 +89,19 @@ impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 							?block_hash,
 							"Availability recovery failed",
 						);
-						(block_hash, None)
 					},
 					Err(_) => {
 						tracing::debug!(
 							target: crate::LOG_TARGET,
 							"Availability recovery oneshot channel closed",
 						);
-						(block_hash, None)
 					},
 				}
+
+				let pov = fetch_from_fallback_sources(&fallback_sources, block_hash, pov_hash)
+					.await
+					.map(Arc::new);
+				(block_hash, pov)
 			}
 			.boxed(),
 		);
@@ -90,7 +109,8 @@ impl<Block: BlockT> ActiveCandidateRecovery<Block> {
 
 	/// Waits for the next recovery.
 	///
-	/// If the returned [`PoV`] is `None`, it means that the recovery failed.
+	/// If the returned [`PoV`] is `None`, it means that the recovery failed, also from all fallback
+	/// sources.
 	pub async fn wait_for_recovery(&mut self) -> (Block::Hash, Option<Arc<PoV>>) {
 		loop {
 			if let Some(res) = self.recoveries.next().await {
@@ -0,0 +1,162 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sources of PoVs besides the availability recovery of the relay chain.
+
+use codec::Decode;
+use polkadot_node_primitives::PoV;
+use polkadot_primitives::Hash as PHash;
+use sp_runtime::traits::Block as BlockT;
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex},
+};
+
+/// A source of PoVs that is queried when a PoV can not be recovered from the relay chain.
+///
+/// This happens for example when the relay chain already pruned the erasure chunks of the
+/// candidate. A PoV returned by a fallback source is only used if it matches the `pov_hash` of the
+/// candidate, so fallback sources don't need to be trusted.
+#[async_trait::async_trait]
+pub trait PoVFallbackSource<Block: BlockT>: Send + Sync {
+	/// Fetch the PoV with the given `pov_hash` of the candidate for the block `block_hash`.
+	async fn fetch_pov(&self, block_hash: Block::Hash, pov_hash: PHash) -> Option<PoV>;
+}
+
+/// Fetch a PoV from the given `sources`, in order.
+///
+/// Returns the first PoV that matches `pov_hash`.
+pub(crate) async fn fetch_from_fallback_sources<Block: BlockT>(
+	sources: &[Arc<dyn PoVFallbackSource<Block>>],
+	block_hash: Block::Hash,
+	pov_hash: PHash,
+) -> Option<PoV> {
+	for source in sources {
+		match source.fetch_pov(block_hash, pov_hash).await {
+			Some(pov) if pov.hash() == pov_hash => {
+				tracing::debug!(
+					target: crate::LOG_TARGET,
+					?block_hash,
+					"Fetched PoV from fallback source",
+				);
+				return Some(pov)
+			},
+			Some(pov) => {
+				tracing::debug!(
+					target: crate::LOG_TARGET,
+					?block_hash,
+					expected_pov_hash = ?pov_hash,
+					pov_hash = ?pov.hash(),
+					"Fallback source returned a PoV not matching the candidate",
+				);
+			},
+			None => (),
+		}
+	}
+
+	None
+}
+
+/// Reads PoVs from a local directory.
+///
+/// The directory has the layout written by collators exporting their PoVs: one file per block,
+/// named `<block_hash>_<block_number>.pov` and starting with the encoded [`PoV`].
+///
+/// The files are indexed by block hash. The index is only updated when a PoV is not found in it,
+/// so the directory is not listed for every request.
+pub struct ArchivePoVSource {
+	path: PathBuf,
+	/// The PoV files by the block hash in their name.
+	index: Arc<Mutex<HashMap<String, PathBuf>>>,
+}
+
+impl ArchivePoVSource {
+	/// Create a new instance reading PoVs from the directory at `path`.
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self { path: path.into(), index: Default::default() }
+	}
+}
+
+/// Adds the PoV files in the directory at `path` to the `index`.
+fn update_index(path: &Path, index: &mut HashMap<String, PathBuf>) {
+	let entries = match std::fs::read_dir(path) {
+		Ok(entries) => entries,
+		Err(error) => {
+			tracing::debug!(
+				target: crate::LOG_TARGET,
+				%error,
+				path = %path.display(),
+				"Failed to read PoV archive",
+			);
+			return
+		},
+	};
+
+	for entry in entries.filter_map(Result::ok) {
+		let file_name = entry.file_name();
+		if let Some((block_hash, _)) = file_name.to_string_lossy().split_once('_') {
+			index.entry(block_hash.to_owned()).or_insert_with(|| entry.path());
+		}
+	}
+}
+
+/// Reads the PoV of the block `block_hash` from the archive at `path`.
+fn read_pov(
+	path: &Path,
+	index: &Mutex<HashMap<String, PathBuf>>,
+	block_hash: String,
+) -> Option<PoV> {
+	let pov_path = {
+		let mut index = index.lock().expect("poisoned lock");
+		if !index.contains_key(&block_hash) {
+			update_index(path, &mut index);
+		}
+		index.get(&block_hash)?.clone()
+	};
+
+	let pov = std::fs::read(&pov_path)
+		.map_err(|error| error.to_string())
+		.and_then(|data| PoV::decode(&mut &data[..]).map_err(|error| error.to_string()));
+	match pov {
+		Ok(pov) => Some(pov),
+		Err(error) => {
+			tracing::debug!(
+				target: crate::LOG_TARGET,
+				%error,
+				path = %pov_path.display(),
+				"Failed to read PoV from archive",
+			);
+			None
+		},
+	}
+}
+
+#[async_trait::async_trait]
+impl<Block: BlockT> PoVFallbackSource<Block> for ArchivePoVSource {
+	async fn fetch_pov(&self, block_hash: Block::Hash, _pov_hash: PHash) -> Option<PoV> {
+		let path = self.path.clone();
+		let index = self.index.clone();
+		let block_hash = format!("{block_hash:?}");
+
+		tokio::task::spawn_blocking(move || read_pov(&path, &index, block_hash))
+			.await
+			.ok()
+			.flatten()
+	}
+}
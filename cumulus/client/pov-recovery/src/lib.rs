@@ -37,7 +37,10 @@
 //! 2. If between starting and firing the timer the block is imported, we skip the recovery of the
 //!    PoV.
 //!
-//! 3. If the timer fired we recover the PoV using the relay chain PoV recovery protocol.
+//! 3. If the timer fired we recover the PoV using the relay chain PoV recovery protocol. If the
+//!    recovery fails, e.g. because the relay chain already pruned the PoV, the configured
+//!    [`PoVFallbackSource`]s are queried. A PoV from a fallback source is only accepted if it
+//!    matches the PoV hash of the candidate.
 //!
 //! 4a. After it is recovered, we restore the block and import it.
 //!
@@ -84,6 +87,15 @@ mod tests;
 mod active_candidate_recovery;
 use active_candidate_recovery::ActiveCandidateRecovery;
 
+mod fallback;
+pub use fallback::{ArchivePoVSource, PoVFallbackSource};
+
+mod request_response;
+pub use request_response::{
+	pov_request_protocol_name, pov_request_response_config, NetworkPoVSource, PoVRequest,
+	PoVRequestHandler,
+};
+
 const LOG_TARGET: &str = "cumulus-pov-recovery";
 
 /// Test-friendly wrapper trait for the overseer handle.
@@ -269,6 +281,17 @@ where
 		}
 	}
 
+	/// Query the given `sources` for PoVs that can not be recovered from the relay chain.
+	///
+	/// The sources are queried in order.
+	pub fn with_fallback_sources(
+		mut self,
+		sources: Vec<Arc<dyn PoVFallbackSource<Block>>>,
+	) -> Self {
+		self.active_candidate_recovery.set_fallback_sources(sources);
+		self
+	}
+
 	/// Handle a new pending candidate.
 	fn handle_pending_candidate(
 		&mut self,
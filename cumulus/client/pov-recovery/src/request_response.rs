@@ -0,0 +1,287 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+//! Request-response protocol to fetch PoVs from other parachain nodes.
+//!
+//! The request is a SCALE encoded [`PoVRequest`] and the response the SCALE encoded
+//! `Option<PoV>`.
+
+use crate::{
+	fallback::{fetch_from_fallback_sources, PoVFallbackSource},
+	LOG_TARGET,
+};
+
+use codec::{Decode, DecodeAll, Encode};
+use polkadot_node_primitives::PoV;
+use polkadot_primitives::{Hash as PHash, MAX_POV_SIZE};
+use rand::{seq::SliceRandom, thread_rng};
+use sc_network::{
+	config::OutgoingResponse,
+	request_responses::{IfDisconnected, IncomingRequest},
+	service::traits::{NetworkBackend, NetworkRequest, NetworkService},
+	PeerId, ProtocolName,
+};
+use sc_network_sync::SyncingService;
+use schnellru::{ByLength, LruMap};
+use sp_runtime::traits::Block as BlockT;
+
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+/// Expected maximum number of simultaneous requests from remote peers.
+const INBOUND_CHANNEL_SIZE: usize = 16;
+/// Maximum request size. Enough to fit an encoded [`PoVRequest`].
+const MAX_REQUEST_SIZE: u64 = 1024;
+/// Maximum response size. Enough to fit an encoded PoV of the maximum size.
+const MAX_RESPONSE_SIZE: u64 = MAX_POV_SIZE as u64 + 1024;
+/// Request-response protocol timeout.
+const TIMEOUT: Duration = Duration::from_secs(20);
+/// Maximum number of peers asked for a PoV before giving up.
+const MAX_PEERS_TO_ASK: usize = 5;
+/// Maximum number of requests served per peer within [`RATE_LIMIT_PERIOD`].
+pub(crate) const MAX_REQUESTS_PER_PEER: u32 = 4;
+/// The period in which a peer can send up to [`MAX_REQUESTS_PER_PEER`] requests.
+const RATE_LIMIT_PERIOD: Duration = Duration::from_secs(6);
+/// Number of peers whose requests are tracked for rate limiting.
+const MAX_TRACKED_PEERS: u32 = 1024;
+
+mod rep {
+	use sc_network::ReputationChange as Rep;
+
+	/// Reputation change when a peer sent a request that can't be decoded.
+	pub const INVALID_REQUEST: Rep = Rep::new(-(1 << 12), "Invalid PoV request");
+
+	/// Reputation change when a peer sent more requests than allowed.
+	pub const TOO_MANY_REQUESTS: Rep = Rep::new(-(1 << 10), "Too many PoV requests");
+}
+
+/// A request for a PoV.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct PoVRequest<Hash> {
+	/// The hash of the block the PoV is requested for.
+	pub block_hash: Hash,
+	/// The hash of the requested PoV.
+	pub pov_hash: PHash,
+}
+
+/// PoV request-response protocol name given a genesis hash and fork id.
+pub fn pov_request_protocol_name<Hash: AsRef<[u8]>>(
+	genesis_hash: Hash,
+	fork_id: Option<&str>,
+) -> ProtocolName {
+	let genesis_hash = genesis_hash.as_ref();
+	if let Some(fork_id) = fork_id {
+		format!("/{}/{}/pov/1", array_bytes::bytes2hex("", genesis_hash), fork_id)
+	} else {
+		format!("/{}/pov/1", array_bytes::bytes2hex("", genesis_hash))
+	}
+	.into()
+}
+
+/// PoV request-response protocol config.
+pub fn pov_request_response_config<
+	Hash: AsRef<[u8]>,
+	B: BlockT,
+	N: NetworkBackend<B, <B as BlockT>::Hash>,
+>(
+	genesis_hash: Hash,
+	fork_id: Option<&str>,
+) -> (N::RequestResponseProtocolConfig, async_channel::Receiver<IncomingRequest>) {
+	let (inbound_tx, inbound_rx) = async_channel::bounded(INBOUND_CHANNEL_SIZE);
+
+	let config = N::request_response_config(
+		pov_request_protocol_name(genesis_hash, fork_id),
+		Vec::new(),
+		MAX_REQUEST_SIZE,
+		MAX_RESPONSE_SIZE,
+		TIMEOUT,
+		Some(inbound_tx),
+	);
+
+	(config, inbound_rx)
+}
+
+/// Serves PoV requests of other nodes from local PoV sources.
+///
+/// Each peer is served up to [`MAX_REQUESTS_PER_PEER`] requests per [`RATE_LIMIT_PERIOD`],
+/// further requests are refused and reduce the reputation of the peer.
+pub struct PoVRequestHandler<Block: BlockT> {
+	request_receiver: async_channel::Receiver<IncomingRequest>,
+	sources: Vec<Arc<dyn PoVFallbackSource<Block>>>,
+	/// The start of the current rate limit period and the number of requests served in it, by
+	/// peer.
+	served_requests: LruMap<PeerId, (Instant, u32)>,
+}
+
+impl<Block: BlockT> PoVRequestHandler<Block> {
+	/// Create a new instance answering requests with PoVs from the given `sources`.
+	pub fn new(
+		request_receiver: async_channel::Receiver<IncomingRequest>,
+		sources: Vec<Arc<dyn PoVFallbackSource<Block>>>,
+	) -> Self {
+		Self {
+			request_receiver,
+			sources,
+			served_requests: LruMap::new(ByLength::new(MAX_TRACKED_PEERS)),
+		}
+	}
+
+	/// Run the request handler.
+	pub async fn run(mut self) {
+		while let Ok(request) = self.request_receiver.recv().await {
+			self.handle_request(request).await;
+		}
+
+		tracing::debug!(target: LOG_TARGET, "PoV request stream ended");
+	}
+
+	/// Counts a request of `peer` at `now`, returning `false` if the peer exceeded its rate limit.
+	fn note_request(&mut self, peer: PeerId, now: Instant) -> bool {
+		let Some((period_start, requests)) = self.served_requests.get_or_insert(peer, || (now, 0))
+		else {
+			return true
+		};
+
+		if now.duration_since(*period_start) >= RATE_LIMIT_PERIOD {
+			*period_start = now;
+			*requests = 0;
+		}
+
+		*requests += 1;
+		*requests <= MAX_REQUESTS_PER_PEER
+	}
+
+	async fn handle_request(&mut self, request: IncomingRequest) {
+		let IncomingRequest { peer, payload, pending_response } = request;
+
+		let (result, reputation_changes) = if !self.note_request(peer, Instant::now()) {
+			tracing::debug!(target: LOG_TARGET, ?peer, "Refusing PoV request, rate limit exceeded");
+			(Err(()), vec![rep::TOO_MANY_REQUESTS])
+		} else {
+			match PoVRequest::<Block::Hash>::decode_all(&mut &payload[..]) {
+				Ok(PoVRequest { block_hash, pov_hash }) => {
+					let pov =
+						fetch_from_fallback_sources(&self.sources, block_hash, pov_hash).await;
+
+					tracing::trace!(
+						target: LOG_TARGET,
+						?peer,
+						?block_hash,
+						found = pov.is_some(),
+						"Serving PoV request",
+					);
+
+					(Ok(pov.encode()), Vec::new())
+				},
+				Err(error) => {
+					tracing::debug!(
+						target: LOG_TARGET,
+						?peer,
+						?error,
+						"Failed to decode PoV request",
+					);
+					(Err(()), vec![rep::INVALID_REQUEST])
+				},
+			}
+		};
+
+		let _ = pending_response.send(OutgoingResponse {
+			result,
+			reputation_changes,
+			sent_feedback: None,
+		});
+	}
+}
+
+/// Fetches PoVs from the connected parachain nodes over the PoV request-response protocol.
+pub struct NetworkPoVSource<Block: BlockT> {
+	network: Arc<dyn NetworkService>,
+	sync_service: Arc<SyncingService<Block>>,
+	protocol_name: ProtocolName,
+}
+
+impl<Block: BlockT> NetworkPoVSource<Block> {
+	/// Create a new instance.
+	///
+	/// `protocol_name` is the name returned by [`pov_request_protocol_name`].
+	pub fn new(
+		network: Arc<dyn NetworkService>,
+		sync_service: Arc<SyncingService<Block>>,
+		protocol_name: ProtocolName,
+	) -> Self {
+		Self { network, sync_service, protocol_name }
+	}
+}
+
+#[async_trait::async_trait]
+impl<Block: BlockT> PoVFallbackSource<Block> for NetworkPoVSource<Block> {
+	async fn fetch_pov(&self, block_hash: Block::Hash, pov_hash: PHash) -> Option<PoV> {
+		let mut peers = match self.sync_service.peers_info().await {
+			Ok(peers) => peers.into_iter().map(|(peer, _)| peer).collect::<Vec<_>>(),
+			Err(_) => {
+				tracing::debug!(target: LOG_TARGET, "Failed to retrieve the connected peers");
+				return None
+			},
+		};
+		peers.shuffle(&mut thread_rng());
+
+		let request = PoVRequest { block_hash, pov_hash }.encode();
+
+		for peer in peers.into_iter().take(MAX_PEERS_TO_ASK) {
+			let response = self
+				.network
+				.request(
+					peer,
+					self.protocol_name.clone(),
+					request.clone(),
+					None,
+					IfDisconnected::ImmediateError,
+				)
+				.await;
+
+			match response.map(|(response, _)| Option::<PoV>::decode_all(&mut &response[..])) {
+				Ok(Ok(Some(pov))) if pov.hash() == pov_hash => return Some(pov),
+				Ok(Ok(Some(_))) => {
+					tracing::debug!(
+						target: LOG_TARGET,
+						?peer,
+						?block_hash,
+						"Peer returned a PoV not matching the candidate",
+					);
+				},
+				Ok(Ok(None)) => {
+					tracing::trace!(target: LOG_TARGET, ?peer, ?block_hash, "Peer doesn't have PoV");
+				},
+				Ok(Err(error)) => {
+					tracing::debug!(
+						target: LOG_TARGET,
+						?peer,
+						?error,
+						"Failed to decode PoV response",
+					);
+				},
+				Err(error) => {
+					tracing::trace!(target: LOG_TARGET, ?peer, ?error, "PoV request failed");
+				},
+			}
+		}
+
+		None
+	}
+}
@@ -39,6 +39,7 @@ use sc_client_api::{
 	FinalizeSummary, ImportNotifications, StorageEventStream, StorageKey,
 };
 use sc_consensus::import_queue::RuntimeOrigin;
+use sc_network::{request_responses::IncomingRequest, PeerId};
 use sc_utils::mpsc::{TracingUnboundedReceiver, TracingUnboundedSender};
 use sp_api::RuntimeApiInfo;
 use sp_blockchain::Info;
@@ -1542,3 +1543,216 @@ async fn recovery_multiple_blocks_per_candidate() {
 	// No more import requests received
 	assert_matches!(import_requests_rx.next().timeout(Duration::from_millis(100)).await, None);
 }
+
+struct MockFallbackSource {
+	pov: PoV,
+	requests: Arc<Mutex<Vec<(PHash, PHash)>>>,
+}
+
+#[async_trait::async_trait]
+impl PoVFallbackSource<Block> for MockFallbackSource {
+	async fn fetch_pov(&self, block_hash: PHash, pov_hash: PHash) -> Option<PoV> {
+		self.requests.lock().unwrap().push((block_hash, pov_hash));
+		Some(self.pov.clone())
+	}
+}
+
+#[rstest]
+#[tokio::test]
+async fn single_pending_candidate_recovery_from_fallback_source(
+	#[values(true, false)] matching_pov: bool,
+) {
+	sp_tracing::init_for_tests();
+
+	let (recovery_subsystem_tx, mut recovery_subsystem_rx) =
+		AvailabilityRecoverySubsystemHandle::new();
+	let recovery_delay_range =
+		RecoveryDelayRange { min: Duration::from_millis(0), max: Duration::from_millis(10) };
+	let (_explicit_recovery_chan_tx, explicit_recovery_chan_rx) = mpsc::channel(10);
+	let mut candidates = make_candidate_chain(1..2);
+	let header = Header::decode(&mut &candidates[0].commitments.head_data.0[..]).unwrap();
+
+	let pov = PoV {
+		block_data: ParachainBlockData::<Block>::new(
+			vec![Block::new(header.clone(), vec![])],
+			CompactProof { encoded_nodes: vec![] },
+		)
+		.encode()
+		.into(),
+	};
+	let pov_hash = pov.hash();
+	candidates[0].descriptor = CandidateDescriptorV2::new(
+		ParaId::from(1000),
+		PHash::zero(),
+		CoreIndex(0),
+		0,
+		PHash::zero(),
+		pov_hash,
+		PHash::zero(),
+		PHash::zero(),
+		PHash::zero().into(),
+	);
+	let candidate_hash = candidates[0].hash();
+
+	let relay_chain_client = Relaychain::new(vec![(
+		PHeader {
+			parent_hash: PHash::from_low_u64_be(0),
+			number: 1,
+			state_root: PHash::random(),
+			extrinsics_root: PHash::random(),
+			digest: Default::default(),
+		},
+		candidates,
+	)]);
+	let mut known_blocks = HashMap::new();
+	known_blocks.insert(GENESIS_HASH, BlockStatus::InChainWithState);
+	let (parachain_client, _import_notifications_tx, _finality_notifications_tx) =
+		ParachainClient::new(vec![dummy_usage_info(0)], Arc::new(Mutex::new(known_blocks)));
+	let (parachain_import_queue, mut import_requests_rx) = ParachainImportQueue::new();
+
+	let fallback_requests = Arc::new(Mutex::new(Vec::new()));
+	let fallback_source = MockFallbackSource {
+		pov: if matching_pov { pov } else { PoV { block_data: vec![1, 2, 3].into() } },
+		requests: fallback_requests.clone(),
+	};
+
+	let pov_recovery = PoVRecovery::<Block, _, _>::new(
+		Box::new(recovery_subsystem_tx),
+		recovery_delay_range,
+		Arc::new(parachain_client),
+		Box::new(parachain_import_queue),
+		relay_chain_client,
+		ParaId::new(1000),
+		explicit_recovery_chan_rx,
+		Arc::new(DummySyncOracle::default()),
+	)
+	.with_fallback_sources(vec![Arc::new(fallback_source)]);
+
+	task::spawn(pov_recovery.run());
+
+	// Availability recovery fails, the PoV was already pruned by the relay chain.
+	assert_matches!(
+		recovery_subsystem_rx.next().await,
+		Some(AvailabilityRecoveryMessage::RecoverAvailableData(
+			receipt,
+			session_index,
+			None,
+			None,
+			response_tx
+		)) => {
+			assert_eq!(receipt.hash(), candidate_hash);
+			assert_eq!(session_index, TEST_SESSION_INDEX);
+			response_tx.send(
+				Err(RecoveryError::Unavailable)
+			).unwrap()
+		}
+	);
+
+	if matching_pov {
+		// Received import request for the candidate recovered from the fallback source.
+		assert_matches!(import_requests_rx.next().await, Some(incoming_blocks) => {
+			assert_eq!(incoming_blocks.len(), 1);
+			assert_eq!(incoming_blocks[0].header, Some(header.clone()));
+		});
+		assert_eq!(*fallback_requests.lock().unwrap(), vec![(header.hash(), pov_hash)]);
+
+		// No more recovery messages received.
+		assert_matches!(
+			recovery_subsystem_rx.next().timeout(Duration::from_millis(100)).await,
+			None
+		);
+	} else {
+		// The PoV not matching the candidate is rejected and the recovery is retried.
+		let _response_tx = assert_matches!(
+			recovery_subsystem_rx.next().await,
+			Some(AvailabilityRecoveryMessage::RecoverAvailableData(
+				receipt,
+				_,
+				None,
+				None,
+				response_tx
+			)) => {
+				assert_eq!(receipt.hash(), candidate_hash);
+				response_tx
+			}
+		);
+		assert_eq!(fallback_requests.lock().unwrap().len(), 1);
+	}
+
+	// No import request received
+	assert_matches!(import_requests_rx.next().timeout(Duration::from_millis(100)).await, None);
+}
+
+#[tokio::test]
+async fn pov_request_handler_limits_requests_per_peer() {
+	let pov = PoV { block_data: vec![1, 2, 3].into() };
+	let source = MockFallbackSource { pov: pov.clone(), requests: Default::default() };
+	let (request_tx, request_rx) = async_channel::bounded(16);
+	task::spawn(PoVRequestHandler::<Block>::new(request_rx, vec![Arc::new(source)]).run());
+
+	let send_request = |peer: PeerId, payload: Vec<u8>| {
+		let request_tx = request_tx.clone();
+		async move {
+			let (pending_response, response) = futures::channel::oneshot::channel();
+			request_tx
+				.send(IncomingRequest { peer, payload, pending_response })
+				.await
+				.unwrap();
+			response.await.unwrap()
+		}
+	};
+	let request = PoVRequest { block_hash: GENESIS_HASH, pov_hash: pov.hash() }.encode();
+
+	let peer = PeerId::random();
+	for _ in 0..request_response::MAX_REQUESTS_PER_PEER {
+		let response = send_request(peer, request.clone()).await;
+		assert_eq!(response.result, Ok(Some(pov.clone()).encode()));
+		assert!(response.reputation_changes.is_empty());
+	}
+
+	// Further requests of the peer are refused.
+	let response = send_request(peer, request.clone()).await;
+	assert_eq!(response.result, Err(()));
+	assert_eq!(response.reputation_changes.len(), 1);
+	assert!(response.reputation_changes[0].value < 0);
+
+	// Other peers are still served.
+	let other_peer = PeerId::random();
+	let response = send_request(other_peer, request.clone()).await;
+	assert_eq!(response.result, Ok(Some(pov.clone()).encode()));
+
+	// Invalid requests reduce the reputation of the peer.
+	let response = send_request(other_peer, vec![1, 2, 3]).await;
+	assert_eq!(response.result, Err(()));
+	assert_eq!(response.reputation_changes.len(), 1);
+	assert!(response.reputation_changes[0].value < 0);
+}
+
+#[tokio::test]
+async fn archive_pov_source_reads_pov_files() {
+	let archive = tempfile::tempdir().unwrap();
+	let source = ArchivePoVSource::new(archive.path());
+	let pov = PoV { block_data: vec![1, 2, 3].into() };
+	let write_pov = |block_hash: PHash| {
+		std::fs::write(archive.path().join(format!("{block_hash:?}_1.pov")), pov.encode()).unwrap()
+	};
+
+	let block_hash = PHash::repeat_byte(1);
+	write_pov(block_hash);
+	assert_eq!(
+		PoVFallbackSource::<Block>::fetch_pov(&source, block_hash, pov.hash()).await,
+		Some(pov.clone())
+	);
+
+	// PoVs added to the archive later are found as well.
+	let later_block_hash = PHash::repeat_byte(2);
+	assert_eq!(
+		PoVFallbackSource::<Block>::fetch_pov(&source, later_block_hash, pov.hash()).await,
+		None
+	);
+	write_pov(later_block_hash);
+	assert_eq!(
+		PoVFallbackSource::<Block>::fetch_pov(&source, later_block_hash, pov.hash()).await,
+		Some(pov)
+	);
+}
@@ -419,6 +419,8 @@ fn build_polkadot_full_node(
 		keep_finalized_for: None,
 		invulnerable_ah_collators: HashSet::new(),
 		collator_protocol_hold_off: None,
+		pvf_dump: None,
+		enable_dispute_rpc: false,
 	};
 
 	let (relay_chain_full_node, paranode_req_receiver) = match config.network.network_backend {
@@ -21,7 +21,9 @@
 
 use cumulus_client_cli::CollatorOptions;
 use cumulus_client_network::{AssumeSybilResistance, RequireSecondedInBlockAnnounce};
-use cumulus_client_pov_recovery::{PoVRecovery, RecoveryDelayRange, RecoveryHandle};
+use cumulus_client_pov_recovery::{
+	PoVFallbackSource, PoVRecovery, RecoveryDelayRange, RecoveryHandle,
+};
 use cumulus_primitives_core::{CollectCollationInfo, ParaId};
 pub use cumulus_primitives_proof_size_hostfunction::storage_proof_size;
 use cumulus_relay_chain_inprocess_interface::build_inprocess_relay_chain;
@@ -98,6 +100,8 @@ pub struct StartRelayChainTasksParams<'a, Block: BlockT, Client, RCInterface> {
 	pub import_queue: Box<dyn ImportQueueService<Block>>,
 	pub relay_chain_slot_duration: Duration,
 	pub recovery_handle: Box<dyn RecoveryHandle>,
+	/// Sources queried for PoVs that can not be recovered from the relay chain.
+	pub pov_fallback_sources: Vec<Arc<dyn PoVFallbackSource<Block>>>,
 	pub sync_service: Arc<SyncingService<Block>>,
 	pub prometheus_registry: Option<&'a Registry>,
 }
@@ -122,6 +126,7 @@ pub fn start_relay_chain_tasks<Block, Client, Backend, RCInterface>(
 		import_queue,
 		relay_chain_slot_duration,
 		recovery_handle,
+		pov_fallback_sources,
 		sync_service,
 		prometheus_registry,
 	}: StartRelayChainTasksParams<Block, Client, RCInterface>,
@@ -184,7 +189,8 @@ where
 		para_id,
 		recovery_chan_rx,
 		sync_service.clone(),
-	);
+	)
+	.with_fallback_sources(pov_fallback_sources);
 
 	task_manager
 		.spawn_essential_handle()
@@ -102,6 +102,24 @@ fn min_invulnerables<T: Config>() -> u32 {
 	min_collators.saturating_sub(candidates_length)
 }
 
+fn min_delegation<T: Config>() -> BalanceOf<T> {
+	T::MinDelegation::get().max(<T as pallet::Config>::Currency::minimum_balance())
+}
+
+fn delegate_to<T: Config>(candidate: &T::AccountId, count: u32) {
+	let amount = min_delegation::<T>();
+	for d in 0..count {
+		let delegator: T::AccountId = account("delegator", d, SEED);
+		<T as pallet::Config>::Currency::make_free_balance_be(&delegator, amount * 2u32.into());
+		<CollatorSelection<T>>::delegate(
+			RawOrigin::Signed(delegator).into(),
+			candidate.clone(),
+			amount,
+		)
+		.unwrap();
+	}
+}
+
 #[benchmarks(where T: pallet_authorship::Config + session_benchmarking::Config)]
 mod benchmarks {
 	use super::*;
@@ -442,5 +460,161 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn end_session(c: Linear<1, { MaxCollators::<T>::get() }>) {
+		let collators = (0..c).map(|c| account("collator", c, SEED)).collect::<Vec<T::AccountId>>();
+		for collator in collators.iter() {
+			AuthoredBlocks::<T>::insert(collator, 1);
+		}
+		SessionBlocks::<T>::put(c);
+		SessionCollators::<T>::insert(
+			0,
+			frame_support::BoundedVec::truncate_from(collators.clone()),
+		);
+
+		#[block]
+		{
+			<CollatorSelection<T> as SessionManager<_>>::end_session(0);
+		}
+
+		assert_eq!(Performance::<T>::get(&collators[0]), Some(sp_runtime::Perbill::one()));
+		assert_eq!(SessionCollators::<T>::get(0), None);
+	}
+
+	// worst case is delegating to the last candidate in the list.
+	#[benchmark]
+	fn delegate(c: Linear<1, { T::MaxCandidates::get() }>) -> Result<(), BenchmarkError> {
+		if T::MaxDelegators::get() == 0 {
+			return Err(BenchmarkError::Weightless)
+		}
+		CandidacyBond::<T>::put(<T as pallet::Config>::Currency::minimum_balance());
+		DesiredCandidates::<T>::put(c);
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let candidate = CandidateList::<T>::get().iter().last().unwrap().who.clone();
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = min_delegation::<T>();
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone(), amount);
+
+		assert_last_event::<T>(Event::Delegated { delegator: caller, candidate, amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn undelegate() -> Result<(), BenchmarkError> {
+		if T::MaxDelegators::get() == 0 {
+			return Err(BenchmarkError::Weightless)
+		}
+		CandidacyBond::<T>::put(<T as pallet::Config>::Currency::minimum_balance());
+
+		register_validators::<T>(1);
+		register_candidates::<T>(1);
+
+		let candidate = CandidateList::<T>::get()[0].who.clone();
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = min_delegation::<T>();
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+		<CollatorSelection<T>>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			candidate.clone(),
+			amount,
+		)
+		.map_err(|e| e.error)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone());
+
+		assert_last_event::<T>(Event::Undelegated { delegator: caller, candidate, amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded() -> Result<(), BenchmarkError> {
+		if T::MaxDelegators::get() == 0 {
+			return Err(BenchmarkError::Weightless)
+		}
+		CandidacyBond::<T>::put(<T as pallet::Config>::Currency::minimum_balance());
+
+		register_validators::<T>(1);
+		register_candidates::<T>(1);
+
+		let candidate = CandidateList::<T>::get()[0].who.clone();
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = min_delegation::<T>();
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, amount * 2u32.into());
+		<CollatorSelection<T>>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			candidate.clone(),
+			amount,
+		)
+		.map_err(|e| e.error)?;
+		<CollatorSelection<T>>::undelegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			candidate.clone(),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::UnbondingPeriod::get(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone());
+
+		assert_last_event::<T>(Event::Withdrawn { who: caller, candidate, amount }.into());
+		Ok(())
+	}
+
+	// worst case is slashing the last candidate in the list, with all delegator slots taken.
+	#[benchmark]
+	fn report_equivocation(
+		d: Linear<0, { T::MaxDelegators::get() }>,
+		c: Linear<{ min_candidates::<T>() + 1 }, { T::MaxCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin = T::EquivocationReportOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		CandidacyBond::<T>::put(<T as pallet::Config>::Currency::minimum_balance());
+		DesiredCandidates::<T>::put(c);
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let offender = CandidateList::<T>::get().iter().last().unwrap().who.clone();
+		delegate_to::<T>(&offender, d);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, offender.clone());
+
+		assert_last_event::<T>(Event::CandidateRemoved { account_id: offender }.into());
+		Ok(())
+	}
+
+	// worst case is the author being the last candidate in the list, with all delegator slots
+	// taken.
+	#[benchmark]
+	fn reward_delegators(
+		d: Linear<0, { T::MaxDelegators::get() }>,
+		c: Linear<1, { T::MaxCandidates::get() }>,
+	) {
+		CandidacyBond::<T>::put(<T as pallet::Config>::Currency::minimum_balance());
+		DesiredCandidates::<T>::put(c);
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let author = CandidateList::<T>::get().iter().last().unwrap().who.clone();
+		delegate_to::<T>(&author, d);
+		let reward = <T as pallet::Config>::Currency::minimum_balance() * 100u32.into();
+		let _ = <T as pallet::Config>::Currency::deposit_creating(&author, reward);
+
+		#[block]
+		{
+			<CollatorSelection<T>>::reward_delegators(&author, reward);
+		}
+	}
+
 	impl_benchmark_test_suite!(CollatorSelection, crate::mock::new_test_ext(), crate::mock::Test);
 }
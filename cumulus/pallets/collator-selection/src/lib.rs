@@ -61,10 +61,37 @@
 //! would fall below `MinEligibleCollators`. This is to ensure that some collators will always
 //! exist, i.e. someone is eligible to produce a block.
 //!
-//! When a new session starts, candidates with the highest deposits will be selected in order until
+//! When a new session starts, candidates with the highest scores will be selected in order until
 //! the desired number of collators is reached. Candidates can increase or decrease their deposits
 //! between sessions in order to ensure they receive a slot in the collator list.
 //!
+//! ### Delegation
+//!
+//! Any account can back a candidate by reserving stake through `delegate`, as long as fewer than
+//! `MaxDelegators` accounts delegate to or unbond from the candidate. The candidate's stake is its
+//! own deposit plus all stake delegated to it. Delegations can be undelegated at any time through
+//! `undelegate`, after which they stay reserved for the `UnbondingPeriod` before they can be
+//! withdrawn through `withdraw_unbonded`. Delegations to accounts which are no longer candidates
+//! stay reserved until they are undelegated and count again once the account becomes a candidate
+//! again.
+//!
+//! ### Scoring
+//!
+//! Every collator's share of the blocks it was expected to author in its last session is recorded
+//! when that session ends. When assembling the next collator set, the candidates are ranked by
+//! `CandidateScore`, which combines the stake of a candidate with that ratio. Candidates without
+//! any recorded session are assumed to perform perfectly. The default score is the stake alone,
+//! which is equivalent to ranking by deposit as long as there is no delegated stake.
+//!
+//! ### Slashing
+//!
+//! Equivocations of candidates, e.g. the ones the node's equivocation import queue hands to its
+//! `EquivocationHandler`, can be reported through `report_equivocation` by the
+//! `EquivocationReportOrigin`. The offender loses `EquivocationSlash` of its deposit and of all
+//! stake delegated to or unbonding from it, which is handed to `OnSlash`. It is removed from the
+//! candidate list unless that would leave too few eligible collators, and the rest of its deposit
+//! unbonds like an undelegated delegation.
+//!
 //! ### Rewards
 //!
 //! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
@@ -74,6 +101,9 @@
 //! - Half the value of the transaction fees within the block. The other half of the transaction
 //!   fees are deposited into the Pot.
 //!
+//! If the author has delegators, they receive a share of the reward in proportion to the stake
+//! they delegated to the author.
+//!
 //! To initiate rewards, an ED needs to be transferred to the pot address.
 //!
 //! Note: Eventually the Pot distribution may be modified as discussed in [this
@@ -84,8 +114,9 @@
 extern crate alloc;
 
 use core::marker::PhantomData;
-use frame_support::traits::TypedGet;
+use frame_support::traits::{Get, TypedGet};
 pub use pallet::*;
+use sp_runtime::{traits::UniqueSaturatedInto, PerThing, Perbill};
 
 #[cfg(test)]
 mod mock;
@@ -103,14 +134,14 @@ const LOG_TARGET: &str = "runtime::collator-selection";
 #[frame_support::pallet]
 pub mod pallet {
 	pub use crate::weights::WeightInfo;
-	use alloc::vec::Vec;
+	use alloc::{collections::btree_map::BTreeMap, vec::Vec};
 	use core::ops::Div;
 	use frame_support::{
 		dispatch::{DispatchClass, DispatchResultWithPostInfo},
 		pallet_prelude::*,
 		traits::{
-			Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, ReservableCurrency,
-			ValidatorRegistration,
+			Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, Imbalance, OnUnbalanced,
+			ReservableCurrency, ValidatorRegistration,
 		},
 		BoundedVec, DefaultNoBound, PalletId,
 	};
@@ -118,7 +149,7 @@ pub mod pallet {
 	use pallet_session::SessionManager;
 	use sp_runtime::{
 		traits::{AccountIdConversion, CheckedSub, Convert, Saturating, Zero},
-		Debug,
+		Debug, Perbill,
 	};
 	use sp_staking::SessionIndex;
 
@@ -128,6 +159,9 @@ pub mod pallet {
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 
+	type NegativeImbalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::NegativeImbalance;
+
 	/// A convertor from collators id. Since this pallet does not have stash/controller, this is
 	/// just identity.
 	pub struct IdentityCollator;
@@ -185,6 +219,37 @@ pub mod pallet {
 		/// Validate a user is registered
 		type ValidatorRegistration: ValidatorRegistration<Self::ValidatorId>;
 
+		/// Maximum number of accounts that can delegate stake to a single candidate.
+		///
+		/// Setting this to zero disables delegation.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// Minimum amount of stake that has to be delegated to a candidate.
+		#[pallet::constant]
+		type MinDelegation: Get<BalanceOf<Self>>;
+
+		/// Scores candidates by their stake and recent performance when assembling the collator
+		/// set of a new session.
+		type CandidateScore: crate::CandidateScore<Self::AccountId, BalanceOf<Self>>;
+
+		/// Origin that can report equivocations of candidates.
+		type EquivocationReportOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Fraction of the deposit and the delegated stake of a candidate that is slashed when
+		/// an equivocation of the candidate is reported.
+		#[pallet::constant]
+		type EquivocationSlash: Get<Perbill>;
+
+		/// Handler for the slashed funds.
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Number of blocks undelegated stake, and the deposit of candidates removed for an
+		/// equivocation, stay reserved before they can be withdrawn. Equivocations reported in
+		/// the meantime are still slashed from it.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -206,6 +271,38 @@ pub mod pallet {
 		pub deposit: Balance,
 	}
 
+	/// Stake delegated to a collation candidate.
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, Debug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct DelegationInfo<Balance> {
+		/// Total amount of stake delegated to the candidate.
+		pub total: Balance,
+		/// Number of accounts delegating to the candidate.
+		pub delegators: u32,
+		/// Number of accounts unbonding stake from the candidate.
+		pub unbonding: u32,
+	}
+
+	/// Stake unbonding from a collation candidate.
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, Debug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct UnbondingInfo<Balance, BlockNumber> {
+		/// Amount of stake that is unbonding.
+		pub amount: Balance,
+		/// Block from which the stake can be withdrawn.
+		pub unlock_at: BlockNumber,
+	}
+
+	/// Upper bound of the collator set size, all invulnerables and candidates.
+	pub struct MaxCollators<T>(PhantomData<T>);
+	impl<T: Config> Get<u32> for MaxCollators<T> {
+		fn get() -> u32 {
+			T::MaxInvulnerables::get().saturating_add(T::MaxCandidates::get())
+		}
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -244,6 +341,61 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Stake delegated to a candidate, by candidate and delegator.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// Total stake delegated to a candidate and the number of accounts delegating to or unbonding
+	/// from it.
+	#[pallet::storage]
+	pub type DelegatedStake<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DelegationInfo<BalanceOf<T>>, ValueQuery>;
+
+	/// Stake unbonding from a candidate, by candidate and account.
+	#[pallet::storage]
+	pub type Unbonding<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		UnbondingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The collator set assembled for a session, kept until the session ends.
+	#[pallet::storage]
+	pub type SessionCollators<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		BoundedVec<T::AccountId, MaxCollators<T>>,
+		OptionQuery,
+	>;
+
+	/// Number of blocks authored by a collator in the current session.
+	#[pallet::storage]
+	pub type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of blocks authored in the current session.
+	#[pallet::storage]
+	pub type SessionBlocks<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Share of the expected blocks a candidate authored in the last session it was a collator in.
+	///
+	/// Entries are removed together with the candidate.
+	#[pallet::storage]
+	pub type Performance<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Perbill, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -304,6 +456,14 @@ pub mod pallet {
 		/// An account was unable to be added to the Invulnerables because they did not have keys
 		/// registered. Other Invulnerables may have been set.
 		InvalidInvulnerableSkipped { account_id: T::AccountId },
+		/// Stake was delegated to a candidate.
+		Delegated { delegator: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// A delegation started unbonding.
+		Undelegated { delegator: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// Unbonded stake was withdrawn.
+		Withdrawn { who: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// A candidate was slashed for an equivocation, together with its delegators.
+		CandidateSlashed { account_id: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -342,6 +502,16 @@ pub mod pallet {
 		IdenticalDeposit,
 		/// Cannot lower candidacy bond while occupying a future collator slot in the list.
 		InvalidUnreserve,
+		/// The amount to delegate is below the minimum delegation.
+		DelegationTooLow,
+		/// The candidate has too many delegators.
+		TooManyDelegators,
+		/// Account does not delegate to the candidate.
+		NotDelegating,
+		/// Account is not unbonding stake from the candidate.
+		NotUnbonding,
+		/// The unbonding period has not passed yet.
+		StillUnbonding,
 	}
 
 	#[pallet::hooks]
@@ -791,7 +961,7 @@ pub mod pallet {
 					// Insert the new candidate in the correct spot in the list.
 					candidates
 						.try_insert(new_pos, new_info)
-						.expect("candidate count previously decremented; qed");
+						.map_err(|_| Error::<T>::InsertToCandidateListFailed)?;
 
 					Ok(target_info)
 				},
@@ -799,6 +969,7 @@ pub mod pallet {
 			T::Currency::reserve(&who, deposit)?;
 			T::Currency::unreserve(&target_info.who, target_info.deposit);
 			LastAuthoredBlock::<T>::remove(target_info.who.clone());
+			Performance::<T>::remove(&target_info.who);
 			LastAuthoredBlock::<T>::insert(
 				who.clone(),
 				frame_system::Pallet::<T>::block_number() + T::KickThreshold::get(),
@@ -807,6 +978,133 @@ pub mod pallet {
 			Self::deposit_event(Event::CandidateReplaced { old: target, new: who, deposit });
 			Ok(Some(T::WeightInfo::take_candidate_slot(length as u32)).into())
 		}
+
+		/// Delegate `amount` of stake from `origin` to the collator candidate `candidate`.
+		///
+		/// The amount is reserved and added to any stake `origin` already delegated to the
+		/// candidate. A new delegation has to be at least `MinDelegation` and is only accepted if
+		/// fewer than `MaxDelegators` accounts delegate to or unbond from the candidate.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::delegate(T::MaxCandidates::get()))]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::DelegationTooLow);
+
+			let length = CandidateList::<T>::decode_len().unwrap_or_default();
+			ensure!(
+				CandidateList::<T>::get().iter().any(|info| info.who == candidate),
+				Error::<T>::NotCandidate
+			);
+
+			let delegated = Delegations::<T>::get(&candidate, &who);
+			DelegatedStake::<T>::try_mutate(&candidate, |info| -> DispatchResult {
+				if delegated.is_none() {
+					ensure!(amount >= T::MinDelegation::get(), Error::<T>::DelegationTooLow);
+					ensure!(
+						info.delegators.saturating_add(info.unbonding) < T::MaxDelegators::get(),
+						Error::<T>::TooManyDelegators
+					);
+					info.delegators.saturating_inc();
+				}
+				T::Currency::reserve(&who, amount)?;
+				info.total.saturating_accrue(amount);
+				Ok(())
+			})?;
+			Delegations::<T>::insert(
+				&candidate,
+				&who,
+				delegated.unwrap_or_else(Zero::zero).saturating_add(amount),
+			);
+
+			Self::deposit_event(Event::Delegated { delegator: who, candidate, amount });
+			Ok(Some(T::WeightInfo::delegate(length as u32)).into())
+		}
+
+		/// Undelegate all stake `origin` delegated to `candidate`.
+		///
+		/// This is possible at any time, also after `candidate` stopped being a candidate. The
+		/// stake stays reserved and can be withdrawn through `withdraw_unbonded` after the
+		/// `UnbondingPeriod`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount =
+				Delegations::<T>::take(&candidate, &who).ok_or(Error::<T>::NotDelegating)?;
+			DelegatedStake::<T>::mutate(&candidate, |info| {
+				info.total.saturating_reduce(amount);
+				info.delegators.saturating_dec();
+			});
+			Self::start_unbonding(&candidate, &who, amount);
+
+			Self::deposit_event(Event::Undelegated { delegator: who, candidate, amount });
+			Ok(())
+		}
+
+		/// Report an equivocation of the collator candidate `offender`.
+		///
+		/// `EquivocationSlash` of the deposit of `offender` and of all stake delegated to or
+		/// unbonding from it is slashed. The offender is removed from the candidate list, unless
+		/// this would leave fewer than `MinEligibleCollators` eligible collators, and the rest of
+		/// its deposit starts unbonding.
+		///
+		/// Offenders which are no longer candidates can be reported as long as stake is delegated
+		/// to or unbonding from them.
+		///
+		/// The origin for this call must be the `EquivocationReportOrigin`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::report_equivocation(
+			T::MaxDelegators::get().saturating_add(1),
+			T::MaxCandidates::get()
+		))]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			offender: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::EquivocationReportOrigin::ensure_origin(origin)?;
+
+			let length = CandidateList::<T>::decode_len().unwrap_or_default();
+			let slashed = Self::slash_candidate(&offender, T::EquivocationSlash::get())?;
+			let is_candidate = CandidateList::<T>::get()
+				.iter()
+				.any(|candidate_info| candidate_info.who == offender);
+			if is_candidate && Self::eligible_collators() > T::MinEligibleCollators::get() {
+				Self::remove_and_unbond_candidate(&offender)?;
+			}
+
+			Ok(Some(T::WeightInfo::report_equivocation(slashed, length as u32)).into())
+		}
+
+		/// Withdraw the stake `origin` unbonded from `candidate`, once the `UnbondingPeriod` has
+		/// passed.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let unbonding =
+				Unbonding::<T>::get(&candidate, &who).ok_or(Error::<T>::NotUnbonding)?;
+			ensure!(
+				unbonding.unlock_at <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::StillUnbonding
+			);
+			Unbonding::<T>::remove(&candidate, &who);
+			T::Currency::unreserve(&who, unbonding.amount);
+			DelegatedStake::<T>::mutate_exists(&candidate, |maybe_info| {
+				*maybe_info = maybe_info.take().and_then(|mut info| {
+					info.unbonding.saturating_dec();
+					(info.delegators > 0 || info.unbonding > 0).then_some(info)
+				});
+			});
+
+			Self::deposit_event(Event::Withdrawn { who, candidate, amount: unbonding.amount });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -843,28 +1141,205 @@ pub mod pallet {
 				};
 				Ok(())
 			})?;
+			Performance::<T>::remove(who);
+			Self::deposit_event(Event::CandidateRemoved { account_id: who.clone() });
+			Ok(())
+		}
+
+		/// Removes the candidate `who` and starts unbonding its deposit.
+		fn remove_and_unbond_candidate(who: &T::AccountId) -> Result<(), DispatchError> {
+			let deposit =
+				CandidateList::<T>::try_mutate(|candidates| -> Result<_, DispatchError> {
+					let idx = candidates
+						.iter()
+						.position(|candidate_info| candidate_info.who == *who)
+						.ok_or(Error::<T>::NotCandidate)?;
+					Ok(candidates.remove(idx).deposit)
+				})?;
+			LastAuthoredBlock::<T>::remove(who);
+			Performance::<T>::remove(who);
+			Self::start_unbonding(who, who, deposit);
 			Self::deposit_event(Event::CandidateRemoved { account_id: who.clone() });
 			Ok(())
 		}
 
+		/// Start unbonding `amount` of the stake `who` reserved for `candidate`, adding to any
+		/// stake it is already unbonding from `candidate`.
+		fn start_unbonding(candidate: &T::AccountId, who: &T::AccountId, amount: BalanceOf<T>) {
+			let unlock_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+			Unbonding::<T>::mutate(candidate, who, |maybe_unbonding| {
+				let unbonding = maybe_unbonding.get_or_insert_with(|| {
+					DelegatedStake::<T>::mutate(candidate, |info| info.unbonding.saturating_inc());
+					Default::default()
+				});
+				unbonding.amount.saturating_accrue(amount);
+				unbonding.unlock_at = unlock_at;
+			});
+		}
+
+		/// Slash `fraction` of the deposit of the candidate `who` and of all stake delegated to or
+		/// unbonding from it.
+		///
+		/// Return value is the number of slashed delegations and unbonding accounts.
+		pub(crate) fn slash_candidate(
+			who: &T::AccountId,
+			fraction: Perbill,
+		) -> Result<u32, DispatchError> {
+			let mut slashed = NegativeImbalanceOf::<T>::zero();
+			let is_candidate =
+				CandidateList::<T>::try_mutate(|candidates| -> Result<_, DispatchError> {
+					let Some(idx) =
+						candidates.iter().position(|candidate_info| candidate_info.who == *who)
+					else {
+						return Ok(false)
+					};
+					// Remove the candidate and insert it again at the spot of its reduced deposit.
+					let mut info = candidates.remove(idx);
+					let (imbalance, _) = T::Currency::slash_reserved(who, fraction * info.deposit);
+					info.deposit.saturating_reduce(imbalance.peek());
+					slashed.subsume(imbalance);
+					let new_pos = candidates
+						.iter()
+						.position(|candidate| candidate.deposit >= info.deposit)
+						.unwrap_or_else(|| candidates.len());
+					candidates
+						.try_insert(new_pos, info)
+						.map_err(|_| Error::<T>::InsertToCandidateListFailed)?;
+					Ok(true)
+				})?;
+			ensure!(
+				is_candidate || DelegatedStake::<T>::contains_key(who),
+				Error::<T>::NotCandidate
+			);
+
+			let mut count = 0u32;
+			let mut delegated_slashed = BalanceOf::<T>::zero();
+			for (delegator, amount) in Delegations::<T>::iter_prefix(who) {
+				let (imbalance, _) = T::Currency::slash_reserved(&delegator, fraction * amount);
+				let amount_slashed = imbalance.peek();
+				Delegations::<T>::insert(who, &delegator, amount.saturating_sub(amount_slashed));
+				delegated_slashed.saturating_accrue(amount_slashed);
+				slashed.subsume(imbalance);
+				count.saturating_inc();
+			}
+			for (account, mut unbonding) in Unbonding::<T>::iter_prefix(who) {
+				let (imbalance, _) =
+					T::Currency::slash_reserved(&account, fraction * unbonding.amount);
+				unbonding.amount.saturating_reduce(imbalance.peek());
+				Unbonding::<T>::insert(who, &account, unbonding);
+				slashed.subsume(imbalance);
+				count.saturating_inc();
+			}
+			if !delegated_slashed.is_zero() {
+				DelegatedStake::<T>::mutate(who, |info| {
+					info.total.saturating_reduce(delegated_slashed)
+				});
+			}
+
+			Self::deposit_event(Event::CandidateSlashed {
+				account_id: who.clone(),
+				amount: slashed.peek(),
+			});
+			T::OnSlash::on_unbalanced(slashed);
+			Ok(count)
+		}
+
+		/// Share `reward` of the block author `author` with its delegators, pro rata to the stake
+		/// they delegated. The author's own deposit counts as its own share.
+		///
+		/// Return value is the number of rewarded delegators.
+		pub(crate) fn reward_delegators(author: &T::AccountId, reward: BalanceOf<T>) -> u32 {
+			let delegated = DelegatedStake::<T>::get(author);
+			if delegated.total.is_zero() || reward.is_zero() {
+				return 0
+			}
+			let Some(deposit) = CandidateList::<T>::get()
+				.iter()
+				.find(|candidate_info| candidate_info.who == *author)
+				.map(|candidate_info| candidate_info.deposit)
+			else {
+				// Delegations to accounts which are not candidates are not rewarded.
+				return 0
+			};
+
+			let stake = deposit.saturating_add(delegated.total);
+			let mut rewarded = 0u32;
+			for (delegator, amount) in Delegations::<T>::iter_prefix(author) {
+				let share = Perbill::from_rational(amount, stake) * reward;
+				// The author received the reward just before, this should never fail.
+				let _success = T::Currency::transfer(author, &delegator, share, KeepAlive);
+				debug_assert!(_success.is_ok());
+				rewarded.saturating_inc();
+			}
+			rewarded
+		}
+
+		/// The score of a candidate used to rank it when assembling a new collator set.
+		fn candidate_score(candidate_info: &CandidateInfo<T::AccountId, BalanceOf<T>>) -> u128 {
+			let stake = candidate_info
+				.deposit
+				.saturating_add(DelegatedStake::<T>::get(&candidate_info.who).total);
+			let performance =
+				Performance::<T>::get(&candidate_info.who).unwrap_or_else(Perbill::one);
+			<T::CandidateScore as crate::CandidateScore<_, _>>::score(
+				&candidate_info.who,
+				stake,
+				performance,
+			)
+		}
+
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
 		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
+		/// Candidates are selected by their score, candidates with equal scores are ordered like
+		/// in the [`CandidateList`].
 		pub fn assemble_collators() -> Vec<T::AccountId> {
 			// Casting `u32` to `usize` should be safe on all machines running this.
 			let desired_candidates = DesiredCandidates::<T>::get() as usize;
 			let mut collators = Invulnerables::<T>::get().to_vec();
+			let mut candidates = CandidateList::<T>::get()
+				.into_iter()
+				.rev()
+				.map(|candidate_info| (Self::candidate_score(&candidate_info), candidate_info.who))
+				.collect::<Vec<_>>();
+			// The sort is stable, so ties keep their order in the list.
+			candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
 			collators.extend(
-				CandidateList::<T>::get()
-					.iter()
-					.rev()
-					.cloned()
-					.take(desired_candidates)
-					.map(|candidate_info| candidate_info.who),
+				candidates.into_iter().take(desired_candidates).map(|(_, candidate)| candidate),
 			);
 			collators
 		}
 
+		/// Record the share of the expected blocks each candidate among the collators of the ended
+		/// session authored. Invulnerables are not ranked, so their share is not recorded.
+		///
+		/// Return value is the number of collators in the ended session.
+		fn note_session_performance(index: SessionIndex) -> u32 {
+			let session_blocks = SessionBlocks::<T>::take();
+			// Only collators of the session author blocks, so this is bounded by the set size.
+			let authored_blocks = AuthoredBlocks::<T>::drain().collect::<BTreeMap<_, _>>();
+			let Some(collators) = SessionCollators::<T>::take(index) else { return 0 };
+			let count = collators.len() as u32;
+			if session_blocks == 0 {
+				return count
+			}
+			let candidates = CandidateList::<T>::get();
+			for collator in collators {
+				if !candidates.iter().any(|candidate_info| candidate_info.who == collator) {
+					continue
+				}
+				let authored = authored_blocks.get(&collator).copied().unwrap_or_default();
+				// Every collator is expected to author an equal share of the session's blocks.
+				let performance = Perbill::from_rational(
+					(authored.saturating_mul(count)).min(session_blocks),
+					session_blocks,
+				);
+				Performance::<T>::insert(&collator, performance);
+			}
+			count
+		}
+
 		/// Kicks out candidates that did not produce a block in the kick threshold and refunds
 		/// their deposits.
 		///
@@ -916,6 +1391,11 @@ pub mod pallet {
 		/// * The current desired candidate count should not exceed the candidate list capacity.
 		/// * The number of selected candidates together with the invulnerables must be greater than
 		///   or equal to the minimum number of eligible collators.
+		///
+		/// ## `DelegatedStake`
+		///
+		/// * The delegated stake and delegator count of every candidate should match its
+		///   `Delegations`, and its unbonding count its `Unbonding` entries.
 		#[cfg(any(test, feature = "try-runtime"))]
 		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 			let desired_candidates = DesiredCandidates::<T>::get();
@@ -931,6 +1411,23 @@ pub mod pallet {
 				"Invulnerable set together with desired candidates should be able to meet the collator quota."
 			);
 
+			for (candidate, info) in DelegatedStake::<T>::iter() {
+				let (total, delegators) = Delegations::<T>::iter_prefix(&candidate).fold(
+					(BalanceOf::<T>::zero(), 0u32),
+					|(total, delegators), (_, amount)| {
+						(total.saturating_add(amount), delegators.saturating_add(1))
+					},
+				);
+				frame_support::ensure!(
+					info.total == total && info.delegators == delegators,
+					"Delegated stake should match the sum of all delegations."
+				);
+				frame_support::ensure!(
+					info.unbonding == Unbonding::<T>::iter_prefix(&candidate).count() as u32,
+					"Unbonding count should match the unbonding entries."
+				);
+			}
+
 			Ok(())
 		}
 	}
@@ -950,10 +1447,16 @@ pub mod pallet {
 			// `reward` is half of pot account minus ED, this should never fail.
 			let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
 			debug_assert!(_success.is_ok());
+			let delegators = Self::reward_delegators(&author, reward);
+			AuthoredBlocks::<T>::mutate(&author, |authored| authored.saturating_inc());
+			SessionBlocks::<T>::mutate(|blocks| blocks.saturating_inc());
 			LastAuthoredBlock::<T>::insert(author, frame_system::Pallet::<T>::block_number());
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::note_author(),
+				T::WeightInfo::note_author().saturating_add(T::WeightInfo::reward_delegators(
+					delegators,
+					T::MaxCandidates::get(),
+				)),
 				DispatchClass::Mandatory,
 			);
 		}
@@ -982,6 +1485,9 @@ pub mod pallet {
 			);
 			let removed = candidates_len_before.saturating_sub(active_candidates_count);
 			let result = Self::assemble_collators();
+			// The set consists of invulnerables and candidates only, it never exceeds
+			// `MaxCollators`.
+			SessionCollators::<T>::insert(index, BoundedVec::truncate_from(result.clone()));
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
 				T::WeightInfo::new_session(removed, candidates_len_before),
@@ -992,12 +1498,51 @@ pub mod pallet {
 		fn start_session(_: SessionIndex) {
 			// we don't care.
 		}
-		fn end_session(_: SessionIndex) {
-			// we don't care.
+		fn end_session(index: SessionIndex) {
+			let collators = Self::note_session_performance(index);
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::end_session(collators),
+				DispatchClass::Mandatory,
+			);
 		}
 	}
 }
 
+/// Scores collation candidates to rank them when assembling a new collator set.
+pub trait CandidateScore<AccountId, Balance> {
+	/// The score of candidate `who`, backed by `stake` in total, which authored `performance` of
+	/// the blocks it was expected to author in the last session it was a collator in.
+	///
+	/// Candidates with higher scores are preferred.
+	fn score(who: &AccountId, stake: Balance, performance: Perbill) -> u128;
+}
+
+/// Score candidates by their stake alone.
+impl<AccountId, Balance: UniqueSaturatedInto<u128>> CandidateScore<AccountId, Balance> for () {
+	fn score(_: &AccountId, stake: Balance, _: Perbill) -> u128 {
+		stake.unique_saturated_into()
+	}
+}
+
+/// Score candidates by their stake, of which `PerformanceWeight` is scaled by their performance.
+///
+/// With a `PerformanceWeight` of zero this is the stake alone, with a `PerformanceWeight` of one
+/// the stake is fully scaled by the share of the expected blocks the candidate authored.
+pub struct StakeWeightedByPerformance<PerformanceWeight>(PhantomData<PerformanceWeight>);
+impl<AccountId, Balance, PerformanceWeight> CandidateScore<AccountId, Balance>
+	for StakeWeightedByPerformance<PerformanceWeight>
+where
+	Balance: UniqueSaturatedInto<u128>,
+	PerformanceWeight: Get<Perbill>,
+{
+	fn score(_: &AccountId, stake: Balance, performance: Perbill) -> u128 {
+		let weight = PerformanceWeight::get();
+		let stake: u128 = stake.unique_saturated_into();
+		(weight.left_from_one() * stake).saturating_add((weight * performance) * stake)
+	}
+}
+
 /// [`TypedGet`] implementation to get the AccountId of the StakingPot.
 pub struct StakingPotAccountId<R>(PhantomData<R>);
 impl<R> TypedGet for StakingPotAccountId<R>
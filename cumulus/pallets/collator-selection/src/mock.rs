@@ -22,7 +22,9 @@ use frame_support::{
 };
 use frame_system as system;
 use frame_system::EnsureSignedBy;
-use sp_runtime::{testing::UintAuthorityId, traits::OpaqueKeys, BuildStorage, RuntimeAppPublic};
+use sp_runtime::{
+	testing::UintAuthorityId, traits::OpaqueKeys, BuildStorage, Perbill, RuntimeAppPublic,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -159,6 +161,11 @@ impl ValidatorRegistration<u64> for IsRegistered {
 	}
 }
 
+parameter_types! {
+	pub static PerformanceWeight: Perbill = Perbill::zero();
+	pub const EquivocationSlash: Perbill = Perbill::from_percent(10);
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -171,6 +178,13 @@ impl Config for Test {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = IdentityCollator;
 	type ValidatorRegistration = IsRegistered;
+	type MaxDelegators = ConstU32<2>;
+	type MinDelegation = ConstU64<5>;
+	type CandidateScore = StakeWeightedByPerformance<PerformanceWeight>;
+	type EquivocationReportOrigin = EnsureSignedBy<RootAccount, u64>;
+	type EquivocationSlash = EquivocationSlash;
+	type OnSlash = ();
+	type UnbondingPeriod = ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -15,8 +15,9 @@
 
 use crate as collator_selection;
 use crate::{
-	mock::*, CandidacyBond, CandidateInfo, CandidateList, DesiredCandidates, Error, Invulnerables,
-	LastAuthoredBlock,
+	mock::*, CandidacyBond, CandidateInfo, CandidateList, DelegatedStake, DelegationInfo,
+	Delegations, DesiredCandidates, Error, Invulnerables, LastAuthoredBlock, Performance,
+	SessionCollators, Unbonding, UnbondingInfo,
 };
 use codec::Encode;
 use frame_support::{
@@ -24,7 +25,7 @@ use frame_support::{
 	traits::{Currency, OnInitialize},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage};
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage, Perbill};
 
 #[test]
 fn basic_setup_works() {
@@ -1591,6 +1592,292 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 	});
 }
 
+#[test]
+fn delegate_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+
+		// can only delegate to candidates.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 10),
+			Error::<Test>::NotCandidate
+		);
+		// a new delegation must be at least the minimum delegation.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 4),
+			Error::<Test>::DelegationTooLow
+		);
+
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 10));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Delegated {
+			delegator: 5,
+			candidate: 3,
+			amount: 10,
+		}));
+		// topping up an existing delegation is not subject to the minimum.
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 1));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(4), 3, 20));
+
+		assert_eq!(Delegations::<Test>::get(3, 5), Some(11));
+		assert_eq!(Delegations::<Test>::get(3, 4), Some(20));
+		assert_eq!(
+			DelegatedStake::<Test>::get(3),
+			DelegationInfo { total: 31, delegators: 2, unbonding: 0 }
+		);
+		assert_eq!(Balances::free_balance(5), 89);
+		assert_eq!(Balances::reserved_balance(5), 11);
+
+		// at most 2 delegators in the mock.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 10),
+			Error::<Test>::TooManyDelegators
+		);
+		// but existing delegators can still top up.
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(4), 3, 5));
+		assert_eq!(
+			DelegatedStake::<Test>::get(3),
+			DelegationInfo { total: 36, delegators: 2, unbonding: 0 }
+		);
+	});
+}
+
+#[test]
+fn undelegate_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 10));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(4), 3, 20));
+
+		assert_noop!(
+			CollatorSelection::undelegate(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::NotDelegating
+		);
+
+		assert_ok!(CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Undelegated {
+			delegator: 5,
+			candidate: 3,
+			amount: 10,
+		}));
+		// the stake stays reserved while it is unbonding.
+		assert_eq!(Balances::free_balance(5), 90);
+		assert_eq!(Delegations::<Test>::get(3, 5), None);
+		assert_eq!(Unbonding::<Test>::get(3, 5), Some(UnbondingInfo { amount: 10, unlock_at: 11 }));
+		assert_eq!(
+			DelegatedStake::<Test>::get(3),
+			DelegationInfo { total: 20, delegators: 1, unbonding: 1 }
+		);
+		assert_noop!(
+			CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(5), 3),
+			Error::<Test>::StillUnbonding
+		);
+
+		// delegations can be undelegated after the candidate left.
+		assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::undelegate(RuntimeOrigin::signed(4), 3));
+		assert_eq!(
+			DelegatedStake::<Test>::get(3),
+			DelegationInfo { total: 0, delegators: 0, unbonding: 2 }
+		);
+		assert_ok!(CollatorSelection::do_try_state());
+
+		System::set_block_number(11);
+		assert_noop!(
+			CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::NotUnbonding
+		);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(5), 3));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Withdrawn {
+			who: 5,
+			candidate: 3,
+			amount: 10,
+		}));
+		assert_eq!(Balances::free_balance(5), 100);
+		assert_eq!(Unbonding::<Test>::get(3, 5), None);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(4), 3));
+		assert_eq!(Balances::free_balance(4), 100);
+		assert!(!DelegatedStake::<Test>::contains_key(3));
+		assert_ok!(CollatorSelection::do_try_state());
+	});
+}
+
+#[test]
+fn unbonding_accounts_count_towards_max_delegators() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 10));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(4), 3, 20));
+		assert_ok!(CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3));
+
+		// 5 is still unbonding, so both slots of the mock are taken.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 10),
+			Error::<Test>::TooManyDelegators
+		);
+
+		System::set_block_number(11);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(5), 3));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(1), 3, 10));
+		assert_ok!(CollatorSelection::do_try_state());
+	});
+}
+
+#[test]
+fn delegators_share_rewards_pro_rata() {
+	new_test_ext().execute_with(|| {
+		// put 100 in the pot + 5 for ED
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 105);
+
+		// 4 is the default author.
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(3), 4, 30));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 10));
+		// triggers `note_author`
+		Authorship::on_initialize(1);
+
+		// the reward of 50 is shared by a stake of 50, 10 of which is the deposit of 4.
+		assert_eq!(Balances::free_balance(3), 70 + 30);
+		assert_eq!(Balances::free_balance(5), 90 + 10);
+		assert_eq!(Balances::free_balance(4), 90 + 10);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 55);
+	});
+}
+
+#[test]
+fn performance_is_recorded_when_session_ends() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+
+		// 3 and 4 become collators in session 2, which starts at block 20.
+		initialize_to_block(29);
+		assert_eq!(SessionHandlerCollators::get(), vec![1, 2, 3, 4]);
+		// 4 authored all blocks so far, but was not a collator in any ended session.
+		assert_eq!(Performance::<Test>::get(4), None);
+		// invulnerables are not ranked.
+		assert_eq!(Performance::<Test>::get(1), None);
+
+		initialize_to_block(30);
+		// 4 authored all blocks of session 2, more than its share.
+		assert_eq!(Performance::<Test>::get(4), Some(Perbill::one()));
+		assert_eq!(Performance::<Test>::get(3), Some(Perbill::zero()));
+		assert_eq!(Performance::<Test>::get(1), None);
+		assert_eq!(SessionCollators::<Test>::get(2), None);
+
+		// the performance is removed together with the candidate.
+		assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+		assert_eq!(Performance::<Test>::get(3), None);
+	});
+}
+
+#[test]
+fn candidates_are_ranked_by_score() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 30));
+		assert_eq!(CollatorSelection::assemble_collators(), vec![1, 2, 3, 4]);
+
+		// delegated stake counts towards the stake of a candidate.
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(1), 5, 25));
+		assert_eq!(CollatorSelection::assemble_collators(), vec![1, 2, 5, 3]);
+
+		// a poor performance only matters if it is weighted.
+		Performance::<Test>::insert(5, Perbill::from_percent(10));
+		assert_eq!(CollatorSelection::assemble_collators(), vec![1, 2, 5, 3]);
+
+		// 5 now has a score of 3, which is less than 4 without any recorded performance.
+		PerformanceWeight::set(Perbill::one());
+		assert_eq!(CollatorSelection::assemble_collators(), vec![1, 2, 3, 4]);
+
+		// with half the weight, 5 has a score of 19 and 4 still has 10.
+		PerformanceWeight::set(Perbill::from_percent(50));
+		assert_eq!(CollatorSelection::assemble_collators(), vec![1, 2, 3, 5]);
+	});
+}
+
+#[test]
+fn report_equivocation_slashes_candidate_and_delegators() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20));
+		let total_issuance = Balances::total_issuance();
+
+		assert_noop!(
+			CollatorSelection::report_equivocation(RuntimeOrigin::signed(1), 3),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorSelection::report_equivocation(RuntimeOrigin::signed(RootAccount::get()), 5),
+			Error::<Test>::NotCandidate
+		);
+
+		assert_ok!(CollatorSelection::report_equivocation(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateSlashed {
+			account_id: 3,
+			amount: 3,
+		}));
+
+		// 10% of the deposit and the delegation are burned.
+		assert_eq!(Balances::total_issuance(), total_issuance - 3);
+		// 3 is removed from the candidates and the rest of its deposit starts unbonding.
+		assert_eq!(CandidateList::<Test>::get().iter().map(|c| c.who).collect::<Vec<_>>(), vec![4]);
+		assert_eq!(Balances::reserved_balance(3), 9);
+		assert_eq!(Unbonding::<Test>::get(3, 3), Some(UnbondingInfo { amount: 9, unlock_at: 11 }));
+		// the delegation stays until it is undelegated.
+		assert_eq!(Delegations::<Test>::get(3, 5), Some(18));
+		assert_eq!(
+			DelegatedStake::<Test>::get(3),
+			DelegationInfo { total: 18, delegators: 1, unbonding: 1 }
+		);
+		assert_eq!(Balances::reserved_balance(5), 18);
+		assert_ok!(CollatorSelection::do_try_state());
+
+		// unbonding stake is slashed for equivocations reported before it is withdrawn.
+		assert_ok!(CollatorSelection::report_equivocation(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		assert_eq!(Balances::total_issuance(), total_issuance - 6);
+		assert_eq!(Unbonding::<Test>::get(3, 3), Some(UnbondingInfo { amount: 8, unlock_at: 11 }));
+		assert_eq!(Delegations::<Test>::get(3, 5), Some(16));
+		assert_ok!(CollatorSelection::do_try_state());
+
+		System::set_block_number(11);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(3), 3));
+		assert_eq!(Balances::free_balance(3), 98);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn report_equivocation_keeps_candidate_if_too_few_collators() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::set_invulnerables(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![]
+		));
+
+		assert_ok!(CollatorSelection::report_equivocation(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		// 3 is the only eligible collator, it stays with a reduced deposit.
+		assert_eq!(
+			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			vec![CandidateInfo { who: 3, deposit: 9 }]
+		);
+		assert_eq!(Balances::reserved_balance(3), 9);
+	});
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
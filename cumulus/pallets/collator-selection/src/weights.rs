@@ -37,6 +37,12 @@ pub trait WeightInfo {
 	fn take_candidate_slot(_c: u32) -> Weight;
 	fn note_author() -> Weight;
 	fn new_session(_c: u32, _r: u32) -> Weight;
+	fn end_session(_c: u32) -> Weight;
+	fn delegate(_c: u32) -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn report_equivocation(_d: u32, _c: u32) -> Weight;
+	fn reward_delegators(_d: u32, _c: u32) -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -82,11 +88,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// The `AuthoredBlocks` and `SessionBlocks` accesses are not benchmarked yet.
 	fn note_author() -> Weight {
 		Weight::from_parts(71_461_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads_writes(2_u64, 2_u64))
 	}
+	/// The `SessionCollators` write is not benchmarked yet.
 	fn new_session(r: u32, c: u32) -> Weight {
 		Weight::from_parts(0_u64, 0)
 			// Standard Error: 1_010_000
@@ -97,6 +106,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64.saturating_mul(c as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
@@ -139,6 +149,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Not benchmarked yet, bounded by `new_session`, which reads and writes as many entries per
+	/// collator, and the per-session storage accesses.
+	fn end_session(c: u32) -> Weight {
+		<Self as WeightInfo>::new_session(0, c)
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn delegate(c: u32) -> Weight {
+		<Self as WeightInfo>::update_bond(c)
+			.saturating_add(T::DbWeight::get().reads_writes(2_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn undelegate() -> Weight {
+		<Self as WeightInfo>::update_bond(1)
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn withdraw_unbonded() -> Weight {
+		<Self as WeightInfo>::update_bond(1)
+			.saturating_add(T::DbWeight::get().reads_writes(2_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by repositioning and removing the offender and one reward
+	/// payout per slashed account.
+	fn report_equivocation(d: u32, c: u32) -> Weight {
+		<Self as WeightInfo>::update_bond(c)
+			.saturating_add(<Self as WeightInfo>::leave_intent(c))
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+			.saturating_add(
+				<Self as WeightInfo>::note_author()
+					.saturating_add(T::DbWeight::get().reads_writes(1_u64, 1_u64))
+					.saturating_mul(d as u64),
+			)
+	}
+	/// Not benchmarked yet, bounded by reading the candidate list like `update_bond` and one
+	/// reward payout per delegator.
+	fn reward_delegators(d: u32, c: u32) -> Weight {
+		T::DbWeight::get()
+			.reads(1_u64)
+			.saturating_add(<Self as WeightInfo>::update_bond(c).saturating_mul(d.min(1) as u64))
+			.saturating_add(<Self as WeightInfo>::note_author().saturating_mul(d as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -169,10 +220,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// The `AuthoredBlocks` and `SessionBlocks` accesses are not benchmarked yet.
 	fn note_author() -> Weight {
 		Weight::from_parts(71_461_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 2_u64))
 	}
 	fn update_bond(c: u32) -> Weight {
 		Weight::from_parts(55_336_000_u64, 0)
@@ -188,6 +241,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// The `SessionCollators` write is not benchmarked yet.
 	fn new_session(r: u32, c: u32) -> Weight {
 		Weight::from_parts(0_u64, 0)
 			// Standard Error: 1_010_000
@@ -198,6 +252,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64.saturating_mul(c as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
@@ -240,4 +295,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Not benchmarked yet, bounded by `new_session`, which reads and writes as many entries per
+	/// collator, and the per-session storage accesses.
+	fn end_session(c: u32) -> Weight {
+		<Self as WeightInfo>::new_session(0, c)
+			.saturating_add(RocksDbWeight::get().reads_writes(3_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn delegate(c: u32) -> Weight {
+		<Self as WeightInfo>::update_bond(c)
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn undelegate() -> Weight {
+		<Self as WeightInfo>::update_bond(1)
+			.saturating_add(RocksDbWeight::get().reads_writes(3_u64, 3_u64))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn withdraw_unbonded() -> Weight {
+		<Self as WeightInfo>::update_bond(1)
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 2_u64))
+	}
+	/// Not benchmarked yet, bounded by repositioning and removing the offender and one reward
+	/// payout per slashed account.
+	fn report_equivocation(d: u32, c: u32) -> Weight {
+		<Self as WeightInfo>::update_bond(c)
+			.saturating_add(<Self as WeightInfo>::leave_intent(c))
+			.saturating_add(RocksDbWeight::get().reads_writes(3_u64, 3_u64))
+			.saturating_add(
+				<Self as WeightInfo>::note_author()
+					.saturating_add(RocksDbWeight::get().reads_writes(1_u64, 1_u64))
+					.saturating_mul(d as u64),
+			)
+	}
+	/// Not benchmarked yet, bounded by reading the candidate list like `update_bond` and one
+	/// reward payout per delegator.
+	fn reward_delegators(d: u32, c: u32) -> Weight {
+		RocksDbWeight::get()
+			.reads(1_u64)
+			.saturating_add(<Self as WeightInfo>::update_bond(c).saturating_mul(d.min(1) as u64))
+			.saturating_add(<Self as WeightInfo>::note_author().saturating_mul(d as u64))
+	}
 }
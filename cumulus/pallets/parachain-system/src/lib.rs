@@ -29,7 +29,7 @@
 
 extern crate alloc;
 
-use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
+use alloc::{collections::btree_map::BTreeMap, rc::Rc, vec, vec::Vec};
 use codec::{Decode, DecodeLimit, Encode};
 use core::cmp;
 use cumulus_primitives_core::{
@@ -74,6 +74,7 @@ pub use weights::WeightInfo;
 mod unincluded_segment;
 
 pub mod consensus_hook;
+pub mod relay_state_keys;
 pub mod relay_state_snapshot;
 #[macro_use]
 pub mod validate_block;
@@ -106,6 +107,7 @@ pub use consensus_hook::{ConsensusHook, ExpectParentIncluded};
 /// # fn main() {}
 /// ```
 pub use cumulus_pallet_parachain_system_proc_macro::register_validate_block;
+pub use relay_state_keys::{RelayStateKeysProvider, RelayStateReader};
 pub use relay_state_snapshot::{MessagingStateSnapshot, RelayChainStateProof};
 pub use unincluded_segment::{Ancestor, UsedBandwidth};
 
@@ -604,12 +606,14 @@ pub mod pallet {
 				LastRelayChainBlockNumber::<T>::get(),
 			);
 
-			let relay_state_proof = RelayChainStateProof::new(
-				T::SelfParaId::get(),
-				vfp.relay_parent_storage_root,
-				relay_chain_state.clone(),
-			)
-			.expect("Invalid relay chain state proof");
+			let relay_state_proof = Rc::new(
+				RelayChainStateProof::new(
+					T::SelfParaId::get(),
+					vfp.relay_parent_storage_root,
+					relay_chain_state.clone(),
+				)
+				.expect("Invalid relay chain state proof"),
+			);
 
 			let expected_rp_descendants_num = T::RelayParentOffset::get();
 
@@ -698,6 +702,7 @@ pub mod pallet {
 
 			<ValidationData<T>>::put(&vfp);
 			<RelayStateProof<T>>::put(relay_chain_state);
+			Self::cache_relay_state_proof(vfp.relay_parent_storage_root, relay_state_proof.clone());
 			<RelevantMessagingState<T>>::put(relevant_messaging_state.clone());
 			<HostConfiguration<T>>::put(host_config);
 
@@ -0,0 +1,160 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Relay chain storage requested by the runtime.
+//!
+//! Besides the storage that is always part of the relay chain state proof, pallets can request
+//! arbitrary relay chain storage by implementing [`RelayStateKeysProvider`]. The runtime combines
+//! the providers of all its pallets in the
+//! [`RelayStateKeysApi`](cumulus_primitives_core::RelayStateKeysApi) runtime API, which is queried
+//! by the collator when building the parachain inherent:
+//!
+//! ```ignore
+//! impl cumulus_primitives_core::RelayStateKeysApi<Block> for Runtime {
+//!     fn relay_state_keys() -> Vec<Vec<u8>> {
+//!         <(PalletA, PalletB) as RelayStateKeysProvider>::relay_state_keys()
+//!     }
+//! }
+//! ```
+//!
+//! The pallets read the values from the relay chain state proof of the current block through the
+//! [`RelayStateReader`] returned by [`Pallet::relay_state_reader`].
+
+use crate::{
+	relay_state_snapshot::{Error, RelayChainStateProof},
+	Config, Pallet, RelayStateProof, ValidationData,
+};
+use alloc::{rc::Rc, vec::Vec};
+use codec::{Decode, Encode};
+use core::cell::RefCell;
+use cumulus_primitives_core::relay_chain;
+use frame_support::traits::Get;
+
+/// Something that requires relay chain storage to be included in the relay chain state proof.
+pub trait RelayStateKeysProvider {
+	/// The relay chain storage keys to include in the relay chain state proof.
+	fn relay_state_keys() -> Vec<Vec<u8>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl RelayStateKeysProvider for Tuple {
+	fn relay_state_keys() -> Vec<Vec<u8>> {
+		let mut keys = Vec::new();
+		for_tuples!( #( keys.extend(Tuple::relay_state_keys()); )* );
+		keys
+	}
+}
+
+/// Reads relay chain storage from the relay chain state proof of the current block.
+///
+/// The proof is decoded and checked against the relay parent storage root once per block, all
+/// readers of the block share it.
+pub struct RelayStateReader {
+	proof: Rc<RelayChainStateProof>,
+}
+
+impl RelayStateReader {
+	/// Read the value stored under `key` in the relay chain and decode it.
+	///
+	/// Returns `Ok(None)` if the relay chain doesn't store a value under `key`. Returns an error if
+	/// the value can not be decoded or if `key` is not part of the proof, e.g. because it wasn't
+	/// returned by the [`RelayStateKeysProvider`] of the runtime.
+	pub fn read<V: Decode>(&self, key: &[u8]) -> Result<Option<V>, Error> {
+		self.proof.read_optional_entry(key)
+	}
+
+	/// Get the underlying relay chain state proof.
+	pub fn proof(&self) -> &RelayChainStateProof {
+		&self.proof
+	}
+}
+
+/// The decoded relay chain state proof of a block, with the relay parent storage root and the
+/// encoded parent hash of the block it belongs to.
+type CachedProof = (relay_chain::Hash, Vec<u8>, Rc<RelayChainStateProof>);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+	static CACHED_PROOF: RefCell<Option<CachedProof>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "std")]
+fn with_cached_proof<R>(f: impl FnOnce(&RefCell<Option<CachedProof>>) -> R) -> R {
+	CACHED_PROOF.with(f)
+}
+
+#[cfg(not(feature = "std"))]
+struct ProofCache(RefCell<Option<CachedProof>>);
+// NOTE: Safe only in wasm (guarded above) because there's only one thread.
+#[cfg(not(feature = "std"))]
+unsafe impl Sync for ProofCache {}
+
+#[cfg(not(feature = "std"))]
+static CACHED_PROOF: ProofCache = ProofCache(RefCell::new(None));
+
+#[cfg(not(feature = "std"))]
+fn with_cached_proof<R>(f: impl FnOnce(&RefCell<Option<CachedProof>>) -> R) -> R {
+	f(&CACHED_PROOF.0)
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get a [`RelayStateReader`] for the relay chain state proof of the current block.
+	///
+	/// Returns `None` if the parachain inherent was not yet applied in the current block.
+	pub fn relay_state_reader() -> Option<RelayStateReader> {
+		let relay_parent_storage_root = ValidationData::<T>::get()?.relay_parent_storage_root;
+		let parent_hash = frame_system::Pallet::<T>::parent_hash().encode();
+
+		let cached = with_cached_proof(|cache| {
+			cache
+				.borrow()
+				.as_ref()
+				.filter(|(root, parent, _)| {
+					*root == relay_parent_storage_root && *parent == parent_hash
+				})
+				.map(|(_, _, proof)| proof.clone())
+		});
+		let proof = match cached {
+			Some(proof) => proof,
+			None => {
+				let proof = Rc::new(
+					RelayChainStateProof::new(
+						T::SelfParaId::get(),
+						relay_parent_storage_root,
+						RelayStateProof::<T>::get()?,
+					)
+					.ok()?,
+				);
+				Self::cache_relay_state_proof(relay_parent_storage_root, proof.clone());
+				proof
+			},
+		};
+
+		Some(RelayStateReader { proof })
+	}
+
+	/// Cache the decoded relay chain state `proof` of the current block for the readers of the
+	/// block.
+	pub(crate) fn cache_relay_state_proof(
+		relay_parent_storage_root: relay_chain::Hash,
+		proof: Rc<RelayChainStateProof>,
+	) {
+		let parent_hash = frame_system::Pallet::<T>::parent_hash().encode();
+		with_cached_proof(|cache| {
+			*cache.borrow_mut() = Some((relay_parent_storage_root, parent_hash, proof))
+		});
+	}
+}
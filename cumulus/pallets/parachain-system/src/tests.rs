@@ -1732,3 +1732,38 @@ fn ump_signals_are_sent_correctly() {
 			);
 	}
 }
+
+#[test]
+fn relay_state_reader_reads_additional_keys() {
+	struct BalanceKeys;
+	impl RelayStateKeysProvider for BalanceKeys {
+		fn relay_state_keys() -> Vec<Vec<u8>> {
+			vec![b"relay_balance".to_vec()]
+		}
+	}
+
+	struct HeadKeys;
+	impl RelayStateKeysProvider for HeadKeys {
+		fn relay_state_keys() -> Vec<Vec<u8>> {
+			vec![b"other_para_head".to_vec()]
+		}
+	}
+
+	assert_eq!(
+		<(BalanceKeys, HeadKeys) as RelayStateKeysProvider>::relay_state_keys(),
+		vec![b"relay_balance".to_vec(), b"other_para_head".to_vec()],
+	);
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			sproof.additional_key_values.push((b"relay_balance".to_vec(), 42u128.encode()));
+		})
+		.add(1, || {
+			let reader = ParachainSystem::relay_state_reader().expect("inherent was applied");
+			assert_eq!(reader.read::<u128>(b"relay_balance").unwrap(), Some(42));
+
+			// The proof decoded by the inherent is shared by all readers of the block.
+			let other_reader = ParachainSystem::relay_state_reader().unwrap();
+			assert!(core::ptr::eq(reader.proof(), other_reader.proof()));
+		});
+}
@@ -79,7 +79,7 @@ use sp_core::MAX_POSSIBLE_ALLOCATION;
 use sp_runtime::{FixedU128, SaturatedConversion, WeakBoundedVec};
 use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm, WrapVersion, MAX_XCM_DECODE_DEPTH};
 use xcm_builder::InspectMessageQueues;
-use xcm_executor::traits::ConvertOrigin;
+use xcm_executor::traits::{message_topic, ConvertOrigin, MessageStatus, RecordMessageStatus};
 
 pub use pallet::*;
 
@@ -167,6 +167,11 @@ pub mod pallet {
 		/// The price for delivering an XCM to a sibling parachain destination.
 		type PriceForSiblingDelivery: PriceForMessageDelivery<Id = ParaId>;
 
+		/// Records that a message with a topic was put onto an outbound XCMP channel.
+		///
+		/// Set it to `()` to not keep track of the messages.
+		type MessageStatusRecorder: RecordMessageStatus;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfoExt;
 	}
@@ -1152,12 +1157,12 @@ impl<T: Config> XcmpMessageSource for Pallet<T> {
 
 /// Xcm sender for sending to a sibling parachain.
 impl<T: Config> SendXcm for Pallet<T> {
-	type Ticket = (ParaId, VersionedXcm<()>);
+	type Ticket = (ParaId, VersionedXcm<()>, Option<XcmHash>);
 
 	fn validate(
 		dest: &mut Option<Location>,
 		msg: &mut Option<Xcm<()>>,
-	) -> SendResult<(ParaId, VersionedXcm<()>)> {
+	) -> SendResult<(ParaId, VersionedXcm<()>, Option<XcmHash>)> {
 		let d = dest.take().ok_or(SendError::MissingArgument)?;
 
 		match d.unpack() {
@@ -1166,13 +1171,14 @@ impl<T: Config> SendXcm for Pallet<T> {
 				let xcm = msg.take().ok_or(SendError::MissingArgument)?;
 				let id = ParaId::from(*id);
 				let price = T::PriceForSiblingDelivery::price_for_delivery(id, &xcm);
+				let topic = message_topic(&xcm);
 				let versioned_xcm = T::VersionWrapper::wrap_version(&d, xcm)
 					.map_err(|()| SendError::DestinationUnsupported)?;
 				versioned_xcm
 					.check_is_decodable()
 					.map_err(|()| SendError::ExceedsMaxMessageSize)?;
 
-				Ok(((id, versioned_xcm), price))
+				Ok(((id, versioned_xcm, topic), price))
 			},
 			_ => {
 				// Anything else is unhandled. This includes a message that is not meant for us.
@@ -1183,11 +1189,21 @@ impl<T: Config> SendXcm for Pallet<T> {
 		}
 	}
 
-	fn deliver((id, xcm): (ParaId, VersionedXcm<()>)) -> Result<XcmHash, SendError> {
+	fn deliver(
+		(id, xcm, topic): (ParaId, VersionedXcm<()>, Option<XcmHash>),
+	) -> Result<XcmHash, SendError> {
 		let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
 
 		match Self::send_fragment(id, XcmpMessageFormat::ConcatenatedVersionedXcm, xcm) {
 			Ok(_) => {
+				if let Some(topic) = topic {
+					T::MessageStatusRecorder::record_message_status(
+						topic,
+						MessageStatus::Queued {
+							destination: Location::new(1, [Parachain(id.into())]),
+						},
+					);
+				}
 				Self::deposit_event(Event::XcmpMessageSent { message_hash: hash });
 				Ok(hash)
 			},
@@ -290,6 +290,13 @@ mod tests {
 		type ValidatorId = <Self as frame_system::Config>::AccountId;
 		type ValidatorIdOf = IdentityCollator;
 		type ValidatorRegistration = IsRegistered;
+		type MaxDelegators = ConstU32<0>;
+		type MinDelegation = ();
+		type CandidateScore = ();
+		type EquivocationReportOrigin = EnsureRoot<AccountId>;
+		type EquivocationSlash = ();
+		type OnSlash = ();
+		type UnbondingPeriod = ();
 		type KickThreshold = ();
 		type WeightInfo = ();
 	}
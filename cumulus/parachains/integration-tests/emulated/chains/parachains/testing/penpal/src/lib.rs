@@ -62,6 +62,8 @@ decl_test_parachains! {
 			ForeignAssets: penpal_runtime::ForeignAssets,
 			AssetConversion: penpal_runtime::AssetConversion,
 			Balances: penpal_runtime::Balances,
+			Nfts: penpal_runtime::Nfts,
+			NftsXcm: penpal_runtime::NftsXcm,
 		}
 	},
 	pub struct PenpalB {
@@ -87,6 +89,8 @@ decl_test_parachains! {
 			ForeignAssets: penpal_runtime::ForeignAssets,
 			AssetConversion: penpal_runtime::AssetConversion,
 			Balances: penpal_runtime::Balances,
+			Nfts: penpal_runtime::Nfts,
+			NftsXcm: penpal_runtime::NftsXcm,
 		}
 	},
 }
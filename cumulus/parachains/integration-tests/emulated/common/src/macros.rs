@@ -54,7 +54,7 @@ pub use frame_support::{
 	BoundedVec,
 };
 pub use xcm_runtime_apis::{
-	dry_run::runtime_decl_for_dry_run_api::DryRunApiV2,
+	dry_run::runtime_decl_for_dry_run_api::DryRunApiV3,
 	fees::{runtime_decl_for_xcm_payment_api::XcmPaymentApiV2, Error as XcmPaymentApiError},
 };
 
@@ -109,7 +109,7 @@ macro_rules! test_parachain_is_trusted_teleporter {
 						type OriginCaller = <$sender_para as $crate::macros::Chain>::OriginCaller;
 
 						let origin = OriginCaller::system($crate::macros::RawOrigin::Signed(sender.clone()));
-						let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_call(origin, call.clone(),
+						let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_call(origin, call.clone(),
 							$crate::macros::XCM_VERSION).unwrap();
 
 
@@ -254,7 +254,7 @@ macro_rules! test_relay_is_trusted_teleporter {
 						type OriginCaller = <$sender_relay as $crate::macros::Chain>::OriginCaller;
 
 						let origin = OriginCaller::system($crate::macros::RawOrigin::Signed(sender.clone()));
-						let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_call(origin, call.clone(),
+						let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_call(origin, call.clone(),
 							$crate::macros::XCM_VERSION).unwrap();
 						// We filter the result to get only the messages we are interested in.
 						let (destination_to_query, messages_to_query) = &result
@@ -406,7 +406,7 @@ macro_rules! test_parachain_is_trusted_teleporter_for_relay {
 				type OriginCaller = <$sender_para as $crate::macros::Chain>::OriginCaller;
 
 				let origin = OriginCaller::system($crate::macros::RawOrigin::Signed(sender.clone()));
-				let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_call(origin, call.clone(),
+				let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_call(origin, call.clone(),
 					$crate::macros::XCM_VERSION).unwrap();
 				// We filter the result to get only the messages we are interested in.
 				let (destination_to_query, messages_to_query) = &result
@@ -704,7 +704,7 @@ macro_rules! test_can_estimate_and_pay_exact_fees {
 					($crate::macros::Parent, 100_000_000_000u128),
 				);
 				let origin = OriginCaller::system($crate::macros::RawOrigin::Signed(sender.clone()));
-				let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_call(origin, call.clone(),
+				let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_call(origin, call.clone(),
 					$crate::macros::XCM_VERSION).unwrap();
 				let local_xcm = result.local_xcm.unwrap().clone();
 				let local_xcm_weight = <Runtime as $crate::macros::XcmPaymentApiV2<_>>::query_xcm_weight(local_xcm).unwrap();
@@ -756,7 +756,7 @@ macro_rules! test_can_estimate_and_pay_exact_fees {
 						remote_message.clone().try_into().unwrap()));
 
 				// Now we get the delivery fees to the final destination.
-				let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_xcm(
+				let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_xcm(
 					sender_as_seen_by_ah.clone().into(), xcm_program).unwrap();
 				let (destination_to_query, messages_to_query) = &result
 					.forwarded_xcms
@@ -892,7 +892,7 @@ macro_rules! test_dry_run_transfer_across_pk_bridge {
 					weight_limit: $crate::macros::Unlimited,
 				});
 				let origin = OriginCaller::system($crate::macros::RawOrigin::Signed(who));
-				let result = <Runtime as $crate::macros::DryRunApiV2<_,_,_,_>>::dry_run_call(origin, call.clone(),
+				let result = <Runtime as $crate::macros::DryRunApiV3<_,_,_,_>>::dry_run_call(origin, call.clone(),
 					$crate::macros::XCM_VERSION).unwrap();
 
 				// We assert the dry run succeeds and sends only one message to the local bridge hub.
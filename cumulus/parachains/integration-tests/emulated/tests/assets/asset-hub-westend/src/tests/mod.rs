@@ -19,6 +19,7 @@ mod exchange_asset;
 mod fellowship_treasury;
 mod foreign_assets;
 mod hybrid_transfers;
+mod nfts_xcm;
 mod reserve_transfer;
 mod reward_pool;
 mod send;
@@ -0,0 +1,314 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::imports::*;
+use frame_support::traits::fungible::Mutate;
+use pallet_nfts::{AttributeNamespace, CollectionConfig, CollectionSettings, MintSettings};
+use pallet_nfts_xcm::{AttributeOwner, TransferMode};
+use westend_system_emulated_network::penpal_emulated_chain::penpal_runtime::{
+	Balances, Derivatives, ForeignAssets, Nfts, NftsXcm, Runtime as PenpalRuntime, RuntimeOrigin,
+	EXISTENTIAL_DEPOSIT,
+};
+
+const COLLECTION: u32 = 0;
+const ITEM: u32 = 1;
+const REMOTE_FEES: Balance = 1_000_000_000_000;
+
+const COLLECTION_METADATA: &[u8] = b"collection";
+const ITEM_METADATA: &[u8] = b"item";
+const COLLECTION_OWNER_ATTRIBUTE: (&[u8], &[u8]) = (b"rarity", b"legendary");
+const ITEM_OWNER_ATTRIBUTE: (&[u8], &[u8]) = (b"nickname", b"bob");
+
+fn beneficiary(who: &AccountId) -> Location {
+	AccountId32Junction { network: None, id: who.clone().into() }.into()
+}
+
+/// Lets the `sender` on `Source` pay for the execution on `Dest` in the native token of `Dest`.
+fn fund_remote_fees<Source, Dest>(sender: &AccountId)
+where
+	Source: Para<Runtime = PenpalRuntime>,
+	Dest: Para<Runtime = PenpalRuntime>,
+{
+	let dest_native = Source::sibling_location_of(Dest::para_id());
+	Source::execute_with(|| {
+		let owner = PenpalAssetOwner::get();
+		assert_ok!(ForeignAssets::force_create(
+			RuntimeOrigin::root(),
+			dest_native.clone(),
+			owner.clone().into(),
+			false,
+			ASSET_MIN_BALANCE,
+		));
+		assert_ok!(ForeignAssets::mint(
+			RuntimeOrigin::signed(owner.clone()),
+			dest_native,
+			sender.clone().into(),
+			REMOTE_FEES * 10,
+		));
+		// The delivery fees are paid in the relay token.
+		assert_ok!(ForeignAssets::mint(
+			RuntimeOrigin::signed(owner),
+			RelayLocation::get(),
+			sender.clone().into(),
+			WESTEND_ED * 10_000,
+		));
+	});
+
+	// The pallet account executing the transfer holds the fees until they are sent.
+	let pallet_account = Source::execute_with(NftsXcm::account_id);
+	Source::execute_with(|| {
+		assert_ok!(<Balances as Mutate<_>>::mint_into(&pallet_account, EXISTENTIAL_DEPOSIT));
+	});
+
+	// The fees are withdrawn from the sovereign account of `Source` on `Dest`.
+	let sovereign_of_source =
+		Dest::sovereign_account_id_of(Dest::sibling_location_of(Source::para_id()));
+	Dest::execute_with(|| {
+		assert_ok!(<Balances as Mutate<_>>::mint_into(&sovereign_of_source, REMOTE_FEES * 10));
+	});
+}
+
+/// Creates the `COLLECTION` owned by `owner` with the `ITEM` of `owner`, both having data.
+fn create_item_with_data<C: Para<Runtime = PenpalRuntime>>(owner: &AccountId) {
+	C::execute_with(|| {
+		let origin = RuntimeOrigin::signed(owner.clone());
+		assert_ok!(Nfts::create(
+			origin.clone(),
+			owner.clone().into(),
+			CollectionConfig {
+				settings: CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: MintSettings::default(),
+			},
+		));
+		assert_ok!(Nfts::set_collection_metadata(
+			origin.clone(),
+			COLLECTION,
+			COLLECTION_METADATA.to_vec().try_into().unwrap(),
+		));
+		assert_ok!(Nfts::mint(origin.clone(), COLLECTION, ITEM, owner.clone().into(), None));
+		assert_ok!(Nfts::set_metadata(
+			origin.clone(),
+			COLLECTION,
+			ITEM,
+			ITEM_METADATA.to_vec().try_into().unwrap(),
+		));
+		for (namespace, (key, value)) in [
+			(AttributeNamespace::CollectionOwner, COLLECTION_OWNER_ATTRIBUTE),
+			(AttributeNamespace::ItemOwner, ITEM_OWNER_ATTRIBUTE),
+		] {
+			assert_ok!(Nfts::set_attribute(
+				origin.clone(),
+				COLLECTION,
+				Some(ITEM),
+				namespace,
+				key.to_vec().try_into().unwrap(),
+				value.to_vec().try_into().unwrap(),
+			));
+		}
+	});
+}
+
+fn owner_of(collection: u32, item: u32) -> Option<AccountId> {
+	pallet_nfts::Item::<PenpalRuntime>::get(collection, item).map(|details| details.owner)
+}
+
+/// The attribute set by the `owner`, or received along with the item if `received`.
+fn attribute(
+	collection: u32,
+	maybe_item: Option<u32>,
+	owner: AttributeOwner,
+	key: &[u8],
+	received: bool,
+) -> Option<Vec<u8>> {
+	let (namespace, key) = match received {
+		true => (AttributeNamespace::Pallet, owner.received_key(key)),
+		false => (owner.namespace(), key.to_vec()),
+	};
+	pallet_nfts::Attribute::<PenpalRuntime>::get((
+		collection,
+		maybe_item,
+		namespace,
+		BoundedVec::try_from(key).unwrap(),
+	))
+	.map(|(value, _)| value.into_inner())
+}
+
+/// Asserts the item is owned by `owner` and carries the data set by [`create_item_with_data`],
+/// either as set by the owners or as received along with the item.
+fn assert_item_with_data(collection: u32, item: u32, owner: &AccountId, received: bool) {
+	assert_eq!(owner_of(collection, item).as_ref(), Some(owner));
+	assert_eq!(
+		pallet_nfts::ItemMetadataOf::<PenpalRuntime>::get(collection, item)
+			.map(|metadata| metadata.data.into_inner()),
+		Some(ITEM_METADATA.to_vec())
+	);
+	for (owner, (key, value)) in [
+		(AttributeOwner::CollectionOwner, COLLECTION_OWNER_ATTRIBUTE),
+		(AttributeOwner::ItemOwner, ITEM_OWNER_ATTRIBUTE),
+	] {
+		assert_eq!(attribute(collection, Some(item), owner, key, received), Some(value.to_vec()));
+	}
+}
+
+/// Asserts the derivative collection of the `COLLECTION` of the `reserve` chain exists on the
+/// current chain and carries the collection data.
+fn derivative_collection(reserve: u32) -> u32 {
+	let original = Location::new(
+		1,
+		[
+			Parachain(reserve),
+			PalletInstance(<Nfts as frame_support::traits::PalletInfoAccess>::index() as u8),
+			GeneralIndex(COLLECTION.into()),
+		],
+	);
+	let collection = Derivatives::original_to_derivative(AssetId(original))
+		.expect("the derivative collection is created");
+	assert_eq!(
+		pallet_nfts::CollectionMetadataOf::<PenpalRuntime>::get(collection)
+			.map(|metadata| metadata.data.into_inner()),
+		Some(COLLECTION_METADATA.to_vec())
+	);
+	collection
+}
+
+fn transfer_nft<Source, Dest>(
+	sender: &AccountId,
+	collection: u32,
+	item: u32,
+	receiver: &AccountId,
+	mode: TransferMode,
+) where
+	Source: Para<Runtime = PenpalRuntime>,
+	Dest: Para<Runtime = PenpalRuntime>,
+{
+	let dest = Source::sibling_location_of(Dest::para_id());
+	let fees: Asset = (dest.clone(), REMOTE_FEES).into();
+	let delivery_fees: Asset = (RelayLocation::get(), WESTEND_ED * 1_000).into();
+	Source::execute_with(|| {
+		assert_ok!(NftsXcm::transfer(
+			RuntimeOrigin::signed(sender.clone()),
+			collection,
+			item,
+			bx!(dest.into()),
+			bx!(beneficiary(receiver).into()),
+			mode,
+			bx!(fees.into()),
+			bx!(TransferType::DestinationReserve),
+			bx!(delivery_fees.into()),
+		));
+	});
+}
+
+fn transfer_nft_from_para_to_para_and_back(mode: TransferMode) {
+	let sender = PenpalASender::get();
+	let receiver = PenpalBReceiver::get();
+	let penpal_a_id: u32 = PenpalA::para_id().into();
+	let sovereign_of_b_on_a =
+		PenpalA::sovereign_account_id_of(PenpalA::sibling_location_of(PenpalB::para_id()));
+
+	create_item_with_data::<PenpalA>(&sender);
+	fund_remote_fees::<PenpalA, PenpalB>(&sender);
+	fund_remote_fees::<PenpalB, PenpalA>(&receiver);
+
+	// PenpalA -> PenpalB
+	transfer_nft::<PenpalA, PenpalB>(&sender, COLLECTION, ITEM, &receiver, mode);
+
+	PenpalA::execute_with(|| {
+		type RuntimeEvent = <PenpalA as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			PenpalA,
+			vec![
+				RuntimeEvent::NftsXcm(pallet_nfts_xcm::Event::ItemSent { .. }) => {},
+			]
+		);
+
+		match mode {
+			// The item is kept by the sovereign account of the destination.
+			TransferMode::Reserve =>
+				assert_eq!(owner_of(COLLECTION, ITEM), Some(sovereign_of_b_on_a.clone())),
+			// The item is burned.
+			TransferMode::Teleport => assert_eq!(owner_of(COLLECTION, ITEM), None),
+		}
+	});
+
+	let derivative = PenpalB::execute_with(|| {
+		type RuntimeEvent = <PenpalB as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			PenpalB,
+			vec![
+				RuntimeEvent::NftsXcm(pallet_nfts_xcm::Event::DataReceived { .. }) => {},
+				RuntimeEvent::NftsXcm(
+					pallet_nfts_xcm::Event::DerivativeCollectionCreated { .. }
+				) => {},
+				RuntimeEvent::NftsXcm(pallet_nfts_xcm::Event::ItemReceived { item, owner, .. }) => {
+					item: *item == ITEM,
+					owner: *owner == receiver,
+				},
+			]
+		);
+
+		let derivative = derivative_collection(penpal_a_id);
+		assert_item_with_data(derivative, ITEM, &receiver, true);
+		assert_eq!(pallet_nfts_xcm::PendingData::<PenpalRuntime>::iter().count(), 0);
+		derivative
+	});
+
+	// PenpalB -> PenpalA
+	transfer_nft::<PenpalB, PenpalA>(&receiver, derivative, ITEM, &sender, mode);
+
+	PenpalB::execute_with(|| {
+		type RuntimeEvent = <PenpalB as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			PenpalB,
+			vec![
+				RuntimeEvent::NftsXcm(pallet_nfts_xcm::Event::ItemSent { .. }) => {},
+			]
+		);
+
+		// The derivative is burned with its data, the derivative collection stays.
+		assert_eq!(owner_of(derivative, ITEM), None);
+		assert_eq!(
+			attribute(
+				derivative,
+				Some(ITEM),
+				AttributeOwner::ItemOwner,
+				ITEM_OWNER_ATTRIBUTE.0,
+				true
+			),
+			None
+		);
+		assert_eq!(derivative_collection(penpal_a_id), derivative);
+	});
+
+	PenpalA::execute_with(|| {
+		// The original is released as is, or minted again with the data it carries.
+		assert_item_with_data(COLLECTION, ITEM, &sender, mode == TransferMode::Teleport);
+		assert_eq!(pallet_nfts_xcm::PendingData::<PenpalRuntime>::iter().count(), 0);
+	});
+}
+
+/// The item is kept by the sovereign account of PenpalB on PenpalA while PenpalB holds a derivative
+/// carrying its data.
+#[test]
+fn reserve_transfer_nft_from_para_to_para_and_back() {
+	transfer_nft_from_para_to_para_and_back(TransferMode::Reserve)
+}
+
+/// The item is burned on PenpalA and minted with its data on PenpalB, then the other way around.
+#[test]
+fn teleport_nft_from_para_to_para_and_back() {
+	transfer_nft_from_para_to_para_and_back(TransferMode::Teleport)
+}
@@ -20,7 +20,7 @@ use crate::{create_pool_with_wnd_on, imports::*};
 use emulated_integration_tests_common::test_can_estimate_and_pay_exact_fees;
 use frame_support::dispatch::RawOrigin;
 use xcm_runtime_apis::{
-	dry_run::runtime_decl_for_dry_run_api::DryRunApiV2,
+	dry_run::runtime_decl_for_dry_run_api::DryRunApiV3,
 	fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV2,
 };
 
@@ -240,6 +240,7 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The `AuthoredBlocks` and `SessionBlocks` accesses are not benchmarked yet.
 	fn note_author() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `103`
@@ -249,6 +250,7 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 			.saturating_add(Weight::from_parts(0, 6196))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 	}
 	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
@@ -262,6 +264,7 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 100]`.
 	/// The range of component `c` is `[1, 100]`.
+	/// The `SessionCollators` write is not benchmarked yet.
 	fn new_session(r: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2214 + c * (97 ±0) + r * (112 ±0)`
@@ -276,5 +279,52 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Not benchmarked yet, bounded by `new_session`, which reads and writes as many entries per
+	/// collator, and the per-session storage accesses.
+	fn end_session(c: u32) -> Weight {
+		<Self as pallet_collator_selection::WeightInfo>::new_session(0, c)
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn delegate(c: u32) -> Weight {
+		<Self as pallet_collator_selection::WeightInfo>::update_bond(c)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn undelegate() -> Weight {
+		<Self as pallet_collator_selection::WeightInfo>::update_bond(1)
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+	/// Not benchmarked yet, bounded by `update_bond` and the delegation storage accesses.
+	fn withdraw_unbonded() -> Weight {
+		<Self as pallet_collator_selection::WeightInfo>::update_bond(1)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	/// Not benchmarked yet, bounded by repositioning and removing the offender and one reward
+	/// payout per slashed account.
+	fn report_equivocation(d: u32, c: u32) -> Weight {
+		<Self as pallet_collator_selection::WeightInfo>::update_bond(c)
+			.saturating_add(<Self as pallet_collator_selection::WeightInfo>::leave_intent(c))
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+			.saturating_add(
+				<Self as pallet_collator_selection::WeightInfo>::note_author()
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_mul(d as u64),
+			)
+	}
+	/// Not benchmarked yet, bounded by reading the candidate list like `update_bond` and one
+	/// reward payout per delegator.
+	fn reward_delegators(d: u32, c: u32) -> Weight {
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(
+				<Self as pallet_collator_selection::WeightInfo>::update_bond(c)
+					.saturating_mul(d.min(1) as u64),
+			)
+			.saturating_add(
+				<Self as pallet_collator_selection::WeightInfo>::note_author().saturating_mul(d as u64),
+			)
 	}
 }
@@ -428,7 +428,12 @@ pub type PriceForParentDelivery =
 /// For routing XCM messages which do not cross local consensus boundary.
 type LocalXcmRouter = (
 	// Two routers - use UMP to communicate with the relay chain:
-	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, PriceForParentDelivery>,
+	cumulus_primitives_utility::ParentAsUmp<
+		ParachainSystem,
+		PolkadotXcm,
+		PriceForParentDelivery,
+		PolkadotXcm,
+	>,
 	// ..and XCMP to communicate with the sibling chains.
 	XcmpQueue,
 );
@@ -481,6 +486,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = ();
+	type TrappedAssetsBeneficiary = ();
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
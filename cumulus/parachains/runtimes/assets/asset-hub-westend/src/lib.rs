@@ -120,7 +120,10 @@ use xcm::{
 	},
 };
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, CallDryRunEffectsWithTrace, Error as XcmDryRunApiError, XcmDryRunEffects,
+		XcmDryRunEffectsWithTrace,
+	},
 	fees::Error as XcmPaymentApiError,
 };
 
@@ -930,11 +933,13 @@ impl pallet_message_queue::Config for Runtime {
 		AggregateMessageOrigin,
 		xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
 		RuntimeCall,
+		PolkadotXcm,
 	>;
 	type Size = u32;
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -970,6 +975,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type MaxPageSize = ConstU32<{ 103 * 1024 }>;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
+	type MessageStatusRecorder = PolkadotXcm;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
 }
@@ -1015,6 +1021,8 @@ impl pallet_aura::Config for Runtime {
 
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
+	pub const CollatorEquivocationSlash: Perbill = Perbill::from_percent(10);
+	pub const CollatorUnbondingPeriod: BlockNumber = 7 * DAYS;
 	pub const SessionLength: BlockNumber = 6 * HOURS;
 }
 
@@ -1033,6 +1041,13 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type MaxDelegators = ConstU32<0>;
+	type MinDelegation = ExistentialDeposit;
+	type CandidateScore = ();
+	type EquivocationReportOrigin = CollatorSelectionUpdateOrigin;
+	type EquivocationSlash = CollatorEquivocationSlash;
+	type OnSlash = ();
+	type UnbondingPeriod = CollatorUnbondingPeriod;
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -1968,6 +1983,13 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_message_queue::runtime_api::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn scheduling_state() -> Vec<pallet_message_queue::QueueSchedulingInfo<AggregateMessageOrigin>> {
+			MessageQueue::scheduling_state()
+		}
+	}
+
+	#[api_version(3)]
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
@@ -1976,6 +1998,14 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_xcm::<xcm_config::XcmRouter>(origin_location, xcm)
 		}
+
+		fn dry_run_xcm_with_trace(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffectsWithTrace<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm_with_trace::<xcm_config::XcmRouter>(origin_location, xcm)
+		}
+
+		fn dry_run_call_with_trace(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffectsWithTrace<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_call_with_trace::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
 	}
 
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
@@ -1999,6 +2029,18 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl xcm_runtime_apis::message_status::XcmMessageStatusApi<Block, BlockNumber> for Runtime {
+		fn message_status(topic: [u8; 32]) -> Result<Vec<(BlockNumber, xcm_runtime_apis::message_status::MessageStatus)>, xcm_runtime_apis::message_status::Error> {
+			PolkadotXcm::message_status(topic)
+		}
+	}
+
+	impl xcm_runtime_apis::trapped_assets::TrappedAssetsApi<Block, BlockNumber> for Runtime {
+		fn trapped_assets(origin: Option<VersionedLocation>, start: u64, limit: u32) -> Result<xcm_runtime_apis::trapped_assets::TrappedAssetsPage<BlockNumber>, xcm_runtime_apis::trapped_assets::Error> {
+			PolkadotXcm::trapped_assets(origin, start, limit)
+		}
+	}
+
 	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
 		fn authorized_aliasers(target: VersionedLocation) -> Result<
 			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
@@ -2610,6 +2652,65 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 					Err(BenchmarkError::Skip)
 				}
 
+				fn unlockable_fungibles_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+					let (account, owner) = pallet_xcm_benchmarks::account_and_location::<Runtime>(1);
+					let asset_id = 1u32;
+					let amount = 1_000 * UNITS;
+
+					assert_ok!(<Balances as fungible::Mutate<_>>::mint_into(
+						&account,
+						ExistentialDeposit::get() + (1_000 * UNITS)
+					));
+					assert_ok!(Assets::force_create(
+						RuntimeOrigin::root(),
+						asset_id.into(),
+						account.clone().into(),
+						true,
+						1,
+					));
+					assert_ok!(Assets::mint(
+						RuntimeOrigin::signed(account.clone()),
+						asset_id.into(),
+						account.into(),
+						amount,
+					));
+
+					let asset_location = Location::new(
+						0,
+						[PalletInstance(xcm_config::TrustBackedAssetsPalletIndex::get()), GeneralIndex(asset_id.into())],
+					);
+					Ok((PeopleLocation::get(), owner, (asset_location, amount).into()))
+				}
+
+				fn unlockable_non_fungible_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+					use frame_support::traits::tokens::nonfungibles_v2::{Create, Mutate};
+
+					let (account, owner) = pallet_xcm_benchmarks::account_and_location::<Runtime>(1);
+					let item = 0u32;
+
+					assert_ok!(<Balances as fungible::Mutate<_>>::mint_into(
+						&account,
+						ExistentialDeposit::get() + (1_000 * UNITS)
+					));
+					let collection = <Nfts as Create<_, _>>::create_collection(
+						&account,
+						&account,
+						&Default::default(),
+					)
+					.map_err(|_| BenchmarkError::Stop("Failed to create an NFT collection"))?;
+					assert_ok!(<Nfts as Mutate<_, _>>::mint_into(
+						&collection,
+						&item,
+						&account,
+						&Default::default(),
+						true,
+					));
+
+					let asset_location =
+						Location::new(0, [PalletInstance(<Nfts as PalletInfoAccess>::index() as u8), GeneralIndex(collection.into())]);
+					Ok((PeopleLocation::get(), owner, (asset_location, AssetInstance::Index(item.into())).into()))
+				}
+
 				fn export_message_origin_and_destination(
 				) -> Result<(Location, NetworkId, InteriorLocation), BenchmarkError> {
 					Err(BenchmarkError::Skip)
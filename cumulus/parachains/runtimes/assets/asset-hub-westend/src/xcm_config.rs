@@ -14,11 +14,12 @@
 // limitations under the License.
 
 use super::{
-	governance::TreasuryAccount, AccountId, AllPalletsWithSystem, Assets, Balance, Balances,
-	BaseDeliveryFee, CollatorSelection, DepositPerByte, DepositPerItem, FeeAssetId,
-	FellowshipAdmin, ForeignAssets, GeneralAdmin, ParachainInfo, ParachainSystem, PolkadotXcm,
-	PoolAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, StakingAdmin,
-	ToRococoXcmRouter, TransactionByteFee, Treasurer, Uniques, WeightToFee, XcmpQueue,
+	governance::TreasuryAccount, AccountId, AllPalletsWithSystem, Assets, AssetsFreezer, Balance,
+	Balances, BaseDeliveryFee, CollatorSelection, DepositPerByte, DepositPerItem, FeeAssetId,
+	FellowshipAdmin, ForeignAssets, GeneralAdmin, Nfts, ParachainInfo, ParachainSystem,
+	PolkadotXcm, PoolAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+	RuntimeHoldReason, RuntimeOrigin, StakingAdmin, ToRococoXcmRouter, TransactionByteFee,
+	Treasurer, Uniques, WeightToFee, XcmpQueue,
 };
 use alloc::{collections::BTreeSet, vec, vec::Vec};
 use assets_common::{
@@ -39,8 +40,11 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::{AuthorizedAliasers, XcmPassthrough};
-use parachains_common::xcm_config::{
-	AllSiblingSystemParachains, ConcreteAssetFromSystem, RelayOrOtherSystemParachains,
+use parachains_common::{
+	xcm_config::{
+		AllSiblingSystemParachains, ConcreteAssetFromSystem, RelayOrOtherSystemParachains,
+	},
+	BlockNumber, DAYS,
 };
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::ExponentialPrice;
@@ -85,6 +89,8 @@ parameter_types! {
 		PalletInstance(<PoolAssets as PalletInfoAccess>::index() as u8).into();
 	pub UniquesPalletLocation: Location =
 		PalletInstance(<Uniques as PalletInfoAccess>::index() as u8).into();
+	pub NftsPalletLocation: Location =
+		PalletInstance(<Nfts as PalletInfoAccess>::index() as u8).into();
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 	pub StakingPot: AccountId = CollatorSelection::account_id();
 	pub RelayTreasuryLocation: Location = (Parent, PalletInstance(westend_runtime_constants::TREASURY_PALLET_ID)).into();
@@ -155,6 +161,9 @@ pub type UniquesTransactor = UniqueInstancesAdapter<
 	pallet_uniques::asset_ops::Item<Uniques>,
 >;
 
+/// Matcher for converting `CollectionId`/`ItemId` into an NFTs asset.
+pub type NftsConvertedConcreteId = assets_common::UniquesConvertedConcreteId<NftsPalletLocation>;
+
 /// `AssetId`/`Balance` converter for `ForeignAssets`.
 pub type ForeignAssetsConvertedConcreteId = assets_common::ForeignAssetsConvertedConcreteId<
 	(
@@ -386,6 +395,22 @@ pub type TrustedTeleporters = (
 /// - Allow origins explicitly authorized by the alias target location.
 pub type TrustedAliasers = (AliasChildLocation, AuthorizedAliasers<Runtime>);
 
+parameter_types! {
+	pub const XcmLockFreezeId: RuntimeFreezeReason =
+		RuntimeFreezeReason::PolkadotXcm(pallet_xcm::FreezeReason::XcmLock);
+}
+
+/// Locks `TrustBackedAssets` by freezing them and NFTs by disabling their transfer, on behalf of
+/// remote unlockers.
+pub type AssetLocker = pallet_xcm::AssetLockAdapter<
+	Runtime,
+	AssetsFreezer,
+	TrustBackedAssetsConvertedConcreteId,
+	XcmLockFreezeId,
+	Nfts,
+	NftsConvertedConcreteId,
+>;
+
 /// Asset converter for pool assets.
 /// Used to convert one asset to another, when there is a pool available between the two.
 /// This type thus allows paying fees with any asset as long as there is a pool between said
@@ -455,7 +480,7 @@ impl xcm_executor::Config for XcmConfig {
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
-	type AssetLocker = ();
+	type AssetLocker = AssetLocker;
 	type AssetExchanger = PoolAssetsExchanger;
 	type FeeManager = XcmFeeManagerFromComponents<
 		WaivedLocations,
@@ -523,7 +548,12 @@ pub type PriceForParentDelivery =
 /// For routing XCM messages which do not cross local consensus boundary.
 type LocalXcmRouter = (
 	// Two routers - use UMP to communicate with the relay chain:
-	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, PriceForParentDelivery>,
+	cumulus_primitives_utility::ParentAsUmp<
+		ParachainSystem,
+		PolkadotXcm,
+		PriceForParentDelivery,
+		PolkadotXcm,
+	>,
 	// ..and XCMP to communicate with the sibling chains.
 	XcmpQueue,
 );
@@ -555,6 +585,8 @@ pub type XcmRouter = WithUniqueTopic<(
 parameter_types! {
 	pub Collectives: Location = Location::new(1, [Parachain(COLLECTIVES_ID)]);
 	pub const AuthorizeAliasHoldReason: RuntimeHoldReason = RuntimeHoldReason::PolkadotXcm(pallet_xcm::HoldReason::AuthorizeAlias);
+	/// Assets left in an asset trap for this long are deposited to the account of their origin.
+	pub const TrappedAssetsExpiry: Option<BlockNumber> = Some(30 * DAYS);
 }
 
 impl pallet_xcm::Config for Runtime {
@@ -585,6 +617,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = TrappedAssetsExpiry;
+	type TrappedAssetsBeneficiary = pallet_xcm::TrappedAssetsToOrigin;
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
@@ -22,8 +22,8 @@
 use crate::{
 	bridge_common_config::RelayersForPermissionlessLanesInstance, weights,
 	xcm_config::UniversalLocation, AccountId, Balance, Balances, BridgeRococoBulletinGrandpa,
-	BridgeRococoBulletinMessages, Runtime, RuntimeEvent, RuntimeHoldReason, XcmOverRococoBulletin,
-	XcmRouter,
+	BridgeRococoBulletinMessages, PolkadotXcm, Runtime, RuntimeEvent, RuntimeHoldReason,
+	XcmOverRococoBulletin, XcmRouter,
 };
 use bp_messages::{
 	source_chain::FromBridgedChainMessagesDeliveryProof,
@@ -155,6 +155,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverPolkadotBulletinInstance> for Runtime
 
 	type LocalXcmChannelManager = ();
 	type BlobDispatcher = FromRococoBulletinMessageBlobDispatcher;
+	type MessageStatusRecorder = PolkadotXcm;
 }
 
 #[cfg(test)]
@@ -158,6 +158,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubWestendInstance> for Runtime
 
 	type LocalXcmChannelManager = CongestionManager;
 	type BlobDispatcher = FromWestendMessageBlobDispatcher;
+	type MessageStatusRecorder = PolkadotXcm;
 }
 
 /// Implementation of `bp_xcm_bridge_hub::LocalXcmChannelManager` for congestion management.
@@ -187,6 +187,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubRococoInstance> for Runtime {
 
 	type LocalXcmChannelManager = CongestionManager;
 	type BlobDispatcher = FromRococoMessageBlobDispatcher;
+	type MessageStatusRecorder = PolkadotXcm;
 }
 
 /// Implementation of `bp_xcm_bridge_hub::LocalXcmChannelManager` for congestion management.
@@ -78,7 +78,10 @@ pub use sp_runtime::{MultiAddress, Perbill, Permill};
 use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin, XcmRouter};
 
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, CallDryRunEffectsWithTrace, Error as XcmDryRunApiError, XcmDryRunEffects,
+		XcmDryRunEffectsWithTrace,
+	},
 	fees::Error as XcmPaymentApiError,
 };
 
@@ -354,7 +357,7 @@ impl pallet_balances::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type FreezeIdentifier = ();
-	type MaxFreezes = ConstU32<0>;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 	type DoneSlashHandler = ();
 }
 
@@ -423,6 +426,7 @@ impl pallet_message_queue::Config for Runtime {
 			AggregateMessageOrigin,
 			xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
 			RuntimeCall,
+			PolkadotXcm,
 		>,
 		EthereumOutboundQueue,
 		EthereumOutboundQueueV2,
@@ -431,6 +435,7 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -465,6 +470,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type MaxPageSize = ConstU32<{ 103 * 1024 }>;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
+	type MessageStatusRecorder = PolkadotXcm;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
 }
@@ -508,6 +514,8 @@ impl pallet_aura::Config for Runtime {
 
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
+	pub const CollatorEquivocationSlash: Perbill = Perbill::from_percent(10);
+	pub const CollatorUnbondingPeriod: BlockNumber = 7 * DAYS;
 	pub const SessionLength: BlockNumber = 6 * HOURS;
 }
 
@@ -526,6 +534,13 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type MaxDelegators = ConstU32<0>;
+	type MinDelegation = ExistentialDeposit;
+	type CandidateScore = ();
+	type EquivocationReportOrigin = CollatorSelectionUpdateOrigin;
+	type EquivocationSlash = CollatorEquivocationSlash;
+	type OnSlash = ();
+	type UnbondingPeriod = CollatorUnbondingPeriod;
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -841,6 +856,13 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_message_queue::runtime_api::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn scheduling_state() -> Vec<pallet_message_queue::QueueSchedulingInfo<AggregateMessageOrigin>> {
+			MessageQueue::scheduling_state()
+		}
+	}
+
+	#[api_version(3)]
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
@@ -849,6 +871,14 @@ impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_xcm::<xcm_config::XcmRouter>(origin_location, xcm)
 		}
+
+		fn dry_run_xcm_with_trace(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffectsWithTrace<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm_with_trace::<xcm_config::XcmRouter>(origin_location, xcm)
+		}
+
+		fn dry_run_call_with_trace(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffectsWithTrace<RuntimeEvent>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_call_with_trace::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
 	}
 
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
@@ -872,6 +902,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::message_status::XcmMessageStatusApi<Block, BlockNumber> for Runtime {
+		fn message_status(topic: [u8; 32]) -> Result<Vec<(BlockNumber, xcm_runtime_apis::message_status::MessageStatus)>, xcm_runtime_apis::message_status::Error> {
+			PolkadotXcm::message_status(topic)
+		}
+	}
+
+	impl xcm_runtime_apis::trapped_assets::TrappedAssetsApi<Block, BlockNumber> for Runtime {
+		fn trapped_assets(origin: Option<VersionedLocation>, start: u64, limit: u32) -> Result<xcm_runtime_apis::trapped_assets::TrappedAssetsPage<BlockNumber>, xcm_runtime_apis::trapped_assets::Error> {
+			PolkadotXcm::trapped_assets(origin, start, limit)
+		}
+	}
+
 	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
 		fn authorized_aliasers(target: VersionedLocation) -> Result<
 			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
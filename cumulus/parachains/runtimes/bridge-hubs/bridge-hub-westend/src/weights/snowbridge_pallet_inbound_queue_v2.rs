@@ -68,4 +68,10 @@ impl<T: frame_system::Config> snowbridge_pallet_inbound_queue_v2::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Not benchmarked yet, bounded by `submit` plus the verification of the two merkle
+	/// branches of the historical summary proof.
+	fn submit_with_historical_proof() -> Weight {
+		<Self as snowbridge_pallet_inbound_queue_v2::WeightInfo>::submit()
+			.saturating_add(Weight::from_parts(10_000_000, 0))
+	}
 }
@@ -215,6 +215,7 @@ impl pallet_message_queue::Config for Runtime {
 	type QueueChangeHandler = ();
 	// No XCMP queue pallet deployed.
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -36,7 +36,7 @@
 //! soon.
 use super::{
 	AccountId, AllPalletsWithSystem, AssetId as AssetIdPalletAssets, Assets, Authorship, Balance,
-	Balances, CollatorSelection, ForeignAssets, ForeignAssetsInstance, NonZeroIssuance,
+	Balances, CollatorSelection, ForeignAssets, ForeignAssetsInstance, Nfts, NonZeroIssuance,
 	ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
 	RuntimeHoldReason, RuntimeOrigin, WeightToFee, XcmpQueue,
 };
@@ -195,8 +195,12 @@ pub type ForeignFungiblesTransactor = FungiblesAdapter<
 	CheckingAccount,
 >;
 
+/// Means for transacting the `pallet-nfts` items sent with their data by `pallet-nfts-xcm`.
+pub type NftsTransactor = pallet_nfts_xcm::NftsXcmAdapter<Runtime, (), LocationToAccountId>;
+
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = (FungibleTransactor, ForeignFungiblesTransactor, FungiblesTransactor);
+pub type AssetTransactors =
+	(FungibleTransactor, ForeignFungiblesTransactor, FungiblesTransactor, NftsTransactor);
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
@@ -298,6 +302,8 @@ parameter_types! {
 		Location::new(1, [Parachain(ASSET_HUB_ID), PalletInstance(ASSETS_PALLET_ID)]);
 	pub AssetsPalletLocation: Location =
 		Location::new(0, [PalletInstance(ASSETS_PALLET_ID)]);
+	pub NftsPalletIndex: u8 = <Nfts as PalletInfoAccess>::index() as u8;
+	pub NftsPalletLocation: Location = PalletInstance(NftsPalletIndex::get()).into();
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 	pub LocalReservableFromAssetHub: Location = Location::new(
 		1,
@@ -336,11 +342,44 @@ impl<AssetLocation: Get<Location>, Origin: Get<Location>> ContainsPair<Asset, Lo
 	}
 }
 
+/// Accepts the `pallet-nfts` items of a sibling chain coming from that chain.
+///
+/// The sibling Penpal chains share the runtime, so their `pallet-nfts` index is the same as here.
+pub struct NftsFromSiblingReserve;
+impl ContainsPair<Asset, Location> for NftsFromSiblingReserve {
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		let (1, [Parachain(origin_para)]) = origin.unpack() else { return false };
+		let Asset { id: AssetId(id), fun: NonFungible(_) } = asset else { return false };
+		matches!(
+			id.unpack(),
+			(1, [Parachain(para), PalletInstance(index), GeneralIndex(_)])
+				if para == origin_para && *index == NftsPalletIndex::get()
+		)
+	}
+}
+
+/// Accepts the local `pallet-nfts` items teleported back from a sibling chain.
+pub struct LocalNftsFromSiblings;
+impl ContainsPair<Asset, Location> for LocalNftsFromSiblings {
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		let (1, [Parachain(_)]) = origin.unpack() else { return false };
+		let Asset { id: AssetId(id), fun: NonFungible(_) } = asset else { return false };
+		matches!(
+			id.unpack(),
+			(0, [PalletInstance(index), GeneralIndex(_)]) if *index == NftsPalletIndex::get()
+		)
+	}
+}
+
+/// The chains trusted to send the `pallet-nfts` items along with their data.
+pub type TrustedNftsSources = (NftsFromSiblingReserve, LocalNftsFromSiblings);
+
 pub type TrustedReserves = (
 	NativeAsset,
 	ConcreteAssetFromSystem<RelayLocation>,
 	AssetsFrom<SystemAssetHubLocation>,
 	AssetPrefixFrom<CustomizableAssetFromSystemAssetHub, SystemAssetHubLocation>,
+	NftsFromSiblingReserve,
 );
 
 pub type TrustedTeleporters = (
@@ -348,6 +387,8 @@ pub type TrustedTeleporters = (
 	// This is used in the `IsTeleporter` configuration, meaning it accepts
 	// native tokens teleported from Asset Hub.
 	xcm_builder::Case<AssetHubTrustedTeleporter>,
+	// The `pallet-nfts` items are teleported between the sibling Penpal chains.
+	TrustedNftsSources,
 );
 
 /// Defines origin aliasing rules for this chain.
@@ -463,7 +504,12 @@ pub type PriceForParentDelivery =
 /// queues.
 pub type XcmRouter = WithUniqueTopic<(
 	// Two routers - use UMP to communicate with the relay chain:
-	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, PriceForParentDelivery>,
+	cumulus_primitives_utility::ParentAsUmp<
+		ParachainSystem,
+		PolkadotXcm,
+		PriceForParentDelivery,
+		PolkadotXcm,
+	>,
 	// ..and XCMP to communicate with the sibling chains.
 	XcmpQueue,
 )>;
@@ -500,6 +546,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = ();
+	type TrappedAssetsBeneficiary = ();
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
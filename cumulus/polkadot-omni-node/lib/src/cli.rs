@@ -25,6 +25,7 @@ use crate::{
 		chain_spec::{Extensions, LoadSpec},
 		NodeExtraArgs,
 	},
+	nodes::fork::{Dispatch, ForkParams},
 };
 use chain_spec_builder::ChainSpecBuilder;
 use clap::{Command, CommandFactory, FromArgMatches, ValueEnum};
@@ -136,6 +137,72 @@ pub enum Subcommand {
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[command(subcommand)]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Start a dev node on top of a state snapshot of a live parachain.
+	///
+	/// The snapshot becomes the finalized best block of the node, keeping the block number of the
+	/// live chain. New blocks are sealed locally, with mocked relay chain validation data, so the
+	/// relay chain is not needed.
+	Fork(ForkCmd),
+}
+
+/// The `fork` command.
+#[derive(Debug, clap::Parser)]
+pub struct ForkCmd {
+	/// The state snapshot to fork from, as created by `try-runtime create-snapshot`.
+	///
+	/// The snapshot is only imported into an empty database. If the database already contains
+	/// the fork, the node continues from its best block.
+	#[arg(long, value_name = "PATH")]
+	pub snapshot: PathBuf,
+
+	/// A JSON file with storage overrides applied on top of the snapshot.
+	///
+	/// The file contains an object mapping storage keys to hex encoded SCALE values, or to `null`
+	/// to remove the value. A key is either a hex encoded raw storage key or a storage item name
+	/// like `Sudo.Key`. Storage maps like `System.Account` map to a list of `[key, value]`
+	/// entries, where the key is the hex encoded SCALE map key, or a list of them for maps with
+	/// several keys. Only the top level storage can be overridden.
+	#[arg(long, value_name = "PATH")]
+	pub storage_overrides: Option<PathBuf>,
+
+	/// Dispatch a call in the first block on top of the snapshot.
+	///
+	/// The call is hex encoded, optionally prefixed by the hex encoded `OriginCaller` of the
+	/// runtime and `:`, like a collective or a referenda track origin. The origin defaults to
+	/// `Root`. The calls are dispatched by the scheduler pallet, the runtime doesn't need the sudo
+	/// pallet. Can be given multiple times.
+	#[arg(long = "dispatch", value_name = "[ORIGIN:]CALL")]
+	pub dispatches: Vec<Dispatch>,
+
+	/// Accept mocked signatures for any account.
+	///
+	/// A mocked signature starts with `0xdeadbeef` and is filled with `0xcd` bytes up to the
+	/// signature length. It makes it possible to submit transactions on behalf of any `sr25519` or
+	/// `ed25519` account.
+	#[arg(long)]
+	pub mock_signatures: bool,
+
+	/// Produce a block each `dev_block_time` ms instead of a block per transaction.
+	#[arg(long)]
+	pub dev_block_time: Option<u64>,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub run: sc_cli::RunCmd,
+}
+
+impl ForkCmd {
+	/// Returns the parameters of the forked node.
+	pub(crate) fn fork_params(&self) -> ForkParams {
+		ForkParams {
+			snapshot: self.snapshot.clone(),
+			storage_overrides: self.storage_overrides.clone(),
+			dispatches: self.dispatches.clone(),
+			mock_signatures: self.mock_signatures,
+			mode: self.dev_block_time.map_or(DevSealMode::InstantSeal, DevSealMode::ManualSeal),
+		}
+	}
 }
 
 /// CLI Options shipped with `polkadot-omni-node`.
@@ -212,6 +279,13 @@ pub struct Cli<Config: CliConfig> {
 	#[arg(long)]
 	pub export_pov_to_path: Option<PathBuf>,
 
+	/// Read `PoVs` that can not be recovered from the relay chain from the given folder.
+	///
+	/// The folder is expected to have the layout written by `--export-pov-to-path`. The `PoVs` are
+	/// also served to other nodes of the network.
+	#[arg(long)]
+	pub pov_archive_path: Option<PathBuf>,
+
 	/// Relay chain arguments
 	#[arg(raw = true)]
 	pub relay_chain_args: Vec<String>,
@@ -266,6 +340,7 @@ impl<Config: CliConfig> Cli<Config> {
 				.then(|| AuthoringPolicy::SlotBased)
 				.unwrap_or(self.authoring),
 			export_pov: self.export_pov_to_path.clone(),
+			pov_archive: self.pov_archive_path.clone(),
 			max_pov_percentage: self.run.experimental_max_pov_percentage,
 			enable_statement_store: self.enable_statement_store,
 			storage_monitor: self.storage_monitor.clone(),
@@ -20,7 +20,7 @@ use crate::{
 		chain_spec::LoadSpec,
 		runtime::{
 			AuraConsensusId, Consensus, Runtime, RuntimeResolver as RuntimeResolverT,
-			RuntimeResolver,
+			RuntimeResolver, SolochainConsensus,
 		},
 		spec::DynNodeSpec,
 		types::Block,
@@ -28,6 +28,7 @@ use crate::{
 	},
 	extra_subcommand::DefaultExtraSubcommands,
 	fake_runtime_api,
+	nodes::fork::ForkParams,
 	runtime::BlockNumber,
 };
 use clap::{CommandFactory, FromArgMatches};
@@ -79,6 +80,48 @@ where
 	}
 }
 
+pub fn new_solochain_node_spec(
+	block_number: BlockNumber,
+	consensus: SolochainConsensus,
+) -> Box<dyn DynNodeSpec> {
+	use crate::nodes::solochain::{new_solochain_aura_node_spec, new_solochain_babe_node_spec};
+
+	match (block_number, consensus) {
+		(BlockNumber::U32, SolochainConsensus::Aura(AuraConsensusId::Sr25519)) =>
+			new_solochain_aura_node_spec::<
+				Block<u32>,
+				fake_runtime_api::solochain_sr25519_u32::RuntimeApi,
+				sp_consensus_aura::sr25519::AuthorityId,
+			>(),
+		(BlockNumber::U64, SolochainConsensus::Aura(AuraConsensusId::Sr25519)) =>
+			new_solochain_aura_node_spec::<
+				Block<u64>,
+				fake_runtime_api::solochain_sr25519_u64::RuntimeApi,
+				sp_consensus_aura::sr25519::AuthorityId,
+			>(),
+		(BlockNumber::U32, SolochainConsensus::Aura(AuraConsensusId::Ed25519)) =>
+			new_solochain_aura_node_spec::<
+				Block<u32>,
+				fake_runtime_api::solochain_ed25519_u32::RuntimeApi,
+				sp_consensus_aura::ed25519::AuthorityId,
+			>(),
+		(BlockNumber::U64, SolochainConsensus::Aura(AuraConsensusId::Ed25519)) =>
+			new_solochain_aura_node_spec::<
+				Block<u64>,
+				fake_runtime_api::solochain_ed25519_u64::RuntimeApi,
+				sp_consensus_aura::ed25519::AuthorityId,
+			>(),
+		(BlockNumber::U32, SolochainConsensus::Babe) => new_solochain_babe_node_spec::<
+			Block<u32>,
+			fake_runtime_api::solochain_sr25519_u32::RuntimeApi,
+		>(),
+		(BlockNumber::U64, SolochainConsensus::Babe) => new_solochain_babe_node_spec::<
+			Block<u64>,
+			fake_runtime_api::solochain_sr25519_u64::RuntimeApi,
+		>(),
+	}
+}
+
 fn new_node_spec(
 	config: &sc_service::Configuration,
 	runtime_resolver: &Box<dyn RuntimeResolverT>,
@@ -93,9 +136,56 @@ fn new_node_spec(
 			(BlockNumber::U64, Consensus::Aura(aura_id)) =>
 				new_aura_node_spec::<Block<u64>>(aura_id, extra_args),
 		},
+		Runtime::Solochain(block_number, consensus) =>
+			new_solochain_node_spec(block_number, consensus),
 	})
 }
 
+async fn start_fork_node(
+	config: sc_service::Configuration,
+	runtime_resolver: &Box<dyn RuntimeResolverT>,
+	params: ForkParams,
+) -> std::result::Result<sc_service::TaskManager, sc_cli::Error> {
+	use crate::nodes::fork::start_fork_node;
+
+	let runtime = runtime_resolver.runtime(config.chain_spec.as_ref())?;
+
+	let task_manager = match runtime {
+		Runtime::Omni(BlockNumber::U32, Consensus::Aura(AuraConsensusId::Sr25519)) =>
+			start_fork_node::<
+				Block<u32>,
+				fake_runtime_api::aura_sr25519::RuntimeApi,
+				sp_consensus_aura::sr25519::AuthorityId,
+			>(config, params)
+			.await?,
+		Runtime::Omni(BlockNumber::U64, Consensus::Aura(AuraConsensusId::Sr25519)) =>
+			start_fork_node::<
+				Block<u64>,
+				fake_runtime_api::aura_sr25519::RuntimeApi,
+				sp_consensus_aura::sr25519::AuthorityId,
+			>(config, params)
+			.await?,
+		Runtime::Omni(BlockNumber::U32, Consensus::Aura(AuraConsensusId::Ed25519)) =>
+			start_fork_node::<
+				Block<u32>,
+				fake_runtime_api::aura_ed25519::RuntimeApi,
+				sp_consensus_aura::ed25519::AuthorityId,
+			>(config, params)
+			.await?,
+		Runtime::Omni(BlockNumber::U64, Consensus::Aura(AuraConsensusId::Ed25519)) =>
+			start_fork_node::<
+				Block<u64>,
+				fake_runtime_api::aura_ed25519::RuntimeApi,
+				sp_consensus_aura::ed25519::AuthorityId,
+			>(config, params)
+			.await?,
+		Runtime::Solochain(..) =>
+			return Err("The `fork` subcommand only supports parachain runtimes".into()),
+	};
+
+	Ok(task_manager)
+}
+
 /// Parse command line arguments into service configuration.
 pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()> {
 	run_with_custom_cli::<CliConfig, DefaultExtraSubcommands>(cmd_config)
@@ -283,6 +373,12 @@ where
 			}
 		},
 		Some(Subcommand::Key(cmd)) => Ok(cmd.run(&cli)?),
+		Some(Subcommand::Fork(cmd)) => {
+			let runner = cli.create_runner(&cmd.run)?;
+			runner.run_node_until_exit(|config| async move {
+				start_fork_node(config, &cmd_config.runtime_resolver, cmd.fork_params()).await
+			})
+		},
 		None => {
 			let runner = cli.create_runner(&cli.run.normalize())?;
 			let polkadot_cli =
@@ -355,6 +451,19 @@ where
 						})
 					})
 					.flatten();
+
+				if node_spec.is_solochain() {
+					info!(
+						"✍️ Is authoring: {}",
+						if config.role.is_authority() { "yes" } else { "no" }
+					);
+
+					return node_spec
+						.start_solochain_node(config, hwbench, cli.node_extra_args())
+						.await
+						.map_err(Into::into)
+				}
+
 				let tokio_handle = config.tokio_handle.clone();
 				let polkadot_config =
 					SubstrateCli::create_configuration(&polkadot_cli, &polkadot_cli, tokio_handle)
@@ -42,7 +42,9 @@ pub struct Extensions {
 	/// The relay chain of the Parachain. It is kept here only for compatibility reasons until
 	/// people migrate to using the new `Extensions` struct and associated logic in the node
 	/// corresponding to pulling the parachain id from the runtime.
-	#[serde(alias = "relayChain", alias = "RelayChain")]
+	///
+	/// Empty for solochains.
+	#[serde(alias = "relayChain", alias = "RelayChain", default)]
 	relay_chain: String,
 	/// The id of the Parachain.
 	#[serde(alias = "paraId", alias = "ParaId")]
@@ -89,14 +91,18 @@ mod tests {
 		let snake_case = r#"{"relay_chain":"relay","para_id":1}"#;
 		let pascal_case = r#"{"RelayChain":"relay","ParaId":1}"#;
 		let para_id_missing = r#"{"RelayChain":"westend"}"#;
+		let solochain = r#"{}"#;
 
 		let camel_case_extension: Extensions = serde_json::from_str(camel_case).unwrap();
 		let snake_case_extension: Extensions = serde_json::from_str(snake_case).unwrap();
 		let pascal_case_extension: Extensions = serde_json::from_str(pascal_case).unwrap();
 		let missing_paraid_extension: Extensions = serde_json::from_str(para_id_missing).unwrap();
+		let solochain_extension: Extensions = serde_json::from_str(solochain).unwrap();
 		assert_eq!(camel_case_extension, snake_case_extension);
 		assert_eq!(snake_case_extension, pascal_case_extension);
 		assert_eq!(missing_paraid_extension.relay_chain, "westend".to_string());
 		assert!(missing_paraid_extension.para_id.is_none());
+		assert!(solochain_extension.relay_chain.is_empty());
+		assert!(solochain_extension.para_id.is_none());
 	}
 }
@@ -29,7 +29,9 @@ pub mod types;
 
 use crate::cli::AuthoringPolicy;
 
-use cumulus_primitives_core::{CollectCollationInfo, GetParachainInfo, RelayParentOffsetApi};
+use cumulus_primitives_core::{
+	CollectCollationInfo, GetParachainInfo, RelayParentOffsetApi, RelayStateKeysApi,
+};
 use sc_client_db::DbHash;
 use sc_offchain::OffchainWorkerApi;
 use serde::de::DeserializeOwned;
@@ -75,6 +77,7 @@ pub trait NodeRuntimeApi<Block: BlockT>:
 	+ ValidateStatement<Block>
 	+ GetParachainInfo<Block>
 	+ RelayParentOffsetApi<Block>
+	+ RelayStateKeysApi<Block>
 	+ Sized
 {
 }
@@ -87,6 +90,7 @@ impl<T, Block: BlockT> NodeRuntimeApi<Block> for T where
 		+ TaggedTransactionQueue<Block>
 		+ OffchainWorkerApi<Block>
 		+ RelayParentOffsetApi<Block>
+		+ RelayStateKeysApi<Block>
 		+ CollectCollationInfo<Block>
 		+ ValidateStatement<Block>
 		+ GetParachainInfo<Block>
@@ -120,6 +124,9 @@ pub struct NodeExtraArgs {
 	/// If set, each `PoV` build by the node will be exported to this folder.
 	pub export_pov: Option<PathBuf>,
 
+	/// If set, `PoVs` that can not be recovered from the relay chain are read from this folder.
+	pub pov_archive: Option<PathBuf>,
+
 	/// The maximum percentage of the maximum PoV size that the collator can use.
 	/// It will be removed once <https://github.com/paritytech/polkadot-sdk/issues/6020> is fixed.
 	pub max_pov_percentage: Option<u32>,
@@ -16,6 +16,7 @@
 
 //! Runtime parameters.
 
+use crate::common::chain_spec::Extensions;
 use codec::Decode;
 use cumulus_client_service::ParachainHostFunctions;
 use sc_chain_spec::ChainSpec;
@@ -29,9 +30,15 @@ use subxt_metadata::{Metadata, StorageEntryType};
 pub const DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME: &str = "ParachainSystem";
 /// Expected frame system pallet runtime type name.
 pub const DEFAULT_FRAME_SYSTEM_PALLET_NAME: &str = "System";
+/// Expected GRANDPA pallet runtime type name.
+pub const DEFAULT_GRANDPA_PALLET_NAME: &str = "Grandpa";
+/// Expected BABE pallet runtime type name.
+pub const DEFAULT_BABE_PALLET_NAME: &str = "Babe";
+/// Expected Aura pallet runtime type name.
+pub const DEFAULT_AURA_PALLET_NAME: &str = "Aura";
 
 /// The Aura ID used by the Aura consensus
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 pub enum AuraConsensusId {
 	/// Ed25519
 	Ed25519,
@@ -46,6 +53,17 @@ pub enum Consensus {
 	Aura(AuraConsensusId),
 }
 
+/// The choice of block authoring consensus for a solochain.
+///
+/// Finality is always provided by GRANDPA.
+#[derive(PartialEq, Debug)]
+pub enum SolochainConsensus {
+	/// Aura consensus.
+	Aura(AuraConsensusId),
+	/// BABE consensus.
+	Babe,
+}
+
 /// The choice of block number for the parachain omni-node.
 #[derive(PartialEq, Debug)]
 pub enum BlockNumber {
@@ -89,6 +107,9 @@ pub enum Runtime {
 	/// None of the system-chain runtimes, rather the node will act agnostic to the runtime ie. be
 	/// an omni-node, and simply run a node with the given consensus algorithm.
 	Omni(BlockNumber, Consensus),
+	/// A standalone chain, which doesn't follow any relay chain. The node runs the given block
+	/// authoring consensus together with GRANDPA finality.
+	Solochain(BlockNumber, SolochainConsensus),
 }
 
 /// Helper trait used for extracting the Runtime variant from the chain spec ID.
@@ -97,8 +118,14 @@ pub trait RuntimeResolver {
 	fn runtime(&self, chain_spec: &dyn ChainSpec) -> sc_cli::Result<Runtime>;
 }
 
-/// Default implementation for `RuntimeResolver` that just returns
-/// `Runtime::Omni(BlockNumber::U32, Consensus::Aura(AuraConsensusId::Sr25519))`.
+/// Default implementation for `RuntimeResolver`.
+///
+/// Returns `Runtime::Solochain` if the chain spec doesn't reference a relay chain and the runtime
+/// contains the GRANDPA pallet, but not the parachain system pallet. The block authoring consensus
+/// of a solochain is BABE if the runtime contains the BABE pallet, otherwise Aura with the key type
+/// of the Aura pallet authorities. Otherwise returns
+/// `Runtime::Omni(_, Consensus::Aura(AuraConsensusId::Sr25519))`. The block number type is read
+/// from the runtime metadata.
 pub struct DefaultRuntimeResolver;
 
 impl RuntimeResolver for DefaultRuntimeResolver {
@@ -121,6 +148,15 @@ impl RuntimeResolver for DefaultRuntimeResolver {
 			},
 		};
 
+		if is_solochain_spec(chain_spec) &&
+			metadata_inspector.pallet_exists(DEFAULT_GRANDPA_PALLET_NAME) &&
+			!metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME)
+		{
+			let consensus = metadata_inspector.solochain_consensus();
+			log::info!("⛓️  Detected a solochain runtime, the node won't follow any relay chain.");
+			return Ok(Runtime::Solochain(block_number, consensus))
+		}
+
 		if !metadata_inspector.pallet_exists(DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME) {
 			log::warn!(
 				r#"⚠️  The parachain system pallet (https://docs.rs/crate/cumulus-pallet-parachain-system/latest) is
@@ -133,6 +169,12 @@ impl RuntimeResolver for DefaultRuntimeResolver {
 	}
 }
 
+/// Returns `true` if the chain spec doesn't reference any relay chain or parachain id.
+fn is_solochain_spec(chain_spec: &dyn ChainSpec) -> bool {
+	Extensions::try_get(chain_spec)
+		.map_or(true, |ext| ext.relay_chain().is_empty() && ext.para_id().is_none())
+}
+
 struct MetadataInspector(Metadata);
 
 impl MetadataInspector {
@@ -157,29 +199,80 @@ impl MetadataInspector {
 			.and_then(|portable_type| BlockNumber::from_type_def(&portable_type.type_def))
 	}
 
+	/// The block authoring consensus of a solochain runtime.
+	///
+	/// Falls back to Aura with sr25519 keys if the Aura authority key type can't be determined.
+	fn solochain_consensus(&self) -> SolochainConsensus {
+		if self.pallet_exists(DEFAULT_BABE_PALLET_NAME) {
+			return SolochainConsensus::Babe
+		}
+
+		SolochainConsensus::Aura(self.aura_consensus_id().unwrap_or(AuraConsensusId::Sr25519))
+	}
+
+	/// The key type of the Aura pallet authorities.
+	fn aura_consensus_id(&self) -> Option<AuraConsensusId> {
+		let authorities_ty = match self
+			.0
+			.pallet_by_name(DEFAULT_AURA_PALLET_NAME)?
+			.storage()?
+			.entry_by_name("Authorities")?
+			.entry_type()
+		{
+			StorageEntryType::Plain(ty_id) => *ty_id,
+			_ => return None,
+		};
+		let authority_ty = self.0.types().resolve(self.sequence_element(authorities_ty)?)?;
+
+		let segments = &authority_ty.path.segments;
+		if segments.iter().any(|segment| segment == "ed25519") {
+			Some(AuraConsensusId::Ed25519)
+		} else if segments.iter().any(|segment| segment == "sr25519") {
+			Some(AuraConsensusId::Sr25519)
+		} else {
+			None
+		}
+	}
+
+	/// The element type of the sequence type `ty_id`, looking through single field wrappers like
+	/// `BoundedVec`.
+	fn sequence_element(&self, ty_id: u32) -> Option<u32> {
+		match &self.0.types().resolve(ty_id)?.type_def {
+			TypeDef::Sequence(sequence) => Some(sequence.type_param.id),
+			TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+				self.sequence_element(composite.fields[0].ty.id),
+			_ => None,
+		}
+	}
+
 	fn fetch_metadata(chain_spec: &dyn ChainSpec) -> Result<Metadata, sc_cli::Error> {
 		let mut storage = chain_spec.build_storage()?;
 		let code_bytes = storage
 			.top
 			.remove(sp_storage::well_known_keys::CODE)
 			.ok_or("chain spec genesis does not contain code")?;
-		let opaque_metadata = fetch_latest_metadata_from_code_blob(
-			&WasmExecutor::<ParachainHostFunctions>::builder()
-				.with_allow_missing_host_functions(true)
-				.build(),
-			sp_runtime::Cow::Borrowed(code_bytes.as_slice()),
-		)
-		.map_err(|err| err.to_string())?;
-
-		Metadata::decode(&mut (*opaque_metadata).as_slice()).map_err(Into::into)
+		metadata_from_code(&code_bytes)
 	}
 }
 
+/// Fetches the latest metadata supported by the runtime `code`.
+pub(crate) fn metadata_from_code(code: &[u8]) -> Result<Metadata, sc_cli::Error> {
+	let opaque_metadata = fetch_latest_metadata_from_code_blob(
+		&WasmExecutor::<ParachainHostFunctions>::builder()
+			.with_allow_missing_host_functions(true)
+			.build(),
+		sp_runtime::Cow::Borrowed(code),
+	)
+	.map_err(|err| err.to_string())?;
+
+	Metadata::decode(&mut (*opaque_metadata).as_slice()).map_err(Into::into)
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::runtime::{
-		BlockNumber, MetadataInspector, DEFAULT_FRAME_SYSTEM_PALLET_NAME,
-		DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME,
+		AuraConsensusId, BlockNumber, MetadataInspector, SolochainConsensus,
+		DEFAULT_FRAME_SYSTEM_PALLET_NAME, DEFAULT_PARACHAIN_SYSTEM_PALLET_NAME,
 	};
 	use codec::Decode;
 	use cumulus_client_service::ParachainHostFunctions;
@@ -210,4 +303,14 @@ mod tests {
 		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
 		assert_eq!(metadata_inspector.block_number().unwrap(), BlockNumber::U32);
 	}
+
+	#[test]
+	fn test_aura_consensus_id() {
+		let metadata_inspector = MetadataInspector(cumulus_test_runtime_metadata());
+		assert_eq!(metadata_inspector.aura_consensus_id(), Some(AuraConsensusId::Sr25519));
+		assert_eq!(
+			metadata_inspector.solochain_consensus(),
+			SolochainConsensus::Aura(AuraConsensusId::Sr25519)
+		);
+	}
 }
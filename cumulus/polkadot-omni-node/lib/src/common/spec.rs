@@ -31,6 +31,10 @@ use crate::{
 use codec::Encode;
 use cumulus_client_bootnodes::{start_bootnode_tasks, StartBootnodeTasksParams};
 use cumulus_client_cli::CollatorOptions;
+use cumulus_client_pov_recovery::{
+	pov_request_protocol_name, pov_request_response_config, ArchivePoVSource, NetworkPoVSource,
+	PoVFallbackSource, PoVRequestHandler,
+};
 use cumulus_client_service::{
 	build_network, build_relay_chain_interface, prepare_node_config, start_relay_chain_tasks,
 	BuildNetworkParams, CollatorSybilResistance, DARecoveryProfile, ParachainTracingExecuteBlock,
@@ -370,6 +374,14 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				new_statement_handler_proto(&*client, &parachain_config, &metrics, &mut net_config)
 			});
 
+			let genesis_hash = client.chain_info().genesis_hash;
+			let (pov_request_protocol_config, pov_request_receiver) =
+				pov_request_response_config::<_, Self::Block, Net>(
+					genesis_hash,
+					parachain_fork_id.as_deref(),
+				);
+			net_config.add_request_response_protocol(pov_request_protocol_config);
+
 			let (network, system_rpc_tx, tx_handler_controller, sync_service) =
 				build_network(BuildNetworkParams {
 					parachain_config: &parachain_config,
@@ -386,6 +398,28 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				.await?;
 			let peer_id = network.local_peer_id();
 
+			// PoVs of the local archives are served to other nodes and used when the relay chain
+			// can not provide them anymore.
+			let pov_archives = [&node_extra_args.pov_archive, &node_extra_args.export_pov]
+				.into_iter()
+				.flatten()
+				.map(|path| {
+					Arc::new(ArchivePoVSource::new(path.clone()))
+						as Arc<dyn PoVFallbackSource<Self::Block>>
+				})
+				.collect::<Vec<_>>();
+			task_manager.spawn_handle().spawn(
+				"pov-request-handler",
+				None,
+				PoVRequestHandler::new(pov_request_receiver, pov_archives.clone()).run(),
+			);
+			let mut pov_fallback_sources = pov_archives;
+			pov_fallback_sources.push(Arc::new(NetworkPoVSource::new(
+				network.clone(),
+				sync_service.clone(),
+				pov_request_protocol_name(genesis_hash, parachain_fork_id.as_deref()),
+			)));
+
 			let statement_store = statement_handler_proto
 				.map(|statement_handler_proto| {
 					build_statement_store(
@@ -424,6 +458,8 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 						network_provider: Arc::new(network.clone()),
 						is_validator: parachain_config.role.is_authority(),
 						enable_http_requests: true,
+						enable_websocket_connections: parachain_config.offchain_worker.websocket_enabled,
+						outbound_policy: parachain_config.offchain_worker.outbound_policy.clone(),
 						custom_extensions,
 					})?;
 				task_manager.spawn_handle().spawn(
@@ -520,6 +556,7 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				import_queue: import_queue_service,
 				relay_chain_slot_duration,
 				recovery_handle: Box::new(overseer_handle.clone()),
+				pov_fallback_sources,
 				sync_service,
 				prometheus_registry: prometheus_registry.as_ref(),
 			})?;
@@ -535,7 +572,7 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				request_receiver: paranode_rx,
 				parachain_network: network,
 				advertise_non_global_ips,
-				parachain_genesis_hash: client.chain_info().genesis_hash.encode(),
+				parachain_genesis_hash: genesis_hash.encode(),
 				parachain_fork_id,
 				parachain_public_addresses,
 			});
@@ -592,6 +629,23 @@ pub(crate) trait DynNodeSpec: NodeCommandRunner {
 		hwbench: Option<HwBench>,
 		node_extra_args: NodeExtraArgs,
 	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>>;
+
+	/// Whether the node runs a standalone chain, which doesn't follow any relay chain.
+	fn is_solochain(&self) -> bool {
+		false
+	}
+
+	/// Start the node of a standalone chain.
+	fn start_solochain_node(
+		self: Box<Self>,
+		_config: Configuration,
+		_hwbench: Option<HwBench>,
+		_node_extra_args: NodeExtraArgs,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>> {
+		Box::pin(async {
+			Err(sc_service::Error::Other("Not a solochain node, a relay chain is required".into()))
+		})
+	}
 }
 
 impl<T> DynNodeSpec for T
@@ -19,10 +19,12 @@
 
 mod utils;
 
-use utils::{impl_node_runtime_apis, imports::*};
+use utils::{impl_node_runtime_apis, impl_solochain_runtime_apis, imports::*};
 
 #[allow(dead_code)]
 type CustomBlock = crate::common::types::Block<u32>;
+#[allow(dead_code)]
+type CustomBlockU64 = crate::common::types::Block<u64>;
 
 pub mod aura_sr25519 {
 	use super::*;
@@ -37,3 +39,42 @@ pub mod aura_ed25519 {
 	struct FakeRuntime;
 	impl_node_runtime_apis!(FakeRuntime, CustomBlock, sp_consensus_aura::ed25519::AuthorityId);
 }
+
+// Some solochain runtime APIs are generic over the block number, so we need a fake runtime per
+// block number type. BABE solochains use the `sr25519` variants.
+
+pub mod solochain_sr25519_u32 {
+	use super::*;
+	#[allow(dead_code)]
+	struct FakeRuntime;
+	impl_solochain_runtime_apis!(FakeRuntime, CustomBlock, sp_consensus_aura::sr25519::AuthorityId);
+}
+
+pub mod solochain_sr25519_u64 {
+	use super::*;
+	#[allow(dead_code)]
+	struct FakeRuntime;
+	impl_solochain_runtime_apis!(
+		FakeRuntime,
+		CustomBlockU64,
+		sp_consensus_aura::sr25519::AuthorityId
+	);
+}
+
+pub mod solochain_ed25519_u32 {
+	use super::*;
+	#[allow(dead_code)]
+	struct FakeRuntime;
+	impl_solochain_runtime_apis!(FakeRuntime, CustomBlock, sp_consensus_aura::ed25519::AuthorityId);
+}
+
+pub mod solochain_ed25519_u64 {
+	use super::*;
+	#[allow(dead_code)]
+	struct FakeRuntime;
+	impl_solochain_runtime_apis!(
+		FakeRuntime,
+		CustomBlockU64,
+		sp_consensus_aura::ed25519::AuthorityId
+	);
+}
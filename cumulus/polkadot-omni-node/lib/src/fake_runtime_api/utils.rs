@@ -17,9 +17,13 @@
 pub(crate) mod imports {
 	pub use cumulus_primitives_core::ParaId;
 	pub use parachains_common_types::{AccountId, Balance, Nonce};
+	pub use sp_consensus_beefy::{
+		ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature},
+		MmrRootHash,
+	};
 	pub use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 	pub use sp_runtime::{
-		traits::Block as BlockT,
+		traits::{Block as BlockT, NumberFor},
 		transaction_validity::{TransactionSource, TransactionValidity},
 		ApplyExtrinsicResult,
 	};
@@ -28,6 +32,9 @@ pub(crate) mod imports {
 
 macro_rules! impl_node_runtime_apis {
 	($runtime: ty, $block: tt, $aura_id: ty) => {
+		impl_node_runtime_apis!($runtime, $block, $aura_id, {});
+	};
+	($runtime: ty, $block: tt, $aura_id: ty, { $($extra_apis: tt)* }) => {
 		sp_api::impl_runtime_apis! {
 			impl sp_api::Core<$block> for $runtime {
 				fn version() -> sp_version::RuntimeVersion {
@@ -65,6 +72,12 @@ macro_rules! impl_node_runtime_apis {
 				}
 			}
 
+			impl cumulus_primitives_core::RelayStateKeysApi<$block> for $runtime {
+				fn relay_state_keys() -> Vec<Vec<u8>> {
+					unimplemented!()
+				}
+			}
+
 			impl sp_consensus_aura::AuraApi<$block, $aura_id> for $runtime {
 				fn slot_duration() -> sp_consensus_aura::SlotDuration {
 					unimplemented!()
@@ -248,8 +261,170 @@ macro_rules! impl_node_runtime_apis {
 					unimplemented!()
 				}
 			}
+
+			$($extra_apis)*
 		}
 	};
 }
 
+/// Same as `impl_node_runtime_apis`, extended with the runtime APIs used by solochain nodes.
+macro_rules! impl_solochain_runtime_apis {
+	($runtime: ty, $block: tt, $aura_id: ty) => {
+		impl_node_runtime_apis!($runtime, $block, $aura_id, {
+			impl sp_consensus_babe::BabeApi<$block> for $runtime {
+				fn configuration() -> sp_consensus_babe::BabeConfiguration {
+					unimplemented!()
+				}
+
+				fn current_epoch_start() -> sp_consensus_babe::Slot {
+					unimplemented!()
+				}
+
+				fn current_epoch() -> sp_consensus_babe::Epoch {
+					unimplemented!()
+				}
+
+				fn next_epoch() -> sp_consensus_babe::Epoch {
+					unimplemented!()
+				}
+
+				fn generate_key_ownership_proof(
+					_: sp_consensus_babe::Slot,
+					_: sp_consensus_babe::AuthorityId,
+				) -> Option<sp_consensus_babe::OpaqueKeyOwnershipProof> {
+					unimplemented!()
+				}
+
+				fn submit_report_equivocation_unsigned_extrinsic(
+					_: sp_consensus_babe::EquivocationProof<<$block as BlockT>::Header>,
+					_: sp_consensus_babe::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+			}
+
+			impl sp_consensus_grandpa::GrandpaApi<$block> for $runtime {
+				fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+					unimplemented!()
+				}
+
+				fn current_set_id() -> sp_consensus_grandpa::SetId {
+					unimplemented!()
+				}
+
+				fn submit_report_equivocation_unsigned_extrinsic(
+					_: sp_consensus_grandpa::EquivocationProof<
+						<$block as BlockT>::Hash,
+						NumberFor<$block>,
+					>,
+					_: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+
+				fn generate_key_ownership_proof(
+					_: sp_consensus_grandpa::SetId,
+					_: sp_consensus_grandpa::AuthorityId,
+				) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+					unimplemented!()
+				}
+			}
+
+			impl sp_consensus_beefy::BeefyApi<$block, BeefyId> for $runtime {
+				fn beefy_genesis() -> Option<NumberFor<$block>> {
+					unimplemented!()
+				}
+
+				fn validator_set() -> Option<sp_consensus_beefy::ValidatorSet<BeefyId>> {
+					unimplemented!()
+				}
+
+				fn submit_report_double_voting_unsigned_extrinsic(
+					_: sp_consensus_beefy::DoubleVotingProof<
+						NumberFor<$block>,
+						BeefyId,
+						BeefySignature,
+					>,
+					_: sp_consensus_beefy::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+
+				fn submit_report_fork_voting_unsigned_extrinsic(
+					_: sp_consensus_beefy::ForkVotingProof<
+						<$block as BlockT>::Header,
+						BeefyId,
+						sp_runtime::OpaqueValue,
+					>,
+					_: sp_consensus_beefy::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+
+				fn submit_report_future_block_voting_unsigned_extrinsic(
+					_: sp_consensus_beefy::FutureBlockVotingProof<NumberFor<$block>, BeefyId>,
+					_: sp_consensus_beefy::OpaqueKeyOwnershipProof,
+				) -> Option<()> {
+					unimplemented!()
+				}
+
+				fn generate_key_ownership_proof(
+					_: sp_consensus_beefy::ValidatorSetId,
+					_: BeefyId,
+				) -> Option<sp_consensus_beefy::OpaqueKeyOwnershipProof> {
+					unimplemented!()
+				}
+			}
+
+			impl sp_mmr_primitives::MmrApi<$block, MmrRootHash, NumberFor<$block>> for $runtime {
+				fn mmr_root() -> Result<MmrRootHash, sp_mmr_primitives::Error> {
+					unimplemented!()
+				}
+
+				fn mmr_leaf_count() -> Result<sp_mmr_primitives::LeafIndex, sp_mmr_primitives::Error>
+				{
+					unimplemented!()
+				}
+
+				fn generate_proof(
+					_: Vec<NumberFor<$block>>,
+					_: Option<NumberFor<$block>>,
+				) -> Result<
+					(
+						Vec<sp_mmr_primitives::EncodableOpaqueLeaf>,
+						sp_mmr_primitives::LeafProof<MmrRootHash>,
+					),
+					sp_mmr_primitives::Error,
+				> {
+					unimplemented!()
+				}
+
+				fn verify_proof(
+					_: Vec<sp_mmr_primitives::EncodableOpaqueLeaf>,
+					_: sp_mmr_primitives::LeafProof<MmrRootHash>,
+				) -> Result<(), sp_mmr_primitives::Error> {
+					unimplemented!()
+				}
+
+				fn generate_ancestry_proof(
+					_: NumberFor<$block>,
+					_: Option<NumberFor<$block>>,
+				) -> Result<sp_mmr_primitives::AncestryProof<MmrRootHash>, sp_mmr_primitives::Error>
+				{
+					unimplemented!()
+				}
+
+				fn verify_proof_stateless(
+					_: MmrRootHash,
+					_: Vec<sp_mmr_primitives::EncodableOpaqueLeaf>,
+					_: sp_mmr_primitives::LeafProof<MmrRootHash>,
+				) -> Result<(), sp_mmr_primitives::Error> {
+					unimplemented!()
+				}
+			}
+		});
+	};
+}
+
 pub(crate) use impl_node_runtime_apis;
+pub(crate) use impl_solochain_runtime_apis;
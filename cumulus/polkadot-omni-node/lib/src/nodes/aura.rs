@@ -261,6 +261,8 @@ where
 					network_provider: Arc::new(network.clone()),
 					is_validator: config.role.is_authority(),
 					enable_http_requests: true,
+					enable_websocket_connections: config.offchain_worker.websocket_enabled,
+					outbound_policy: config.offchain_worker.outbound_policy.clone(),
 					custom_extensions: move |_| vec![],
 				})?;
 			task_manager.spawn_handle().spawn(
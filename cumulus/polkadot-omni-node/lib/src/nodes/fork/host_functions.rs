@@ -0,0 +1,171 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signature verification host functions that accept mocked signatures.
+//!
+//! A mocked signature starts with [`MOCK_SIGNATURE_PREFIX`] and is filled with
+//! [`MOCK_SIGNATURE_FILL`] up to the signature length. It is accepted for any public key and any
+//! message, which allows submitting transactions on behalf of any account. Real signatures are
+//! still verified as usual.
+//!
+//! Mocked signatures are only accepted when [`MockSignaturesExt`] is registered.
+
+use sc_client_api::execution_extensions::ExtensionsFactory;
+use sp_core::{ecdsa, ed25519, sr25519, Pair};
+use sp_externalities::{decl_extension, Extensions};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_runtime_interface::{
+	pass_by::{PassFatPointerAndRead, PassPointerAndRead},
+	runtime_interface,
+};
+
+/// The prefix of a mocked signature.
+pub const MOCK_SIGNATURE_PREFIX: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+/// The byte a mocked signature is filled with after the [`MOCK_SIGNATURE_PREFIX`].
+pub const MOCK_SIGNATURE_FILL: u8 = 0xcd;
+
+decl_extension! {
+	/// Accept mocked signatures in the signature verification host functions.
+	pub struct MockSignaturesExt;
+}
+
+/// Registers [`MockSignaturesExt`] for every runtime call.
+pub struct MockSignaturesExtFactory;
+
+impl<Block: BlockT> ExtensionsFactory<Block> for MockSignaturesExtFactory {
+	fn extensions_for(&self, _: Block::Hash, _: NumberFor<Block>) -> Extensions {
+		let mut extensions = Extensions::new();
+		extensions.register(MockSignaturesExt);
+		extensions
+	}
+}
+
+/// Checks whether `signature` is a mocked signature that should be accepted.
+fn is_accepted_mock_signature(signature: &[u8]) -> bool {
+	let is_mock_signature = signature.starts_with(&MOCK_SIGNATURE_PREFIX) &&
+		signature[MOCK_SIGNATURE_PREFIX.len()..]
+			.iter()
+			.all(|b| *b == MOCK_SIGNATURE_FILL);
+
+	is_mock_signature &&
+		sp_externalities::with_externalities(|mut ext| {
+			ext.extension::<MockSignaturesExt>().is_some()
+		})
+		.unwrap_or_default()
+}
+
+/// Overrides of the `sp_io::crypto` signature verification functions.
+#[runtime_interface]
+pub trait Crypto {
+	/// Verify `ed25519` signature.
+	fn ed25519_verify(
+		sig: PassPointerAndRead<&ed25519::Signature, 64>,
+		msg: PassFatPointerAndRead<&[u8]>,
+		pub_key: PassPointerAndRead<&ed25519::Public, 32>,
+	) -> bool {
+		is_accepted_mock_signature(sig.as_ref()) || ed25519::Pair::verify(sig, msg, pub_key)
+	}
+
+	/// Verify `sr25519` signature, regardless of the signature version.
+	fn sr25519_verify(
+		sig: PassPointerAndRead<&sr25519::Signature, 64>,
+		msg: PassFatPointerAndRead<&[u8]>,
+		pubkey: PassPointerAndRead<&sr25519::Public, 32>,
+	) -> bool {
+		is_accepted_mock_signature(sig.as_ref()) ||
+			sr25519::Pair::verify_deprecated(sig, msg, pubkey)
+	}
+
+	/// Verify `sr25519` signature.
+	#[version(2)]
+	fn sr25519_verify(
+		sig: PassPointerAndRead<&sr25519::Signature, 64>,
+		msg: PassFatPointerAndRead<&[u8]>,
+		pub_key: PassPointerAndRead<&sr25519::Public, 32>,
+	) -> bool {
+		is_accepted_mock_signature(sig.as_ref()) || sr25519::Pair::verify(sig, msg, pub_key)
+	}
+
+	/// Verify `ecdsa` signature, handling non-standard overflowing signatures.
+	fn ecdsa_verify(
+		sig: PassPointerAndRead<&ecdsa::Signature, 65>,
+		msg: PassFatPointerAndRead<&[u8]>,
+		pub_key: PassPointerAndRead<&ecdsa::Public, 33>,
+	) -> bool {
+		#[allow(deprecated)]
+		let verified = ecdsa::Pair::verify_deprecated(sig, msg, pub_key);
+		is_accepted_mock_signature(sig.as_ref()) || verified
+	}
+
+	/// Verify `ecdsa` signature.
+	#[version(2)]
+	fn ecdsa_verify(
+		sig: PassPointerAndRead<&ecdsa::Signature, 65>,
+		msg: PassFatPointerAndRead<&[u8]>,
+		pub_key: PassPointerAndRead<&ecdsa::Public, 33>,
+	) -> bool {
+		is_accepted_mock_signature(sig.as_ref()) || ecdsa::Pair::verify(sig, msg, pub_key)
+	}
+
+	/// Verify `ecdsa` signature with pre-hashed `msg`.
+	fn ecdsa_verify_prehashed(
+		sig: PassPointerAndRead<&ecdsa::Signature, 65>,
+		msg: PassPointerAndRead<&[u8; 32], 32>,
+		pub_key: PassPointerAndRead<&ecdsa::Public, 33>,
+	) -> bool {
+		is_accepted_mock_signature(sig.as_ref()) || ecdsa::Pair::verify_prehashed(sig, msg, pub_key)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_state_machine::BasicExternalities;
+
+	fn mock_signature() -> sr25519::Signature {
+		let mut signature = [MOCK_SIGNATURE_FILL; 64];
+		signature[..MOCK_SIGNATURE_PREFIX.len()].copy_from_slice(&MOCK_SIGNATURE_PREFIX);
+		signature.into()
+	}
+
+	#[test]
+	fn mock_signatures_require_extension() {
+		let public = sr25519::Pair::from_string("//Alice", None).unwrap().public();
+		let mut ext = BasicExternalities::default();
+
+		assert!(!ext.execute_with(|| crypto::sr25519_verify(&mock_signature(), b"msg", &public)));
+
+		ext.register_extension(MockSignaturesExt);
+		assert!(ext.execute_with(|| crypto::sr25519_verify(&mock_signature(), b"msg", &public)));
+	}
+
+	#[test]
+	fn real_signatures_are_still_verified() {
+		let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let mut ext = BasicExternalities::default();
+		ext.register_extension(MockSignaturesExt);
+
+		ext.execute_with(|| {
+			assert!(crypto::sr25519_verify(&pair.sign(b"msg"), b"msg", &pair.public()));
+			assert!(!crypto::sr25519_verify(&pair.sign(b"msg"), b"other", &pair.public()));
+
+			let mut almost_mock = mock_signature();
+			almost_mock.0[63] = 0;
+			assert!(!crypto::sr25519_verify(&almost_mock, b"msg", &pair.public()));
+		});
+	}
+}
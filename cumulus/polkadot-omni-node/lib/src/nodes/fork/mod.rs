@@ -0,0 +1,406 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dev node running on top of the state of a live parachain.
+//!
+//! The state snapshot is imported as the finalized best block of a fresh database, keeping the
+//! block number and the header of the live chain. On top of it, blocks are sealed locally with
+//! instant or manual seal and the relay chain validation data is mocked, so no relay chain is
+//! needed.
+
+pub mod host_functions;
+mod snapshot;
+
+pub use snapshot::Dispatch;
+
+use crate::{
+	cli::DevSealMode,
+	common::{
+		aura::{AuraIdT, AuraRuntimeApi},
+		chain_spec::Extensions,
+		rpc::RpcExtension,
+		types::{AccountId, Balance, Nonce, ParachainHostFunctions},
+		ConstructNodeRuntimeApi, NodeBlock,
+	},
+};
+use codec::{Decode, Encode};
+use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
+use cumulus_primitives_core::{
+	CollectCollationInfo, GetParachainInfo, ParaId, RelayParentOffsetApi,
+};
+use futures::{future, FutureExt};
+use host_functions::MockSignaturesExtFactory;
+use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+use polkadot_primitives::{HeadData, UpgradeGoAhead};
+use sc_client_api::{Backend, ExecutorProvider, HeaderBackend, StorageProvider};
+use sc_consensus::LongestChain;
+use sc_consensus_manual_seal::consensus::aura::AuraConsensusDataProvider;
+use sc_executor::{
+	sp_wasm_interface::ExtendedHostFunctions, HeapAllocStrategy, WasmExecutor,
+	DEFAULT_HEAP_ALLOC_STRATEGY,
+};
+use sc_network::{config::FullNetworkConfiguration, NotificationMetrics};
+use sc_rpc::dev::{Dev, DevApiServer};
+use sc_service::{Configuration, TFullClient, TaskManager};
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_consensus_aura::{Slot, SlotDuration};
+use sp_core::{hexdisplay::HexDisplay, storage::StorageKey, twox_128};
+use sp_runtime::traits::{Header as HeaderT, UniqueSaturatedInto};
+use std::{path::PathBuf, sync::Arc};
+use substrate_frame_rpc_system::{System, SystemApiServer};
+
+/// The host functions of a forked node, accepting mocked signatures.
+type ForkHostFunctions =
+	ExtendedHostFunctions<ParachainHostFunctions, host_functions::crypto::HostFunctions>;
+
+type ForkClient<Block, RuntimeApi> =
+	TFullClient<Block, RuntimeApi, WasmExecutor<ForkHostFunctions>>;
+
+type InherentDataProviders =
+	(sp_timestamp::InherentDataProvider, MockValidationDataInherentDataProvider<()>);
+
+/// The slot duration of the relay chain the forked parachain is running on.
+const RELAY_CHAIN_SLOT_DURATION_MILLIS: u64 = 6000;
+
+/// Parameters of a forked node.
+pub(crate) struct ForkParams {
+	/// The state snapshot to fork from.
+	pub snapshot: PathBuf,
+	/// Storage overrides applied on top of the state snapshot.
+	pub storage_overrides: Option<PathBuf>,
+	/// Calls dispatched in the first block on top of the state snapshot.
+	pub dispatches: Vec<Dispatch>,
+	/// Accept mocked signatures for any account.
+	pub mock_signatures: bool,
+	/// How blocks are sealed.
+	pub mode: DevSealMode,
+}
+
+/// Starts a dev node on top of the state snapshot given in `params`.
+pub(crate) async fn start_fork_node<Block, RuntimeApi, AuraId>(
+	mut config: Configuration,
+	params: ForkParams,
+) -> sc_service::error::Result<TaskManager>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ForkClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	AuraId: AuraIdT + Sync,
+{
+	let heap_pages = config
+		.executor
+		.default_heap_pages
+		.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |h| HeapAllocStrategy::Static { extra_pages: h as _ });
+
+	let executor = WasmExecutor::<ForkHostFunctions>::builder()
+		.with_execution_method(config.executor.wasm_method)
+		.with_max_runtime_instances(config.executor.max_runtime_instances)
+		.with_runtime_cache_size(config.executor.runtime_cache_size)
+		.with_onchain_heap_alloc_strategy(heap_pages)
+		.with_offchain_heap_alloc_strategy(heap_pages)
+		.build();
+
+	let (client, backend, keystore_container, mut task_manager) =
+		sc_service::new_full_parts::<Block, RuntimeApi, _>(&config, None, executor.clone())?;
+	let client = Arc::new(client);
+
+	if params.mock_signatures {
+		log::info!(
+			"🍴 Accepting mocked signatures, starting with 0x{} and filled with 0x{:02x}",
+			HexDisplay::from(&host_functions::MOCK_SIGNATURE_PREFIX),
+			host_functions::MOCK_SIGNATURE_FILL,
+		);
+		client.execution_extensions().set_extensions_factory(MockSignaturesExtFactory);
+	}
+
+	let overrides = match &params.storage_overrides {
+		Some(path) => snapshot::read_storage_overrides(path)?,
+		None => Default::default(),
+	};
+	// The transaction pool starts at the best block, so the snapshot needs to be imported first.
+	snapshot::import_snapshot(&*client, &executor, &params.snapshot, overrides, &params.dispatches)
+		.await?;
+
+	let transaction_pool = Arc::from(
+		sc_transaction_pool::Builder::new(
+			task_manager.spawn_essential_handle(),
+			client.clone(),
+			config.role.is_authority().into(),
+		)
+		.with_options(config.transaction_pool.clone())
+		.with_prometheus(config.prometheus_registry())
+		.build(),
+	);
+
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(client.clone()),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	);
+
+	// Since this is a dev node, prevent it from connecting to peers.
+	config.network.default_peers_set.in_peers = 0;
+	config.network.default_peers_set.out_peers = 0;
+	let net_config = FullNetworkConfiguration::<_, _, sc_network::Litep2pNetworkBackend>::new(
+		&config.network,
+		None,
+	);
+
+	let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			net_config,
+			block_announce_validator_builder: None,
+			warp_sync_config: None,
+			block_relay: None,
+			metrics: NotificationMetrics::new(None),
+		})?;
+
+	if config.offchain_worker.enabled {
+		let offchain_workers =
+			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+				runtime_api_provider: client.clone(),
+				keystore: Some(keystore_container.keystore()),
+				offchain_db: backend.offchain_storage(),
+				transaction_pool: Some(OffchainTransactionPoolFactory::new(
+					transaction_pool.clone(),
+				)),
+				network_provider: Arc::new(network.clone()),
+				is_validator: config.role.is_authority(),
+				enable_http_requests: true,
+				enable_websocket_connections: config.offchain_worker.websocket_enabled,
+				outbound_policy: config.offchain_worker.outbound_policy.clone(),
+				custom_extensions: move |_| vec![],
+			})?;
+		task_manager.spawn_handle().spawn(
+			"offchain-workers-runner",
+			"offchain-work",
+			offchain_workers.run(client.clone(), task_manager.spawn_handle()).boxed(),
+		);
+	}
+
+	let proposer = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		None,
+		None,
+	);
+
+	let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+	let aura_digest_provider =
+		AuraConsensusDataProvider::<Block>::new_with_slot_duration(slot_duration);
+
+	let para_id = parachain_id(&client, &config).ok_or("Failed to retrieve the parachain id")?;
+	let create_inherent_data_providers = {
+		let client = client.clone();
+		move |parent: Block::Hash, ()| {
+			future::ready(fork_inherent_data_providers(&client, parent, para_id, slot_duration))
+		}
+	};
+
+	match params.mode {
+		DevSealMode::InstantSeal => {
+			let params = sc_consensus_manual_seal::InstantSealParams {
+				block_import: client.clone(),
+				env: proposer,
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				select_chain: LongestChain::new(backend.clone()),
+				consensus_data_provider: Some(Box::new(aura_digest_provider)),
+				create_inherent_data_providers,
+			};
+
+			let authorship_future = sc_consensus_manual_seal::run_instant_seal(params);
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"instant-seal",
+				None,
+				authorship_future,
+			);
+		},
+		DevSealMode::ManualSeal(block_time) => {
+			let (mut manual_seal_sink, manual_seal_stream) = futures::channel::mpsc::channel(1024);
+			task_manager
+				.spawn_essential_handle()
+				.spawn("block_authoring", None, async move {
+					loop {
+						futures_timer::Delay::new(std::time::Duration::from_millis(block_time))
+							.await;
+						manual_seal_sink
+							.try_send(sc_consensus_manual_seal::EngineCommand::SealNewBlock {
+								create_empty: true,
+								finalize: true,
+								parent_hash: None,
+								sender: None,
+							})
+							.unwrap();
+					}
+				});
+
+			let params = sc_consensus_manual_seal::ManualSealParams {
+				block_import: client.clone(),
+				env: proposer,
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				select_chain: LongestChain::new(backend.clone()),
+				commands_stream: Box::pin(manual_seal_stream),
+				consensus_data_provider: Some(Box::new(aura_digest_provider)),
+				create_inherent_data_providers,
+			};
+
+			let authorship_future = sc_consensus_manual_seal::run_manual_seal(params);
+			task_manager.spawn_essential_handle().spawn_blocking(
+				"manual-seal",
+				None,
+				authorship_future,
+			);
+		},
+	}
+
+	let rpc_extensions_builder = {
+		let client = client.clone();
+		let transaction_pool = transaction_pool.clone();
+
+		Box::new(move |_| {
+			let build = || -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>> {
+				let mut module = RpcExtension::new(());
+				module.merge(System::new(client.clone(), transaction_pool.clone()).into_rpc())?;
+				module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+				module.merge(Dev::new(client.clone()).into_rpc())?;
+				Ok(module)
+			};
+			build().map_err(Into::into)
+		})
+	};
+
+	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		network,
+		client,
+		keystore: keystore_container.keystore(),
+		task_manager: &mut task_manager,
+		transaction_pool,
+		rpc_builder: rpc_extensions_builder,
+		backend,
+		system_rpc_tx,
+		tx_handler_controller,
+		sync_service,
+		config,
+		telemetry: None,
+		tracing_execute_block: None,
+	})?;
+
+	Ok(task_manager)
+}
+
+/// Retrieves the parachain id from the runtime, falling back to the chain spec.
+fn parachain_id<Block, RuntimeApi>(
+	client: &ForkClient<Block, RuntimeApi>,
+	config: &Configuration,
+) -> Option<ParaId>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ForkClient<Block, RuntimeApi>>,
+{
+	let best_hash = client.info().best_hash;
+	match client.runtime_api().has_api::<dyn GetParachainInfo<Block>>(best_hash) {
+		Ok(true) => client.runtime_api().parachain_id(best_hash).ok(),
+		_ => Extensions::try_get(&*config.chain_spec)
+			.and_then(|ext| ext.para_id())
+			.map(ParaId::from),
+	}
+}
+
+/// Reads a storage value of a pallet at the given block.
+fn storage_value<Block, RuntimeApi, T: Decode>(
+	client: &ForkClient<Block, RuntimeApi>,
+	at: Block::Hash,
+	pallet: &[u8],
+	item: &[u8],
+) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync>>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ForkClient<Block, RuntimeApi>>,
+{
+	let key = StorageKey([twox_128(pallet), twox_128(item)].concat());
+	Ok(client.storage(at, &key)?.map(|data| T::decode(&mut &data.0[..])).transpose()?)
+}
+
+/// Creates the inherent data providers for a block built on top of `parent`.
+///
+/// Unlike a dev node started from genesis, the timestamp and the mocked relay chain block continue
+/// from the values stored in the forked state:
+/// - the timestamp is the start of the first relay chain slot which begins in a later parachain
+///   slot than the one of `parent`, as `cumulus-pallet-aura-ext` requires the parachain slot to be
+///   derived from the relay chain slot.
+/// - the relay parent is the block following the last relay parent seen by `parachain-system`.
+fn fork_inherent_data_providers<Block, RuntimeApi>(
+	client: &ForkClient<Block, RuntimeApi>,
+	parent: Block::Hash,
+	para_id: ParaId,
+	slot_duration: SlotDuration,
+) -> Result<InherentDataProviders, Box<dyn std::error::Error + Send + Sync>>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ForkClient<Block, RuntimeApi>>,
+{
+	let parent_header = client.header(parent)?.ok_or("Parent header is not in the database")?;
+
+	let parent_timestamp: u64 =
+		storage_value(client, parent, b"Timestamp", b"Now")?.unwrap_or_default();
+	let para_timestamp =
+		(parent_timestamp / slot_duration.as_millis() + 1) * slot_duration.as_millis();
+	let relay_slot = para_timestamp.div_ceil(RELAY_CHAIN_SLOT_DURATION_MILLIS);
+	let timestamp = relay_slot * RELAY_CHAIN_SLOT_DURATION_MILLIS;
+
+	let last_relay_parent: u32 =
+		storage_value(client, parent, b"ParachainSystem", b"LastRelayChainBlockNumber")?
+			.unwrap_or_default();
+	let current_para_block =
+		UniqueSaturatedInto::<u32>::unique_saturated_into(*parent_header.number()) + 1;
+
+	let should_send_go_ahead = client
+		.runtime_api()
+		.collect_collation_info(parent, &parent_header)
+		.map(|info| info.new_validation_code.is_some())
+		.unwrap_or_default();
+	let relay_parent_offset = client.runtime_api().relay_parent_offset(parent).unwrap_or_default();
+
+	let mocked_parachain = MockValidationDataInherentDataProvider::<()> {
+		current_para_block,
+		para_id,
+		current_para_block_head: Some(HeadData(parent_header.encode())),
+		relay_offset: (last_relay_parent + 1).saturating_sub(current_para_block),
+		relay_blocks_per_para_block: 1,
+		relay_parent_offset,
+		para_blocks_per_relay_epoch: 10,
+		xcm_config: MockXcmConfig::new(client, parent, Default::default()),
+		upgrade_go_ahead: should_send_go_ahead.then(|| {
+			log::info!("Detected pending validation code, sending go-ahead signal.");
+			UpgradeGoAhead::GoAhead
+		}),
+		relay_slot: Some(Slot::from(relay_slot)),
+		..Default::default()
+	};
+
+	Ok((sp_timestamp::InherentDataProvider::new(timestamp.into()), mocked_parachain))
+}
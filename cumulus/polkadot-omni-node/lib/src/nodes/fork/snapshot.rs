@@ -0,0 +1,660 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Importing a state snapshot into a fresh database.
+
+use crate::common::runtime::metadata_from_code;
+use codec::{Compact, Decode, Encode};
+use remote_externalities::{Builder, Mode, OfflineConfig, SnapshotConfig};
+use sc_client_api::HeaderBackend;
+use sc_consensus::{
+	BlockImport, BlockImportParams, ForkChoiceStrategy, ImportResult, ImportedState, StateAction,
+	StorageChanges,
+};
+use sc_executor::RuntimeVersionOf;
+use serde::{de::DeserializeOwned, Deserialize};
+use sp_consensus::BlockOrigin;
+use sp_core::{
+	blake2_128, blake2_256,
+	bytes::from_hex,
+	storage::{
+		well_known_keys::{CODE, DEFAULT_CHILD_STORAGE_KEY_PREFIX},
+		ChildInfo, Storage, StorageChild,
+	},
+	twox_128, twox_256, twox_64, Bytes,
+};
+use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT, NumberFor, One, Zero};
+use sp_state_machine::{
+	Backend as _, InMemoryBackend, IterArgs, KeyValueStates, KeyValueStorageLevel,
+};
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+use subxt_metadata::{Metadata, StorageEntryType, StorageHasher};
+
+/// Calls up to this length are stored inline in the scheduler agenda, longer calls are stored as
+/// preimages. See `frame_support::traits::BoundedInline`.
+const MAX_INLINE_CALL_LEN: usize = 128;
+
+/// Storage overrides applied on top of the snapshot.
+///
+/// Maps hex encoded storage keys, or storage item names in the form `Pallet.Item`, to their new
+/// values.
+pub(crate) type StorageOverrides = BTreeMap<String, StorageOverride>;
+
+/// The new value of an overridden storage key or storage item.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum StorageOverride {
+	/// The new SCALE encoded value, or `None` to remove the value.
+	Value(Option<Bytes>),
+	/// The new values of storage map entries, keyed by the SCALE encoded map keys.
+	Map(Vec<(MapKey, Option<Bytes>)>),
+}
+
+/// The SCALE encoded keys of a storage map entry.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum MapKey {
+	/// The key of a map with a single key.
+	Single(Bytes),
+	/// The keys of a map with several keys, like a double map.
+	Multi(Vec<Bytes>),
+}
+
+impl MapKey {
+	fn into_keys(self) -> Vec<Vec<u8>> {
+		match self {
+			MapKey::Single(key) => vec![key.0],
+			MapKey::Multi(keys) => keys.into_iter().map(|key| key.0).collect(),
+		}
+	}
+}
+
+/// A call dispatched in the first block on top of the snapshot.
+///
+/// The call is added to the agenda of the scheduler pallet, so it is dispatched with any origin,
+/// without requiring the sudo pallet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dispatch {
+	/// The SCALE encoded `OriginCaller` of the runtime, `Root` if `None`.
+	pub origin: Option<Vec<u8>>,
+	/// The SCALE encoded call.
+	pub call: Vec<u8>,
+}
+
+impl FromStr for Dispatch {
+	type Err = String;
+
+	/// Parses a hex encoded call, optionally prefixed by a hex encoded origin and `:`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (origin, call) = match s.split_once(':') {
+			Some((origin, call)) => (Some(origin), call),
+			None => (None, s),
+		};
+		let origin = origin
+			.map(|origin| from_hex(origin).map_err(|e| format!("Invalid origin {origin}: {e}")))
+			.transpose()?;
+		let call = from_hex(call).map_err(|e| format!("Invalid call {call}: {e}"))?;
+
+		Ok(Dispatch { origin, call })
+	}
+}
+
+/// Reads the storage overrides from a JSON file.
+///
+/// The file contains a single object mapping the storage keys to their new values, see
+/// `ForkCmd::storage_overrides`.
+pub(crate) fn read_storage_overrides(path: &Path) -> sc_service::error::Result<StorageOverrides> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open storage overrides file {}: {e}", path.display()))?;
+
+	Ok(serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Failed to parse storage overrides file {}: {e}", path.display()))?)
+}
+
+/// The storage key of `frame_system::BlockHash` for the genesis block.
+fn genesis_block_hash_key<Block: BlockT>() -> Vec<u8> {
+	let number = NumberFor::<Block>::zero().encode();
+
+	[&twox_128(b"System")[..], &twox_128(b"BlockHash")[..], &twox_64(&number)[..], &number[..]]
+		.concat()
+}
+
+/// Hashes a storage map `key` with `hasher`.
+fn hash_map_key(hasher: &StorageHasher, key: &[u8]) -> Vec<u8> {
+	match hasher {
+		StorageHasher::Blake2_128 => blake2_128(key).to_vec(),
+		StorageHasher::Blake2_256 => blake2_256(key).to_vec(),
+		StorageHasher::Blake2_128Concat => [&blake2_128(key)[..], key].concat(),
+		StorageHasher::Twox128 => twox_128(key).to_vec(),
+		StorageHasher::Twox256 => twox_256(key).to_vec(),
+		StorageHasher::Twox64Concat => [&twox_64(key)[..], key].concat(),
+		StorageHasher::Identity => key.to_vec(),
+	}
+}
+
+/// The storage key of the storage item `pallet.item` for the SCALE encoded map `keys`.
+fn storage_key(
+	metadata: &Metadata,
+	pallet: &str,
+	item: &str,
+	keys: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, String> {
+	let storage = metadata
+		.pallet_by_name(pallet)
+		.and_then(|pallet| pallet.storage())
+		.ok_or_else(|| format!("The runtime doesn't contain storage items of `{pallet}`"))?;
+	let entry = storage
+		.entry_by_name(item)
+		.ok_or_else(|| format!("The runtime doesn't contain the storage item `{pallet}.{item}`"))?;
+	let hashers = match entry.entry_type() {
+		StorageEntryType::Plain(_) => &[][..],
+		StorageEntryType::Map { hashers, .. } => &hashers[..],
+	};
+	if hashers.len() != keys.len() {
+		return Err(format!(
+			"The storage item `{pallet}.{item}` has {} keys, but {} were given",
+			hashers.len(),
+			keys.len()
+		))
+	}
+
+	let mut storage_key =
+		[twox_128(storage.prefix().as_bytes()), twox_128(item.as_bytes())].concat();
+	for (hasher, key) in hashers.iter().zip(keys) {
+		storage_key.extend(hash_map_key(hasher, &key));
+	}
+	Ok(storage_key)
+}
+
+/// Sets or removes the value at `key`.
+fn set_storage(top: &mut BTreeMap<Vec<u8>, Vec<u8>>, key: Vec<u8>, value: Option<Bytes>) {
+	match value {
+		Some(value) => top.insert(key, value.0),
+		None => top.remove(&key),
+	};
+}
+
+/// Applies the `overrides` to the `top` storage and schedules the `dispatches` for the block
+/// `when`.
+///
+/// Overrides of raw storage keys are applied first, so that the storage items are resolved with
+/// the metadata of an overridden runtime code.
+fn apply_overrides(
+	top: &mut BTreeMap<Vec<u8>, Vec<u8>>,
+	overrides: StorageOverrides,
+	dispatches: &[Dispatch],
+	when: Vec<u8>,
+) -> Result<(), String> {
+	let mut items = Vec::new();
+	for (key, value) in overrides {
+		if !key.starts_with("0x") {
+			items.push((key, value));
+			continue
+		}
+
+		let raw_key = from_hex(&key).map_err(|e| format!("Invalid storage key {key}: {e}"))?;
+		match value {
+			StorageOverride::Value(value) => set_storage(top, raw_key, value),
+			StorageOverride::Map(_) =>
+				return Err(format!("The storage key {key} can only be overridden with a value")),
+		}
+	}
+
+	if items.is_empty() && dispatches.is_empty() {
+		return Ok(())
+	}
+
+	let code = top.get(CODE).ok_or("The state snapshot doesn't contain the runtime code")?;
+	let metadata = metadata_from_code(code)
+		.map_err(|e| format!("Failed to fetch the metadata of the runtime: {e}"))?;
+
+	for (name, value) in items {
+		let (pallet, item) =
+			name.split_once('.').ok_or_else(|| {
+				format!("Invalid storage override `{name}`, expected a hex encoded key or `Pallet.Item`")
+			})?;
+		match value {
+			StorageOverride::Value(value) =>
+				set_storage(top, storage_key(&metadata, pallet, item, Vec::new())?, value),
+			StorageOverride::Map(entries) =>
+				for (key, value) in entries {
+					set_storage(top, storage_key(&metadata, pallet, item, key.into_keys())?, value);
+				},
+		}
+	}
+
+	schedule_dispatches(top, &metadata, dispatches, when)
+}
+
+/// Appends the `dispatches` to the scheduler agenda of the block `when`.
+///
+/// Calls too long to be stored inline are added as requested preimages, which are removed by the
+/// scheduler after dispatching them. The runtime is assumed to hash preimages with `BlakeTwo256`.
+fn schedule_dispatches(
+	top: &mut BTreeMap<Vec<u8>, Vec<u8>>,
+	metadata: &Metadata,
+	dispatches: &[Dispatch],
+	when: Vec<u8>,
+) -> Result<(), String> {
+	if dispatches.is_empty() {
+		return Ok(())
+	}
+
+	let system_index = metadata
+		.pallet_by_name("System")
+		.ok_or("The runtime doesn't contain the `System` pallet")?
+		.index();
+	let agenda_key = storage_key(metadata, "Scheduler", "Agenda", vec![when])?;
+
+	let (mut len, mut agenda) = match top.get(&agenda_key) {
+		Some(agenda) => {
+			let mut input = &agenda[..];
+			let len = Compact::<u32>::decode(&mut input)
+				.map_err(|e| format!("Failed to decode the scheduler agenda: {e}"))?;
+			(len.0, input.to_vec())
+		},
+		None => (0, Vec::new()),
+	};
+
+	for dispatch in dispatches {
+		let call = if dispatch.call.len() <= MAX_INLINE_CALL_LEN {
+			// `Bounded::Inline(call)`
+			(1u8, &dispatch.call).encode()
+		} else {
+			let hash = blake2_256(&dispatch.call);
+			let call_len = dispatch.call.len() as u32;
+
+			let status_key =
+				storage_key(metadata, "Preimage", "RequestStatusFor", vec![hash.encode()])?;
+			if !top.contains_key(&status_key) {
+				let preimage_key = storage_key(
+					metadata,
+					"Preimage",
+					"PreimageFor",
+					vec![(hash, call_len).encode()],
+				)?;
+				top.insert(preimage_key, dispatch.call.encode());
+				// `RequestStatus::Requested { maybe_ticket: None, count: 1, maybe_len: Some(len) }`
+				top.insert(status_key, (1u8, 0u8, 1u32, Some(call_len)).encode());
+			}
+
+			// `Bounded::Lookup { hash, len }`
+			(2u8, hash, call_len).encode()
+		};
+		let origin = dispatch.origin.clone().unwrap_or_else(|| {
+			// `OriginCaller::system(RawOrigin::Root)`
+			vec![system_index, 0]
+		});
+
+		// `Some(Scheduled { maybe_id: None, priority: 0, call, maybe_periodic: None, origin })`
+		agenda.extend([1, 0, 0]);
+		agenda.extend(call);
+		agenda.push(0);
+		agenda.extend(origin);
+		len += 1;
+	}
+
+	top.insert(agenda_key, [Compact(len).encode(), agenda].concat());
+	Ok(())
+}
+
+/// Loads the state snapshot at `path`, applies the `overrides` and schedules the `dispatches`
+/// for the first block on top of the snapshot.
+///
+/// Besides the given overrides, the genesis hash stored in `frame_system` is replaced by
+/// `genesis_hash`, so that transactions signed for the forked node pass the genesis check.
+async fn load_snapshot<Block>(
+	path: &Path,
+	overrides: StorageOverrides,
+	dispatches: &[Dispatch],
+	genesis_hash: Block::Hash,
+) -> sc_service::error::Result<(Block::Header, Storage)>
+where
+	Block: BlockT,
+	Block::Hash: DeserializeOwned,
+	Block::Header: DeserializeOwned,
+{
+	let mut ext = Builder::<Block>::new()
+		.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+		.inject_hashed_key_value(vec![(
+			genesis_block_hash_key::<Block>().into(),
+			genesis_hash.encode().into(),
+		)])
+		.build()
+		.await
+		.map_err(|e| format!("Failed to load state snapshot {}: {e}", path.display()))?;
+
+	let backend = ext.as_backend();
+	let pairs = |child_info: Option<ChildInfo>| {
+		backend
+			.pairs(IterArgs { child_info, ..Default::default() })
+			.and_then(|pairs| pairs.collect::<Result<BTreeMap<_, _>, _>>())
+			.map_err(|e| format!("Failed to read the state snapshot: {e}"))
+	};
+
+	let mut storage = Storage::default();
+	for (key, value) in pairs(None)? {
+		match key.strip_prefix(DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			Some(storage_key) => {
+				let child_info = ChildInfo::new_default(storage_key);
+				let data = pairs(Some(child_info.clone()))?;
+				storage
+					.children_default
+					.insert(storage_key.to_vec(), StorageChild { data, child_info });
+			},
+			None => {
+				storage.top.insert(key, value);
+			},
+		}
+	}
+
+	let when = (*ext.header.number() + One::one()).encode();
+	apply_overrides(&mut storage.top, overrides, dispatches, when)?;
+
+	Ok((ext.header.clone(), storage))
+}
+
+/// Imports the state snapshot at `path` as the finalized best block of `client`.
+///
+/// The header of the snapshot is kept, apart from the state root, which is recomputed because the
+/// overrides change the state. The parent of the imported block is unknown to the database, just
+/// like after warp sync.
+///
+/// Does nothing if the database already contains blocks besides genesis.
+pub(crate) async fn import_snapshot<Block, Client, Executor>(
+	client: &Client,
+	executor: &Executor,
+	path: &Path,
+	overrides: StorageOverrides,
+	dispatches: &[Dispatch],
+) -> sc_service::error::Result<()>
+where
+	Block: BlockT,
+	Block::Hash: DeserializeOwned,
+	Block::Header: DeserializeOwned,
+	Client: BlockImport<Block> + HeaderBackend<Block>,
+	Executor: RuntimeVersionOf,
+{
+	let info = client.info();
+	if !info.best_number.is_zero() {
+		log::info!(
+			"🍴 Database already contains the fork at #{} ({}), not importing the snapshot again",
+			info.best_number,
+			info.best_hash,
+		);
+		return Ok(())
+	}
+
+	let (mut header, storage) =
+		load_snapshot::<Block>(path, overrides, dispatches, info.genesis_hash).await?;
+
+	let state_version =
+		sc_service::resolve_state_version_from_wasm::<_, HashingFor<Block>>(&storage, executor)?;
+	let state = InMemoryBackend::<HashingFor<Block>>::from((storage.clone(), state_version));
+	header.set_state_root(state.storage_root(std::iter::empty(), state_version).0);
+
+	let mut levels = vec![KeyValueStorageLevel {
+		state_root: Vec::new(),
+		parent_storage_keys: Vec::new(),
+		key_values: storage.top.into_iter().collect(),
+	}];
+	for child in storage.children_default.into_values() {
+		levels.push(KeyValueStorageLevel {
+			state_root: state
+				.child_storage_root(&child.child_info, std::iter::empty(), state_version)
+				.0
+				.encode(),
+			parent_storage_keys: vec![child.child_info.prefixed_storage_key().into_inner()],
+			key_values: child.data.into_iter().collect(),
+		});
+	}
+
+	let hash = header.hash();
+	let number = *header.number();
+
+	let mut params = BlockImportParams::new(BlockOrigin::File, header);
+	params.state_action = StateAction::ApplyChanges(StorageChanges::Import(ImportedState {
+		block: hash,
+		state: KeyValueStates(levels),
+	}));
+	params.finalized = true;
+	params.fork_choice = Some(ForkChoiceStrategy::Custom(true));
+
+	match client.import_block(params).await {
+		Ok(ImportResult::Imported(_)) => {
+			log::info!("🍴 Forked from state snapshot at #{number} ({hash})");
+			Ok(())
+		},
+		Ok(result) => Err(format!("Failed to import the state snapshot: {result:?}").into()),
+		Err(e) => Err(format!("Failed to import the state snapshot: {e}").into()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::types::ParachainHostFunctions;
+	use cumulus_test_runtime::{Block, Header};
+	use sc_client_api::blockchain::{BlockStatus, Info};
+	use sc_consensus::BlockCheckParams;
+	use sc_executor::WasmExecutor;
+	use sp_core::{hexdisplay::HexDisplay, H256};
+	use sp_runtime::{traits::BlakeTwo256, Digest};
+	use sp_state_machine::{StateVersion, TestExternalities};
+	use std::sync::Mutex;
+
+	struct TestClient {
+		best_number: u32,
+		imported: Mutex<Vec<BlockImportParams<Block>>>,
+	}
+
+	impl TestClient {
+		fn new(best_number: u32) -> Self {
+			Self { best_number, imported: Default::default() }
+		}
+	}
+
+	const GENESIS_HASH: H256 = H256::repeat_byte(7);
+
+	impl HeaderBackend<Block> for TestClient {
+		fn header(&self, _: H256) -> sc_client_api::blockchain::Result<Option<Header>> {
+			Ok(None)
+		}
+
+		fn info(&self) -> Info<Block> {
+			Info {
+				best_hash: GENESIS_HASH,
+				best_number: self.best_number,
+				genesis_hash: GENESIS_HASH,
+				finalized_hash: GENESIS_HASH,
+				finalized_number: self.best_number,
+				finalized_state: None,
+				number_leaves: 1,
+				block_gap: None,
+			}
+		}
+
+		fn status(&self, _: H256) -> sc_client_api::blockchain::Result<BlockStatus> {
+			Ok(BlockStatus::Unknown)
+		}
+
+		fn number(&self, _: H256) -> sc_client_api::blockchain::Result<Option<u32>> {
+			Ok(None)
+		}
+
+		fn hash(&self, _: u32) -> sc_client_api::blockchain::Result<Option<H256>> {
+			Ok(None)
+		}
+	}
+
+	#[async_trait::async_trait]
+	impl BlockImport<Block> for TestClient {
+		type Error = sp_consensus::Error;
+
+		async fn check_block(
+			&self,
+			_: BlockCheckParams<Block>,
+		) -> Result<ImportResult, Self::Error> {
+			Ok(ImportResult::imported(false))
+		}
+
+		async fn import_block(
+			&self,
+			block: BlockImportParams<Block>,
+		) -> Result<ImportResult, Self::Error> {
+			self.imported.lock().unwrap().push(block);
+			Ok(ImportResult::imported(true))
+		}
+	}
+
+	fn hex(data: &[u8]) -> String {
+		format!("0x{}", HexDisplay::from(&data))
+	}
+
+	fn alice() -> Vec<u8> {
+		[1u8; 32].to_vec()
+	}
+
+	/// Writes a snapshot in the format of `remote-externalities` with the runtime of
+	/// `cumulus-test-runtime`.
+	fn write_snapshot(path: &Path, top: Vec<(Vec<u8>, Vec<u8>)>) -> Header {
+		let storage = Storage { top: top.into_iter().collect(), ..Default::default() };
+		let ext = TestExternalities::<BlakeTwo256>::new_with_code_and_state(
+			cumulus_test_runtime::WASM_BINARY.unwrap(),
+			storage,
+			StateVersion::V1,
+		);
+		let (raw_storage, storage_root) = ext.into_raw_snapshot();
+		let header = Header::new(
+			41,
+			Default::default(),
+			storage_root,
+			H256::repeat_byte(1),
+			Digest::default(),
+		);
+
+		let snapshot =
+			(Compact(4u16), StateVersion::V1, raw_storage, storage_root, header.clone()).encode();
+		std::fs::write(path, snapshot).unwrap();
+		header
+	}
+
+	fn imported_top(params: &BlockImportParams<Block>) -> BTreeMap<Vec<u8>, Vec<u8>> {
+		match &params.state_action {
+			StateAction::ApplyChanges(StorageChanges::Import(state)) =>
+				state.state.0[0].key_values.iter().cloned().collect(),
+			_ => panic!("the snapshot is imported as state"),
+		}
+	}
+
+	#[tokio::test]
+	async fn import_snapshot_applies_overrides() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("state.snap");
+		let header = write_snapshot(
+			&path,
+			vec![(b"removed".to_vec(), vec![1]), (b"kept".to_vec(), vec![2])],
+		);
+
+		let overrides: StorageOverrides = serde_json::from_value(serde_json::json!({
+			hex(b"removed"): null,
+			hex(b"added"): "0x03",
+			"Sudo.Key": hex(&alice()),
+			"System.Account": [[hex(&alice()), "0x04"]],
+		}))
+		.unwrap();
+
+		let client = TestClient::new(0);
+		let executor = WasmExecutor::<ParachainHostFunctions>::builder().build();
+		import_snapshot(&client, &executor, &path, overrides, &[]).await.unwrap();
+
+		let imported = client.imported.lock().unwrap();
+		assert_eq!(imported.len(), 1);
+		let params = &imported[0];
+		assert!(params.finalized);
+		assert_eq!(params.header.number(), header.number());
+		assert_eq!(params.header.parent_hash(), header.parent_hash());
+
+		let top = imported_top(params);
+		assert_eq!(top.get(&b"removed"[..]), None);
+		assert_eq!(top.get(&b"kept"[..]), Some(&vec![2]));
+		assert_eq!(top.get(&b"added"[..]), Some(&vec![3]));
+		assert_eq!(top.get(&genesis_block_hash_key::<Block>()), Some(&GENESIS_HASH.encode()));
+		assert_eq!(top.get(&[twox_128(b"Sudo"), twox_128(b"Key")].concat()), Some(&alice()),);
+		let account_key =
+			[&twox_128(b"System")[..], &twox_128(b"Account")[..], &blake2_128(&alice()), &alice()]
+				.concat();
+		assert_eq!(top.get(&account_key), Some(&vec![4]));
+
+		let state = InMemoryBackend::<BlakeTwo256>::from((
+			Storage { top, ..Default::default() },
+			StateVersion::V1,
+		));
+		assert_eq!(
+			*params.header.state_root(),
+			state.storage_root(std::iter::empty(), StateVersion::V1).0,
+		);
+	}
+
+	#[tokio::test]
+	async fn import_snapshot_rejects_unknown_storage_items() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("state.snap");
+		write_snapshot(&path, Vec::new());
+
+		let overrides: StorageOverrides =
+			serde_json::from_value(serde_json::json!({ "Unknown.Item": "0x00" })).unwrap();
+
+		let client = TestClient::new(0);
+		let executor = WasmExecutor::<ParachainHostFunctions>::builder().build();
+		assert!(import_snapshot(&client, &executor, &path, overrides, &[]).await.is_err());
+
+		// The test runtime doesn't contain the scheduler pallet to dispatch the calls with.
+		let dispatch = Dispatch::from_str("0x0000").unwrap();
+		assert!(import_snapshot(&client, &executor, &path, Default::default(), &[dispatch])
+			.await
+			.is_err());
+		assert!(client.imported.lock().unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn import_snapshot_keeps_existing_fork() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("state.snap");
+		write_snapshot(&path, Vec::new());
+
+		let client = TestClient::new(42);
+		let executor = WasmExecutor::<ParachainHostFunctions>::builder().build();
+		import_snapshot(&client, &executor, &path, Default::default(), &[])
+			.await
+			.unwrap();
+
+		assert!(client.imported.lock().unwrap().is_empty());
+	}
+
+	#[test]
+	fn parse_dispatch() {
+		assert_eq!(
+			Dispatch::from_str("0x0001").unwrap(),
+			Dispatch { origin: None, call: vec![0, 1] }
+		);
+		assert_eq!(
+			Dispatch::from_str("0x0e02:0x0001").unwrap(),
+			Dispatch { origin: Some(vec![14, 2]), call: vec![0, 1] }
+		);
+		assert!(Dispatch::from_str("0xzz").is_err());
+	}
+}
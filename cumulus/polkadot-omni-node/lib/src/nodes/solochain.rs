@@ -0,0 +1,1042 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Node implementation for standalone chains, which author blocks with Aura or BABE and finalize
+//! them with GRANDPA. A BEEFY gadget is started if the runtime exposes the BEEFY and MMR APIs.
+
+use crate::{
+	cli::DevSealMode,
+	common::{
+		aura::{AuraIdT, AuraRuntimeApi},
+		command::{AsyncCmdResult, NodeCommandRunner, SyncCmdResult},
+		rpc::{BuildParachainRpcExtensions, BuildRpcExtensions},
+		spec::DynNodeSpec,
+		statement_store::{build_statement_store, new_statement_handler_proto},
+		types::{
+			AccountId, Balance, Hash, Nonce, ParachainBackend, ParachainClient,
+			ParachainHostFunctions,
+		},
+		ConstructNodeRuntimeApi, NodeBlock, NodeExtraArgs,
+	},
+};
+use cumulus_client_cli::{CollatorOptions, ExportGenesisHeadCommand};
+use frame_benchmarking_cli::BlockCmd;
+#[cfg(any(feature = "runtime-benchmarks"))]
+use frame_benchmarking_cli::StorageCmd;
+use futures::FutureExt;
+use log::info;
+use prometheus_endpoint::Registry;
+use sc_cli::{CheckBlockCmd, ExportBlocksCmd, ExportStateCmd, ImportBlocksCmd, RevertCmd};
+use sc_client_api::Backend;
+use sc_consensus::{DefaultImportQueue, LongestChain};
+use sc_consensus_babe::{BabeBlockImport, BabeLink};
+use sc_consensus_beefy::{import::BeefyBlockImport, BeefyVoterLinks};
+use sc_consensus_grandpa::{GrandpaBlockImport, LinkHalf, SharedVoterState};
+use sc_executor::{HeapAllocStrategy, DEFAULT_HEAP_ALLOC_STRATEGY};
+use sc_network::{config::FullNetworkConfiguration, NetworkBackend};
+use sc_network_sync::SyncingService;
+use sc_service::{Configuration, PartialComponents, TaskManager, WarpSyncConfig};
+use sc_sysinfo::HwBench;
+use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker};
+use sc_transaction_pool::TransactionPoolHandle;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_consensus_babe::inherents::BabeCreateInherentDataProviders;
+use sp_consensus_beefy::{ecdsa_crypto::AuthorityId as BeefyId, BeefyApi, MmrRootHash};
+use sp_consensus_grandpa::GrandpaApi;
+use sp_keystore::KeystorePtr;
+use sp_mmr_primitives::MmrApi;
+use sp_runtime::{app_crypto::AppCrypto, traits::NumberFor};
+use std::{future::Future, marker::PhantomData, pin::Pin, sync::Arc, time::Duration};
+
+/// The minimum period of blocks on which GRANDPA justifications will be imported and generated.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
+/// The minimum number of blocks between two BEEFY votes.
+const BEEFY_MIN_BLOCK_DELTA: u32 = 8;
+
+type SolochainSelectChain<Block> = LongestChain<ParachainBackend<Block>, Block>;
+
+type SolochainGrandpaBlockImport<Block, RuntimeApi> = GrandpaBlockImport<
+	ParachainBackend<Block>,
+	Block,
+	ParachainClient<Block, RuntimeApi>,
+	SolochainSelectChain<Block>,
+>;
+
+type SolochainBeefyBlockImport<Block, RuntimeApi> = BeefyBlockImport<
+	Block,
+	ParachainBackend<Block>,
+	ParachainClient<Block, RuntimeApi>,
+	SolochainGrandpaBlockImport<Block, RuntimeApi>,
+	BeefyId,
+>;
+
+type SolochainTransactionPool<Block, RuntimeApi> =
+	TransactionPoolHandle<Block, ParachainClient<Block, RuntimeApi>>;
+
+/// Assembly of PartialComponents (enough to run chain ops subcommands)
+type SolochainService<Block, RuntimeApi, BlockImport, Link> = PartialComponents<
+	ParachainClient<Block, RuntimeApi>,
+	ParachainBackend<Block>,
+	SolochainSelectChain<Block>,
+	DefaultImportQueue<Block>,
+	SolochainTransactionPool<Block, RuntimeApi>,
+	(
+		BlockImport,
+		Link,
+		LinkHalf<Block, ParachainClient<Block, RuntimeApi>, SolochainSelectChain<Block>>,
+		BeefyVoterLinks<Block, BeefyId>,
+		Option<Telemetry>,
+	),
+>;
+
+/// Convenience trait that defines the basic bounds of a solochain runtime, besides the ones
+/// required by the block authoring consensus.
+pub(crate) trait SolochainRuntimeApi<Block: NodeBlock>:
+	GrandpaApi<Block>
+	+ BeefyApi<Block, BeefyId>
+	+ MmrApi<Block, MmrRootHash, NumberFor<Block>>
+	+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+	+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+	+ Sized
+{
+}
+
+impl<T, Block: NodeBlock> SolochainRuntimeApi<Block> for T where
+	T: GrandpaApi<Block>
+		+ BeefyApi<Block, BeefyId>
+		+ MmrApi<Block, MmrRootHash, NumberFor<Block>>
+		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+{
+}
+
+/// Checks whether the runtime exposes the APIs required by the BEEFY gadget.
+fn has_beefy_api<Block: NodeBlock, RuntimeApi>(
+	client: &ParachainClient<Block, RuntimeApi>,
+	at: Block::Hash,
+) -> bool
+where
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: SolochainRuntimeApi<Block>,
+{
+	let runtime_api = client.runtime_api();
+	runtime_api.has_api::<dyn BeefyApi<Block, BeefyId>>(at).unwrap_or(false) &&
+		runtime_api
+			.has_api::<dyn MmrApi<Block, MmrRootHash, NumberFor<Block>>>(at)
+			.unwrap_or(false)
+}
+
+/// The block authoring consensus of a solochain.
+pub(crate) trait SolochainConsensus<Block: NodeBlock, RuntimeApi>
+where
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+{
+	/// The block import used by the import queue and by block authoring.
+	type BlockImport: sc_consensus::BlockImport<Block, Error = sp_consensus::Error>
+		+ Clone
+		+ Send
+		+ Sync
+		+ 'static;
+
+	/// Data shared between the import queue and block authoring.
+	type Link: Send + 'static;
+
+	/// Wrap the given block import and build the import queue on top of it.
+	fn build_import_queue(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: SolochainBeefyBlockImport<Block, RuntimeApi>,
+		justification_import: SolochainGrandpaBlockImport<Block, RuntimeApi>,
+		select_chain: SolochainSelectChain<Block>,
+		transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		config: &Configuration,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> sc_service::error::Result<(DefaultImportQueue<Block>, Self::BlockImport, Self::Link)>;
+
+	/// Start authoring blocks.
+	fn start_authoring(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: Self::BlockImport,
+		link: Self::Link,
+		select_chain: SolochainSelectChain<Block>,
+		transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		sync_service: Arc<SyncingService<Block>>,
+		keystore: KeystorePtr,
+		force_authoring: bool,
+		prometheus_registry: Option<&Registry>,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> Result<(), sc_service::Error>;
+}
+
+/// Author blocks with Aura.
+pub(crate) struct SolochainAura<AuraId>(PhantomData<AuraId>);
+
+impl<Block: NodeBlock, RuntimeApi, AuraId> SolochainConsensus<Block, RuntimeApi>
+	for SolochainAura<AuraId>
+where
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId> + SolochainRuntimeApi<Block>,
+	AuraId: AuraIdT + Sync + Send,
+	<AuraId as AppCrypto>::Pair: Send + Sync,
+{
+	type BlockImport = SolochainBeefyBlockImport<Block, RuntimeApi>;
+	type Link = ();
+
+	fn build_import_queue(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: SolochainBeefyBlockImport<Block, RuntimeApi>,
+		justification_import: SolochainGrandpaBlockImport<Block, RuntimeApi>,
+		_select_chain: SolochainSelectChain<Block>,
+		_transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		config: &Configuration,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> sc_service::error::Result<(DefaultImportQueue<Block>, Self::BlockImport, ())> {
+		let client_for_cidp = client.clone();
+		let import_queue =
+			sc_consensus_aura::import_queue::<<AuraId as AppCrypto>::Pair, _, _, _, _, _>(
+				sc_consensus_aura::ImportQueueParams {
+					block_import: block_import.clone(),
+					justification_import: Some(Box::new(justification_import)),
+					client,
+					create_inherent_data_providers: move |parent_hash, _| {
+						let client = client_for_cidp.clone();
+						async move {
+							let slot_duration = sc_consensus_aura::standalone::slot_duration_at(
+								&*client,
+								parent_hash,
+							)?;
+							let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+							let slot =
+								sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+									*timestamp,
+									slot_duration,
+								);
+
+							Ok((slot, timestamp))
+						}
+					},
+					spawner: &task_manager.spawn_essential_handle(),
+					registry: config.prometheus_registry(),
+					check_for_equivocation: Default::default(),
+					telemetry,
+					compatibility_mode: Default::default(),
+				},
+			)?;
+
+		Ok((import_queue, block_import, ()))
+	}
+
+	fn start_authoring(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: Self::BlockImport,
+		_: (),
+		select_chain: SolochainSelectChain<Block>,
+		transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		sync_service: Arc<SyncingService<Block>>,
+		keystore: KeystorePtr,
+		force_authoring: bool,
+		prometheus_registry: Option<&Registry>,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> Result<(), sc_service::Error> {
+		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+			task_manager.spawn_handle(),
+			client.clone(),
+			transaction_pool,
+			prometheus_registry,
+			telemetry.clone(),
+		);
+
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+		let aura = sc_consensus_aura::start_aura::<
+			<AuraId as AppCrypto>::Pair,
+			_,
+			_,
+			_,
+			_,
+			_,
+			_,
+			_,
+			_,
+			_,
+			_,
+		>(sc_consensus_aura::StartAuraParams {
+			slot_duration,
+			client,
+			select_chain,
+			block_import,
+			proposer_factory,
+			create_inherent_data_providers: move |_, ()| async move {
+				let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+				let slot =
+					sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+						*timestamp,
+						slot_duration,
+					);
+
+				Ok((slot, timestamp))
+			},
+			force_authoring,
+			backoff_authoring_blocks: None::<()>,
+			keystore,
+			sync_oracle: sync_service.clone(),
+			justification_sync_link: sync_service,
+			block_proposal_slot_portion: sc_consensus_aura::SlotProportion::new(2f32 / 3f32),
+			max_block_proposal_slot_portion: None,
+			telemetry,
+			compatibility_mode: Default::default(),
+		})?;
+
+		task_manager
+			.spawn_essential_handle()
+			.spawn_blocking("aura", Some("block-authoring"), aura);
+
+		Ok(())
+	}
+}
+
+/// Author blocks with BABE.
+pub(crate) struct SolochainBabe;
+
+impl<Block: NodeBlock, RuntimeApi> SolochainConsensus<Block, RuntimeApi> for SolochainBabe
+where
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: sp_consensus_babe::BabeApi<Block> + SolochainRuntimeApi<Block>,
+{
+	type BlockImport = BabeBlockImport<
+		Block,
+		ParachainClient<Block, RuntimeApi>,
+		SolochainBeefyBlockImport<Block, RuntimeApi>,
+		BabeCreateInherentDataProviders<Block>,
+		SolochainSelectChain<Block>,
+	>;
+	type Link = BabeLink<Block>;
+
+	fn build_import_queue(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: SolochainBeefyBlockImport<Block, RuntimeApi>,
+		justification_import: SolochainGrandpaBlockImport<Block, RuntimeApi>,
+		select_chain: SolochainSelectChain<Block>,
+		transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		config: &Configuration,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> sc_service::error::Result<(DefaultImportQueue<Block>, Self::BlockImport, Self::Link)> {
+		let babe_config = sc_consensus_babe::configuration(&*client)?;
+		let slot_duration = babe_config.slot_duration();
+		let (block_import, babe_link) = sc_consensus_babe::block_import(
+			babe_config,
+			block_import,
+			client.clone(),
+			Arc::new(move |_, _| async move {
+				let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+				let slot =
+					sp_consensus_babe::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+						*timestamp,
+						slot_duration,
+					);
+
+				Ok((slot, timestamp))
+			}) as BabeCreateInherentDataProviders<Block>,
+			select_chain,
+			OffchainTransactionPoolFactory::new(transaction_pool),
+		)?;
+
+		let (import_queue, _babe_worker_handle) =
+			sc_consensus_babe::import_queue(sc_consensus_babe::ImportQueueParams {
+				link: babe_link.clone(),
+				block_import: block_import.clone(),
+				justification_import: Some(Box::new(justification_import)),
+				client,
+				slot_duration,
+				spawner: &task_manager.spawn_essential_handle(),
+				registry: config.prometheus_registry(),
+				telemetry,
+			})?;
+
+		Ok((import_queue, block_import, babe_link))
+	}
+
+	fn start_authoring(
+		client: Arc<ParachainClient<Block, RuntimeApi>>,
+		block_import: Self::BlockImport,
+		babe_link: Self::Link,
+		select_chain: SolochainSelectChain<Block>,
+		transaction_pool: Arc<SolochainTransactionPool<Block, RuntimeApi>>,
+		sync_service: Arc<SyncingService<Block>>,
+		keystore: KeystorePtr,
+		force_authoring: bool,
+		prometheus_registry: Option<&Registry>,
+		telemetry: Option<TelemetryHandle>,
+		task_manager: &TaskManager,
+	) -> Result<(), sc_service::Error> {
+		let proposer = sc_basic_authorship::ProposerFactory::new(
+			task_manager.spawn_handle(),
+			client.clone(),
+			transaction_pool,
+			prometheus_registry,
+			telemetry.clone(),
+		);
+
+		let slot_duration = babe_link.config().slot_duration();
+		let babe = sc_consensus_babe::start_babe(sc_consensus_babe::BabeParams {
+			keystore,
+			client,
+			select_chain,
+			env: proposer,
+			block_import,
+			sync_oracle: sync_service.clone(),
+			justification_sync_link: sync_service,
+			create_inherent_data_providers: move |_, ()| async move {
+				let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+				let slot =
+					sp_consensus_babe::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+						*timestamp,
+						slot_duration,
+					);
+
+				Ok((slot, timestamp))
+			},
+			force_authoring,
+			backoff_authoring_blocks: None::<()>,
+			babe_link,
+			block_proposal_slot_portion: sc_consensus_babe::SlotProportion::new(0.5),
+			max_block_proposal_slot_portion: None,
+			telemetry,
+		})?;
+
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"babe-proposer",
+			Some("block-authoring"),
+			babe,
+		);
+
+		Ok(())
+	}
+}
+
+/// Start a standalone chain node, authoring blocks with the given `Consensus` and finalizing them
+/// with GRANDPA.
+pub(crate) struct SolochainNode<Block, RuntimeApi, Consensus>(
+	PhantomData<(Block, RuntimeApi, Consensus)>,
+);
+
+impl<Block, RuntimeApi, Consensus> Default for SolochainNode<Block, RuntimeApi, Consensus> {
+	fn default() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<Block, RuntimeApi, Consensus> SolochainNode<Block, RuntimeApi, Consensus>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: SolochainRuntimeApi<Block>,
+	Consensus: SolochainConsensus<Block, RuntimeApi> + 'static,
+{
+	/// Starts a `ServiceBuilder` for a full service.
+	fn new_partial(
+		config: &Configuration,
+	) -> sc_service::error::Result<
+		SolochainService<Block, RuntimeApi, Consensus::BlockImport, Consensus::Link>,
+	> {
+		let telemetry = config
+			.telemetry_endpoints
+			.clone()
+			.filter(|x| !x.is_empty())
+			.map(|endpoints| -> Result<_, sc_telemetry::Error> {
+				let worker = TelemetryWorker::new(16)?;
+				let telemetry = worker.handle().new_telemetry(endpoints);
+				Ok((worker, telemetry))
+			})
+			.transpose()?;
+
+		let heap_pages =
+			config.executor.default_heap_pages.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |h| {
+				HeapAllocStrategy::Static { extra_pages: h as _ }
+			});
+
+		let executor = sc_executor::WasmExecutor::<ParachainHostFunctions>::builder()
+			.with_execution_method(config.executor.wasm_method)
+			.with_max_runtime_instances(config.executor.max_runtime_instances)
+			.with_runtime_cache_size(config.executor.runtime_cache_size)
+			.with_onchain_heap_alloc_strategy(heap_pages)
+			.with_offchain_heap_alloc_strategy(heap_pages)
+			.build();
+
+		let (client, backend, keystore_container, task_manager) =
+			sc_service::new_full_parts::<Block, RuntimeApi, _>(
+				config,
+				telemetry.as_ref().map(|(_, telemetry)| telemetry.handle()),
+				executor,
+			)?;
+		let client = Arc::new(client);
+
+		let telemetry = telemetry.map(|(worker, telemetry)| {
+			task_manager.spawn_handle().spawn("telemetry", None, worker.run());
+			telemetry
+		});
+
+		let select_chain = LongestChain::new(backend.clone());
+
+		let transaction_pool = Arc::from(
+			sc_transaction_pool::Builder::new(
+				task_manager.spawn_essential_handle(),
+				client.clone(),
+				config.role.is_authority().into(),
+			)
+			.with_options(config.transaction_pool.clone())
+			.with_prometheus(config.prometheus_registry())
+			.build(),
+		);
+
+		let (grandpa_block_import, grandpa_link) = sc_consensus_grandpa::block_import(
+			client.clone(),
+			GRANDPA_JUSTIFICATION_PERIOD,
+			&client,
+			select_chain.clone(),
+			telemetry.as_ref().map(|telemetry| telemetry.handle()),
+		)?;
+
+		let (beefy_block_import, beefy_voter_links, _beefy_rpc_links) =
+			sc_consensus_beefy::beefy_block_import_and_links(
+				grandpa_block_import.clone(),
+				backend.clone(),
+				client.clone(),
+				config.prometheus_registry().cloned(),
+			);
+
+		let (import_queue, block_import, consensus_link) = Consensus::build_import_queue(
+			client.clone(),
+			beefy_block_import,
+			grandpa_block_import,
+			select_chain.clone(),
+			transaction_pool.clone(),
+			config,
+			telemetry.as_ref().map(|telemetry| telemetry.handle()),
+			&task_manager,
+		)?;
+
+		Ok(PartialComponents {
+			backend,
+			client,
+			import_queue,
+			keystore_container,
+			task_manager,
+			transaction_pool,
+			select_chain,
+			other: (block_import, consensus_link, grandpa_link, beefy_voter_links, telemetry),
+		})
+	}
+
+	/// Start a solochain node with the given configuration.
+	fn start_node<Net>(
+		config: Configuration,
+		hwbench: Option<HwBench>,
+		node_extra_args: NodeExtraArgs,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>>
+	where
+		Net: NetworkBackend<Block, Hash>,
+	{
+		Box::pin(async move {
+			let PartialComponents {
+				client,
+				backend,
+				mut task_manager,
+				import_queue,
+				keystore_container,
+				select_chain,
+				transaction_pool,
+				other:
+					(block_import, consensus_link, grandpa_link, beefy_voter_links, mut telemetry),
+			} = Self::new_partial(&config)?;
+
+			let role = config.role;
+			let force_authoring = config.force_authoring;
+			let enable_grandpa = !config.disable_grandpa;
+			let is_offchain_indexing_enabled = config.offchain_worker.indexing_enabled;
+			let node_name = config.network.node_name.clone();
+			let prometheus_registry = config.prometheus_registry().cloned();
+			let database_path = config.database.path().map(|p| p.to_path_buf());
+
+			let mut net_config = FullNetworkConfiguration::<_, _, Net>::new(
+				&config.network,
+				prometheus_registry.clone(),
+			);
+			let metrics = Net::register_notification_metrics(prometheus_registry.as_ref());
+			let peer_store_handle = net_config.peer_store_handle();
+			let genesis_hash = client.chain_info().genesis_hash;
+
+			let grandpa_protocol_name =
+				sc_consensus_grandpa::protocol_standard_name(&genesis_hash, &config.chain_spec);
+			let (grandpa_protocol_config, grandpa_notification_service) =
+				sc_consensus_grandpa::grandpa_peers_set_config::<_, Net>(
+					grandpa_protocol_name.clone(),
+					metrics.clone(),
+					peer_store_handle.clone(),
+				);
+			net_config.add_notification_protocol(grandpa_protocol_config);
+
+			// The BEEFY protocols are only registered if the runtime supports BEEFY, so that
+			// the node doesn't advertise protocols it doesn't serve.
+			let beefy_enabled = has_beefy_api(&*client, client.chain_info().best_hash);
+			let beefy_network_setup = beefy_enabled.then(|| {
+				let gossip_protocol_name = sc_consensus_beefy::gossip_protocol_name(
+					&genesis_hash,
+					config.chain_spec.fork_id(),
+				);
+				let (on_demand_justifications_handler, request_response_config) =
+					sc_consensus_beefy::communication::request_response::BeefyJustifsRequestHandler::new::<_, Net>(
+						&genesis_hash,
+						config.chain_spec.fork_id(),
+						client.clone(),
+						prometheus_registry.clone(),
+					);
+				let (notification_config, notification_service) =
+					sc_consensus_beefy::communication::beefy_peers_set_config::<_, Net>(
+						gossip_protocol_name.clone(),
+						metrics.clone(),
+						peer_store_handle.clone(),
+					);
+				net_config.add_notification_protocol(notification_config);
+				net_config.add_request_response_protocol(request_response_config);
+
+				(gossip_protocol_name, on_demand_justifications_handler, notification_service)
+			});
+
+			let statement_handler_proto = node_extra_args
+				.enable_statement_store
+				.then(|| new_statement_handler_proto(&*client, &config, &metrics, &mut net_config));
+
+			let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+				backend.clone(),
+				grandpa_link.shared_authority_set().clone(),
+				Vec::default(),
+			));
+
+			let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+				sc_service::build_network(sc_service::BuildNetworkParams {
+					config: &config,
+					net_config,
+					client: client.clone(),
+					transaction_pool: transaction_pool.clone(),
+					spawn_handle: task_manager.spawn_handle(),
+					import_queue,
+					block_announce_validator_builder: None,
+					warp_sync_config: Some(WarpSyncConfig::WithProvider(warp_sync)),
+					block_relay: None,
+					metrics,
+				})?;
+
+			let statement_store = statement_handler_proto
+				.map(|statement_handler_proto| {
+					build_statement_store(
+						&config,
+						&mut task_manager,
+						client.clone(),
+						network.clone(),
+						sync_service.clone(),
+						keystore_container.local_keystore(),
+						statement_handler_proto,
+					)
+				})
+				.transpose()?;
+
+			if config.offchain_worker.enabled {
+				let custom_extensions = {
+					let statement_store = statement_store.clone();
+					move |_hash| {
+						if let Some(statement_store) = &statement_store {
+							vec![Box::new(statement_store.clone().as_statement_store_ext())
+								as Box<_>]
+						} else {
+							vec![]
+						}
+					}
+				};
+
+				let offchain_workers =
+					sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+						runtime_api_provider: client.clone(),
+						keystore: Some(keystore_container.keystore()),
+						offchain_db: backend.offchain_storage(),
+						transaction_pool: Some(OffchainTransactionPoolFactory::new(
+							transaction_pool.clone(),
+						)),
+						network_provider: Arc::new(network.clone()),
+						is_validator: role.is_authority(),
+						enable_http_requests: true,
+						enable_websocket_connections: config.offchain_worker.websocket_enabled,
+						outbound_policy: config.offchain_worker.outbound_policy.clone(),
+						custom_extensions,
+					})?;
+				task_manager.spawn_handle().spawn(
+					"offchain-workers-runner",
+					"offchain-work",
+					offchain_workers.run(client.clone(), task_manager.spawn_handle()).boxed(),
+				);
+			}
+
+			let rpc_builder = {
+				let client = client.clone();
+				let transaction_pool = transaction_pool.clone();
+				let backend_for_rpc = backend.clone();
+				let statement_store = statement_store.clone();
+
+				Box::new(move |_| {
+					BuildParachainRpcExtensions::<Block, RuntimeApi>::build_rpc_extensions(
+						client.clone(),
+						backend_for_rpc.clone(),
+						transaction_pool.clone(),
+						statement_store.clone(),
+					)
+				})
+			};
+
+			sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+				rpc_builder,
+				client: client.clone(),
+				transaction_pool: transaction_pool.clone(),
+				task_manager: &mut task_manager,
+				config,
+				keystore: keystore_container.keystore(),
+				backend: backend.clone(),
+				network: network.clone(),
+				sync_service: sync_service.clone(),
+				system_rpc_tx,
+				tx_handler_controller,
+				telemetry: telemetry.as_mut(),
+				tracing_execute_block: None,
+			})?;
+
+			// Spawn the storage monitor
+			if let Some(database_path) = database_path {
+				sc_storage_monitor::StorageMonitorService::try_spawn(
+					node_extra_args.storage_monitor.clone(),
+					database_path,
+					&task_manager.spawn_essential_handle(),
+				)
+				.map_err(|e| sc_service::Error::Application(Box::new(e) as Box<_>))?;
+			}
+
+			if let Some(hwbench) = hwbench {
+				sc_sysinfo::print_hwbench(&hwbench);
+				if role.is_authority() {
+					if let Err(err) = frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE
+						.check_hardware(&hwbench, false)
+					{
+						log::warn!(
+							"⚠️  The hardware does not meet the minimal requirements {} for role 'Authority'.",
+							err
+						);
+					}
+				}
+
+				if let Some(ref mut telemetry) = telemetry {
+					let telemetry_handle = telemetry.handle();
+					task_manager.spawn_handle().spawn(
+						"telemetry_hwbench",
+						None,
+						sc_sysinfo::initialize_hwbench_telemetry(telemetry_handle, hwbench),
+					);
+				}
+			}
+
+			if role.is_authority() {
+				Consensus::start_authoring(
+					client.clone(),
+					block_import,
+					consensus_link,
+					select_chain,
+					transaction_pool.clone(),
+					sync_service.clone(),
+					keystore_container.keystore(),
+					force_authoring,
+					prometheus_registry.as_ref(),
+					telemetry.as_ref().map(|t| t.handle()),
+					&task_manager,
+				)?;
+			}
+
+			// If the node isn't actively participating in consensus then it doesn't need a
+			// keystore, regardless of which protocol we use below.
+			let keystore = role.is_authority().then(|| keystore_container.keystore());
+
+			if let Some((
+				gossip_protocol_name,
+				on_demand_justifications_handler,
+				notification_service,
+			)) = beefy_network_setup
+			{
+				info!("🥩 Runtime exposes the BEEFY API, starting the BEEFY gadget.");
+				let network_params = sc_consensus_beefy::BeefyNetworkParams {
+					network: Arc::new(network.clone()),
+					sync: sync_service.clone(),
+					gossip_protocol_name,
+					justifications_protocol_name: on_demand_justifications_handler.protocol_name(),
+					notification_service,
+					_phantom: PhantomData::<Block>,
+				};
+				let beefy_params = sc_consensus_beefy::BeefyParams {
+					client: client.clone(),
+					backend: backend.clone(),
+					payload_provider: sp_consensus_beefy::mmr::MmrRootProvider::new(client.clone()),
+					runtime: client.clone(),
+					key_store: keystore.clone(),
+					network_params,
+					min_block_delta: BEEFY_MIN_BLOCK_DELTA,
+					prometheus_registry: prometheus_registry.clone(),
+					links: beefy_voter_links,
+					on_demand_justifications_handler,
+					is_authority: role.is_authority(),
+				};
+
+				// BEEFY is part of consensus, if it fails we'll bring the node down with it to
+				// make sure it is noticed.
+				task_manager.spawn_essential_handle().spawn_blocking(
+					"beefy-gadget",
+					None,
+					sc_consensus_beefy::start_beefy_gadget::<_, _, _, _, _, _, _, _>(beefy_params),
+				);
+
+				// When offchain indexing is enabled, MMR gadget should also run.
+				if is_offchain_indexing_enabled {
+					task_manager.spawn_essential_handle().spawn_blocking(
+						"mmr-gadget",
+						None,
+						mmr_gadget::MmrGadget::start(
+							client.clone(),
+							backend.clone(),
+							sp_mmr_primitives::INDEXING_PREFIX.to_vec(),
+						),
+					);
+				}
+			}
+
+			if enable_grandpa {
+				let grandpa_config = sc_consensus_grandpa::Config {
+					gossip_duration: Duration::from_millis(333),
+					justification_generation_period: GRANDPA_JUSTIFICATION_PERIOD,
+					name: Some(node_name),
+					observer_enabled: false,
+					keystore,
+					local_role: role,
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					protocol_name: grandpa_protocol_name,
+				};
+
+				// Start the full GRANDPA voter.
+				//
+				// NOTE: non-authorities could run the GRANDPA observer protocol, but at this point
+				// the full voter should provide better guarantees of block and vote data
+				// availability than the observer.
+				let grandpa_params = sc_consensus_grandpa::GrandpaParams {
+					config: grandpa_config,
+					link: grandpa_link,
+					network,
+					sync: Arc::new(sync_service),
+					notification_service: grandpa_notification_service,
+					telemetry: telemetry.as_ref().map(|x| x.handle()),
+					voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
+					prometheus_registry,
+					shared_voter_state: SharedVoterState::empty(),
+					offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool),
+				};
+
+				// The GRANDPA voter task is considered infallible, i.e. if it fails we take down
+				// the service with it.
+				task_manager.spawn_essential_handle().spawn_blocking(
+					"grandpa-voter",
+					None,
+					sc_consensus_grandpa::run_grandpa_voter(grandpa_params)?,
+				);
+			}
+
+			Ok(task_manager)
+		})
+	}
+}
+
+impl<Block, RuntimeApi, Consensus> NodeCommandRunner for SolochainNode<Block, RuntimeApi, Consensus>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: SolochainRuntimeApi<Block>,
+	Consensus: SolochainConsensus<Block, RuntimeApi> + 'static,
+{
+	fn prepare_check_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &CheckBlockCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, partial.import_queue)), partial.task_manager))
+	}
+
+	fn prepare_export_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, config.database)), partial.task_manager))
+	}
+
+	fn prepare_export_state_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportStateCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, config.chain_spec)), partial.task_manager))
+	}
+
+	fn prepare_import_blocks_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ImportBlocksCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		Ok((Box::pin(cmd.run(partial.client, partial.import_queue)), partial.task_manager))
+	}
+
+	fn prepare_revert_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &RevertCmd,
+	) -> AsyncCmdResult<'_> {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		let aux_revert = Box::new(|client, _, blocks| {
+			sc_consensus_grandpa::revert(client, blocks)?;
+			Ok(())
+		});
+		Ok((
+			Box::pin(cmd.run(partial.client, partial.backend, Some(aux_revert))),
+			partial.task_manager,
+		))
+	}
+
+	fn run_export_genesis_head_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &ExportGenesisHeadCommand,
+	) -> SyncCmdResult {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		cmd.run(partial.client)
+	}
+
+	fn run_benchmark_block_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &BlockCmd,
+	) -> SyncCmdResult {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		cmd.run(partial.client)
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks"))]
+	fn run_benchmark_storage_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &StorageCmd,
+	) -> SyncCmdResult {
+		let partial = Self::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		let db = partial.backend.expose_db();
+		let storage = partial.backend.expose_storage();
+		let shared_trie_cache = partial.backend.expose_shared_trie_cache();
+
+		cmd.run(config, partial.client, db, storage, shared_trie_cache)
+	}
+}
+
+impl<Block, RuntimeApi, Consensus> DynNodeSpec for SolochainNode<Block, RuntimeApi, Consensus>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: SolochainRuntimeApi<Block>,
+	Consensus: SolochainConsensus<Block, RuntimeApi> + 'static,
+{
+	fn is_solochain(&self) -> bool {
+		true
+	}
+
+	fn start_dev_node(
+		self: Box<Self>,
+		_config: Configuration,
+		_mode: DevSealMode,
+	) -> sc_service::error::Result<TaskManager> {
+		Err(sc_service::Error::Other(
+			"Dev seal modes are not supported for solochains, start an authority with `--alice` \
+			instead"
+				.into(),
+		))
+	}
+
+	fn start_node(
+		self: Box<Self>,
+		_parachain_config: Configuration,
+		_polkadot_config: Configuration,
+		_collator_options: CollatorOptions,
+		_hwbench: Option<HwBench>,
+		_node_extra_args: NodeExtraArgs,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>> {
+		Box::pin(async {
+			Err(sc_service::Error::Other("Solochain nodes don't follow a relay chain".into()))
+		})
+	}
+
+	fn start_solochain_node(
+		self: Box<Self>,
+		config: Configuration,
+		hwbench: Option<HwBench>,
+		node_extra_args: NodeExtraArgs,
+	) -> Pin<Box<dyn Future<Output = sc_service::error::Result<TaskManager>>>> {
+		match config.network.network_backend {
+			sc_network::config::NetworkBackendType::Libp2p => Self::start_node::<
+				sc_network::NetworkWorker<_, _>,
+			>(config, hwbench, node_extra_args),
+			sc_network::config::NetworkBackendType::Litep2p => Self::start_node::<
+				sc_network::Litep2pNetworkBackend,
+			>(
+				config, hwbench, node_extra_args
+			),
+		}
+	}
+}
+
+pub(crate) fn new_solochain_aura_node_spec<Block, RuntimeApi, AuraId>() -> Box<dyn DynNodeSpec>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: AuraRuntimeApi<Block, AuraId> + SolochainRuntimeApi<Block>,
+	AuraId: AuraIdT + Sync + Send + 'static,
+	<AuraId as AppCrypto>::Pair: Send + Sync,
+{
+	Box::new(SolochainNode::<Block, RuntimeApi, SolochainAura<AuraId>>::default())
+}
+
+pub(crate) fn new_solochain_babe_node_spec<Block, RuntimeApi>() -> Box<dyn DynNodeSpec>
+where
+	Block: NodeBlock,
+	RuntimeApi: ConstructNodeRuntimeApi<Block, ParachainClient<Block, RuntimeApi>>,
+	RuntimeApi::RuntimeApi: sp_consensus_babe::BabeApi<Block> + SolochainRuntimeApi<Block>,
+{
+	Box::new(SolochainNode::<Block, RuntimeApi, SolochainBabe>::default())
+}
@@ -513,4 +513,16 @@ sp_api::decl_runtime_apis! {
 		/// Returns the target number of blocks per relay chain slot.
 		fn target_block_rate() -> u32;
 	}
+
+	/// API to tell the node side which additional relay chain storage is required by the runtime.
+	///
+	/// The collator includes the values of the returned keys in the relay chain state proof that is
+	/// passed to the runtime with the parachain inherent. The runtime can then read and verify these
+	/// values against the storage root of the relay parent.
+	pub trait RelayStateKeysApi {
+		/// Relay chain storage keys to include in the relay chain state proof.
+		///
+		/// The keys are requested at the parent of the block that is being built.
+		fn relay_state_keys() -> Vec<Vec<u8>>;
+	}
 }
@@ -40,7 +40,10 @@ use sp_runtime::{
 use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm, WrapVersion};
 use xcm_builder::{InspectMessageQueues, TakeRevenue};
 use xcm_executor::{
-	traits::{MatchesFungibles, TransactAsset, WeightTrader},
+	traits::{
+		message_topic, MatchesFungibles, MessageStatus, RecordMessageStatus, TransactAsset,
+		WeightTrader,
+	},
 	AssetsInHolding,
 };
 
@@ -51,25 +54,32 @@ mod tests;
 /// the given UMP `UpwardMessageSender` implementation. Thus this essentially adapts an
 /// `UpwardMessageSender` trait impl into a `SendXcm` trait impl.
 ///
+/// The messages with a topic put onto the UMP queue are recorded as queued by `R`.
+///
 /// NOTE: This is a pretty dumb "just send it" router; we will probably want to introduce queuing
 /// to UMP eventually and when we do, the pallet which implements the queuing will be responsible
 /// for the `SendXcm` implementation.
-pub struct ParentAsUmp<T, W, P>(PhantomData<(T, W, P)>);
-impl<T, W, P> SendXcm for ParentAsUmp<T, W, P>
+pub struct ParentAsUmp<T, W, P, R = ()>(PhantomData<(T, W, P, R)>);
+impl<T, W, P, R> SendXcm for ParentAsUmp<T, W, P, R>
 where
 	T: UpwardMessageSender,
 	W: WrapVersion,
 	P: PriceForMessageDelivery<Id = ()>,
+	R: RecordMessageStatus,
 {
-	type Ticket = Vec<u8>;
+	type Ticket = (Vec<u8>, Option<XcmHash>);
 
-	fn validate(dest: &mut Option<Location>, msg: &mut Option<Xcm<()>>) -> SendResult<Vec<u8>> {
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<(Vec<u8>, Option<XcmHash>)> {
 		let d = dest.take().ok_or(SendError::MissingArgument)?;
 
 		if d.contains_parents_only(1) {
 			// An upward message for the relay chain.
 			let xcm = msg.take().ok_or(SendError::MissingArgument)?;
 			let price = P::price_for_delivery((), &xcm);
+			let topic = message_topic(&xcm);
 			let versioned_xcm =
 				W::wrap_version(&d, xcm).map_err(|()| SendError::DestinationUnsupported)?;
 			versioned_xcm
@@ -80,7 +90,7 @@ where
 			// Pre-check with our message sender if everything else is okay.
 			T::can_send_upward_message(&data).map_err(Self::map_upward_sender_err)?;
 
-			Ok((data, price))
+			Ok(((data, topic), price))
 		} else {
 			// Anything else is unhandled. This includes a message that is not meant for us.
 			// We need to make sure that dest/msg is not consumed here.
@@ -89,8 +99,14 @@ where
 		}
 	}
 
-	fn deliver(data: Vec<u8>) -> Result<XcmHash, SendError> {
+	fn deliver((data, topic): (Vec<u8>, Option<XcmHash>)) -> Result<XcmHash, SendError> {
 		let (_, hash) = T::send_upward_message(data).map_err(Self::map_upward_sender_err)?;
+		if let Some(topic) = topic {
+			R::record_message_status(
+				topic,
+				MessageStatus::Queued { destination: Location::parent() },
+			);
+		}
 		Ok(hash)
 	}
 
@@ -102,7 +118,7 @@ where
 	}
 }
 
-impl<T, W, P> ParentAsUmp<T, W, P> {
+impl<T, W, P, R> ParentAsUmp<T, W, P, R> {
 	fn map_upward_sender_err(message_send_error: MessageSendError) -> SendError {
 		match message_send_error {
 			MessageSendError::TooBig => SendError::ExceedsMaxMessageSize,
@@ -111,8 +127,8 @@ impl<T, W, P> ParentAsUmp<T, W, P> {
 	}
 }
 
-impl<T: UpwardMessageSender + InspectMessageQueues, W, P> InspectMessageQueues
-	for ParentAsUmp<T, W, P>
+impl<T: UpwardMessageSender + InspectMessageQueues, W, P, R> InspectMessageQueues
+	for ParentAsUmp<T, W, P, R>
 {
 	fn clear_messages() {
 		T::clear_messages();
@@ -680,6 +696,47 @@ mod test_xcm_router {
 		);
 	}
 
+	#[test]
+	fn parent_as_ump_records_queued_messages() {
+		std::thread_local! {
+			static RECORDED: core::cell::RefCell<Vec<(XcmHash, MessageStatus)>> =
+				core::cell::RefCell::new(Vec::new());
+		}
+		struct MockedRecorder;
+		impl RecordMessageStatus for MockedRecorder {
+			fn record_message_status(topic: XcmHash, status: MessageStatus) -> Weight {
+				RECORDED.with(|r| r.borrow_mut().push((topic, status)));
+				Weight::zero()
+			}
+
+			fn record_message_status_weight() -> Weight {
+				Weight::zero()
+			}
+		}
+		struct OkUpwardMessageSender;
+		impl UpwardMessageSender for OkUpwardMessageSender {
+			fn send_upward_message(_: UpwardMessage) -> Result<(u32, XcmHash), MessageSendError> {
+				Ok((0, [0; 32]))
+			}
+
+			fn can_send_upward_message(_: &UpwardMessage) -> Result<(), MessageSendError> {
+				Ok(())
+			}
+		}
+		type Router = ParentAsUmp<OkUpwardMessageSender, (), (), MockedRecorder>;
+
+		// A message without a topic is not recorded.
+		assert_ok!(send_xcm::<Router>(Parent.into(), Xcm(vec![ClearOrigin])));
+		assert!(RECORDED.with(|r| r.borrow().is_empty()));
+
+		let topic = [1; 32];
+		assert_ok!(send_xcm::<Router>(Parent.into(), Xcm(vec![ClearOrigin, SetTopic(topic)])));
+		assert_eq!(
+			RECORDED.with(|r| r.take()),
+			vec![(topic, MessageStatus::Queued { destination: Location::parent() })]
+		);
+	}
+
 	#[test]
 	fn parent_as_ump_validate_nested_xcm_works() {
 		let dest = Parent;
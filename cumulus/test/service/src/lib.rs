@@ -427,6 +427,7 @@ where
 		import_queue: import_queue_service,
 		relay_chain_slot_duration,
 		recovery_handle,
+		pov_fallback_sources: Vec::new(),
 		sync_service: sync_service.clone(),
 		prometheus_registry: None,
 	})?;
@@ -843,10 +844,15 @@ pub fn node_config(
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
 			request_logger_limit: 1024,
+			rate_limit_overrides: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
-		offchain_worker: OffchainWorkerConfig { enabled: true, indexing_enabled: false },
+		offchain_worker: OffchainWorkerConfig {
+			enabled: true,
+			indexing_enabled: false,
+			..Default::default()
+		},
 		force_authoring: false,
 		disable_grandpa: false,
 		dev_key_seed: Some(key_seed),
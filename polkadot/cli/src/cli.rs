@@ -166,6 +166,27 @@ pub struct RunCmd {
 	///  **Dangerous!** Do not touch unless explicitly advised to.
 	#[arg(long, hide = true)]
 	pub collator_protocol_hold_off: Option<u64>,
+
+	/// Directory where the inputs of failed candidate validations are dumped.
+	///
+	/// The dumps can be replayed locally with `polkadot-pvf-replay`. Only used by validators.
+	#[arg(long, value_name = "PATH")]
+	pub pvf_dump_dir: Option<PathBuf>,
+
+	/// Also dump successful candidate validations taking at least this long. The value is in
+	/// milliseconds.
+	#[arg(long, value_name = "MILLIS", requires = "pvf_dump_dir")]
+	pub pvf_dump_slow_threshold: Option<u64>,
+
+	/// The maximum number of candidate validation dumps kept in `--pvf-dump-dir`.
+	#[arg(long, value_name = "COUNT", default_value_t = 100, requires = "pvf_dump_dir")]
+	pub pvf_dump_max_count: usize,
+
+	/// Serve the `disputes_*` RPC methods for inspecting ongoing and recent disputes.
+	///
+	/// The methods are unsafe and only available on validators.
+	#[arg(long)]
+	pub enable_dispute_rpc: bool,
 }
 
 #[allow(missing_docs)]
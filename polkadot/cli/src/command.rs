@@ -258,6 +258,12 @@ where
 	let collator_protocol_hold_off = cli.run.collator_protocol_hold_off.map(Duration::from_millis);
 	let invulnerable_ah_collators = get_invulnerable_ah_collators(&chain_spec);
 
+	let pvf_dump = cli.run.pvf_dump_dir.clone().map(|dir| polkadot_service::PvfDumpConfig {
+		dir,
+		slow_threshold: cli.run.pvf_dump_slow_threshold.map(Duration::from_millis),
+		max_dumps: cli.run.pvf_dump_max_count,
+	});
+
 	runner.run_node_until_exit(move |config| async move {
 		let hwbench = (!cli.run.no_hardware_benchmarks)
 			.then(|| {
@@ -292,6 +298,8 @@ where
 				keep_finalized_for: cli.run.keep_finalized_for,
 				invulnerable_ah_collators,
 				collator_protocol_hold_off,
+				pvf_dump,
+				enable_dispute_rpc: cli.run.enable_dispute_rpc,
 			},
 		)
 		.map(|full| full.task_manager)?;
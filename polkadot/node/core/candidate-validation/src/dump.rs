@@ -0,0 +1,196 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dumping the inputs of failed or slow validations to disk.
+
+use crate::{pvf_exec_timeout, pvf_prep_timeout, PvfDumpConfig, LOG_TARGET};
+use futures::{channel::mpsc, Future, StreamExt};
+use polkadot_node_core_pvf::dump::{count_dumps, ValidationDump};
+use polkadot_node_primitives::{PoV, ValidationResult};
+use polkadot_node_subsystem::messages::{PvfExecKind, ValidationFailed};
+use polkadot_parachain_primitives::primitives::BlockData;
+use polkadot_primitives::{
+	CandidateReceiptV2 as CandidateReceipt, ExecutorParams, Hash, PersistedValidationData,
+	PvfPrepKind, ValidationCode, ValidationCodeHash,
+};
+use std::{sync::Arc, time::Instant};
+
+/// The number of concluded dumps waiting to be written before new ones are dropped.
+const DUMP_QUEUE_SIZE: usize = 4;
+
+/// The inputs of a validation in flight, dumped once the validation concludes if needed.
+pub(crate) struct PendingDump {
+	config: Arc<PvfDumpConfig>,
+	dump: ValidationDump,
+	persisted_validation_data: Arc<PersistedValidationData>,
+	pov: Arc<PoV>,
+	started: Instant,
+}
+
+impl PendingDump {
+	pub(crate) fn new(
+		config: Arc<PvfDumpConfig>,
+		candidate_receipt: &CandidateReceipt,
+		persisted_validation_data: Arc<PersistedValidationData>,
+		pov: Arc<PoV>,
+		executor_params: &ExecutorParams,
+		exec_kind: PvfExecKind,
+		validation_code_bomb_limit: u32,
+	) -> Self {
+		let dump = ValidationDump {
+			candidate_hash: candidate_receipt.hash(),
+			para_id: candidate_receipt.descriptor.para_id(),
+			relay_parent: candidate_receipt.descriptor.relay_parent(),
+			validation_code_hash: candidate_receipt.descriptor.validation_code_hash(),
+			executor_params: executor_params.clone(),
+			// Both filled in once the validation concludes, to avoid copying them for every
+			// validation.
+			persisted_validation_data: Default::default(),
+			pov: PoV { block_data: BlockData(Vec::new()) },
+			exec_kind: exec_kind.into(),
+			validation_code_bomb_limit,
+			prep_timeout_millis: pvf_prep_timeout(executor_params, PvfPrepKind::Prepare).as_millis()
+				as u64,
+			exec_timeout_millis: pvf_exec_timeout(executor_params, exec_kind.into()).as_millis()
+				as u64,
+			elapsed_millis: 0,
+			outcome: String::new(),
+		};
+
+		Self { config, dump, persisted_validation_data, pov, started: Instant::now() }
+	}
+
+	/// Returns the dump to write if the validation failed or was slow.
+	pub(crate) fn conclude(
+		self,
+		result: &Result<ValidationResult, ValidationFailed>,
+	) -> Option<ConcludedDump> {
+		let Self { config, mut dump, persisted_validation_data, pov, started } = self;
+		let elapsed = started.elapsed();
+
+		dump.outcome = match result {
+			Ok(ValidationResult::Valid(..)) => {
+				if config.slow_threshold.map_or(true, |threshold| elapsed < threshold) {
+					return None
+				}
+				"Valid".into()
+			},
+			Ok(ValidationResult::Invalid(err)) => format!("Invalid({:?})", err),
+			Err(err) => format!("Failed({})", err.0),
+		};
+		dump.elapsed_millis = elapsed.as_millis() as u64;
+		dump.persisted_validation_data = (*persisted_validation_data).clone();
+		dump.pov = (*pov).clone();
+
+		Some(ConcludedDump { config, dump })
+	}
+}
+
+/// A dump of a failed or slow validation, waiting for its validation code to be written.
+pub(crate) struct ConcludedDump {
+	config: Arc<PvfDumpConfig>,
+	dump: ValidationDump,
+}
+
+impl ConcludedDump {
+	pub(crate) fn relay_parent(&self) -> Hash {
+		self.dump.relay_parent
+	}
+
+	pub(crate) fn validation_code_hash(&self) -> ValidationCodeHash {
+		self.dump.validation_code_hash
+	}
+
+	/// Write the dump to disk, unless the configured number of dumps is reached.
+	///
+	/// This does blocking filesystem work.
+	pub(crate) fn write(self, validation_code: &[u8]) {
+		let Self { config, dump } = self;
+
+		match count_dumps(&config.dir) {
+			Ok(n_dumps) if n_dumps >= config.max_dumps => {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?dump.candidate_hash,
+					dir = ?config.dir,
+					"Validation dump limit reached, skipping dump",
+				);
+				return
+			},
+			Ok(_) => {},
+			Err(err) => {
+				gum::warn!(target: LOG_TARGET, dir = ?config.dir, ?err, "Cannot read dump directory");
+				return
+			},
+		}
+
+		match dump.write(&config.dir, validation_code) {
+			Ok(path) => gum::info!(
+				target: LOG_TARGET,
+				candidate_hash = ?dump.candidate_hash,
+				para_id = ?dump.para_id,
+				outcome = %dump.outcome,
+				?path,
+				"Dumped candidate validation inputs",
+			),
+			Err(err) => gum::warn!(
+				target: LOG_TARGET,
+				candidate_hash = ?dump.candidate_hash,
+				dir = ?config.dir,
+				?err,
+				"Failed to dump candidate validation inputs",
+			),
+		}
+	}
+}
+
+/// Hands concluded dumps over to a blocking task writing them to disk.
+#[derive(Clone)]
+pub(crate) struct DumpWriter {
+	config: Arc<PvfDumpConfig>,
+	queue: mpsc::Sender<(ConcludedDump, ValidationCode)>,
+}
+
+impl DumpWriter {
+	/// Create a new writer, along with the task writing the dumps which is meant to be spawned as
+	/// a blocking task.
+	pub(crate) fn new(config: PvfDumpConfig) -> (Self, impl Future<Output = ()> + Send) {
+		let (queue, mut dumps) = mpsc::channel::<(ConcludedDump, ValidationCode)>(DUMP_QUEUE_SIZE);
+		let task = async move {
+			while let Some((dump, validation_code)) = dumps.next().await {
+				dump.write(&validation_code.0);
+			}
+		};
+
+		(Self { config: Arc::new(config), queue }, task)
+	}
+
+	pub(crate) fn config(&self) -> Arc<PvfDumpConfig> {
+		self.config.clone()
+	}
+
+	/// Queue a dump for writing. The dump is dropped if too many dumps are queued already.
+	pub(crate) fn write(&mut self, dump: ConcludedDump, validation_code: ValidationCode) {
+		if let Err(err) = self.queue.try_send((dump, validation_code)) {
+			let (dump, _) = err.into_inner();
+			gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?dump.dump.candidate_hash,
+				"Validation dump queue is full, skipping dump",
+			);
+		}
+	}
+}
@@ -72,8 +72,12 @@ use std::{
 
 use async_trait::async_trait;
 
+mod dump;
 mod metrics;
-use self::metrics::Metrics;
+use self::{
+	dump::{DumpWriter, PendingDump},
+	metrics::Metrics,
+};
 
 #[cfg(test)]
 mod tests;
@@ -112,6 +116,21 @@ pub struct Config {
 	pub pvf_prepare_workers_soft_max_num: usize,
 	/// The absolute number of pvf workers that can be spawned in the pvf prepare pool.
 	pub pvf_prepare_workers_hard_max_num: usize,
+	/// Where to dump the inputs of failed or slow validations. `None` disables dumping.
+	pub pvf_dump: Option<PvfDumpConfig>,
+}
+
+/// Configuration for dumping the inputs of failed or slow validations to disk, so that they can be
+/// replayed with `polkadot-pvf-replay`.
+#[derive(Debug, Clone)]
+pub struct PvfDumpConfig {
+	/// The directory the dumps are written to.
+	pub dir: PathBuf,
+	/// Successful validations taking at least this long are dumped as well. `None` only dumps
+	/// failed validations.
+	pub slow_threshold: Option<Duration>,
+	/// The maximum number of dumps kept in `dir`. New dumps are skipped once it is reached.
+	pub max_dumps: usize,
 }
 
 /// The candidate validation subsystem.
@@ -173,6 +192,7 @@ fn handle_validation_message<S>(
 	mut sender: S,
 	validation_host: ValidationHost,
 	metrics: Metrics,
+	pvf_dump: Option<DumpWriter>,
 	msg: CandidateValidationMessage,
 ) -> Pin<Box<dyn Future<Output = ()> + Send>>
 where
@@ -228,6 +248,19 @@ where
 				return
 			};
 
+			let validation_data = Arc::new(validation_data);
+			let pending_dump = pvf_dump.as_ref().map(|writer| {
+				PendingDump::new(
+					writer.config(),
+					&candidate_receipt,
+					validation_data.clone(),
+					pov.clone(),
+					&executor_params,
+					exec_kind,
+					validation_code_bomb_limit,
+				)
+			});
+
 			let res = validate_candidate_exhaustive(
 				session_index,
 				validation_host,
@@ -244,7 +277,28 @@ where
 			.await;
 
 			metrics.on_validation_event(&res);
+			let concluded_dump = pending_dump.and_then(|pending_dump| pending_dump.conclude(&res));
 			let _ = response_sender.send(res);
+
+			// The validation code was handed over to the validation host, fetch it again for the
+			// dump.
+			if let (Some(dump), Some(mut writer)) = (concluded_dump, pvf_dump) {
+				match request_validation_code_by_hash(
+					&mut sender,
+					dump.relay_parent(),
+					dump.validation_code_hash(),
+				)
+				.await
+				{
+					Ok(Some(validation_code)) => writer.write(dump, validation_code),
+					Ok(None) | Err(_) => gum::debug!(
+						target: LOG_TARGET,
+						?relay_parent,
+						validation_code_hash = ?dump.validation_code_hash(),
+						"Cannot fetch validation code, skipping dump",
+					),
+				}
+			}
 		}
 		.boxed(),
 		CandidateValidationMessage::PreCheck {
@@ -315,6 +369,7 @@ async fn run<Context>(
 		pvf_execute_workers_max_num,
 		pvf_prepare_workers_soft_max_num,
 		pvf_prepare_workers_hard_max_num,
+		pvf_dump,
 	}: Config,
 ) -> SubsystemResult<()> {
 	let (mut validation_host, task) = polkadot_node_core_pvf::start(
@@ -333,6 +388,15 @@ async fn run<Context>(
 	.await?;
 	ctx.spawn_blocking("pvf-validation-host", task.boxed())?;
 
+	let pvf_dump = match pvf_dump {
+		Some(config) => {
+			let (writer, task) = DumpWriter::new(config);
+			ctx.spawn_blocking("pvf-dump-writer", task.boxed())?;
+			Some(writer)
+		},
+		None => None,
+	};
+
 	let mut tasks = FuturesUnordered::new();
 	let mut prepare_state = PrepareValidationState::default();
 
@@ -353,7 +417,13 @@ async fn run<Context>(
 						Ok(FromOrchestra::Signal(OverseerSignal::BlockFinalized(..))) => {},
 						Ok(FromOrchestra::Signal(OverseerSignal::Conclude)) => return Ok(()),
 						Ok(FromOrchestra::Communication { msg }) => {
-							let task = handle_validation_message(ctx.sender().clone(), validation_host.clone(), metrics.clone(), msg);
+							let task = handle_validation_message(
+								ctx.sender().clone(),
+								validation_host.clone(),
+								metrics.clone(),
+								pvf_dump.clone(),
+								msg,
+							);
 							tasks.push(task);
 							if tasks.len() >= TASK_LIMIT {
 								break
@@ -849,7 +919,7 @@ where
 async fn validate_candidate_exhaustive(
 	expected_session_index: SessionIndex,
 	mut validation_backend: impl ValidationBackend + Send,
-	persisted_validation_data: PersistedValidationData,
+	persisted_validation_data: impl Into<Arc<PersistedValidationData>>,
 	validation_code: ValidationCode,
 	candidate_receipt: CandidateReceipt,
 	pov: Arc<PoV>,
@@ -860,6 +930,7 @@ async fn validate_candidate_exhaustive(
 	validation_code_bomb_limit: u32,
 ) -> Result<ValidationResult, ValidationFailed> {
 	let _timer = metrics.time_validate_candidate_exhaustive();
+	let persisted_validation_data: Arc<PersistedValidationData> = persisted_validation_data.into();
 	let validation_code_hash = validation_code.hash();
 	let relay_parent = candidate_receipt.descriptor.relay_parent();
 	let para_id = candidate_receipt.descriptor.para_id();
@@ -892,7 +963,6 @@ async fn validate_candidate_exhaustive(
 		return Ok(ValidationResult::Invalid(e))
 	}
 
-	let persisted_validation_data = Arc::new(persisted_validation_data);
 	let result = match exec_kind {
 		// Retry is disabled to reduce the chance of nondeterministic blocks getting backed and
 		// honest backers getting slashed.
@@ -2218,3 +2218,76 @@ fn maybe_prepare_validation_does_not_prepare_already_prepared_pvfs() {
 	assert!(state.is_next_session_authority);
 	assert_eq!(state.already_prepared_code_hashes.len(), 3);
 }
+
+#[test]
+fn failed_and_slow_validations_are_dumped() {
+	let validation_data =
+		Arc::new(PersistedValidationData { max_pov_size: 1024, ..Default::default() });
+	let pov = Arc::new(PoV { block_data: BlockData(vec![1; 32]) });
+	let validation_code = ValidationCode(vec![2; 16]);
+	let descriptor = make_valid_candidate_descriptor(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		validation_data.hash(),
+		pov.hash(),
+		validation_code.hash(),
+		dummy_hash(),
+		dummy_hash(),
+		Sr25519Keyring::Alice,
+	)
+	.into();
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: Hash::zero() };
+
+	let dir = tempfile::tempdir().unwrap();
+	let pending_dump = |slow_threshold| {
+		let config =
+			Arc::new(PvfDumpConfig { dir: dir.path().to_owned(), slow_threshold, max_dumps: 2 });
+		PendingDump::new(
+			config,
+			&candidate_receipt,
+			validation_data.clone(),
+			pov.clone(),
+			&ExecutorParams::default(),
+			PvfExecKind::Approval,
+			VALIDATION_CODE_BOMB_LIMIT,
+		)
+	};
+	let valid = Ok(ValidationResult::Valid(Default::default(), (*validation_data).clone()));
+	let num_dumps = || polkadot_node_core_pvf::dump::count_dumps(dir.path()).unwrap();
+	let conclude = |pending_dump: PendingDump,
+	                result: &Result<ValidationResult, ValidationFailed>| {
+		if let Some(dump) = pending_dump.conclude(result) {
+			dump.write(&validation_code.0);
+		}
+	};
+
+	// Successful validations are only dumped if they are slow.
+	conclude(pending_dump(None), &valid);
+	conclude(pending_dump(Some(Duration::from_secs(60))), &valid);
+	assert_eq!(num_dumps(), 0);
+
+	conclude(pending_dump(Some(Duration::ZERO)), &valid);
+	assert_eq!(num_dumps(), 1);
+
+	std::thread::sleep(Duration::from_millis(2));
+	conclude(pending_dump(None), &Ok(ValidationResult::Invalid(InvalidCandidate::Timeout)));
+	assert_eq!(num_dumps(), 2);
+
+	// The limit is reached.
+	std::thread::sleep(Duration::from_millis(2));
+	conclude(pending_dump(None), &Err(ValidationFailed("oops".into())));
+	assert_eq!(num_dumps(), 2);
+
+	let mut dumps = std::fs::read_dir(dir.path())
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.filter(|path| path.extension().map_or(false, |ext| ext == "pvfdump"))
+		.map(|path| polkadot_node_core_pvf::dump::ValidationDump::read(&path).unwrap())
+		.collect::<Vec<_>>();
+	dumps.sort_by(|a, b| a.outcome.cmp(&b.outcome));
+	assert_eq!(dumps[0].outcome, "Invalid(Timeout)");
+	assert_eq!(dumps[1].outcome, "Valid");
+	assert_eq!(dumps[1].persisted_validation_data, *validation_data);
+	assert_eq!(dumps[1].pov, *pov);
+	assert_eq!(dumps[1].read_validation_code(dir.path()).unwrap(), validation_code.0);
+}
@@ -19,6 +19,7 @@
 use std::{
 	collections::{BTreeMap, VecDeque},
 	sync::Arc,
+	time::Instant,
 };
 
 use futures::{
@@ -29,8 +30,8 @@ use futures::{
 use sc_keystore::LocalKeystore;
 
 use polkadot_node_primitives::{
-	disputes::ValidCandidateVotes, CandidateVotes, DisputeStatus, SignedDisputeStatement,
-	Timestamp, DISPUTE_WINDOW,
+	dispute_is_inactive, disputes::ValidCandidateVotes, CandidateVotes, DisputeInspection,
+	DisputeStatus, SignedDisputeStatement, Timestamp, DISPUTE_WINDOW,
 };
 use polkadot_node_subsystem::{
 	messages::{
@@ -54,6 +55,7 @@ use crate::{
 	db::{self, v1::RecentDisputes},
 	error::{log_error, FatalError, FatalResult, JfyiError, JfyiResult, Result},
 	import::{CandidateEnvironment, CandidateVoteState},
+	inspection::Inspection,
 	is_potential_spam,
 	metrics::Metrics,
 	scraping::ScrapedUpdates,
@@ -108,6 +110,8 @@ pub(crate) struct Initialized {
 	participation: Participation,
 	scraper: ChainScraper,
 	participation_receiver: WorkerMessageReceiver,
+	/// Dispute information which is only kept for inspection.
+	inspection: Inspection,
 	/// Backlog of still to be imported votes from chain.
 	///
 	/// For some reason importing votes is relatively slow, if there is a large finality lag (~50
@@ -151,6 +155,7 @@ impl Initialized {
 			scraper,
 			participation,
 			participation_receiver,
+			inspection: Inspection::default(),
 			chain_import_backlog: VecDeque::new(),
 			metrics,
 		}
@@ -238,6 +243,7 @@ impl Initialized {
 							candidate_receipt,
 							outcome,
 						} = self.participation.get_participation_result(ctx, msg).await?;
+						self.inspection.note_participation(session, candidate_hash, outcome);
 						if let Some(valid) = outcome.validity() {
 							gum::trace!(
 								target: LOG_TARGET,
@@ -361,6 +367,7 @@ impl Initialized {
 					db::v1::note_earliest_session(overlay_db, prune_up_to)?;
 					self.spam_slots.prune_old(prune_up_to);
 					self.offchain_disabled_validators.prune_old(prune_up_to);
+					self.inspection.prune_old(prune_up_to);
 				},
 				Ok(_) => { /* no new session => nothing to cache */ },
 				Err(err) => {
@@ -866,6 +873,29 @@ impl Initialized {
 						.collect::<BTreeMap<_, _>>(),
 				);
 			},
+			DisputeCoordinatorMessage::InspectDisputes(tx) => {
+				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::InspectDisputes");
+				let recent_disputes = overlay_db.load_recent_disputes()?.unwrap_or_default();
+				let mut disputes = Vec::with_capacity(recent_disputes.len());
+				for (key, status) in recent_disputes {
+					let (session, candidate_hash) = key;
+					let votes = overlay_db
+						.load_candidate_votes(session, &candidate_hash)?
+						.map(CandidateVotes::from);
+					disputes.push(DisputeInspection {
+						session,
+						candidate_hash,
+						status,
+						active: !dispute_is_inactive(&status, &now),
+						votes,
+						participation: self.inspection.participation(&key),
+						spam_slots: self.spam_slots.usage(&key),
+						import_timing: self.inspection.import_timing(&key),
+					});
+				}
+
+				let _ = tx.send(disputes);
+			},
 			DisputeCoordinatorMessage::QueryCandidateVotes(query, tx) => {
 				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::QueryCandidateVotes");
 				let mut query_output = Vec::new();
@@ -940,6 +970,7 @@ impl Initialized {
 		now: Timestamp,
 	) -> FatalResult<ImportStatementsResult> {
 		gum::trace!(target: LOG_TARGET, ?statements, "In handle import statements");
+		let import_start = Instant::now();
 		if self.session_is_ancient(session) {
 			// It is not valid to participate in an ancient dispute (spam?) or too new.
 			return Ok(ImportStatementsResult::InvalidImport)
@@ -1317,6 +1348,11 @@ impl Initialized {
 			}
 		}
 
+		if is_disputed {
+			self.inspection
+				.note_import(session, candidate_hash, now, import_start.elapsed());
+		}
+
 		// Update metrics:
 		if import_result.is_freshly_disputed() {
 			self.metrics.on_open();
@@ -0,0 +1,89 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::{collections::HashMap, time::Duration};
+
+use polkadot_node_primitives::{
+	disputes::{DisputeImportTiming, ParticipationOutcome},
+	Timestamp,
+};
+use polkadot_primitives::{CandidateHash, SessionIndex};
+
+/// Information about disputes which is not persisted, but useful for node operators inspecting
+/// disputes.
+#[derive(Default)]
+pub struct Inspection {
+	/// Timing of the vote imports per dispute.
+	import_timings: HashMap<(SessionIndex, CandidateHash), DisputeImportTiming>,
+	/// Outcome of our participation per dispute.
+	participations: HashMap<(SessionIndex, CandidateHash), ParticipationOutcome>,
+}
+
+impl Inspection {
+	/// Note that votes for a disputed candidate have been imported.
+	pub fn note_import(
+		&mut self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		now: Timestamp,
+		duration: Duration,
+	) {
+		let timing =
+			self.import_timings
+				.entry((session, candidate_hash))
+				.or_insert(DisputeImportTiming {
+					first_import: now,
+					last_import: now,
+					imports: 0,
+					last_import_duration: duration,
+				});
+		timing.last_import = now;
+		timing.imports = timing.imports.saturating_add(1);
+		timing.last_import_duration = duration;
+	}
+
+	/// Note the outcome of our participation in a dispute.
+	pub fn note_participation(
+		&mut self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		outcome: ParticipationOutcome,
+	) {
+		self.participations.insert((session, candidate_hash), outcome);
+	}
+
+	/// Timing of the vote imports for the given dispute, if any were noted.
+	pub fn import_timing(
+		&self,
+		key: &(SessionIndex, CandidateHash),
+	) -> Option<DisputeImportTiming> {
+		self.import_timings.get(key).copied()
+	}
+
+	/// Outcome of our participation in the given dispute, if any was noted.
+	pub fn participation(
+		&self,
+		key: &(SessionIndex, CandidateHash),
+	) -> Option<ParticipationOutcome> {
+		self.participations.get(key).copied()
+	}
+
+	/// Prune all information about disputes of sessions older than the given index.
+	pub fn prune_old(&mut self, oldest_index: SessionIndex) {
+		self.import_timings.retain(|(session, _), _| *session >= oldest_index);
+		self.participations.retain(|(session, _), _| *session >= oldest_index);
+	}
+}
@@ -93,6 +93,10 @@ use scraping::ChainScraper;
 /// can be relatively small, as a drop is not fatal.
 mod spam_slots;
 
+/// In-memory bookkeeping of dispute information only needed for inspecting disputes via
+/// `DisputeCoordinatorMessage::InspectDisputes`.
+mod inspection;
+
 /// Handling of participation requests via `Participation`.
 ///
 /// `Participation` provides an API (`Participation::queue_participation`) for queuing of dispute
@@ -25,6 +25,7 @@ use futures::{
 #[cfg(test)]
 use futures_timer::Delay;
 
+pub use polkadot_node_primitives::disputes::ParticipationOutcome;
 use polkadot_node_primitives::ValidationResult;
 use polkadot_node_subsystem::{
 	messages::{AvailabilityRecoveryMessage, CandidateValidationMessage, PvfExecKind},
@@ -101,30 +102,6 @@ pub struct ParticipationStatement {
 	pub outcome: ParticipationOutcome,
 }
 
-/// Outcome of the validation process.
-#[derive(Copy, Clone, Debug)]
-pub enum ParticipationOutcome {
-	/// Candidate was found to be valid.
-	Valid,
-	/// Candidate was found to be invalid.
-	Invalid,
-	/// Candidate was found to be unavailable.
-	Unavailable,
-	/// Something went wrong (bug), details can be found in the logs.
-	Error,
-}
-
-impl ParticipationOutcome {
-	/// If validation was successful, get whether the candidate was valid or invalid.
-	pub fn validity(self) -> Option<bool> {
-		match self {
-			Self::Valid => Some(true),
-			Self::Invalid => Some(false),
-			Self::Unavailable | Self::Error => None,
-		}
-	}
-}
-
 impl WorkerMessage {
 	fn from_request(req: ParticipationRequest, outcome: ParticipationOutcome) -> Self {
 		let session = req.session();
@@ -127,6 +127,22 @@ impl SpamSlots {
 			}
 		}
 	}
+
+	/// Spam slot usage of all validators which voted invalid on the given unconfirmed dispute.
+	///
+	/// Returns the number of spam slots each of those validators occupies in the session.
+	pub fn usage(&self, key: &(SessionIndex, CandidateHash)) -> Vec<(ValidatorIndex, SpamCount)> {
+		let (session, _) = key;
+		self.unconfirmed
+			.get(key)
+			.into_iter()
+			.flatten()
+			.map(|validator| {
+				(*validator, self.slots.get(&(*session, *validator)).copied().unwrap_or_default())
+			})
+			.collect()
+	}
+
 	/// Prune all spam slots for sessions older than the given index.
 	pub fn prune_old(&mut self, oldest_index: SessionIndex) {
 		self.unconfirmed.retain(|(session, _), _| *session >= oldest_index);
@@ -73,7 +73,9 @@ use polkadot_primitives_test_helpers::{
 use crate::{
 	backend::Backend,
 	metrics::Metrics,
-	participation::{participation_full_happy_path, participation_missing_availability},
+	participation::{
+		participation_full_happy_path, participation_missing_availability, ParticipationOutcome,
+	},
 	status::Clock,
 	Config, DisputeCoordinatorSubsystem,
 };
@@ -1085,6 +1087,91 @@ fn dispute_gets_confirmed_via_participation() {
 	});
 }
 
+#[test]
+fn inspect_disputes_reports_votes_and_participation() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(
+					&mut virtual_overseer,
+					session,
+					1,
+					vec![make_candidate_backed_event(candidate_receipt.clone())],
+				)
+				.await;
+
+			let (valid_vote, invalid_vote) = generate_opposing_votes_pair(
+				&test_state,
+				ValidatorIndex(3),
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				VoteType::Explicit,
+			)
+			.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(valid_vote, ValidatorIndex(3)),
+							(invalid_vote, ValidatorIndex(1)),
+						],
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			participation_with_distribution(
+				&mut virtual_overseer,
+				&candidate_hash,
+				candidate_receipt.commitments_hash,
+			)
+			.await;
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::InspectDisputes(tx),
+				})
+				.await;
+
+			let disputes = rx.await.unwrap();
+			assert_eq!(disputes.len(), 1);
+			let dispute = &disputes[0];
+			assert_eq!((dispute.session, dispute.candidate_hash), (session, candidate_hash));
+			assert_eq!(dispute.status, DisputeStatus::Active);
+			assert!(dispute.active);
+			assert_matches!(dispute.participation, Some(ParticipationOutcome::Valid));
+			// The candidate was backed, so no spam slots are used.
+			assert!(dispute.spam_slots.is_empty());
+			let votes = dispute.votes.as_ref().unwrap();
+			assert_eq!(votes.valid.raw().len(), 2);
+			assert_eq!(votes.invalid.len(), 1);
+			assert_matches!(dispute.import_timing, Some(timing) if timing.imports >= 1);
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+
+			// No more messages expected:
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn dispute_gets_confirmed_at_byzantine_threshold() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -93,6 +93,9 @@ pub const DEFAULT_CONFIG: Config = Config {
 		wasm_simd: false,
 		wasm_bulk_memory: false,
 		wasm_multi_value: false,
+
+		// PVF execution time is bounded by the execute worker itself.
+		execution_limits: false,
 	},
 };
 
@@ -0,0 +1,224 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Replays candidate validation dumps through the PVF prepare and execute workers.
+//!
+//! The dumps are written by candidate validation when a validator runs with `--pvf-dump-dir`. Each
+//! dump is replayed with a fresh validation host and artifact cache, so the preparation is always
+//! part of the replay and its timings and memory statistics are reported.
+
+use clap::Parser;
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use futures::channel::oneshot;
+use polkadot_node_core_pvf::{
+	dump::{ValidationDump, DUMP_FILE_EXTENSION},
+	start, Config, Metrics, PrepareJobKind, PvfPrepData, EXECUTE_BINARY_NAME, PREPARE_BINARY_NAME,
+};
+use polkadot_node_metrics::metrics::{prometheus::Registry, Metrics as _};
+use polkadot_node_subsystem::messages::PvfExecKind;
+use polkadot_primitives::PvfExecKind as RuntimePvfExecKind;
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Instant,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Replays candidate validation dumps through the PVF prepare and execute workers")]
+struct Cli {
+	/// Dump files, or directories containing dumps.
+	#[arg(required = true, value_name = "PATH")]
+	dumps: Vec<PathBuf>,
+
+	/// Directory containing the prepare and execute worker binaries.
+	///
+	/// Defaults to the directory of this binary.
+	#[arg(long, value_name = "PATH")]
+	workers_path: Option<PathBuf>,
+
+	/// Run the workers without the security sandbox, e.g. on systems that do not support it.
+	#[arg(long)]
+	insecure: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+	color_eyre::install()?;
+	sp_tracing::try_init_simple();
+
+	let cli = Cli::parse();
+	let workers_path = match cli.workers_path {
+		Some(path) => path,
+		None => std::env::current_exe()?
+			.parent()
+			.ok_or_else(|| eyre!("cannot determine the directory of the binary"))?
+			.to_owned(),
+	};
+	let prepare_worker_path = workers_path.join(PREPARE_BINARY_NAME);
+	let execute_worker_path = workers_path.join(EXECUTE_BINARY_NAME);
+	for path in [&prepare_worker_path, &execute_worker_path] {
+		if !path.is_file() {
+			return Err(eyre!("worker binary not found at {}", path.display()))
+		}
+	}
+
+	let mut num_failed = 0;
+	for dump_path in collect_dumps(&cli.dumps)? {
+		println!("{}", dump_path.display());
+		if let Err(err) =
+			replay(&dump_path, &prepare_worker_path, &execute_worker_path, !cli.insecure).await
+		{
+			println!("  replay failed: {err:#}");
+			num_failed += 1;
+		}
+	}
+
+	if num_failed > 0 {
+		return Err(eyre!("{num_failed} dump(s) could not be replayed"))
+	}
+
+	Ok(())
+}
+
+/// Expand directories into the dumps they contain, sorted by name and thus by time.
+fn collect_dumps(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+	let mut dumps = Vec::new();
+	for path in paths {
+		if path.is_dir() {
+			let mut dir_dumps = std::fs::read_dir(path)
+				.wrap_err_with(|| format!("cannot read {}", path.display()))?
+				.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+				.filter(|path| path.extension().map_or(false, |ext| ext == DUMP_FILE_EXTENSION))
+				.collect::<Vec<_>>();
+			dir_dumps.sort();
+			dumps.extend(dir_dumps);
+		} else {
+			dumps.push(path.clone());
+		}
+	}
+
+	Ok(dumps)
+}
+
+async fn replay(
+	dump_path: &Path,
+	prepare_worker_path: &Path,
+	execute_worker_path: &Path,
+	secure_validator_mode: bool,
+) -> Result<()> {
+	let dump = ValidationDump::read(dump_path).wrap_err("cannot read the dump")?;
+	let code = dump
+		.read_validation_code(dump_path.parent().unwrap_or(Path::new(".")))
+		.wrap_err("cannot read the validation code")?;
+
+	println!(
+		"  candidate {:?} of para {}, {:?} execution",
+		dump.candidate_hash.0, dump.para_id, dump.exec_kind
+	);
+	println!(
+		"  recorded: {} after {} ms (execution timeout {} ms)",
+		dump.outcome, dump.elapsed_millis, dump.exec_timeout_millis
+	);
+
+	// A fresh cache, so that the PVF is always prepared as part of the replay.
+	let cache_dir = tempfile::tempdir()?;
+	let registry = Registry::new();
+	let metrics = Metrics::register(Some(&registry))?;
+	let config = Config::new(
+		cache_dir.path().to_owned(),
+		None,
+		secure_validator_mode,
+		prepare_worker_path.to_owned(),
+		execute_worker_path.to_owned(),
+		1,
+		1,
+		1,
+	);
+	let (mut host, task) = start(config, metrics).await?;
+	let task = tokio::spawn(task);
+
+	let pvf = PvfPrepData::from_code(
+		code,
+		dump.executor_params.clone(),
+		dump.prep_timeout(),
+		PrepareJobKind::Compilation,
+		dump.validation_code_bomb_limit,
+	);
+	let exec_kind = match dump.exec_kind {
+		RuntimePvfExecKind::Backing => PvfExecKind::Backing(dump.relay_parent),
+		RuntimePvfExecKind::Approval => PvfExecKind::Approval,
+	};
+
+	let started = Instant::now();
+	let (result_tx, result_rx) = oneshot::channel();
+	host.execute_pvf(
+		pvf,
+		dump.exec_timeout(),
+		Arc::new(dump.persisted_validation_data.clone()),
+		Arc::new(dump.pov.clone()),
+		exec_kind.into(),
+		exec_kind,
+		result_tx,
+	)
+	.await
+	.map_err(|err| eyre!("cannot send the PVF to the validation host: {err}"))?;
+	let result = result_rx.await.wrap_err("the validation host hung up")?;
+	let elapsed = started.elapsed();
+
+	drop(host);
+	task.abort();
+
+	match result {
+		Ok(result) => println!(
+			"  replayed: valid, head data hash {:?}, after {} ms",
+			result.head_data.hash(),
+			elapsed.as_millis()
+		),
+		Err(err) => println!("  replayed: {err:?} after {} ms", elapsed.as_millis()),
+	}
+
+	let report = |label: &str, metric: &str, unit: &str| {
+		if let Some(value) = histogram_sum(&registry, metric) {
+			println!("  {label}: {value} {unit}");
+		}
+	};
+	report("preparation time", "polkadot_pvf_preparation_time", "s");
+	report("execution time", "polkadot_pvf_execution_time", "s");
+	report("preparation max rss", "polkadot_pvf_preparation_max_rss", "KiB");
+	report("preparation max resident", "polkadot_pvf_preparation_max_resident", "KiB");
+	report("preparation max allocated", "polkadot_pvf_preparation_max_allocated", "KiB");
+	report(
+		"preparation peak tracked allocation",
+		"polkadot_pvf_preparation_peak_tracked_allocation",
+		"KiB",
+	);
+
+	Ok(())
+}
+
+/// The sum of all the observations of a histogram. As every replay has its own registry, this is
+/// the single observed value.
+fn histogram_sum(registry: &Registry, name: &str) -> Option<f64> {
+	registry
+		.gather()
+		.into_iter()
+		.find(|family| family.get_name() == name)?
+		.get_metric()
+		.first()
+		.map(|metric| metric.get_histogram())
+		.filter(|histogram| histogram.get_sample_count() > 0)
+		.map(|histogram| histogram.get_sample_sum())
+}
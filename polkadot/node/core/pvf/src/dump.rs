@@ -0,0 +1,228 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! On-disk format of candidate validation dumps.
+//!
+//! Candidate validation can be configured to record the inputs of failing or slow executions, so
+//! that they can be replayed locally through the prepare and execute workers.
+//!
+//! A dump directory has the following layout:
+//!
+//! ```text
+//! <dir>/<unix time millis>-<candidate hash>-<exec kind>.pvfdump
+//! <dir>/<validation code hash>.code
+//! ```
+//!
+//! The validation code is stored once per code hash, since it is shared by all candidates of a para
+//! and is usually much larger than the PoV.
+
+use codec::{Decode, Encode};
+use polkadot_node_primitives::PoV;
+use polkadot_primitives::{
+	CandidateHash, ExecutorParams, Hash, Id as ParaId, PersistedValidationData,
+	PvfExecKind as RuntimePvfExecKind, ValidationCodeHash,
+};
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The file extension of validation dumps.
+pub const DUMP_FILE_EXTENSION: &str = "pvfdump";
+
+/// The file extension of the validation code stored next to the dumps.
+const CODE_FILE_EXTENSION: &str = "code";
+
+/// The version of the dump format. Bumped on every incompatible change of [`ValidationDump`].
+const DUMP_VERSION: u8 = 1;
+
+/// The inputs and the outcome of a single candidate validation.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct ValidationDump {
+	/// The hash of the validated candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate belongs to.
+	pub para_id: ParaId,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// The hash of the validation code. The code itself is stored separately.
+	pub validation_code_hash: ValidationCodeHash,
+	/// The executor parameters of the session the candidate was validated in.
+	pub executor_params: ExecutorParams,
+	/// The persisted validation data of the candidate.
+	pub persisted_validation_data: PersistedValidationData,
+	/// The proof of validity of the candidate.
+	pub pov: PoV,
+	/// The kind of the execution.
+	pub exec_kind: RuntimePvfExecKind,
+	/// The maximum size of the decompressed validation code.
+	pub validation_code_bomb_limit: u32,
+	/// The preparation timeout, in milliseconds.
+	pub prep_timeout_millis: u64,
+	/// The execution timeout, in milliseconds.
+	pub exec_timeout_millis: u64,
+	/// How long the validation took on the node, in milliseconds, including queueing.
+	pub elapsed_millis: u64,
+	/// A human readable description of the validation outcome on the node.
+	pub outcome: String,
+}
+
+impl ValidationDump {
+	/// The preparation timeout.
+	pub fn prep_timeout(&self) -> Duration {
+		Duration::from_millis(self.prep_timeout_millis)
+	}
+
+	/// The execution timeout.
+	pub fn exec_timeout(&self) -> Duration {
+		Duration::from_millis(self.exec_timeout_millis)
+	}
+
+	/// Write the dump and the validation code to `dir`, returning the path of the dump file.
+	///
+	/// The code is only written if it is not already present in the directory.
+	pub fn write(&self, dir: &Path, validation_code: &[u8]) -> io::Result<PathBuf> {
+		fs::create_dir_all(dir)?;
+
+		let code_path = code_path(dir, &self.validation_code_hash);
+		if !code_path.exists() {
+			write_atomically(&code_path, validation_code)?;
+		}
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+		let exec_kind = match self.exec_kind {
+			RuntimePvfExecKind::Backing => "backing",
+			RuntimePvfExecKind::Approval => "approval",
+		};
+		let dump_path = dir.join(format!(
+			"{}-{:?}-{}.{}",
+			now.as_millis(),
+			self.candidate_hash.0,
+			exec_kind,
+			DUMP_FILE_EXTENSION
+		));
+		write_atomically(&dump_path, &(DUMP_VERSION, self).encode())?;
+
+		Ok(dump_path)
+	}
+
+	/// Read a dump from `path`.
+	pub fn read(path: &Path) -> io::Result<Self> {
+		let raw = fs::read(path)?;
+		let (version, dump) = <(u8, Self)>::decode(&mut &raw[..])
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+		if version != DUMP_VERSION {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("unsupported dump version {version}, expected {DUMP_VERSION}"),
+			))
+		}
+
+		Ok(dump)
+	}
+
+	/// Read the validation code of this dump, stored in `dir`.
+	pub fn read_validation_code(&self, dir: &Path) -> io::Result<Vec<u8>> {
+		fs::read(code_path(dir, &self.validation_code_hash))
+	}
+}
+
+/// The number of validation dumps in `dir`.
+pub fn count_dumps(dir: &Path) -> io::Result<usize> {
+	match fs::read_dir(dir) {
+		Ok(entries) => Ok(entries
+			.filter_map(|entry| entry.ok())
+			.filter(|entry| {
+				entry.path().extension().map_or(false, |ext| ext == DUMP_FILE_EXTENSION)
+			})
+			.count()),
+		Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(0),
+		Err(err) => Err(err),
+	}
+}
+
+fn code_path(dir: &Path, validation_code_hash: &ValidationCodeHash) -> PathBuf {
+	dir.join(format!("{:?}.{}", validation_code_hash, CODE_FILE_EXTENSION))
+}
+
+// Write to a temporary file first, so that a crash never leaves a truncated file behind.
+fn write_atomically(path: &Path, data: &[u8]) -> io::Result<()> {
+	let tmp_path = path.with_extension("tmp");
+	fs::write(&tmp_path, data)?;
+	fs::rename(&tmp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_parachain_primitives::primitives::{BlockData, HeadData};
+
+	fn dump() -> ValidationDump {
+		ValidationDump {
+			candidate_hash: CandidateHash(Hash::repeat_byte(1)),
+			para_id: 100.into(),
+			relay_parent: Hash::repeat_byte(2),
+			validation_code_hash: ValidationCodeHash::from(Hash::repeat_byte(3)),
+			executor_params: ExecutorParams::default(),
+			persisted_validation_data: PersistedValidationData {
+				parent_head: HeadData(vec![1, 2, 3]),
+				relay_parent_number: 5,
+				relay_parent_storage_root: Hash::repeat_byte(4),
+				max_pov_size: 1024,
+			},
+			pov: PoV { block_data: BlockData(vec![4, 5, 6]) },
+			exec_kind: RuntimePvfExecKind::Approval,
+			validation_code_bomb_limit: 1024,
+			prep_timeout_millis: 60_000,
+			exec_timeout_millis: 12_000,
+			elapsed_millis: 13_000,
+			outcome: "Invalid(Timeout)".into(),
+		}
+	}
+
+	#[test]
+	fn dump_roundtrip() {
+		let dir = tempfile::tempdir().unwrap();
+		let dump = dump();
+		let code = vec![0, 97, 115, 109];
+
+		let path = dump.write(dir.path(), &code).unwrap();
+		// The code is shared with the first dump.
+		let backing = ValidationDump { exec_kind: RuntimePvfExecKind::Backing, ..dump.clone() };
+		let _ = backing.write(dir.path(), &code).unwrap();
+		assert_eq!(count_dumps(dir.path()).unwrap(), 2);
+
+		let read = ValidationDump::read(&path).unwrap();
+		assert_eq!(read.encode(), dump.encode());
+		assert_eq!(read.read_validation_code(dir.path()).unwrap(), code);
+	}
+
+	#[test]
+	fn unknown_version_is_rejected() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("bad.pvfdump");
+		fs::write(&path, (DUMP_VERSION + 1, dump()).encode()).unwrap();
+
+		assert_eq!(ValidationDump::read(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn count_dumps_in_missing_dir() {
+		let dir = tempfile::tempdir().unwrap();
+		assert_eq!(count_dumps(&dir.path().join("missing")).unwrap(), 0);
+	}
+}
@@ -91,6 +91,7 @@
 //! [`params`][`polkadot_parachain_primitives::primitives::ValidationParams`].
 
 mod artifacts;
+pub mod dump;
 mod error;
 mod execute;
 mod host;
@@ -42,6 +42,12 @@ enum NemesisVariant {
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
 	/// Spam many request statements instead of sending a single one.
 	SpamStatementRequests(SpamStatementRequestsOptions),
+	/// Back candidates but refuse to serve their erasure chunks and available data.
+	WithholdAvailabilityChunks(WithholdAvailabilityChunksOptions),
+	/// Delay or drop approval assignments and votes.
+	DelayApprovals(DelayApprovalsOptions),
+	/// Gossip conflicting availability bitfields.
+	EquivocateBitfields(EquivocateBitfieldsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -105,6 +111,29 @@ impl MalusCli {
 
 				polkadot_cli::run_node(cli, SpamStatementRequests { spam_factor }, finality_delay)?
 			},
+			NemesisVariant::WithholdAvailabilityChunks(opts) => {
+				let WithholdAvailabilityChunksOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdAvailabilityChunks { percentage },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::DelayApprovals(opts) => {
+				let DelayApprovalsOptions { drop_percentage, delay_ms, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					DelayApprovals { drop_percentage, delay_ms },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::EquivocateBitfields(opts) => {
+				let EquivocateBitfieldsOptions { cli } = opts;
+
+				polkadot_cli::run_node(cli, EquivocateBitfields, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -244,4 +273,74 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn percentage_works_withhold_availability_chunks() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-availability-chunks",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdAvailabilityChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn delay_approvals_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"delay-approvals",
+			"--drop-percentage",
+			"30",
+			"--delay-ms",
+			"6000",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::DelayApprovals(opts),
+			..
+		} => {
+			assert_eq!(opts.drop_percentage, 30);
+			assert_eq!(opts.delay_ms, 6000);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	#[should_panic]
+	fn validate_range_for_drop_percentage() {
+		MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"delay-approvals",
+			"--drop-percentage",
+			"101",
+			"--bob",
+		]))
+		.unwrap();
+	}
+
+	#[test]
+	fn equivocate_bitfields_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"equivocate-bitfields",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::EquivocateBitfields(run),
+			..
+		} => {
+			assert!(run.cli.run.base.bob);
+		});
+	}
 }
@@ -0,0 +1,258 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that delays or drops its approval gossip.
+//!
+//! This malus variant behaves honestly in backing and approval checking, but intercepts the
+//! assignments and approvals that approval distribution sends to the network. A configurable
+//! percentage of them is dropped and the rest is only sent after a delay. This slows down the
+//! approval of candidates and forces the other validators to trigger tranches beyond the first one.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_network_protocol::{
+	v3 as protocol_v3, PeerId, ValidationProtocols, VersionedValidationProtocol,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use rand::distributions::{Bernoulli, Distribution};
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+/// Sends a message to the network bridge, bypassing the interception of outgoing messages.
+type NetworkSender = Box<dyn FnMut(NetworkBridgeTxMessage) + Send>;
+
+/// Wraps around the approval voting and distribution subsystem and replaces it.
+#[derive(Clone)]
+struct ApprovalDelayer<Spawner> {
+	spawner: Spawner,
+	/// Decides whether an approval message is dropped.
+	drop_distribution: Bernoulli,
+	/// How long approval messages which are not dropped are held back.
+	delay: Duration,
+	/// The unfiltered sender of the subsystem, captured from the first incoming message.
+	network_sender: Arc<Mutex<Option<NetworkSender>>>,
+}
+
+impl<Spawner> ApprovalDelayer<Spawner> {
+	fn new(spawner: Spawner, drop_percentage: u8, delay: Duration) -> Self {
+		let drop_distribution = Bernoulli::new(f64::from(drop_percentage) / 100.0)
+			.expect("Invalid probability! Percentage must be in range [0..=100].");
+		Self { spawner, drop_distribution, delay, network_sender: Arc::new(Mutex::new(None)) }
+	}
+}
+
+/// Returns the approval distribution part of a validation protocol message, if any.
+fn approval_message(
+	msg: &VersionedValidationProtocol,
+) -> Option<&protocol_v3::ApprovalDistributionMessage> {
+	match msg {
+		ValidationProtocols::V3(protocol_v3::ValidationProtocol::ApprovalDistribution(msg)) =>
+			Some(msg),
+		_ => None,
+	}
+}
+
+impl<Spawner> ApprovalDelayer<Spawner>
+where
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	/// Drop the message or schedule it to be sent after the delay.
+	fn hold_back(&self, peers: Vec<PeerId>, msg: &protocol_v3::ApprovalDistributionMessage) {
+		let kind = match msg {
+			protocol_v3::ApprovalDistributionMessage::Assignments(_) => "assignments",
+			protocol_v3::ApprovalDistributionMessage::Approvals(_) => "approvals",
+		};
+
+		if self.drop_distribution.sample(&mut rand::thread_rng()) {
+			gum::info!(target: MALUS, n_peers = peers.len(), "😈 Dropping {}", kind);
+			return
+		}
+
+		gum::info!(
+			target: MALUS,
+			n_peers = peers.len(),
+			delay = ?self.delay,
+			"😈 Delaying {}",
+			kind,
+		);
+
+		let delay = self.delay;
+		let network_sender = self.network_sender.clone();
+		let msg = NetworkBridgeTxMessage::SendValidationMessage(
+			peers,
+			ValidationProtocols::V3(msg.clone().into()),
+		);
+		self.spawner.spawn(
+			"malus-delay-approvals",
+			Some("malus"),
+			Box::pin(async move {
+				futures_timer::Delay::new(delay).await;
+				if let Some(send) = network_sender.lock().expect("poisoned lock").as_mut() {
+					send(msg);
+				}
+			}),
+		);
+	}
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for ApprovalDelayer<Spawner>
+where
+	Sender: overseer::ApprovalVotingParallelSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = ApprovalVotingParallelMessage;
+
+	/// Capture the unfiltered sender, so that delayed messages can be sent later, and pass
+	/// everything as normal.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		let mut network_sender = self.network_sender.lock().expect("poisoned lock");
+		if network_sender.is_none() {
+			let mut sender = subsystem_sender.clone();
+			*network_sender = Some(Box::new(move |msg: NetworkBridgeTxMessage| {
+				sender.send_unbounded_message(msg)
+			}));
+		}
+
+		Some(msg)
+	}
+
+	fn need_intercept_outgoing(
+		&self,
+		msg: &<Self::Message as overseer::AssociateOutgoing>::OutgoingMessages,
+	) -> bool {
+		match msg {
+			overseer::ApprovalVotingParallelOutgoingMessages::NetworkBridgeTxMessage(
+				NetworkBridgeTxMessage::SendValidationMessage(_, msg),
+			) => approval_message(msg).is_some(),
+			overseer::ApprovalVotingParallelOutgoingMessages::NetworkBridgeTxMessage(
+				NetworkBridgeTxMessage::SendValidationMessages(msgs),
+			) => msgs.iter().any(|(_, msg)| approval_message(msg).is_some()),
+			_ => false,
+		}
+	}
+
+	/// Hold back the approval messages and pass the rest of the batch, if any.
+	fn intercept_outgoing(
+		&self,
+		msg: &<Self::Message as overseer::AssociateOutgoing>::OutgoingMessages,
+	) -> Option<<Self::Message as overseer::AssociateOutgoing>::OutgoingMessages> {
+		let msgs = match msg {
+			overseer::ApprovalVotingParallelOutgoingMessages::NetworkBridgeTxMessage(
+				NetworkBridgeTxMessage::SendValidationMessage(peers, msg),
+			) => vec![(peers.clone(), msg.clone())],
+			overseer::ApprovalVotingParallelOutgoingMessages::NetworkBridgeTxMessage(
+				NetworkBridgeTxMessage::SendValidationMessages(msgs),
+			) => msgs.clone(),
+			_ => return None,
+		};
+
+		let mut passed = Vec::new();
+		for (peers, msg) in msgs {
+			match approval_message(&msg) {
+				Some(approval_msg) => self.hold_back(peers, approval_msg),
+				None => passed.push((peers, msg)),
+			}
+		}
+
+		if passed.is_empty() {
+			None
+		} else {
+			Some(NetworkBridgeTxMessage::SendValidationMessages(passed).into())
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DelayApprovalsOptions {
+	/// Determines the percentage of assignments and approvals that are dropped instead of being
+	/// delayed.
+	#[clap(long, ignore_case = true, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub drop_percentage: u8,
+
+	/// How long assignments and approvals are delayed, in milliseconds.
+	#[clap(long, ignore_case = true, default_value_t = 12_000, value_parser = clap::value_parser!(u64).range(0..=600_000))]
+	pub delay_ms: u64,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// DelayApprovals implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct DelayApprovals {
+	/// The percentage of assignments and approvals that are dropped.
+	pub drop_percentage: u8,
+	/// How long assignments and approvals are delayed, in milliseconds.
+	pub delay_ms: u64,
+}
+
+impl OverseerGen for DelayApprovals {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that drops {}% of its approval messages and delays the rest by {} ms.",
+			&self.drop_percentage,
+			&self.delay_ms,
+		);
+
+		let approval_delayer = ApprovalDelayer::new(
+			SpawnGlue(args.spawner.clone()),
+			self.drop_percentage,
+			Duration::from_millis(self.delay_ms),
+		);
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_approval_voting_parallel(move |cb| InterceptedSubsystem::new(cb, approval_delayer))
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,214 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that equivocates on its availability bitfields.
+//!
+//! This malus variant signs and gossips its availability bitfields honestly. In addition, for every
+//! bitfield it signs a second, conflicting one claiming the opposite availability of every core
+//! and sends it directly to all the peers it is connected to. Honest nodes accept only the first
+//! bitfield they receive from a validator and penalize the peer for sending a second one.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_network_protocol::{v3 as protocol_v3, PeerId, ValidationProtocols};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_node_subsystem_util::Validator;
+use polkadot_primitives::{AvailabilityBitfield, Hash, SignedAvailabilityBitfield};
+use sp_core::traits::SpawnNamed;
+use sp_keystore::KeystorePtr;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Wraps around bitfield distribution and replaces it.
+#[derive(Clone)]
+struct BitfieldEquivocator<Spawner> {
+	spawner: Spawner,
+	keystore: KeystorePtr,
+	/// The peers we are connected to on the validation peer set.
+	peers: Arc<Mutex<HashSet<PeerId>>>,
+}
+
+impl<Spawner> BitfieldEquivocator<Spawner>
+where
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	/// Sign a bitfield conflicting with `bitfield` and send it to all connected peers.
+	fn equivocate<Sender>(
+		&self,
+		mut sender: Sender,
+		relay_parent: Hash,
+		bitfield: &SignedAvailabilityBitfield,
+	) where
+		Sender: overseer::BitfieldDistributionSenderTrait + Clone + Send + 'static,
+	{
+		let keystore = self.keystore.clone();
+		let peers = self.peers.clone();
+		let conflicting = AvailabilityBitfield(!bitfield.payload().0.clone());
+
+		self.spawner.spawn(
+			"malus-equivocate-bitfield",
+			Some("malus"),
+			Box::pin(async move {
+				let validator =
+					match Validator::new(relay_parent, keystore.clone(), &mut sender).await {
+						Ok(validator) => validator,
+						Err(err) => {
+							gum::debug!(
+								target: MALUS,
+								?relay_parent,
+								?err,
+								"😈 Cannot determine our validator at relay parent",
+							);
+							return
+						},
+					};
+				let signed = match validator.sign(keystore, conflicting) {
+					Ok(Some(signed)) => signed,
+					_ => {
+						gum::warn!(
+							target: MALUS,
+							?relay_parent,
+							"😈 Failed to sign conflicting bitfield",
+						);
+						return
+					},
+				};
+
+				let peers =
+					peers.lock().expect("poisoned lock").iter().cloned().collect::<Vec<_>>();
+				gum::info!(
+					target: MALUS,
+					?relay_parent,
+					n_peers = peers.len(),
+					"😈 Sending conflicting bitfield",
+				);
+				sender
+					.send_message(NetworkBridgeTxMessage::SendValidationMessage(
+						peers,
+						ValidationProtocols::V3(
+							protocol_v3::BitfieldDistributionMessage::Bitfield(
+								relay_parent,
+								signed.into_unchecked(),
+							)
+							.into(),
+						),
+					))
+					.await;
+			}),
+		);
+	}
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for BitfieldEquivocator<Spawner>
+where
+	Sender: overseer::BitfieldDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = BitfieldDistributionMessage;
+
+	/// Track the connected peers and equivocate on every bitfield we distribute. All messages are
+	/// passed as normal.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match &msg {
+			FromOrchestra::Communication {
+				msg: BitfieldDistributionMessage::DistributeBitfield(relay_parent, bitfield),
+			} => self.equivocate(subsystem_sender.clone(), *relay_parent, bitfield),
+			FromOrchestra::Communication {
+				msg:
+					BitfieldDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::PeerConnected(
+						peer,
+						..,
+					)),
+			} => {
+				self.peers.lock().expect("poisoned lock").insert(*peer);
+			},
+			FromOrchestra::Communication {
+				msg:
+					BitfieldDistributionMessage::NetworkBridgeUpdate(
+						NetworkBridgeEvent::PeerDisconnected(peer),
+					),
+			} => {
+				self.peers.lock().expect("poisoned lock").remove(peer);
+			},
+			_ => {},
+		}
+
+		Some(msg)
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct EquivocateBitfieldsOptions {
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// EquivocateBitfields implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct EquivocateBitfields;
+
+impl OverseerGen for EquivocateBitfields {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(target: MALUS, "😈 Started Malus node that equivocates on its bitfields.");
+
+		let ext_args =
+			ext_args.expect("Extended arguments required to build validator overseer are provided");
+		let bitfield_equivocator = BitfieldEquivocator {
+			spawner: SpawnGlue(args.spawner.clone()),
+			keystore: ext_args.keystore.clone(),
+			peers: Default::default(),
+		};
+
+		validator_overseer_builder(args, ext_args)?
+			.replace_bitfield_distribution(move |cb| {
+				InterceptedSubsystem::new(cb, bitfield_equivocator)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
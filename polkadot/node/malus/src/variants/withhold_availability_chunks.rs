@@ -0,0 +1,166 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that withholds the availability data of candidates.
+//!
+//! This malus variant behaves honestly in backing, availability distribution and bitfield signing:
+//! it receives and stores its erasure chunks, so it keeps claiming availability of the candidates
+//! it backed. However, it refuses to serve any chunk or full available data of the selected
+//! candidates, forcing the rest of the network to recover them from honest validators.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::CandidateHash;
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::sync::Arc;
+
+/// Wraps around the availability store and replaces it.
+#[derive(Clone)]
+struct ChunkWithholder {
+	/// The percentage of candidates whose availability data is withheld.
+	percentage: u8,
+}
+
+impl ChunkWithholder {
+	/// Whether the data of the candidate is withheld.
+	///
+	/// The decision is derived from the candidate hash, so that all queries concerning the same
+	/// candidate are answered consistently.
+	fn withholds(&self, candidate_hash: &CandidateHash) -> bool {
+		let mut prefix = [0u8; 8];
+		prefix.copy_from_slice(&candidate_hash.0[..8]);
+		u64::from_le_bytes(prefix) % 100 < u64::from(self.percentage)
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ChunkWithholder
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	/// Answer the queries for the data of the selected candidates as if it was not stored and pass
+	/// the rest as normal.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAvailableData(candidate_hash, tx),
+			} if self.withholds(&candidate_hash) => {
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					"😈 Withholding available data of candidate",
+				);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunk(candidate_hash, validator_index, tx),
+			} if self.withholds(&candidate_hash) => {
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					?validator_index,
+					"😈 Withholding erasure chunk of candidate",
+				);
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunkSize(candidate_hash, tx),
+			} if self.withholds(&candidate_hash) => {
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAllChunks(candidate_hash, tx),
+			} if self.withholds(&candidate_hash) => {
+				let _ = tx.send(Vec::new());
+				None
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdAvailabilityChunksOptions {
+	/// Determines the percentage of candidates whose availability data is withheld.
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdAvailabilityChunks implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdAvailabilityChunks {
+	/// The percentage of candidates whose availability data is withheld.
+	pub percentage: u8,
+}
+
+impl OverseerGen for WithholdAvailabilityChunks {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that withholds the availability data of {}% of the candidates.",
+			&self.percentage,
+		);
+
+		let chunk_withholder = ChunkWithholder { percentage: self.percentage };
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_store(move |cb| InterceptedSubsystem::new(cb, chunk_withholder))
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
@@ -34,6 +34,11 @@ use futures::{
 use polkadot_node_subsystem_util::reputation::ReputationAggregator;
 use sp_keystore::KeystorePtr;
 
+#[cfg(feature = "experimental-collator-protocol")]
+use polkadot_node_subsystem_util::database::Database;
+#[cfg(feature = "experimental-collator-protocol")]
+use std::sync::Arc;
+
 use polkadot_node_network_protocol::{
 	request_response::{v2 as protocol_v2, IncomingRequestReceiver},
 	PeerId, UnifiedReputationChange as Rep,
@@ -47,6 +52,9 @@ mod validator_side;
 #[cfg(feature = "experimental-collator-protocol")]
 mod validator_side_experimental;
 
+#[cfg(feature = "experimental-collator-protocol")]
+pub use validator_side_experimental::ReputationDbConfig;
+
 const LOG_TARGET: &'static str = "parachain::collator-protocol";
 const LOG_TARGET_STATS: &'static str = "parachain::collator-protocol::stats";
 
@@ -90,6 +98,10 @@ pub enum ProtocolSide {
 		keystore: KeystorePtr,
 		/// Prometheus metrics for validators.
 		metrics: validator_side_experimental::Metrics,
+		/// The database used to persist collator reputations.
+		db: Arc<dyn Database>,
+		/// The column configuration of the reputation database.
+		db_config: ReputationDbConfig,
 	},
 	/// Collators operate on a parachain.
 	Collator {
@@ -151,8 +163,8 @@ impl<Context> CollatorProtocolSubsystem {
 				.boxed()
 			},
 			#[cfg(feature = "experimental-collator-protocol")]
-			ProtocolSide::ValidatorExperimental { keystore, metrics } =>
-				validator_side_experimental::run(ctx, keystore, metrics)
+			ProtocolSide::ValidatorExperimental { keystore, metrics, db, db_config } =>
+				validator_side_experimental::run(ctx, keystore, metrics, db, db_config)
 					.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
 					.boxed(),
 			ProtocolSide::Collator { peer_id, collator_pair, request_receiver_v2, metrics } =>
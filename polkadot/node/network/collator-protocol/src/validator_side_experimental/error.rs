@@ -44,6 +44,8 @@ pub enum Error {
 	#[fatal]
 	#[error("Receiving message from overseer failed: {0}")]
 	SubsystemReceive(#[source] SubsystemError),
+	#[error("Loading the collator reputations from the database failed: {0}")]
+	LoadReputations(#[source] std::io::Error),
 }
 
 /// Utility for eating top level errors and log them.
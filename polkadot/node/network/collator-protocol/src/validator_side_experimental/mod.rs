@@ -16,7 +16,8 @@
 
 #![allow(unused)]
 
-// See reasoning in Cargo.toml why this temporary useless import is needed.
+// See reasoning in Cargo.toml why these temporary useless imports are needed.
+use kvdb_memorydb as _;
 use tokio as _;
 
 mod common;
@@ -25,24 +26,25 @@ mod metrics;
 mod peer_manager;
 mod state;
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 use common::MAX_STORED_SCORES_PER_PARA;
 use error::{log_error, FatalError, FatalResult, Result};
 use fatality::Split;
-use peer_manager::{Db, PeerManager};
+use peer_manager::{PeerManager, PersistentDb};
 use polkadot_node_subsystem::{
 	overseer, ActivatedLeaf, CollatorProtocolSenderTrait, FromOrchestra, OverseerSignal,
 };
 use polkadot_node_subsystem_util::{
-	find_validator_group, request_claim_queue, request_validator_groups, request_validators,
-	runtime::recv_runtime, signing_key_and_index,
+	database::Database, find_validator_group, request_claim_queue, request_validator_groups,
+	request_validators, runtime::recv_runtime, signing_key_and_index,
 };
 use polkadot_primitives::{Hash, Id as ParaId};
 use sp_keystore::KeystorePtr;
 use state::State;
 
 pub use metrics::Metrics;
+pub use peer_manager::ReputationDbConfig;
 
 use crate::LOG_TARGET;
 
@@ -52,8 +54,10 @@ pub(crate) async fn run<Context>(
 	mut ctx: Context,
 	keystore: KeystorePtr,
 	metrics: Metrics,
+	db: Arc<dyn Database>,
+	db_config: ReputationDbConfig,
 ) -> FatalResult<()> {
-	if let Some(_state) = initialize(&mut ctx, keystore, metrics).await? {
+	if let Some(_state) = initialize(&mut ctx, keystore, metrics, db, db_config).await? {
 		// run_inner(state);
 	}
 
@@ -65,7 +69,9 @@ async fn initialize<Context>(
 	ctx: &mut Context,
 	keystore: KeystorePtr,
 	metrics: Metrics,
-) -> FatalResult<Option<State<Db>>> {
+	db: Arc<dyn Database>,
+	db_config: ReputationDbConfig,
+) -> FatalResult<Option<State<PersistentDb>>> {
 	loop {
 		let first_leaf = match wait_for_first_leaf(ctx).await? {
 			Some(activated_leaf) => activated_leaf,
@@ -81,7 +87,19 @@ async fn initialize<Context>(
 			},
 		};
 
-		let backend = Db::new(MAX_STORED_SCORES_PER_PARA).await;
+		let backend = match PersistentDb::new(
+			db.clone(),
+			db_config.clone(),
+			MAX_STORED_SCORES_PER_PARA,
+		)
+		.await
+		{
+			Ok(backend) => backend,
+			Err(err) => {
+				log_error(Err(err))?;
+				continue
+			},
+		};
 
 		match PeerManager::startup(backend, ctx.sender(), scheduled_paras.into_iter().collect())
 			.await
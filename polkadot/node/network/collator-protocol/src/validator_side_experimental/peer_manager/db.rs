@@ -26,8 +26,10 @@ use std::{
 	time::{SystemTime, UNIX_EPOCH},
 };
 
-/// This is an in-memory temporary implementation for the DB, to be used only for prototyping and
-/// testing purposes.
+/// In-memory implementation of the reputation DB.
+///
+/// Reputations are lost on restart. Use [`PersistentDb`](super::PersistentDb) to keep them on
+/// disk.
 pub struct Db {
 	db: BTreeMap<ParaId, HashMap<PeerId, ScoreEntry>>,
 	last_finalized: Option<BlockNumber>,
@@ -41,14 +43,33 @@ impl Db {
 	pub async fn new(stored_limit_per_para: u8) -> Self {
 		Self { db: BTreeMap::new(), last_finalized: None, stored_limit_per_para }
 	}
+
+	/// Create a new instance of the in-memory DB, holding previously stored reputations.
+	pub(super) fn with_entries(
+		stored_limit_per_para: u8,
+		last_finalized: Option<BlockNumber>,
+		db: BTreeMap<ParaId, HashMap<PeerId, ScoreEntry>>,
+	) -> Self {
+		Self { db, last_finalized, stored_limit_per_para }
+	}
+
+	/// Iterate over the paras for which reputations are stored.
+	pub(super) fn paras(&self) -> impl Iterator<Item = &ParaId> {
+		self.db.keys()
+	}
+
+	/// Get all the reputations stored for this para, if any.
+	pub(super) fn para_entries(&self, para_id: &ParaId) -> Option<&HashMap<PeerId, ScoreEntry>> {
+		self.db.get(para_id)
+	}
 }
 
-type Timestamp = u128;
+pub(super) type Timestamp = u128;
 
 #[derive(Clone, Debug)]
-struct ScoreEntry {
-	score: Score,
-	last_bumped: Timestamp,
+pub(super) struct ScoreEntry {
+	pub(super) score: Score,
+	pub(super) last_bumped: Timestamp,
 }
 
 #[async_trait]
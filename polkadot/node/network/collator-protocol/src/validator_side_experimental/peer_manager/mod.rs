@@ -16,6 +16,7 @@
 mod backend;
 mod connected;
 mod db;
+mod persistent_db;
 
 use futures::channel::oneshot;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -34,6 +35,7 @@ use crate::{
 pub use backend::Backend;
 use connected::ConnectedPeers;
 pub use db::Db;
+pub use persistent_db::{PersistentDb, ReputationDbConfig};
 use polkadot_node_network_protocol::{
 	peer_set::{CollationVersion, PeerSet},
 	PeerId,
@@ -0,0 +1,376 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Reputation DB persisted in a column of the parachains database.
+//!
+//! All reputations are kept in memory using a [`Db`] and every change is written through to disk,
+//! so that the reputations survive restarts. The reputations of a para are stored as one value,
+//! which is bounded by the `stored_limit_per_para`.
+
+use crate::{
+	validator_side_experimental::{
+		common::Score,
+		error::{Error, Result},
+		peer_manager::{
+			backend::Backend,
+			db::{Db, ScoreEntry, Timestamp},
+			ReputationUpdate,
+		},
+	},
+	LOG_TARGET,
+};
+use async_trait::async_trait;
+use codec::{Decode, Encode};
+use polkadot_node_network_protocol::PeerId;
+use polkadot_node_subsystem_util::database::{DBTransaction, Database};
+use polkadot_primitives::{BlockNumber, Id as ParaId};
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap},
+	sync::Arc,
+};
+
+const LAST_FINALIZED_KEY: &[u8; 14] = b"last-finalized";
+const PARA_REPUTATIONS_PREFIX: &[u8; 16] = b"para-reputations";
+
+/// Column configuration for the reputation DB.
+#[derive(Debug, Clone)]
+pub struct ReputationDbConfig {
+	/// The column in the key-value DB where reputations are stored.
+	pub col_reputation_data: u32,
+}
+
+/// Reputation of a peer, as stored on disk.
+#[derive(Encode, Decode)]
+struct StoredScore {
+	peer_id: Vec<u8>,
+	score: u16,
+	last_bumped: Timestamp,
+}
+
+fn para_reputations_key(para_id: &ParaId) -> Vec<u8> {
+	(PARA_REPUTATIONS_PREFIX, para_id).encode()
+}
+
+/// Reputation DB backed by the parachains database.
+pub struct PersistentDb {
+	inner: Db,
+	db: Arc<dyn Database>,
+	config: ReputationDbConfig,
+}
+
+impl PersistentDb {
+	/// Create a new instance, loading all reputations stored in the database.
+	///
+	/// `stored_limit_per_para` is the maximum number of reputations that can be stored per para.
+	/// If it was lowered since the reputations were stored, the excess is pruned on the next bump
+	/// of the para.
+	pub async fn new(
+		db: Arc<dyn Database>,
+		config: ReputationDbConfig,
+		stored_limit_per_para: u8,
+	) -> Result<Self> {
+		let last_finalized = db
+			.get(config.col_reputation_data, LAST_FINALIZED_KEY)
+			.map_err(Error::LoadReputations)?
+			.and_then(|raw| {
+				BlockNumber::decode(&mut &raw[..])
+					.map_err(|err| {
+						gum::warn!(
+							target: LOG_TARGET,
+							?err,
+							"Ignoring corrupted last finalized block number of the reputation DB",
+						)
+					})
+					.ok()
+			});
+
+		let mut entries = BTreeMap::new();
+		for item in db.iter_with_prefix(config.col_reputation_data, PARA_REPUTATIONS_PREFIX) {
+			let (key, value) = item.map_err(Error::LoadReputations)?;
+			let Ok(para_id) = ParaId::decode(&mut &key[PARA_REPUTATIONS_PREFIX.len()..]) else {
+				gum::warn!(target: LOG_TARGET, ?key, "Ignoring reputations with corrupted key");
+				continue
+			};
+			let Ok(stored) = Vec::<StoredScore>::decode(&mut &value[..]) else {
+				gum::warn!(target: LOG_TARGET, ?para_id, "Ignoring corrupted reputations");
+				continue
+			};
+
+			let per_para: HashMap<_, _> = stored
+				.into_iter()
+				.filter_map(|stored| {
+					let peer_id = PeerId::from_bytes(&stored.peer_id).ok()?;
+					let score = Score::new(stored.score)?;
+					Some((peer_id, ScoreEntry { score, last_bumped: stored.last_bumped }))
+				})
+				.collect();
+
+			if !per_para.is_empty() {
+				entries.insert(para_id, per_para);
+			}
+		}
+
+		gum::debug!(
+			target: LOG_TARGET,
+			?last_finalized,
+			n_paras = entries.len(),
+			"Loaded collator reputations from the database",
+		);
+
+		Ok(Self {
+			inner: Db::with_entries(stored_limit_per_para, last_finalized, entries),
+			db,
+			config,
+		})
+	}
+
+	/// Write the current reputations of `paras` to the database, deleting the paras without any.
+	fn persist(
+		&self,
+		paras: impl IntoIterator<Item = ParaId>,
+		last_finalized: Option<BlockNumber>,
+	) {
+		let col = self.config.col_reputation_data;
+		let mut tx = DBTransaction::new();
+
+		for para_id in paras {
+			let key = para_reputations_key(&para_id);
+			match self.inner.para_entries(&para_id) {
+				Some(per_para) => {
+					let stored = per_para
+						.iter()
+						.map(|(peer_id, entry)| StoredScore {
+							peer_id: peer_id.to_bytes(),
+							score: entry.score.into(),
+							last_bumped: entry.last_bumped,
+						})
+						.collect::<Vec<_>>();
+					tx.put_vec(col, &key, stored.encode());
+				},
+				None => tx.delete(col, &key),
+			}
+		}
+
+		if let Some(last_finalized) = last_finalized {
+			tx.put_vec(col, LAST_FINALIZED_KEY, last_finalized.encode());
+		}
+
+		if let Err(err) = self.db.write(tx) {
+			gum::warn!(
+				target: LOG_TARGET,
+				?err,
+				"Failed to write collator reputations to the database",
+			);
+		}
+	}
+}
+
+#[async_trait]
+impl Backend for PersistentDb {
+	async fn processed_finalized_block_number(&self) -> Option<BlockNumber> {
+		self.inner.processed_finalized_block_number().await
+	}
+
+	async fn query(&self, peer_id: &PeerId, para_id: &ParaId) -> Option<Score> {
+		self.inner.query(peer_id, para_id).await
+	}
+
+	async fn slash(&mut self, peer_id: &PeerId, para_id: &ParaId, value: Score) {
+		self.inner.slash(peer_id, para_id, value).await;
+		self.persist([*para_id], None);
+	}
+
+	async fn prune_paras(&mut self, registered_paras: BTreeSet<ParaId>) {
+		let pruned = self
+			.inner
+			.paras()
+			.filter(|para_id| !registered_paras.contains(para_id))
+			.copied()
+			.collect::<Vec<_>>();
+		if pruned.is_empty() {
+			return
+		}
+
+		self.inner.prune_paras(registered_paras).await;
+		self.persist(pruned, None);
+	}
+
+	async fn process_bumps(
+		&mut self,
+		leaf_number: BlockNumber,
+		bumps: BTreeMap<ParaId, HashMap<PeerId, Score>>,
+		decay_value: Option<Score>,
+	) -> Vec<ReputationUpdate> {
+		let prev_finalized = self.inner.processed_finalized_block_number().await;
+		let paras = bumps.keys().copied().collect::<Vec<_>>();
+
+		let updates = self.inner.process_bumps(leaf_number, bumps, decay_value).await;
+
+		let last_finalized = self.inner.processed_finalized_block_number().await;
+		if last_finalized != prev_finalized {
+			self.persist(paras, last_finalized);
+		}
+
+		updates
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+
+	const COL: u32 = 0;
+
+	fn make_db() -> Arc<dyn Database> {
+		Arc::new(DbAdapter::new(kvdb_memorydb::create(1), &[COL]))
+	}
+
+	async fn open(db: &Arc<dyn Database>, stored_limit_per_para: u8) -> PersistentDb {
+		PersistentDb::new(
+			db.clone(),
+			ReputationDbConfig { col_reputation_data: COL },
+			stored_limit_per_para,
+		)
+		.await
+		.unwrap()
+	}
+
+	fn score(value: u16) -> Score {
+		Score::new(value).unwrap()
+	}
+
+	fn bumps(
+		bumps: impl IntoIterator<Item = (ParaId, PeerId, u16)>,
+	) -> BTreeMap<ParaId, HashMap<PeerId, Score>> {
+		let mut result = BTreeMap::<_, HashMap<_, _>>::new();
+		for (para_id, peer_id, value) in bumps {
+			result.entry(para_id).or_default().insert(peer_id, score(value));
+		}
+		result
+	}
+
+	#[tokio::test]
+	// Test that reputations survive reopening the database.
+	async fn reputations_survive_restart() {
+		let db = make_db();
+		let (para_a, para_b) = (ParaId::from(100), ParaId::from(200));
+		let (peer_a, peer_b) = (PeerId::random(), PeerId::random());
+
+		{
+			let mut rep_db = open(&db, 10).await;
+			assert_eq!(rep_db.processed_finalized_block_number().await, None);
+
+			assert_eq!(
+				rep_db
+					.process_bumps(
+						10,
+						bumps([(para_a, peer_a, 10), (para_a, peer_b, 20), (para_b, peer_a, 5)]),
+						None,
+					)
+					.await
+					.len(),
+				3
+			);
+			// Decays `peer_b` for `para_a`.
+			rep_db.process_bumps(11, bumps([(para_a, peer_a, 10)]), Some(score(5))).await;
+			rep_db.slash(&peer_a, &para_b, score(2)).await;
+		}
+
+		let rep_db = open(&db, 10).await;
+		assert_eq!(rep_db.processed_finalized_block_number().await, Some(11));
+		assert_eq!(rep_db.query(&peer_a, &para_a).await, Some(score(20)));
+		assert_eq!(rep_db.query(&peer_b, &para_a).await, Some(score(15)));
+		assert_eq!(rep_db.query(&peer_a, &para_b).await, Some(score(3)));
+		assert_eq!(rep_db.query(&peer_b, &para_b).await, None);
+	}
+
+	#[tokio::test]
+	// Test that removed reputations are also removed from disk.
+	async fn removals_are_persisted() {
+		let db = make_db();
+		let (para_a, para_b, para_c) = (ParaId::from(100), ParaId::from(200), ParaId::from(300));
+		let (peer_a, peer_b) = (PeerId::random(), PeerId::random());
+
+		{
+			let mut rep_db = open(&db, 10).await;
+			rep_db
+				.process_bumps(
+					1,
+					bumps([(para_a, peer_a, 10), (para_b, peer_b, 10), (para_c, peer_a, 10)]),
+					None,
+				)
+				.await;
+
+			// Slashing the whole score removes the para.
+			rep_db.slash(&peer_a, &para_a, score(10)).await;
+			rep_db.prune_paras([para_a, para_c].into_iter().collect()).await;
+
+			// Bumps for an already processed block are ignored.
+			assert!(rep_db.process_bumps(1, bumps([(para_c, peer_b, 10)]), None).await.is_empty());
+		}
+
+		let rep_db = open(&db, 10).await;
+		assert_eq!(rep_db.processed_finalized_block_number().await, Some(1));
+		assert_eq!(rep_db.inner.paras().copied().collect::<Vec<_>>(), vec![para_c]);
+		assert_eq!(rep_db.query(&peer_a, &para_c).await, Some(score(10)));
+		assert_eq!(rep_db.query(&peer_b, &para_c).await, None);
+	}
+
+	#[tokio::test]
+	// Test that the per para limit is enforced on the stored reputations.
+	async fn per_para_limit_is_persisted() {
+		let db = make_db();
+		let para_id = ParaId::from(100);
+		let peers = (0..3).map(|_| PeerId::random()).collect::<Vec<_>>();
+
+		{
+			let mut rep_db = open(&db, 2).await;
+			rep_db.process_bumps(1, bumps([(para_id, peers[0], 10)]), None).await;
+			// Make sure the first peer is the least recently bumped.
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+			rep_db
+				.process_bumps(2, bumps([(para_id, peers[1], 10), (para_id, peers[2], 10)]), None)
+				.await;
+		}
+
+		let rep_db = open(&db, 2).await;
+		assert_eq!(rep_db.query(&peers[0], &para_id).await, None);
+		assert_eq!(rep_db.query(&peers[1], &para_id).await, Some(score(10)));
+		assert_eq!(rep_db.query(&peers[2], &para_id).await, Some(score(10)));
+	}
+
+	#[tokio::test]
+	// Test that corrupted entries are skipped when loading.
+	async fn corrupted_entries_are_ignored() {
+		let db = make_db();
+		let (para_a, para_b) = (ParaId::from(100), ParaId::from(200));
+		let peer_id = PeerId::random();
+
+		{
+			let mut rep_db = open(&db, 10).await;
+			rep_db.process_bumps(1, bumps([(para_a, peer_id, 10)]), None).await;
+		}
+
+		let mut tx = DBTransaction::new();
+		tx.put_vec(COL, &para_reputations_key(&para_b), vec![1, 2, 3]);
+		db.write(tx).unwrap();
+
+		let rep_db = open(&db, 10).await;
+		assert_eq!(rep_db.query(&peer_id, &para_a).await, Some(score(10)));
+		assert_eq!(rep_db.inner.paras().copied().collect::<Vec<_>>(), vec![para_a]);
+	}
+}
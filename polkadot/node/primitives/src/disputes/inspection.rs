@@ -0,0 +1,83 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::time::Duration;
+
+use polkadot_primitives::{CandidateHash, SessionIndex, ValidatorIndex};
+
+use super::{CandidateVotes, DisputeStatus, Timestamp};
+
+/// Outcome of the validation process of a dispute participation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParticipationOutcome {
+	/// Candidate was found to be valid.
+	Valid,
+	/// Candidate was found to be invalid.
+	Invalid,
+	/// Candidate was found to be unavailable.
+	Unavailable,
+	/// Something went wrong (bug), details can be found in the logs.
+	Error,
+}
+
+impl ParticipationOutcome {
+	/// If validation was successful, get whether the candidate was valid or invalid.
+	pub fn validity(self) -> Option<bool> {
+		match self {
+			Self::Valid => Some(true),
+			Self::Invalid => Some(false),
+			Self::Unavailable | Self::Error => None,
+		}
+	}
+}
+
+/// Timing of the vote imports for a dispute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisputeImportTiming {
+	/// When votes for the disputed candidate were first imported.
+	pub first_import: Timestamp,
+	/// When votes for the disputed candidate were last imported.
+	pub last_import: Timestamp,
+	/// How many vote imports were processed.
+	pub imports: u32,
+	/// How long processing the last import took.
+	pub last_import_duration: Duration,
+}
+
+/// Everything the dispute coordinator knows about a recent dispute.
+///
+/// Status and votes are read from the database, the remaining information is only kept in memory
+/// and therefore limited to what happened since the node started.
+#[derive(Debug, Clone)]
+pub struct DisputeInspection {
+	/// The session the disputed candidate appeared in.
+	pub session: SessionIndex,
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// Current status of the dispute.
+	pub status: DisputeStatus,
+	/// Whether the dispute is still considered active.
+	pub active: bool,
+	/// All votes received for the candidate, if still available.
+	pub votes: Option<CandidateVotes>,
+	/// Outcome of our own participation, if we participated.
+	pub participation: Option<ParticipationOutcome>,
+	/// Validators whose invalid votes for this yet unconfirmed dispute occupy a spam slot,
+	/// together with the number of spam slots they use in the session.
+	pub spam_slots: Vec<(ValidatorIndex, u32)>,
+	/// Timing of the vote imports, if any happened.
+	pub import_timing: Option<DisputeImportTiming>,
+}
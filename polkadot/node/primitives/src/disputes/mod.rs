@@ -35,6 +35,9 @@ mod message;
 pub use message::{DisputeMessage, Error as DisputeMessageCheckError, UncheckedDisputeMessage};
 mod status;
 pub use status::{dispute_is_inactive, DisputeStatus, Timestamp, ACTIVE_DURATION_SECS};
+/// Types for inspecting disputes known to the dispute coordinator.
+mod inspection;
+pub use inspection::{DisputeImportTiming, DisputeInspection, ParticipationOutcome};
 
 /// A checked dispute statement from an associated validator.
 #[derive(Debug, Clone)]
@@ -50,9 +50,9 @@ pub mod approval;
 /// Disputes related types.
 pub mod disputes;
 pub use disputes::{
-	dispute_is_inactive, CandidateVotes, DisputeMessage, DisputeMessageCheckError, DisputeStatus,
-	InvalidDisputeVote, SignedDisputeStatement, Timestamp, UncheckedDisputeMessage,
-	ValidDisputeVote, ACTIVE_DURATION_SECS,
+	dispute_is_inactive, CandidateVotes, DisputeImportTiming, DisputeInspection, DisputeMessage,
+	DisputeMessageCheckError, DisputeStatus, InvalidDisputeVote, SignedDisputeStatement, Timestamp,
+	UncheckedDisputeMessage, ValidDisputeVote, ACTIVE_DURATION_SECS,
 };
 
 /// The current node version, which takes the basic SemVer form `<major>.<minor>.<patch>`.
@@ -36,7 +36,7 @@ use mmr_gadget::MmrGadget;
 use polkadot_availability_recovery::FETCH_CHUNKS_THRESHOLD;
 use polkadot_node_core_approval_voting::Config as ApprovalVotingConfig;
 use polkadot_node_core_av_store::Config as AvailabilityConfig;
-use polkadot_node_core_candidate_validation::Config as CandidateValidationConfig;
+use polkadot_node_core_candidate_validation::{Config as CandidateValidationConfig, PvfDumpConfig};
 use polkadot_node_core_chain_selection::{
 	self as chain_selection_subsystem, Config as ChainSelectionConfig,
 };
@@ -98,6 +98,10 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub invulnerable_ah_collators: HashSet<polkadot_node_network_protocol::PeerId>,
 	/// Override for `HOLD_OFF_DURATION` constant .
 	pub collator_protocol_hold_off: Option<Duration>,
+	/// Where to dump the inputs of failed or slow candidate validations. `None` disables dumping.
+	pub pvf_dump: Option<PvfDumpConfig>,
+	/// Serve the unsafe disputes RPC. Only has an effect on validators.
+	pub enable_dispute_rpc: bool,
 }
 
 /// Completely built polkadot node service.
@@ -155,8 +159,11 @@ where
 			SelectRelayChain::new_longest_chain(basics.backend.clone())
 		};
 
+		// The dispute coordinator only runs on validators.
+		let dispute_rpc = (params.enable_dispute_rpc && config.role.is_authority())
+			.then(|| overseer_handle.clone());
 		let partial_components =
-			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain, dispute_rpc)?;
 
 		let net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 			&config.network,
@@ -209,6 +216,8 @@ where
 					keep_finalized_for,
 					invulnerable_ah_collators,
 					collator_protocol_hold_off,
+					pvf_dump,
+					enable_dispute_rpc: _,
 				},
 			overseer_connector,
 			partial_components:
@@ -399,6 +408,7 @@ where
 					pvf_execute_workers_max_num: execute_workers_max_num.unwrap_or(4),
 					pvf_prepare_workers_soft_max_num: prepare_workers_soft_max_num.unwrap_or(1),
 					pvf_prepare_workers_hard_max_num: prepare_workers_hard_max_num.unwrap_or(2),
+					pvf_dump,
 				})
 			} else {
 				None
@@ -485,6 +495,8 @@ where
 					network_provider: Arc::new(network.clone()),
 					is_validator: role.is_authority(),
 					enable_http_requests: false,
+					enable_websocket_connections: config.offchain_worker.websocket_enabled,
+					outbound_policy: config.offchain_worker.outbound_policy.clone(),
 					custom_extensions: move |_| vec![],
 				})?
 				.run(client.clone(), task_manager.spawn_handle())
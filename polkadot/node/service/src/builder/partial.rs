@@ -22,6 +22,7 @@ use crate::{
 	fake_runtime_api::RuntimeApi, grandpa_support, relay_chain_selection, Error, FullBackend,
 	FullClient, IdentifyVariant, GRANDPA_JUSTIFICATION_PERIOD,
 };
+use polkadot_overseer::Handle;
 use polkadot_primitives::Block;
 use sc_consensus_grandpa::FinalityProofProvider as GrandpaFinalityProofProvider;
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
@@ -146,6 +147,7 @@ pub(crate) fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	dispute_rpc: Option<Handle>,
 ) -> Result<PolkadotPartialComponents<ChainSelection>, Error>
 where
 	ChainSelection: 'static + SelectChain<Block>,
@@ -259,6 +261,9 @@ where
 					subscription_executor,
 				},
 				backend: backend.clone(),
+				disputes: dispute_rpc
+					.clone()
+					.map(|overseer_handle| polkadot_rpc::DisputesDeps { overseer_handle }),
 			};
 
 			polkadot_rpc::create_full(deps).map_err(Into::into)
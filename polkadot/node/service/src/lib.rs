@@ -35,6 +35,9 @@ pub mod workers;
 #[cfg(feature = "full-node")]
 pub use crate::builder::{new_full, NewFull, NewFullParams};
 
+#[cfg(feature = "full-node")]
+pub use polkadot_node_core_candidate_validation::PvfDumpConfig;
+
 #[cfg(feature = "full-node")]
 pub use self::overseer::{
 	CollatorOverseerGen, ExtendedOverseerGenArgs, OverseerGen, OverseerGenArgs,
@@ -400,7 +403,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let sc_service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
@@ -299,6 +299,7 @@ where
 					return Err(Error::Overseer(SubsystemError::Context(
 						"build validator overseer for parachain node".to_owned(),
 					))),
+				#[cfg(not(feature = "experimental-collator-protocol"))]
 				IsParachainNode::No => ProtocolSide::Validator {
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
@@ -306,6 +307,20 @@ where
 					invulnerables: invulnerable_ah_collators,
 					collator_protocol_hold_off,
 				},
+				#[cfg(feature = "experimental-collator-protocol")]
+				IsParachainNode::No => {
+					// Not supported by the experimental validator side yet.
+					let _ = (invulnerable_ah_collators, collator_protocol_hold_off);
+					ProtocolSide::ValidatorExperimental {
+						keystore: keystore.clone(),
+						metrics: Metrics::register(registry)?,
+						db: parachains_db.clone(),
+						db_config: polkadot_collator_protocol::ReputationDbConfig {
+							col_reputation_data: crate::parachains_db::REAL_COLUMNS
+								.col_collator_reputation_data,
+						},
+					}
+				},
 			};
 			CollatorProtocolSubsystem::new(side)
 		})
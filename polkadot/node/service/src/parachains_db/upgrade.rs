@@ -40,7 +40,8 @@ const VERSION_FILE_NAME: &'static str = "parachain_db_version";
 /// Version 4 changes approval db format for `OurAssignment`.
 /// Version 5 changes approval db format to hold some additional
 /// information about delayed approvals.
-pub(crate) const CURRENT_VERSION: Version = 5;
+/// Version 6 adds a column for collator reputations.
+pub(crate) const CURRENT_VERSION: Version = 6;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -111,6 +112,8 @@ pub(crate) fn try_upgrade_db_to_next_version(
 			// 3 -> 4 migration
 			Some(3) => migrate_from_version_3_or_4_to_5(db_path, db_kind, v1_to_latest)?,
 			Some(4) => migrate_from_version_3_or_4_to_5(db_path, db_kind, v2_to_latest)?,
+			// 5 -> 6 migration
+			Some(5) => migrate_from_version_5_to_6(db_path, db_kind)?,
 			// Already at current version, do nothing.
 			Some(CURRENT_VERSION) => CURRENT_VERSION,
 			// This is an arbitrary future version, we don't handle it.
@@ -228,7 +231,19 @@ where
 	};
 
 	gum::info!(target: LOG_TARGET, "Migration complete! ");
-	Ok(CURRENT_VERSION)
+	Ok(5)
+}
+
+fn migrate_from_version_5_to_6(path: &Path, db_kind: DatabaseKind) -> Result<Version, Error> {
+	gum::info!(target: LOG_TARGET, "Migrating parachains db from version 5 to version 6 ...");
+	match db_kind {
+		DatabaseKind::ParityDB => paritydb_migrate_from_version_5_to_6(path),
+		DatabaseKind::RocksDB => rocksdb_migrate_from_version_5_to_6(path),
+	}
+	.and_then(|result| {
+		gum::info!(target: LOG_TARGET, "Migration complete! ");
+		Ok(result)
+	})
 }
 
 fn migrate_from_version_2_to_3(path: &Path, db_kind: DatabaseKind) -> Result<Version, Error> {
@@ -290,6 +305,22 @@ fn rocksdb_migrate_from_version_2_to_3(path: &Path) -> Result<Version, Error> {
 	Ok(3)
 }
 
+/// Migration from version 5 to version 6:
+/// * the number of columns has changed from 5 to 6;
+fn rocksdb_migrate_from_version_5_to_6(path: &Path) -> Result<Version, Error> {
+	use kvdb_rocksdb::{Database, DatabaseConfig};
+
+	let db_path = path
+		.to_str()
+		.ok_or_else(|| super::other_io_error("Invalid database path".into()))?;
+	let db_cfg = DatabaseConfig::with_columns(super::columns::v4::NUM_COLUMNS);
+	let mut db = Database::open(&db_cfg, db_path)?;
+
+	db.add_column()?;
+
+	Ok(6)
+}
+
 // This currently clears columns which had their configs altered between versions.
 // The columns to be changed are constrained by the `allowed_columns` vector.
 fn paritydb_fix_columns(
@@ -380,6 +411,17 @@ pub(crate) fn paritydb_version_3_config(path: &Path) -> parity_db::Options {
 	options
 }
 
+/// Database configuration for version 6.
+pub(crate) fn paritydb_version_6_config(path: &Path) -> parity_db::Options {
+	let mut options =
+		parity_db::Options::with_columns(&path, super::columns::v5::NUM_COLUMNS as u8);
+	for i in columns::v5::ORDERED_COL {
+		options.columns[*i as usize].btree_index = true;
+	}
+
+	options
+}
+
 /// Database configuration for version 0. This is useful just for testing.
 #[cfg(test)]
 pub(crate) fn paritydb_version_0_config(path: &Path) -> parity_db::Options {
@@ -426,6 +468,21 @@ fn paritydb_migrate_from_version_2_to_3(path: &Path) -> Result<Version, Error> {
 	Ok(3)
 }
 
+/// Migration from version 5 to version 6:
+/// - add a new column for collator reputations
+fn paritydb_migrate_from_version_5_to_6(path: &Path) -> Result<Version, Error> {
+	let mut options = paritydb_version_3_config(path);
+
+	// Adds the collator reputation column. It is iterated by prefix, hence the btree index.
+	parity_db::Db::add_column(
+		&mut options,
+		parity_db::ColumnOptions { btree_index: true, ..Default::default() },
+	)
+	.map_err(|e| other_io_error(format!("Error adding column {:?}", e)))?;
+
+	Ok(6)
+}
+
 /// Remove the lock file. If file is locked, it will wait up to 1s.
 #[cfg(test)]
 pub fn remove_file_lock(path: &std::path::Path) {
@@ -699,6 +756,66 @@ mod tests {
 		assert_eq!(db.num_columns(), columns::v4::NUM_COLUMNS as u8);
 	}
 
+	#[test]
+	fn test_rocksdb_migrate_5_to_6() {
+		use kvdb_rocksdb::{Database, DatabaseConfig};
+
+		let db_dir = tempfile::tempdir().unwrap();
+		let db_path = db_dir.path().to_str().unwrap();
+		let db_cfg = DatabaseConfig::with_columns(super::columns::v4::NUM_COLUMNS);
+
+		{
+			let db = Database::open(&db_cfg, db_path).unwrap();
+			assert_eq!(db.num_columns(), super::columns::v4::NUM_COLUMNS as u32);
+		}
+
+		// We need to properly set db version for upgrade to work.
+		fs::write(version_file_path(db_dir.path()), "5").expect("Failed to write DB version");
+
+		try_upgrade_db(&db_dir.path(), DatabaseKind::RocksDB, 6).unwrap();
+
+		let db_cfg = DatabaseConfig::with_columns(super::columns::v5::NUM_COLUMNS);
+		let db = Database::open(&db_cfg, db_path).unwrap();
+
+		assert_eq!(db.num_columns(), super::columns::v5::NUM_COLUMNS);
+	}
+
+	#[test]
+	fn test_paritydb_migrate_5_to_6() {
+		use parity_db::Db;
+
+		let db_dir = tempfile::tempdir().unwrap();
+		let path = db_dir.path();
+		let test_key = b"1337";
+
+		// We need to properly set db version for upgrade to work.
+		fs::write(version_file_path(path), "5").expect("Failed to write DB version");
+
+		{
+			let db = Db::open_or_create(&paritydb_version_3_config(&path)).unwrap();
+
+			// Write some dummy data
+			db.commit(vec![(
+				COL_DISPUTE_COORDINATOR_DATA as u8,
+				test_key.to_vec(),
+				Some(b"0xdeadb00b".to_vec()),
+			)])
+			.unwrap();
+
+			assert_eq!(db.num_columns(), columns::v4::NUM_COLUMNS as u8);
+		}
+
+		try_upgrade_db(&path, DatabaseKind::ParityDB, 6).unwrap();
+
+		let db = Db::open(&paritydb_version_6_config(&path)).unwrap();
+
+		assert_eq!(db.num_columns(), columns::v5::NUM_COLUMNS as u8);
+		assert_eq!(
+			db.get(COL_DISPUTE_COORDINATOR_DATA as u8, test_key).unwrap(),
+			Some("0xdeadb00b".as_bytes().to_vec())
+		);
+	}
+
 	#[test]
 	fn test_paritydb_migrate_2_to_3() {
 		use parity_db::Db;
@@ -20,7 +20,10 @@
 use clap::Parser;
 use color_eyre::eyre;
 use colored::Colorize;
-use polkadot_subsystem_bench::{approval, availability, configuration, disputes, statement};
+use polkadot_subsystem_bench::{
+	approval, availability, collator_protocol, configuration, disputes, prospective_parachains,
+	statement,
+};
 use pyroscope::PyroscopeAgent;
 use pyroscope_pprofrs::{pprof_backend, PprofConfig};
 use serde::{Deserialize, Serialize};
@@ -44,6 +47,10 @@ pub enum TestObjective {
 	StatementDistribution,
 	/// Benchmark the dispute-coordinator subsystem
 	DisputeCoordinator(disputes::DisputesOptions),
+	/// Benchmark the validator side of the collator-protocol subsystem
+	CollatorProtocol(collator_protocol::CollatorProtocolOptions),
+	/// Benchmark the prospective-parachains subsystem
+	ProspectiveParachains(prospective_parachains::ProspectiveParachainsOptions),
 }
 
 impl std::fmt::Display for TestObjective {
@@ -57,6 +64,8 @@ impl std::fmt::Display for TestObjective {
 				Self::ApprovalVoting(_) => "ApprovalVoting",
 				Self::StatementDistribution => "StatementDistribution",
 				Self::DisputeCoordinator(_) => "DisputeCoordinator",
+				Self::CollatorProtocol(_) => "CollatorProtocol",
+				Self::ProspectiveParachains(_) => "ProspectiveParachains",
 			}
 		)
 	}
@@ -178,6 +187,21 @@ impl BenchCli {
 					env.runtime()
 						.block_on(disputes::benchmark_dispute_coordinator(&mut env, &state))
 				},
+				TestObjective::CollatorProtocol(ref options) => {
+					let state = collator_protocol::TestState::new(&test_config, options);
+					let mut env = collator_protocol::prepare_test(&state, options, true);
+					env.runtime()
+						.block_on(collator_protocol::benchmark_collator_protocol(&mut env, &state))
+				},
+				TestObjective::ProspectiveParachains(ref options) => {
+					let state = prospective_parachains::TestState::new(&test_config, options);
+					let mut env = prospective_parachains::prepare_test(&state, true);
+					env.runtime().block_on(
+						prospective_parachains::benchmark_prospective_parachains(
+							&mut env, &state, options,
+						),
+					)
+				},
 			};
 			println!("\n{}\n{}", benchmark_name.purple(), usage);
 		}
@@ -0,0 +1,116 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A mocked `candidate-backing` subsystem which seconds every collation it is given.
+
+use crate::{
+	collator_protocol::{test_state::TestState, LOG_TARGET},
+	NODE_UNDER_TEST,
+};
+use futures::FutureExt;
+use polkadot_node_primitives::{SignedFullStatement, Statement};
+use polkadot_node_subsystem::{
+	messages::{CandidateBackingMessage, CollatorProtocolMessage},
+	overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use polkadot_primitives::{SigningContext, ValidatorIndex};
+use sp_core::Pair;
+use std::time::Instant;
+
+/// A mock of the candidate backing subsystem, which accepts all collations for seconding and
+/// reports them as seconded right away.
+pub struct MockSecondingBacking {
+	state: TestState,
+}
+
+impl MockSecondingBacking {
+	pub fn new(state: TestState) -> Self {
+		Self { state }
+	}
+}
+
+#[overseer::subsystem(CandidateBacking, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockSecondingBacking {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "test-environment", future }
+	}
+}
+
+#[overseer::contextbounds(CandidateBacking, prefix = self::overseer)]
+impl MockSecondingBacking {
+	async fn run<Context>(self, mut ctx: Context) {
+		let pair = self
+			.state
+			.test_authorities
+			.validator_pairs
+			.get(NODE_UNDER_TEST as usize)
+			.expect("The node under test is a validator")
+			.clone();
+
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					CandidateBackingMessage::CanSecond(_request, tx) => {
+						let _ = tx.send(true);
+					},
+					CandidateBackingMessage::Second(
+						relay_parent,
+						candidate_receipt,
+						_pvd,
+						_pov,
+					) => {
+						let candidate_hash = candidate_receipt.hash();
+						let (receipt, _) = self
+							.state
+							.collations
+							.get(&candidate_hash)
+							.expect("Only the emulated collators are connected");
+						self.state.seconded.lock().unwrap().insert(candidate_hash, Instant::now());
+						gum::debug!(target: LOG_TARGET, ?candidate_hash, "Collation seconded");
+
+						let statement = Statement::Seconded(receipt.clone());
+						let context =
+							SigningContext { parent_hash: relay_parent, session_index: 0 };
+						let payload = statement.to_compact().signing_payload(&context);
+						let statement = SignedFullStatement::new(
+							statement,
+							ValidatorIndex(NODE_UNDER_TEST),
+							pair.sign(&payload[..]),
+							&context,
+							&pair.public(),
+						)
+						.expect("The statement is signed by the node under test");
+
+						ctx.send_message(CollatorProtocolMessage::Seconded(
+							relay_parent,
+							statement,
+						))
+						.await;
+					},
+					message => unimplemented!("Unexpected candidate-backing message {:?}", message),
+				},
+			}
+		}
+	}
+}
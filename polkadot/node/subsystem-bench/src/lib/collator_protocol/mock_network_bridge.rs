@@ -0,0 +1,146 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A mocked `network-bridge-tx` subsystem which emulates the collators the node under test
+//! fetches collations from.
+
+use crate::{
+	collator_protocol::{test_state::TestState, LOG_TARGET},
+	configuration::random_latency,
+	network::{NetworkEmulatorHandle, RateLimit},
+};
+use codec::Encode;
+use futures::{lock::Mutex, FutureExt};
+use polkadot_node_network_protocol::{
+	request_response::{v2::CollationFetchingResponse, Recipient, Requests},
+	CollationProtocols,
+};
+use polkadot_node_subsystem::{
+	messages::NetworkBridgeTxMessage, overseer, SpawnedSubsystem, SubsystemError,
+};
+use polkadot_node_subsystem_types::OverseerSignal;
+use sc_network::{ProtocolName, RequestFailure};
+use sc_network_types::PeerId;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// A mock of the network bridge tx subsystem, which answers collation fetching requests on
+/// behalf of the emulated collators.
+pub struct MockCollatorNetwork {
+	state: TestState,
+	network: NetworkEmulatorHandle,
+	// Upload bandwidth of each collator
+	rate_limiters: HashMap<PeerId, Arc<Mutex<RateLimit>>>,
+}
+
+impl MockCollatorNetwork {
+	pub fn new(state: TestState, network: NetworkEmulatorHandle) -> Self {
+		let rate_limiters = state
+			.collators
+			.iter()
+			.map(|collator| {
+				(
+					collator.peer_id,
+					Arc::new(Mutex::new(RateLimit::new(10, state.config.peer_bandwidth))),
+				)
+			})
+			.collect();
+
+		Self { state, network, rate_limiters }
+	}
+}
+
+#[overseer::subsystem(NetworkBridgeTx, error=SubsystemError, prefix=self::overseer)]
+impl<Context> MockCollatorNetwork {
+	fn start(self, ctx: Context) -> SpawnedSubsystem {
+		let future = self.run(ctx).map(|_| Ok(())).boxed();
+
+		SpawnedSubsystem { name: "network-bridge-tx", future }
+	}
+}
+
+#[overseer::contextbounds(NetworkBridgeTx, prefix = self::overseer)]
+impl MockCollatorNetwork {
+	async fn run<Context>(self, mut ctx: Context) {
+		loop {
+			let msg = ctx.recv().await.expect("Overseer never fails us");
+			match msg {
+				orchestra::FromOrchestra::Signal(signal) =>
+					if signal == OverseerSignal::Conclude {
+						return
+					},
+				orchestra::FromOrchestra::Communication { msg } => match msg {
+					NetworkBridgeTxMessage::SendRequests(requests, _if_disconnected) =>
+						for request in requests {
+							let Requests::CollationFetchingV2(request) = request else {
+								unimplemented!("Unexpected request {:?}", request)
+							};
+							gum::debug!(target: LOG_TARGET, request = ?request.payload, "Collation requested");
+							self.network.inc_sent(request.payload.encoded_size());
+
+							let Recipient::Peer(peer_id) = request.peer else {
+								unimplemented!("Collators are always addressed by peer id")
+							};
+							let (Some(rate_limiter), Some((receipt, pov))) = (
+								self.rate_limiters.get(&peer_id).cloned(),
+								self.state.collations.get(&request.payload.candidate_hash),
+							) else {
+								let _ = request.pending_response.send(Err(RequestFailure::Refused));
+								continue
+							};
+
+							let response = CollationFetchingResponse::Collation(
+								receipt.to_plain(),
+								pov.clone(),
+							)
+							.encode();
+							let latency = random_latency(self.state.config.latency.as_ref());
+							let network = self.network.clone();
+							let respond = async move {
+								tokio::time::sleep(Duration::from_millis(latency as u64)).await;
+								rate_limiter.lock().await.reap(response.len()).await;
+								network.inc_received(response.len());
+								let _ = request
+									.pending_response
+									.send(Ok((response, ProtocolName::from(""))));
+							};
+							ctx.spawn("collation-response", respond.boxed())
+								.expect("Spawning never fails");
+						},
+					NetworkBridgeTxMessage::SendCollationMessage(peers, message) => {
+						let size = match message {
+							CollationProtocols::V1(message) => message.encoded_size(),
+							CollationProtocols::V2(message) => message.encoded_size(),
+						};
+						self.network.inc_sent(size * peers.len());
+					},
+					NetworkBridgeTxMessage::SendCollationMessages(messages) =>
+						for (peers, message) in messages {
+							let size = match message {
+								CollationProtocols::V1(message) => message.encoded_size(),
+								CollationProtocols::V2(message) => message.encoded_size(),
+							};
+							self.network.inc_sent(size * peers.len());
+						},
+					NetworkBridgeTxMessage::ReportPeer(_) |
+					NetworkBridgeTxMessage::DisconnectPeers(_, _) => {
+						// The emulated collators never go away.
+					},
+					message => unimplemented!("Unexpected network bridge message {:?}", message),
+				},
+			}
+		}
+	}
+}
@@ -0,0 +1,308 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Subsystem benchmark for the validator side of the collator protocol.
+//!
+//! Scenario:
+//!   - Emulated collators of the para assigned to the core of the node under test connect and
+//!     declare themselves.
+//!   - At every block each collator advertises a collation built on top of the new leaf.
+//!   - The collator protocol fetches one of the collations and asks candidate backing to second it.
+//!     The mocked candidate backing seconds it right away.
+//!   - We measure the time between the advertisement and the seconding of the collation.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		chain_api::{ChainApiState, MockChainApi},
+		prospective_parachains::MockProspectiveParachains,
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle},
+	usage::BenchmarkUsage,
+};
+use codec::Encode;
+use colored::Colorize;
+use itertools::Itertools;
+use mock_candidate_backing::MockSecondingBacking;
+use mock_network_bridge::MockCollatorNetwork;
+use polkadot_collator_protocol::{
+	CollatorEvictionPolicy, CollatorProtocolSubsystem, ProtocolSide, ReputationDbConfig,
+};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	peer_set::CollationVersion, v1 as protocol_v1, v2 as protocol_v2, CollationProtocols,
+	CollatorProtocolMessage as WireMessage, ObservedRole, OurView,
+};
+use polkadot_node_subsystem::messages::{AllMessages, CollatorProtocolMessage, NetworkBridgeEvent};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, ValidatorId};
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_core::Pair;
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+use test_state::OWN_PARA;
+
+mod mock_candidate_backing;
+mod mock_network_bridge;
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::collator-protocol";
+
+/// How long we wait for a collation to be seconded at every block.
+const MAX_SECONDING_WAIT: Duration = Duration::from_secs(6);
+
+/// Parameters specific to the collator protocol benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CollatorProtocolOptions {
+	#[clap(short, long, default_value_t = 10)]
+	/// The number of collators advertising a collation at every block.
+	pub n_collators: usize,
+	#[clap(long, default_value_t = false)]
+	#[serde(default)]
+	/// Benchmark the experimental validator side of the protocol.
+	/// It only bootstraps its state for now, so no collations are fetched.
+	pub experimental: bool,
+}
+
+pub fn make_keystore() -> KeystorePtr {
+	let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	options: &CollatorProtocolOptions,
+	network: NetworkEmulatorHandle,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		Default::default(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let mock_prospective_parachains = MockProspectiveParachains::new()
+		.with_min_relay_parents(state.min_relay_parents.clone())
+		.with_validation_data(state.validation_data.clone());
+	let mock_candidate_backing = MockSecondingBacking::new(state.clone());
+	let network_bridge_tx = MockCollatorNetwork::new(state.clone(), network);
+	let keystore = make_keystore();
+	let protocol_side = if options.experimental {
+		let db = kvdb_memorydb::create(1);
+		let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(db, &[0]);
+		ProtocolSide::ValidatorExperimental {
+			keystore,
+			metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+			db: Arc::new(db),
+			db_config: ReputationDbConfig { col_reputation_data: 0 },
+		}
+	} else {
+		ProtocolSide::Validator {
+			keystore,
+			eviction_policy: CollatorEvictionPolicy::default(),
+			metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+			invulnerables: HashSet::new(),
+			collator_protocol_hold_off: None,
+		}
+	};
+	let collator_protocol = CollatorProtocolSubsystem::new(protocol_side);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_prospective_parachains(|_| mock_prospective_parachains)
+		.replace_candidate_backing(|_| mock_candidate_backing)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_collator_protocol(|_| collator_protocol);
+
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(
+	state: &TestState,
+	options: &CollatorProtocolOptions,
+	with_prometheus_endpoint: bool,
+) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	// Collators are not part of the emulated validator network, so we only use it to account the
+	// traffic of the node under test.
+	let (network, _network_interface, _network_receiver) =
+		new_network(&state.config, &dependencies, &state.test_authorities, vec![]);
+	let (overseer, overseer_handle) =
+		build_overseer(state, options, network.clone(), &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+fn network_bridge_update(event: NetworkBridgeEvent<WireMessage>) -> AllMessages {
+	AllMessages::CollatorProtocol(CollatorProtocolMessage::NetworkBridgeUpdate(event))
+}
+
+fn wire_message_size(message: &WireMessage) -> usize {
+	match message {
+		CollationProtocols::V1(message) => message.encoded_size(),
+		CollationProtocols::V2(message) => message.encoded_size(),
+	}
+}
+
+async fn connect_collators(env: &mut TestEnvironment, state: &TestState) {
+	for collator in state.collators.iter() {
+		env.send_message(network_bridge_update(NetworkBridgeEvent::PeerConnected(
+			collator.peer_id,
+			ObservedRole::Full,
+			CollationVersion::V2.into(),
+			None,
+		)))
+		.await;
+
+		let declare = CollationProtocols::V2(protocol_v2::CollatorProtocolMessage::Declare(
+			collator.pair.public(),
+			OWN_PARA,
+			collator.pair.sign(&protocol_v1::declare_signature_payload(&collator.peer_id)),
+		));
+		env.network().inc_received(wire_message_size(&declare));
+		env.send_message(network_bridge_update(NetworkBridgeEvent::PeerMessage(
+			collator.peer_id,
+			declare,
+		)))
+		.await;
+	}
+}
+
+pub async fn benchmark_collator_protocol(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let test_start = Instant::now();
+	let mut fetch_latencies = vec![];
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+		env.send_message(network_bridge_update(NetworkBridgeEvent::OurViewChange(OurView::new(
+			[block_info.hash],
+			0,
+		))))
+		.await;
+
+		// Collators can only declare once we know the para assigned to our core.
+		if block_num == 1 {
+			connect_collators(env, state).await;
+		}
+
+		let mut advertised_at = HashMap::new();
+		for advertisement in state.advertisements.get(&block_info.hash).expect("pregenerated") {
+			let peer_id = state.collators[advertisement.collator_index].peer_id;
+			let message =
+				CollationProtocols::V2(protocol_v2::CollatorProtocolMessage::AdvertiseCollation {
+					relay_parent: block_info.hash,
+					candidate_hash: advertisement.candidate_hash,
+					parent_head_data_hash: advertisement.parent_head_data_hash,
+				});
+			env.network().inc_received(wire_message_size(&message));
+			advertised_at.insert(advertisement.candidate_hash, Instant::now());
+			env.send_message(network_bridge_update(NetworkBridgeEvent::PeerMessage(
+				peer_id, message,
+			)))
+			.await;
+		}
+
+		let wait_start = Instant::now();
+		let latencies = loop {
+			let latencies = {
+				let seconded = state.seconded.lock().unwrap();
+				advertised_at
+					.iter()
+					.filter_map(|(candidate_hash, advertised_at)| {
+						seconded.get(candidate_hash).map(|at| at.duration_since(*advertised_at))
+					})
+					.collect_vec()
+			};
+			if !latencies.is_empty() || wait_start.elapsed() > MAX_SECONDING_WAIT {
+				break latencies
+			}
+
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		};
+
+		if latencies.is_empty() {
+			gum::warn!(target: LOG_TARGET, "No collation seconded at block {}", block_num);
+		}
+		fetch_latencies.extend(latencies);
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{duration:?}ms").cyan());
+	gum::info!(target: LOG_TARGET,
+		"Collations seconded: {}",
+		format!("{}/{}", fetch_latencies.len(), config.num_blocks).cyan()
+	);
+	if !fetch_latencies.is_empty() {
+		let total = fetch_latencies.iter().sum::<Duration>();
+		let max = fetch_latencies.iter().max().expect("not empty");
+		gum::info!(target: LOG_TARGET,
+			"Collation fetch latency: avg {}, max {}",
+			format!("{} ms", total.as_millis() / fetch_latencies.len() as u128).red(),
+			format!("{} ms", max.as_millis()).red()
+		);
+	}
+
+	env.stop().await;
+	env.collect_resource_usage(&["collator-protocol"], false)
+}
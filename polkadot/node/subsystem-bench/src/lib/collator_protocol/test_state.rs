@@ -0,0 +1,204 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	collator_protocol::CollatorProtocolOptions,
+	configuration::{TestAuthorities, TestConfiguration},
+	environment::GENESIS_HASH,
+};
+use codec::Encode;
+use polkadot_node_primitives::{BlockData, PoV};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	BlockNumber, CandidateHash, CandidateReceiptV2 as CandidateReceipt, CollatorPair,
+	CommittedCandidateReceiptV2 as CommittedCandidateReceipt, Hash, HeadData, Header, Id,
+	MutateDescriptorV2, PersistedValidationData,
+};
+use polkadot_primitives_test_helpers::{dummy_hash, make_candidate};
+use sc_network_types::PeerId;
+use sp_core::Pair;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+/// The para assigned to the core of the node under test.
+pub const OWN_PARA: Id = Id::new(1);
+
+/// An emulated collator of [`OWN_PARA`].
+#[derive(Clone)]
+pub struct Collator {
+	pub peer_id: PeerId,
+	pub pair: CollatorPair,
+}
+
+/// A collation advertised by a collator.
+#[derive(Clone)]
+pub struct Advertisement {
+	// Index of the advertising collator
+	pub collator_index: usize,
+	pub candidate_hash: CandidateHash,
+	pub parent_head_data_hash: Hash,
+}
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers, including genesis
+	pub block_headers: HashMap<Hash, Header>,
+	// One candidate receipt per core and block, used to build the claim queue
+	pub candidate_receipts: HashMap<Hash, Vec<CandidateReceipt>>,
+	// Emulated collators
+	pub collators: Vec<Collator>,
+	// Advertisements sent by the collators at each block
+	pub advertisements: HashMap<Hash, Vec<Advertisement>>,
+	// Collations served by the collators
+	pub collations: Arc<HashMap<CandidateHash, (CommittedCandidateReceipt, PoV)>>,
+	// Validation data keyed by the parent head data hash of the collations
+	pub validation_data: HashMap<Hash, PersistedValidationData>,
+	// Minimum relay parents reported by prospective parachains per leaf
+	pub min_relay_parents: HashMap<Hash, Vec<(Id, BlockNumber)>>,
+	// Time at which each collation was seconded
+	pub seconded: Arc<Mutex<HashMap<CandidateHash, Instant>>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &CollatorProtocolOptions) -> Self {
+		let config = config.clone();
+		let test_authorities = config.generate_authorities();
+		let block_infos = generate_block_infos(config.num_blocks);
+		let block_headers = std::iter::once(genesis_header())
+			.chain(block_infos.iter().map(generate_block_header))
+			.collect();
+		let collators = (0..options.n_collators)
+			.map(|_| Collator { peer_id: PeerId::random(), pair: CollatorPair::generate().0 })
+			.collect::<Vec<_>>();
+
+		let mut candidate_receipts: HashMap<Hash, Vec<CandidateReceipt>> = HashMap::new();
+		let mut advertisements: HashMap<Hash, Vec<Advertisement>> = HashMap::new();
+		let mut collations = HashMap::new();
+		let mut validation_data = HashMap::new();
+		let mut min_relay_parents = HashMap::new();
+		let pov_size = *config.pov_sizes().first().expect("PoV sizes are generated");
+
+		for block_info in block_infos.iter() {
+			let number = block_info.number;
+			for core_index in 0..config.n_cores {
+				let (candidate, _) = make_candidate(
+					block_info.hash,
+					number,
+					Id::new(core_index as u32 + 1),
+					HeadData::default(),
+					HeadData::default(),
+					Default::default(),
+				);
+				candidate_receipts
+					.entry(block_info.hash)
+					.or_default()
+					.push(candidate.to_plain());
+			}
+
+			// All collators build on top of the same parent.
+			let parent_head = HeadData(number.encode());
+			for collator_index in 0..collators.len() {
+				let (mut candidate, pvd) = make_candidate(
+					block_info.hash,
+					number,
+					OWN_PARA,
+					parent_head.clone(),
+					HeadData((number, collator_index as u32).encode()),
+					Default::default(),
+				);
+				let pov = PoV { block_data: BlockData(vec![collator_index as u8; pov_size]) };
+				candidate.descriptor.set_pov_hash(pov.hash());
+				let candidate_hash = candidate.hash();
+
+				advertisements.entry(block_info.hash).or_default().push(Advertisement {
+					collator_index,
+					candidate_hash,
+					parent_head_data_hash: parent_head.hash(),
+				});
+				collations.insert(candidate_hash, (candidate, pov));
+				validation_data.insert(parent_head.hash(), pvd);
+			}
+
+			min_relay_parents.insert(
+				block_info.hash,
+				(0..config.n_cores).map(|index| (Id::new(index as u32 + 1), number)).collect(),
+			);
+		}
+
+		Self {
+			config,
+			test_authorities,
+			block_infos,
+			block_headers,
+			candidate_receipts,
+			collators,
+			advertisements,
+			collations: Arc::new(collations),
+			validation_data,
+			min_relay_parents,
+			seconded: Default::default(),
+		}
+	}
+}
+
+fn generate_block_infos(num_blocks: usize) -> Vec<BlockInfo> {
+	let mut parent_hash = GENESIS_HASH;
+	(1..=num_blocks)
+		.map(|block_num| {
+			let mut block_info =
+				new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber);
+			block_info.parent_hash = parent_hash;
+			parent_hash = block_info.hash;
+			block_info
+		})
+		.collect()
+}
+
+fn genesis_header() -> (Hash, Header) {
+	(
+		GENESIS_HASH,
+		Header {
+			digest: Default::default(),
+			number: 0,
+			parent_hash: Default::default(),
+			extrinsics_root: Default::default(),
+			state_root: dummy_hash(),
+		},
+	)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (Hash, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: dummy_hash(),
+		},
+	)
+}
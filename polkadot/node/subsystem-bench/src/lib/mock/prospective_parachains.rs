@@ -20,14 +20,39 @@ use futures::FutureExt;
 use polkadot_node_subsystem::{
 	messages::ProspectiveParachainsMessage, overseer, SpawnedSubsystem, SubsystemError,
 };
-use polkadot_node_subsystem_types::OverseerSignal;
-use polkadot_primitives::Hash;
+use polkadot_node_subsystem_types::{messages::ProspectiveValidationDataRequest, OverseerSignal};
+use polkadot_primitives::{BlockNumber, Hash, Id as ParaId, PersistedValidationData};
+use std::collections::HashMap;
 
-pub struct MockProspectiveParachains {}
+#[derive(Default)]
+pub struct MockProspectiveParachains {
+	// Minimum relay parents reported per leaf
+	min_relay_parents: HashMap<Hash, Vec<(ParaId, BlockNumber)>>,
+	// Validation data of the known candidates, keyed by the hash of their parent head data
+	validation_data: HashMap<Hash, PersistedValidationData>,
+}
 
 impl MockProspectiveParachains {
 	pub fn new() -> Self {
-		Self {}
+		Self::default()
+	}
+
+	/// Sets the minimum relay parents reported for each leaf.
+	pub fn with_min_relay_parents(
+		mut self,
+		min_relay_parents: HashMap<Hash, Vec<(ParaId, BlockNumber)>>,
+	) -> Self {
+		self.min_relay_parents = min_relay_parents;
+		self
+	}
+
+	/// Sets the validation data returned for candidates building on a given parent head data hash.
+	pub fn with_validation_data(
+		mut self,
+		validation_data: HashMap<Hash, PersistedValidationData>,
+	) -> Self {
+		self.validation_data = validation_data;
+		self
 	}
 }
 
@@ -51,8 +76,18 @@ impl MockProspectiveParachains {
 						return
 					},
 				orchestra::FromOrchestra::Communication { msg } => match msg {
-					ProspectiveParachainsMessage::GetMinimumRelayParents(_relay_parent, tx) => {
-						tx.send(vec![]).unwrap();
+					ProspectiveParachainsMessage::GetMinimumRelayParents(relay_parent, tx) => {
+						tx.send(
+							self.min_relay_parents.get(&relay_parent).cloned().unwrap_or_default(),
+						)
+						.unwrap();
+					},
+					ProspectiveParachainsMessage::GetProspectiveValidationData(
+						ProspectiveValidationDataRequest { parent_head_data, .. },
+						tx,
+					) => {
+						tx.send(self.validation_data.get(&parent_head_data.hash()).cloned())
+							.unwrap();
 					},
 					ProspectiveParachainsMessage::GetHypotheticalMembership(req, tx) => {
 						tx.send(
@@ -65,7 +100,7 @@ impl MockProspectiveParachains {
 						.unwrap();
 					},
 					_ => {
-						unimplemented!("Unexpected chain-api message")
+						unimplemented!("Unexpected prospective-parachains message")
 					},
 				},
 			}
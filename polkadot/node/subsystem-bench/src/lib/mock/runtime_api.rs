@@ -26,10 +26,10 @@ use polkadot_node_subsystem::{
 };
 use polkadot_node_subsystem_types::OverseerSignal;
 use polkadot_primitives::{
-	node_features, ApprovalVotingParams, AsyncBackingParams, CandidateEvent,
-	CandidateReceiptV2 as CandidateReceipt, CoreIndex, CoreState, GroupIndex, GroupRotationInfo,
-	Id as ParaId, IndexedVec, NodeFeatures, OccupiedCore, ScheduledCore, SessionIndex, SessionInfo,
-	ValidationCode, ValidatorIndex,
+	async_backing::Constraints, node_features, ApprovalVotingParams, AsyncBackingParams,
+	CandidateEvent, CandidateReceiptV2 as CandidateReceipt, CoreIndex, CoreState, GroupIndex,
+	GroupRotationInfo, Id as ParaId, IndexedVec, NodeFeatures, OccupiedCore, ScheduledCore,
+	SessionIndex, SessionInfo, ValidationCode, ValidatorIndex,
 };
 use sp_consensus_babe::Epoch as BabeEpoch;
 use sp_core::H256;
@@ -53,6 +53,8 @@ pub struct RuntimeApiState {
 	session_index: SessionIndex,
 	// The claim queue
 	claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+	// Backing constraints per block and para
+	backing_constraints: HashMap<H256, HashMap<ParaId, Constraints>>,
 }
 
 #[derive(Clone)]
@@ -111,12 +113,22 @@ impl MockRuntimeApi {
 				session_index,
 				node_features,
 				claim_queue,
+				backing_constraints: Default::default(),
 			},
 			config,
 			core_state,
 		}
 	}
 
+	/// Sets the backing constraints returned for each block and para.
+	pub fn with_backing_constraints(
+		mut self,
+		backing_constraints: HashMap<H256, HashMap<ParaId, Constraints>>,
+	) -> Self {
+		self.state.backing_constraints = backing_constraints;
+		self
+	}
+
 	fn session_info(&self) -> SessionInfo {
 		session_info_for_peers(&self.config, &self.state.authorities)
 	}
@@ -330,6 +342,39 @@ impl MockRuntimeApi {
 						RuntimeApiMessage::Request(_parent, RuntimeApiRequest::ClaimQueue(tx)) => {
 							tx.send(Ok(self.state.claim_queue.clone())).unwrap();
 						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::SchedulingLookahead(_session_index, tx),
+						) => {
+							// The claim queue is as long as the scheduling lookahead.
+							let lookahead = self
+								.state
+								.claim_queue
+								.values()
+								.map(|claims| claims.len() as u32)
+								.max()
+								.unwrap_or(1);
+							tx.send(Ok(lookahead)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							block_hash,
+							RuntimeApiRequest::BackingConstraints(para_id, tx),
+						) => {
+							let constraints = self
+								.state
+								.backing_constraints
+								.get(&block_hash)
+								.and_then(|constraints| constraints.get(&para_id))
+								.cloned();
+							tx.send(Ok(constraints)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::CandidatesPendingAvailability(_para_id, tx),
+						) => {
+							// Candidates are never pending availability in the benchmarks.
+							tx.send(Ok(vec![])).unwrap();
+						},
 						RuntimeApiMessage::Request(
 							_parent,
 							RuntimeApiRequest::FetchOnChainVotes(tx),
@@ -0,0 +1,217 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Subsystem benchmark for the prospective parachains subsystem under elastic scaling.
+//!
+//! Scenario:
+//!   - Every para is assigned `cores_per_para` cores in the claim queue.
+//!   - At every block each para produces a chain of `cores_per_para` candidates built on top of the
+//!     new leaf.
+//!   - All candidates are introduced as seconded and then marked as backed.
+//!   - We request the backable chain of every para and measure how long it takes to get it.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		chain_api::{ChainApiState, MockChainApi},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::new_network,
+	usage::BenchmarkUsage,
+};
+use colored::Colorize;
+use futures::channel::oneshot;
+use polkadot_node_core_prospective_parachains::ProspectiveParachainsSubsystem;
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_subsystem::messages::{
+	AllMessages, IntroduceSecondedCandidateRequest, ProspectiveParachainsMessage,
+};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::prospective-parachains";
+
+/// Parameters specific to the prospective parachains benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct ProspectiveParachainsOptions {
+	#[clap(short, long, default_value_t = 3)]
+	/// The number of cores assigned to each para.
+	pub cores_per_para: usize,
+}
+
+fn build_overseer(
+	state: &TestState,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		Default::default(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	)
+	.with_backing_constraints(state.backing_constraints.clone());
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let prospective_parachains =
+		ProspectiveParachainsSubsystem::new(Metrics::try_register(&dependencies.registry).unwrap());
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_prospective_parachains(|_| prospective_parachains);
+
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	// The subsystem does not talk to the network, the emulator is only required by the
+	// environment.
+	let (network, _network_interface, _network_receiver) =
+		new_network(&state.config, &dependencies, &state.test_authorities, vec![]);
+	let (overseer, overseer_handle) = build_overseer(state, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+pub async fn benchmark_prospective_parachains(
+	env: &mut TestEnvironment,
+	state: &TestState,
+	options: &ProspectiveParachainsOptions,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let test_start = Instant::now();
+	let mut introduce_latencies = vec![];
+	let mut backable_latencies = vec![];
+	let mut n_backable = 0;
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+
+		let candidates = state.candidates.get(&block_info.hash).expect("pregenerated");
+		for (para, chain) in candidates.iter() {
+			for (candidate, pvd) in chain.iter() {
+				let candidate_hash = candidate.hash();
+				let (tx, rx) = oneshot::channel();
+				let request = IntroduceSecondedCandidateRequest {
+					candidate_para: *para,
+					candidate_receipt: candidate.clone(),
+					persisted_validation_data: pvd.clone(),
+				};
+				let introduced_at = Instant::now();
+				env.send_message(AllMessages::ProspectiveParachains(
+					ProspectiveParachainsMessage::IntroduceSecondedCandidate(request, tx),
+				))
+				.await;
+				if !rx.await.expect("prospective parachains never drop requests") {
+					gum::warn!(target: LOG_TARGET, ?candidate_hash, "Candidate not introduced");
+					continue
+				}
+				introduce_latencies.push(introduced_at.elapsed());
+
+				env.send_message(AllMessages::ProspectiveParachains(
+					ProspectiveParachainsMessage::CandidateBacked(*para, candidate_hash),
+				))
+				.await;
+			}
+		}
+
+		for para in state.paras.iter() {
+			let (tx, rx) = oneshot::channel();
+			let requested_at = Instant::now();
+			env.send_message(AllMessages::ProspectiveParachains(
+				ProspectiveParachainsMessage::GetBackableCandidates(
+					block_info.hash,
+					*para,
+					options.cores_per_para as u32,
+					Default::default(),
+					tx,
+				),
+			))
+			.await;
+			let backable = rx.await.expect("prospective parachains never drop requests");
+			backable_latencies.push(requested_at.elapsed());
+
+			if backable.len() != options.cores_per_para {
+				gum::warn!(target: LOG_TARGET,
+					"Only {}/{} candidates of para {} backable at block {}",
+					backable.len(), options.cores_per_para, para, block_num
+				);
+			}
+			n_backable += backable.len();
+		}
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{duration:?}ms").cyan());
+	gum::info!(target: LOG_TARGET,
+		"Backable candidates: {}",
+		format!("{}/{}", n_backable, state.paras.len() * options.cores_per_para * config.num_blocks)
+			.cyan()
+	);
+	for (name, latencies) in [
+		("Candidate introduction", introduce_latencies),
+		("Backable candidates request", backable_latencies),
+	] {
+		if latencies.is_empty() {
+			continue
+		}
+		let total = latencies.iter().sum::<Duration>();
+		let max = latencies.iter().max().expect("not empty");
+		gum::info!(target: LOG_TARGET,
+			"{} latency: avg {}, max {}",
+			name,
+			format!("{} µs", total.as_micros() / latencies.len() as u128).red(),
+			format!("{} µs", max.as_micros()).red()
+		);
+	}
+
+	env.stop().await;
+	env.collect_resource_usage(&["prospective-parachains"], false)
+}
@@ -0,0 +1,170 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	environment::GENESIS_HASH,
+	prospective_parachains::ProspectiveParachainsOptions,
+};
+use codec::Encode;
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	async_backing::{Constraints, InboundHrmpLimitations},
+	BlockNumber, CandidateReceiptV2 as CandidateReceipt,
+	CommittedCandidateReceiptV2 as CommittedCandidateReceipt, Hash, HeadData, Header, Id,
+	PersistedValidationData, ValidationCodeHash,
+};
+use polkadot_primitives_test_helpers::{dummy_hash, make_candidate};
+use std::collections::HashMap;
+
+/// Matches the PoV size limit of the generated persisted validation data.
+const MAX_POV_SIZE: u32 = 1_000_000;
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<Hash, Header>,
+	// Paras, each one is assigned `cores_per_para` consecutive cores
+	pub paras: Vec<Id>,
+	// One candidate receipt per core and block, used to build the claim queue
+	pub candidate_receipts: HashMap<Hash, Vec<CandidateReceipt>>,
+	// Chains of candidates of every para at each block, in the order they build on each other
+	pub candidates:
+		HashMap<Hash, HashMap<Id, Vec<(CommittedCandidateReceipt, PersistedValidationData)>>>,
+	// Backing constraints of every para at each block
+	pub backing_constraints: HashMap<Hash, HashMap<Id, Constraints>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &ProspectiveParachainsOptions) -> Self {
+		let config = config.clone();
+		let test_authorities = config.generate_authorities();
+		let block_infos = generate_block_infos(config.num_blocks);
+		let block_headers = block_infos.iter().map(generate_block_header).collect();
+		let n_paras = config.n_cores / options.cores_per_para;
+		let paras = (1..=n_paras as u32).map(Id::new).collect::<Vec<_>>();
+		let validation_code_hash = ValidationCodeHash::default();
+
+		let mut heads: HashMap<Id, HeadData> =
+			paras.iter().map(|para| (*para, HeadData(para.encode()))).collect();
+		let mut candidate_receipts: HashMap<Hash, Vec<CandidateReceipt>> = HashMap::new();
+		let mut candidates: HashMap<_, HashMap<_, Vec<_>>> = HashMap::new();
+		let mut backing_constraints: HashMap<_, HashMap<_, _>> = HashMap::new();
+
+		for block_info in block_infos.iter() {
+			let number = block_info.number;
+			for para in paras.iter() {
+				let head = heads.get_mut(para).expect("All paras have a head");
+				backing_constraints
+					.entry(block_info.hash)
+					.or_default()
+					.insert(*para, make_constraints(number, head.clone(), validation_code_hash));
+
+				for core in 0..options.cores_per_para {
+					let new_head = HeadData((para, number, core as u32).encode());
+					let (candidate, pvd) = make_candidate(
+						block_info.hash,
+						number,
+						*para,
+						head.clone(),
+						new_head.clone(),
+						validation_code_hash,
+					);
+					*head = new_head;
+
+					candidate_receipts
+						.entry(block_info.hash)
+						.or_default()
+						.push(candidate.to_plain());
+					candidates
+						.entry(block_info.hash)
+						.or_default()
+						.entry(*para)
+						.or_default()
+						.push((candidate, pvd));
+				}
+			}
+		}
+
+		Self {
+			config,
+			test_authorities,
+			block_infos,
+			block_headers,
+			paras,
+			candidate_receipts,
+			candidates,
+			backing_constraints,
+		}
+	}
+}
+
+fn make_constraints(
+	min_relay_parent_number: BlockNumber,
+	required_parent: HeadData,
+	validation_code_hash: ValidationCodeHash,
+) -> Constraints {
+	Constraints {
+		min_relay_parent_number,
+		max_pov_size: MAX_POV_SIZE,
+		max_code_size: 1_000_000,
+		max_head_data_size: Constraints::<BlockNumber>::DEFAULT_MAX_HEAD_DATA_SIZE,
+		ump_remaining: 10,
+		ump_remaining_bytes: 1_000,
+		max_ump_num_per_candidate: 10,
+		dmp_remaining_messages: vec![],
+		hrmp_inbound: InboundHrmpLimitations { valid_watermarks: vec![min_relay_parent_number] },
+		hrmp_channels_out: vec![],
+		max_hrmp_num_per_candidate: 0,
+		required_parent,
+		validation_code_hash,
+		upgrade_restriction: None,
+		future_validation_code: None,
+	}
+}
+
+fn generate_block_infos(num_blocks: usize) -> Vec<BlockInfo> {
+	let mut parent_hash = GENESIS_HASH;
+	(1..=num_blocks)
+		.map(|block_num| {
+			let mut block_info =
+				new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber);
+			block_info.parent_hash = parent_hash;
+			parent_hash = block_info.hash;
+			block_info
+		})
+		.collect()
+}
+
+fn generate_block_header(info: &BlockInfo) -> (Hash, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: dummy_hash(),
+		},
+	)
+}
@@ -102,6 +102,8 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		keep_finalized_for: None,
 		invulnerable_ah_collators: HashSet::new(),
 		collator_protocol_hold_off: None,
+		pvf_dump: None,
+		enable_dispute_rpc: false,
 	};
 
 	match config.network.network_backend {
@@ -210,6 +212,7 @@ pub fn node_config(
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
 			request_logger_limit: 1024,
+			rate_limit_overrides: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
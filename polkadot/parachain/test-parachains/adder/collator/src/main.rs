@@ -101,6 +101,8 @@ fn main() -> Result<()> {
 						keep_finalized_for: None,
 						invulnerable_ah_collators: HashSet::new(),
 						collator_protocol_hold_off: None,
+						pvf_dump: None,
+						enable_dispute_rpc: false,
 					},
 				)
 				.map_err(|e| e.to_string())?;
@@ -0,0 +1,292 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Node-local RPC for inspecting the disputes known to the dispute coordinator.
+//!
+//! All methods are unsafe, as they expose details about the local validator.
+
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use polkadot_node_primitives::{
+	disputes::{DisputeImportTiming, DisputeInspection, ParticipationOutcome},
+	DisputeStatus, Timestamp,
+};
+use polkadot_node_subsystem_types::messages::DisputeCoordinatorMessage;
+use polkadot_overseer::Handle;
+use polkadot_primitives::{Hash, SessionIndex, ValidDisputeStatementKind};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use serde::{Deserialize, Serialize};
+
+const DISPUTES_ERROR: i32 = 9500;
+
+/// Provides RPC methods for inspecting disputes.
+#[rpc(client, server)]
+pub trait DisputesApi {
+	/// Returns all disputes which are not yet concluded or concluded only recently.
+	#[method(name = "disputes_active", with_extensions)]
+	async fn active(&self) -> Result<Vec<DisputeInfo>, Error>;
+
+	/// Returns all disputes of the recent sessions, including the ones concluded long ago.
+	#[method(name = "disputes_recent", with_extensions)]
+	async fn recent(&self) -> Result<Vec<DisputeInfo>, Error>;
+}
+
+/// Provides RPC methods for inspecting disputes, served by the dispute coordinator.
+pub struct Disputes {
+	/// Handle to the overseer the dispute coordinator is running under.
+	overseer_handle: Handle,
+}
+
+impl Disputes {
+	/// Creates a new instance of the disputes RPC handler.
+	pub fn new(overseer_handle: Handle) -> Self {
+		Self { overseer_handle }
+	}
+
+	async fn inspect(&self, ext: &Extensions) -> Result<Vec<DisputeInfo>, Error> {
+		check_if_safe(ext)?;
+
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle
+			.clone()
+			.send_msg(DisputeCoordinatorMessage::InspectDisputes(tx), "DisputesRpc")
+			.await;
+
+		let disputes = rx.await.map_err(|_| Error::DisputeCoordinatorUnavailable)?;
+		Ok(disputes.into_iter().map(DisputeInfo::from).collect())
+	}
+}
+
+#[async_trait]
+impl DisputesApiServer for Disputes {
+	async fn active(&self, ext: &Extensions) -> Result<Vec<DisputeInfo>, Error> {
+		let mut disputes = self.inspect(ext).await?;
+		disputes.retain(|dispute| dispute.active);
+		Ok(disputes)
+	}
+
+	async fn recent(&self, ext: &Extensions) -> Result<Vec<DisputeInfo>, Error> {
+		self.inspect(ext).await
+	}
+}
+
+/// A dispute as seen by the local dispute coordinator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeInfo {
+	/// The session the disputed candidate appeared in.
+	pub session: SessionIndex,
+	/// Hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// Current state of the dispute.
+	pub state: DisputeState,
+	/// When the dispute concluded, in seconds since the unix epoch.
+	pub concluded_at: Option<Timestamp>,
+	/// Whether the dispute is still considered active.
+	pub active: bool,
+	/// Outcome of our own participation, if we participated since the node started.
+	pub participation: Option<Participation>,
+	/// Votes received for the candidate, if still available.
+	pub votes: Option<Votes>,
+	/// Spam slots used by validators voting invalid, while the dispute is unconfirmed.
+	pub spam_slots: Vec<SpamSlotUsage>,
+	/// Timing of the vote imports, if any happened since the node started.
+	pub import_timing: Option<ImportTiming>,
+}
+
+impl From<DisputeInspection> for DisputeInfo {
+	fn from(dispute: DisputeInspection) -> Self {
+		let state = match dispute.status {
+			DisputeStatus::Active => DisputeState::Active,
+			DisputeStatus::Confirmed => DisputeState::Confirmed,
+			DisputeStatus::ConcludedFor(_) => DisputeState::ConcludedFor,
+			DisputeStatus::ConcludedAgainst(_) => DisputeState::ConcludedAgainst,
+		};
+		let votes = dispute.votes.map(|votes| Votes {
+			valid: votes
+				.valid
+				.raw()
+				.iter()
+				.map(|(index, (kind, _))| Vote {
+					validator_index: index.0,
+					kind: match kind {
+						ValidDisputeStatementKind::Explicit => VoteKind::Explicit,
+						ValidDisputeStatementKind::BackingSeconded(_) => VoteKind::BackingSeconded,
+						ValidDisputeStatementKind::BackingValid(_) => VoteKind::BackingValid,
+						ValidDisputeStatementKind::ApprovalChecking |
+						ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(_) =>
+							VoteKind::ApprovalChecking,
+					},
+				})
+				.collect(),
+			invalid: votes
+				.invalid
+				.keys()
+				.map(|index| Vote { validator_index: index.0, kind: VoteKind::Explicit })
+				.collect(),
+		});
+
+		DisputeInfo {
+			session: dispute.session,
+			candidate_hash: dispute.candidate_hash.0,
+			state,
+			concluded_at: dispute.status.concluded_at(),
+			active: dispute.active,
+			participation: dispute.participation.map(Into::into),
+			votes,
+			spam_slots: dispute
+				.spam_slots
+				.into_iter()
+				.map(|(index, used)| SpamSlotUsage { validator_index: index.0, used })
+				.collect(),
+			import_timing: dispute.import_timing.map(Into::into),
+		}
+	}
+}
+
+/// State of a dispute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DisputeState {
+	/// The dispute is active and unconcluded.
+	Active,
+	/// The dispute has been concluded in favor of the candidate.
+	ConcludedFor,
+	/// The dispute has been concluded against the candidate.
+	ConcludedAgainst,
+	/// The dispute has been confirmed, but has not concluded yet.
+	Confirmed,
+}
+
+/// Outcome of our own participation in a dispute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Participation {
+	/// We found the candidate to be valid.
+	Valid,
+	/// We found the candidate to be invalid.
+	Invalid,
+	/// We could not recover the candidate.
+	Unavailable,
+	/// Participation failed, details can be found in the logs.
+	Error,
+}
+
+impl From<ParticipationOutcome> for Participation {
+	fn from(outcome: ParticipationOutcome) -> Self {
+		match outcome {
+			ParticipationOutcome::Valid => Participation::Valid,
+			ParticipationOutcome::Invalid => Participation::Invalid,
+			ParticipationOutcome::Unavailable => Participation::Unavailable,
+			ParticipationOutcome::Error => Participation::Error,
+		}
+	}
+}
+
+/// Votes received for a disputed candidate.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Votes {
+	/// Votes for the validity of the candidate.
+	pub valid: Vec<Vote>,
+	/// Votes against the validity of the candidate.
+	pub invalid: Vec<Vote>,
+}
+
+/// A single vote on a disputed candidate.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vote {
+	/// Index of the voting validator in the session.
+	pub validator_index: u32,
+	/// How the vote was cast.
+	pub kind: VoteKind,
+}
+
+/// How a vote on a disputed candidate was cast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VoteKind {
+	/// An explicit dispute statement.
+	Explicit,
+	/// A seconded statement from the backing phase.
+	BackingSeconded,
+	/// A valid statement from the backing phase.
+	BackingValid,
+	/// An approval vote.
+	ApprovalChecking,
+}
+
+/// Spam slots used by a validator voting invalid on an unconfirmed dispute.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpamSlotUsage {
+	/// Index of the validator in the session.
+	pub validator_index: u32,
+	/// Number of spam slots the validator uses in the session.
+	pub used: u32,
+}
+
+/// Timing of the vote imports for a dispute.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTiming {
+	/// When votes were first imported, in seconds since the unix epoch.
+	pub first_import: Timestamp,
+	/// When votes were last imported, in seconds since the unix epoch.
+	pub last_import: Timestamp,
+	/// Number of processed vote imports.
+	pub imports: u32,
+	/// How long processing the last import took, in microseconds.
+	pub last_import_duration_us: u64,
+}
+
+impl From<DisputeImportTiming> for ImportTiming {
+	fn from(timing: DisputeImportTiming) -> Self {
+		ImportTiming {
+			first_import: timing.first_import,
+			last_import: timing.last_import,
+			imports: timing.imports,
+			last_import_duration_us: timing.last_import_duration.as_micros() as u64,
+		}
+	}
+}
+
+/// Top-level error type for the disputes RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The dispute coordinator did not answer the request.
+	#[error("The dispute coordinator is not available")]
+	DisputeCoordinatorUnavailable,
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::DisputeCoordinatorUnavailable =>
+				ErrorObject::owned(DISPUTES_ERROR + 1, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
@@ -20,6 +20,8 @@
 
 use std::sync::Arc;
 
+pub mod disputes;
+
 use jsonrpsee::RpcModule;
 use polkadot_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
 use sc_client_api::AuxStore;
@@ -73,6 +75,12 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
 	pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
 
+/// Dependencies for the disputes RPC
+pub struct DisputesDeps {
+	/// Handle to the overseer the dispute coordinator is running under.
+	pub overseer_handle: polkadot_overseer::Handle,
+}
+
 /// Full client dependencies
 pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	/// The client instance to use.
@@ -91,17 +99,13 @@ pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	pub beefy: BeefyDeps<AuthorityId>,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
+	/// Disputes specific dependencies, `None` if the disputes RPC is disabled.
+	pub disputes: Option<DisputesDeps>,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, SC, B, AuthorityId>(
-	FullDeps { client, pool, select_chain, chain_spec, babe, grandpa, beefy, backend }: FullDeps<
-		C,
-		P,
-		SC,
-		B,
-		AuthorityId,
-	>,
+	deps: FullDeps<C, P, SC, B, AuthorityId>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>
@@ -123,6 +127,7 @@ where
 	AuthorityId: AuthorityIdBound,
 	<AuthorityId as RuntimeAppPublic>::Signature: Send + Sync,
 {
+	use disputes::{Disputes, DisputesApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
@@ -132,6 +137,17 @@ where
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
+	let FullDeps {
+		client,
+		pool,
+		select_chain,
+		chain_spec,
+		babe,
+		grandpa,
+		beefy,
+		backend,
+		disputes,
+	} = deps;
 	let mut io = RpcModule::new(());
 	let BabeDeps { babe_worker_handle, keystore } = babe;
 	let GrandpaDeps {
@@ -180,5 +196,9 @@ where
 		.into_rpc(),
 	)?;
 
+	if let Some(DisputesDeps { overseer_handle }) = disputes {
+		io.merge(Disputes::new(overseer_handle).into_rpc())?;
+	}
+
 	Ok(io)
 }
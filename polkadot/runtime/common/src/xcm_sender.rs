@@ -29,6 +29,7 @@ use polkadot_runtime_parachains::{
 use sp_runtime::FixedPointNumber;
 use xcm::{prelude::*, MAX_XCM_DECODE_DEPTH};
 use xcm_builder::InspectMessageQueues;
+use xcm_executor::traits::{message_topic, MessageStatus, RecordMessageStatus};
 use SendError::*;
 
 /// Simple value-bearing trait for determining/expressing the assets required to be paid for a
@@ -95,19 +96,22 @@ impl<A: Get<AssetId>, B: Get<u128>, M: Get<u128>, F: FeeTracker> PriceForMessage
 }
 
 /// XCM sender for relay chain. It only sends downward message.
-pub struct ChildParachainRouter<T, W, P>(PhantomData<(T, W, P)>);
+///
+/// The messages with a topic put onto the DMP queue are recorded as queued by `R`.
+pub struct ChildParachainRouter<T, W, P, R = ()>(PhantomData<(T, W, P, R)>);
 
-impl<T: configuration::Config + dmp::Config, W: xcm::WrapVersion, P> SendXcm
-	for ChildParachainRouter<T, W, P>
+impl<T: configuration::Config + dmp::Config, W: xcm::WrapVersion, P, R> SendXcm
+	for ChildParachainRouter<T, W, P, R>
 where
 	P: PriceForMessageDelivery<Id = ParaId>,
+	R: RecordMessageStatus,
 {
-	type Ticket = (HostConfiguration<BlockNumberFor<T>>, ParaId, Vec<u8>);
+	type Ticket = (HostConfiguration<BlockNumberFor<T>>, ParaId, Vec<u8>, Option<XcmHash>);
 
 	fn validate(
 		dest: &mut Option<Location>,
 		msg: &mut Option<Xcm<()>>,
-	) -> SendResult<(HostConfiguration<BlockNumberFor<T>>, ParaId, Vec<u8>)> {
+	) -> SendResult<(HostConfiguration<BlockNumberFor<T>>, ParaId, Vec<u8>, Option<XcmHash>)> {
 		let d = dest.take().ok_or(MissingArgument)?;
 		let id = if let (0, [Parachain(id)]) = d.unpack() {
 			*id
@@ -121,21 +125,35 @@ where
 		let config = configuration::ActiveConfig::<T>::get();
 		let para = id.into();
 		let price = P::price_for_delivery(para, &xcm);
+		let topic = message_topic(&xcm);
 		let versioned_xcm = W::wrap_version(&d, xcm).map_err(|()| DestinationUnsupported)?;
 		versioned_xcm.check_is_decodable().map_err(|()| ExceedsMaxMessageSize)?;
 		let blob = versioned_xcm.encode();
 		dmp::Pallet::<T>::can_queue_downward_message(&config, &para, &blob)
 			.map_err(Into::<SendError>::into)?;
 
-		Ok(((config, para, blob), price))
+		Ok(((config, para, blob, topic), price))
 	}
 
 	fn deliver(
-		(config, para, blob): (HostConfiguration<BlockNumberFor<T>>, ParaId, Vec<u8>),
+		(config, para, blob, topic): (
+			HostConfiguration<BlockNumberFor<T>>,
+			ParaId,
+			Vec<u8>,
+			Option<XcmHash>,
+		),
 	) -> Result<XcmHash, SendError> {
 		let hash = sp_io::hashing::blake2_256(&blob[..]);
 		dmp::Pallet::<T>::queue_downward_message(&config, para, blob)
-			.map(|()| hash)
+			.map(|()| {
+				if let Some(topic) = topic {
+					R::record_message_status(
+						topic,
+						MessageStatus::Queued { destination: Parachain(para.into()).into() },
+					);
+				}
+				hash
+			})
 			.map_err(|error| {
 				log::debug!(
 					target: "xcm::xcm_sender::deliver",
@@ -153,7 +171,7 @@ where
 	}
 }
 
-impl<T: dmp::Config, W, P> InspectMessageQueues for ChildParachainRouter<T, W, P> {
+impl<T: dmp::Config, W, P, R> InspectMessageQueues for ChildParachainRouter<T, W, P, R> {
 	fn clear_messages() {
 		// Best effort.
 		let _ = dmp::DownwardMessageQueues::<T>::clear(u32::MAX, None);
@@ -283,7 +301,7 @@ mod tests {
 	use super::*;
 	use crate::integration_tests::new_test_ext;
 	use alloc::vec;
-	use frame_support::{assert_ok, parameter_types};
+	use frame_support::{assert_ok, parameter_types, weights::Weight};
 	use polkadot_runtime_parachains::FeeTracker;
 	use sp_runtime::FixedU128;
 	use xcm::MAX_XCM_DECODE_DEPTH;
@@ -383,4 +401,50 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn child_parachain_router_records_queued_messages() {
+		std::thread_local! {
+			static RECORDED: core::cell::RefCell<Vec<(XcmHash, MessageStatus)>> =
+				core::cell::RefCell::new(Vec::new());
+		}
+		struct MockedRecorder;
+		impl RecordMessageStatus for MockedRecorder {
+			fn record_message_status(topic: XcmHash, status: MessageStatus) -> Weight {
+				RECORDED.with(|r| r.borrow_mut().push((topic, status)));
+				Weight::zero()
+			}
+
+			fn record_message_status_weight() -> Weight {
+				Weight::zero()
+			}
+		}
+		type Router = ChildParachainRouter<
+			crate::integration_tests::Test,
+			(),
+			NoPriceForMessageDelivery<ParaId>,
+			MockedRecorder,
+		>;
+
+		new_test_ext().execute_with(|| {
+			configuration::ActiveConfig::<crate::integration_tests::Test>::mutate(|c| {
+				c.max_downward_message_size = 1024;
+			});
+			dmp::Pallet::<crate::integration_tests::Test>::make_parachain_reachable(5555);
+
+			// A message without a topic is not recorded.
+			assert_ok!(send_xcm::<Router>(Parachain(5555).into(), Xcm(vec![ClearOrigin])));
+			assert!(RECORDED.with(|r| r.borrow().is_empty()));
+
+			let topic = [1; 32];
+			assert_ok!(send_xcm::<Router>(
+				Parachain(5555).into(),
+				Xcm(vec![ClearOrigin, SetTopic(topic)])
+			));
+			assert_eq!(
+				RECORDED.with(|r| r.take()),
+				vec![(topic, MessageStatus::Queued { destination: Parachain(5555).into() })]
+			);
+		});
+	}
 }
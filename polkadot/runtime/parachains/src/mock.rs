@@ -386,6 +386,9 @@ impl pallet_message_queue::WeightInfo for TestMessageQueueWeight {
 	fn execute_overweight_page_updated() -> Weight {
 		Weight::zero()
 	}
+	fn schedule_visit() -> Weight {
+		Weight::zero()
+	}
 }
 parameter_types! {
 	pub const MessageQueueServiceWeight: Weight = Weight::from_all(500);
@@ -400,6 +403,7 @@ impl pallet_message_queue::Config for Test {
 	type MessageProcessor = TestProcessMessage;
 	type QueueChangeHandler = ParaInclusion;
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 	type HeapSize = ConstU32<65536>;
 	type MaxStale = ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
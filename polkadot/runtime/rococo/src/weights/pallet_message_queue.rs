@@ -210,4 +210,11 @@ impl<T: frame_system::Config> pallet_message_queue::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Not benchmarked yet, bounded by `bump_service_head` and the accesses to the scheduling
+	/// state of the visited queue and of its priority class.
+	fn schedule_visit() -> Weight {
+		<Self as pallet_message_queue::WeightInfo>::bump_service_head()
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
 }
@@ -83,7 +83,12 @@ pub type PriceForChildParachainDelivery = TestDeliveryPrice<FeeAssetId, super::D
 /// individual routers.
 pub type XcmRouter = WithUniqueTopic<
 	// Only one router so far - use DMP to communicate with child parachains.
-	ChildParachainRouter<super::Runtime, super::Xcm, PriceForChildParachainDelivery>,
+	ChildParachainRouter<
+		super::Runtime,
+		super::Xcm,
+		PriceForChildParachainDelivery,
+		super::Xcm,
+	>,
 >;
 
 pub type Barrier = AllowUnpaidExecutionFrom<Everything>;
@@ -184,6 +189,8 @@ impl pallet_xcm::Config for crate::Runtime {
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = ();
+	type TrappedAssetsBeneficiary = ();
 	type WeightInfo = pallet_xcm::TestWeightInfo;
 	type AdminOrigin = EnsureRoot<crate::AccountId>;
 	// Aliasing is disabled: xcm_executor::Config::Aliasers is set to `Nothing`.
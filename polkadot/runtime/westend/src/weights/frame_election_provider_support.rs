@@ -81,4 +81,36 @@ impl<T: frame_system::Config> frame_election_provider_support::WeightInfo for We
 			// Standard Error: 14_450_583
 			.saturating_add(Weight::from_parts(1_581_504_003, 0).saturating_mul(d.into()))
 	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_012_584_000 picoseconds.
+		Weight::from_parts(6_087_441_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 154_921
+			.saturating_add(Weight::from_parts(6_231_402, 0).saturating_mul(v.into()))
+			// Standard Error: 309_842
+			.saturating_add(Weight::from_parts(2_417_906, 0).saturating_mul(t.into()))
+			// Standard Error: 15_839_217
+			.saturating_add(Weight::from_parts(1_704_310_275, 0).saturating_mul(d.into()))
+	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn approval_voting(v: u32, _t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_184_207_000 picoseconds.
+		Weight::from_parts(1_201_563_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 31_416
+			.saturating_add(Weight::from_parts(1_268_115, 0).saturating_mul(v.into()))
+			// Standard Error: 3_211_887
+			.saturating_add(Weight::from_parts(187_402_619, 0).saturating_mul(d.into()))
+	}
 }
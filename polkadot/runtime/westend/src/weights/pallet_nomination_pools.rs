@@ -892,4 +892,11 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Not benchmarked yet, bounded by the reward payout of both accounts and the transfer of
+	/// the delegation between them.
+	fn transfer_points() -> Weight {
+		<Self as pallet_nomination_pools::WeightInfo>::claim_payout()
+			.saturating_mul(2)
+			.saturating_add(<Self as pallet_nomination_pools::WeightInfo>::migrate_delegation())
+	}
 }
@@ -17,9 +17,9 @@
 //! XCM configurations for Westend.
 
 use super::{
-	parachains_origin, AccountId, AllPalletsWithSystem, Balances, Dmp, FellowshipAdmin,
-	GeneralAdmin, ParaId, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, StakingAdmin,
-	TransactionByteFee, Treasury, WeightToFee, XcmPallet,
+	parachains_origin, AccountId, AllPalletsWithSystem, Balances, BlockNumber, Dmp,
+	FellowshipAdmin, GeneralAdmin, ParaId, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	StakingAdmin, TransactionByteFee, Treasury, WeightToFee, XcmPallet,
 };
 use crate::governance::pallet_custom_origins::Treasurer;
 use frame_support::{
@@ -34,7 +34,7 @@ use polkadot_runtime_common::{
 };
 use sp_core::ConstU32;
 use westend_runtime_constants::{
-	currency::CENTS, system_parachain::*, xcm::body::FELLOWSHIP_ADMIN_INDEX,
+	currency::CENTS, system_parachain::*, time::DAYS, xcm::body::FELLOWSHIP_ADMIN_INDEX,
 };
 use xcm::latest::{prelude::*, WESTEND_GENESIS_HASH};
 use xcm_builder::{
@@ -64,6 +64,8 @@ parameter_types! {
 	pub const BaseDeliveryFee: u128 = CENTS.saturating_mul(3);
 	// Fellows pluralistic body.
 	pub const FellowsBodyId: BodyId = BodyId::Technical;
+	/// Assets left in an asset trap for this long are deposited to the account of their origin.
+	pub const TrappedAssetsExpiry: Option<BlockNumber> = Some(30 * DAYS);
 }
 
 pub type LocationConverter = (
@@ -110,7 +112,7 @@ pub type PriceForChildParachainDelivery =
 /// individual routers.
 pub type XcmRouter = WithUniqueTopic<
 	// Only one router so far - use DMP to communicate with child parachains.
-	ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery>,
+	ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery, XcmPallet>,
 >;
 
 parameter_types! {
@@ -319,6 +321,8 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = ConstU32<8>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = TrappedAssetsExpiry;
+	type TrappedAssetsBeneficiary = pallet_xcm::TrappedAssetsToOrigin;
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	// Aliasing is disabled: xcm_executor::Config::Aliasers only allows `AliasChildLocation`.
@@ -53,4 +53,5 @@ impl mock_message_queue::Config for Runtime {
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
+	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 }
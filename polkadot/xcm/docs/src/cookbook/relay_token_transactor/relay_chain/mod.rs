@@ -48,6 +48,7 @@ impl frame_system::Config for Runtime {
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
+	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -90,6 +91,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MessageProcessor = MessageProcessor;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 	type WeightInfo = ();
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
 }
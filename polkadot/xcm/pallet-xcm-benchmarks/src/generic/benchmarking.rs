@@ -16,7 +16,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{account_and_location, new_executor, EnsureDelivery, XcmCallOf};
+use crate::{account_and_location, new_executor, EnsureDelivery, ExecutorOf, XcmCallOf};
 use alloc::{vec, vec::Vec};
 use codec::Encode;
 use frame_benchmarking::v2::*;
@@ -30,6 +30,45 @@ use xcm_executor::{
 	ExecutorError, FeesMode,
 };
 
+/// Locks `asset` of `owner` on behalf of `unlocker` with the configured `AssetLocker`.
+fn lock<T: Config>(
+	unlocker: Location,
+	asset: Asset,
+	owner: Location,
+) -> Result<(), BenchmarkError> {
+	use xcm_executor::traits::{AssetLock, Enact};
+
+	<T::XcmConfig as xcm_executor::Config>::AssetLocker::prepare_lock(unlocker, asset, owner)
+		.map_err(|_| BenchmarkError::Skip)?
+		.enact()
+		.map_err(|_| BenchmarkError::Skip)
+}
+
+/// Returns an executor for `owner` holding `asset` and the fees to notify `unlocker` of a lock.
+fn executor_with_lockable_asset<T: Config>(
+	unlocker: &Location,
+	owner: Location,
+	asset: &Asset,
+) -> ExecutorOf<T> {
+	let (expected_fees_mode, expected_assets_in_holding) =
+		T::DeliveryHelper::ensure_successful_delivery(&owner, unlocker, FeeReason::LockAsset);
+
+	// generate holding and add possible required fees
+	let mut holding: Assets = asset.clone().into();
+	if let Some(expected_assets_in_holding) = expected_assets_in_holding {
+		for a in expected_assets_in_holding.into_inner() {
+			holding.push(a);
+		}
+	};
+
+	let mut executor = new_executor::<T>(owner);
+	executor.set_holding(holding.into());
+	if let Some(expected_fees_mode) = expected_fees_mode {
+		executor.set_fees_mode(expected_fees_mode);
+	}
+	executor
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -795,25 +834,10 @@ mod benchmarks {
 	fn lock_asset() -> Result<(), BenchmarkError> {
 		let (unlocker, owner, asset) = T::unlockable_asset()?;
 
-		let (expected_fees_mode, expected_assets_in_holding) =
-			T::DeliveryHelper::ensure_successful_delivery(&owner, &unlocker, FeeReason::LockAsset);
 		let sender_account = T::AccountIdConverter::convert_location(&owner).unwrap();
+		let mut executor = executor_with_lockable_asset::<T>(&unlocker, owner, &asset);
 		let sender_account_balance_before = T::TransactAsset::balance(&sender_account);
 
-		// generate holding and add possible required fees
-		let mut holding: Assets = asset.clone().into();
-		if let Some(expected_assets_in_holding) = expected_assets_in_holding {
-			for a in expected_assets_in_holding.into_inner() {
-				holding.push(a);
-			}
-		};
-
-		let mut executor = new_executor::<T>(owner);
-		executor.set_holding(holding.into());
-		if let Some(expected_fees_mode) = expected_fees_mode {
-			executor.set_fees_mode(expected_fees_mode);
-		}
-
 		let instruction = Instruction::LockAsset { asset, unlocker };
 		let xcm = Xcm(vec![instruction]);
 		#[block]
@@ -828,21 +852,12 @@ mod benchmarks {
 
 	#[benchmark]
 	fn unlock_asset() -> Result<(), BenchmarkError> {
-		use xcm_executor::traits::{AssetLock, Enact};
-
 		let (unlocker, owner, asset) = T::unlockable_asset()?;
 
 		let mut executor = new_executor::<T>(unlocker.clone());
 
 		// We first place the asset in lock first...
-		<T::XcmConfig as xcm_executor::Config>::AssetLocker::prepare_lock(
-			unlocker,
-			asset.clone(),
-			owner.clone(),
-		)
-		.map_err(|_| BenchmarkError::Skip)?
-		.enact()
-		.map_err(|_| BenchmarkError::Skip)?;
+		lock::<T>(unlocker, asset.clone(), owner.clone())?;
 
 		// ... then unlock them with the UnlockAsset instruction.
 		let instruction = Instruction::UnlockAsset { asset, target: owner };
@@ -855,22 +870,90 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn note_unlockable() -> Result<(), BenchmarkError> {
-		use xcm_executor::traits::{AssetLock, Enact};
+	fn lock_fungibles_asset() -> Result<(), BenchmarkError> {
+		let (unlocker, owner, asset) = T::unlockable_fungibles_asset()?;
+
+		let mut executor = executor_with_lockable_asset::<T>(&unlocker, owner, &asset);
+
+		let instruction = Instruction::LockAsset { asset, unlocker };
+		let xcm = Xcm(vec![instruction]);
+		#[block]
+		{
+			executor.bench_process(xcm)?;
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlock_fungibles_asset() -> Result<(), BenchmarkError> {
+		let (unlocker, owner, asset) = T::unlockable_fungibles_asset()?;
+
+		let mut executor = new_executor::<T>(unlocker.clone());
+		lock::<T>(unlocker, asset.clone(), owner.clone())?;
+
+		let instruction = Instruction::UnlockAsset { asset, target: owner };
+		let xcm = Xcm(vec![instruction]);
+		#[block]
+		{
+			executor.bench_process(xcm)?;
+		}
+		Ok(())
+	}
 
+	#[benchmark]
+	fn lock_non_fungible_asset() -> Result<(), BenchmarkError> {
+		let (unlocker, owner, asset) = T::unlockable_non_fungible_asset()?;
+
+		let mut executor = executor_with_lockable_asset::<T>(&unlocker, owner, &asset);
+
+		let instruction = Instruction::LockAsset { asset, unlocker };
+		let xcm = Xcm(vec![instruction]);
+		#[block]
+		{
+			executor.bench_process(xcm)?;
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlock_non_fungible_asset() -> Result<(), BenchmarkError> {
+		let (unlocker, owner, asset) = T::unlockable_non_fungible_asset()?;
+
+		let mut executor = new_executor::<T>(unlocker.clone());
+		lock::<T>(unlocker, asset.clone(), owner.clone())?;
+
+		let instruction = Instruction::UnlockAsset { asset, target: owner };
+		let xcm = Xcm(vec![instruction]);
+		#[block]
+		{
+			executor.bench_process(xcm)?;
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn note_unlockable_non_fungible() -> Result<(), BenchmarkError> {
+		let (unlocker, owner, asset) = T::unlockable_non_fungible_asset()?;
+
+		let mut executor = new_executor::<T>(unlocker);
+
+		let instruction = Instruction::NoteUnlockable { asset, owner };
+		let xcm = Xcm(vec![instruction]);
+		#[block]
+		{
+			executor.bench_process(xcm)?;
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn note_unlockable() -> Result<(), BenchmarkError> {
 		let (unlocker, owner, asset) = T::unlockable_asset()?;
 
 		let mut executor = new_executor::<T>(unlocker.clone());
 
 		// We first place the asset in lock first...
-		<T::XcmConfig as xcm_executor::Config>::AssetLocker::prepare_lock(
-			unlocker,
-			asset.clone(),
-			owner.clone(),
-		)
-		.map_err(|_| BenchmarkError::Skip)?
-		.enact()
-		.map_err(|_| BenchmarkError::Skip)?;
+		lock::<T>(unlocker, asset.clone(), owner.clone())?;
 
 		// ... then note them as unlockable with the NoteUnlockable instruction.
 		let instruction = Instruction::NoteUnlockable { asset, owner };
@@ -884,19 +967,10 @@ mod benchmarks {
 
 	#[benchmark]
 	fn request_unlock() -> Result<(), BenchmarkError> {
-		use xcm_executor::traits::{AssetLock, Enact};
-
 		let (locker, owner, asset) = T::unlockable_asset()?;
 
 		// We first place the asset in lock first...
-		<T::XcmConfig as xcm_executor::Config>::AssetLocker::prepare_lock(
-			locker.clone(),
-			asset.clone(),
-			owner.clone(),
-		)
-		.map_err(|_| BenchmarkError::Skip)?
-		.enact()
-		.map_err(|_| BenchmarkError::Skip)?;
+		lock::<T>(locker.clone(), asset.clone(), owner.clone())?;
 
 		let (expected_fees_mode, expected_assets_in_holding) =
 			T::DeliveryHelper::ensure_successful_delivery(
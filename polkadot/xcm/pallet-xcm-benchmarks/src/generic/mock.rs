@@ -182,6 +182,16 @@ impl generic::Config for Test {
 		Ok((Default::default(), account_id_junction::<Test>(1).into(), assets))
 	}
 
+	fn unlockable_fungibles_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+		let assets: Asset = (AssetId(GeneralIndex(1).into()), 100).into();
+		Ok((Default::default(), account_id_junction::<Test>(1).into(), assets))
+	}
+
+	fn unlockable_non_fungible_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+		let assets: Asset = (AssetId(GeneralIndex(2).into()), AssetInstance::Index(1)).into();
+		Ok((Default::default(), account_id_junction::<Test>(1).into(), assets))
+	}
+
 	fn export_message_origin_and_destination(
 	) -> Result<(Location, NetworkId, InteriorLocation), BenchmarkError> {
 		// No MessageExporter in tests
@@ -84,6 +84,21 @@ pub mod pallet {
 		/// Return an unlocker, owner and assets that can be locked and unlocked.
 		fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError>;
 
+		/// Return an unlocker, owner and `fungibles` asset other than the one returned by
+		/// `unlockable_asset` that can be locked and unlocked.
+		///
+		/// If set to `Err`, benchmarks which rely on locking `fungibles` assets will be skipped.
+		fn unlockable_fungibles_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+			Err(BenchmarkError::Skip)
+		}
+
+		/// Return an unlocker, owner and non-fungible asset that can be locked and unlocked.
+		///
+		/// If set to `Err`, benchmarks which rely on locking non-fungible assets will be skipped.
+		fn unlockable_non_fungible_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+			Err(BenchmarkError::Skip)
+		}
+
 		/// A `(Location, NetworkId, InteriorLocation)` we can successfully export message
 		/// to.
 		///
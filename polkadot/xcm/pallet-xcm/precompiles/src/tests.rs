@@ -83,9 +83,14 @@ fn test_xcm_send_precompile_works() {
 			ExecConfig::new_substrate_tx(),
 		);
 		assert!(result.result.is_ok());
+		let message_id = pallet_xcm::xcm_helpers::find_xcm_sent_message_id::<Test>(
+			System::events().into_iter().map(|record| record.event),
+		)
+		.expect("Missing XcmPallet::Sent event");
 		let sent_message = Xcm(Some(DescendOrigin(sender.clone().try_into().unwrap()))
 			.into_iter()
 			.chain(message.0.clone().into_iter())
+			.chain(Some(SetTopic(message_id)))
 			.collect());
 		assert_eq!(sent_xcm(), vec![(Here.into(), sent_message)]);
 	});
@@ -135,9 +140,14 @@ fn test_xcm_send_precompile_to_parachain() {
 		);
 
 		assert!(result.result.is_ok());
+		let message_id = pallet_xcm::xcm_helpers::find_xcm_sent_message_id::<Test>(
+			System::events().into_iter().map(|record| record.event),
+		)
+		.expect("Missing XcmPallet::Sent event");
 		let sent_message = Xcm(Some(DescendOrigin(sender.clone().try_into().unwrap()))
 			.into_iter()
 			.chain(message.0.clone().into_iter())
+			.chain(Some(SetTopic(message_id)))
 			.collect());
 		assert_eq!(sent_xcm(), vec![(Parachain(SOME_PARA_ID).into(), sent_message)]);
 	});
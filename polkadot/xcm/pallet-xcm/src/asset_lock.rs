@@ -0,0 +1,378 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM asset locking of `fungibles` and `nonfungibles` assets.
+//!
+//! [`Pallet`] only locks [`Config::Currency`]. [`AssetLockAdapter`] extends it to lock fungible
+//! assets by freezing them and non-fungible items by disabling their transfer. It is meant to be
+//! used as the `AssetLocker` of the XCM executor:
+//!
+//! ```ignore
+//! parameter_types! {
+//!     pub const XcmLockFreezeId: RuntimeFreezeReason =
+//!         RuntimeFreezeReason::PolkadotXcm(pallet_xcm::FreezeReason::XcmLock);
+//! }
+//!
+//! type AssetLocker = pallet_xcm::AssetLockAdapter<
+//!     Runtime,
+//!     AssetsFreezer,
+//!     TrustBackedAssetsConvertedConcreteId,
+//!     XcmLockFreezeId,
+//!     Nfts,
+//!     UniquesConvertedConcreteId,
+//! >;
+//! ```
+
+use crate::{Config, LockTicket, LockedAsset, LockedAssets, Pallet, ReduceTicket, UnlockTicket};
+use core::marker::PhantomData;
+use frame_support::{
+	ensure,
+	traits::{
+		fungibles::{self, MutateFreeze},
+		tokens::nonfungibles_v2,
+		Get,
+	},
+	BoundedVec,
+};
+use sp_runtime::{traits::Zero, SaturatedConversion};
+use xcm::{latest::prelude::*, VersionedAsset, VersionedLocation};
+use xcm_executor::traits::{
+	AssetLock, ConvertLocation, Enact, LockError, MatchesFungibles, MatchesNonFungibles,
+};
+
+type LocksOf<T> = BoundedVec<LockedAsset, <T as Config>::MaxLockers>;
+
+/// Locks assets on behalf of remote unlockers.
+///
+/// Assets matched by `CurrencyMatcher` of [`Pallet`] are locked by [`Pallet`]. Other fungible
+/// assets matched by `FungiblesMatcher` are frozen in `Fungibles` with the `FreezeId` freeze
+/// reason. Non-fungible items matched by `NonFungiblesMatcher` can't be transferred in
+/// `NonFungibles` while locked.
+///
+/// An account may hold up to `MaxLockers` locks of [`Config::Currency`] and `MaxLockers` locks of
+/// other assets. A non-fungible item may only be locked for a single unlocker. Remote locks are
+/// recorded by [`Pallet`].
+pub struct AssetLockAdapter<
+	T,
+	Fungibles,
+	FungiblesMatcher,
+	FreezeId,
+	NonFungibles,
+	NonFungiblesMatcher,
+>(PhantomData<(T, Fungibles, FungiblesMatcher, FreezeId, NonFungibles, NonFungiblesMatcher)>);
+
+/// Ticket to lock or unlock an asset with [`AssetLockAdapter`].
+pub enum AssetLockTicket<NativeTicket, T, Fungibles, FreezeId, NonFungibles>
+where
+	T: Config,
+	Fungibles: fungibles::Inspect<T::AccountId>,
+	NonFungibles: nonfungibles_v2::Inspect<T::AccountId>,
+{
+	/// Lock of [`Config::Currency`], handled by [`Pallet`].
+	Native(NativeTicket),
+	/// Freeze of a `fungibles` asset.
+	Fungibles(FungiblesLockTicket<T, Fungibles, FreezeId>),
+	/// Transfer lock of a `nonfungibles` item.
+	NonFungibles(NonFungiblesLockTicket<T, NonFungibles>),
+}
+
+impl<NativeTicket, T, Fungibles, FreezeId, NonFungibles> Enact
+	for AssetLockTicket<NativeTicket, T, Fungibles, FreezeId, NonFungibles>
+where
+	NativeTicket: Enact,
+	T: Config,
+	Fungibles: MutateFreeze<T::AccountId>,
+	FreezeId: Get<Fungibles::Id>,
+	NonFungibles: nonfungibles_v2::Transfer<T::AccountId>,
+{
+	fn enact(self) -> Result<(), LockError> {
+		match self {
+			Self::Native(ticket) => ticket.enact(),
+			Self::Fungibles(ticket) => ticket.enact(),
+			Self::NonFungibles(ticket) => ticket.enact(),
+		}
+	}
+}
+
+/// Updates the freeze of a `fungibles` asset to the largest amount locked for any unlocker.
+pub struct FungiblesLockTicket<T: Config, Fungibles: fungibles::Inspect<T::AccountId>, FreezeId> {
+	sovereign_account: T::AccountId,
+	asset_id: Fungibles::AssetId,
+	frozen: Fungibles::Balance,
+	locks: LocksOf<T>,
+	_phantom: PhantomData<FreezeId>,
+}
+
+impl<T, Fungibles, FreezeId> Enact for FungiblesLockTicket<T, Fungibles, FreezeId>
+where
+	T: Config,
+	Fungibles: MutateFreeze<T::AccountId>,
+	FreezeId: Get<Fungibles::Id>,
+{
+	fn enact(self) -> Result<(), LockError> {
+		let freeze_id = FreezeId::get();
+		if self.frozen.is_zero() {
+			Fungibles::thaw(self.asset_id, &freeze_id, &self.sovereign_account)
+		} else {
+			Fungibles::set_freeze(self.asset_id, &freeze_id, &self.sovereign_account, self.frozen)
+		}
+		.map_err(|error| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::enact", ?error,
+				"Failed to update the freeze of locked fungibles",
+			);
+			LockError::UnexpectedState
+		})?;
+		write_locks::<T>(&self.sovereign_account, self.locks);
+		Ok(())
+	}
+}
+
+/// Disables or re-enables the transfer of a `nonfungibles` item.
+pub struct NonFungiblesLockTicket<T: Config, NonFungibles: nonfungibles_v2::Inspect<T::AccountId>> {
+	sovereign_account: T::AccountId,
+	collection: NonFungibles::CollectionId,
+	item: NonFungibles::ItemId,
+	lock: bool,
+	locks: LocksOf<T>,
+}
+
+impl<T, NonFungibles> Enact for NonFungiblesLockTicket<T, NonFungibles>
+where
+	T: Config,
+	NonFungibles: nonfungibles_v2::Transfer<T::AccountId>,
+{
+	fn enact(self) -> Result<(), LockError> {
+		if self.lock {
+			NonFungibles::disable_transfer(&self.collection, &self.item)
+		} else {
+			NonFungibles::enable_transfer(&self.collection, &self.item)
+		}
+		.map_err(|error| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::enact", ?error, lock = self.lock,
+				"Failed to update the transfer lock of a non-fungible item",
+			);
+			LockError::UnexpectedState
+		})?;
+		write_locks::<T>(&self.sovereign_account, self.locks);
+		Ok(())
+	}
+}
+
+fn write_locks<T: Config>(who: &T::AccountId, locks: LocksOf<T>) {
+	if locks.is_empty() {
+		LockedAssets::<T>::remove(who);
+	} else {
+		LockedAssets::<T>::insert(who, locks);
+	}
+}
+
+/// Returns whether `locked` is a lock of the fungible asset `id` held by `unlocker`.
+fn is_fungible_lock(locked: &LockedAsset, id: &AssetId, unlocker: &Location) -> bool {
+	locked.unlocker.try_as::<Location>() == Ok(unlocker) &&
+		matches!(
+			locked.asset.try_as::<Asset>(),
+			Ok(Asset { id: locked_id, fun: Fungible(_) }) if locked_id == id
+		)
+}
+
+/// The largest amount of the fungible asset `id` locked for any unlocker.
+fn frozen_amount<T: Config>(locks: &LocksOf<T>, id: &AssetId) -> u128 {
+	locks
+		.iter()
+		.filter_map(|locked| match locked.asset.try_as::<Asset>() {
+			Ok(Asset { id: locked_id, fun: Fungible(amount) }) if locked_id == id => Some(*amount),
+			_ => None,
+		})
+		.max()
+		.unwrap_or_default()
+}
+
+impl<T, Fungibles, FungiblesMatcher, FreezeId, NonFungibles, NonFungiblesMatcher>
+	AssetLockAdapter<T, Fungibles, FungiblesMatcher, FreezeId, NonFungibles, NonFungiblesMatcher>
+where
+	T: Config,
+	Fungibles: MutateFreeze<T::AccountId>,
+	FungiblesMatcher: MatchesFungibles<Fungibles::AssetId, Fungibles::Balance>,
+	FreezeId: Get<Fungibles::Id>,
+	NonFungibles: nonfungibles_v2::Transfer<T::AccountId>,
+	NonFungiblesMatcher: MatchesNonFungibles<NonFungibles::CollectionId, NonFungibles::ItemId>,
+{
+	fn prepare_fungibles(
+		sovereign_account: T::AccountId,
+		mut locks: LocksOf<T>,
+		asset: Asset,
+		unlocker: Location,
+		lock: bool,
+	) -> Result<FungiblesLockTicket<T, Fungibles, FreezeId>, LockError> {
+		use LockError::*;
+		let amount = match asset.fun {
+			Fungible(amount) => amount,
+			NonFungible(_) => return Err(UnknownAsset),
+		};
+		let (asset_id, balance) =
+			FungiblesMatcher::matches_fungibles(&asset).map_err(|_| UnknownAsset)?;
+		let item_index = locks.iter().position(|x| is_fungible_lock(x, &asset.id, &unlocker));
+		let locked = item_index
+			.and_then(|i| match locks[i].asset.try_as::<Asset>() {
+				Ok(Asset { fun: Fungible(locked), .. }) => Some(*locked),
+				_ => None,
+			})
+			.unwrap_or_default();
+
+		let new_locked = if lock {
+			ensure!(
+				Fungibles::balance(asset_id.clone(), &sovereign_account) >= balance,
+				AssetNotOwned
+			);
+			ensure!(
+				Fungibles::can_freeze(asset_id.clone(), &FreezeId::get(), &sovereign_account),
+				NoResources
+			);
+			locked.max(amount)
+		} else {
+			ensure!(item_index.is_some() && locked >= amount, NotLocked);
+			locked.saturating_sub(amount)
+		};
+
+		let new_lock = LockedAsset {
+			asset: Asset { id: asset.id.clone(), fun: Fungible(new_locked) }.into(),
+			unlocker: VersionedLocation::from(unlocker),
+		};
+		match item_index {
+			Some(i) if new_locked.is_zero() => {
+				locks.swap_remove(i);
+			},
+			Some(i) => locks[i] = new_lock,
+			None if new_locked.is_zero() => {},
+			None => locks.try_push(new_lock).map_err(|_| NoResources)?,
+		}
+		let frozen = frozen_amount::<T>(&locks, &asset.id).saturated_into();
+
+		Ok(FungiblesLockTicket {
+			sovereign_account,
+			asset_id,
+			frozen,
+			locks,
+			_phantom: PhantomData,
+		})
+	}
+
+	fn prepare_non_fungibles(
+		sovereign_account: T::AccountId,
+		mut locks: LocksOf<T>,
+		asset: Asset,
+		unlocker: Location,
+		lock: bool,
+	) -> Result<NonFungiblesLockTicket<T, NonFungibles>, LockError> {
+		use LockError::*;
+		let (collection, item) =
+			NonFungiblesMatcher::matches_nonfungibles(&asset).map_err(|_| UnknownAsset)?;
+		let item_index = locks.iter().position(|x| x.asset.try_as::<Asset>() == Ok(&asset));
+
+		if lock {
+			ensure!(
+				NonFungibles::owner(&collection, &item).as_ref() == Some(&sovereign_account),
+				AssetNotOwned
+			);
+			ensure!(item_index.is_none(), WouldClobber);
+			let new_lock = LockedAsset {
+				asset: VersionedAsset::from(asset),
+				unlocker: VersionedLocation::from(unlocker),
+			};
+			locks.try_push(new_lock).map_err(|_| NoResources)?;
+		} else {
+			let index = item_index.ok_or(NotLocked)?;
+			ensure!(locks[index].unlocker.try_as::<Location>() == Ok(&unlocker), NotLocked);
+			locks.swap_remove(index);
+		}
+
+		Ok(NonFungiblesLockTicket { sovereign_account, collection, item, lock, locks })
+	}
+
+	fn prepare<NativeTicket>(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+		lock: bool,
+	) -> Result<AssetLockTicket<NativeTicket, T, Fungibles, FreezeId, NonFungibles>, LockError> {
+		let sovereign_account =
+			T::SovereignAccountOf::convert_location(&owner).ok_or(LockError::BadOwner)?;
+		let locks = LockedAssets::<T>::get(&sovereign_account).unwrap_or_default();
+		match asset.fun {
+			Fungible(_) => Self::prepare_fungibles(sovereign_account, locks, asset, unlocker, lock)
+				.map(AssetLockTicket::Fungibles),
+			NonFungible(_) =>
+				Self::prepare_non_fungibles(sovereign_account, locks, asset, unlocker, lock)
+					.map(AssetLockTicket::NonFungibles),
+		}
+	}
+}
+
+impl<T, Fungibles, FungiblesMatcher, FreezeId, NonFungibles, NonFungiblesMatcher> AssetLock
+	for AssetLockAdapter<T, Fungibles, FungiblesMatcher, FreezeId, NonFungibles, NonFungiblesMatcher>
+where
+	T: Config,
+	Fungibles: MutateFreeze<T::AccountId>,
+	FungiblesMatcher: MatchesFungibles<Fungibles::AssetId, Fungibles::Balance>,
+	FreezeId: Get<Fungibles::Id>,
+	NonFungibles: nonfungibles_v2::Transfer<T::AccountId>,
+	NonFungiblesMatcher: MatchesNonFungibles<NonFungibles::CollectionId, NonFungibles::ItemId>,
+{
+	type LockTicket = AssetLockTicket<LockTicket<T>, T, Fungibles, FreezeId, NonFungibles>;
+	type UnlockTicket = AssetLockTicket<UnlockTicket<T>, T, Fungibles, FreezeId, NonFungibles>;
+	type ReduceTicket = ReduceTicket<T>;
+
+	fn prepare_lock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::LockTicket, LockError> {
+		match <Pallet<T> as AssetLock>::prepare_lock(unlocker.clone(), asset.clone(), owner.clone())
+		{
+			Err(LockError::UnknownAsset) => Self::prepare(unlocker, asset, owner, true),
+			result => result.map(AssetLockTicket::Native),
+		}
+	}
+
+	fn prepare_unlock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::UnlockTicket, LockError> {
+		match <Pallet<T> as AssetLock>::prepare_unlock(
+			unlocker.clone(),
+			asset.clone(),
+			owner.clone(),
+		) {
+			Err(LockError::UnknownAsset) => Self::prepare(unlocker, asset, owner, false),
+			result => result.map(AssetLockTicket::Native),
+		}
+	}
+
+	fn note_unlockable(locker: Location, asset: Asset, owner: Location) -> Result<(), LockError> {
+		<Pallet<T> as AssetLock>::note_unlockable(locker, asset, owner)
+	}
+
+	fn prepare_reduce_unlockable(
+		locker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::ReduceTicket, LockError> {
+		<Pallet<T> as AssetLock>::prepare_reduce_unlockable(locker, asset, owner)
+	}
+}
@@ -26,6 +26,7 @@ mod mock;
 mod tests;
 mod transfer_assets_validation;
 
+pub mod asset_lock;
 pub mod migration;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod xcm_helpers;
@@ -33,6 +34,7 @@ pub mod xcm_helpers;
 extern crate alloc;
 
 use alloc::{boxed::Box, vec, vec::Vec};
+pub use asset_lock::AssetLockAdapter;
 use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use core::{marker::PhantomData, result::Result};
 use frame_support::{
@@ -52,8 +54,8 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BadOrigin, BlakeTwo256, BlockNumberProvider, Dispatchable, Hash,
-		Saturating, Zero,
+		AccountIdConversion, BadOrigin, BlakeTwo256, BlockNumberProvider, Convert, Dispatchable,
+		Hash, Saturating, Zero,
 	},
 	Debug, Either, SaturatedConversion,
 };
@@ -66,16 +68,21 @@ use xcm_builder::{
 use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
-		DropAssets, EventEmitter, FeeManager, FeeReason, MatchesFungible, OnResponse, Properties,
-		QueryHandler, QueryResponseStatus, RecordXcm, TransactAsset, TransferType,
-		VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
+		DropAssets, EventEmitter, FeeManager, FeeReason, MatchesFungible, MessageStatus,
+		OnResponse, Properties, QueryHandler, QueryResponseStatus, RecordMessageStatus, RecordXcm,
+		TransactAsset, TransferType, VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
 	},
 	AssetsInHolding,
 };
 use xcm_runtime_apis::{
 	authorized_aliases::{Error as AuthorizedAliasersApiError, OriginAliaser},
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, CallDryRunEffectsWithTrace, Error as XcmDryRunApiError,
+		XcmDryRunEffects, XcmDryRunEffectsWithTrace, XcmInstructionTrace,
+	},
 	fees::Error as XcmPaymentApiError,
+	message_status::Error as XcmMessageStatusApiError,
+	trapped_assets::{Error as TrappedAssetsApiError, TrappedAssets, TrappedAssetsPage},
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -237,6 +244,16 @@ pub mod pallet {
 		#[derive(Debug, TypeInfo)]
 		/// The maximum number of distinct locations allowed as authorized aliases for a local origin.
 		pub const MaxAuthorizedAliases: u32 = 10;
+
+		/// The maximum number of messages whose lifecycle is kept in [`MessageStatuses`].
+		pub const MaxTrackedMessages: u32 = 4096;
+
+		/// The maximum number of steps kept for a single message in [`MessageStatuses`].
+		pub const MaxMessageStatuses: u32 = 8;
+
+		/// The maximum number of identical asset traps, i.e. of the same assets for the same
+		/// origin, whose details are kept in [`AssetTrapRecords`].
+		pub const MaxAssetTrapRecordsPerHash: u32 = 16;
 	}
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -338,6 +355,17 @@ pub mod pallet {
 		/// The ID type for local consumers of remote locks.
 		type RemoteLockConsumerIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
 
+		/// The number of blocks after which assets left in an asset trap are deposited to the
+		/// [`Config::TrappedAssetsBeneficiary`] of their origin. `None` keeps them trapped until
+		/// claimed.
+		type TrappedAssetsExpiry: Get<Option<BlockNumberFor<Self>>>;
+
+		/// Where the expired trapped assets of an origin are deposited to. `None` keeps the
+		/// assets of that origin trapped until claimed.
+		///
+		/// See [`TrappedAssetsToOrigin`] and [`TrappedAssetsTo`].
+		type TrappedAssetsBeneficiary: Convert<Location, Option<Location>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -358,7 +386,8 @@ pub mod pallet {
 			tracing::trace!(target: "xcm::pallet_xcm::execute", ?message, ?max_weight);
 			let outcome = (|| {
 				let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
-				let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+				// Used as the topic of the sent messages unless the message has its own.
+				let mut hash = frame_system::unique(&message);
 				let message = (*message).try_into().map_err(|()| {
 					tracing::debug!(
 						target: "xcm::pallet_xcm::execute", id=?hash,
@@ -491,6 +520,11 @@ pub mod pallet {
 			message: Option<Xcm<()>>,
 			message_id: XcmHash,
 		) {
+			// The recording is part of the benchmarked weight of the sending instructions.
+			Self::record_message_status(
+				message_id,
+				MessageStatus::Sent { destination: destination.clone() },
+			);
 			Self::deposit_event(Event::Sent {
 				origin,
 				destination,
@@ -505,12 +539,50 @@ pub mod pallet {
 			error: SendError,
 			message_id: XcmHash,
 		) {
+			// The recording is part of the benchmarked weight of the sending instructions.
+			Self::record_message_status(
+				message_id,
+				MessageStatus::SendFailed { destination: destination.clone() },
+			);
 			Self::deposit_event(Event::SendFailed { origin, destination, error, message_id });
 		}
 
 		fn emit_process_failure_event(origin: Location, error: XcmError, message_id: XcmHash) {
 			Self::deposit_event(Event::ProcessXcmError { origin, error, message_id });
 		}
+
+		fn on_process_outcome(outcome: &Outcome, topic: XcmHash) -> Weight {
+			let status = match outcome {
+				Outcome::Complete { .. } => MessageStatus::Executed,
+				Outcome::Incomplete { error, .. } | Outcome::Error(error) =>
+					MessageStatus::Failed { index: error.index, error: error.error },
+			};
+			Self::record_message_status(topic, status)
+		}
+	}
+
+	impl<T: Config> RecordMessageStatus for Pallet<T> {
+		fn record_message_status(topic: XcmHash, status: MessageStatus) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut statuses = MessageStatuses::<T>::get(topic).unwrap_or_else(|| {
+				weight.saturating_accrue(Self::track_message(topic));
+				Default::default()
+			});
+			// Keep the first steps and the latest one.
+			if statuses.is_full() {
+				statuses.pop();
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let _ = statuses.try_push((now, status));
+			MessageStatuses::<T>::insert(topic, statuses);
+			weight
+		}
+
+		/// Not benchmarked yet, bounded by the storage accesses of recording the first status of
+		/// a message.
+		fn record_message_status_weight() -> Weight {
+			T::DbWeight::get().reads_writes(3, 4)
+		}
 	}
 
 	#[pallet::event]
@@ -623,6 +695,22 @@ pub mod pallet {
 		FeesPaid { paying: Location, fees: Assets },
 		/// Some assets have been claimed from an asset trap
 		AssetsClaimed { hash: H256, origin: Location, assets: VersionedAssets },
+		/// Some assets stayed in an asset trap past [`Config::TrappedAssetsExpiry`] and have been
+		/// deposited to `beneficiary`.
+		TrappedAssetsExpired {
+			hash: H256,
+			origin: Location,
+			beneficiary: Location,
+			assets: VersionedAssets,
+		},
+		/// Depositing some expired trapped assets to `beneficiary` failed. The assets stay trapped
+		/// until claimed, but are no longer listed nor retried.
+		TrappedAssetsExpiryFailed {
+			hash: H256,
+			origin: Location,
+			beneficiary: Location,
+			error: XcmError,
+		},
 		/// A XCM version migration finished.
 		VersionMigrationFinished { version: XcmVersion },
 		/// An `aliaser` location was authorized by `target` to alias it, authorization valid until
@@ -657,6 +745,13 @@ pub mod pallet {
 		AuthorizeAlias,
 	}
 
+	/// A reason for this pallet placing a freeze on funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// The funds are locked by an XCM `LockAsset` instruction.
+		XcmLock,
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The desired destination was unreachable, generally because there is a no way of routing
@@ -810,6 +905,38 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type AssetTraps<T: Config> = StorageMap<_, Identity, H256, u32, ValueQuery>;
 
+	/// The details of a single asset trap.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+	pub struct AssetTrapRecord<BlockNumber> {
+		/// The location the assets were trapped for, i.e. the one able to claim them.
+		pub origin: VersionedLocation,
+		/// The trapped assets, in the version they were trapped with.
+		pub assets: VersionedAssets,
+		/// The block the assets were trapped in.
+		pub trapped_at: BlockNumber,
+	}
+
+	/// The details of the asset traps in [`AssetTraps`], keyed by an increasing index.
+	///
+	/// Traps that existed before their details started being recorded are only in
+	/// [`AssetTraps`], they never expire.
+	#[pallet::storage]
+	pub(super) type AssetTrapRecords<T: Config> =
+		StorageMap<_, Twox64Concat, u64, AssetTrapRecord<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The indices of the [`AssetTrapRecords`] of each asset trap hash, oldest first.
+	#[pallet::storage]
+	pub(super) type AssetTrapRecordsByHash<T: Config> =
+		StorageMap<_, Identity, H256, BoundedVec<u64, MaxAssetTrapRecordsPerHash>, ValueQuery>;
+
+	/// The index of the next record in [`AssetTrapRecords`].
+	#[pallet::storage]
+	pub(super) type NextAssetTrapRecord<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The index of the oldest record in [`AssetTrapRecords`] not yet checked for expiry.
+	#[pallet::storage]
+	pub(super) type AssetTrapExpiryCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	/// Default version to encode XCM when latest version of destination is unknown. If `None`,
 	/// then the destinations whose XCM version is unknown are considered unreachable.
 	#[pallet::storage]
@@ -922,6 +1049,47 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct RemoteLockedNonFungibleRecord {
+		/// The owner of the locked asset.
+		pub owner: VersionedLocation,
+		/// The location which holds the original lock.
+		pub locker: VersionedLocation,
+	}
+
+	/// Non-fungible assets which we know are locked on a remote chain.
+	#[pallet::storage]
+	pub(super) type RemoteLockedNonFungibles<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, XcmVersion>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, VersionedAsset>,
+		),
+		RemoteLockedNonFungibleRecord,
+		OptionQuery,
+	>;
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct LockedAsset {
+		/// The locked asset. For fungible assets, the amount locked for `unlocker`.
+		pub asset: VersionedAsset,
+		/// The location which may unlock the asset.
+		pub unlocker: VersionedLocation,
+	}
+
+	/// Assets other than [`Config::Currency`] which we know are locked on this chain.
+	///
+	/// Populated by [`AssetLockAdapter`](crate::asset_lock::AssetLockAdapter).
+	#[pallet::storage]
+	pub(super) type LockedAssets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<LockedAsset, T::MaxLockers>,
+		OptionQuery,
+	>;
+
 	/// Global suspension state of the XCM executor.
 	#[pallet::storage]
 	pub(super) type XcmExecutionSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -945,6 +1113,50 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// Whether or not the XCM executor should record a trace of every processed instruction.
+	/// This is meant to be used in runtime APIs, and it's advised it stays false
+	/// for all other use cases, so as to not degrade regular performance.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type ShouldTraceXcm<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// If [`ShouldTraceXcm`] is set to true, the state of the XCM executor after every processed
+	/// instruction is appended here, up to [`MAX_TRACED_INSTRUCTIONS`] instructions.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type RecordedXcmTrace<T: Config> = StorageValue<
+		_,
+		BoundedVec<xcm_executor::traits::InstructionTrace, ConstU32<MAX_TRACED_INSTRUCTIONS>>,
+		ValueQuery,
+	>;
+
+	/// The lifecycle of the recently sent or processed XCMs on this chain, keyed by their topic.
+	///
+	/// Only the last [`MaxTrackedMessages`] messages are kept, see [`TrackedMessages`]. The topics
+	/// are chosen by the senders, so the record is informational only.
+	#[pallet::storage]
+	pub(super) type MessageStatuses<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		XcmHash,
+		BoundedVec<(BlockNumberFor<T>, MessageStatus), MaxMessageStatuses>,
+		OptionQuery,
+	>;
+
+	/// Ring buffer of the topics in [`MessageStatuses`] in the order they were first recorded.
+	/// The oldest topic is pruned once a new one takes its slot.
+	#[pallet::storage]
+	pub(super) type TrackedMessages<T: Config> =
+		StorageMap<_, Twox64Concat, u32, XcmHash, OptionQuery>;
+
+	/// The slot of [`TrackedMessages`] the next new topic is put into.
+	#[pallet::storage]
+	pub(super) type NextTrackedMessage<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Map of authorized aliasers of local origins. Each local location can authorize a list of
 	/// other locations to alias into it. Each aliaser is only valid until its inner `expiry`
 	/// block number.
@@ -1030,6 +1242,10 @@ pub mod pallet {
 			weight_used
 		}
 
+		fn on_idle(_n: BlockNumberFor<T>, limit: Weight) -> Weight {
+			Self::expire_asset_traps(limit)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state()
@@ -1871,6 +2087,10 @@ pub mod pallet {
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
+/// The maximum number of instructions recorded in a single XCM trace, further instructions are
+/// dropped from the trace.
+pub const MAX_TRACED_INSTRUCTIONS: u32 = 1_000;
+
 /// Specify how assets used for fees are handled during asset transfers.
 #[derive(Clone, PartialEq)]
 enum FeesHandling<T: Config> {
@@ -2298,7 +2518,7 @@ impl<T: Config> Pallet<T> {
 		origin: Location,
 		dest: Location,
 		mut local_xcm: Xcm<<T as Config>::RuntimeCall>,
-		remote_xcm: Option<Xcm<()>>,
+		mut remote_xcm: Option<Xcm<()>>,
 	) -> DispatchResult {
 		tracing::debug!(
 			target: "xcm::pallet_xcm::execute_xcm_transfer",
@@ -2310,7 +2530,13 @@ impl<T: Config> Pallet<T> {
 				tracing::debug!(target: "xcm::pallet_xcm::execute_xcm_transfer", ?error, "Failed to calculate weight");
 				Error::<T>::UnweighableMessage
 			})?;
-		let mut hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
+		// All the messages of the transfer share a single topic, be it the one of the remote
+		// message or a new one used as the ID of the local execution.
+		let topic = match remote_xcm.as_mut() {
+			Some(remote_xcm) => Self::ensure_topic(remote_xcm),
+			None => frame_system::unique(&local_xcm),
+		};
+		let mut hash = topic;
 		let outcome = T::XcmExecutor::prepare_and_execute(
 			origin.clone(),
 			local_xcm,
@@ -2345,7 +2571,7 @@ impl<T: Config> Pallet<T> {
 					Error::<T>::FeesNotMet
 				})?;
 			}
-			let message_id = T::XcmRouter::deliver(ticket)
+			let message_id = Self::deliver_tracked(ticket, dest.clone(), topic)
 				.map_err(|error| {
 					tracing::error!(target: "xcm::pallet_xcm::execute_xcm_transfer", ?error, ?dest, ?remote_xcm, "XCM deliver failed with error");
 					Error::<T>::from(error)
@@ -3018,8 +3244,9 @@ impl<T: Config> Pallet<T> {
 		if interior != Junctions::Here {
 			message.0.insert(0, DescendOrigin(interior.clone()));
 		}
+		let topic = Self::ensure_topic(&mut message);
 		tracing::debug!(target: "xcm::send_xcm", "{:?}, {:?}", dest.clone(), message.clone());
-		let (ticket, price) = validate_send::<T::XcmRouter>(dest, message)?;
+		let (ticket, price) = validate_send::<T::XcmRouter>(dest.clone(), message)?;
 		if !is_waived {
 			Self::charge_fees(local_origin, price).map_err(|e| {
 				tracing::error!(
@@ -3030,7 +3257,255 @@ impl<T: Config> Pallet<T> {
 				SendError::Fees
 			})?;
 		}
-		T::XcmRouter::deliver(ticket)
+		Self::deliver_tracked(ticket, dest, topic)
+	}
+
+	/// Append a unique `SetTopic` to the `message` unless it already ends with one, so that the
+	/// message can be followed over all its hops. Returns the topic of the message.
+	fn ensure_topic(message: &mut Xcm<()>) -> XcmHash {
+		match message.last() {
+			Some(SetTopic(topic)) => *topic,
+			_ => {
+				let topic = frame_system::unique(&*message);
+				message.0.push(SetTopic(topic));
+				topic
+			},
+		}
+	}
+
+	/// Deliver the `ticket` to `dest`, recording the result under the `topic` of the message.
+	fn deliver_tracked(
+		ticket: <T::XcmRouter as SendXcm>::Ticket,
+		dest: Location,
+		topic: XcmHash,
+	) -> Result<XcmHash, SendError> {
+		let result = T::XcmRouter::deliver(ticket);
+		let status = match result {
+			Ok(_) => MessageStatus::Sent { destination: dest },
+			Err(_) => MessageStatus::SendFailed { destination: dest },
+		};
+		// The recording is part of the benchmarked weight of the sending extrinsics.
+		Self::record_message_status(topic, status);
+		result
+	}
+
+	/// The lifecycle of the message with the `topic` as recorded by this chain, oldest step first.
+	///
+	/// Meant to be used by the [`xcm_runtime_apis::message_status::XcmMessageStatusApi`].
+	pub fn message_status(
+		topic: XcmHash,
+	) -> Result<Vec<(BlockNumberFor<T>, MessageStatus)>, XcmMessageStatusApiError> {
+		MessageStatuses::<T>::get(topic)
+			.map(BoundedVec::into_inner)
+			.ok_or(XcmMessageStatusApiError::UnknownMessage)
+	}
+
+	/// Start tracking the message with the `topic`, pruning the oldest tracked message if its
+	/// slot is taken.
+	///
+	/// Returns the weight used.
+	fn track_message(topic: XcmHash) -> Weight {
+		let slot = NextTrackedMessage::<T>::get();
+		if let Some(oldest) = TrackedMessages::<T>::take(slot) {
+			MessageStatuses::<T>::remove(oldest);
+		}
+		TrackedMessages::<T>::insert(slot, topic);
+		NextTrackedMessage::<T>::put(slot.saturating_add(1) % MaxTrackedMessages::get());
+		T::DbWeight::get().reads_writes(2, 3)
+	}
+
+	/// Returns a page of the asset traps with recorded details, optionally only the ones of
+	/// `origin`, looking at no more than `limit` of them starting with the `start`th.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::trapped_assets::TrappedAssetsApi` runtime API.
+	pub fn trapped_assets(
+		origin: Option<VersionedLocation>,
+		start: u64,
+		limit: u32,
+	) -> Result<TrappedAssetsPage<BlockNumberFor<T>>, TrappedAssetsApiError> {
+		let origin = origin
+			.map(Location::try_from)
+			.transpose()
+			.map_err(|()| TrappedAssetsApiError::VersionedConversionFailed)?;
+		let expiry = T::TrappedAssetsExpiry::get();
+		let next_record = NextAssetTrapRecord::<T>::get();
+		let end = start.saturating_add(limit.into()).min(next_record);
+		let trapped = (start..end)
+			.filter_map(AssetTrapRecords::<T>::get)
+			.filter_map(|record| {
+				let record_origin = Location::try_from(record.origin.clone()).ok();
+				if origin.is_some() && record_origin != origin {
+					return None
+				}
+				let expires_at = expiry
+					.filter(|_| {
+						record_origin
+							.map_or(false, |o| T::TrappedAssetsBeneficiary::convert(o).is_some())
+					})
+					.map(|expiry| record.trapped_at.saturating_add(expiry));
+				Some(TrappedAssets {
+					origin: record.origin,
+					assets: record.assets,
+					trapped_at: record.trapped_at,
+					expires_at,
+				})
+			})
+			.collect();
+		let next = (end < next_record).then_some(end);
+		Ok(TrappedAssetsPage { trapped, next })
+	}
+
+	/// Record the details of a new asset trap with the given `hash`, unless the details of
+	/// [`MaxAssetTrapRecordsPerHash`] identical ones are already recorded.
+	///
+	/// Returns the weight used.
+	fn record_asset_trap(hash: H256, origin: &Location, assets: &VersionedAssets) -> Weight {
+		let index = NextAssetTrapRecord::<T>::get();
+		if AssetTrapRecordsByHash::<T>::mutate(hash, |indices| indices.try_push(index)).is_err() {
+			return T::DbWeight::get().reads_writes(2, 1)
+		}
+		AssetTrapRecords::<T>::insert(
+			index,
+			AssetTrapRecord {
+				origin: origin.clone().into(),
+				assets: assets.clone(),
+				trapped_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+		NextAssetTrapRecord::<T>::put(index.saturating_add(1));
+		T::DbWeight::get().reads_writes(2, 3)
+	}
+
+	/// Remove the details of the oldest asset trap with the given `hash`, if any.
+	fn forget_asset_trap(hash: H256) {
+		let mut indices = AssetTrapRecordsByHash::<T>::take(hash);
+		if indices.is_empty() {
+			return
+		}
+		AssetTrapRecords::<T>::remove(indices.remove(0));
+		if !indices.is_empty() {
+			AssetTrapRecordsByHash::<T>::insert(hash, indices);
+		}
+	}
+
+	/// Deposit the assets trapped for longer than [`Config::TrappedAssetsExpiry`] to the
+	/// [`Config::TrappedAssetsBeneficiary`] of their origin, oldest first, using at most `limit`.
+	///
+	/// Traps that cannot be deposited stay claimable, but their details are pruned.
+	fn expire_asset_traps(limit: Weight) -> Weight {
+		let Some(expiry) = T::TrappedAssetsExpiry::get() else { return Weight::zero() };
+		let db_weight = T::DbWeight::get();
+		// The cursor and the next record index are read, the cursor is written.
+		let mut weight_used = db_weight.reads_writes(2, 1);
+		if weight_used.any_gt(limit) {
+			return Weight::zero()
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let next = NextAssetTrapRecord::<T>::get();
+		let start = AssetTrapExpiryCursor::<T>::get();
+		let mut cursor = start;
+		while cursor < next {
+			let read = db_weight.reads(1);
+			if weight_used.saturating_add(read).any_gt(limit) {
+				break
+			}
+			weight_used.saturating_accrue(read);
+			let Some(record) = AssetTrapRecords::<T>::get(cursor) else {
+				// Already claimed.
+				cursor.saturating_inc();
+				continue
+			};
+			if record.trapped_at.saturating_add(expiry) > now {
+				break
+			}
+			match Self::expire_asset_trap(record, limit.saturating_sub(weight_used)) {
+				Some(weight) => weight_used.saturating_accrue(weight),
+				None => break,
+			}
+			cursor.saturating_inc();
+		}
+		if cursor != start {
+			AssetTrapExpiryCursor::<T>::put(cursor);
+		}
+		weight_used
+	}
+
+	/// Deposit the assets of an expired asset trap to the beneficiary of its origin by claiming
+	/// them in an XCM executed on its behalf. The details of the trap are pruned if that fails.
+	///
+	/// Returns the weight used, or `None` if more than `limit` would be needed.
+	fn expire_asset_trap(
+		record: AssetTrapRecord<BlockNumberFor<T>>,
+		limit: Weight,
+	) -> Option<Weight> {
+		let (Ok(origin), Ok(assets)) =
+			(Location::try_from(record.origin), Assets::try_from(record.assets.clone()))
+		else {
+			return Some(Weight::zero())
+		};
+		let Some(beneficiary) = T::TrappedAssetsBeneficiary::convert(origin.clone()) else {
+			return Some(Weight::zero())
+		};
+		let hash = BlakeTwo256::hash_of(&(&origin, &record.assets));
+		let ticket: Location = GeneralIndex(record.assets.identify_version() as u128).into();
+		let number_of_assets = assets.len() as u32;
+		let mut message = Xcm(vec![
+			ClaimAsset { assets, ticket },
+			DepositAsset {
+				assets: AllCounted(number_of_assets).into(),
+				beneficiary: beneficiary.clone(),
+			},
+		]);
+		// Pruning the details of the trap on failure.
+		let forget_weight = T::DbWeight::get().reads_writes(1, 2);
+		let Ok(weight) = T::Weigher::weight(&mut message, Weight::MAX) else {
+			tracing::debug!(target: "xcm::pallet_xcm::expire_asset_trap", ?hash, "Failed to calculate weight");
+			Self::forget_asset_trap(hash);
+			return Some(forget_weight)
+		};
+		if weight.saturating_add(forget_weight).any_gt(limit) {
+			return None
+		}
+		let mut id = hash.0;
+		// A failed deposit must not trap the assets again, so its effects are reverted.
+		let outcome = with_transaction(|| -> TransactionOutcome<Result<_, DispatchError>> {
+			let outcome = T::XcmExecutor::prepare_and_execute(
+				origin.clone(),
+				message,
+				&mut id,
+				weight,
+				weight,
+			);
+			if outcome.clone().ensure_complete().is_ok() {
+				TransactionOutcome::Commit(Ok(outcome))
+			} else {
+				TransactionOutcome::Rollback(Ok(outcome))
+			}
+		})
+		.ok()?;
+		match outcome.clone().ensure_complete() {
+			Ok(()) => {
+				Self::deposit_event(Event::TrappedAssetsExpired {
+					hash,
+					origin,
+					beneficiary,
+					assets: record.assets,
+				});
+				Some(outcome.weight_used())
+			},
+			Err(error) => {
+				tracing::debug!(target: "xcm::pallet_xcm::expire_asset_trap", ?hash, ?error, "Failed to deposit expired trapped assets");
+				// Not retried, the assets can still be claimed.
+				Self::forget_asset_trap(hash);
+				Self::deposit_event(Event::TrappedAssetsExpiryFailed {
+					hash,
+					origin,
+					beneficiary,
+					error: error.error,
+				});
+				Some(outcome.weight_used().saturating_add(forget_weight))
+			},
+		}
 	}
 
 	pub fn check_account() -> T::AccountId {
@@ -3148,6 +3623,115 @@ impl<T: Config> Pallet<T> {
 		Ok(XcmDryRunEffects { forwarded_xcms, emitted_events: events, execution_result: result })
 	}
 
+	/// Dry-runs `xcm` with the given `origin_location`, recording the state of the XCM executor
+	/// after every processed instruction.
+	///
+	/// Returns the effects of [`Self::dry_run_xcm`] together with the trace. The trace is only
+	/// recorded if this pallet is the `XcmRecorder` of the XCM executor.
+	/// Meant to be used in version 3 of the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_xcm_with_trace<Router>(
+		origin_location: VersionedLocation,
+		xcm: VersionedXcm<<T as Config>::RuntimeCall>,
+	) -> Result<
+		XcmDryRunEffectsWithTrace<<T as frame_system::Config>::RuntimeEvent>,
+		XcmDryRunApiError,
+	>
+	where
+		Router: InspectMessageQueues,
+	{
+		let xcm_version = xcm.identify_version();
+
+		// Drop any stale trace, so we only return the instructions of this program.
+		let _ = Self::take_trace();
+		// The executor only traces while recording.
+		Self::set_record_xcm(true);
+		Self::set_trace_xcm(true);
+		let effects = Self::dry_run_xcm::<Router>(origin_location, xcm);
+		Self::set_trace_xcm(false);
+		Self::set_record_xcm(false);
+		let trace = Self::convert_instruction_traces(xcm_version, Self::take_trace()).inspect_err(
+			|_| {
+				tracing::error!(
+					target: "xcm::DryRunApi::dry_run_xcm_with_trace",
+					"Instruction trace version conversion failed"
+				);
+			},
+		)?;
+		Ok(XcmDryRunEffectsWithTrace { effects: effects?, trace })
+	}
+
+	/// Dry-runs `call` with the given `origin`, recording the state of the XCM executor after
+	/// every instruction of the XCMs executed locally by the call.
+	///
+	/// Returns the effects of [`Self::dry_run_call`] together with the trace. The trace is only
+	/// recorded if this pallet is the `XcmRecorder` of the XCM executor.
+	/// Meant to be used in version 3 of the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_call_with_trace<Runtime, Router, OriginCaller, RuntimeCall>(
+		origin: OriginCaller,
+		call: RuntimeCall,
+		result_xcms_version: XcmVersion,
+	) -> Result<
+		CallDryRunEffectsWithTrace<<Runtime as frame_system::Config>::RuntimeEvent>,
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+		<RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+	{
+		// Drop any stale trace, so we only return the instructions of this call.
+		let _ = crate::Pallet::<Runtime>::take_trace();
+		crate::Pallet::<Runtime>::set_trace_xcm(true);
+		let effects = Self::dry_run_call::<Runtime, Router, OriginCaller, RuntimeCall>(
+			origin,
+			call,
+			result_xcms_version,
+		);
+		crate::Pallet::<Runtime>::set_trace_xcm(false);
+		let trace = Self::convert_instruction_traces(
+			result_xcms_version,
+			crate::Pallet::<Runtime>::take_trace(),
+		)
+		.inspect_err(|_| {
+			tracing::error!(
+				target: "xcm::DryRunApi::dry_run_call_with_trace",
+				"Instruction trace version conversion failed"
+			);
+		})?;
+		Ok(CallDryRunEffectsWithTrace { effects: effects?, trace })
+	}
+
+	fn convert_instruction_traces(
+		xcm_version: XcmVersion,
+		trace: Vec<xcm_executor::traits::InstructionTrace>,
+	) -> Result<Vec<XcmInstructionTrace>, XcmDryRunApiError> {
+		trace
+			.into_iter()
+			.map(|step| Self::convert_instruction_trace(xcm_version, step))
+			.collect::<Result<Vec<_>, ()>>()
+			.map_err(|()| XcmDryRunApiError::VersionedConversionFailed)
+	}
+
+	fn convert_instruction_trace(
+		xcm_version: XcmVersion,
+		step: xcm_executor::traits::InstructionTrace,
+	) -> Result<XcmInstructionTrace, ()> {
+		Ok(XcmInstructionTrace {
+			index: step.index,
+			instruction: VersionedXcm::from(Xcm(vec![step.instruction]))
+				.into_version(xcm_version)?,
+			weight: step.weight,
+			origin: step
+				.origin
+				.map(|o| VersionedLocation::from(o).into_version(xcm_version))
+				.transpose()?,
+			holding: VersionedAssets::from(step.holding).into_version(xcm_version)?,
+			fees: VersionedAssets::from(step.fees).into_version(xcm_version)?,
+			error: step.error,
+		})
+	}
+
 	fn convert_xcms(
 		xcm_version: XcmVersion,
 		xcms: Vec<VersionedXcm<()>>,
@@ -3608,6 +4192,25 @@ impl<T: Config> Pallet<T> {
 			)
 		);
 
+		// check `LockedAssets`
+		ensure!(
+			!LockedAssets::<T>::iter_values()
+				.any(|data| data.needs_migration(minimal_allowed_xcm_version)),
+			TryRuntimeError::Other(
+				"`LockedAssets` data should be migrated to the higher xcm version!"
+			)
+		);
+
+		// check `RemoteLockedNonFungibles`
+		ensure!(
+			!RemoteLockedNonFungibles::<T>::iter()
+				.any(|(key, data)| key.needs_migration(minimal_allowed_xcm_version) ||
+					data.needs_migration(minimal_allowed_xcm_version)),
+			TryRuntimeError::Other(
+				"`RemoteLockedNonFungibles` data should be migrated to the higher xcm version!"
+			)
+		);
+
 		// if migration has been already scheduled, everything is ok and data will be eventually
 		// migrated
 		if CurrentMigration::<T>::exists() {
@@ -3717,9 +4320,14 @@ impl<T: Config> xcm_executor::traits::Enact for UnlockTicket<T> {
 	}
 }
 
+/// The remote lock reduced by a [`ReduceTicket`].
+enum RemoteLock<AccountId> {
+	Fungible { key: (u32, AccountId, VersionedAssetId), amount: u128 },
+	NonFungible { key: (u32, AccountId, VersionedAsset) },
+}
+
 pub struct ReduceTicket<T: Config> {
-	key: (u32, T::AccountId, VersionedAssetId),
-	amount: u128,
+	lock: RemoteLock<T::AccountId>,
 	locker: VersionedLocation,
 	owner: VersionedLocation,
 }
@@ -3727,15 +4335,30 @@ pub struct ReduceTicket<T: Config> {
 impl<T: Config> xcm_executor::traits::Enact for ReduceTicket<T> {
 	fn enact(self) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::UnexpectedState;
-		let mut record = RemoteLockedFungibles::<T>::get(&self.key).ok_or(UnexpectedState)?;
-		ensure!(self.locker == record.locker && self.owner == record.owner, UnexpectedState);
-		let new_amount = record.amount.checked_sub(self.amount).ok_or(UnexpectedState)?;
-		ensure!(record.amount_held().map_or(true, |h| new_amount >= h), UnexpectedState);
-		if new_amount == 0 {
-			RemoteLockedFungibles::<T>::remove(&self.key);
-		} else {
-			record.amount = new_amount;
-			RemoteLockedFungibles::<T>::insert(&self.key, &record);
+		match self.lock {
+			RemoteLock::Fungible { key, amount } => {
+				let mut record = RemoteLockedFungibles::<T>::get(&key).ok_or(UnexpectedState)?;
+				ensure!(
+					self.locker == record.locker && self.owner == record.owner,
+					UnexpectedState
+				);
+				let new_amount = record.amount.checked_sub(amount).ok_or(UnexpectedState)?;
+				ensure!(record.amount_held().map_or(true, |h| new_amount >= h), UnexpectedState);
+				if new_amount == 0 {
+					RemoteLockedFungibles::<T>::remove(&key);
+				} else {
+					record.amount = new_amount;
+					RemoteLockedFungibles::<T>::insert(&key, &record);
+				}
+			},
+			RemoteLock::NonFungible { key } => {
+				let record = RemoteLockedNonFungibles::<T>::get(&key).ok_or(UnexpectedState)?;
+				ensure!(
+					self.locker == record.locker && self.owner == record.owner,
+					UnexpectedState
+				);
+				RemoteLockedNonFungibles::<T>::remove(&key);
+			},
 		}
 		Ok(())
 	}
@@ -3783,14 +4406,23 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 	) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::*;
 		ensure!(T::TrustedLockers::contains(&locker, &asset), NotTrusted);
-		let amount = match asset.fun {
-			Fungible(a) => a,
-			NonFungible(_) => return Err(Unimplemented),
-		};
 		owner.remove_network_id();
 		let account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
 		let locker = locker.into();
 		let owner = owner.into();
+		let amount = match asset.fun {
+			Fungible(a) => a,
+			NonFungible(_) => {
+				let key = (XCM_VERSION, account, VersionedAsset::from(asset));
+				let record = RemoteLockedNonFungibleRecord { owner, locker };
+				if let Some(old) = RemoteLockedNonFungibles::<T>::get(&key) {
+					// Make sure that the new record wouldn't clobber any old data.
+					ensure!(old == record, WouldClobber);
+				}
+				RemoteLockedNonFungibles::<T>::insert(&key, record);
+				return Ok(())
+			},
+		};
 		let id: VersionedAssetId = asset.id.into();
 		let key = (XCM_VERSION, account, id);
 		let mut record =
@@ -3811,14 +4443,20 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		mut owner: Location,
 	) -> Result<Self::ReduceTicket, xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::*;
-		let amount = match asset.fun {
-			Fungible(a) => a,
-			NonFungible(_) => return Err(Unimplemented),
-		};
 		owner.remove_network_id();
 		let sovereign_account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
 		let locker = locker.into();
 		let owner = owner.into();
+		let amount = match asset.fun {
+			Fungible(a) => a,
+			NonFungible(_) => {
+				let key = (XCM_VERSION, sovereign_account, VersionedAsset::from(asset));
+				let record = RemoteLockedNonFungibles::<T>::get(&key).ok_or(NotLocked)?;
+				// Make sure that the record contains what we expect.
+				ensure!(locker == record.locker && owner == record.owner, WouldClobber);
+				return Ok(ReduceTicket { lock: RemoteLock::NonFungible { key }, locker, owner })
+			},
+		};
 		let id: VersionedAssetId = asset.id.into();
 		let key = (XCM_VERSION, sovereign_account, id);
 
@@ -3830,7 +4468,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 			record.amount_held().map_or(true, |h| record.amount.saturating_sub(amount) >= h),
 			InUse
 		);
-		Ok(ReduceTicket { key, amount, locker, owner })
+		Ok(ReduceTicket { lock: RemoteLock::Fungible { key, amount }, locker, owner })
 	}
 }
 
@@ -3917,13 +4555,14 @@ impl<T: Config> DropAssets for Pallet<T> {
 		let versioned = VersionedAssets::from(Assets::from(assets));
 		let hash = BlakeTwo256::hash_of(&(&origin, &versioned));
 		AssetTraps::<T>::mutate(hash, |n| *n += 1);
+		let record_weight = Self::record_asset_trap(hash, origin, &versioned);
 		Self::deposit_event(Event::AssetsTrapped {
 			hash,
 			origin: origin.clone(),
 			assets: versioned,
 		});
-		// TODO #3735: Put the real weight in there.
-		Weight::zero()
+		// TODO #3735: Benchmark the trapping, only its storage accesses are accounted for.
+		T::DbWeight::get().reads_writes(1, 1).saturating_add(record_weight)
 	}
 }
 
@@ -3950,6 +4589,7 @@ impl<T: Config> ClaimAssets for Pallet<T> {
 			1 => AssetTraps::<T>::remove(hash),
 			n => AssetTraps::<T>::insert(hash, n - 1),
 		}
+		Self::forget_asset_trap(hash);
 		Self::deposit_event(Event::AssetsClaimed {
 			hash,
 			origin: origin.clone(),
@@ -4169,6 +4809,27 @@ impl<T: Config> RecordXcm for Pallet<T> {
 	fn record(xcm: Xcm<()>) {
 		RecordedXcm::<T>::put(xcm);
 	}
+
+	fn should_trace() -> bool {
+		ShouldTraceXcm::<T>::get()
+	}
+
+	fn set_trace_xcm(enabled: bool) {
+		ShouldTraceXcm::<T>::put(enabled);
+	}
+
+	fn take_trace() -> Vec<xcm_executor::traits::InstructionTrace> {
+		RecordedXcmTrace::<T>::take().into_inner()
+	}
+
+	fn record_instruction(trace: xcm_executor::traits::InstructionTrace) {
+		if RecordedXcmTrace::<T>::try_append(trace).is_err() {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::record_instruction",
+				"Instruction trace is full, dropping instruction",
+			);
+		}
+	}
 }
 
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
@@ -4214,6 +4875,26 @@ impl<L: Into<VersionedLocation> + Clone, T: Config> ContainsPair<L, L> for Autho
 	}
 }
 
+/// [`Config::TrappedAssetsBeneficiary`] depositing expired trapped assets to their origin, e.g.
+/// to the sovereign account of a sibling chain.
+pub struct TrappedAssetsToOrigin;
+impl Convert<Location, Option<Location>> for TrappedAssetsToOrigin {
+	fn convert(origin: Location) -> Option<Location> {
+		Some(origin)
+	}
+}
+
+/// [`Config::TrappedAssetsBeneficiary`] depositing all expired trapped assets to `Beneficiary`,
+/// e.g. a treasury.
+pub struct TrappedAssetsTo<Beneficiary>(PhantomData<Beneficiary>);
+impl<Beneficiary: Get<Location>> Convert<Location, Option<Location>>
+	for TrappedAssetsTo<Beneficiary>
+{
+	fn convert(_origin: Location) -> Option<Location> {
+		Some(Beneficiary::get())
+	}
+}
+
 /// Filter for `Location` to find those which represent a strict majority approval of an
 /// identified plurality.
 ///
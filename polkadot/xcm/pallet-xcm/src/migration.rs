@@ -170,6 +170,75 @@ pub mod data {
 		}
 	}
 
+	/// Implementation of `NeedsMigration` for `LockedAssets` data.
+	impl<M> NeedsMigration for BoundedVec<LockedAsset, M> {
+		type MigratedData = Self;
+
+		fn needs_migration(&self, minimal_allowed_xcm_version: XcmVersion) -> bool {
+			self.iter().any(|locked| {
+				locked.asset.identify_version() < minimal_allowed_xcm_version ||
+					locked.unlocker.identify_version() < minimal_allowed_xcm_version
+			})
+		}
+
+		fn try_migrate(
+			mut self,
+			to_xcm_version: XcmVersion,
+		) -> Result<Option<Self::MigratedData>, ()> {
+			if !self.needs_migration(to_xcm_version) {
+				return Ok(None)
+			}
+
+			for locked in self.iter_mut() {
+				locked.asset = locked.asset.clone().into_version(to_xcm_version)?;
+				locked.unlocker = locked.unlocker.clone().into_version(to_xcm_version)?;
+			}
+			Ok(Some(self))
+		}
+	}
+
+	/// Implementation of `NeedsMigration` for `RemoteLockedNonFungibles` key type.
+	impl<A> NeedsMigration for (XcmVersion, A, VersionedAsset) {
+		type MigratedData = Self;
+
+		fn needs_migration(&self, minimal_allowed_xcm_version: XcmVersion) -> bool {
+			self.0 < minimal_allowed_xcm_version ||
+				self.2.identify_version() < minimal_allowed_xcm_version
+		}
+
+		fn try_migrate(self, to_xcm_version: XcmVersion) -> Result<Option<Self::MigratedData>, ()> {
+			if !self.needs_migration(to_xcm_version) {
+				return Ok(None)
+			}
+
+			let Ok(asset) = self.2.into_version(to_xcm_version) else { return Err(()) };
+			Ok(Some((to_xcm_version, self.1, asset)))
+		}
+	}
+
+	/// Implementation of `NeedsMigration` for `RemoteLockedNonFungibles` data.
+	impl NeedsMigration for RemoteLockedNonFungibleRecord {
+		type MigratedData = Self;
+
+		fn needs_migration(&self, minimal_allowed_xcm_version: XcmVersion) -> bool {
+			self.owner.identify_version() < minimal_allowed_xcm_version ||
+				self.locker.identify_version() < minimal_allowed_xcm_version
+		}
+
+		fn try_migrate(self, to_xcm_version: XcmVersion) -> Result<Option<Self::MigratedData>, ()> {
+			if !self.needs_migration(to_xcm_version) {
+				return Ok(None)
+			}
+
+			let RemoteLockedNonFungibleRecord { owner, locker } = self;
+
+			let Ok(owner) = owner.into_version(to_xcm_version) else { return Err(()) };
+			let Ok(locker) = locker.into_version(to_xcm_version) else { return Err(()) };
+
+			Ok(Some(RemoteLockedNonFungibleRecord { owner, locker }))
+		}
+	}
+
 	/// Implementation of `NeedsMigration` for `AuthorizedAliases` data.
 	impl<M: Get<u32>, T: Config> NeedsMigration
 		for (&VersionedLocation, AuthorizedAliasesEntry<TicketOf<T>, M>, PhantomData<T>)
@@ -366,6 +435,79 @@ pub mod data {
 				weight.saturating_accrue(T::DbWeight::get().writes(1));
 			}
 
+			// check and migrate `LockedAssets`
+			let locked_assets_to_migrate = LockedAssets::<T>::iter().filter_map(|(id, data)| {
+				match data.try_migrate(required_xcm_version) {
+					Ok(Some(new_data)) => Some((id, new_data)),
+					Ok(None) => None,
+					Err(_) => {
+						tracing::error!(
+							target: LOG_TARGET,
+							?id,
+							?required_xcm_version,
+							"`LockedAssets` cannot be migrated!"
+						);
+						None
+					},
+				}
+			});
+			for (id, new_data) in locked_assets_to_migrate {
+				tracing::info!(
+					target: LOG_TARGET,
+					account_id = ?id,
+					?new_data,
+					"Migrating `LockedAssets`"
+				);
+				LockedAssets::<T>::insert(id, new_data);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+
+			// check and migrate `RemoteLockedNonFungibles` - key and data at once
+			let remote_locked_non_fungibles_to_migrate = RemoteLockedNonFungibles::<T>::iter()
+				.filter(|(key, data)| {
+					key.needs_migration(required_xcm_version) ||
+						data.needs_migration(required_xcm_version)
+				})
+				.collect::<Vec<_>>();
+			for (old_key, data) in remote_locked_non_fungibles_to_migrate {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				let (Ok(new_key), Ok(new_data)) = (
+					old_key.clone().try_migrate(required_xcm_version),
+					data.clone().try_migrate(required_xcm_version),
+				) else {
+					tracing::error!(
+						target: LOG_TARGET,
+						?old_key,
+						?required_xcm_version,
+						"`RemoteLockedNonFungibles` cannot be migrated!"
+					);
+					continue;
+				};
+				let new_key = new_key.unwrap_or_else(|| old_key.clone());
+				let new_data = new_data.unwrap_or(data);
+				// make sure, that we don't override accidentally other data
+				if new_key != old_key && RemoteLockedNonFungibles::<T>::contains_key(&new_key) {
+					tracing::error!(
+						target: LOG_TARGET,
+						?old_key,
+						?new_key,
+						"`RemoteLockedNonFungibles` already contains data for a `new_key`!"
+					);
+					continue;
+				}
+
+				tracing::info!(
+					target: LOG_TARGET,
+					?old_key,
+					?new_key,
+					?new_data,
+					"Migrating `RemoteLockedNonFungibles`"
+				);
+				RemoteLockedNonFungibles::<T>::remove(&old_key);
+				RemoteLockedNonFungibles::<T>::insert(&new_key, new_data);
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+			}
+
 			// check and migrate `AuthorizedAliases`
 			let aliases_to_migrate = AuthorizedAliases::<T>::iter().filter_map(|(id, data)| {
 				weight.saturating_accrue(T::DbWeight::get().reads(1));
@@ -18,28 +18,29 @@ pub use core::cell::RefCell;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		fungible::HoldConsideration, AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Equals,
-		Everything, EverythingBut, Footprint, Nothing,
+		fungible::HoldConsideration, AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64,
+		Contains, ContainsPair, Equals, Everything, EverythingBut, Footprint, Nothing,
 	},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
+use pallet_nfts::PalletFeatures;
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use polkadot_runtime_parachains::origin;
 use sp_core::H256;
 use sp_runtime::{
-	traits::{Convert, IdentityLookup},
-	AccountId32, BuildStorage,
+	traits::{Convert, IdentityLookup, TryConvertInto, Verify},
+	AccountId32, BuildStorage, MultiSignature,
 };
 use xcm::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, Case, ChildParachainAsNative, ChildParachainConvertsVia,
-	ChildSystemParachainAsSuperuser, DescribeAllTerminal, EnsureDecodableXcm, FixedRateOfFungible,
-	FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter,
-	HashedDescription, IsConcrete, MatchedConvertedConcreteId, NoChecking, SendXcmFeeToAccount,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-	XcmFeeManagerFromComponents,
+	AllowTopLevelPaidExecutionFrom, AsPrefixedGeneralIndex, Case, ChildParachainAsNative,
+	ChildParachainConvertsVia, ChildSystemParachainAsSuperuser, DescribeAllTerminal,
+	EnsureDecodableXcm, FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor,
+	FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete, MatchedConvertedConcreteId,
+	NoChecking, SendXcmFeeToAccount, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, StartsWith, TakeWeightCredit, XcmFeeManagerFromComponents,
 };
 use xcm_executor::{
 	traits::{Identity, JustTry},
@@ -148,6 +149,8 @@ construct_runtime!(
 		ParasOrigin: origin,
 		XcmPallet: pallet_xcm,
 		TestNotifier: pallet_test_notifier,
+		AssetsFreezer: pallet_assets_freezer,
+		Nfts: pallet_nfts,
 	}
 );
 
@@ -284,6 +287,7 @@ impl pallet_balances::Config for Test {
 	type Balance = Balance;
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -313,7 +317,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
-	type Freezer = ();
+	type Freezer = AssetsFreezer;
 	type WeightInfo = ();
 	type CallbackHandle = ();
 	type Extra = ();
@@ -322,6 +326,45 @@ impl pallet_assets::Config for Test {
 	type BenchmarkHelper = XcmBenchmarkHelper;
 }
 
+impl pallet_assets_freezer::Config for Test {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub storage NftsFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Locker = ();
+	type CollectionDeposit = ConstU128<2>;
+	type ItemDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type AttributeDepositBase = ConstU128<1>;
+	type DepositPerByte = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type Features = NftsFeatures;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = <MultiSignature as Verify>::Signer;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type BlockNumberProvider = System;
+}
+
 // This child parachain is a system parachain trusted to teleport native token.
 pub const SOME_SYSTEM_PARA: u32 = 1001;
 
@@ -346,6 +389,9 @@ pub const OTHER_PARA_ID: u32 = 2009;
 // This child parachain is used for filtered/disallowed assets.
 pub const FILTERED_PARA_ID: u32 = 2010;
 
+// This child parachain is trusted to lock assets on behalf of our accounts.
+pub const TRUSTED_LOCKER_PARA_ID: u32 = 2011;
+
 parameter_types! {
 	pub const RelayLocation: Location = Here.into_location();
 	pub const NativeAsset: Asset = Asset {
@@ -411,6 +457,11 @@ parameter_types! {
 			[Parachain(FILTERED_PARA_ID)],
 		)),
 	};
+	pub TrustedLockerLocation: Location = Location::new(
+		0,
+		[Parachain(TRUSTED_LOCKER_PARA_ID)]
+	);
+	pub NftsLocation: Location = Location::new(0, [PalletInstance(42)]);
 	pub const AnyNetwork: Option<NetworkId> = None;
 	pub UniversalLocation: InteriorLocation = GlobalConsensus(ByGenesis([0; 32])).into();
 	pub UnitWeightCost: u64 = 1_000;
@@ -432,6 +483,30 @@ pub type ForeignAssetsConvertedConcreteId = MatchedConvertedConcreteId<
 	JustTry,
 >;
 
+pub type NftsConvertedConcreteId = MatchedConvertedConcreteId<
+	u32,
+	u32,
+	StartsWith<NftsLocation>,
+	AsPrefixedGeneralIndex<NftsLocation, u32, TryConvertInto>,
+	TryConvertInto,
+>;
+
+parameter_types! {
+	pub const XcmLockFreezeId: RuntimeFreezeReason =
+		RuntimeFreezeReason::XcmPallet(pallet_xcm::FreezeReason::XcmLock);
+}
+
+/// Locks the relay chain currency with [`XcmPallet`], foreign assets with [`AssetsFreezer`] and
+/// NFTs with [`Nfts`].
+pub type TestAssetLocker = pallet_xcm::AssetLockAdapter<
+	Test,
+	AssetsFreezer,
+	ForeignAssetsConvertedConcreteId,
+	XcmLockFreezeId,
+	Nfts,
+	NftsConvertedConcreteId,
+>;
+
 pub type AssetTransactors = (
 	FungibleAdapter<Balances, IsConcrete<RelayLocation>, SovereignAccountOf, AccountId, ()>,
 	FungiblesAdapter<
@@ -470,6 +545,14 @@ parameter_types! {
 
 pub const XCM_FEES_NOT_WAIVED_USER_ACCOUNT: [u8; 32] = [37u8; 32];
 
+/// Trusts `TrustedLockerLocation` to have locked any asset.
+pub struct TrustedLocker;
+impl ContainsPair<Location, Asset> for TrustedLocker {
+	fn contains(locker: &Location, _: &Asset) -> bool {
+		locker == &TrustedLockerLocation::get()
+	}
+}
+
 pub struct XcmFeesNotWaivedLocations;
 impl Contains<Location> for XcmFeesNotWaivedLocations {
 	fn contains(location: &Location) -> bool {
@@ -541,6 +624,7 @@ pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, A
 
 parameter_types! {
 	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 4;
+	pub static TrappedAssetsExpiry: Option<u64> = None;
 	pub const AuthorizeAliasHoldReason: RuntimeHoldReason = RuntimeHoldReason::XcmPallet(pallet_xcm::HoldReason::AuthorizeAlias);
 }
 
@@ -575,13 +659,15 @@ impl pallet_xcm::Config for Test {
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type AdvertisedXcmVersion = AdvertisedXcmVersion;
 	type AdminOrigin = EnsureRoot<AccountId>;
-	type TrustedLockers = ();
+	type TrustedLockers = TrustedLocker;
 	type SovereignAccountOf = AccountId32Aliases<(), AccountId32>;
 	type Currency = Balances;
 	type CurrencyMatcher = IsConcrete<RelayLocation>;
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = TrappedAssetsExpiry;
+	type TrappedAssetsBeneficiary = pallet_xcm::TrappedAssetsToOrigin;
 	type WeightInfo = TestWeightInfo;
 	type AuthorizedAliasConsideration =
 		HoldConsideration<AccountId, Balances, AuthorizeAliasHoldReason, ConvertDeposit>;
@@ -0,0 +1,242 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{
+	mock::*,
+	pallet::{LockedAssets, RemoteLockedNonFungibles},
+	tests::{ALICE, BOB, INITIAL_BALANCE},
+	RemoteLockedNonFungibleRecord,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::{
+		fungibles::InspectFreeze,
+		nonfungibles_v2::{Create, Mutate},
+	},
+};
+use xcm::{prelude::*, VersionedAsset};
+use xcm_executor::traits::{AssetLock, Enact, LockError};
+
+const COLLECTION: u32 = 0;
+const ITEM: u32 = 1;
+
+fn alice() -> Location {
+	AccountId32 { network: None, id: ALICE.into() }.into()
+}
+
+fn unlocker() -> Location {
+	Location::new(0, [Parachain(OTHER_PARA_ID)])
+}
+
+fn foreign_asset_id() -> Location {
+	Location::new(0, [Parachain(FOREIGN_ASSET_RESERVE_PARA_ID), FOREIGN_ASSET_INNER_JUNCTION])
+}
+
+fn foreign_asset(amount: u128) -> Asset {
+	(foreign_asset_id(), amount).into()
+}
+
+fn nft() -> Asset {
+	let collection = NftsLocation::get().appended_with(GeneralIndex(COLLECTION.into())).unwrap();
+	(collection, AssetInstance::Index(ITEM.into())).into()
+}
+
+/// Creates the foreign asset with `amount` of it owned by `ALICE`.
+fn mint_foreign_asset(amount: u128) {
+	assert_ok!(AssetsPallet::force_create(
+		RuntimeOrigin::root(),
+		foreign_asset_id(),
+		ALICE,
+		true,
+		1
+	));
+	assert_ok!(AssetsPallet::mint(RuntimeOrigin::signed(ALICE), foreign_asset_id(), ALICE, amount));
+}
+
+/// Creates the NFT returned by [`nft`], owned by `ALICE`.
+fn mint_nft() {
+	let collection =
+		<Nfts as Create<_, _>>::create_collection(&ALICE, &ALICE, &Default::default()).unwrap();
+	assert_eq!(collection, COLLECTION);
+	assert_ok!(<Nfts as Mutate<_, _>>::mint_into(
+		&COLLECTION,
+		&ITEM,
+		&ALICE,
+		&Default::default(),
+		true
+	));
+}
+
+fn frozen() -> u128 {
+	<AssetsFreezer as InspectFreeze<_>>::balance_frozen(
+		foreign_asset_id(),
+		&XcmLockFreezeId::get(),
+		&ALICE,
+	)
+}
+
+fn lock(unlocker: Location, asset: Asset) -> Result<(), LockError> {
+	TestAssetLocker::prepare_lock(unlocker, asset, alice())?.enact()
+}
+
+fn unlock(unlocker: Location, asset: Asset) -> Result<(), LockError> {
+	TestAssetLocker::prepare_unlock(unlocker, asset, alice())?.enact()
+}
+
+#[test]
+fn lock_and_unlock_fungibles_works() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		mint_foreign_asset(100);
+		let other_unlocker = Location::new(0, [Parachain(USDT_PARA_ID)]);
+
+		// Can't lock more than owned.
+		assert_eq!(lock(unlocker(), foreign_asset(101)), Err(LockError::AssetNotOwned));
+
+		assert_ok!(lock(unlocker(), foreign_asset(30)));
+		assert_eq!(frozen(), 30);
+		// Locking again for the same unlocker raises the lock to the larger amount.
+		assert_ok!(lock(unlocker(), foreign_asset(50)));
+		assert_eq!(frozen(), 50);
+		// Locks of several unlockers overlap.
+		assert_ok!(lock(other_unlocker.clone(), foreign_asset(40)));
+		assert_eq!(frozen(), 50);
+		assert_eq!(LockedAssets::<Test>::get(&ALICE).unwrap().len(), 2);
+
+		// The frozen amount can't be transferred.
+		assert_noop!(
+			AssetsPallet::transfer(RuntimeOrigin::signed(ALICE), foreign_asset_id(), BOB, 60),
+			pallet_assets::Error::<Test>::BalanceLow
+		);
+
+		// Only locked amounts can be unlocked.
+		assert_eq!(unlock(other_unlocker.clone(), foreign_asset(41)), Err(LockError::NotLocked));
+		assert_eq!(
+			unlock(Location::new(0, [Parachain(1)]), foreign_asset(1)),
+			Err(LockError::NotLocked)
+		);
+
+		assert_ok!(unlock(unlocker(), foreign_asset(20)));
+		assert_eq!(frozen(), 40);
+		assert_ok!(unlock(other_unlocker, foreign_asset(40)));
+		assert_eq!(frozen(), 30);
+		assert_ok!(unlock(unlocker(), foreign_asset(30)));
+		assert_eq!(frozen(), 0);
+		assert_eq!(LockedAssets::<Test>::get(&ALICE), None);
+
+		assert_ok!(AssetsPallet::transfer(
+			RuntimeOrigin::signed(ALICE),
+			foreign_asset_id(),
+			BOB,
+			60
+		));
+	});
+}
+
+#[test]
+fn lock_and_unlock_non_fungible_works() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)])
+		.execute_with(|| {
+			// Can't lock an item which doesn't exist.
+			assert_eq!(lock(unlocker(), nft()), Err(LockError::AssetNotOwned));
+			mint_nft();
+
+			assert_ok!(lock(unlocker(), nft()));
+			assert_eq!(LockedAssets::<Test>::get(&ALICE).unwrap().len(), 1);
+			// An item can only be locked once.
+			assert_eq!(lock(Location::new(0, [Parachain(1)]), nft()), Err(LockError::WouldClobber));
+
+			// The locked item can't be transferred.
+			assert_noop!(
+				Nfts::transfer(RuntimeOrigin::signed(ALICE), COLLECTION, ITEM, BOB),
+				pallet_nfts::Error::<Test>::ItemLocked
+			);
+
+			// Only the unlocker can unlock the item.
+			assert_eq!(unlock(Location::new(0, [Parachain(1)]), nft()), Err(LockError::NotLocked));
+			assert_ok!(unlock(unlocker(), nft()));
+			assert_eq!(LockedAssets::<Test>::get(&ALICE), None);
+			assert_eq!(unlock(unlocker(), nft()), Err(LockError::NotLocked));
+
+			assert_ok!(Nfts::transfer(RuntimeOrigin::signed(ALICE), COLLECTION, ITEM, BOB));
+			// Items owned by others can't be locked.
+			assert_eq!(lock(unlocker(), nft()), Err(LockError::AssetNotOwned));
+		});
+}
+
+#[test]
+fn lock_unknown_asset_fails() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let unknown_nft: Asset =
+			(Location::new(0, [PalletInstance(1), GeneralIndex(0)]), AssetInstance::Index(0))
+				.into();
+		assert_eq!(lock(unlocker(), unknown_nft), Err(LockError::UnknownAsset));
+	});
+}
+
+#[test]
+fn note_and_reduce_unlockable_works() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let locker = TrustedLockerLocation::get();
+		let key = (XCM_VERSION, ALICE, VersionedAsset::from(nft()));
+
+		// Only trusted lockers can note locks.
+		assert_eq!(
+			TestAssetLocker::note_unlockable(unlocker(), nft(), alice()),
+			Err(LockError::NotTrusted)
+		);
+
+		assert_ok!(TestAssetLocker::note_unlockable(locker.clone(), nft(), alice()));
+		assert_eq!(
+			RemoteLockedNonFungibles::<Test>::get(&key),
+			Some(RemoteLockedNonFungibleRecord {
+				owner: alice().into(),
+				locker: locker.clone().into(),
+			})
+		);
+		// Noting the same lock again is fine.
+		assert_ok!(TestAssetLocker::note_unlockable(locker.clone(), nft(), alice()));
+
+		assert_eq!(
+			TestAssetLocker::prepare_reduce_unlockable(unlocker(), nft(), alice()).err(),
+			Some(LockError::WouldClobber)
+		);
+		assert_ok!(TestAssetLocker::prepare_reduce_unlockable(locker.clone(), nft(), alice())
+			.and_then(|ticket| ticket.enact()));
+		assert_eq!(RemoteLockedNonFungibles::<Test>::get(&key), None);
+		assert_eq!(
+			TestAssetLocker::prepare_reduce_unlockable(locker.clone(), nft(), alice()).err(),
+			Some(LockError::NotLocked)
+		);
+
+		// Fungible locks are noted and reduced by amount.
+		assert_ok!(TestAssetLocker::note_unlockable(locker.clone(), foreign_asset(50), alice()));
+		assert_ok!(TestAssetLocker::prepare_reduce_unlockable(
+			locker.clone(),
+			foreign_asset(20),
+			alice()
+		)
+		.and_then(|ticket| ticket.enact()));
+		assert_eq!(
+			TestAssetLocker::prepare_reduce_unlockable(locker.clone(), foreign_asset(31), alice())
+				.err(),
+			Some(LockError::NotEnoughLocked)
+		);
+		assert_ok!(TestAssetLocker::prepare_reduce_unlockable(locker, foreign_asset(30), alice())
+			.and_then(|ticket| ticket.enact()));
+	});
+}
@@ -61,6 +61,8 @@ fn limited_teleport_assets_works() {
 			weight_limit,
 		));
 		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -73,6 +75,7 @@ fn limited_teleport_assets_works() {
 						assets: AllCounted(1).into(),
 						beneficiary: expected_beneficiary
 					},
+					SetTopic(message_id),
 				]),
 			)]
 		);
@@ -165,6 +168,8 @@ fn reserve_transfer_assets_with_paid_router_works() {
 		);
 
 		let dest_para: Location = Parachain(paid_para_id).into();
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -174,6 +179,7 @@ fn reserve_transfer_assets_with_paid_router_works() {
 					ClearOrigin,
 					buy_execution((Parent, SEND_AMOUNT)),
 					DepositAsset { assets: AllCounted(1).into(), beneficiary: dest.clone() },
+					SetTopic(message_id),
 				]),
 			)]
 		);
@@ -304,6 +310,8 @@ fn local_asset_reserve_and_local_fee_reserve_call<Call>(
 		// Destination account (parachain account) has amount
 		let para_acc: AccountId = ParaId::from(OTHER_PARA_ID).into_account_truncating();
 		assert_eq!(Balances::free_balance(para_acc), INITIAL_BALANCE + SEND_AMOUNT);
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -316,6 +324,7 @@ fn local_asset_reserve_and_local_fee_reserve_call<Call>(
 						assets: AllCounted(1).into(),
 						beneficiary: expected_beneficiary.clone()
 					},
+					SetTopic(message_id),
 				]),
 			)]
 		);
@@ -475,6 +484,8 @@ fn destination_asset_reserve_and_local_fee_reserve_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(foreign_asset_id_location), expected_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -487,6 +498,7 @@ fn destination_asset_reserve_and_local_fee_reserve_call<Call>(
 					WithdrawAsset(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary: beneficiary.clone() },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -758,6 +770,8 @@ fn local_asset_reserve_and_destination_fee_reserve_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(usdc_id_location), expected_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -772,6 +786,7 @@ fn local_asset_reserve_and_destination_fee_reserve_call<Call>(
 					ReserveAssetDeposited(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -916,6 +931,8 @@ fn destination_asset_reserve_and_destination_fee_reserve_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(foreign_asset_id_location), expected_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -925,6 +942,7 @@ fn destination_asset_reserve_and_destination_fee_reserve_call<Call>(
 					ClearOrigin,
 					buy_limited_execution(expected_assets.get(0).unwrap().clone(), Unlimited),
 					DepositAsset { assets: AllCounted(1).into(), beneficiary: beneficiary.clone() },
+					SetTopic(message_id),
 				]),
 			)]
 		);
@@ -1595,6 +1613,8 @@ fn local_asset_reserve_and_teleported_fee_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(usdt_id_location), expected_usdt_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -1608,6 +1628,7 @@ fn local_asset_reserve_and_teleported_fee_call<Call>(
 					ReserveAssetDeposited(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -1776,6 +1797,8 @@ fn destination_asset_reserve_and_teleported_fee_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(foreign_asset_id_location), expected_bla_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -1788,6 +1811,7 @@ fn destination_asset_reserve_and_teleported_fee_call<Call>(
 					WithdrawAsset(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -2213,6 +2237,8 @@ fn teleport_asset_using_local_fee_reserve_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(usdt_id_location), expected_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -2225,6 +2251,7 @@ fn teleport_asset_using_local_fee_reserve_call<Call>(
 					ReceiveTeleportedAsset(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -2403,6 +2430,8 @@ fn teleported_asset_using_destination_reserve_fee_call<Call>(
 		assert_eq!(AssetsPallet::active_issuance(foreign_asset_id_location), expected_bla_issuance);
 
 		// Verify sent XCM program
+		let message_id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
 		assert_eq!(
 			sent_xcm(),
 			vec![(
@@ -2415,6 +2444,7 @@ fn teleported_asset_using_destination_reserve_fee_call<Call>(
 					ReceiveTeleportedAsset(expected_asset.into()),
 					ClearOrigin,
 					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+					SetTopic(message_id),
 				])
 			)]
 		);
@@ -16,6 +16,7 @@
 
 #![cfg(test)]
 
+pub(crate) mod asset_lock;
 pub(crate) mod assets_transfer;
 
 use crate::{
@@ -24,10 +25,11 @@ use crate::{
 	mock::*,
 	pallet::{LockedFungibles, RemoteLockedFungibles, SupportedVersion},
 	xcm_helpers::find_xcm_sent_message_id,
-	AssetTraps, AuthorizedAliasers, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
-	LatestVersionedLocation, MaxAuthorizedAliases, Pallet, Queries, QueryStatus, RecordedXcm,
-	RemoteLockedFungibleRecord, ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage,
-	VersionNotifiers, VersionNotifyTargets, WeightInfo,
+	AssetTrapRecords, AssetTraps, AuthorizedAliasers, Config, CurrentMigration, Error,
+	ExecuteControllerWeightInfo, LatestVersionedLocation, MaxAuthorizedAliases, MaxMessageStatuses,
+	MaxTrackedMessages, Pallet, Queries, QueryStatus, RecordedXcm, RemoteLockedFungibleRecord,
+	ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
+	VersionNotifyTargets, WeightInfo,
 };
 use bounded_collections::BoundedVec;
 use frame_support::{
@@ -43,9 +45,16 @@ use sp_runtime::{
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{
+		DropAssets, MessageStatus, Properties, QueryHandler, QueryResponseStatus,
+		RecordMessageStatus, ShouldExecute,
+	},
 	XcmExecutor,
 };
+use xcm_runtime_apis::{
+	message_status::Error as MessageStatusError,
+	trapped_assets::{TrappedAssets, TrappedAssetsPage},
+};
 use xcm_simulator::fake_message_hash;
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
@@ -321,11 +330,14 @@ fn send_works() {
 			versioned_dest,
 			versioned_message
 		));
+		let id =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
+		// The message gets a topic appended.
 		let sent_message = Xcm(Some(DescendOrigin(sender.clone().try_into().unwrap()))
 			.into_iter()
 			.chain(message.0.clone().into_iter())
+			.chain(Some(SetTopic(id)))
 			.collect());
-		let id = fake_message_hash(&sent_message);
 		assert_eq!(sent_xcm(), vec![(Here.into(), sent_message)]);
 		assert_eq!(
 			last_event(),
@@ -718,6 +730,173 @@ fn claim_assets_works() {
 	});
 }
 
+/// All the assets trapped, optionally only the ones trapped for `origin`.
+fn trapped_assets(origin: Option<Location>) -> Vec<TrappedAssets<u64>> {
+	let page = XcmPallet::trapped_assets(origin.map(Into::into), 0, u32::MAX).unwrap();
+	assert_eq!(page.next, None);
+	page.trapped
+}
+
+#[test]
+fn trapped_assets_are_deposited_to_fallback_beneficiary_after_expiry() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let trapping_program =
+			Xcm::<RuntimeCall>::builder_unsafe().withdraw_asset((Here, SEND_AMOUNT)).build();
+		assert_ok!(XcmPallet::execute(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(VersionedXcm::from(trapping_program)),
+			BaseXcmWeight::get() * 2,
+		));
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+
+		let source: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let versioned_assets = VersionedAssets::from(Assets::from((Here, SEND_AMOUNT)));
+		let hash = BlakeTwo256::hash_of(&(source.clone(), versioned_assets.clone()));
+		let trapped = TrappedAssets {
+			origin: source.clone().into(),
+			assets: versioned_assets.clone(),
+			trapped_at: 1,
+			expires_at: None,
+		};
+		// Without an expiry the assets stay trapped.
+		assert_eq!(trapped_assets(None), vec![trapped.clone()]);
+		XcmPallet::on_idle(100, Weight::MAX);
+		assert_eq!(AssetTraps::<Test>::get(hash), 1);
+
+		TrappedAssetsExpiry::set(Some(10));
+		assert_eq!(
+			trapped_assets(Some(source.clone())),
+			vec![TrappedAssets { expires_at: Some(11), ..trapped }]
+		);
+		let other: Location = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+		assert_eq!(trapped_assets(Some(other)), vec![]);
+
+		// Not expired yet.
+		System::set_block_number(10);
+		XcmPallet::on_idle(10, Weight::MAX);
+		assert_eq!(AssetTraps::<Test>::get(hash), 1);
+
+		System::set_block_number(11);
+		XcmPallet::on_idle(11, Weight::MAX);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE);
+		assert_eq!(AssetTraps::<Test>::get(hash), 0);
+		assert_eq!(trapped_assets(None), vec![]);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::TrappedAssetsExpired {
+				hash,
+				origin: source.clone(),
+				beneficiary: source,
+				assets: versioned_assets,
+			})
+		);
+	});
+}
+
+#[test]
+fn claimed_asset_traps_are_forgotten() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		TrappedAssetsExpiry::set(Some(10));
+		// Trap the same assets twice.
+		for block in [1, 5] {
+			System::set_block_number(block);
+			assert_ok!(XcmPallet::execute(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(
+					Xcm::<RuntimeCall>::builder_unsafe()
+						.withdraw_asset((Here, SEND_AMOUNT))
+						.build()
+				)),
+				BaseXcmWeight::get() * 2,
+			));
+		}
+		assert_eq!(AssetTrapRecords::<Test>::iter().count(), 2);
+
+		// Claiming forgets the oldest trap.
+		assert_ok!(XcmPallet::claim_assets(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(VersionedAssets::from(Assets::from((Here, SEND_AMOUNT)))),
+			Box::new(VersionedLocation::from(Location::from(AccountId32 {
+				network: None,
+				id: ALICE.clone().into()
+			}))),
+		));
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		let trapped = trapped_assets(None);
+		assert_eq!(trapped.len(), 1);
+		assert_eq!((trapped[0].trapped_at, trapped[0].expires_at), (5, Some(15)));
+
+		// Only the remaining trap expires.
+		System::set_block_number(15);
+		XcmPallet::on_idle(15, Weight::MAX);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE);
+		assert_eq!(AssetTraps::<Test>::iter().count(), 0);
+		assert_eq!(AssetTrapRecords::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn failed_asset_trap_expiry_is_forgotten() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		TrappedAssetsExpiry::set(Some(10));
+		let source: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		// No transactor matches this asset, so it cannot be deposited when expiring.
+		let assets = Assets::from((GeneralIndex(42), SEND_AMOUNT));
+		let context = XcmContext { origin: Some(source.clone()), message_id: [0; 32], topic: None };
+		XcmPallet::drop_assets(&source, assets.clone().into(), &context);
+		let hash = BlakeTwo256::hash_of(&(source.clone(), VersionedAssets::from(assets)));
+		assert_eq!(trapped_assets(None).len(), 1);
+
+		System::set_block_number(11);
+		XcmPallet::on_idle(11, Weight::MAX);
+		assert!(matches!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::TrappedAssetsExpiryFailed { hash: h, .. })
+				if h == hash
+		));
+		// The assets stay claimable, but are no longer listed nor retried.
+		assert_eq!(AssetTraps::<Test>::get(hash), 1);
+		assert_eq!(AssetTrapRecords::<Test>::iter().count(), 0);
+		assert_eq!(trapped_assets(None), vec![]);
+	});
+}
+
+#[test]
+fn trapped_assets_are_paginated() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(XcmPallet::execute(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(
+					Xcm::<RuntimeCall>::builder_unsafe()
+						.withdraw_asset((Here, SEND_AMOUNT))
+						.build()
+				)),
+				BaseXcmWeight::get() * 2,
+			));
+		}
+		let trapped = trapped_assets(None);
+		assert_eq!(trapped.len(), 3);
+
+		assert_eq!(
+			XcmPallet::trapped_assets(None, 0, 2),
+			Ok(TrappedAssetsPage { trapped: trapped[..2].to_vec(), next: Some(2) })
+		);
+		assert_eq!(
+			XcmPallet::trapped_assets(None, 2, 2),
+			Ok(TrappedAssetsPage { trapped: trapped[2..].to_vec(), next: None })
+		);
+		assert_eq!(
+			XcmPallet::trapped_assets(None, 3, 2),
+			Ok(TrappedAssetsPage { trapped: vec![], next: None })
+		);
+	});
+}
+
 /// Test failure to complete execution reverts intermediate side-effects.
 ///
 /// XCM program will withdraw and deposit some assets, then fail execution of a further withdraw.
@@ -1785,3 +1964,121 @@ fn query_weight_to_asset_fee_noop() {
 		.unwrap());
 	})
 }
+
+/// Test that the lifecycle of the sent and processed messages is recorded by their topic.
+#[test]
+fn message_status_is_recorded_by_topic() {
+	let balances = vec![
+		(ALICE, INITIAL_BALANCE),
+		(ParaId::from(OTHER_PARA_ID).into_account_truncating(), INITIAL_BALANCE),
+	];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		// A sent message gets a topic.
+		assert_ok!(XcmPallet::send(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(RelayLocation::get().into()),
+			Box::new(VersionedXcm::from(Xcm(vec![ClearOrigin]))),
+		));
+		let topic =
+			find_xcm_sent_message_id::<Test>(all_events()).expect("Missing XcmPallet::Sent event");
+		assert_eq!(
+			XcmPallet::message_status(topic),
+			Ok(vec![(1, MessageStatus::Sent { destination: RelayLocation::get() })])
+		);
+
+		let beneficiary: Location = AccountId32 { network: None, id: BOB.into() }.into();
+		let execute = |message: Xcm<RuntimeCall>| {
+			let mut hash = fake_message_hash(&message);
+			XcmExecutor::<XcmConfig>::prepare_and_execute(
+				Parachain(OTHER_PARA_ID),
+				message,
+				&mut hash,
+				BaseXcmWeight::get() * 4,
+				Weight::zero(),
+			);
+			hash
+		};
+
+		// A successfully executed message.
+		let executed_topic = [1; 32];
+		execute(Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			DepositAsset { assets: AllCounted(1).into(), beneficiary: beneficiary.clone() },
+			SetTopic(executed_topic),
+		]));
+		assert_eq!(
+			XcmPallet::message_status(executed_topic),
+			Ok(vec![(1, MessageStatus::Executed)])
+		);
+
+		// A message failing to execute.
+		let failed_topic = [2; 32];
+		execute(Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			Trap(1),
+			SetTopic(failed_topic),
+		]));
+		assert_eq!(
+			XcmPallet::message_status(failed_topic),
+			Ok(vec![(1, MessageStatus::Failed { index: 2, error: XcmError::Trap(1) })])
+		);
+
+		// A message rejected by the barrier is not executed, so it is not recorded.
+		let rejected_topic = [3; 32];
+		execute(Xcm(vec![ClearOrigin, SetTopic(rejected_topic)]));
+		assert_eq!(
+			XcmPallet::message_status(rejected_topic),
+			Err(MessageStatusError::UnknownMessage)
+		);
+
+		// A message without a topic is not recorded.
+		let id = execute(Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			DepositAsset { assets: AllCounted(1).into(), beneficiary: beneficiary.clone() },
+		]));
+		assert_eq!(XcmPallet::message_status(id), Err(MessageStatusError::UnknownMessage));
+
+		assert_eq!(XcmPallet::message_status([4; 32]), Err(MessageStatusError::UnknownMessage));
+	});
+}
+
+/// Test that only the last `MaxTrackedMessages` messages are tracked, each with up to
+/// `MaxMessageStatuses` steps.
+#[test]
+fn message_statuses_are_pruned() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let topic = |index: u32| BlakeTwo256::hash_of(&index).0;
+
+		// The first and the latest steps are kept.
+		for index in 0..=MaxMessageStatuses::get() {
+			System::set_block_number(index.into());
+			XcmPallet::record_message_status(
+				topic(0),
+				MessageStatus::Exported { nonce: index.into() },
+			);
+		}
+		let statuses = XcmPallet::message_status(topic(0)).unwrap();
+		assert_eq!(statuses.len(), MaxMessageStatuses::get() as usize);
+		assert_eq!(statuses.first(), Some(&(0, MessageStatus::Exported { nonce: 0 })));
+		assert_eq!(
+			statuses.last(),
+			Some(&(
+				MaxMessageStatuses::get().into(),
+				MessageStatus::Exported { nonce: MaxMessageStatuses::get().into() }
+			))
+		);
+
+		// The oldest message is pruned once there are too many.
+		for index in 1..MaxTrackedMessages::get() {
+			XcmPallet::record_message_status(topic(index), MessageStatus::Executed);
+		}
+		assert!(XcmPallet::message_status(topic(0)).is_ok());
+		XcmPallet::record_message_status(topic(MaxTrackedMessages::get()), MessageStatus::Executed);
+		assert_eq!(XcmPallet::message_status(topic(0)), Err(MessageStatusError::UnknownMessage));
+		assert!(XcmPallet::message_status(topic(1)).is_ok());
+		assert!(XcmPallet::message_status(topic(MaxTrackedMessages::get())).is_ok());
+	});
+}
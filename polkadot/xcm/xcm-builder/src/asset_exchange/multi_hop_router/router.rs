@@ -0,0 +1,309 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Multi-hop swap router.
+
+extern crate alloc;
+use alloc::{vec, vec::Vec};
+use core::{fmt::Debug, marker::PhantomData};
+use frame_support::{
+	traits::{tokens::fungibles, Get},
+	weights::Weight,
+};
+use pallet_asset_conversion::{QuotePrice, SwapCredit};
+use sp_runtime::DispatchError;
+
+/// A wrapper around [`pallet_asset_conversion::SwapCredit`] and
+/// [`pallet_asset_conversion::QuotePrice`] that routes swaps between two assets over the best
+/// path of pools.
+///
+/// When asked to swap or quote between two assets, the router considers the direct pool and every
+/// path of at most `MaxHops` swaps going through the assets of `Intermediates`, and uses the path
+/// that yields the most of the wanted asset, or costs the least of the given one. Swaps with an
+/// explicit path of more than two assets are passed to `AssetConversion` unchanged.
+///
+/// It can be used wherever `AssetConversion` is, e.g. in
+/// [`SingleAssetExchangeAdapter`](crate::SingleAssetExchangeAdapter) to handle `ExchangeAsset`
+/// between two assets without a common pool, or in fee traders swapping the first asset of the
+/// payment, to pay fees in assets that aren't paired with the native one.
+///
+/// Every candidate path is quoted, reading the reserves of its pools. The number of candidate
+/// paths grows quickly with the length of `Intermediates` and `MaxHops`, both should be kept small,
+/// typically the native asset and a couple of widely paired assets with `MaxHops` of two or three.
+/// The length of the swapped paths is also bounded by the maximum path length of
+/// `AssetConversion`, so `MaxHops` should be lower than it to quote the paths that can be swapped.
+///
+/// The search isn't part of the benchmarked weights of swaps over a single pool: users of the
+/// router should add [`Self::search_weight`] to the weights of the operations going through it,
+/// e.g. the `ExchangeAsset` instruction or fee payment.
+///
+/// Generic parameters:
+/// - `AssetConversion`: the swap and quote implementation, usually `pallet-asset-conversion`.
+/// - `Fungibles`: the fungibles implementation the credits of `AssetConversion` are for.
+/// - `Intermediates`: the assets that swaps may go through.
+/// - `MaxHops`: the maximum number of swaps in a path.
+pub struct MultiHopSwapRouter<AssetConversion, Fungibles, Intermediates, MaxHops>(
+	PhantomData<(AssetConversion, Fungibles, Intermediates, MaxHops)>,
+);
+
+impl<AssetConversion, Fungibles, Intermediates, MaxHops>
+	MultiHopSwapRouter<AssetConversion, Fungibles, Intermediates, MaxHops>
+where
+	AssetConversion: QuotePrice,
+	AssetConversion::AssetKind: Clone + PartialEq + Debug,
+	Intermediates: Get<Vec<AssetConversion::AssetKind>>,
+	MaxHops: Get<u32>,
+{
+	/// Finds the path of at most `max_hops` swaps yielding the most of `to` for exactly `amount`
+	/// of `from`, returning it together with the resulting amount of `to`.
+	///
+	/// If `include_fee` is set, the quotes include the fees of the pools.
+	pub fn best_path_exact_in(
+		from: AssetConversion::AssetKind,
+		to: AssetConversion::AssetKind,
+		amount: AssetConversion::Balance,
+		max_hops: u32,
+		include_fee: bool,
+	) -> Option<(Vec<AssetConversion::AssetKind>, AssetConversion::Balance)> {
+		let mut best = None;
+		search(
+			&mut vec![from],
+			&to,
+			amount,
+			max_hops,
+			&Intermediates::get(),
+			&|asset1, asset2, amount| {
+				AssetConversion::quote_price_exact_tokens_for_tokens(
+					asset1.clone(),
+					asset2.clone(),
+					amount,
+					include_fee,
+				)
+			},
+			&|new, best| new > best,
+			&mut best,
+		);
+		best
+	}
+
+	/// Finds the path of at most `max_hops` swaps costing the least of `from` to obtain exactly
+	/// `amount` of `to`, returning it together with the required amount of `from`.
+	///
+	/// If `include_fee` is set, the quotes include the fees of the pools.
+	pub fn best_path_exact_out(
+		from: AssetConversion::AssetKind,
+		to: AssetConversion::AssetKind,
+		amount: AssetConversion::Balance,
+		max_hops: u32,
+		include_fee: bool,
+	) -> Option<(Vec<AssetConversion::AssetKind>, AssetConversion::Balance)> {
+		// Search backwards, from the wanted asset to the given one.
+		let mut best = None;
+		search(
+			&mut vec![to],
+			&from,
+			amount,
+			max_hops,
+			&Intermediates::get(),
+			&|asset2, asset1, amount| {
+				AssetConversion::quote_price_tokens_for_exact_tokens(
+					asset1.clone(),
+					asset2.clone(),
+					amount,
+					include_fee,
+				)
+			},
+			&|new, best| new < best,
+			&mut best,
+		);
+		best.map(|(mut path, amount)| {
+			path.reverse();
+			(path, amount)
+		})
+	}
+
+	/// The maximum number of quotes made to find the best path, reached when there's a pool
+	/// between every pair of assets.
+	pub fn max_quotes() -> u32 {
+		max_quotes(MaxHops::get(), Intermediates::get().len() as u32)
+	}
+
+	/// The worst-case weight of finding the best path, given the weight of a single `quote`.
+	///
+	/// A quote of `pallet-asset-conversion` reads the pool and the balances of both its assets.
+	pub fn search_weight(quote: Weight) -> Weight {
+		quote.saturating_mul(Self::max_quotes().into())
+	}
+
+	/// Replaces a path of two assets by the path of at most `max_hops` swaps found by `find`, if
+	/// any.
+	fn route(
+		path: Vec<AssetConversion::AssetKind>,
+		max_hops: u32,
+		find: impl FnOnce(
+			AssetConversion::AssetKind,
+			AssetConversion::AssetKind,
+			u32,
+		) -> Option<(Vec<AssetConversion::AssetKind>, AssetConversion::Balance)>,
+	) -> Vec<AssetConversion::AssetKind> {
+		let [from, to] = &path[..] else { return path };
+		match find(from.clone(), to.clone(), max_hops) {
+			Some((route, _)) => {
+				tracing::trace!(
+					target: "xcm::MultiHopSwapRouter::route",
+					?route, "Routing swap",
+				);
+				route
+			},
+			// Let `AssetConversion` report the error.
+			None => path,
+		}
+	}
+}
+
+impl<AssetConversion, Fungibles, Intermediates, MaxHops> QuotePrice
+	for MultiHopSwapRouter<AssetConversion, Fungibles, Intermediates, MaxHops>
+where
+	AssetConversion: QuotePrice,
+	AssetConversion::AssetKind: Clone + PartialEq + Debug,
+	Intermediates: Get<Vec<AssetConversion::AssetKind>>,
+	MaxHops: Get<u32>,
+{
+	type Balance = AssetConversion::Balance;
+	type AssetKind = AssetConversion::AssetKind;
+
+	fn quote_price_tokens_for_exact_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		include_fee: bool,
+	) -> Option<Self::Balance> {
+		Self::best_path_exact_out(asset1, asset2, amount, MaxHops::get(), include_fee)
+			.map(|(_, amount)| amount)
+	}
+
+	fn quote_price_exact_tokens_for_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		include_fee: bool,
+	) -> Option<Self::Balance> {
+		Self::best_path_exact_in(asset1, asset2, amount, MaxHops::get(), include_fee)
+			.map(|(_, amount)| amount)
+	}
+}
+
+impl<AccountId, AssetConversion, Fungibles, Intermediates, MaxHops> SwapCredit<AccountId>
+	for MultiHopSwapRouter<AssetConversion, Fungibles, Intermediates, MaxHops>
+where
+	AssetConversion: SwapCredit<
+			AccountId,
+			Balance = Fungibles::Balance,
+			AssetKind = Fungibles::AssetId,
+			Credit = fungibles::Credit<AccountId, Fungibles>,
+		> + QuotePrice<Balance = Fungibles::Balance, AssetKind = Fungibles::AssetId>,
+	Fungibles: fungibles::Balanced<AccountId>,
+	Intermediates: Get<Vec<Fungibles::AssetId>>,
+	MaxHops: Get<u32>,
+{
+	type Balance = Fungibles::Balance;
+	type AssetKind = Fungibles::AssetId;
+	type Credit = fungibles::Credit<AccountId, Fungibles>;
+
+	fn max_path_len() -> u32 {
+		AssetConversion::max_path_len()
+	}
+
+	fn swap_exact_tokens_for_tokens(
+		path: Vec<Self::AssetKind>,
+		credit_in: Self::Credit,
+		amount_out_min: Option<Self::Balance>,
+	) -> Result<Self::Credit, (Self::Credit, DispatchError)> {
+		let max_hops = MaxHops::get().min(AssetConversion::max_path_len().saturating_sub(1));
+		let path = Self::route(path, max_hops, |from, to, max_hops| {
+			Self::best_path_exact_in(from, to, credit_in.peek(), max_hops, true)
+		});
+		AssetConversion::swap_exact_tokens_for_tokens(path, credit_in, amount_out_min)
+	}
+
+	fn swap_tokens_for_exact_tokens(
+		path: Vec<Self::AssetKind>,
+		credit_in: Self::Credit,
+		amount_out: Self::Balance,
+	) -> Result<(Self::Credit, Self::Credit), (Self::Credit, DispatchError)> {
+		let max_hops = MaxHops::get().min(AssetConversion::max_path_len().saturating_sub(1));
+		let path = Self::route(path, max_hops, |from, to, max_hops| {
+			Self::best_path_exact_out(from, to, amount_out, max_hops, true)
+		});
+		AssetConversion::swap_tokens_for_exact_tokens(path, credit_in, amount_out)
+	}
+}
+
+/// Depth-first search of the best path from the last asset of `path` to `to`, of at most
+/// `hops_left` swaps going through `intermediates`.
+///
+/// `quote` returns the amount reached by swapping `amount` along one hop, from its first asset to
+/// its second one, and `is_better` tells whether a newly reached amount beats the best one so far.
+/// On ties, the path found first is kept, the direct one being tried before the others.
+fn search<AssetKind: Clone + PartialEq, Balance: Copy>(
+	path: &mut Vec<AssetKind>,
+	to: &AssetKind,
+	amount: Balance,
+	hops_left: u32,
+	intermediates: &[AssetKind],
+	quote: &impl Fn(&AssetKind, &AssetKind, Balance) -> Option<Balance>,
+	is_better: &impl Fn(Balance, Balance) -> bool,
+	best: &mut Option<(Vec<AssetKind>, Balance)>,
+) {
+	let Some(current) = path.last().cloned() else { return };
+	if hops_left == 0 {
+		return
+	}
+
+	if let Some(reached) = quote(&current, to, amount) {
+		if best.as_ref().map_or(true, |(_, best)| is_better(reached, *best)) {
+			let mut found = path.clone();
+			found.push(to.clone());
+			*best = Some((found, reached));
+		}
+	}
+
+	for next in intermediates {
+		if next == to || path.contains(next) {
+			continue
+		}
+		let Some(reached) = quote(&current, next, amount) else { continue };
+		path.push(next.clone());
+		search(path, to, reached, hops_left - 1, intermediates, quote, is_better, best);
+		path.pop();
+	}
+}
+
+/// The maximum number of quotes made by [`search`] with `hops_left` swaps and `intermediates`
+/// unused intermediate assets, neither of them being the target asset.
+///
+/// Every step quotes the swap to the target asset and to every unused intermediate asset, then
+/// continues from each of them.
+fn max_quotes(hops_left: u32, intermediates: u32) -> u32 {
+	if hops_left == 0 {
+		return 0
+	}
+	let next = match intermediates {
+		0 => 0,
+		_ => max_quotes(hops_left - 1, intermediates - 1),
+	};
+	intermediates.saturating_mul(next.saturating_add(1)).saturating_add(1)
+}
@@ -0,0 +1,223 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the [`MultiHopSwapRouter`](crate::MultiHopSwapRouter) type.
+//!
+//! The mock has a pool between the native asset and each of the assets 1 and 2, but no pool
+//! between the assets 1 and 2.
+
+use super::super::single_asset_adapter::mock::*;
+use frame_support::traits::{fungible::NativeOrWithId, fungibles::Balanced, ConstU32};
+use pallet_asset_conversion::{QuotePrice, SwapCredit};
+use xcm::prelude::*;
+use xcm_executor::{traits::AssetExchange, AssetsInHolding};
+
+fn asset(index: u128, amount: u128) -> Asset {
+	([PalletInstance(2), GeneralIndex(index)], amount).into()
+}
+
+#[test]
+fn maximal_exchange_goes_through_native() {
+	new_test_ext().execute_with(|| {
+		// There's no direct pool.
+		assert!(PoolAssetsExchanger::exchange_asset(
+			None,
+			vec![asset(1, 1_000_000)].into(),
+			&vec![asset(2, 1)].into(),
+			true,
+		)
+		.is_err());
+
+		let assets = MultiHopExchanger::exchange_asset(
+			None,
+			vec![asset(1, 1_000_000)].into(),
+			&vec![asset(2, 1)].into(),
+			true, // Maximal
+		)
+		.unwrap();
+		// `1_000_000` of asset 1 are swapped for `493_579` of the native asset, which are swapped
+		// for `1_949_209` of asset 2.
+		assert_eq!(fungibles(&assets), vec![asset(2, 1_949_209)]);
+	});
+}
+
+#[test]
+fn minimal_exchange_goes_through_native() {
+	new_test_ext().execute_with(|| {
+		let assets = MultiHopExchanger::exchange_asset(
+			None,
+			vec![asset(1, 10_000_000)].into(),
+			&vec![asset(2, 1_000_000)].into(),
+			false, // Minimal
+		)
+		.unwrap();
+		// `1_000_000` of asset 2 cost `252_013` of the native asset, which cost `508_104` of
+		// asset 1.
+		assert_eq!(fungibles(&assets), vec![asset(1, 10_000_000 - 508_104), asset(2, 1_000_000)]);
+	});
+}
+
+#[test]
+fn quotes_match_exchanges() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			MultiHopExchanger::quote_exchange_price(
+				&vec![asset(1, 1_000_000)].into(),
+				&vec![asset(2, 1)].into(),
+				true,
+			),
+			Some(vec![asset(2, 1_949_209)].into()),
+		);
+		assert_eq!(
+			MultiHopExchanger::quote_exchange_price(
+				&vec![asset(1, 10_000_000)].into(),
+				&vec![asset(2, 1_000_000)].into(),
+				false,
+			),
+			Some(vec![asset(1, 508_104)].into()),
+		);
+	});
+}
+
+#[test]
+fn direct_pools_are_used() {
+	new_test_ext().execute_with(|| {
+		// Same results as the swaps over the direct pool with `SingleAssetExchangeAdapter`.
+		assert_eq!(
+			MultiHopExchanger::quote_exchange_price(
+				&vec![asset(1, 10_000_000)].into(),
+				&vec![(Here, 2_000_000).into()].into(),
+				true,
+			),
+			Some(vec![(Here, 4_533_054).into()].into()),
+		);
+		assert_eq!(
+			MultiHopRouter::best_path_exact_out(
+				NativeOrWithId::WithId(1),
+				NativeOrWithId::Native,
+				2_000_000,
+				2,
+				true,
+			),
+			Some((vec![NativeOrWithId::WithId(1), NativeOrWithId::Native], 4_179_205)),
+		);
+	});
+}
+
+#[test]
+fn paths_are_bounded_by_max_hops() {
+	new_test_ext().execute_with(|| {
+		type SingleHopRouter = crate::MultiHopSwapRouter<
+			AssetConversion,
+			NativeAndAssets,
+			SwapIntermediates,
+			ConstU32<1>,
+		>;
+
+		assert_eq!(
+			SingleHopRouter::quote_price_exact_tokens_for_tokens(
+				NativeOrWithId::WithId(1),
+				NativeOrWithId::WithId(2),
+				1_000_000,
+				true,
+			),
+			None,
+		);
+		assert_eq!(
+			MultiHopRouter::quote_price_exact_tokens_for_tokens(
+				NativeOrWithId::WithId(1),
+				NativeOrWithId::WithId(2),
+				1_000_000,
+				true,
+			),
+			Some(1_949_209),
+		);
+	});
+}
+
+#[test]
+fn explicit_paths_are_kept() {
+	new_test_ext().execute_with(|| {
+		let credit_in = NativeAndAssets::issue(NativeOrWithId::WithId(1), 1_000_000);
+		let credit_out = <MultiHopRouter as SwapCredit<AccountId>>::swap_exact_tokens_for_tokens(
+			vec![NativeOrWithId::WithId(1), NativeOrWithId::Native, NativeOrWithId::WithId(2)],
+			credit_in,
+			None,
+		)
+		.unwrap();
+		assert_eq!(credit_out.asset(), NativeOrWithId::WithId(2));
+		assert_eq!(credit_out.peek(), 1_949_209);
+
+		// A path without pools is passed on, for the swap to fail.
+		let credit_in = NativeAndAssets::issue(NativeOrWithId::WithId(1), 1_000_000);
+		assert!(<MultiHopRouter as SwapCredit<AccountId>>::swap_exact_tokens_for_tokens(
+			vec![NativeOrWithId::WithId(1), NativeOrWithId::WithId(3)],
+			credit_in,
+			None,
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn search_weight_covers_the_worst_case() {
+	thread_local! {
+		static QUOTES: core::cell::Cell<u32> = const { core::cell::Cell::new(0) };
+	}
+
+	/// Quotes every swap, as if there was a pool between every pair of assets.
+	struct AllPools;
+	impl QuotePrice for AllPools {
+		type Balance = u128;
+		type AssetKind = u32;
+
+		fn quote_price_exact_tokens_for_tokens(
+			_: u32,
+			_: u32,
+			amount: u128,
+			_: bool,
+		) -> Option<u128> {
+			QUOTES.with(|quotes| quotes.set(quotes.get() + 1));
+			Some(amount)
+		}
+
+		fn quote_price_tokens_for_exact_tokens(
+			asset1: u32,
+			asset2: u32,
+			amount: u128,
+			include_fee: bool,
+		) -> Option<u128> {
+			Self::quote_price_exact_tokens_for_tokens(asset1, asset2, amount, include_fee)
+		}
+	}
+
+	frame_support::parameter_types! {
+		pub Intermediates: Vec<u32> = vec![10, 11, 12];
+	}
+	type Router = crate::MultiHopSwapRouter<AllPools, (), Intermediates, ConstU32<3>>;
+
+	assert_eq!(Router::quote_price_exact_tokens_for_tokens(1, 2, 100, true), Some(100));
+	assert_eq!(QUOTES.with(|quotes| quotes.get()), Router::max_quotes());
+	assert_eq!(Router::max_quotes(), 25);
+	assert_eq!(
+		Router::search_weight(Weight::from_parts(1_000, 10)),
+		Weight::from_parts(25_000, 250)
+	);
+}
+
+fn fungibles(assets: &AssetsInHolding) -> Vec<Asset> {
+	assets.fungible_assets_iter().collect()
+}
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Mock to test [`SingleAssetExchangeAdapter`].
+//! Mock to test [`SingleAssetExchangeAdapter`] and
+//! [`MultiHopSwapRouter`](crate::MultiHopSwapRouter).
 
 use core::marker::PhantomData;
 use frame_support::{
@@ -66,6 +67,7 @@ impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	type AccountStore = System;
 	type ExistentialDeposit = ConstU128<1>;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 }
 
 pub type TrustBackedAssetsInstance = pallet_assets::Instance1;
@@ -204,16 +206,33 @@ impl MaybeEquivalence<Location, NativeOrWithId<u32>> for LocationToAssetId {
 	}
 }
 
+pub type PoolAssetsMatcher = MatchedConvertedConcreteId<
+	NativeOrWithId<u32>,
+	Balance,
+	(StartsWith<TrustBackedAssetsPalletLocation>, Equals<HereLocation>),
+	LocationToAssetId,
+	TryConvertInto,
+>;
+
 pub type PoolAssetsExchanger = crate::SingleAssetExchangeAdapter<
 	AssetConversion,
 	NativeAndAssets,
-	MatchedConvertedConcreteId<
-		NativeOrWithId<u32>,
-		Balance,
-		(StartsWith<TrustBackedAssetsPalletLocation>, Equals<HereLocation>),
-		LocationToAssetId,
-		TryConvertInto,
-	>,
+	PoolAssetsMatcher,
+	AccountId,
+>;
+
+parameter_types! {
+	pub SwapIntermediates: Vec<NativeOrWithId<u32>> = vec![NativeOrWithId::Native];
+}
+
+/// Routes swaps over paths of at most two pools, going through the native asset.
+pub type MultiHopRouter =
+	crate::MultiHopSwapRouter<AssetConversion, NativeAndAssets, SwapIntermediates, ConstU32<2>>;
+
+pub type MultiHopExchanger = crate::SingleAssetExchangeAdapter<
+	MultiHopRouter,
+	NativeAndAssets,
+	PoolAssetsMatcher,
 	AccountId,
 >;
 
@@ -325,6 +344,8 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = frame_support::traits::ConstU32<0>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type TrappedAssetsExpiry = ();
+	type TrappedAssetsBeneficiary = ();
 	// How to turn locations into accounts
 	type SovereignAccountOf = LocationToAccountId;
 	// A currency to pay for things and its matcher, we are using the relay token
@@ -374,6 +395,23 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			0,
 			owner,
 		));
+		assert_ok!(AssetsPallet::force_create(RuntimeOrigin::root(), 2, owner, false, 1,));
+		assert_ok!(AssetsPallet::mint_into(2, &owner, INITIAL_BALANCE,));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(owner),
+			Box::new(NativeOrWithId::Native),
+			Box::new(NativeOrWithId::WithId(2)),
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(owner),
+			Box::new(NativeOrWithId::Native),
+			Box::new(NativeOrWithId::WithId(2)),
+			50_000_000,
+			200_000_000,
+			0,
+			0,
+			owner,
+		));
 	});
 	ext
 }
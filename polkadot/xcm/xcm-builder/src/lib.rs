@@ -38,7 +38,7 @@ pub use asset_conversion::{
 };
 
 mod asset_exchange;
-pub use asset_exchange::SingleAssetExchangeAdapter;
+pub use asset_exchange::{MultiHopSwapRouter, SingleAssetExchangeAdapter};
 
 mod barriers;
 pub use barriers::{
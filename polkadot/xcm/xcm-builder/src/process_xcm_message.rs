@@ -25,18 +25,24 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_weights::{Weight, WeightMeter};
 use xcm::{prelude::*, MAX_XCM_DECODE_DEPTH};
+use xcm_executor::traits::{message_topic, MessageStatus, RecordMessageStatus};
 
 const LOG_TARGET: &str = "xcm::process-message";
 
 /// A message processor that delegates execution to an `XcmExecutor`.
-pub struct ProcessXcmMessage<MessageOrigin, XcmExecutor, Call>(
-	PhantomData<(MessageOrigin, XcmExecutor, Call)>,
+///
+/// The `Recorder` is expected to be the one recording the outcome of the execution, usually the
+/// `XcmEventEmitter` of the executor. Its worst-case weight is reserved for every message, and the
+/// messages with a topic that are too heavy for the remaining weight are recorded as overweight.
+pub struct ProcessXcmMessage<MessageOrigin, XcmExecutor, Call, Recorder = ()>(
+	PhantomData<(MessageOrigin, XcmExecutor, Call, Recorder)>,
 );
 impl<
 		MessageOrigin: Into<Location> + FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug,
 		XcmExecutor: ExecuteXcm<Call>,
 		Call: Decode + GetDispatchInfo,
-	> ProcessMessage for ProcessXcmMessage<MessageOrigin, XcmExecutor, Call>
+		Recorder: RecordMessageStatus,
+	> ProcessMessage for ProcessXcmMessage<MessageOrigin, XcmExecutor, Call, Recorder>
 {
 	type Origin = MessageOrigin;
 
@@ -68,6 +74,7 @@ impl<
 
 			ProcessMessageError::Unsupported
 		})?;
+		let topic = message_topic(&message);
 		let pre = XcmExecutor::prepare(message, Weight::MAX).map_err(|_| {
 			tracing::trace!(
 				target: LOG_TARGET,
@@ -76,8 +83,8 @@ impl<
 
 			ProcessMessageError::Unsupported
 		})?;
-		// The worst-case weight:
-		let required = pre.weight_of();
+		// The worst-case weight, including recording the outcome:
+		let required = pre.weight_of().saturating_add(Recorder::record_message_status_weight());
 		if !meter.can_consume(required) {
 			tracing::trace!(
 				target: LOG_TARGET,
@@ -85,6 +92,12 @@ impl<
 				meter.remaining(),
 			);
 
+			if let Some(topic) = topic {
+				if meter.can_consume(Recorder::record_message_status_weight()) {
+					meter
+						.consume(Recorder::record_message_status(topic, MessageStatus::Overweight));
+				}
+			}
 			return Err(ProcessMessageError::Overweight(required))
 		}
 
@@ -240,6 +253,67 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn process_message_overweight_is_recorded() {
+		std::thread_local! {
+			static RECORDED: core::cell::RefCell<Vec<(XcmHash, MessageStatus)>> =
+				core::cell::RefCell::new(Vec::new());
+		}
+		struct MockedRecorder;
+		impl RecordMessageStatus for MockedRecorder {
+			fn record_message_status(topic: XcmHash, status: MessageStatus) -> Weight {
+				RECORDED.with(|r| r.borrow_mut().push((topic, status)));
+				Self::record_message_status_weight()
+			}
+
+			fn record_message_status_weight() -> Weight {
+				10.into()
+			}
+		}
+
+		type Processor = ProcessXcmMessage<
+			Junction,
+			xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
+			RuntimeCall,
+			MockedRecorder,
+		>;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let topic = [7; 32];
+			let msg = VersionedXcm::V5(v5::Xcm::<RuntimeCall>(vec![
+				v5::Instruction::<RuntimeCall>::ClearOrigin,
+				v5::Instruction::<RuntimeCall>::SetTopic(topic),
+			]))
+			.encode();
+
+			// Not recorded if even the recording does not fit.
+			let meter = &mut WeightMeter::with_limit(5.into());
+			assert_err!(
+				Processor::process_message(&msg, ORIGIN, meter, &mut [0; 32]),
+				Overweight(2010.into())
+			);
+			assert_eq!(meter.consumed(), 0.into());
+			assert!(RECORDED.with(|r| r.borrow().is_empty()));
+
+			let meter = &mut WeightMeter::with_limit(2000.into());
+			assert_err!(
+				Processor::process_message(&msg, ORIGIN, meter, &mut [0; 32]),
+				Overweight(2010.into())
+			);
+			assert_eq!(meter.consumed(), 10.into());
+			assert_eq!(RECORDED.with(|r| r.take()), vec![(topic, MessageStatus::Overweight)]);
+
+			// Messages without a topic are not recorded.
+			let meter = &mut WeightMeter::with_limit(100.into());
+			assert_err!(
+				Processor::process_message(&v5_xcm(true).encode(), ORIGIN, meter, &mut [0; 32]),
+				Overweight(1010.into())
+			);
+			assert_eq!(meter.consumed(), 0.into());
+			assert!(RECORDED.with(|r| r.borrow().is_empty()));
+		});
+	}
+
 	fn v3_xcm(success: bool) -> VersionedXcm<RuntimeCall> {
 		let instr = if success {
 			v3::Instruction::<RuntimeCall>::ClearOrigin
@@ -0,0 +1,238 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A chain loaded from a state snapshot, and calls into its runtime.
+
+use crate::{metadata::Metadata, Error};
+use codec::{Decode, Encode};
+use remote_externalities::{Builder, Mode, OfflineConfig, SnapshotConfig};
+use sc_executor::WasmExecutor;
+use sp_core::{blake2_128, blake2_64, crypto::AccountId32, traits::CallContext, twox_128};
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+use sp_state_machine::{
+	backend::BackendRuntimeCode, Backend as _, InMemoryBackend, OverlayedChanges, StateMachine,
+};
+use sp_version::RuntimeVersion;
+use std::path::PathBuf;
+use xcm::latest::prelude::*;
+
+/// The block type used to load the snapshots.
+///
+/// Only the header of the block is decoded, which is the same for all relay and parachains.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// The host functions provided to the runtimes.
+type HostFunctions = (
+	sp_io::SubstrateHostFunctions,
+	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+);
+
+/// Configuration of a chain taking part in a dry-run.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainConfig {
+	/// The name of the chain in the report.
+	pub name: String,
+	/// The universal location of the chain, used to route messages between the chains.
+	pub universal_location: InteriorLocation,
+	/// The path of the `remote-externalities` state snapshot of the chain.
+	pub snapshot: PathBuf,
+	/// The asset in which execution and delivery fees are quoted, as seen by the chain.
+	#[serde(default = "Location::here")]
+	pub fee_asset: Location,
+	/// The accounts whose balances are reported.
+	#[serde(default)]
+	pub accounts: Vec<AccountId32>,
+}
+
+/// Executes the runtime calls of a [`Chain`] and reads its state.
+pub(crate) trait Runtime {
+	/// Calls `method` with the SCALE encoded `data`, keeping the state changes.
+	fn call(&mut self, method: &str, data: &[u8]) -> Result<Vec<u8>, String>;
+	/// Reads the value of `key` from the current state.
+	fn storage(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, String>;
+}
+
+/// The wasm runtime of a state snapshot.
+///
+/// All the state changes made by runtime calls are kept in an overlay on top of the snapshot, so
+/// every call sees the effects of the previous ones.
+struct SnapshotRuntime {
+	backend: InMemoryBackend<BlakeTwo256>,
+	overlay: OverlayedChanges<BlakeTwo256>,
+	executor: WasmExecutor<HostFunctions>,
+}
+
+impl Runtime for SnapshotRuntime {
+	fn call(&mut self, method: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+		let runtime_code = BackendRuntimeCode::new(&self.backend);
+		let runtime_code = runtime_code.runtime_code()?;
+
+		StateMachine::new(
+			&self.backend,
+			&mut self.overlay,
+			&self.executor,
+			method,
+			data,
+			&mut Default::default(),
+			&runtime_code,
+			CallContext::Offchain,
+		)
+		.execute()
+		.map_err(|e| e.to_string())
+	}
+
+	fn storage(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, String> {
+		match self.overlay.storage(key) {
+			Some(value) => Ok(value.map(|v| v.to_vec())),
+			None => self.backend.storage(key),
+		}
+	}
+}
+
+/// A chain taking part in a dry-run, usually loaded from a state snapshot.
+pub struct Chain {
+	config: ChainConfig,
+	runtime: Box<dyn Runtime>,
+	version: RuntimeVersion,
+	metadata: Metadata,
+}
+
+impl Chain {
+	/// Loads the chain described by `config`.
+	pub async fn load(config: ChainConfig) -> Result<Self, Error> {
+		let mut ext = Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new(config.snapshot.clone()),
+			}))
+			.build()
+			.await
+			.map_err(|error| Error::Snapshot { chain: config.name.clone(), error: error.into() })?;
+		let mut runtime = SnapshotRuntime {
+			backend: ext.as_backend(),
+			overlay: OverlayedChanges::default(),
+			executor: WasmExecutor::<HostFunctions>::builder()
+				.with_allow_missing_host_functions(true)
+				.build(),
+		};
+
+		let mut runtime_call = |method: &str, data: &[u8]| {
+			runtime.call(method, data).map_err(|error| Error::RuntimeCall {
+				chain: config.name.clone(),
+				method: method.into(),
+				error,
+			})
+		};
+		let version = runtime_call("Core_version", &[])?;
+		let version = RuntimeVersion::decode(&mut &version[..]).map_err(|error| Error::Decode {
+			chain: config.name.clone(),
+			method: "Core_version".into(),
+			error,
+		})?;
+		let metadata =
+			Metadata::decode(&runtime_call("Metadata_metadata_at_version", &15u32.encode())?)
+				.map_err(|reason| Error::UnsupportedRuntime {
+					chain: config.name.clone(),
+					reason,
+				})?;
+
+		log::info!(
+			target: crate::LOG_TARGET,
+			"Loaded {} ({} v{}) from {}",
+			config.name,
+			version.spec_name,
+			version.spec_version,
+			config.snapshot.display(),
+		);
+
+		Ok(Self::with_runtime(config, runtime, version, metadata))
+	}
+
+	/// Creates a chain calling into `runtime`.
+	pub(crate) fn with_runtime(
+		config: ChainConfig,
+		runtime: impl Runtime + 'static,
+		version: RuntimeVersion,
+		metadata: Metadata,
+	) -> Self {
+		Self { config, runtime: Box::new(runtime), version, metadata }
+	}
+
+	/// The name of the chain.
+	pub fn name(&self) -> &str {
+		&self.config.name
+	}
+
+	/// The configuration the chain was loaded with.
+	pub fn config(&self) -> &ChainConfig {
+		&self.config
+	}
+
+	pub(crate) fn metadata(&self) -> &Metadata {
+		&self.metadata
+	}
+
+	/// The version of the runtime API `name` implemented by the runtime, if any.
+	pub(crate) fn api_version(&self, name: &str) -> Option<u32> {
+		self.version.api_version(&blake2_64(name.as_bytes()))
+	}
+
+	/// Calls `method` of the runtime with the SCALE encoded `data`, keeping the state changes.
+	pub(crate) fn call(&mut self, method: &str, data: &[u8]) -> Result<Vec<u8>, Error> {
+		self.runtime.call(method, data).map_err(|error| Error::RuntimeCall {
+			chain: self.config.name.clone(),
+			method: method.into(),
+			error,
+		})
+	}
+
+	/// Reads the value of `key` from the current state.
+	pub(crate) fn storage(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+		self.runtime.storage(key).map_err(|error| Error::RuntimeCall {
+			chain: self.config.name.clone(),
+			method: "storage".into(),
+			error,
+		})
+	}
+
+	/// Reads the free, reserved and frozen balance of `who` from `frame_system::Account`.
+	pub(crate) fn balance(&mut self, who: &AccountId32) -> Result<(u128, u128, u128), Error> {
+		let Some(info) = self.storage(&account_key(who))? else { return Ok(Default::default()) };
+		// `AccountInfo { nonce, consumers, providers, sufficients, data }`, where `data` starts
+		// with the free, reserved and frozen balances.
+		let (_, _, _, _, free, reserved, frozen) =
+			<(u32, u32, u32, u32, u128, u128, u128)>::decode(&mut &info[..]).map_err(|error| {
+				Error::Decode {
+					chain: self.config.name.clone(),
+					method: "System::Account".into(),
+					error,
+				}
+			})?;
+
+		Ok((free, reserved, frozen))
+	}
+}
+
+/// The storage key of the `frame_system::Account` of `who`.
+pub(crate) fn account_key(who: &AccountId32) -> Vec<u8> {
+	[
+		&twox_128(b"System")[..],
+		&twox_128(b"Account")[..],
+		&blake2_128(who.as_ref())[..],
+		who.as_ref(),
+	]
+	.concat()
+}
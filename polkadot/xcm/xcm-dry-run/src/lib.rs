@@ -0,0 +1,69 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-hop XCM dry-running.
+//!
+//! The [`DryRunApi`](xcm_runtime_apis::dry_run::DryRunApi) only tells what happens on a single
+//! chain: the messages a call or an XCM program sends to other chains are returned, but not
+//! executed. This crate loads the runtimes and state of several chains from
+//! `remote-externalities` snapshots and follows those messages:
+//!
+//! 1. The call is dry-run on the origin chain with `DryRunApi::dry_run_call`.
+//! 2. Every forwarded message is dry-run with `DryRunApi::dry_run_xcm` on its destination, if the
+//!    destination is one of the loaded chains.
+//! 3. The messages forwarded by the destination are followed in the same way, until no message is
+//!    left or the maximum number of hops is reached.
+//!
+//! State changes of every dry-run are kept, so a message is executed on top of the effects of
+//! the messages that preceded it. The outcome of every execution, the fees and the balances of
+//! the accounts of interest before and after the run are collected into a [`Report`].
+//!
+//! The chains are expected to be FRAME based, to use 32 byte account ids, `u32` nonces and
+//! `u128` balances stored in `frame_system::Account`, and to expose metadata V15.
+
+mod chain;
+mod metadata;
+mod orchestrator;
+
+pub use chain::{Chain, ChainConfig};
+pub use orchestrator::{
+	AccountBalances, Balance, DryRunner, Forwarded, Report, Step, StepKind, DEFAULT_MAX_HOPS,
+};
+
+const LOG_TARGET: &str = "xcm::dry-run";
+
+/// Errors of the dry-run orchestration.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The state snapshot of a chain could not be loaded.
+	#[error("failed to load the state snapshot of {chain}: {error}")]
+	Snapshot { chain: String, error: String },
+	/// Calling into the runtime of a chain failed.
+	#[error("calling `{method}` on {chain} failed: {error}")]
+	RuntimeCall { chain: String, method: String, error: String },
+	/// The result of a runtime call could not be decoded.
+	#[error("failed to decode the result of `{method}` on {chain}: {error}")]
+	Decode { chain: String, method: String, error: codec::Error },
+	/// A runtime API returned an error.
+	#[error("`{method}` on {chain} returned an error: {error}")]
+	Api { chain: String, method: String, error: String },
+	/// The runtime of a chain doesn't provide what the orchestrator needs.
+	#[error("unsupported runtime of {chain}: {reason}")]
+	UnsupportedRuntime { chain: String, reason: String },
+	/// No chain with the given name is loaded.
+	#[error("unknown chain {0}")]
+	UnknownChain(String),
+}
@@ -0,0 +1,90 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry-run a call and the XCMs it sends across several chains, printing a JSON report.
+//!
+//! The chains are described in a JSON file holding a list of [`ChainConfig`]s, for example:
+//!
+//! ```json
+//! [
+//!   {
+//!     "name": "asset-hub",
+//!     "universalLocation": { "X2": [{ "GlobalConsensus": "Westend" }, { "Parachain": 1000 }] },
+//!     "snapshot": "asset-hub.snap",
+//!     "feeAsset": { "parents": 1, "interior": "Here" },
+//!     "accounts": ["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"]
+//!   },
+//!   {
+//!     "name": "westend",
+//!     "universalLocation": { "X1": [{ "GlobalConsensus": "Westend" }] },
+//!     "snapshot": "westend.snap"
+//!   }
+//! ]
+//! ```
+
+use clap::Parser;
+use std::path::PathBuf;
+use xcm_dry_run::{Chain, ChainConfig, DryRunner, DEFAULT_MAX_HOPS};
+
+#[derive(Debug, Parser)]
+struct Opt {
+	/// The JSON file describing the chains taking part in the dry-run.
+	#[arg(long)]
+	chains: PathBuf,
+
+	/// The name of the chain on which the call is dispatched.
+	#[arg(long)]
+	origin_chain: String,
+
+	/// The hex encoded `OriginCaller` dispatching the call.
+	#[arg(long)]
+	origin: String,
+
+	/// The hex encoded `RuntimeCall` to dry-run.
+	#[arg(long)]
+	call: String,
+
+	/// The maximum number of hops followed from the origin chain.
+	#[arg(long, default_value_t = DEFAULT_MAX_HOPS)]
+	max_hops: u32,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+	let Opt { chains, origin_chain, origin, call, max_hops } = Opt::parse();
+
+	let file = std::fs::File::open(&chains)
+		.map_err(|e| format!("Failed to open {}: {e}", chains.display()))?;
+	let configs: Vec<ChainConfig> = serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Failed to parse {}: {e}", chains.display()))?;
+	let origin = array_bytes::hex2bytes(&origin).map_err(|e| format!("Invalid origin: {e:?}"))?;
+	let call = array_bytes::hex2bytes(&call).map_err(|e| format!("Invalid call: {e:?}"))?;
+
+	let mut loaded = Vec::with_capacity(configs.len());
+	for config in configs {
+		loaded.push(Chain::load(config).await?);
+	}
+
+	let report =
+		DryRunner::new(loaded)
+			.with_max_hops(max_hops)
+			.run(&origin_chain, &origin, &call)?;
+	println!("{}", serde_json::to_string_pretty(&report)?);
+
+	Ok(())
+}
@@ -0,0 +1,233 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding of runtime specific types with the help of the runtime metadata.
+//!
+//! The dry-run APIs are generic over the `RuntimeEvent` of the chain, which isn't known to the
+//! orchestrator. The type registry of the metadata is used to name the events and to skip over
+//! their fields.
+
+use codec::{Compact, Decode, Input};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, TypeDefPrimitive};
+
+/// The parts of the metadata of a runtime needed to decode its events.
+pub(crate) struct Metadata {
+	types: PortableRegistry,
+	event_ty: u32,
+}
+
+impl Metadata {
+	/// Decodes the metadata returned by `Metadata_metadata_at_version(15)`.
+	pub(crate) fn decode(encoded: &[u8]) -> Result<Self, String> {
+		let metadata = Option::<Vec<u8>>::decode(&mut &encoded[..])
+			.map_err(|e| format!("invalid metadata: {e}"))?
+			.ok_or_else(|| "metadata V15 is not supported".to_string())?;
+		let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+			.map_err(|e| format!("invalid metadata: {e}"))?
+			.1;
+
+		let RuntimeMetadata::V15(metadata) = metadata else {
+			return Err("metadata V15 is required".into())
+		};
+
+		Ok(Self { types: metadata.types, event_ty: metadata.outer_enums.event_enum_ty.id })
+	}
+
+	/// The metadata of a runtime with the `RuntimeEvent` type `E`.
+	#[cfg(test)]
+	pub(crate) fn with_event<E: scale_info::TypeInfo + 'static>() -> Self {
+		let mut registry = scale_info::Registry::new();
+		let event_ty = registry.register_type(&scale_info::meta_type::<E>()).id;
+		Self { types: registry.into(), event_ty }
+	}
+
+	/// Decodes a `RuntimeEvent` from `input` and returns its name as `Pallet::Variant`.
+	pub(crate) fn decode_event(&self, input: &mut &[u8]) -> Result<String, codec::Error> {
+		let (pallet, fields) = self.decode_variant(self.event_ty, input)?;
+		let [field] = fields else {
+			self.skip_fields(fields.iter().map(|f| f.ty.id), input)?;
+			return Ok(pallet.to_string())
+		};
+
+		match self.resolve(field.ty.id)? {
+			TypeDef::Variant(_) => {
+				let (event, fields) = self.decode_variant(field.ty.id, input)?;
+				self.skip_fields(fields.iter().map(|f| f.ty.id), input)?;
+				Ok(format!("{pallet}::{event}"))
+			},
+			_ => {
+				self.skip(field.ty.id, input)?;
+				Ok(pallet.to_string())
+			},
+		}
+	}
+
+	fn resolve(&self, ty: u32) -> Result<&TypeDef<PortableForm>, codec::Error> {
+		self.types
+			.resolve(ty)
+			.map(|ty| &ty.type_def)
+			.ok_or_else(|| "type not found in the metadata".into())
+	}
+
+	/// Reads the index of a variant of the enum `ty`, returning its name and fields.
+	fn decode_variant(
+		&self,
+		ty: u32,
+		input: &mut &[u8],
+	) -> Result<(&str, &[scale_info::Field<PortableForm>]), codec::Error> {
+		let TypeDef::Variant(def) = self.resolve(ty)? else {
+			return Err("expected an enum".into())
+		};
+		let index = input.read_byte()?;
+		def.variants
+			.iter()
+			.find(|v| v.index == index)
+			.map(|v| (v.name.as_str(), &v.fields[..]))
+			.ok_or_else(|| "invalid enum variant index".into())
+	}
+
+	fn skip_fields(
+		&self,
+		fields: impl IntoIterator<Item = u32>,
+		input: &mut &[u8],
+	) -> Result<(), codec::Error> {
+		fields.into_iter().try_for_each(|ty| self.skip(ty, input))
+	}
+
+	/// Skips over a value of type `ty`.
+	fn skip(&self, ty: u32, input: &mut &[u8]) -> Result<(), codec::Error> {
+		match self.resolve(ty)? {
+			TypeDef::Composite(def) => self.skip_fields(def.fields.iter().map(|f| f.ty.id), input),
+			TypeDef::Variant(_) => {
+				let (_, fields) = self.decode_variant(ty, input)?;
+				self.skip_fields(fields.iter().map(|f| f.ty.id), input)
+			},
+			TypeDef::Sequence(def) => {
+				let len = Compact::<u32>::decode(input)?.0;
+				(0..len).try_for_each(|_| self.skip(def.type_param.id, input))
+			},
+			TypeDef::Array(def) =>
+				(0..def.len).try_for_each(|_| self.skip(def.type_param.id, input)),
+			TypeDef::Tuple(def) => self.skip_fields(def.fields.iter().map(|f| f.id), input),
+			TypeDef::Primitive(def) => match def {
+				TypeDefPrimitive::Str => {
+					let len = Compact::<u32>::decode(input)?.0;
+					advance(input, len as usize)
+				},
+				primitive => advance(input, primitive_size(primitive)),
+			},
+			// The compact encoding of a single field wrapper is the one of the wrapped integer.
+			TypeDef::Compact(_) => {
+				let mode = *input.first().ok_or("not enough data to skip")?;
+				let len = match mode & 0b11 {
+					0b00 => 1,
+					0b01 => 2,
+					0b10 => 4,
+					_ => (mode >> 2) as usize + 5,
+				};
+				advance(input, len)
+			},
+			TypeDef::BitSequence(def) => {
+				let TypeDef::Primitive(store) = self.resolve(def.bit_store_type.id)? else {
+					return Err("invalid bit sequence store type".into())
+				};
+				let bits = Compact::<u32>::decode(input)?.0 as usize;
+				let store_bits = primitive_size(store) * 8;
+				advance(input, bits.div_ceil(store_bits) * primitive_size(store))
+			},
+		}
+	}
+}
+
+/// The encoded size of a fixed size primitive.
+fn primitive_size(primitive: &TypeDefPrimitive) -> usize {
+	match primitive {
+		TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+		TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+		TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+		TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+		TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+		// Strings are length prefixed and handled by the caller.
+		TypeDefPrimitive::Str => 0,
+	}
+}
+
+fn advance(input: &mut &[u8], len: usize) -> Result<(), codec::Error> {
+	*input = input.get(len..).ok_or("not enough data to skip")?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use scale_info::TypeInfo;
+
+	#[derive(Encode, TypeInfo)]
+	enum PalletEvent {
+		Transferred { from: [u8; 32], amount: u128, memo: Vec<u8> },
+		Compacted(#[codec(compact)] u64, String),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum RuntimeEvent {
+		#[codec(index = 4)]
+		Balances(PalletEvent),
+		#[codec(index = 7)]
+		Unit,
+	}
+
+	fn metadata() -> Metadata {
+		Metadata::with_event::<RuntimeEvent>()
+	}
+
+	#[test]
+	fn decodes_and_skips_events() {
+		let metadata = metadata();
+		let events = (
+			RuntimeEvent::Balances(PalletEvent::Transferred {
+				from: [1; 32],
+				amount: 10,
+				memo: vec![1, 2, 3],
+			}),
+			RuntimeEvent::Unit,
+			RuntimeEvent::Balances(PalletEvent::Compacted(u64::MAX, "hello".into())),
+			42u8,
+		)
+			.encode();
+
+		let input = &mut &events[..];
+		assert_eq!(metadata.decode_event(input).unwrap(), "Balances::Transferred");
+		assert_eq!(metadata.decode_event(input).unwrap(), "Unit");
+		assert_eq!(metadata.decode_event(input).unwrap(), "Balances::Compacted");
+		assert_eq!(*input, &[42u8][..]);
+	}
+
+	#[test]
+	fn rejects_truncated_events() {
+		let metadata = metadata();
+		let event = RuntimeEvent::Balances(PalletEvent::Transferred {
+			from: [1; 32],
+			amount: 10,
+			memo: vec![1, 2, 3],
+		})
+		.encode();
+
+		assert!(metadata.decode_event(&mut &event[..event.len() - 1]).is_err());
+	}
+}
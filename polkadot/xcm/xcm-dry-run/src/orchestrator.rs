@@ -0,0 +1,716 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Following the messages of a dry-run across the loaded chains.
+
+use crate::{chain::Chain, metadata::Metadata, Error, LOG_TARGET};
+use codec::{Compact, Decode, Encode, Input};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use serde::Serialize;
+use sp_core::crypto::AccountId32;
+use std::collections::VecDeque;
+use xcm::{latest::prelude::*, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_runtime_apis::{dry_run, fees};
+
+/// The default maximum number of hops followed from the origin chain.
+pub const DEFAULT_MAX_HOPS: u32 = 8;
+
+/// The end-to-end report of a dry-run.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+	/// The executions, in the order they happened.
+	///
+	/// The first step is the dry-run of the call on the origin chain.
+	pub steps: Vec<Step>,
+	/// The balances of the configured accounts before and after the dry-run.
+	pub balances: Vec<AccountBalances>,
+}
+
+/// What was executed by a [`Step`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum StepKind {
+	/// The call dry-run on the origin chain.
+	Call,
+	/// A message forwarded by the step with index `parent`, executed with the given `origin`.
+	Xcm { origin: Location, parent: usize },
+}
+
+/// The execution of a call or a message on one of the chains.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Step {
+	/// The name of the chain.
+	pub chain: String,
+	/// The number of hops from the origin chain.
+	pub hop: u32,
+	/// What was executed.
+	#[serde(flatten)]
+	pub kind: StepKind,
+	/// Whether the execution succeeded.
+	pub success: bool,
+	/// The result of the call or the outcome of the message, as reported by the runtime.
+	pub outcome: String,
+	/// The weight used by the execution, if known.
+	pub weight: Option<Weight>,
+	/// The execution fee of a message, in the fee asset of the chain.
+	pub execution_fee: Option<Asset>,
+	/// The events emitted by the execution, as `Pallet::Event`.
+	pub events: Vec<String>,
+	/// The messages sent by the execution, grouped by destination.
+	pub forwarded: Vec<Forwarded>,
+}
+
+/// Messages sent to a destination.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Forwarded {
+	/// The destination, as seen by the sending chain.
+	pub destination: Location,
+	/// The name of the destination chain, if it is loaded.
+	pub chain: Option<String>,
+	/// The number of messages.
+	pub messages: usize,
+	/// The delivery fees of all the messages, if they could be queried.
+	pub delivery_fees: Option<Assets>,
+	/// The indices of the steps executing the messages on the destination.
+	///
+	/// Empty if the destination isn't loaded or the maximum number of hops was reached.
+	pub steps: Vec<usize>,
+}
+
+/// Balances of an account.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+	pub free: u128,
+	pub reserved: u128,
+	pub frozen: u128,
+}
+
+/// Balances of an account before and after a dry-run.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalances {
+	pub chain: String,
+	pub account: AccountId32,
+	pub before: Balance,
+	pub after: Balance,
+}
+
+/// The effects of a dry-run on a single chain.
+struct Execution {
+	success: bool,
+	outcome: String,
+	weight: Option<Weight>,
+	events: Vec<String>,
+	forwarded: Vec<(Location, Vec<VersionedXcm<()>>)>,
+}
+
+/// A forwarded message waiting to be dry-run on its destination.
+struct Pending {
+	chain: usize,
+	origin: Location,
+	message: VersionedXcm<()>,
+	hop: u32,
+	/// The index of the step that sent the message, and of the destination in its `forwarded`.
+	forwarded_by: (usize, usize),
+}
+
+/// Dry-runs calls and follows the sent messages across a set of chains.
+///
+/// The state changes of every dry-run are kept, so consecutive runs build on top of each other.
+pub struct DryRunner {
+	chains: Vec<Chain>,
+	max_hops: u32,
+}
+
+impl DryRunner {
+	/// Creates a new runner over `chains`.
+	pub fn new(chains: Vec<Chain>) -> Self {
+		Self { chains, max_hops: DEFAULT_MAX_HOPS }
+	}
+
+	/// Sets the maximum number of hops followed from the origin chain.
+	pub fn with_max_hops(mut self, max_hops: u32) -> Self {
+		self.max_hops = max_hops;
+		self
+	}
+
+	/// The loaded chains.
+	pub fn chains(&self) -> &[Chain] {
+		&self.chains
+	}
+
+	/// Dry-runs `call` dispatched with `origin` on the chain named `chain`, then follows the sent
+	/// messages.
+	///
+	/// `origin` and `call` are the SCALE encoded `OriginCaller` and `RuntimeCall` of the chain.
+	pub fn run(&mut self, chain: &str, origin: &[u8], call: &[u8]) -> Result<Report, Error> {
+		let origin_chain = self
+			.chains
+			.iter()
+			.position(|c| c.name() == chain)
+			.ok_or_else(|| Error::UnknownChain(chain.into()))?;
+
+		let before = self.balances()?;
+
+		let mut steps = Vec::new();
+		let mut pending = VecDeque::new();
+		let execution = dry_run_call(&mut self.chains[origin_chain], origin, call)?;
+		self.push_step(&mut steps, &mut pending, origin_chain, 0, StepKind::Call, execution);
+
+		while let Some(Pending { chain, origin, message, hop, forwarded_by }) = pending.pop_front()
+		{
+			let execution = dry_run_xcm(&mut self.chains[chain], &origin, &message)?;
+			let (parent, destination) = forwarded_by;
+			steps[parent].forwarded[destination].steps.push(steps.len());
+			self.push_step(
+				&mut steps,
+				&mut pending,
+				chain,
+				hop,
+				StepKind::Xcm { origin, parent },
+				execution,
+			);
+		}
+
+		let after = self.balances()?;
+		let balances = before
+			.into_iter()
+			.zip(after)
+			.map(|((chain, account, before), (_, _, after))| AccountBalances {
+				chain,
+				account,
+				before,
+				after,
+			})
+			.collect();
+
+		Ok(Report { steps, balances })
+	}
+
+	/// Records the step of `execution` on `chain`, and queues the messages it sent.
+	fn push_step(
+		&mut self,
+		steps: &mut Vec<Step>,
+		pending: &mut VecDeque<Pending>,
+		chain: usize,
+		hop: u32,
+		kind: StepKind,
+		execution: Execution,
+	) {
+		let context = self.chains[chain].config().universal_location.clone();
+		let step = steps.len();
+
+		let execution_fee = match (&kind, execution.weight) {
+			(StepKind::Xcm { .. }, Some(weight)) => {
+				let fee = query_execution_fee(&mut self.chains[chain], weight);
+				warn_on_error(&self.chains[chain], fee)
+			},
+			_ => None,
+		};
+
+		let mut forwarded = Vec::new();
+		for (destination, messages) in execution.forwarded {
+			let delivery_fees = messages
+				.iter()
+				.map(|message| query_delivery_fees(&mut self.chains[chain], &destination, message))
+				.collect::<Result<Vec<_>, _>>()
+				.map(|fees| {
+					let mut total = Assets::new();
+					fees.into_iter().flat_map(Assets::into_inner).for_each(|fee| total.push(fee));
+					total
+				});
+			let delivery_fees = warn_on_error(&self.chains[chain], delivery_fees);
+
+			let target = universal_destination(&context, &destination).and_then(|location| {
+				self.chains.iter().position(|c| c.config().universal_location == location)
+			});
+			match target {
+				Some(target) if hop < self.max_hops => {
+					let origin = context
+						.clone()
+						.relative_to(&self.chains[target].config().universal_location);
+					for message in messages.iter().cloned() {
+						pending.push_back(Pending {
+							chain: target,
+							origin: origin.clone(),
+							message,
+							hop: hop + 1,
+							forwarded_by: (step, forwarded.len()),
+						});
+					}
+				},
+				Some(_) => log::warn!(
+					target: LOG_TARGET,
+					"Not following messages from {} to {destination:?}, the maximum number of hops \
+					 was reached",
+					self.chains[chain].name(),
+				),
+				None => log::debug!(
+					target: LOG_TARGET,
+					"Not following messages from {} to {destination:?}, the destination isn't loaded",
+					self.chains[chain].name(),
+				),
+			}
+
+			forwarded.push(Forwarded {
+				destination,
+				chain: target.map(|target| self.chains[target].name().to_string()),
+				messages: messages.len(),
+				delivery_fees,
+				steps: Vec::new(),
+			});
+		}
+
+		steps.push(Step {
+			chain: self.chains[chain].name().to_string(),
+			hop,
+			kind,
+			success: execution.success,
+			outcome: execution.outcome,
+			weight: execution.weight,
+			execution_fee,
+			events: execution.events,
+			forwarded,
+		});
+	}
+
+	/// The balances of all the configured accounts.
+	fn balances(&mut self) -> Result<Vec<(String, AccountId32, Balance)>, Error> {
+		let mut balances = Vec::new();
+		for chain in &mut self.chains {
+			for account in chain.config().accounts.clone() {
+				let (free, reserved, frozen) = chain.balance(&account)?;
+				balances.push((
+					chain.name().to_string(),
+					account,
+					Balance { free, reserved, frozen },
+				));
+			}
+		}
+		Ok(balances)
+	}
+}
+
+/// The universal location of `destination`, as addressed from the universal location `context`.
+///
+/// Returns `None` if `destination` goes beyond the universal root.
+fn universal_destination(
+	context: &InteriorLocation,
+	destination: &Location,
+) -> Option<InteriorLocation> {
+	if destination.parent_count() as usize == context.len() {
+		return Some(destination.interior().clone())
+	}
+	context.clone().within_global(destination.clone()).ok()
+}
+
+fn warn_on_error<T>(chain: &Chain, result: Result<T, Error>) -> Option<T> {
+	result
+		.inspect_err(
+			|e| log::warn!(target: LOG_TARGET, "Failed to query fees on {}: {e}", chain.name()),
+		)
+		.ok()
+}
+
+fn dry_run_call(chain: &mut Chain, origin: &[u8], call: &[u8]) -> Result<Execution, Error> {
+	const METHOD: &str = "DryRunApi_dry_run_call";
+
+	let mut data = [origin, call].concat();
+	match chain.api_version("DryRunApi") {
+		None => return Err(unsupported(chain, "the `DryRunApi` is not implemented")),
+		Some(1) => {},
+		Some(_) => XCM_VERSION.encode_to(&mut data),
+	}
+
+	let result = chain.call(METHOD, &data)?;
+	decode_dry_run_result(chain, METHOD, &result, |input| {
+		let result = DispatchResultWithPostInfo::decode(input)?;
+		let events = decode_events(chain.metadata(), input)?;
+		// The local XCM is already part of the executed call, its effects are in the events.
+		let _local_xcm = Option::<VersionedXcm<()>>::decode(input)?;
+		let forwarded = decode_forwarded(input)?;
+
+		let weight = match &result {
+			Ok(info) => info.actual_weight,
+			Err(error) => error.post_info.actual_weight,
+		};
+		Ok(Execution {
+			success: result.is_ok(),
+			outcome: format!("{result:?}"),
+			weight,
+			events,
+			forwarded,
+		})
+	})
+}
+
+fn dry_run_xcm(
+	chain: &mut Chain,
+	origin: &Location,
+	message: &VersionedXcm<()>,
+) -> Result<Execution, Error> {
+	const METHOD: &str = "DryRunApi_dry_run_xcm";
+
+	if chain.api_version("DryRunApi").is_none() {
+		return Err(unsupported(chain, "the `DryRunApi` is not implemented"))
+	}
+
+	// `VersionedXcm<()>` and `VersionedXcm<RuntimeCall>` share the encoding, the calls of
+	// `Transact` are kept double encoded.
+	let data = (VersionedLocation::from(origin.clone()), message).encode();
+	let result = chain.call(METHOD, &data)?;
+	decode_dry_run_result(chain, METHOD, &result, |input| {
+		let outcome = Outcome::decode(input)?;
+		let events = decode_events(chain.metadata(), input)?;
+		let forwarded = decode_forwarded(input)?;
+
+		Ok(Execution {
+			success: matches!(outcome, Outcome::Complete { .. }),
+			outcome: format!("{outcome:?}"),
+			weight: Some(outcome.weight_used()),
+			events,
+			forwarded,
+		})
+	})
+}
+
+fn query_delivery_fees(
+	chain: &mut Chain,
+	destination: &Location,
+	message: &VersionedXcm<()>,
+) -> Result<Assets, Error> {
+	const METHOD: &str = "XcmPaymentApi_query_delivery_fees";
+
+	let mut data = (VersionedLocation::from(destination.clone()), message).encode();
+	match chain.api_version("XcmPaymentApi") {
+		None => return Err(unsupported(chain, "the `XcmPaymentApi` is not implemented")),
+		Some(1) => {},
+		Some(_) =>
+			VersionedAssetId::from(AssetId(chain.config().fee_asset.clone())).encode_to(&mut data),
+	}
+
+	let fees: VersionedAssets = query_fees(chain, METHOD, &data)?;
+	fees.try_into().map_err(|()| Error::Api {
+		chain: chain.name().into(),
+		method: METHOD.into(),
+		error: "unsupported XCM version of the fees".into(),
+	})
+}
+
+fn query_execution_fee(chain: &mut Chain, weight: Weight) -> Result<Asset, Error> {
+	let asset = AssetId(chain.config().fee_asset.clone());
+	let data = (weight, VersionedAssetId::from(asset.clone())).encode();
+	let amount: u128 = query_fees(chain, "XcmPaymentApi_query_weight_to_asset_fee", &data)?;
+	Ok((asset, amount).into())
+}
+
+/// Calls a method of the `XcmPaymentApi`.
+fn query_fees<T: Decode>(chain: &mut Chain, method: &str, data: &[u8]) -> Result<T, Error> {
+	let result = chain.call(method, data)?;
+	Result::<T, fees::Error>::decode(&mut &result[..])
+		.map_err(|error| Error::Decode {
+			chain: chain.name().into(),
+			method: method.into(),
+			error,
+		})?
+		.map_err(|error| Error::Api {
+			chain: chain.name().into(),
+			method: method.into(),
+			error: format!("{error:?}"),
+		})
+}
+
+/// Decodes the result of a method of the `DryRunApi`, with `decode` decoding the effects.
+fn decode_dry_run_result<T>(
+	chain: &Chain,
+	method: &str,
+	result: &[u8],
+	decode: impl FnOnce(&mut &[u8]) -> Result<T, codec::Error>,
+) -> Result<T, Error> {
+	let input = &mut &result[..];
+	let result = match input.read_byte() {
+		Ok(0) => decode(input).map(Ok),
+		Ok(1) => dry_run::Error::decode(input).map(Err),
+		Ok(_) => Err("invalid `Result` variant".into()),
+		Err(error) => Err(error),
+	};
+
+	result
+		.map_err(|error| Error::Decode {
+			chain: chain.name().into(),
+			method: method.into(),
+			error,
+		})?
+		.map_err(|error| Error::Api {
+			chain: chain.name().into(),
+			method: method.into(),
+			error: format!("{error:?}"),
+		})
+}
+
+fn decode_events(metadata: &Metadata, input: &mut &[u8]) -> Result<Vec<String>, codec::Error> {
+	let len = Compact::<u32>::decode(input)?.0;
+	(0..len).map(|_| metadata.decode_event(input)).collect()
+}
+
+fn decode_forwarded(
+	input: &mut &[u8],
+) -> Result<Vec<(Location, Vec<VersionedXcm<()>>)>, codec::Error> {
+	Vec::<(VersionedLocation, Vec<VersionedXcm<()>>)>::decode(input)?
+		.into_iter()
+		.map(|(destination, messages)| {
+			let destination = destination
+				.try_into()
+				.map_err(|()| "unsupported XCM version of the destination")?;
+			Ok((destination, messages))
+		})
+		.collect()
+}
+
+fn unsupported(chain: &Chain, reason: &str) -> Error {
+	Error::UnsupportedRuntime { chain: chain.name().into(), reason: reason.into() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain::{account_key, ChainConfig, Runtime};
+	use sp_core::blake2_64;
+	use sp_version::RuntimeVersion;
+	use std::collections::HashMap;
+
+	/// The amount credited by every executed message.
+	const AMOUNT: u128 = 1_000;
+	const DELIVERY_FEE: u128 = 10;
+	const EXECUTION_FEE: u128 = 100;
+
+	#[derive(Encode, scale_info::TypeInfo)]
+	enum BalancesEvent {
+		Burned { amount: u128 },
+		Minted { amount: u128 },
+	}
+
+	#[derive(Encode, scale_info::TypeInfo)]
+	enum RuntimeEvent {
+		#[codec(index = 10)]
+		Balances(BalancesEvent),
+	}
+
+	/// A runtime answering the dry-run and fee APIs with canned effects.
+	struct TestRuntime {
+		storage: HashMap<Vec<u8>, Vec<u8>>,
+		/// The messages sent by the next dry-run, subsequent dry-runs send nothing.
+		forwarded: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+		/// The account credited with [`AMOUNT`] by every executed message.
+		beneficiary: AccountId32,
+	}
+
+	impl TestRuntime {
+		fn credit(&mut self) {
+			let key = account_key(&self.beneficiary);
+			let free = self
+				.storage
+				.get(&key)
+				.map_or(0, |info| <(u32, u32, u32, u32, u128)>::decode(&mut &info[..]).unwrap().4);
+			let info = (0u32, 0u32, 1u32, 0u32, free + AMOUNT, 0u128, 0u128, 0u128);
+			self.storage.insert(key, info.encode());
+		}
+	}
+
+	impl Runtime for TestRuntime {
+		fn call(&mut self, method: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+			match method {
+				"DryRunApi_dry_run_call" => {
+					let forwarded_xcms = core::mem::take(&mut self.forwarded);
+					let effects = dry_run::CallDryRunEffects {
+						execution_result: Ok(Default::default()),
+						emitted_events: vec![RuntimeEvent::Balances(BalancesEvent::Burned {
+							amount: AMOUNT,
+						})],
+						local_xcm: None,
+						forwarded_xcms,
+					};
+					Ok(Ok::<_, dry_run::Error>(effects).encode())
+				},
+				"DryRunApi_dry_run_xcm" => {
+					<(VersionedLocation, VersionedXcm<()>)>::decode(&mut &data[..])
+						.map_err(|e| e.to_string())?;
+					self.credit();
+					let forwarded_xcms = core::mem::take(&mut self.forwarded);
+					let effects = dry_run::XcmDryRunEffects {
+						execution_result: Outcome::Complete { used: Weight::from_parts(1, 1) },
+						emitted_events: vec![RuntimeEvent::Balances(BalancesEvent::Minted {
+							amount: AMOUNT,
+						})],
+						forwarded_xcms,
+					};
+					Ok(Ok::<_, dry_run::Error>(effects).encode())
+				},
+				"XcmPaymentApi_query_delivery_fees" =>
+					Ok(Ok::<_, fees::Error>(VersionedAssets::from((Here, DELIVERY_FEE))).encode()),
+				"XcmPaymentApi_query_weight_to_asset_fee" =>
+					Ok(Ok::<_, fees::Error>(EXECUTION_FEE).encode()),
+				_ => Err(format!("unknown method {method}")),
+			}
+		}
+
+		fn storage(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, String> {
+			Ok(self.storage.get(key).cloned())
+		}
+	}
+
+	fn test_chain(
+		name: &str,
+		universal_location: InteriorLocation,
+		forwarded: Vec<(Location, Xcm<()>)>,
+	) -> Chain {
+		let account = AccountId32::new([1; 32]);
+		let config = ChainConfig {
+			name: name.into(),
+			universal_location,
+			snapshot: Default::default(),
+			fee_asset: Location::here(),
+			accounts: vec![account.clone()],
+		};
+		let runtime = TestRuntime {
+			storage: Default::default(),
+			forwarded: forwarded
+				.into_iter()
+				.map(|(destination, message)| {
+					(destination.into(), vec![VersionedXcm::from(message)])
+				})
+				.collect(),
+			beneficiary: account,
+		};
+		let version = RuntimeVersion {
+			apis: vec![(blake2_64(b"DryRunApi"), 2), (blake2_64(b"XcmPaymentApi"), 2)].into(),
+			..Default::default()
+		};
+		Chain::with_runtime(config, runtime, version, Metadata::with_event::<RuntimeEvent>())
+	}
+
+	fn message() -> Xcm<()> {
+		Xcm::builder_unsafe().clear_origin().build()
+	}
+
+	/// A relay chain whose call sends a message to Asset Hub, which sends a message back.
+	fn chains() -> Vec<Chain> {
+		let relay = [GlobalConsensus(Polkadot)].into();
+		vec![
+			test_chain("relay", relay, vec![(Parachain(1000).into(), message())]),
+			test_chain("asset-hub", asset_hub(), vec![(Location::parent(), message())]),
+		]
+	}
+
+	fn asset_hub() -> InteriorLocation {
+		[GlobalConsensus(Polkadot), Parachain(1000)].into()
+	}
+
+	#[test]
+	fn run_follows_messages_across_chains() {
+		let report = DryRunner::new(chains()).run("relay", &[], &[]).unwrap();
+
+		let steps = &report.steps;
+		assert_eq!(steps.len(), 3);
+
+		assert_eq!((steps[0].chain.as_str(), steps[0].hop), ("relay", 0));
+		assert!(matches!(steps[0].kind, StepKind::Call));
+		assert!(steps[0].success);
+		assert_eq!(steps[0].events, vec!["Balances::Burned".to_string()]);
+		assert_eq!(steps[0].execution_fee, None);
+		assert_eq!(steps[0].forwarded.len(), 1);
+		assert_eq!(steps[0].forwarded[0].chain.as_deref(), Some("asset-hub"));
+		assert_eq!(steps[0].forwarded[0].delivery_fees, Some((Here, DELIVERY_FEE).into()));
+		assert_eq!(steps[0].forwarded[0].steps, vec![1]);
+
+		assert_eq!((steps[1].chain.as_str(), steps[1].hop), ("asset-hub", 1));
+		assert!(
+			matches!(&steps[1].kind, StepKind::Xcm { origin, parent: 0 } if *origin == Location::parent())
+		);
+		assert!(steps[1].success);
+		assert_eq!(steps[1].events, vec!["Balances::Minted".to_string()]);
+		assert_eq!(steps[1].execution_fee, Some((Here, EXECUTION_FEE).into()));
+		assert_eq!(steps[1].forwarded[0].chain.as_deref(), Some("relay"));
+		assert_eq!(steps[1].forwarded[0].steps, vec![2]);
+
+		assert_eq!((steps[2].chain.as_str(), steps[2].hop), ("relay", 2));
+		assert!(matches!(
+			&steps[2].kind,
+			StepKind::Xcm { origin, parent: 1 } if *origin == Location::new(0, [Parachain(1000)])
+		));
+		assert!(steps[2].forwarded.is_empty());
+
+		// Every chain executed one message, the state changes are kept across the steps.
+		assert_eq!(report.balances.len(), 2);
+		for balances in &report.balances {
+			assert_eq!(balances.before, Balance::default());
+			assert_eq!(balances.after, Balance { free: AMOUNT, ..Default::default() });
+		}
+	}
+
+	#[test]
+	fn run_stops_at_max_hops() {
+		let report = DryRunner::new(chains()).with_max_hops(1).run("relay", &[], &[]).unwrap();
+
+		assert_eq!(report.steps.len(), 2);
+		let forwarded = &report.steps[1].forwarded[0];
+		assert_eq!(forwarded.chain.as_deref(), Some("relay"));
+		assert_eq!(forwarded.messages, 1);
+		assert!(forwarded.steps.is_empty());
+	}
+
+	#[test]
+	fn run_on_unknown_chain_fails() {
+		assert!(matches!(
+			DryRunner::new(chains()).run("unknown", &[], &[]),
+			Err(Error::UnknownChain(chain)) if chain == "unknown"
+		));
+	}
+
+	#[test]
+	fn universal_destination_works() {
+		assert_eq!(
+			universal_destination(&asset_hub(), &Location::parent()),
+			Some([GlobalConsensus(Polkadot)].into()),
+		);
+		assert_eq!(
+			universal_destination(&asset_hub(), &Location::new(1, [Parachain(2000)])),
+			Some([GlobalConsensus(Polkadot), Parachain(2000)].into()),
+		);
+		assert_eq!(
+			universal_destination(
+				&asset_hub(),
+				&Location::new(2, [GlobalConsensus(Kusama), Parachain(1000)])
+			),
+			Some([GlobalConsensus(Kusama), Parachain(1000)].into()),
+		);
+		assert_eq!(universal_destination(&asset_hub(), &Location::new(3, Here)), None);
+	}
+
+	#[test]
+	fn origin_on_destination_works() {
+		let relay: InteriorLocation = [GlobalConsensus(Polkadot)].into();
+		let para: InteriorLocation = [GlobalConsensus(Polkadot), Parachain(2000)].into();
+
+		assert_eq!(asset_hub().relative_to(&relay), Location::new(0, [Parachain(1000)]));
+		assert_eq!(asset_hub().relative_to(&para), Location::new(1, [Parachain(1000)]));
+		assert_eq!(relay.relative_to(&asset_hub()), Location::parent());
+	}
+}
@@ -33,14 +33,14 @@ use xcm::latest::{prelude::*, AssetTransferFilter};
 
 pub mod traits;
 use traits::{
-	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
-	DropAssets, Enact, EventEmitter, ExportXcm, FeeManager, FeeReason, HandleHrmpChannelAccepted,
-	HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest, OnResponse, ProcessTransaction,
-	Properties, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
-	XcmAssetTransfers,
+	message_topic, validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets,
+	ConvertOrigin, DropAssets, Enact, EventEmitter, ExportXcm, FeeManager, FeeReason,
+	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+	OnResponse, ProcessTransaction, Properties, ShouldExecute, TransactAsset,
+	VersionChangeNotifier, WeightBounds, WeightTrader, XcmAssetTransfers,
 };
 
-pub use traits::RecordXcm;
+pub use traits::{InstructionTrace, RecordXcm};
 
 mod assets;
 pub use assets::AssetsInHolding;
@@ -100,6 +100,8 @@ pub struct XcmExecutor<Config: config::Config> {
 	message_weight: Weight,
 	asset_claimer: Option<Location>,
 	already_paid_fees: bool,
+	/// Whether to record an [`InstructionTrace`] of every processed instruction.
+	trace: bool,
 	_config: PhantomData<Config>,
 }
 
@@ -264,7 +266,8 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 
 		// We only want to record under certain conditions (mainly only during dry-running),
 		// so as to not degrade regular performance.
-		if Config::XcmRecorder::should_record() {
+		let should_record = Config::XcmRecorder::should_record();
+		if should_record {
 			Config::XcmRecorder::record(message.clone().into());
 		}
 
@@ -286,13 +289,18 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			return Outcome::Incomplete {
 				used: xcm_weight, // Weight consumed before the error
 				error: InstructionError { index: 0, error: XcmError::Barrier }, // The error that occurred
-			};
+			}
 		}
 
 		*id = properties.message_id.unwrap_or(*id);
+		// The trailing `SetTopic` is either taken by the barrier as the message ID or left to be
+		// executed, which a failing instruction before it would prevent.
+		let topic = properties.message_id.or_else(|| message_topic(&message));
 
 		let mut vm = Self::new(origin, *id);
 		vm.message_weight = xcm_weight;
+		// Tracing is only enabled while recording, so regular execution doesn't pay for the read.
+		vm.trace = should_record && Config::XcmRecorder::should_trace();
 
 		while !message.0.is_empty() {
 			let result = vm.process(message);
@@ -307,7 +315,16 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			}
 		}
 
-		vm.post_process(xcm_weight)
+		let mut outcome = vm.post_process(xcm_weight);
+		if let Some(topic) = topic {
+			let record_weight = Config::XcmEventEmitter::on_process_outcome(&outcome, topic);
+			match &mut outcome {
+				Outcome::Complete { used } | Outcome::Incomplete { used, .. } =>
+					used.saturating_accrue(record_weight),
+				Outcome::Error(_) => {},
+			}
+		}
+		outcome
 	}
 
 	fn charge_fees(origin: impl Into<Location>, fees: Assets) -> XcmResult {
@@ -381,6 +398,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			message_weight: Weight::zero(),
 			asset_claimer: None,
 			already_paid_fees: false,
+			trace: false,
 			_config: PhantomData,
 		}
 	}
@@ -850,6 +868,10 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		for (i, mut instr) in xcm.0.into_iter().enumerate() {
 			match &mut result {
 				r @ Ok(()) => {
+					let traced = self.trace.then(|| {
+						let weight = Config::Weigher::instr_weight(&mut instr).unwrap_or_default();
+						(weight, Instruction::<()>::from(instr.clone()))
+					});
 					// Initialize the recursion count only the first time we hit this code in our
 					// potential recursive execution.
 					let inst_res = recursion_count::using_once(&mut 1, || {
@@ -873,6 +895,17 @@ impl<Config: config::Config> XcmExecutor<Config> {
 
 						self.process_instruction(instr)
 					});
+					if let Some((weight, instruction)) = traced {
+						Config::XcmRecorder::record_instruction(InstructionTrace {
+							index: i as u32,
+							instruction,
+							weight,
+							origin: self.origin_ref().cloned(),
+							holding: self.holding.clone().into(),
+							fees: self.fees.clone().into(),
+							error: inst_res.err(),
+						});
+					}
 					if let Err(error) = inst_res {
 						tracing::debug!(
 							target: "xcm::process",
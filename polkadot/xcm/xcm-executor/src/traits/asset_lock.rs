@@ -17,7 +17,7 @@
 use core::convert::Infallible;
 use xcm::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum LockError {
 	NotApplicable,
 	WouldClobber,
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use sp_weights::Weight;
 use xcm::{
-	latest::{Location, SendError, Xcm, XcmHash},
+	latest::{Location, Outcome, SendError, Xcm, XcmHash},
 	prelude::XcmError,
 };
 
@@ -59,6 +60,22 @@ pub trait EventEmitter {
 	/// - `error`: The error encountered while processing.
 	/// - `message_id`: The unique identifier for the failed message.
 	fn emit_process_failure_event(origin: Location, error: XcmError, message_id: XcmHash);
+
+	/// Notifies about the outcome of executing an XCM with a topic. Messages rejected by the
+	/// barrier are not executed and hence not notified about.
+	///
+	/// Unlike the other methods, this one is called for every executed message, so it is not
+	/// meant to emit an event but rather to keep track of the message lifecycle, see
+	/// [`RecordMessageStatus`](super::RecordMessageStatus).
+	///
+	/// Returns the weight used, which is accounted for in the outcome of the execution.
+	///
+	/// # Parameters
+	/// - `outcome`: The outcome of executing the message.
+	/// - `topic`: The topic of the message.
+	fn on_process_outcome(_outcome: &Outcome, _topic: XcmHash) -> Weight {
+		Weight::zero()
+	}
 }
 
 /// A no-op implementation of `EventEmitter` for unit type `()`.
@@ -0,0 +1,81 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trait for keeping track of the lifecycle of XCMs and a dummy implementation.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_weights::Weight;
+use xcm::latest::{Error as XcmError, Instruction::SetTopic, Location, Xcm, XcmHash};
+
+/// A step in the lifecycle of an XCM as seen by a single chain.
+///
+/// The steps are keyed by the topic of the message, i.e. the ID of its trailing `SetTopic`, which
+/// is kept by the message and its follow-up messages over all the hops.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum MessageStatus {
+	/// The message was handed over to the transport towards `destination`.
+	#[codec(index = 0)]
+	Sent { destination: Location },
+	/// The message could not be sent to `destination`.
+	#[codec(index = 1)]
+	SendFailed { destination: Location },
+	/// The message was put onto the outbound queue of the channel towards `destination`.
+	#[codec(index = 2)]
+	Queued { destination: Location },
+	/// The message was put onto the outbound lane of a bridge under the `nonce`.
+	#[codec(index = 3)]
+	Exported { nonce: u64 },
+	/// The message was executed successfully.
+	#[codec(index = 4)]
+	Executed,
+	/// The execution of the message failed at the instruction with the `index`.
+	#[codec(index = 5)]
+	Failed { index: u8, error: XcmError },
+	/// The message needs more weight than the processing queue could give it and is left to be
+	/// executed manually.
+	#[codec(index = 6)]
+	Overweight,
+}
+
+/// Trait for recording the lifecycle of XCMs.
+pub trait RecordMessageStatus {
+	/// Record that the message with the `topic` reached the `status`.
+	///
+	/// Returns the weight used to record it.
+	fn record_message_status(topic: XcmHash, status: MessageStatus) -> Weight;
+
+	/// The worst-case weight of [`Self::record_message_status`].
+	fn record_message_status_weight() -> Weight;
+}
+
+impl RecordMessageStatus for () {
+	fn record_message_status(_topic: XcmHash, _status: MessageStatus) -> Weight {
+		Weight::zero()
+	}
+
+	fn record_message_status_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The topic of the `message`, i.e. the ID of its trailing `SetTopic`, if it has one.
+pub fn message_topic<Call>(message: &Xcm<Call>) -> Option<XcmHash> {
+	match message.last() {
+		Some(SetTopic(topic)) => Some(*topic),
+		_ => None,
+	}
+}
@@ -51,11 +51,13 @@ pub use hrmp::{
 	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
 };
 mod event_emitter;
+mod message_status;
 mod record_xcm;
 mod weight;
 pub use event_emitter::EventEmitter;
+pub use message_status::{message_topic, MessageStatus, RecordMessageStatus};
 
-pub use record_xcm::RecordXcm;
+pub use record_xcm::{InstructionTrace, RecordXcm};
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
 pub use weight::{WeightBounds, WeightTrader};
@@ -16,7 +16,29 @@
 
 //! Trait for recording XCMs and a dummy implementation.
 
-use xcm::latest::Xcm;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use xcm::latest::{Assets, Error as XcmError, Instruction, Location, Weight, Xcm};
+
+/// The state of the XCM executor after processing an instruction.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct InstructionTrace {
+	/// The index of the instruction in the processed program.
+	pub index: u32,
+	/// The processed instruction.
+	pub instruction: Instruction<()>,
+	/// The weight of the instruction according to the weigher.
+	pub weight: Weight,
+	/// The origin after processing the instruction.
+	pub origin: Option<Location>,
+	/// The holding register after processing the instruction.
+	pub holding: Assets,
+	/// The assets reserved for fees after processing the instruction.
+	pub fees: Assets,
+	/// The error the instruction failed with, if any.
+	pub error: Option<XcmError>,
+}
 
 /// Trait for recording XCMs.
 pub trait RecordXcm {
@@ -29,6 +51,20 @@ pub trait RecordXcm {
 	fn recorded_xcm() -> Option<Xcm<()>>;
 	/// Record `xcm`.
 	fn record(xcm: Xcm<()>);
+
+	/// Whether or not we should record an [`InstructionTrace`] of every processed instruction.
+	/// Only consulted while [`Self::should_record`] is true.
+	fn should_trace() -> bool {
+		false
+	}
+	/// Enable or disable tracing.
+	fn set_trace_xcm(_enabled: bool) {}
+	/// Take the instruction traces recorded since tracing was last enabled.
+	fn take_trace() -> Vec<InstructionTrace> {
+		Vec::new()
+	}
+	/// Record the `trace` of a processed instruction.
+	fn record_instruction(_trace: InstructionTrace) {}
 }
 
 impl RecordXcm for () {
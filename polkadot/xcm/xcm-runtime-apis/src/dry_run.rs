@@ -47,6 +47,47 @@ pub struct XcmDryRunEffects<Event> {
 	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
 }
 
+/// The state of the XCM executor after processing an instruction of a dry-run XCM program.
+#[derive(Encode, Decode, Debug, Clone, Eq, PartialEq, TypeInfo)]
+pub struct XcmInstructionTrace {
+	/// The index of the instruction in the processed program.
+	///
+	/// Error handlers and appendices are processed as separate programs, their indices start at
+	/// zero.
+	pub index: u32,
+	/// The processed instruction, as a single instruction program.
+	pub instruction: VersionedXcm<()>,
+	/// The weight of the instruction according to the weigher.
+	pub weight: Weight,
+	/// The origin after processing the instruction.
+	pub origin: Option<VersionedLocation>,
+	/// The holding register after processing the instruction.
+	pub holding: VersionedAssets,
+	/// The assets reserved for fees after processing the instruction.
+	pub fees: VersionedAssets,
+	/// The error the instruction failed with, if any.
+	pub error: Option<XcmError>,
+}
+
+/// Effects of dry-running an extrinsic, together with the trace of its local XCM execution.
+#[derive(Encode, Decode, Debug, TypeInfo)]
+pub struct CallDryRunEffectsWithTrace<Event> {
+	/// The effects of the extrinsic.
+	pub effects: CallDryRunEffects<Event>,
+	/// The state of the XCM executor after every instruction processed by the extrinsic, in
+	/// processing order.
+	pub trace: Vec<XcmInstructionTrace>,
+}
+
+/// Effects of dry-running an XCM program, together with the trace of its execution.
+#[derive(Encode, Decode, Debug, TypeInfo)]
+pub struct XcmDryRunEffectsWithTrace<Event> {
+	/// The effects of the XCM program execution.
+	pub effects: XcmDryRunEffects<Event>,
+	/// The state of the XCM executor after every processed instruction, in processing order.
+	pub trace: Vec<XcmInstructionTrace>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// API for dry-running extrinsics and XCM programs to get the programs that need to be passed to the fees API.
 	///
@@ -73,6 +114,19 @@ sp_api::decl_runtime_apis! {
 
 		/// Dry run XCM program
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<XcmDryRunEffects<Event>, Error>;
+
+		/// Dry run call, recording the state of the XCM executor after every instruction of the
+		/// XCMs executed locally by the call.
+		///
+		/// Messages in the returned trace are in `result_xcms_version`.
+		#[api_version(3)]
+		fn dry_run_call_with_trace(origin: OriginCaller, call: Call, result_xcms_version: XcmVersion) -> Result<CallDryRunEffectsWithTrace<Event>, Error>;
+
+		/// Dry run XCM program, recording the state of the XCM executor after every instruction.
+		///
+		/// Messages in the returned trace are in the XCM version of `xcm`.
+		#[api_version(3)]
+		fn dry_run_xcm_with_trace(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<XcmDryRunEffectsWithTrace<Event>, Error>;
 	}
 }
 
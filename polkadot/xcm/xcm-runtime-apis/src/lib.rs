@@ -30,6 +30,10 @@ pub mod dry_run;
 /// Fee estimation API.
 /// Given an XCM program, it will return the fees needed to execute it properly or send it.
 pub mod fees;
+/// Exposes runtime API for following the lifecycle of XCMs by their topic.
+pub mod message_status;
+/// Exposes runtime API for listing the assets trapped by failed XCMs.
+pub mod trapped_assets;
 /// Exposes runtime API for querying whether a Location is trusted as a reserve or teleporter for a
 /// given Asset.
 pub mod trusted_query;
@@ -0,0 +1,47 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for following the lifecycle of XCMs by their topic.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::latest::XcmHash;
+pub use xcm_executor::traits::MessageStatus;
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the lifecycle of XCMs by their topic, i.e. the ID of their trailing
+	/// `SetTopic` instruction.
+	///
+	/// A chain only knows about the steps a message went through on it. Following a message over
+	/// several hops means querying every chain on its path with the same topic.
+	pub trait XcmMessageStatusApi<BlockNumber> where BlockNumber: Codec {
+		/// Returns the steps the message with the `topic` went through on this chain together
+		/// with the blocks they happened in, oldest first.
+		///
+		/// # Arguments
+		/// * `topic`: The ID of the trailing `SetTopic` of the message.
+		fn message_status(topic: XcmHash) -> Result<Vec<(BlockNumber, MessageStatus)>, Error>;
+	}
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// The message has never been seen by this chain or its record has already been pruned.
+	#[codec(index = 0)]
+	UnknownMessage,
+}
@@ -0,0 +1,71 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for listing the assets trapped by failed XCMs.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::{VersionedAssets, VersionedLocation};
+
+/// Assets trapped for an origin.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct TrappedAssets<BlockNumber> {
+	/// The location the assets were trapped for, i.e. the one able to claim them.
+	pub origin: VersionedLocation,
+	/// The trapped assets. They need to be claimed in this version.
+	pub assets: VersionedAssets,
+	/// The block the assets were trapped in.
+	pub trapped_at: BlockNumber,
+	/// The block from which on the assets are deposited to the fallback beneficiary of `origin`,
+	/// if they ever are.
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// A page of the assets trapped by failed XCMs.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct TrappedAssetsPage<BlockNumber> {
+	/// The trapped assets of the page.
+	pub trapped: Vec<TrappedAssets<BlockNumber>>,
+	/// The `start` of the next page, if there are more asset traps to look at.
+	pub next: Option<u64>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for listing the assets trapped by failed XCMs and not claimed yet.
+	pub trait TrappedAssetsApi<BlockNumber> where BlockNumber: Codec {
+		/// Returns a page of the trapped assets, optionally only the ones trapped for `origin`.
+		///
+		/// # Arguments
+		/// * `origin`: Only list the assets trapped for this location, if given.
+		/// * `start`: The asset trap to start at, `0` for the first page or the `next` of the
+		///   previous page.
+		/// * `limit`: The maximum number of asset traps to look at for the page.
+		fn trapped_assets(
+			origin: Option<VersionedLocation>,
+			start: u64,
+			limit: u32,
+		) -> Result<TrappedAssetsPage<BlockNumber>, Error>;
+	}
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 0)]
+	VersionedConversionFailed,
+}
@@ -33,6 +33,15 @@ use mock::{
 };
 use xcm_simulator::fake_message_hash;
 
+/// Returns the topic appended by `pallet-xcm` to the single forwarded message.
+fn forwarded_topic(forwarded_xcms: &[(VersionedLocation, Vec<VersionedXcm<()>>)]) -> XcmHash {
+	let message: Xcm<()> = forwarded_xcms[0].1[0].clone().try_into().unwrap();
+	match message.last() {
+		Some(SetTopic(topic)) => *topic,
+		_ => panic!("the forwarded message has no topic"),
+	}
+}
+
 // Scenario: User `1` in the local chain (id 2000) wants to transfer assets to account `[0u8; 32]`
 // on "AssetHub". He wants to make sure he has enough for fees, so before he calls the
 // `transfer_asset` extrinsic to do the transfer, he decides to use the `XcmDryRunApi` and
@@ -83,12 +92,15 @@ fn fee_estimation_for_teleport() {
 			)),
 		);
 		let send_destination = Location::new(1, [Parachain(1000)]);
+		// The remote message gets a unique topic appended.
+		let topic = forwarded_topic(&dry_run_effects.forwarded_xcms);
 		let send_message = Xcm::<()>::builder_unsafe()
 			.withdraw_asset((Parent, 20u128))
 			.buy_execution((Parent, 20u128), Unlimited)
 			.receive_teleported_asset(((Parent, Parachain(2000)), 100u128))
 			.clear_origin()
 			.deposit_asset(AllCounted(2), [0u8; 32])
+			.set_topic(topic)
 			.build();
 		assert_eq!(
 			dry_run_effects.forwarded_xcms,
@@ -130,7 +142,7 @@ fn fee_estimation_for_teleport() {
 					origin: AccountIndex64 { index: 1, network: None }.into(),
 					destination: (Parent, Parachain(1000)).into(),
 					message: send_message.clone(),
-					message_id: fake_message_hash(&send_message),
+					message_id: topic,
 				}),
 			]
 		);
@@ -184,7 +196,8 @@ fn fee_estimation_for_teleport() {
 			)
 			.unwrap()
 			.unwrap();
-		assert_eq!(remote_execution_fees, 550);
+		// The appended `SetTopic` is weighed as well.
+		assert_eq!(remote_execution_fees, 660);
 
 		// Now we know that locally we need to use `execution_fees` and
 		// `delivery_fees`.
@@ -252,11 +265,13 @@ fn dry_run_reserve_asset_transfer_common(
 		// In this case, the transfer type is `DestinationReserve`, so the remote xcm just withdraws
 		// the assets.
 		let send_destination = Location::new(1, Parachain(1000));
+		let topic = forwarded_topic(&dry_run_effects.forwarded_xcms);
 		let send_message = Xcm::<()>::builder_unsafe()
 			.withdraw_asset((Parent, 100u128))
 			.clear_origin()
 			.buy_execution((Parent, 100u128), Unlimited)
 			.deposit_asset(AllCounted(1), [0u8; 32])
+			.set_topic(topic)
 			.build();
 		assert_eq!(
 			dry_run_effects.forwarded_xcms,
@@ -290,7 +305,7 @@ fn dry_run_reserve_asset_transfer_common(
 					origin: AccountIndex64 { index: 1, network: None }.into(),
 					destination: send_destination.clone(),
 					message: send_message.clone(),
-					message_id: fake_message_hash(&send_message),
+					message_id: topic,
 				}),
 			]
 		);
@@ -446,6 +461,108 @@ fn dry_run_xcm_versions() {
 	}
 }
 
+#[test]
+fn dry_run_xcm_with_trace_works() {
+	sp_tracing::init_for_tests();
+	let who = 1; // AccountId = u64.
+	let amount = 1000u128;
+	let origin = Location::from([AccountIndex64 { index: who, network: None }]);
+	let xcm = Xcm::<RuntimeCall>::builder_unsafe()
+		.withdraw_asset((Here, amount))
+		.clear_origin()
+		.buy_execution((Here, amount), Unlimited)
+		.deposit_asset(AllCounted(1), AccountIndex64 { index: 2, network: None })
+		// Fails, the origin was cleared.
+		.withdraw_asset((Here, amount))
+		.build();
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+	new_test_ext_with_balances(vec![(who, amount + ExistentialDeposit::get())]).execute_with(
+		|| {
+			let dry_run = runtime_api
+				.dry_run_xcm_with_trace(
+					H256::zero(),
+					VersionedLocation::from(origin.clone()),
+					VersionedXcm::from(xcm),
+				)
+				.unwrap()
+				.unwrap();
+			assert!(matches!(
+				dry_run.effects.execution_result,
+				Outcome::Incomplete {
+					error: InstructionError { index: 4, error: XcmError::BadOrigin },
+					..
+				}
+			));
+
+			let trace = dry_run.trace;
+			assert_eq!(
+				trace.iter().map(|step| step.index).collect::<Vec<_>>(),
+				vec![0, 1, 2, 3, 4]
+			);
+			assert_eq!(
+				trace[0].instruction,
+				VersionedXcm::from(
+					Xcm::<()>::builder_unsafe().withdraw_asset((Here, amount)).build()
+				)
+			);
+			assert_eq!(trace[0].origin, Some(VersionedLocation::from(origin)));
+			assert_eq!(trace[0].holding, VersionedAssets::from((Here, amount)));
+			assert_eq!(trace[0].error, None);
+			assert_eq!(trace[1].origin, None);
+			assert_eq!(trace[3].holding, VersionedAssets::from(Assets::new()));
+			assert_eq!(trace[4].error, Some(XcmError::BadOrigin));
+		},
+	);
+}
+
+#[test]
+fn dry_run_call_with_trace_works() {
+	sp_tracing::init_for_tests();
+	let who = 1; // AccountId = u64.
+	let balances = vec![(who, 100 + DeliveryFees::get() + ExistentialDeposit::get())];
+	let assets = vec![(1, who, 50)];
+	new_test_ext_with_balances_and_assets(balances, assets).execute_with(|| {
+		let client = TestClient;
+		let runtime_api = client.runtime_api();
+		let call = RuntimeCall::XcmPallet(pallet_xcm::Call::transfer_assets {
+			dest: Box::new(VersionedLocation::from((Parent, Parachain(1000)))),
+			beneficiary: Box::new(VersionedLocation::from(AccountId32 {
+				id: [0u8; 32],
+				network: None,
+			})),
+			assets: Box::new(VersionedAssets::from(vec![
+				(Here, 100u128).into(),
+				(Parent, 20u128).into(),
+			])),
+			fee_asset_item: 1, // Fees are paid with the RelayToken
+			weight_limit: Unlimited,
+		});
+		let origin = OriginCaller::system(RawOrigin::Signed(who));
+		let dry_run = runtime_api
+			.dry_run_call_with_trace(H256::zero(), origin, call, XCM_VERSION)
+			.unwrap()
+			.unwrap();
+		assert!(dry_run.effects.execution_result.is_ok());
+
+		// The trace covers the local XCM, instruction by instruction.
+		let local_xcm: Xcm<()> = dry_run.effects.local_xcm.unwrap().try_into().unwrap();
+		let trace = dry_run.trace;
+		assert_eq!(
+			trace.iter().map(|step| step.instruction.clone()).collect::<Vec<_>>(),
+			local_xcm
+				.0
+				.into_iter()
+				.map(|instruction| VersionedXcm::from(Xcm(vec![instruction])))
+				.collect::<Vec<_>>(),
+		);
+		assert_eq!(trace.iter().map(|step| step.index).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+		assert!(trace.iter().all(|step| step.error.is_none()));
+		assert_eq!(trace[0].holding, VersionedAssets::from((Parent, 20u128)));
+		assert_eq!(trace[3].holding, VersionedAssets::from(Assets::new()));
+	});
+}
+
 #[test]
 fn calling_payment_api_with_a_lower_version_works() {
 	let transfer_amount = 100u128;
@@ -537,11 +654,13 @@ fn fee_estimation_for_usdt_reserve_transfer_in_usdt() {
 		let send_destination = Location::new(1, [Parachain(1000)]);
 		// For destination reserve, the remote message withdraws the assets from the sovereign
 		// account
+		let topic = forwarded_topic(&dry_run_effects.forwarded_xcms);
 		let send_message = Xcm::<()>::builder_unsafe()
 			.withdraw_asset((usdt_location_ah_pov.clone(), 100u128))
 			.clear_origin()
 			.buy_execution((usdt_location_ah_pov.clone(), 100u128), Unlimited)
 			.deposit_asset(AllCounted(1), [0u8; 32])
+			.set_topic(topic)
 			.build();
 		assert_eq!(
 			dry_run_effects.forwarded_xcms,
@@ -0,0 +1,154 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Test that parachains progress and finality keeps up when a validator delays its approval
+// assignments and votes beyond the no-show timeout, or drops them altogether.
+
+use anyhow::anyhow;
+use tokio::time::Duration;
+
+use cumulus_zombienet_sdk_helpers::assert_para_throughput;
+use polkadot_primitives::Id as ParaId;
+use serde_json::json;
+use zombienet_orchestrator::network::node::LogLineCountOptions;
+use zombienet_sdk::{
+	subxt::{OnlineClient, PolkadotConfig},
+	NetworkConfigBuilder,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn delay_approvals_test() -> Result<(), anyhow::Error> {
+	let _ = env_logger::try_init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let images = zombienet_sdk::environment::get_images_from_env();
+
+	let config = NetworkConfigBuilder::new()
+		.with_relaychain(|r| {
+			r.with_chain("rococo-local")
+				.with_default_command("polkadot")
+				.with_default_image(images.polkadot.as_str())
+				.with_default_args(vec![("-lparachain=debug").into()])
+				.with_genesis_overrides(json!({
+					"configuration": {
+						"config": {
+							"needed_approvals": 2,
+							"scheduler_params": {
+								"max_validators_per_core": 5
+							}
+						}
+					}
+				}))
+				.with_node(|node| node.with_name("honest-0"))
+				.with_node(|node| node.with_name("honest-1"))
+				.with_node(|node| node.with_name("honest-2"))
+				.with_node(|node| node.with_name("honest-3"))
+				.with_node(|node| {
+					node.with_name("malus")
+						.with_image(
+							std::env::var("MALUS_IMAGE")
+								.unwrap_or("docker.io/paritypr/malus".to_string())
+								.as_str(),
+						)
+						.with_command("malus")
+						.with_subcommand("delay-approvals")
+						.with_args(vec![
+							"--alice".into(),
+							"--drop-percentage=50".into(),
+							"--delay-ms=30000".into(),
+							"--insecure-validator-i-know-what-i-do".into(),
+							"-lMALUS=trace,parachain=debug".into(),
+						])
+				})
+		})
+		.with_parachain(|p| {
+			p.with_id(2000)
+				.with_default_command("undying-collator")
+				.cumulus_based(false)
+				.with_default_image(
+					std::env::var("COL_IMAGE")
+						.unwrap_or("docker.io/paritypr/colander:latest".to_string())
+						.as_str(),
+				)
+				.with_default_args(vec![("-lparachain=debug").into()])
+				.with_collator(|n| n.with_name("collator-2000"))
+		})
+		.with_parachain(|p| {
+			p.with_id(2001)
+				.with_default_command("undying-collator")
+				.cumulus_based(false)
+				.with_default_image(
+					std::env::var("COL_IMAGE")
+						.unwrap_or("docker.io/paritypr/colander:latest".to_string())
+						.as_str(),
+				)
+				.with_default_args(vec![("-lparachain=debug").into()])
+				.with_collator(|n| n.with_name("collator-2001"))
+		})
+		.build()
+		.map_err(|e| {
+			let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+			anyhow!("config errs: {errs}")
+		})?;
+
+	let spawn_fn = zombienet_sdk::environment::get_spawn_fn();
+	let network = spawn_fn(config).await?;
+
+	let malus = network.get_node("malus")?;
+	let honest = network.get_node("honest-0")?;
+	let relay_client: OnlineClient<PolkadotConfig> = honest.wait_client().await?;
+
+	// Ensure parachains are registered.
+	assert_para_throughput(
+		&relay_client,
+		2,
+		[(ParaId::from(2000), 2..3), (ParaId::from(2001), 2..3)],
+	)
+	.await?;
+
+	// Ensure that malus is holding back its approval messages.
+	let result = malus
+		.wait_log_line_count_with_timeout(
+			"*Delaying approvals*",
+			true,
+			LogLineCountOptions::new(|n| n >= 1, Duration::from_secs(120), false),
+		)
+		.await?;
+	assert!(result.success());
+
+	// Honest nodes consider malus a no-show and cover it with further tranches.
+	honest
+		.wait_metric_with_timeout(
+			"polkadot_parachain_approvals_no_shows_total",
+			|no_shows| no_shows >= 1.0,
+			300_u64,
+		)
+		.await?;
+
+	// Ensure parachains made progress.
+	assert_para_throughput(
+		&relay_client,
+		10,
+		[(ParaId::from(2000), 8..11), (ParaId::from(2001), 8..11)],
+	)
+	.await?;
+
+	// Check lag - approval. The no-shows hold back finality for a short while only.
+	honest
+		.wait_metric_with_timeout(
+			"polkadot_parachain_approval_checking_finality_lag{chain=\"rococo_local_testnet\"}",
+			|lag| lag <= 2.0,
+			120_u64,
+		)
+		.await?;
+
+	// Check lag - dispute conclusion
+	honest
+		.assert("polkadot_parachain_disputes_finality_lag{chain=\"rococo_local_testnet\"}", 0.0)
+		.await?;
+
+	log::info!("Test finished successfully");
+
+	Ok(())
+}
@@ -4,9 +4,12 @@
 mod approval_voting_coalescing;
 mod approved_peer_mixed_validators;
 mod async_backing_6_seconds_rate;
+mod delay_approvals;
 mod dispute_old_finalized;
 mod duplicate_collations;
+mod equivocate_bitfields;
 mod shared_core_idle_parachain;
 mod spam_statement_distribution_requests;
 mod sync_backing;
 mod validator_disabling;
+mod withhold_availability_chunks;
@@ -428,6 +428,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let enable_offchain_worker = config.offchain_worker.enabled;
+	let offchain_worker_config = config.offchain_worker.clone();
 
 	let hwbench = (!disable_hardware_benchmarks)
 		.then(|| {
@@ -805,6 +806,8 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 				network_provider: Arc::new(network.clone()),
 				is_validator: role.is_authority(),
 				enable_http_requests: true,
+				enable_websocket_connections: offchain_worker_config.websocket_enabled,
+				outbound_policy: offchain_worker_config.outbound_policy,
 				custom_extensions: move |_| {
 					vec![Box::new(statement_store.clone().as_statement_store_ext()) as Box<_>]
 				},
@@ -1386,6 +1386,7 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 	type HeapSize = ConstU32<{ 64 * 1024 }>;
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -526,7 +526,7 @@ impl From<CallContext> for TrieCacheContext {
 	fn from(call_context: CallContext) -> Self {
 		match call_context {
 			CallContext::Onchain => TrieCacheContext::Trusted,
-			CallContext::Offchain => TrieCacheContext::Untrusted,
+			CallContext::Offchain | CallContext::Rpc => TrieCacheContext::Untrusted,
 		}
 	}
 }
@@ -28,7 +28,8 @@ use clap::Parser;
 use regex::Regex;
 use sc_service::{
 	config::{
-		BasePath, IpNetwork, PrometheusConfig, RpcBatchRequestConfig, TransactionPoolOptions,
+		BasePath, ExecutionLimit, IpNetwork, PrometheusConfig, RpcBatchRequestConfig,
+		RpcRateLimitOverrides, TransactionPoolOptions,
 	},
 	ChainSpec, Role,
 };
@@ -330,6 +331,10 @@ impl CliConfiguration for RunCmd {
 		Ok(self.rpc_params.rpc_rate_limit_trust_proxy_headers)
 	}
 
+	fn rpc_rate_limit_overrides(&self) -> Result<Option<RpcRateLimitOverrides>> {
+		self.rpc_params.rpc_rate_limit_overrides()
+	}
+
 	fn transaction_pool(&self, is_dev: bool) -> Result<TransactionPoolOptions> {
 		Ok(self.pool_config.transaction_pool(is_dev))
 	}
@@ -342,6 +347,18 @@ impl CliConfiguration for RunCmd {
 		Ok(self.runtime_params.runtime_cache_size)
 	}
 
+	fn rpc_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(self.runtime_params.rpc_execution_limit())
+	}
+
+	fn offchain_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(self.runtime_params.offchain_execution_limit())
+	}
+
+	fn onchain_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(self.runtime_params.onchain_execution_limit())
+	}
+
 	fn base_path(&self) -> Result<Option<BasePath>> {
 		Ok(if self.tmp {
 			Some(BasePath::new_temp_dir()?)
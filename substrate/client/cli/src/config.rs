@@ -27,10 +27,10 @@ use log::warn;
 use names::{Generator, Name};
 use sc_service::{
 	config::{
-		BasePath, Configuration, DatabaseSource, ExecutorConfiguration, IpNetwork, KeystoreConfig,
-		NetworkConfiguration, NodeKeyConfig, OffchainWorkerConfig, PrometheusConfig, PruningMode,
-		Role, RpcBatchRequestConfig, RpcConfiguration, RpcMethods, TelemetryEndpoints,
-		TransactionPoolOptions, WasmExecutionMethod,
+		BasePath, Configuration, DatabaseSource, ExecutionLimit, ExecutorConfiguration, IpNetwork,
+		KeystoreConfig, NetworkConfiguration, NodeKeyConfig, OffchainWorkerConfig,
+		PrometheusConfig, PruningMode, Role, RpcBatchRequestConfig, RpcConfiguration, RpcMethods,
+		RpcRateLimitOverrides, TelemetryEndpoints, TransactionPoolOptions, WasmExecutionMethod,
 	},
 	BlocksPruning, ChainSpec, TracingReceiver,
 };
@@ -376,6 +376,11 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(false)
 	}
 
+	/// RPC rate limit method costs and API key tiers.
+	fn rpc_rate_limit_overrides(&self) -> Result<Option<RpcRateLimitOverrides>> {
+		Ok(None)
+	}
+
 	/// Get the prometheus configuration (`None` if disabled)
 	///
 	/// By default this is `None`.
@@ -476,6 +481,27 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(2)
 	}
 
+	/// Get the execution limit for runtime calls requested through an RPC.
+	///
+	/// By default this is `None`.
+	fn rpc_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(None)
+	}
+
+	/// Get the execution limit for offchain runtime calls.
+	///
+	/// By default this is `None`.
+	fn offchain_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(None)
+	}
+
+	/// Get the execution limit for runtime calls made while building or importing a block.
+	///
+	/// By default this is `None`.
+	fn onchain_execution_limit(&self) -> Result<Option<ExecutionLimit>> {
+		Ok(None)
+	}
+
 	/// Activate or not the automatic announcing of blocks after import
 	///
 	/// By default this is `false`.
@@ -546,6 +572,9 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 				default_heap_pages: self.default_heap_pages()?,
 				max_runtime_instances,
 				runtime_cache_size,
+				rpc_execution_limit: self.rpc_execution_limit()?,
+				offchain_execution_limit: self.offchain_execution_limit()?,
+				onchain_execution_limit: self.onchain_execution_limit()?,
 			},
 			wasm_runtime_overrides: self.wasm_runtime_overrides(),
 			rpc: RpcConfiguration {
@@ -564,6 +593,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 				rate_limit_whitelisted_ips: self.rpc_rate_limit_whitelisted_ips()?,
 				rate_limit_trust_proxy_headers: self.rpc_rate_limit_trust_proxy_headers()?,
 				request_logger_limit: if is_dev { 1024 * 1024 } else { 1024 },
+				rate_limit_overrides: self.rpc_rate_limit_overrides()?,
 			},
 			prometheus_config: self
 				.prometheus_config(DCV::prometheus_listen_port(), &chain_spec)?,
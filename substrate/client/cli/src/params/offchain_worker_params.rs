@@ -25,7 +25,8 @@
 
 use clap::{ArgAction, Args};
 use sc_network::config::Role;
-use sc_service::config::OffchainWorkerConfig;
+use sc_service::config::{HostPattern, HostRateLimit, OffchainWorkerConfig, OutboundPolicy};
+use std::{num::NonZeroU32, time::Duration};
 
 use crate::{error, OffchainWorkerEnabled};
 
@@ -47,6 +48,31 @@ pub struct OffchainWorkerParams {
 	/// Allows the runtime to write directly to offchain workers DB during block import.
 	#[arg(long = "enable-offchain-indexing", value_name = "ENABLE_OFFCHAIN_INDEXING", default_value_t = false, action = ArgAction::Set)]
 	pub indexing_enabled: bool,
+
+	/// Allow offchain workers to open WebSocket connections.
+	#[arg(long = "enable-offchain-websocket")]
+	pub websocket_enabled: bool,
+
+	/// Only allow offchain workers to contact hosts matching this pattern.
+	///
+	/// Can be passed multiple times. A pattern is either `*`, a host name such as `example.com`,
+	/// the subdomains of a domain such as `*.example.com`, or a network such as `10.0.0.0/8`.
+	/// Networks are matched against the addresses hosts resolve to. If not set, every host is
+	/// allowed.
+	#[arg(long = "offchain-outbound-allow", value_name = "PATTERN")]
+	pub outbound_allow: Vec<HostPattern>,
+
+	/// Never allow offchain workers to contact hosts matching this pattern.
+	///
+	/// Can be passed multiple times and takes precedence over `--offchain-outbound-allow`. Uses
+	/// the same patterns, e.g. `127.0.0.0/8` prevents contacting any host resolving to a loopback
+	/// address.
+	#[arg(long = "offchain-outbound-deny", value_name = "PATTERN")]
+	pub outbound_deny: Vec<HostPattern>,
+
+	/// Maximum number of requests offchain workers may send to a single host per minute.
+	#[arg(long = "offchain-outbound-rate-limit", value_name = "COUNT")]
+	pub outbound_rate_limit: Option<NonZeroU32>,
 }
 
 impl OffchainWorkerParams {
@@ -60,6 +86,19 @@ impl OffchainWorkerParams {
 		};
 
 		let indexing_enabled = self.indexing_enabled;
-		Ok(OffchainWorkerConfig { enabled, indexing_enabled })
+		let outbound_policy = OutboundPolicy {
+			allowlist: self.outbound_allow.clone(),
+			denylist: self.outbound_deny.clone(),
+			rate_limit: self.outbound_rate_limit.map(|max_requests| HostRateLimit {
+				max_requests,
+				period: Duration::from_secs(60),
+			}),
+		};
+		Ok(OffchainWorkerConfig {
+			enabled,
+			indexing_enabled,
+			websocket_enabled: self.websocket_enabled,
+			outbound_policy,
+		})
 	}
 }
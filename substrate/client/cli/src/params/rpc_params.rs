@@ -23,9 +23,11 @@ use crate::{
 	RPC_DEFAULT_MAX_SUBS_PER_CONN, RPC_DEFAULT_MESSAGE_CAPACITY_PER_CONN,
 };
 use clap::Args;
+use sc_service::config::RpcRateLimitOverrides;
 use std::{
 	net::{Ipv4Addr, Ipv6Addr, SocketAddr},
 	num::NonZeroU32,
+	path::PathBuf,
 };
 
 const RPC_LISTEN_ADDR: &str = "listen-addr";
@@ -100,6 +102,19 @@ pub struct RpcParams {
 	#[arg(long)]
 	pub rpc_rate_limit_trust_proxy_headers: bool,
 
+	/// Path to a JSON file with RPC rate limit method costs and API key tiers.
+	///
+	/// When set, the rate limit is enforced per IP address instead of per connection, and each
+	/// call is charged by the cost of its method (`1` unless specified otherwise). Callers
+	/// presenting a known API key in the configured HTTP header (`x-api-key` by default) are
+	/// charged against the quota of the key's tier instead.
+	///
+	/// Example:
+	/// `{ "methodCosts": { "state_getKeysPaged": 10 }, "tiers": { "premium": 6000 },
+	/// "apiKeys": { "<key>": "premium" } }`
+	#[arg(long, value_name = "PATH")]
+	pub rpc_rate_limit_config: Option<PathBuf>,
+
 	/// Set the maximum RPC request payload size for both HTTP and WS in megabytes.
 	#[arg(long, default_value_t = RPC_DEFAULT_MAX_REQUEST_SIZE_MB)]
 	pub rpc_max_request_size: u32,
@@ -283,6 +298,21 @@ impl RpcParams {
 		]))
 	}
 
+	/// Returns the RPC rate limit overrides loaded from `--rpc-rate-limit-config`.
+	pub fn rpc_rate_limit_overrides(&self) -> crate::Result<Option<RpcRateLimitOverrides>> {
+		self.rpc_rate_limit_config
+			.as_ref()
+			.map(|path| {
+				RpcRateLimitOverrides::from_file(path).map_err(|e| {
+					crate::Error::Input(format!(
+						"Invalid RPC rate limit config {}: {e}",
+						path.display()
+					))
+				})
+			})
+			.transpose()
+	}
+
 	/// Returns the configuration for batch RPC requests.
 	pub fn rpc_batch_config(&self) -> crate::Result<RpcBatchRequestConfig> {
 		let cfg = if self.rpc_disable_batch_requests {
@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use clap::Args;
-use std::str::FromStr;
+use sc_service::config::ExecutionLimit;
+use std::{str::FromStr, time::Duration};
 
 /// Parameters used to config runtime.
 #[derive(Debug, Clone, Args)]
@@ -31,6 +32,65 @@ pub struct RuntimeParams {
 	/// Maximum number of different runtimes that can be cached.
 	#[arg(long, default_value_t = 2)]
 	pub runtime_cache_size: u8,
+
+	/// Maximum time in milliseconds a runtime call requested through an RPC may take.
+	///
+	/// Applies to `state_call`, `chainHead_v1_call`, `archive_v1_call` and the RPCs calling into
+	/// runtime APIs, e.g. `system_accountNextIndex`. Calls exceeding the limit are aborted and an
+	/// error is returned. Enabling a limit slows down these calls.
+	#[arg(long, value_name = "MILLISECONDS", conflicts_with = "rpc_call_fuel_limit")]
+	pub rpc_call_time_limit: Option<u64>,
+
+	/// Maximum amount of fuel a runtime call requested through an RPC may consume.
+	///
+	/// Same as `--rpc-call-time-limit`, but deterministic: the amount of fuel consumed only
+	/// depends on the executed code.
+	#[arg(long, value_name = "FUEL")]
+	pub rpc_call_fuel_limit: Option<u64>,
+
+	/// Maximum time in milliseconds an offchain runtime call may take.
+	///
+	/// Applies to offchain workers and the runtime calls made by the node itself outside of block
+	/// production and import, e.g. to validate transactions. Enabling a limit slows down these
+	/// calls.
+	#[arg(long, value_name = "MILLISECONDS", conflicts_with = "offchain_call_fuel_limit")]
+	pub offchain_call_time_limit: Option<u64>,
+
+	/// Maximum amount of fuel an offchain runtime call may consume.
+	///
+	/// Same as `--offchain-call-time-limit`, but deterministic.
+	#[arg(long, value_name = "FUEL")]
+	pub offchain_call_fuel_limit: Option<u64>,
+
+	/// Maximum amount of fuel a runtime call made while building or importing a block may
+	/// consume.
+	///
+	/// A block whose execution exceeds the limit can't be imported, so a limit that is too low
+	/// prevents the node from following the chain. There is no time based limit for these calls,
+	/// as it wouldn't be deterministic. Enabling a limit slows down block production and import.
+	#[arg(long, value_name = "FUEL")]
+	pub onchain_call_fuel_limit: Option<u64>,
+}
+
+impl RuntimeParams {
+	/// Returns the execution limit for runtime calls requested through an RPC.
+	pub fn rpc_execution_limit(&self) -> Option<ExecutionLimit> {
+		self.rpc_call_time_limit
+			.map(|ms| ExecutionLimit::Time(Duration::from_millis(ms)))
+			.or(self.rpc_call_fuel_limit.map(ExecutionLimit::Fuel))
+	}
+
+	/// Returns the execution limit for offchain runtime calls.
+	pub fn offchain_execution_limit(&self) -> Option<ExecutionLimit> {
+		self.offchain_call_time_limit
+			.map(|ms| ExecutionLimit::Time(Duration::from_millis(ms)))
+			.or(self.offchain_call_fuel_limit.map(ExecutionLimit::Fuel))
+	}
+
+	/// Returns the execution limit for runtime calls made while building or importing a block.
+	pub fn onchain_execution_limit(&self) -> Option<ExecutionLimit> {
+		self.onchain_call_fuel_limit.map(ExecutionLimit::Fuel)
+	}
 }
 
 fn parse_max_runtime_instances(s: &str) -> Result<usize, String> {
@@ -284,6 +284,7 @@ mod tests {
 					rate_limit_whitelisted_ips: Default::default(),
 					rate_limit_trust_proxy_headers: Default::default(),
 					request_logger_limit: 1024,
+					rate_limit_overrides: None,
 				},
 				prometheus_config: None,
 				telemetry_endpoints: None,
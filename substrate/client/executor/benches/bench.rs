@@ -67,6 +67,7 @@ fn initialize(
 					wasm_bulk_memory: false,
 					wasm_reference_types: false,
 					wasm_simd: false,
+					execution_limits: false,
 				},
 			};
 
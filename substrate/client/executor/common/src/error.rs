@@ -18,6 +18,8 @@
 
 //! Rust executor possible errors.
 
+use crate::wasm_runtime::ExecutionLimit;
+
 /// Result type alias.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -99,6 +101,9 @@ pub enum Error {
 
 	#[error("Output exceeds bounds of wasm memory")]
 	OutputExceedsBounds,
+
+	#[error("Execution limit exceeded: the call used more than {0}")]
+	ExecutionLimitExceeded(ExecutionLimit),
 }
 
 impl From<&'static str> for Error {
@@ -69,6 +69,38 @@ pub trait WasmInstance: Send {
 	fn call_export(&mut self, method: &str, data: &[u8]) -> Result<Vec<u8>, Error> {
 		self.call(method.into(), data)
 	}
+
+	/// Set the [`ExecutionLimit`] applied to all following calls on this instance.
+	///
+	/// Passing `None` removes any previously set limit. A call that exceeds the limit is aborted
+	/// with [`Error::ExecutionLimitExceeded`].
+	///
+	/// Engines that don't support execution limits ignore this.
+	fn set_execution_limit(&mut self, _limit: Option<ExecutionLimit>) {}
+}
+
+/// A limit on the amount of work a single call into the runtime may perform.
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum ExecutionLimit {
+	/// Limit the call to the given amount of fuel.
+	///
+	/// Fuel is consumed deterministically by the executed code, so a call always runs out of fuel
+	/// at the same point, independent of the machine executing it.
+	Fuel(u64),
+	/// Limit the wall clock time the call may take.
+	///
+	/// The limit is enforced with a coarse granularity and is not deterministic, so it should
+	/// never be used for calls whose outcome must be agreed upon, like importing a block.
+	Time(std::time::Duration),
+}
+
+impl std::fmt::Display for ExecutionLimit {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Fuel(fuel) => write!(f, "{fuel} units of fuel"),
+			Self::Time(time) => write!(f, "{time:?} of execution time"),
+		}
+	}
 }
 
 /// Defines the heap pages allocation strategy the wasm runtime should use.
@@ -33,7 +33,8 @@ use codec::Encode;
 use sc_executor_common::{
 	runtime_blob::RuntimeBlob,
 	wasm_runtime::{
-		AllocationStats, HeapAllocStrategy, WasmInstance, WasmModule, DEFAULT_HEAP_ALLOC_STRATEGY,
+		AllocationStats, ExecutionLimit, HeapAllocStrategy, WasmInstance, WasmModule,
+		DEFAULT_HEAP_ALLOC_STRATEGY,
 	},
 };
 use sp_core::traits::{CallContext, CodeExecutor, Externalities, RuntimeCode};
@@ -93,6 +94,9 @@ pub struct WasmExecutorBuilder<H = sp_io::SubstrateHostFunctions> {
 	cache_path: Option<PathBuf>,
 	allow_missing_host_functions: bool,
 	runtime_cache_size: u8,
+	onchain_execution_limit: Option<ExecutionLimit>,
+	offchain_execution_limit: Option<ExecutionLimit>,
+	rpc_execution_limit: Option<ExecutionLimit>,
 }
 
 impl<H> WasmExecutorBuilder<H> {
@@ -110,6 +114,9 @@ impl<H> WasmExecutorBuilder<H> {
 			runtime_cache_size: 4,
 			allow_missing_host_functions: false,
 			cache_path: None,
+			onchain_execution_limit: None,
+			offchain_execution_limit: None,
+			rpc_execution_limit: None,
 		}
 	}
 
@@ -193,6 +200,34 @@ impl<H> WasmExecutorBuilder<H> {
 		self
 	}
 
+	/// Create the wasm executor with the given `execution_limit` for onchain runtime calls.
+	///
+	/// Only [`ExecutionLimit::Fuel`] is deterministic. Since a call exceeding the limit fails,
+	/// a limit that is too low prevents the node from importing valid blocks.
+	///
+	/// By default onchain runtime calls are not limited.
+	pub fn with_onchain_execution_limit(mut self, execution_limit: ExecutionLimit) -> Self {
+		self.onchain_execution_limit = Some(execution_limit);
+		self
+	}
+
+	/// Create the wasm executor with the given `execution_limit` for offchain runtime calls.
+	///
+	/// By default offchain runtime calls are not limited.
+	pub fn with_offchain_execution_limit(mut self, execution_limit: ExecutionLimit) -> Self {
+		self.offchain_execution_limit = Some(execution_limit);
+		self
+	}
+
+	/// Create the wasm executor with the given `execution_limit` for runtime calls requested
+	/// through an RPC.
+	///
+	/// By default these calls are not limited.
+	pub fn with_rpc_execution_limit(mut self, execution_limit: ExecutionLimit) -> Self {
+		self.rpc_execution_limit = Some(execution_limit);
+		self
+	}
+
 	/// Build the configured [`WasmExecutor`].
 	pub fn build(self) -> WasmExecutor<H> {
 		WasmExecutor {
@@ -211,6 +246,9 @@ impl<H> WasmExecutorBuilder<H> {
 			)),
 			cache_path: self.cache_path,
 			allow_missing_host_functions: self.allow_missing_host_functions,
+			onchain_execution_limit: self.onchain_execution_limit,
+			offchain_execution_limit: self.offchain_execution_limit,
+			rpc_execution_limit: self.rpc_execution_limit,
 			phantom: PhantomData,
 		}
 	}
@@ -234,6 +272,12 @@ pub struct WasmExecutor<H = sp_io::SubstrateHostFunctions> {
 	cache_path: Option<PathBuf>,
 	/// Ignore missing function imports.
 	allow_missing_host_functions: bool,
+	/// The execution limit for onchain Wasm calls.
+	onchain_execution_limit: Option<ExecutionLimit>,
+	/// The execution limit for offchain Wasm calls.
+	offchain_execution_limit: Option<ExecutionLimit>,
+	/// The execution limit for Wasm calls requested through an RPC.
+	rpc_execution_limit: Option<ExecutionLimit>,
 	phantom: PhantomData<H>,
 }
 
@@ -247,6 +291,9 @@ impl<H> Clone for WasmExecutor<H> {
 			cache: self.cache.clone(),
 			cache_path: self.cache_path.clone(),
 			allow_missing_host_functions: self.allow_missing_host_functions,
+			onchain_execution_limit: self.onchain_execution_limit,
+			offchain_execution_limit: self.offchain_execution_limit,
+			rpc_execution_limit: self.rpc_execution_limit,
 			phantom: self.phantom,
 		}
 	}
@@ -301,6 +348,9 @@ impl<H> WasmExecutor<H> {
 			)),
 			cache_path,
 			allow_missing_host_functions: false,
+			onchain_execution_limit: None,
+			offchain_execution_limit: None,
+			rpc_execution_limit: None,
 			phantom: PhantomData,
 		}
 	}
@@ -315,6 +365,15 @@ impl<H> WasmExecutor<H> {
 	pub fn allow_missing_host_functions(&mut self, allow_missing_host_functions: bool) {
 		self.allow_missing_host_functions = allow_missing_host_functions
 	}
+
+	/// Returns the execution limit for runtime calls made in the given `context`.
+	fn execution_limit(&self, context: CallContext) -> Option<ExecutionLimit> {
+		match context {
+			CallContext::Onchain => self.onchain_execution_limit,
+			CallContext::Offchain => self.offchain_execution_limit,
+			CallContext::Rpc => self.rpc_execution_limit,
+		}
+	}
 }
 
 impl<H> WasmExecutor<H>
@@ -323,6 +382,8 @@ where
 {
 	/// Execute the given closure `f` with the latest runtime (based on `runtime_code`).
 	///
+	/// The instance passed to `f` has no execution limit set.
+	///
 	/// The closure `f` is expected to return `Err(_)` when there happened a `panic!` in native code
 	/// while executing the runtime in Wasm. If a `panic!` occurred, the runtime is invalidated to
 	/// prevent any poisoned state. Native runtime execution does not need to report back
@@ -341,6 +402,30 @@ where
 		heap_alloc_strategy: HeapAllocStrategy,
 		f: F,
 	) -> Result<R>
+	where
+		F: FnOnce(
+			AssertUnwindSafe<&dyn WasmModule>,
+			AssertUnwindSafe<&mut dyn WasmInstance>,
+			Option<&RuntimeVersion>,
+			AssertUnwindSafe<&mut dyn Externalities>,
+		) -> Result<Result<R>>,
+	{
+		self.with_limited_instance(runtime_code, ext, heap_alloc_strategy, None, f)
+	}
+
+	/// Same as [`Self::with_instance`], but the instance passed to `f` has the given
+	/// `execution_limit` set.
+	///
+	/// Only runtimes used with a limit are compiled with support for execution limits, so calls
+	/// without a limit don't pay for the instrumentation.
+	fn with_limited_instance<R, F>(
+		&self,
+		runtime_code: &RuntimeCode,
+		ext: &mut dyn Externalities,
+		heap_alloc_strategy: HeapAllocStrategy,
+		execution_limit: Option<ExecutionLimit>,
+		f: F,
+	) -> Result<R>
 	where
 		F: FnOnce(
 			AssertUnwindSafe<&dyn WasmModule>,
@@ -355,7 +440,10 @@ where
 			self.method,
 			heap_alloc_strategy,
 			self.allow_missing_host_functions,
+			execution_limit.is_some(),
 			|module, instance, version, ext| {
+				// Instances are reused, so always overwrite the limit of a previous call.
+				instance.set_execution_limit(execution_limit);
 				let module = AssertUnwindSafe(module);
 				let instance = AssertUnwindSafe(instance);
 				let ext = AssertUnwindSafe(ext);
@@ -429,6 +517,7 @@ where
 			self.default_onchain_heap_alloc_strategy,
 			runtime_blob,
 			allow_missing_host_functions,
+			false,
 			self.cache_path.as_deref(),
 		)
 		.map_err(|e| format!("Failed to create module: {}", e))?;
@@ -518,14 +607,16 @@ where
 		};
 
 		let heap_alloc_strategy = match context {
-			CallContext::Offchain => self.default_offchain_heap_alloc_strategy,
+			CallContext::Offchain | CallContext::Rpc => self.default_offchain_heap_alloc_strategy,
 			CallContext::Onchain => on_chain_heap_alloc_strategy,
 		};
+		let execution_limit = self.execution_limit(context);
 
-		let result = self.with_instance(
+		let result = self.with_limited_instance(
 			runtime_code,
 			ext,
 			heap_alloc_strategy,
+			execution_limit,
 			|_, mut instance, _on_chain_version, mut ext| {
 				with_externalities_safe(&mut **ext, move || instance.call_export(method, data))
 			},
@@ -688,15 +779,18 @@ impl<D: NativeExecutionDispatch + 'static> CodeExecutor for NativeElseWasmExecut
 		};
 
 		let heap_alloc_strategy = match context {
-			CallContext::Offchain => self.wasm.default_offchain_heap_alloc_strategy,
+			CallContext::Offchain | CallContext::Rpc =>
+				self.wasm.default_offchain_heap_alloc_strategy,
 			CallContext::Onchain => on_chain_heap_alloc_strategy,
 		};
+		let execution_limit = self.wasm.execution_limit(context);
 
 		let mut used_native = false;
-		let result = self.wasm.with_instance(
+		let result = self.wasm.with_limited_instance(
 			runtime_code,
 			ext,
 			heap_alloc_strategy,
+			execution_limit,
 			|_, mut instance, on_chain_version, mut ext| {
 				let on_chain_version =
 					on_chain_version.ok_or_else(|| Error::ApiError("Unknown version".into()))?;
@@ -452,6 +452,7 @@ fn mk_test_runtime(
 		pages,
 		blob,
 		true,
+		false,
 		None,
 	)
 	.expect("failed to instantiate wasm runtime")
@@ -678,6 +679,7 @@ fn memory_is_cleared_between_invocations(wasm_method: WasmExecutionMethod) {
 		HeapAllocStrategy::Dynamic { maximum_pages: Some(1024) },
 		RuntimeBlob::uncompress_if_needed(&binary[..]).unwrap(),
 		true,
+		false,
 		None,
 	)
 	.unwrap();
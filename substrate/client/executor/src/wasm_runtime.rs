@@ -66,6 +66,8 @@ struct VersionedRuntimeId {
 	wasm_method: WasmExecutionMethod,
 	/// The heap allocation strategy this runtime was created with.
 	heap_alloc_strategy: HeapAllocStrategy,
+	/// Whether this runtime was created with support for execution limits.
+	execution_limits: bool,
 }
 
 /// A Wasm runtime object along with its cached runtime version.
@@ -204,6 +206,9 @@ impl RuntimeCache {
 	///
 	/// `allow_missing_func_imports` - Ignore missing function imports.
 	///
+	/// `execution_limits` - Use a runtime supporting execution limits. Runtimes with and without
+	/// this support are cached separately.
+	///
 	/// `f` - Function to execute.
 	///
 	/// `H` - A compile-time list of host functions to expose to the runtime.
@@ -222,6 +227,7 @@ impl RuntimeCache {
 		wasm_method: WasmExecutionMethod,
 		heap_alloc_strategy: HeapAllocStrategy,
 		allow_missing_func_imports: bool,
+		execution_limits: bool,
 		f: F,
 	) -> Result<Result<R, Error>, Error>
 	where
@@ -235,8 +241,12 @@ impl RuntimeCache {
 	{
 		let code_hash = &runtime_code.hash;
 
-		let versioned_runtime_id =
-			VersionedRuntimeId { code_hash: code_hash.clone(), heap_alloc_strategy, wasm_method };
+		let versioned_runtime_id = VersionedRuntimeId {
+			code_hash: code_hash.clone(),
+			heap_alloc_strategy,
+			wasm_method,
+			execution_limits,
+		};
 
 		let mut runtimes = self.runtimes.lock(); // this must be released prior to calling f
 		let versioned_runtime = if let Some(versioned_runtime) = runtimes.get(&versioned_runtime_id)
@@ -253,6 +263,7 @@ impl RuntimeCache {
 				wasm_method,
 				heap_alloc_strategy,
 				allow_missing_func_imports,
+				execution_limits,
 				self.max_runtime_instances,
 				self.cache_path.as_deref(),
 			);
@@ -287,11 +298,15 @@ impl RuntimeCache {
 }
 
 /// Create a wasm runtime with the given `code`.
+///
+/// If `execution_limits` is set, the runtime supports limiting the calls made into it with
+/// [`WasmInstance::set_execution_limit`].
 pub fn create_wasm_runtime_with_code<H>(
 	wasm_method: WasmExecutionMethod,
 	heap_alloc_strategy: HeapAllocStrategy,
 	blob: RuntimeBlob,
 	allow_missing_func_imports: bool,
+	execution_limits: bool,
 	cache_path: Option<&Path>,
 ) -> Result<Box<dyn WasmModule>, WasmError>
 where
@@ -318,6 +333,7 @@ where
 						wasm_bulk_memory: false,
 						wasm_reference_types: false,
 						wasm_simd: false,
+						execution_limits,
 					},
 				},
 			)
@@ -387,6 +403,7 @@ fn create_versioned_wasm_runtime<H>(
 	wasm_method: WasmExecutionMethod,
 	heap_alloc_strategy: HeapAllocStrategy,
 	allow_missing_func_imports: bool,
+	execution_limits: bool,
 	max_instances: usize,
 	cache_path: Option<&Path>,
 ) -> Result<VersionedRuntime, WasmError>
@@ -407,6 +424,7 @@ where
 		heap_alloc_strategy,
 		blob,
 		allow_missing_func_imports,
+		execution_limits,
 		cache_path,
 	)?;
 
@@ -54,7 +54,15 @@ impl EntryPoint {
 			});
 
 			if let Some(message) = host_state.take_panic_message() {
-				Error::AbortedDueToPanic(MessageWithBacktrace { message, backtrace })
+				return Error::AbortedDueToPanic(MessageWithBacktrace { message, backtrace })
+			}
+
+			let limit_exceeded = matches!(
+				trap.downcast_ref::<wasmtime::Trap>(),
+				Some(wasmtime::Trap::OutOfFuel | wasmtime::Trap::Interrupt)
+			);
+			if let Some(limit) = store.data().execution_limit.filter(|_| limit_exceeded) {
+				Error::ExecutionLimitExceeded(limit)
 			} else {
 				let message = trap.root_cause().to_string();
 				Error::AbortedDueToTrap(MessageWithBacktrace { message, backtrace })
@@ -30,7 +30,7 @@ use sc_executor_common::{
 	error::{Error, Result, WasmError},
 	runtime_blob::RuntimeBlob,
 	util::checked_range,
-	wasm_runtime::{HeapAllocStrategy, WasmInstance, WasmModule},
+	wasm_runtime::{ExecutionLimit, HeapAllocStrategy, WasmInstance, WasmModule},
 };
 use sp_runtime_interface::unpack_ptr_and_len;
 use sp_wasm_interface::{HostFunctions, Pointer, WordSize};
@@ -40,17 +40,31 @@ use std::{
 		atomic::{AtomicBool, Ordering},
 		Arc,
 	},
+	time::Duration,
 };
-use wasmtime::{AsContext, Cache, CacheConfig, Engine, Memory};
+use wasmtime::{AsContext, Cache, CacheConfig, Engine, EngineWeak, Memory};
 
 const MAX_INSTANCE_COUNT: u32 = 64;
 
+/// The interval at which the epoch of an engine with execution limits enabled is incremented.
+///
+/// This is the granularity with which [`ExecutionLimit::Time`] is enforced.
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// The epoch deadline used for calls without a time limit.
+///
+/// Far enough in the future to never be reached, while leaving room for the current epoch to be
+/// added without overflowing.
+const NO_EPOCH_DEADLINE: u64 = u64::MAX / 2;
+
 #[derive(Default)]
 pub(crate) struct StoreData {
 	/// This will only be set when we call into the runtime.
 	pub(crate) host_state: Option<HostState>,
 	/// This will be always set once the store is initialized.
 	pub(crate) memory: Option<Memory>,
+	/// The execution limit of the current call, if any.
+	pub(crate) execution_limit: Option<ExecutionLimit>,
 }
 
 impl StoreData {
@@ -83,6 +97,46 @@ impl InstanceCreator {
 	}
 }
 
+/// Increments the epoch of the given engine every [`EPOCH_TICK`] until the engine is dropped.
+fn spawn_epoch_ticker(engine: EngineWeak) -> std::result::Result<(), WasmError> {
+	std::thread::Builder::new()
+		.name("wasmtime-epoch".into())
+		.spawn(move || loop {
+			std::thread::sleep(EPOCH_TICK);
+			match engine.upgrade() {
+				Some(engine) => engine.increment_epoch(),
+				None => break,
+			}
+		})
+		.map(drop)
+		.map_err(|e| WasmError::Other(format!("cannot spawn the epoch ticker thread: {}", e)))
+}
+
+/// Apply `limit` to the next call made using `store`.
+///
+/// Must be called before every call if execution limits are enabled for the engine, since a fresh
+/// store has neither fuel nor an epoch deadline and would trap right away.
+fn apply_execution_limit(store: &mut Store, limit: Option<ExecutionLimit>) -> Result<()> {
+	let fuel = match limit {
+		Some(ExecutionLimit::Fuel(fuel)) => fuel,
+		_ => u64::MAX,
+	};
+	store
+		.set_fuel(fuel)
+		.map_err(|e| WasmError::Other(format!("cannot set the fuel of the store: {:#}", e)))?;
+
+	let ticks = match limit {
+		// Round up and add one tick, since the current tick may already be almost over.
+		Some(ExecutionLimit::Time(time)) =>
+			(time.as_nanos().div_ceil(EPOCH_TICK.as_nanos()) as u64).saturating_add(1),
+		_ => NO_EPOCH_DEADLINE,
+	};
+	store.set_epoch_deadline(ticks.min(NO_EPOCH_DEADLINE));
+
+	store.data_mut().execution_limit = limit;
+	Ok(())
+}
+
 /// A handle for releasing an instance acquired by [`InstanceCounter::acquire_instance`].
 pub(crate) struct ReleaseInstanceHandle {
 	counter: Arc<InstanceCounter>,
@@ -137,6 +191,7 @@ pub struct WasmtimeRuntime {
 	instance_pre: Arc<wasmtime::InstancePre<StoreData>>,
 	instantiation_strategy: InternalInstantiationStrategy,
 	instance_counter: Arc<InstanceCounter>,
+	execution_limits: bool,
 }
 
 impl WasmModule for WasmtimeRuntime {
@@ -149,7 +204,11 @@ impl WasmModule for WasmtimeRuntime {
 			}),
 		};
 
-		Ok(Box::new(WasmtimeInstance { strategy }))
+		Ok(Box::new(WasmtimeInstance {
+			strategy,
+			execution_limits: self.execution_limits,
+			execution_limit: None,
+		}))
 	}
 }
 
@@ -157,6 +216,10 @@ impl WasmModule for WasmtimeRuntime {
 /// to execute the compiled code.
 pub struct WasmtimeInstance {
 	strategy: Strategy,
+	/// Whether the engine was configured with [`Semantics::execution_limits`].
+	execution_limits: bool,
+	/// The limit applied to every call.
+	execution_limit: Option<ExecutionLimit>,
 }
 
 impl WasmtimeInstance {
@@ -169,6 +232,9 @@ impl WasmtimeInstance {
 		match &mut self.strategy {
 			Strategy::RecreateInstance(ref mut instance_creator) => {
 				let mut instance_wrapper = instance_creator.instantiate()?;
+				if self.execution_limits {
+					apply_execution_limit(instance_wrapper.store_mut(), self.execution_limit)?;
+				}
 				let heap_base = instance_wrapper.extract_heap_base()?;
 				let entrypoint = instance_wrapper.resolve_entrypoint(method)?;
 				let allocator = FreeingBumpHeapAllocator::new(heap_base);
@@ -189,6 +255,19 @@ impl WasmInstance for WasmtimeInstance {
 		let result = self.call_impl(method, data, &mut allocation_stats);
 		(result, allocation_stats)
 	}
+
+	fn set_execution_limit(&mut self, limit: Option<ExecutionLimit>) {
+		if limit.is_some() && !self.execution_limits {
+			log::warn!(
+				target: "wasm-runtime",
+				"Execution limits are not enabled for this runtime, ignoring {:?}.",
+				limit,
+			);
+			return
+		}
+
+		self.execution_limit = limit;
+	}
 }
 
 /// Prepare a directory structure and a config file to enable wasmtime caching.
@@ -250,6 +329,9 @@ fn common_config(semantics: &Semantics) -> std::result::Result<wasmtime::Config,
 
 	config.parallel_compilation(semantics.parallel_compilation);
 
+	config.consume_fuel(semantics.execution_limits);
+	config.epoch_interruption(semantics.execution_limits);
+
 	// Be clear and specific about the extensions we support. If an update brings new features
 	// they should be introduced here as well.
 	config.wasm_reference_types(semantics.wasm_reference_types);
@@ -440,6 +522,15 @@ pub struct Semantics {
 
 	/// Enables WASM Fixed-Width SIMD proposal
 	pub wasm_simd: bool,
+
+	/// Enables support for [`ExecutionLimit`]s.
+	///
+	/// This instruments the compiled code to consume fuel and to check for the epoch deadline,
+	/// which comes with a runtime overhead. A background thread is spawned per runtime to drive
+	/// the epoch while the runtime is alive.
+	///
+	/// If not set, limits passed to [`WasmInstance::set_execution_limit`] are ignored.
+	pub execution_limits: bool,
 }
 
 #[derive(Clone)]
@@ -615,11 +706,16 @@ where
 		.instantiate_pre(&module)
 		.map_err(|e| WasmError::Other(format!("cannot preinstantiate module: {:#}", e)))?;
 
+	if config.semantics.execution_limits {
+		spawn_epoch_ticker(engine.weak())?;
+	}
+
 	Ok(WasmtimeRuntime {
 		engine,
 		instance_pre: Arc::new(instance_pre),
 		instantiation_strategy,
 		instance_counter: Default::default(),
+		execution_limits: config.semantics.execution_limits,
 	})
 }
 
@@ -20,7 +20,7 @@ use codec::{Decode as _, Encode as _};
 use sc_executor_common::{
 	error::Error,
 	runtime_blob::RuntimeBlob,
-	wasm_runtime::{HeapAllocStrategy, WasmModule, DEFAULT_HEAP_ALLOC_STRATEGY},
+	wasm_runtime::{ExecutionLimit, HeapAllocStrategy, WasmModule, DEFAULT_HEAP_ALLOC_STRATEGY},
 };
 use sc_runtime_test::wasm_binary_unwrap;
 
@@ -70,6 +70,7 @@ struct RuntimeBuilder {
 	deterministic_stack: bool,
 	heap_pages: HeapAllocStrategy,
 	precompile_runtime: bool,
+	execution_limits: bool,
 	tmpdir: Option<tempfile::TempDir>,
 }
 
@@ -82,6 +83,7 @@ impl RuntimeBuilder {
 			deterministic_stack: false,
 			heap_pages: DEFAULT_HEAP_ALLOC_STRATEGY,
 			precompile_runtime: false,
+			execution_limits: false,
 			tmpdir: None,
 		}
 	}
@@ -111,6 +113,11 @@ impl RuntimeBuilder {
 		self
 	}
 
+	fn execution_limits(mut self, execution_limits: bool) -> Self {
+		self.execution_limits = execution_limits;
+		self
+	}
+
 	fn build(&mut self) -> impl WasmModule + '_ {
 		let blob = {
 			let wasm: Vec<u8>;
@@ -146,6 +153,7 @@ impl RuntimeBuilder {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				execution_limits: self.execution_limits,
 			},
 		};
 
@@ -453,6 +461,67 @@ fn test_max_memory_pages(
 	assert_grow_fail(HeapAllocStrategy::Static { extra_pages: 10 }, 10, 10);
 }
 
+const INFINITE_LOOP_WAT: &str = r#"
+	(module
+	  (memory $0 32)
+	  (export "memory" (memory $0))
+	  (global (export "__heap_base") i32 (i32.const 0))
+	  (func (export "main")
+	    (param i32 i32) (result i64)
+	    (loop $loop (br $loop))
+	    (i64.const 0)
+	  )
+	)
+"#;
+
+test_wasm_execution!(test_fuel_limit_aborts_execution);
+fn test_fuel_limit_aborts_execution(instantiation_strategy: InstantiationStrategy) {
+	let mut builder = RuntimeBuilder::new(instantiation_strategy)
+		.use_wat(INFINITE_LOOP_WAT.to_string())
+		.execution_limits(true);
+	let runtime = builder.build();
+	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
+
+	instance.set_execution_limit(Some(ExecutionLimit::Fuel(1_000_000)));
+	match instance.call_export("main", &[]).unwrap_err() {
+		Error::ExecutionLimitExceeded(ExecutionLimit::Fuel(1_000_000)) => {},
+		error => panic!("unexpected error: {:?}", error),
+	}
+}
+
+test_wasm_execution!(test_time_limit_aborts_execution);
+fn test_time_limit_aborts_execution(instantiation_strategy: InstantiationStrategy) {
+	let limit = ExecutionLimit::Time(std::time::Duration::from_millis(50));
+	let mut builder = RuntimeBuilder::new(instantiation_strategy)
+		.use_wat(INFINITE_LOOP_WAT.to_string())
+		.execution_limits(true);
+	let runtime = builder.build();
+	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
+
+	instance.set_execution_limit(Some(limit));
+	match instance.call_export("main", &[]).unwrap_err() {
+		Error::ExecutionLimitExceeded(exceeded) => assert_eq!(exceeded, limit),
+		error => panic!("unexpected error: {:?}", error),
+	}
+}
+
+test_wasm_execution!(test_execution_limits_do_not_affect_unlimited_calls);
+fn test_execution_limits_do_not_affect_unlimited_calls(
+	instantiation_strategy: InstantiationStrategy,
+) {
+	let mut builder = RuntimeBuilder::new(instantiation_strategy).execution_limits(true);
+	let runtime = builder.build();
+	let mut instance = runtime.new_instance().expect("failed to instantiate a runtime");
+
+	instance.call_export("test_empty_return", &[0]).unwrap();
+
+	instance.set_execution_limit(Some(ExecutionLimit::Fuel(10_000_000)));
+	instance.call_export("test_empty_return", &[0]).unwrap();
+
+	instance.set_execution_limit(None);
+	instance.call_export("test_empty_return", &[0]).unwrap();
+}
+
 // This test takes quite a while to execute in a debug build (over 6 minutes on a TR 3970x)
 // so it's ignored by default unless it was compiled with `--release`.
 #[cfg_attr(build_profile = "debug", ignore)]
@@ -473,6 +542,7 @@ fn test_instances_without_reuse_are_not_leaked() {
 				wasm_bulk_memory: false,
 				wasm_reference_types: false,
 				wasm_simd: false,
+				execution_limits: false,
 			},
 		},
 	)
@@ -20,19 +20,23 @@ use std::{collections::HashSet, str::FromStr, sync::Arc, thread::sleep};
 
 use crate::NetworkProvider;
 use codec::{Decode, Encode};
-use futures::Future;
+use futures::{future, Future, FutureExt};
 pub use http::SharedClient;
+pub(crate) use policy::OutboundFilter;
+pub use policy::{HostPattern, HostRateLimit, InvalidHostPattern, OutboundPolicy};
 use sc_network::Multiaddr;
 use sc_network_types::PeerId;
 use sp_core::{
 	offchain::{
 		self, HttpError, HttpRequestId, HttpRequestStatus, OpaqueMultiaddr, OpaqueNetworkState,
-		Timestamp,
+		Timestamp, WsConnectionId, WsError,
 	},
 	OpaquePeerId,
 };
 
 mod http;
+mod policy;
+mod ws;
 
 mod timestamp;
 
@@ -47,6 +51,8 @@ pub(crate) struct Api {
 	is_validator: bool,
 	/// Everything HTTP-related is handled by a different struct.
 	http: http::HttpApi,
+	/// Everything WebSocket-related is handled by a different struct.
+	ws: ws::WsApi,
 }
 
 impl offchain::Externalities for Api {
@@ -121,6 +127,35 @@ impl offchain::Externalities for Api {
 		self.http.response_read_body(request_id, buffer, deadline)
 	}
 
+	fn ws_connect(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError> {
+		self.ws.connect(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), WsError> {
+		self.ws.send(id, message, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WsConnectionId,
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError> {
+		self.ws.receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WsConnectionId) {
+		self.ws.close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		let peer_ids: HashSet<PeerId> =
 			nodes.into_iter().filter_map(|node| PeerId::from_bytes(&node.0).ok()).collect();
@@ -193,6 +228,8 @@ impl TryFrom<OpaqueNetworkState> for NetworkState {
 pub(crate) struct AsyncApi {
 	/// Everything HTTP-related is handled by a different struct.
 	http: Option<http::HttpWorker>,
+	/// Everything WebSocket-related is handled by a different struct.
+	ws: Option<ws::WsWorker>,
 }
 
 impl AsyncApi {
@@ -201,19 +238,23 @@ impl AsyncApi {
 		network_provider: Arc<dyn NetworkProvider + Send + Sync>,
 		is_validator: bool,
 		shared_http_client: SharedClient,
+		outbound_filter: OutboundFilter,
 	) -> (Api, Self) {
-		let (http_api, http_worker) = http::http(shared_http_client);
+		let (http_api, http_worker) = http::http(shared_http_client, outbound_filter.clone());
+		let (ws_api, ws_worker) = ws::ws(outbound_filter);
 
-		let api = Api { network_provider, is_validator, http: http_api };
+		let api = Api { network_provider, is_validator, http: http_api, ws: ws_api };
 
-		let async_api = Self { http: Some(http_worker) };
+		let async_api = Self { http: Some(http_worker), ws: Some(ws_worker) };
 
 		(api, async_api)
 	}
 
 	/// Run a processing task for the API
 	pub fn process(self) -> impl Future<Output = ()> {
-		self.http.expect("`process` is only called once; qed")
+		let http = self.http.expect("`process` is only called once; qed");
+		let ws = self.ws.expect("`process` is only called once; qed");
+		future::join(http, ws).map(drop)
 	}
 }
 
@@ -326,9 +367,9 @@ mod tests {
 	fn offchain_api() -> (Api, AsyncApi) {
 		sp_tracing::try_init_simple();
 		let mock = Arc::new(TestNetwork());
-		let shared_client = SharedClient::new().unwrap();
+		let shared_client = SharedClient::new(Default::default()).unwrap();
 
-		AsyncApi::new(mock, false, shared_client)
+		AsyncApi::new(mock, false, shared_client, Default::default())
 	}
 
 	fn offchain_db() -> OffchainDb<LocalStorage> {
@@ -27,7 +27,10 @@
 //! (i.e.: the socket should continue being processed) in the background even if the runtime isn't
 //! actively calling any function.
 
-use crate::api::timestamp;
+use crate::api::{
+	policy::{OutboundFilter, PolicyResolver},
+	timestamp,
+};
 use bytes::buf::{Buf, Reader};
 use fnv::FnvHashMap;
 use futures::{channel::mpsc, future, prelude::*};
@@ -53,7 +56,8 @@ pub type Body = BoxBody<hyper::body::Bytes, hyper::Error>;
 type Sender = mpsc::Sender<Result<hyper::body::Frame<hyper::body::Bytes>, hyper::Error>>;
 type Receiver = mpsc::Receiver<Result<hyper::body::Frame<hyper::body::Bytes>, hyper::Error>>;
 
-type HyperClient = client::Client<HttpsConnector<client::connect::HttpConnector>, Body>;
+type HyperClient =
+	client::Client<HttpsConnector<client::connect::HttpConnector<PolicyResolver>>, Body>;
 type LazyClient = Lazy<HyperClient, Box<dyn FnOnce() -> HyperClient + Send>>;
 
 /// Wrapper struct used for keeping the hyper_rustls client running.
@@ -61,24 +65,30 @@ type LazyClient = Lazy<HyperClient, Box<dyn FnOnce() -> HyperClient + Send>>;
 pub struct SharedClient(Arc<LazyClient>);
 
 impl SharedClient {
-	pub fn new() -> std::io::Result<Self> {
+	/// Creates a new client, only connecting to the addresses allowed by `filter`.
+	pub fn new(filter: OutboundFilter) -> std::io::Result<Self> {
 		let builder = HttpsConnectorBuilder::new()
 			.with_provider_and_native_roots(rustls::crypto::ring::default_provider())?;
-		Ok(Self(Arc::new(Lazy::new(Box::new(|| {
-			let connector = builder.https_or_http().enable_http1().enable_http2().build();
+		Ok(Self(Arc::new(Lazy::new(Box::new(move || {
+			let mut http =
+				client::connect::HttpConnector::new_with_resolver(PolicyResolver(filter));
+			http.enforce_http(false);
+			let connector =
+				builder.https_or_http().enable_http1().enable_http2().wrap_connector(http);
 			client::Client::builder(TokioExecutor::new()).build(connector)
 		})))))
 	}
 }
 
 /// Creates a pair of [`HttpApi`] and [`HttpWorker`].
-pub fn http(shared_client: SharedClient) -> (HttpApi, HttpWorker) {
+pub fn http(shared_client: SharedClient, filter: OutboundFilter) -> (HttpApi, HttpWorker) {
 	let (to_worker, from_api) = tracing_unbounded("mpsc_ocw_to_worker", 100_000);
 	let (to_api, from_worker) = tracing_unbounded("mpsc_ocw_to_api", 100_000);
 
 	let api = HttpApi {
 		to_worker,
 		from_worker: from_worker.fuse(),
+		filter,
 		// We start with a random ID for the first HTTP request, to prevent mischievous people from
 		// writing runtime code with hardcoded IDs.
 		next_id: HttpRequestId(rand::random::<u16>() % 2000),
@@ -101,6 +111,8 @@ pub struct HttpApi {
 	/// Used to receive messages from the worker.
 	/// We use a `Fuse` in order to have an extra protection against panicking.
 	from_worker: stream::Fuse<TracingUnboundedReceiver<WorkerToApi>>,
+	/// Decides which hosts can be sent requests to.
+	filter: OutboundFilter,
 	/// Id to assign to the next HTTP request that is started.
 	next_id: HttpRequestId,
 	/// List of HTTP requests in preparation or in progress.
@@ -155,6 +167,9 @@ impl HttpApi {
 		let mut request = hyper::Request::new(body);
 		*request.method_mut() = hyper::Method::from_bytes(method.as_bytes()).map_err(|_| ())?;
 		*request.uri_mut() = hyper::Uri::from_maybe_shared(uri.to_owned()).map_err(|_| ())?;
+		if !self.filter.check(request.uri()) {
+			return Err(());
+		}
 
 		let new_id = self.next_id;
 		debug_assert!(!self.requests.contains_key(&new_id));
@@ -772,7 +787,11 @@ impl fmt::Debug for HttpWorkerRequest {
 #[cfg(test)]
 mod tests {
 	use super::{
-		super::{tests::TestNetwork, AsyncApi},
+		super::{
+			policy::{HostRateLimit, OutboundPolicy},
+			tests::TestNetwork,
+			AsyncApi,
+		},
 		*,
 	};
 	use crate::api::timestamp;
@@ -784,7 +803,8 @@ mod tests {
 
 	// Using LazyLock to avoid spawning lots of different SharedClients,
 	// as spawning a SharedClient is CPU-intensive and opens lots of fds.
-	static SHARED_CLIENT: LazyLock<SharedClient> = LazyLock::new(|| SharedClient::new().unwrap());
+	static SHARED_CLIENT: LazyLock<SharedClient> =
+		LazyLock::new(|| SharedClient::new(Default::default()).unwrap());
 
 	// Returns an `HttpApi` whose worker is ran in the background, and a `SocketAddr` to an HTTP
 	// server that runs in the background as well.
@@ -794,9 +814,15 @@ mod tests {
 				hyper::body::Bytes::from("Hello World!")
 			)))
 		};
-		( $response:expr ) => {{
-			let hyper_client = SHARED_CLIENT.clone();
-			let (api, worker) = http(hyper_client.clone());
+		( $response:expr ) => {
+			build_api_server!($response, SHARED_CLIENT.clone(), OutboundFilter::default())
+		};
+		( $response:expr, $filter:expr ) => {{
+			let filter = $filter;
+			build_api_server!($response, SharedClient::new(filter.clone()).unwrap(), filter)
+		}};
+		( $response:expr, $client:expr, $filter:expr ) => {{
+			let (api, worker) = http($client, $filter);
 
 			let (addr_tx, addr_rx) = std::sync::mpsc::channel();
 			std::thread::spawn(move || {
@@ -902,6 +928,62 @@ mod tests {
 		};
 	}
 
+	#[test]
+	fn request_start_denied_by_policy() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+		let (mut api, addr) = build_api_server!(
+			hyper::Response::new(http_body_util::Full::new(hyper::body::Bytes::from(
+				"Hello World!"
+			))),
+			OutboundFilter::new(OutboundPolicy {
+				denylist: vec!["127.0.0.0/8".parse().unwrap(), "[::1]".parse().unwrap()],
+				..Default::default()
+			})
+		);
+
+		match api.request_start("GET", &format!("http://{}", addr)) {
+			Err(()) => {},
+			Ok(_) => panic!(),
+		};
+
+		// Names resolving to a denied address can't be used to bypass the policy.
+		let id = api.request_start("GET", &format!("http://localhost:{}", addr.port())).unwrap();
+		match api.response_wait(&[id], Some(deadline))[0] {
+			HttpRequestStatus::IoError => {},
+			v => panic!("Connecting to localhost should have been denied: {:?}", v),
+		}
+	}
+
+	#[test]
+	fn request_start_rate_limited() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+		let (mut api, addr) = build_api_server!(
+			hyper::Response::new(http_body_util::Full::new(hyper::body::Bytes::from(
+				"Hello World!"
+			))),
+			OutboundFilter::new(OutboundPolicy {
+				rate_limit: Some(HostRateLimit {
+					max_requests: 2.try_into().unwrap(),
+					period: std::time::Duration::from_secs(3600),
+				}),
+				..Default::default()
+			})
+		);
+
+		for _ in 0..2 {
+			let id = api.request_start("GET", &format!("http://{}", addr)).unwrap();
+			match api.response_wait(&[id], Some(deadline))[0] {
+				HttpRequestStatus::Finished(200) => {},
+				v => panic!("Connecting to localhost failed: {:?}", v),
+			}
+		}
+
+		match api.request_start("GET", &format!("http://{}", addr)) {
+			Err(()) => {},
+			Ok(_) => panic!(),
+		};
+	}
+
 	#[test]
 	fn request_add_header_invalid_call() {
 		let (mut api, addr) = build_api_server!();
@@ -1126,11 +1208,12 @@ mod tests {
 
 	#[test]
 	fn shared_http_client_is_only_initialized_on_access() {
-		let shared_client = SharedClient::new().unwrap();
+		let shared_client = SharedClient::new(Default::default()).unwrap();
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) =
+				AsyncApi::new(mock, false, shared_client.clone(), Default::default());
 			api.timestamp();
 
 			futures::executor::block_on(async move {
@@ -1141,11 +1224,12 @@ mod tests {
 		// Check that the http client wasn't initialized, because it wasn't used.
 		assert!(Lazy::into_value(Arc::try_unwrap(shared_client.0).unwrap()).is_err());
 
-		let shared_client = SharedClient::new().unwrap();
+		let shared_client = SharedClient::new(Default::default()).unwrap();
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) =
+				AsyncApi::new(mock, false, shared_client.clone(), Default::default());
 			let id = api.http_request_start("lol", "nope", &[]).unwrap();
 			api.http_request_write_body(id, &[], None).unwrap();
 			futures::executor::block_on(async move {
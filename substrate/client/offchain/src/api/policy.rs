@@ -0,0 +1,525 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Operator controlled restrictions on the hosts offchain workers may contact.
+//!
+//! The [`OutboundPolicy`] is checked every time an offchain worker starts an HTTP request or
+//! opens a WebSocket connection. Requests to a host that isn't allowed, or that exceed the rate
+//! limit of that host, fail right away without touching the network.
+
+use parking_lot::Mutex;
+use std::{
+	collections::HashMap,
+	fmt, io,
+	net::{IpAddr, SocketAddr},
+	num::NonZeroU32,
+	str::FromStr,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+const LOG_TARGET: &str = "offchain-worker::policy";
+
+/// Number of hosts tracked by the rate limiter.
+///
+/// Once reached, requests to hosts that aren't tracked yet are denied until some of the tracked
+/// windows have expired.
+const MAX_TRACKED_HOSTS: usize = 1024;
+
+/// Pattern matching the host of a URL.
+///
+/// Patterns are parsed from strings of the form:
+/// - `*`: matches any host.
+/// - `*.example.com`: matches any subdomain of `example.com`, but not `example.com` itself.
+/// - `example.com`: matches exactly this host name.
+/// - `127.0.0.1`, `[::1]` or `10.0.0.0/8`: matches the addresses in this network.
+///
+/// Host names are matched case insensitively. Networks are matched against the addresses the
+/// host resolves to, so a name can't be used to reach a denied address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostPattern {
+	/// Matches any host.
+	Any,
+	/// Matches exactly the given host name.
+	Exact(String),
+	/// Matches any subdomain of the given domain.
+	Subdomains(String),
+	/// Matches the addresses sharing the first `prefix` bits with the given address.
+	Network(IpAddr, u8),
+}
+
+impl HostPattern {
+	/// Returns `true` if the host name `host` matches this pattern.
+	///
+	/// `host` is expected to be lowercase. Networks only match hosts that are IP addresses.
+	pub fn matches(&self, host: &str) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Exact(exact) => host == exact,
+			Self::Subdomains(domain) =>
+				host.len() > domain.len() + 1 &&
+					host.ends_with(domain.as_str()) &&
+					host.as_bytes()[host.len() - domain.len() - 1] == b'.',
+			Self::Network(..) => parse_ip(host).map_or(false, |ip| self.matches_ip(ip)),
+		}
+	}
+
+	/// Returns `true` if the address `ip` matches this pattern.
+	///
+	/// Only [`Self::Any`] and [`Self::Network`] match addresses.
+	pub fn matches_ip(&self, ip: IpAddr) -> bool {
+		match (self, ip.to_canonical()) {
+			(Self::Any, _) => true,
+			(Self::Network(IpAddr::V4(network), prefix), IpAddr::V4(ip)) =>
+				prefix_matches(&network.octets(), &ip.octets(), *prefix),
+			(Self::Network(IpAddr::V6(network), prefix), IpAddr::V6(ip)) =>
+				prefix_matches(&network.octets(), &ip.octets(), *prefix),
+			_ => false,
+		}
+	}
+
+	fn is_network(&self) -> bool {
+		matches!(self, Self::Network(..))
+	}
+}
+
+/// Parses `host` as an IP address, with IPv6 addresses in brackets as they appear in URLs.
+fn parse_ip(host: &str) -> Option<IpAddr> {
+	let host = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')).unwrap_or(host);
+	host.parse::<IpAddr>().ok().map(|ip| ip.to_canonical())
+}
+
+/// Returns `true` if the first `prefix` bits of `a` and `b` are equal.
+fn prefix_matches(a: &[u8], b: &[u8], prefix: u8) -> bool {
+	let (bytes, bits) = (usize::from(prefix / 8), prefix % 8);
+	a[..bytes] == b[..bytes] && (bits == 0 || (a[bytes] ^ b[bytes]) & (0xff_u8 << (8 - bits)) == 0)
+}
+
+/// Error returned when parsing an invalid [`HostPattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidHostPattern(String);
+
+impl fmt::Display for InvalidHostPattern {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Invalid host pattern: {:?}", self.0)
+	}
+}
+
+impl std::error::Error for InvalidHostPattern {}
+
+impl FromStr for HostPattern {
+	type Err = InvalidHostPattern;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || InvalidHostPattern(s.to_owned());
+		let pattern = s.trim_end_matches('.').to_ascii_lowercase();
+
+		if pattern == "*" {
+			return Ok(Self::Any)
+		}
+
+		let (address, prefix) = match pattern.split_once('/') {
+			Some((address, prefix)) => (address, Some(prefix)),
+			None => (pattern.as_str(), None),
+		};
+		if let Some(ip) = parse_ip(address) {
+			let max_prefix = if ip.is_ipv4() { 32 } else { 128 };
+			let prefix = match prefix {
+				Some(prefix) => prefix.parse::<u8>().map_err(|_| invalid())?,
+				None => max_prefix,
+			};
+			if prefix > max_prefix {
+				return Err(invalid())
+			}
+			return Ok(Self::Network(ip, prefix))
+		}
+
+		let (pattern, subdomains) = match pattern.strip_prefix("*.") {
+			Some(domain) => (domain.to_owned(), true),
+			None => (pattern, false),
+		};
+
+		if pattern.is_empty() ||
+			pattern
+				.chars()
+				.any(|c| c.is_whitespace() || matches!(c, '*' | '/' | '@' | '?' | '#'))
+		{
+			return Err(invalid())
+		}
+
+		Ok(if subdomains { Self::Subdomains(pattern) } else { Self::Exact(pattern) })
+	}
+}
+
+/// Limit on the number of outbound requests made to a single host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostRateLimit {
+	/// Maximum number of requests that can be started within `period`.
+	pub max_requests: NonZeroU32,
+	/// Length of the window over which requests are counted.
+	pub period: Duration,
+}
+
+/// Restrictions on the hosts that offchain workers may contact.
+///
+/// Applies to both HTTP requests and WebSocket connections. The default policy allows every host
+/// without any rate limit.
+#[derive(Debug, Clone, Default)]
+pub struct OutboundPolicy {
+	/// If not empty, only hosts matching at least one of these patterns may be contacted.
+	///
+	/// A host is allowed if either its name or the address it is contacted at matches.
+	pub allowlist: Vec<HostPattern>,
+	/// Hosts matching any of these patterns may never be contacted.
+	///
+	/// Takes precedence over [`Self::allowlist`]. A host is denied if either its name or the
+	/// address it is contacted at matches.
+	pub denylist: Vec<HostPattern>,
+	/// Limit on the number of requests per host.
+	///
+	/// The limit is shared by all offchain workers run by the node.
+	pub rate_limit: Option<HostRateLimit>,
+}
+
+impl OutboundPolicy {
+	/// Returns `true` if the lists of this policy may allow contacting the host named `host`,
+	/// depending on the addresses it resolves to.
+	///
+	/// `host` is expected to be lowercase.
+	pub fn is_allowed(&self, host: &str) -> bool {
+		if let Some(ip) = parse_ip(host) {
+			return self.is_address_allowed(host, ip)
+		}
+
+		!self.denylist.iter().any(|p| p.matches(host)) &&
+			(self.allowlist.is_empty() ||
+				self.allowlist.iter().any(|p| p.matches(host) || p.is_network()))
+	}
+
+	/// Returns `true` if the lists of this policy allow contacting the host named `host` at the
+	/// address `ip`.
+	///
+	/// `host` is expected to be lowercase.
+	pub fn is_address_allowed(&self, host: &str, ip: IpAddr) -> bool {
+		!self.denylist.iter().any(|p| p.matches(host) || p.matches_ip(ip)) &&
+			(self.allowlist.is_empty() ||
+				self.allowlist.iter().any(|p| p.matches(host) || p.matches_ip(ip)))
+	}
+
+	fn is_unrestricted(&self) -> bool {
+		self.allowlist.is_empty() && self.denylist.is_empty() && self.rate_limit.is_none()
+	}
+}
+
+/// Requests made to a host in the current rate limit window.
+struct Window {
+	start: Instant,
+	count: u32,
+}
+
+/// Enforces an [`OutboundPolicy`], keeping track of the requests made to every host.
+///
+/// Cloning is cheap and all clones share the same rate limit state.
+#[derive(Clone, Default)]
+pub(crate) struct OutboundFilter(Arc<OutboundFilterInner>);
+
+#[derive(Default)]
+struct OutboundFilterInner {
+	policy: OutboundPolicy,
+	windows: Mutex<HashMap<String, Window>>,
+}
+
+impl OutboundFilter {
+	/// Creates a new filter enforcing `policy`.
+	pub fn new(policy: OutboundPolicy) -> Self {
+		Self(Arc::new(OutboundFilterInner { policy, windows: Default::default() }))
+	}
+
+	/// Returns `true` if a request to `uri` may be started, and accounts for it.
+	///
+	/// The addresses the host resolves to must still be checked with [`Self::resolve`].
+	pub fn check(&self, uri: &hyper::Uri) -> bool {
+		self.check_at(uri, Instant::now())
+	}
+
+	fn check_at(&self, uri: &hyper::Uri, now: Instant) -> bool {
+		let policy = &self.0.policy;
+		if policy.is_unrestricted() {
+			return true
+		}
+
+		let Some(host) = uri.host() else {
+			tracing::debug!(target: LOG_TARGET, %uri, "Denied request without host");
+			return false
+		};
+		let host = normalize_host(host);
+
+		if !policy.is_allowed(&host) {
+			tracing::debug!(target: LOG_TARGET, %host, "Denied request to host by policy");
+			return false
+		}
+
+		let Some(limit) = policy.rate_limit else { return true };
+
+		let mut windows = self.0.windows.lock();
+		if windows.len() >= MAX_TRACKED_HOSTS && !windows.contains_key(&host) {
+			windows.retain(|_, w| now.saturating_duration_since(w.start) < limit.period);
+			if windows.len() >= MAX_TRACKED_HOSTS {
+				tracing::debug!(target: LOG_TARGET, %host, "Denied request, too many hosts tracked");
+				return false
+			}
+		}
+
+		let window = windows.entry(host).or_insert(Window { start: now, count: 0 });
+		if now.saturating_duration_since(window.start) >= limit.period {
+			*window = Window { start: now, count: 0 };
+		}
+
+		if window.count >= limit.max_requests.get() {
+			tracing::debug!(target: LOG_TARGET, %uri, "Denied request to host by rate limit");
+			return false
+		}
+
+		window.count += 1;
+		true
+	}
+
+	/// Resolves `host` and returns the addresses it may be contacted at on `port`.
+	///
+	/// Fails if the policy allows none of the addresses.
+	pub async fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+		let host = normalize_host(host);
+		let lookup_host = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')).unwrap_or(&host);
+		let addrs = tokio::net::lookup_host((lookup_host, port)).await?;
+		self.filter_addresses(&host, addrs)
+	}
+
+	fn filter_addresses(
+		&self,
+		host: &str,
+		addrs: impl Iterator<Item = SocketAddr>,
+	) -> io::Result<Vec<SocketAddr>> {
+		let policy = &self.0.policy;
+		let allowed: Vec<_> =
+			addrs.filter(|addr| policy.is_address_allowed(host, addr.ip())).collect();
+
+		if allowed.is_empty() {
+			tracing::debug!(target: LOG_TARGET, %host, "Denied request to host addresses by policy");
+			return Err(io::Error::new(
+				io::ErrorKind::PermissionDenied,
+				format!("{host} only resolves to addresses denied by the outbound policy"),
+			))
+		}
+
+		Ok(allowed)
+	}
+}
+
+fn normalize_host(host: &str) -> String {
+	host.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// DNS resolver of the HTTP client, only returning the addresses allowed by an
+/// [`OutboundFilter`].
+#[derive(Clone)]
+pub(crate) struct PolicyResolver(pub OutboundFilter);
+
+impl tower::Service<hyper_util::client::legacy::connect::dns::Name> for PolicyResolver {
+	type Response = std::vec::IntoIter<SocketAddr>;
+	type Error = io::Error;
+	type Future = futures::future::BoxFuture<'static, io::Result<Self::Response>>;
+
+	fn poll_ready(&mut self, _cx: &mut std::task::Context<'_>) -> std::task::Poll<io::Result<()>> {
+		std::task::Poll::Ready(Ok(()))
+	}
+
+	fn call(&mut self, name: hyper_util::client::legacy::connect::dns::Name) -> Self::Future {
+		let filter = self.0.clone();
+		// the port is set by the connector.
+		Box::pin(async move { filter.resolve(name.as_str(), 0).await.map(Vec::into_iter) })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn uri(s: &str) -> hyper::Uri {
+		s.parse().unwrap()
+	}
+
+	fn pattern(s: &str) -> HostPattern {
+		s.parse().unwrap()
+	}
+
+	#[test]
+	fn host_patterns_are_parsed() {
+		assert_eq!(pattern("*"), HostPattern::Any);
+		assert_eq!(pattern("Example.COM."), HostPattern::Exact("example.com".into()));
+		assert_eq!(pattern("*.example.com"), HostPattern::Subdomains("example.com".into()));
+		assert_eq!(pattern("[::1]"), HostPattern::Network("::1".parse().unwrap(), 128));
+		assert_eq!(pattern("10.0.0.0/8"), HostPattern::Network("10.0.0.0".parse().unwrap(), 8));
+
+		for invalid in
+			["", "ex*ample.com", "*.*.example.com", "example.com/path", "a b", "10.0.0.0/33"]
+		{
+			assert!(invalid.parse::<HostPattern>().is_err(), "{invalid:?} should be invalid");
+		}
+	}
+
+	#[test]
+	fn host_patterns_match() {
+		assert!(pattern("*").matches("example.com"));
+
+		assert!(pattern("example.com").matches("example.com"));
+		assert!(!pattern("example.com").matches("api.example.com"));
+
+		assert!(pattern("*.example.com").matches("api.example.com"));
+		assert!(pattern("*.example.com").matches("a.b.example.com"));
+		assert!(!pattern("*.example.com").matches("example.com"));
+		assert!(!pattern("*.example.com").matches("badexample.com"));
+
+		assert!(pattern("10.0.0.0/8").matches("10.1.2.3"));
+		assert!(!pattern("10.0.0.0/8").matches("11.0.0.1"));
+		assert!(pattern("[::1]").matches("[::1]"));
+		assert!(!pattern("127.0.0.1").matches("localhost"));
+	}
+
+	#[test]
+	fn network_patterns_match_addresses() {
+		let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+
+		assert!(pattern("127.0.0.0/8").matches_ip(ip("127.0.0.1")));
+		assert!(pattern("127.0.0.0/8").matches_ip(ip("::ffff:127.0.0.1")));
+		assert!(pattern("192.168.0.0/23").matches_ip(ip("192.168.1.255")));
+		assert!(!pattern("192.168.0.0/23").matches_ip(ip("192.168.2.0")));
+		assert!(pattern("fe80::/10").matches_ip(ip("fe80::1")));
+		assert!(!pattern("fe80::/10").matches_ip(ip("127.0.0.1")));
+		assert!(pattern("0.0.0.0/0").matches_ip(ip("8.8.8.8")));
+		assert!(!pattern("example.com").matches_ip(ip("8.8.8.8")));
+	}
+
+	#[test]
+	fn resolved_addresses_are_filtered() {
+		let addr = |s: &str| s.parse::<SocketAddr>().unwrap();
+		let filter = OutboundFilter::new(OutboundPolicy {
+			denylist: vec![pattern("127.0.0.0/8"), pattern("[::1]")],
+			..Default::default()
+		});
+
+		// The name itself isn't denied, only the addresses it resolves to.
+		assert!(filter.check(&uri("http://localhost")));
+		assert!(!filter.check(&uri("http://127.0.0.1")));
+		assert_eq!(
+			filter
+				.filter_addresses("localhost", [addr("127.0.0.1:80"), addr("[::1]:80")].into_iter())
+				.unwrap_err()
+				.kind(),
+			io::ErrorKind::PermissionDenied,
+		);
+		assert_eq!(
+			filter
+				.filter_addresses(
+					"example.com",
+					[addr("127.0.0.1:80"), addr("1.2.3.4:80")].into_iter()
+				)
+				.unwrap(),
+			vec![addr("1.2.3.4:80")],
+		);
+
+		let filter = OutboundFilter::new(OutboundPolicy {
+			allowlist: vec![pattern("10.0.0.0/8")],
+			..Default::default()
+		});
+
+		// Names may resolve to an allowed network.
+		assert!(filter.check(&uri("http://internal.service")));
+		assert!(filter
+			.filter_addresses("internal.service", [addr("10.0.0.1:80")].into_iter())
+			.is_ok());
+		assert!(filter
+			.filter_addresses("internal.service", [addr("1.2.3.4:80")].into_iter())
+			.is_err());
+		assert!(!filter.check(&uri("http://1.2.3.4")));
+	}
+
+	#[test]
+	fn denylist_takes_precedence_over_allowlist() {
+		let filter = OutboundFilter::new(OutboundPolicy {
+			allowlist: vec![pattern("*.example.com")],
+			denylist: vec![pattern("evil.example.com")],
+			rate_limit: None,
+		});
+
+		assert!(filter.check(&uri("https://api.example.com/price")));
+		assert!(filter.check(&uri("wss://API.Example.com:443")));
+		assert!(!filter.check(&uri("https://evil.example.com")));
+		assert!(!filter.check(&uri("https://other.org")));
+		assert!(!filter.check(&uri("/no/host")));
+	}
+
+	#[test]
+	fn default_policy_allows_everything() {
+		let filter = OutboundFilter::default();
+
+		assert!(filter.check(&uri("https://example.com")));
+		assert!(filter.check(&uri("nope")));
+	}
+
+	#[test]
+	fn rate_limit_is_enforced_per_host() {
+		let period = Duration::from_secs(10);
+		let filter = OutboundFilter::new(OutboundPolicy {
+			rate_limit: Some(HostRateLimit { max_requests: NonZeroU32::new(2).unwrap(), period }),
+			..Default::default()
+		});
+		let now = Instant::now();
+
+		assert!(filter.check_at(&uri("http://a.com"), now));
+		assert!(filter.check_at(&uri("http://a.com/other"), now));
+		assert!(!filter.check_at(&uri("http://a.com"), now + Duration::from_secs(9)));
+		// Other hosts have their own budget.
+		assert!(filter.check_at(&uri("http://b.com"), now));
+
+		// Clones share the same state.
+		assert!(!filter.clone().check_at(&uri("http://a.com"), now));
+
+		// A new window starts after `period`.
+		assert!(filter.check_at(&uri("http://a.com"), now + period));
+	}
+
+	#[test]
+	fn rate_limited_hosts_are_bounded() {
+		let period = Duration::from_secs(10);
+		let filter = OutboundFilter::new(OutboundPolicy {
+			rate_limit: Some(HostRateLimit { max_requests: NonZeroU32::new(1).unwrap(), period }),
+			..Default::default()
+		});
+		let now = Instant::now();
+
+		for i in 0..MAX_TRACKED_HOSTS {
+			assert!(filter.check_at(&uri(&format!("http://{i}.com")), now));
+		}
+		assert!(!filter.check_at(&uri("http://new.com"), now));
+		assert_eq!(filter.0.windows.lock().len(), MAX_TRACKED_HOSTS);
+
+		// Expired windows make room for new hosts.
+		assert!(filter.check_at(&uri("http://new.com"), now + period));
+		assert_eq!(filter.0.windows.lock().len(), 1);
+	}
+}
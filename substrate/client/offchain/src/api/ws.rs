@@ -0,0 +1,544 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module is composed of two structs: [`WsApi`] and [`WsWorker`]. Calling the [`ws`]
+//! function returns a pair of [`WsApi`] and [`WsWorker`] that share some state.
+//!
+//! Just like for HTTP, the [`WsApi`] is (indirectly) passed to the runtime when calling an
+//! offchain worker, while the [`WsWorker`] must be processed in the background. The worker keeps
+//! the sockets alive and buffers incoming messages while the runtime is busy doing something else.
+//!
+//! Connections only live as long as the offchain worker run that opened them: once the
+//! [`WsApi`] is dropped, the [`WsWorker`] finishes and all its sockets are closed.
+
+use crate::api::{policy::OutboundFilter, timestamp};
+use fnv::FnvHashMap;
+use futures::{
+	channel::mpsc,
+	future::{self, BoxFuture},
+	prelude::*,
+	stream::FuturesUnordered,
+};
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use sp_core::offchain::{Timestamp, WsConnectionId, WsError};
+use std::{
+	fmt,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use tokio_tungstenite::tungstenite::{self, Message};
+
+const LOG_TARGET: &str = "offchain-worker::ws";
+
+/// Number of messages buffered in each direction of a connection.
+const MESSAGE_BUFFER: usize = 16;
+
+/// Creates a pair of [`WsApi`] and [`WsWorker`].
+pub fn ws(filter: OutboundFilter) -> (WsApi, WsWorker) {
+	let (to_worker, from_api) = tracing_unbounded("mpsc_ocw_ws_to_worker", 100_000);
+	let (to_api, from_worker) = tracing_unbounded("mpsc_ocw_ws_to_api", 100_000);
+
+	let api = WsApi {
+		to_worker,
+		from_worker: from_worker.fuse(),
+		filter: filter.clone(),
+		// Same as for HTTP requests, we start with a random ID to prevent runtime code from
+		// hardcoding IDs.
+		next_id: WsConnectionId(rand::random::<u16>() % 2000),
+		connections: FnvHashMap::default(),
+	};
+
+	let worker = WsWorker { to_api, from_api, filter, connections: FuturesUnordered::new() };
+
+	(api, worker)
+}
+
+/// Provides WebSocket capabilities.
+///
+/// Since this struct is a helper for offchain workers, its API is mimicking the API provided
+/// to offchain workers.
+pub struct WsApi {
+	/// Used to sends messages to the worker.
+	to_worker: TracingUnboundedSender<ApiToWorker>,
+	/// Used to receive messages from the worker.
+	from_worker: stream::Fuse<TracingUnboundedReceiver<WorkerToApi>>,
+	/// Decides which hosts can be connected to.
+	filter: OutboundFilter,
+	/// Id to assign to the next connection that is opened.
+	next_id: WsConnectionId,
+	/// List of open connections.
+	connections: FnvHashMap<WsConnectionId, WsApiConnection>,
+}
+
+/// One open connection within `WsApi`.
+struct WsApiConnection {
+	/// Messages to send to the remote.
+	outgoing: mpsc::Sender<Vec<u8>>,
+	/// Messages received from the remote. Ends once the connection is closed.
+	incoming: stream::Fuse<mpsc::Receiver<Vec<u8>>>,
+}
+
+impl WsApi {
+	/// Mimics the corresponding method in the offchain API.
+	pub fn connect(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError> {
+		let parsed = hyper::Uri::from_maybe_shared(uri.to_owned()).map_err(|_| WsError::Invalid)?;
+		if !matches!(parsed.scheme_str(), Some("ws") | Some("wss")) {
+			return Err(WsError::Invalid)
+		}
+		if !self.filter.check(&parsed) {
+			return Err(WsError::Forbidden)
+		}
+
+		let id = self.next_id;
+		match self.next_id.0.checked_add(1) {
+			Some(new_id) => self.next_id.0 = new_id,
+			None => {
+				tracing::error!(
+					target: LOG_TARGET,
+					"Overflow in offchain worker WebSocket connection ID assignment"
+				);
+				return Err(WsError::Invalid);
+			},
+		};
+
+		let _ = self.to_worker.unbounded_send(ApiToWorker::Connect { id, uri: uri.to_owned() });
+		tracing::trace!(target: LOG_TARGET, id = %id.0, %uri, "Connecting");
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+
+		loop {
+			let next_message = {
+				let mut next_msg = future::maybe_done(self.from_worker.next());
+				futures::executor::block_on(future::select(&mut next_msg, &mut deadline));
+				if let future::MaybeDone::Done(msg) = next_msg {
+					msg
+				} else {
+					tracing::debug!(target: LOG_TARGET, id = %id.0, "Deadline reached while connecting");
+					return Err(WsError::DeadlineReached);
+				}
+			};
+
+			match next_message {
+				Some(WorkerToApi::Connected { id: conn_id, outgoing, incoming })
+					if conn_id == id =>
+				{
+					tracing::debug!(target: LOG_TARGET, id = %id.0, "Connected");
+					self.connections
+						.insert(id, WsApiConnection { outgoing, incoming: incoming.fuse() });
+					return Ok(id);
+				},
+				Some(WorkerToApi::Fail { id: conn_id, error }) if conn_id == id => {
+					tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Failed to connect");
+					return Err(match error {
+						tungstenite::Error::Io(error)
+							if error.kind() == std::io::ErrorKind::PermissionDenied =>
+							WsError::Forbidden,
+						_ => WsError::IoError,
+					});
+				},
+				// Answer to an earlier connection attempt whose deadline has been reached. Dropping
+				// the channels closes the connection.
+				Some(_) => continue,
+				None => {
+					tracing::error!(target: LOG_TARGET, "Worker has crashed");
+					return Err(WsError::IoError);
+				},
+			}
+		}
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), WsError> {
+		let connection = self.connections.get_mut(&id).ok_or(WsError::Invalid)?;
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		let mut when_ready =
+			future::maybe_done(future::poll_fn(|cx| connection.outgoing.poll_ready(cx)));
+		futures::executor::block_on(future::select(&mut when_ready, &mut deadline));
+		let ready = match when_ready {
+			future::MaybeDone::Done(Ok(())) => Ok(()),
+			future::MaybeDone::Done(Err(_)) => Err(WsError::IoError),
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => Err(WsError::DeadlineReached),
+		};
+		let result = ready.and_then(|()| {
+			connection.outgoing.start_send(message.to_vec()).map_err(|_| WsError::IoError)
+		});
+
+		tracing::debug!(target: LOG_TARGET, id = %id.0, res = ?result, "Sent message");
+		if result == Err(WsError::IoError) {
+			self.connections.remove(&id);
+		}
+		result
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn receive(
+		&mut self,
+		id: WsConnectionId,
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError> {
+		let connection = self.connections.get_mut(&id).ok_or(WsError::Invalid)?;
+
+		let mut deadline = timestamp::deadline_to_future(deadline);
+		let mut next_message = future::maybe_done(connection.incoming.next());
+		futures::executor::block_on(future::select(&mut next_message, &mut deadline));
+		let result = match next_message {
+			future::MaybeDone::Done(Some(message)) => Ok(message),
+			// The connection has been closed.
+			future::MaybeDone::Done(None) => Err(WsError::IoError),
+			future::MaybeDone::Future(_) | future::MaybeDone::Gone => Err(WsError::DeadlineReached),
+		};
+
+		if result == Err(WsError::IoError) {
+			tracing::debug!(target: LOG_TARGET, id = %id.0, "Connection closed");
+			self.connections.remove(&id);
+		}
+		result
+	}
+
+	/// Mimics the corresponding method in the offchain API.
+	pub fn close(&mut self, id: WsConnectionId) {
+		// Dropping the channels makes the worker close the socket.
+		if self.connections.remove(&id).is_some() {
+			tracing::debug!(target: LOG_TARGET, id = %id.0, "Closed connection");
+		}
+	}
+}
+
+impl fmt::Debug for WsApi {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_list().entries(self.connections.keys()).finish()
+	}
+}
+
+/// Message send from the API to the worker.
+enum ApiToWorker {
+	/// Opens a new connection.
+	Connect {
+		/// ID to send back once the connection is open.
+		id: WsConnectionId,
+		/// URI to connect to.
+		uri: String,
+	},
+}
+
+/// Message send from the worker to the API.
+enum WorkerToApi {
+	/// A connection has been opened.
+	Connected {
+		/// The ID that was passed to the worker.
+		id: WsConnectionId,
+		/// Channel of the messages to send to the remote.
+		outgoing: mpsc::Sender<Vec<u8>>,
+		/// Channel of the messages received from the remote.
+		incoming: mpsc::Receiver<Vec<u8>>,
+	},
+	/// A connection couldn't be opened.
+	Fail {
+		/// The ID that was passed to the worker.
+		id: WsConnectionId,
+		/// Error that happened.
+		error: tungstenite::Error,
+	},
+}
+
+/// Must be continuously polled for the [`WsApi`] to properly work.
+pub struct WsWorker {
+	/// Used to sends messages to the `WsApi`.
+	to_api: TracingUnboundedSender<WorkerToApi>,
+	/// Used to receive messages from the `WsApi`.
+	from_api: TracingUnboundedReceiver<ApiToWorker>,
+	/// Decides which addresses can be connected to.
+	filter: OutboundFilter,
+	/// Connections being driven by the worker.
+	connections: FuturesUnordered<BoxFuture<'static, ()>>,
+}
+
+impl Future for WsWorker {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+		let me = &mut *self;
+
+		// Drive all the sockets. `FuturesUnordered` returns `Ready(None)` once empty.
+		while let Poll::Ready(Some(())) = me.connections.poll_next_unpin(cx) {}
+
+		// Check for messages coming from the [`WsApi`].
+		loop {
+			match Stream::poll_next(Pin::new(&mut me.from_api), cx) {
+				Poll::Pending => break,
+				Poll::Ready(None) => return Poll::Ready(()), // stops the worker
+				Poll::Ready(Some(ApiToWorker::Connect { id, uri })) => {
+					me.connections.push(
+						run_connection(id, uri, me.filter.clone(), me.to_api.clone()).boxed(),
+					);
+					cx.waker().wake_by_ref(); // reschedule the task to poll the connection
+				},
+			}
+		}
+
+		Poll::Pending
+	}
+}
+
+impl fmt::Debug for WsWorker {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("WsWorker")
+			.field("connections", &self.connections.len())
+			.finish()
+	}
+}
+
+/// Opens a WebSocket connection to `uri`, at one of the addresses allowed by `filter`.
+async fn connect(
+	uri: &str,
+	filter: &OutboundFilter,
+) -> Result<
+	tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+	tungstenite::Error,
+> {
+	let parsed = hyper::Uri::try_from(uri).map_err(|_| {
+		tungstenite::Error::Url(tungstenite::error::UrlError::UnableToConnect(uri.to_owned()))
+	})?;
+	let host = parsed
+		.host()
+		.ok_or(tungstenite::Error::Url(tungstenite::error::UrlError::NoHostName))?;
+	let port =
+		parsed
+			.port_u16()
+			.unwrap_or(if parsed.scheme_str() == Some("wss") { 443 } else { 80 });
+
+	let addrs = filter.resolve(host, port).await?;
+	let stream = tokio::net::TcpStream::connect(&addrs[..]).await?;
+	let (socket, _response) = tokio_tungstenite::client_async_tls(uri, stream).await?;
+	Ok(socket)
+}
+
+/// Opens a connection to `uri` and shuttles messages between the socket and the [`WsApi`] until
+/// either side closes it.
+async fn run_connection(
+	id: WsConnectionId,
+	uri: String,
+	filter: OutboundFilter,
+	to_api: TracingUnboundedSender<WorkerToApi>,
+) {
+	// `tungstenite` builds its TLS configuration from the process wide crypto provider.
+	let _ = rustls::crypto::ring::default_provider().install_default();
+
+	let socket = match connect(&uri, &filter).await {
+		Ok(socket) => socket,
+		Err(error) => {
+			let _ = to_api.unbounded_send(WorkerToApi::Fail { id, error });
+			return
+		},
+	};
+
+	let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<Vec<u8>>(MESSAGE_BUFFER);
+	let (mut incoming_tx, incoming_rx) = mpsc::channel(MESSAGE_BUFFER);
+	if to_api
+		.unbounded_send(WorkerToApi::Connected { id, outgoing: outgoing_tx, incoming: incoming_rx })
+		.is_err()
+	{
+		return
+	}
+
+	let (mut sink, stream) = socket.split();
+	let mut stream = stream.fuse();
+
+	loop {
+		futures::select! {
+			message = outgoing_rx.next() => match message {
+				Some(message) => {
+					let message = match String::from_utf8(message) {
+						Ok(text) => Message::text(text),
+						Err(err) => Message::binary(err.into_bytes()),
+					};
+					if let Err(error) = sink.send(message).await {
+						tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Failed to send message");
+						break
+					}
+				},
+				// The API closed the connection.
+				None => {
+					let _ = sink.close().await;
+					break
+				},
+			},
+			message = stream.next() => match message {
+				Some(Ok(message @ (Message::Text(_) | Message::Binary(_)))) =>
+					if incoming_tx.send(message.into_data().to_vec()).await.is_err() {
+						break
+					},
+				// Pings are answered by `tungstenite` itself.
+				Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {},
+				Some(Ok(Message::Close(_))) | None => break,
+				Some(Err(error)) => {
+					tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Connection failed");
+					break
+				},
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::api::policy::{HostPattern, OutboundPolicy};
+	use sp_core::offchain::Duration;
+
+	// Returns a `WsApi` whose worker is ran in the background, and a `SocketAddr` to a WebSocket
+	// server that runs in the background as well.
+	//
+	// The server greets every new connection with `hello` and then echoes back all messages.
+	fn build_api_server(filter: OutboundFilter) -> (WsApi, std::net::SocketAddr) {
+		let (api, worker) = ws(filter);
+
+		let (addr_tx, addr_rx) = std::sync::mpsc::channel();
+		std::thread::spawn(move || {
+			let rt = tokio::runtime::Runtime::new().unwrap();
+			let worker = rt.spawn(worker);
+			let server = rt.spawn(async move {
+				let addr = std::net::SocketAddr::from(([127, 0, 0, 1], 0));
+				let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+				let _ = addr_tx.send(listener.local_addr().unwrap());
+				loop {
+					let (stream, _) = listener.accept().await.unwrap();
+					tokio::spawn(async move {
+						let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+						socket.send(Message::text("hello")).await.unwrap();
+						while let Some(Ok(message)) = socket.next().await {
+							if message.is_text() || message.is_binary() {
+								let _ = socket.send(message).await;
+							}
+						}
+					});
+				}
+			});
+			let _ = rt.block_on(future::join(worker, server));
+		});
+		(api, addr_rx.recv().unwrap())
+	}
+
+	fn deadline() -> Option<Timestamp> {
+		Some(timestamp::now().add(Duration::from_millis(10_000)))
+	}
+
+	#[test]
+	fn basic_localhost() {
+		let (mut api, addr) = build_api_server(Default::default());
+
+		let id = api.connect(&format!("ws://{}", addr), deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()).unwrap(), b"hello");
+
+		api.send(id, b"{\"subscribe\":\"DOT/USD\"}", deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()).unwrap(), b"{\"subscribe\":\"DOT/USD\"}");
+
+		// Not valid UTF-8, thus sent as binary frame.
+		api.send(id, &[0xff, 0, 1], deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()).unwrap(), vec![0xff, 0, 1]);
+
+		api.close(id);
+		assert_eq!(api.receive(id, deadline()), Err(WsError::Invalid));
+		assert_eq!(api.send(id, b"late", deadline()), Err(WsError::Invalid));
+	}
+
+	#[test]
+	fn multiple_connections() {
+		let (mut api, addr) = build_api_server(Default::default());
+
+		let first = api.connect(&format!("ws://{}", addr), deadline()).unwrap();
+		let second = api.connect(&format!("ws://{}", addr), deadline()).unwrap();
+		assert_ne!(first, second);
+
+		api.send(second, b"second", deadline()).unwrap();
+		api.send(first, b"first", deadline()).unwrap();
+
+		assert_eq!(api.receive(first, deadline()).unwrap(), b"hello");
+		assert_eq!(api.receive(first, deadline()).unwrap(), b"first");
+		assert_eq!(api.receive(second, deadline()).unwrap(), b"hello");
+		assert_eq!(api.receive(second, deadline()).unwrap(), b"second");
+	}
+
+	#[test]
+	fn receive_deadline_reached() {
+		let (mut api, addr) = build_api_server(Default::default());
+
+		let id = api.connect(&format!("ws://{}", addr), deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()).unwrap(), b"hello");
+
+		let short_deadline = Some(timestamp::now().add(Duration::from_millis(100)));
+		assert_eq!(api.receive(id, short_deadline), Err(WsError::DeadlineReached));
+
+		// The connection is still usable.
+		api.send(id, b"still there", deadline()).unwrap();
+		assert_eq!(api.receive(id, deadline()).unwrap(), b"still there");
+	}
+
+	#[test]
+	fn invalid_calls() {
+		let (mut api, addr) = build_api_server(Default::default());
+
+		assert_eq!(api.connect(&format!("http://{}", addr), deadline()), Err(WsError::Invalid));
+		assert_eq!(api.connect("ws://\0localhost", deadline()), Err(WsError::Invalid));
+
+		assert_eq!(api.send(WsConnectionId(0xdead), b"hi", deadline()), Err(WsError::Invalid));
+		assert_eq!(api.receive(WsConnectionId(0xdead), deadline()), Err(WsError::Invalid));
+		api.close(WsConnectionId(0xdead));
+	}
+
+	#[test]
+	fn connecting_to_closed_port_fails() {
+		let (mut api, _) = build_api_server(Default::default());
+
+		let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+		assert_eq!(api.connect(&format!("ws://{}", addr), deadline()), Err(WsError::IoError));
+	}
+
+	#[test]
+	fn connect_is_denied_by_policy() {
+		let (mut api, addr) = build_api_server(OutboundFilter::new(OutboundPolicy {
+			allowlist: vec![HostPattern::Exact("localhost".into())],
+			..Default::default()
+		}));
+
+		assert_eq!(api.connect(&format!("ws://{}", addr), deadline()), Err(WsError::Forbidden));
+	}
+
+	#[test]
+	fn connect_is_denied_by_resolved_address() {
+		let (mut api, addr) = build_api_server(OutboundFilter::new(OutboundPolicy {
+			denylist: vec!["127.0.0.0/8".parse().unwrap(), "[::1]".parse().unwrap()],
+			..Default::default()
+		}));
+
+		assert_eq!(
+			api.connect(&format!("ws://localhost:{}", addr.port()), deadline()),
+			Err(WsError::Forbidden)
+		);
+	}
+}
@@ -32,6 +32,9 @@
 //! 1. Challenge period for incorrect computations
 //! 2. Majority voting for results
 //! 3. etc
+//!
+//! Offchain workers can contact external services over HTTP and WebSockets. Node operators can
+//! restrict the hosts they may contact, and how often, with an [`OutboundPolicy`].
 
 #![warn(missing_docs)]
 
@@ -54,6 +57,7 @@ use threadpool::ThreadPool;
 
 mod api;
 
+pub use api::{HostPattern, HostRateLimit, InvalidHostPattern, OutboundPolicy};
 pub use sp_core::offchain::storage::OffchainDb;
 pub use sp_offchain::{OffchainWorkerApi, STORAGE_PREFIX};
 
@@ -110,6 +114,13 @@ pub struct OffchainWorkerOptions<RA, Block: traits::Block, Storage, CE> {
 	///
 	/// If not enabled, any http request will panic.
 	pub enable_http_requests: bool,
+	/// Enable WebSocket connections from offchain workers?
+	///
+	/// If not enabled, any WebSocket call will panic.
+	pub enable_websocket_connections: bool,
+	/// Restrictions on the hosts that offchain workers may send http requests to or open
+	/// WebSocket connections with.
+	pub outbound_policy: OutboundPolicy,
 	/// Callback to create custom [`Extension`]s that should be registered for the
 	/// `offchain_worker` runtime call.
 	///
@@ -131,7 +142,9 @@ pub struct OffchainWorkers<RA, Block: traits::Block, Storage> {
 	runtime_api_provider: Arc<RA>,
 	thread_pool: Mutex<ThreadPool>,
 	shared_http_client: api::SharedClient,
+	outbound_filter: api::OutboundFilter,
 	enable_http_requests: bool,
+	enable_websocket_connections: bool,
 	keystore: Option<KeystorePtr>,
 	offchain_db: Option<OffchainDb<Storage>>,
 	transaction_pool: Option<OffchainTransactionPoolFactory<Block>>,
@@ -151,17 +164,22 @@ impl<RA, Block: traits::Block, Storage> OffchainWorkers<RA, Block, Storage> {
 			network_provider,
 			is_validator,
 			enable_http_requests,
+			enable_websocket_connections,
+			outbound_policy,
 			custom_extensions,
 		}: OffchainWorkerOptions<RA, Block, Storage, CE>,
 	) -> std::io::Result<Self> {
+		let outbound_filter = api::OutboundFilter::new(outbound_policy);
 		Ok(Self {
 			runtime_api_provider,
 			thread_pool: Mutex::new(ThreadPool::with_name(
 				"offchain-worker".into(),
 				num_cpus::get(),
 			)),
-			shared_http_client: api::SharedClient::new()?,
+			shared_http_client: api::SharedClient::new(outbound_filter.clone())?,
+			outbound_filter,
 			enable_http_requests,
+			enable_websocket_connections,
 			keystore,
 			offchain_db: offchain_db.map(OffchainDb::new),
 			transaction_pool,
@@ -247,6 +265,7 @@ where
 				self.network_provider.clone(),
 				self.is_validator,
 				self.shared_http_client.clone(),
+				self.outbound_filter.clone(),
 			);
 			tracing::debug!(target: LOG_TARGET, "Spawning offchain workers at {hash:?}");
 			let header = header.clone();
@@ -254,6 +273,7 @@ where
 
 			let mut capabilities = offchain::Capabilities::all();
 			capabilities.set(offchain::Capabilities::HTTP, self.enable_http_requests);
+			capabilities.set(offchain::Capabilities::WEBSOCKET, self.enable_websocket_connections);
 
 			let keystore = self.keystore.clone();
 			let db = self.offchain_db.clone();
@@ -465,6 +485,8 @@ mod tests {
 			network_provider: network,
 			is_validator: false,
 			enable_http_requests: false,
+			enable_websocket_connections: false,
+			outbound_policy: Default::default(),
 			custom_extensions: |_| Vec::new(),
 		})
 		.unwrap();
@@ -34,7 +34,8 @@ use jsonrpsee::{
 };
 use tower::Service;
 use utils::{
-	build_rpc_api, deny_unsafe, format_listen_addrs, get_proxy_ip, ListenAddrError, RpcSettings,
+	build_rpc_api, deny_unsafe, format_listen_addrs, get_api_key, get_proxy_ip, ListenAddrError,
+	RpcSettings,
 };
 
 pub use ip_network::IpNetwork;
@@ -42,10 +43,15 @@ pub use jsonrpsee::{
 	core::id_providers::{RandomIntegerIdProvider, RandomStringIdProvider},
 	server::{middleware::rpc::RpcServiceBuilder, BatchRequestConfig},
 };
-pub use middleware::{Metrics, MiddlewareLayer, NodeHealthProxyLayer, RpcMetrics};
+pub use middleware::{
+	Metrics, MiddlewareLayer, NodeHealthProxyLayer, RateLimit, RateLimitOverrides, RpcMetrics,
+	SharedRateLimits,
+};
 pub use utils::{RpcEndpoint, RpcMethods};
 
 const MEGABYTE: u32 = 1024 * 1024;
+/// How often the shared rate limit buckets that have been refilled are forgotten.
+const RATE_LIMIT_RETAIN_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Type to encapsulate the server handle and listening address.
 pub struct Server {
@@ -103,6 +109,10 @@ pub struct Config<M: Send + Sync + 'static> {
 	pub tokio_handle: tokio::runtime::Handle,
 	/// RPC logger capacity (default: 1024).
 	pub request_logger_limit: u32,
+	/// Method costs and API key tiers of the rate limit.
+	///
+	/// If set, rate limits are enforced per IP address or API key instead of per connection.
+	pub rate_limit_overrides: Option<RateLimitOverrides>,
 }
 
 #[derive(Debug, Clone)]
@@ -118,8 +128,15 @@ pub async fn start_server<M>(config: Config<M>) -> Result<Server, Box<dyn StdErr
 where
 	M: Send + Sync,
 {
-	let Config { endpoints, metrics, tokio_handle, rpc_api, id_provider, request_logger_limit } =
-		config;
+	let Config {
+		endpoints,
+		metrics,
+		tokio_handle,
+		rpc_api,
+		id_provider,
+		request_logger_limit,
+		rate_limit_overrides,
+	} = config;
 
 	let (stop_handle, server_handle) = stop_channel();
 	let cfg = PerConnection {
@@ -162,6 +179,28 @@ where
 			rate_limit,
 		} = listener.rpc_settings();
 
+		let shared_rate_limits = match rate_limit_overrides.clone() {
+			Some(overrides) => {
+				let shared = SharedRateLimits::new(overrides, rate_limit)?;
+				let shared2 = shared.clone();
+				let stop_handle = cfg.stop_handle.clone();
+
+				tokio_handle.spawn(async move {
+					let mut interval = tokio::time::interval(RATE_LIMIT_RETAIN_INTERVAL);
+
+					loop {
+						tokio::select! {
+							_ = interval.tick() => shared2.retain_recent(),
+							_ = stop_handle.clone().shutdown() => break,
+						}
+					}
+				});
+
+				Some(shared)
+			},
+			None => None,
+		};
+
 		let http_middleware = tower::ServiceBuilder::new()
 			.option_layer(host_filter)
 			// Proxy `GET /health, /health/readiness` requests to the internal
@@ -213,6 +252,7 @@ where
 				let cfg2 = cfg.clone();
 				let service_builder2 = service_builder.clone();
 				let rate_limit_whitelisted_ips2 = rate_limit_whitelisted_ips.clone();
+				let shared_rate_limits2 = shared_rate_limits.clone();
 
 				let svc =
 					tower::service_fn(move |mut req: http::Request<hyper::body::Incoming>| {
@@ -235,7 +275,14 @@ where
 							if !rate_limit_whitelisted_ips2.is_empty() {
 								log::debug!(target: "rpc", "ip={ip}, proxy_ip={:?} is not trusted, rate-limit enabled", proxy_ip);
 							}
-							rate_limit
+
+							match shared_rate_limits2.as_ref() {
+								Some(shared) => shared.rate_limit(
+									proxy_ip.unwrap_or(ip),
+									get_api_key(&req, shared.api_key_header()),
+								),
+								None => rate_limit.map(RateLimit::per_minute),
+							}
 						};
 
 						let is_websocket = ws::is_upgrade_request(&req);
@@ -248,11 +295,11 @@ where
 									.with_metrics(Metrics::new(metrics, transport_label)),
 							),
 							(None, Some(rate_limit)) =>
-								Some(MiddlewareLayer::new().with_rate_limit_per_minute(rate_limit)),
+								Some(MiddlewareLayer::new().with_rate_limit(rate_limit)),
 							(Some(metrics), Some(rate_limit)) => Some(
 								MiddlewareLayer::new()
 									.with_metrics(Metrics::new(metrics, transport_label))
-									.with_rate_limit_per_minute(rate_limit),
+									.with_rate_limit(rate_limit),
 							),
 						};
 
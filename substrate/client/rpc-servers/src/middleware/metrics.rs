@@ -57,6 +57,29 @@ pub struct RpcMetrics {
 	ws_sessions_closed: Option<Counter<U64>>,
 	/// Histogram over RPC websocket sessions.
 	ws_sessions_time: HistogramVec,
+	/// Number of rate limit decisions.
+	rate_limit_outcomes: CounterVec<U64>,
+}
+
+/// Outcome of the rate limit of a call.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RateLimitOutcome {
+	/// The call was processed right away.
+	Allowed,
+	/// The call was processed after waiting for the rate limit.
+	Delayed,
+	/// The call was rejected.
+	Rejected,
+}
+
+impl RateLimitOutcome {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::Allowed => "allowed",
+			Self::Delayed => "delayed",
+			Self::Rejected => "rejected",
+		}
+	}
 }
 
 impl RpcMetrics {
@@ -122,6 +145,16 @@ impl RpcMetrics {
 					)?,
 					metrics_registry,
 				)?,
+				rate_limit_outcomes: register(
+					CounterVec::new(
+						Opts::new(
+							"substrate_rpc_rate_limit_outcomes",
+							"Number of rate limited RPC calls by method, tier and outcome",
+						),
+						&["protocol", "method", "tier", "outcome"],
+					)?,
+					metrics_registry,
+				)?,
 			}))
 		} else {
 			Ok(None)
@@ -188,6 +221,18 @@ impl RpcMetrics {
 			])
 			.inc();
 	}
+
+	pub(crate) fn on_rate_limit(
+		&self,
+		req: &Request,
+		tier: &str,
+		outcome: RateLimitOutcome,
+		transport_label: &'static str,
+	) {
+		self.rate_limit_outcomes
+			.with_label_values(&[transport_label, req.method_name(), tier, outcome.as_str()])
+			.inc();
+	}
 }
 
 /// Metrics with transport label.
@@ -224,4 +269,8 @@ impl Metrics {
 	) {
 		self.inner.on_response(req, rp, is_rate_limited, self.transport_label, now)
 	}
+
+	pub(crate) fn on_rate_limit(&self, req: &Request, tier: &str, outcome: RateLimitOutcome) {
+		self.inner.on_rate_limit(req, tier, outcome, self.transport_label)
+	}
 }
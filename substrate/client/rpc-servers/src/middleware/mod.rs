@@ -24,7 +24,7 @@ use std::{
 };
 
 use futures::future::{BoxFuture, FutureExt};
-use governor::{clock::Clock, Jitter};
+use governor::Jitter;
 use jsonrpsee::{
 	server::middleware::rpc::RpcServiceT,
 	types::{ErrorObject, Id, Request},
@@ -39,6 +39,8 @@ pub use metrics::*;
 pub use node_health::*;
 pub use rate_limit::*;
 
+use rate_limit::RateLimitCheck;
+
 const MAX_JITTER: Duration = Duration::from_millis(50);
 const MAX_RETRIES: usize = 10;
 
@@ -57,7 +59,12 @@ impl MiddlewareLayer {
 
 	/// Enable new rate limit middleware enforced per minute.
 	pub fn with_rate_limit_per_minute(self, n: NonZeroU32) -> Self {
-		Self { rate_limit: Some(RateLimit::per_minute(n)), metrics: self.metrics }
+		self.with_rate_limit(RateLimit::per_minute(n))
+	}
+
+	/// Enable rate limit middleware with the given rate limit.
+	pub fn with_rate_limit(self, rate_limit: RateLimit) -> Self {
+		Self { rate_limit: Some(rate_limit), metrics: self.metrics }
 	}
 
 	/// Enable metrics middleware.
@@ -121,19 +128,34 @@ where
 
 				loop {
 					if attempts >= MAX_RETRIES {
+						metrics.as_ref().map(|m| {
+							m.on_rate_limit(&req, limit.tier(), RateLimitOutcome::Rejected)
+						});
 						return reject_too_many_calls(req.id);
 					}
 
-					if let Err(rejected) = limit.inner.check() {
-						tokio::time::sleep(jitter + rejected.wait_time_from(limit.clock.now()))
-							.await;
-					} else {
-						break;
+					match limit.check(req.method_name()) {
+						RateLimitCheck::Allowed => break,
+						RateLimitCheck::Wait(wait_time) =>
+							tokio::time::sleep(jitter + wait_time).await,
+						RateLimitCheck::InsufficientCapacity => {
+							metrics.as_ref().map(|m| {
+								m.on_rate_limit(&req, limit.tier(), RateLimitOutcome::Rejected)
+							});
+							return reject_too_many_calls(req.id);
+						},
 					}
 
 					is_rate_limited = true;
 					attempts += 1;
 				}
+
+				let outcome = if is_rate_limited {
+					RateLimitOutcome::Delayed
+				} else {
+					RateLimitOutcome::Allowed
+				};
+				metrics.as_ref().map(|m| m.on_rate_limit(&req, limit.tier(), outcome));
 			}
 
 			let rp = service.call(req.clone()).await;
@@ -19,20 +19,135 @@
 //! RPC rate limit.
 
 use governor::{
-	clock::{DefaultClock, QuantaClock},
+	clock::{Clock, DefaultClock, QuantaClock},
 	middleware::NoOpMiddleware,
-	state::{InMemoryState, NotKeyed},
+	state::{keyed::DefaultKeyedStateStore, InMemoryState, NotKeyed},
 	Quota,
 };
-use std::{num::NonZeroU32, sync::Arc};
+use http::header::HeaderName;
+use serde::Deserialize;
+use std::{
+	collections::HashMap, error::Error as StdError, net::IpAddr, num::NonZeroU32, path::Path,
+	str::FromStr, sync::Arc, time::Duration,
+};
 
 type RateLimitInner = governor::RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+type KeyedRateLimitInner = governor::RateLimiter<
+	RateLimitKey,
+	DefaultKeyedStateStore<RateLimitKey>,
+	DefaultClock,
+	NoOpMiddleware,
+>;
+
+/// The HTTP header used to pass an API key if not overridden.
+pub const DEFAULT_API_KEY_HEADER: &str = "x-api-key";
+
+/// The tier label used for callers without a valid API key.
+pub const DEFAULT_TIER: &str = "default";
+
+/// Key of a rate limit bucket that is shared between connections.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+	/// The bucket of a remote IP address.
+	Ip(IpAddr),
+	/// The bucket of an API key.
+	ApiKey(String),
+}
+
+/// Rate limit overrides, usually loaded from a JSON file.
+///
+/// ```json
+/// {
+///   "methodCosts": { "state_getKeysPaged": 10, "archive_v1_storage": 20 },
+///   "tiers": { "premium": 6000 },
+///   "apiKeys": { "0xdeadbeef": "premium" },
+///   "apiKeyHeader": "x-api-key"
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimitOverrides {
+	/// The cost of a call to a method, methods not listed here cost `1`.
+	#[serde(default)]
+	pub method_costs: HashMap<String, NonZeroU32>,
+	/// Rate limit tiers, mapping the name of a tier to its cost units per minute.
+	#[serde(default)]
+	pub tiers: HashMap<String, NonZeroU32>,
+	/// API keys, mapping each key to the name of its tier.
+	#[serde(default)]
+	pub api_keys: HashMap<String, String>,
+	/// The HTTP header the API key is read from.
+	#[serde(default = "default_api_key_header")]
+	pub api_key_header: String,
+}
+
+fn default_api_key_header() -> String {
+	DEFAULT_API_KEY_HEADER.to_string()
+}
+
+impl Default for RateLimitOverrides {
+	fn default() -> Self {
+		Self {
+			method_costs: Default::default(),
+			tiers: Default::default(),
+			api_keys: Default::default(),
+			api_key_header: default_api_key_header(),
+		}
+	}
+}
+
+impl RateLimitOverrides {
+	/// Load the overrides from a JSON file.
+	pub fn from_file(path: &Path) -> Result<Self, Box<dyn StdError + Send + Sync>> {
+		let bytes = std::fs::read(path)?;
+		let overrides: Self = serde_json::from_slice(&bytes)?;
+		overrides.validate()?;
+		Ok(overrides)
+	}
+
+	/// Ensure that every API key refers to an existing tier and that the header name is valid.
+	pub fn validate(&self) -> Result<(), String> {
+		HeaderName::from_str(&self.api_key_header)
+			.map_err(|_| format!("Invalid API key header `{}`", self.api_key_header))?;
+
+		if self.tiers.contains_key(DEFAULT_TIER) {
+			return Err(format!("The tier name `{DEFAULT_TIER}` is reserved"));
+		}
+
+		for tier in self.api_keys.values() {
+			if !self.tiers.contains_key(tier) {
+				return Err(format!("API key refers to unknown tier `{tier}`"));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Outcome of charging a call against a rate limit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RateLimitCheck {
+	/// The call may proceed.
+	Allowed,
+	/// The bucket is empty, the call may be retried after the given duration.
+	Wait(Duration),
+	/// The cost of the call exceeds the capacity of the bucket.
+	InsufficientCapacity,
+}
+
+#[derive(Debug, Clone)]
+enum Bucket {
+	PerConnection(Arc<RateLimitInner>),
+	Shared { inner: Arc<KeyedRateLimitInner>, key: RateLimitKey },
+}
 
 /// Rate limit.
 #[derive(Debug, Clone)]
 pub struct RateLimit {
-	pub(crate) inner: Arc<RateLimitInner>,
-	pub(crate) clock: QuantaClock,
+	bucket: Bucket,
+	method_costs: Arc<HashMap<String, NonZeroU32>>,
+	tier: Arc<str>,
+	clock: QuantaClock,
 }
 
 impl RateLimit {
@@ -40,8 +155,211 @@ impl RateLimit {
 	pub fn per_minute(n: NonZeroU32) -> Self {
 		let clock = QuantaClock::default();
 		Self {
-			inner: Arc::new(RateLimitInner::direct_with_clock(Quota::per_minute(n), &clock)),
+			bucket: Bucket::PerConnection(Arc::new(RateLimitInner::direct_with_clock(
+				Quota::per_minute(n),
+				&clock,
+			))),
+			method_costs: Default::default(),
+			tier: DEFAULT_TIER.into(),
 			clock,
 		}
 	}
+
+	/// The name of the tier calls are charged against.
+	pub(crate) fn tier(&self) -> &str {
+		&self.tier
+	}
+
+	/// Charge a call to `method` against the bucket.
+	pub(crate) fn check(&self, method: &str) -> RateLimitCheck {
+		let cost = self.method_costs.get(method).copied().unwrap_or(NonZeroU32::MIN);
+
+		let outcome = match &self.bucket {
+			Bucket::PerConnection(inner) => inner.check_n(cost),
+			Bucket::Shared { inner, key } => inner.check_key_n(key, cost),
+		};
+
+		match outcome {
+			Ok(Ok(())) => RateLimitCheck::Allowed,
+			Ok(Err(not_until)) => RateLimitCheck::Wait(not_until.wait_time_from(self.clock.now())),
+			Err(_) => RateLimitCheck::InsufficientCapacity,
+		}
+	}
+}
+
+/// Rate limit buckets that are shared by all connections of an RPC endpoint.
+///
+/// Callers presenting a known API key are charged against the bucket of that key, using the
+/// quota of its tier. Every other caller is charged against the bucket of its IP address.
+#[derive(Debug, Clone)]
+pub struct SharedRateLimits {
+	per_ip: Option<Arc<KeyedRateLimitInner>>,
+	tiers: HashMap<String, Arc<KeyedRateLimitInner>>,
+	overrides: Arc<RateLimitOverrides>,
+	method_costs: Arc<HashMap<String, NonZeroU32>>,
+	api_key_header: HeaderName,
+	clock: QuantaClock,
+}
+
+impl SharedRateLimits {
+	/// Create the buckets from the overrides, `per_ip` is the quota of callers without an API key.
+	pub fn new(
+		overrides: RateLimitOverrides,
+		per_ip: Option<NonZeroU32>,
+	) -> Result<Self, Box<dyn StdError + Send + Sync>> {
+		overrides.validate()?;
+
+		let clock = QuantaClock::default();
+		let keyed = |n| {
+			Arc::new(KeyedRateLimitInner::new(
+				Quota::per_minute(n),
+				DefaultKeyedStateStore::default(),
+				&clock,
+			))
+		};
+
+		Ok(Self {
+			per_ip: per_ip.map(keyed),
+			tiers: overrides.tiers.iter().map(|(name, n)| (name.clone(), keyed(*n))).collect(),
+			api_key_header: HeaderName::from_str(&overrides.api_key_header)?,
+			method_costs: Arc::new(overrides.method_costs.clone()),
+			overrides: Arc::new(overrides),
+			clock,
+		})
+	}
+
+	/// The HTTP header the API key is read from.
+	pub(crate) fn api_key_header(&self) -> &HeaderName {
+		&self.api_key_header
+	}
+
+	/// Get the rate limit of a caller, `None` if the caller is not rate limited.
+	pub fn rate_limit(&self, ip: IpAddr, api_key: Option<&str>) -> Option<RateLimit> {
+		let method_costs = self.method_costs.clone();
+
+		if let Some(api_key) = api_key {
+			match self.overrides.api_keys.get(api_key) {
+				Some(tier) => {
+					let inner = self.tiers.get(tier).expect("API key tiers are validated; qed");
+
+					return Some(RateLimit {
+						bucket: Bucket::Shared {
+							inner: inner.clone(),
+							key: RateLimitKey::ApiKey(api_key.to_string()),
+						},
+						method_costs,
+						tier: tier.as_str().into(),
+						clock: self.clock.clone(),
+					})
+				},
+				None => log::debug!(target: "rpc", "ip={ip} provided an unknown API key"),
+			}
+		}
+
+		self.per_ip.as_ref().map(|inner| RateLimit {
+			bucket: Bucket::Shared { inner: inner.clone(), key: RateLimitKey::Ip(ip) },
+			method_costs,
+			tier: DEFAULT_TIER.into(),
+			clock: self.clock.clone(),
+		})
+	}
+
+	/// Forget the buckets that have been refilled completely.
+	pub fn retain_recent(&self) {
+		if let Some(per_ip) = self.per_ip.as_ref() {
+			per_ip.retain_recent();
+		}
+
+		for tier in self.tiers.values() {
+			tier.retain_recent();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn overrides() -> RateLimitOverrides {
+		serde_json::from_str(
+			r#"{
+				"methodCosts": { "state_getKeysPaged": 5 },
+				"tiers": { "premium": 10 },
+				"apiKeys": { "secret": "premium" }
+			}"#,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn overrides_validation_works() {
+		assert!(overrides().validate().is_ok());
+
+		let mut unknown_tier = overrides();
+		unknown_tier.api_keys.insert("other".into(), "gold".into());
+		assert!(unknown_tier.validate().is_err());
+
+		let mut reserved_tier = overrides();
+		reserved_tier.tiers.insert(DEFAULT_TIER.into(), NonZeroU32::MIN);
+		assert!(reserved_tier.validate().is_err());
+
+		let mut invalid_header = overrides();
+		invalid_header.api_key_header = "x api key".into();
+		assert!(invalid_header.validate().is_err());
+	}
+
+	#[test]
+	fn method_costs_are_charged() {
+		let limits = SharedRateLimits::new(overrides(), NonZeroU32::new(6)).unwrap();
+		let ip = IpAddr::from([127, 0, 0, 1]);
+		let limit = limits.rate_limit(ip, None).unwrap();
+
+		assert_eq!(limit.tier(), DEFAULT_TIER);
+		assert_eq!(limit.check("state_getKeysPaged"), RateLimitCheck::Allowed);
+		assert_eq!(limit.check("system_health"), RateLimitCheck::Allowed);
+		assert!(matches!(limit.check("system_health"), RateLimitCheck::Wait(_)));
+	}
+
+	#[test]
+	fn buckets_are_shared_per_ip() {
+		let limits = SharedRateLimits::new(overrides(), NonZeroU32::new(1)).unwrap();
+		let ip = IpAddr::from([127, 0, 0, 1]);
+		let other_ip = IpAddr::from([127, 0, 0, 2]);
+
+		assert_eq!(limits.rate_limit(ip, None).unwrap().check("a"), RateLimitCheck::Allowed);
+		assert!(matches!(limits.rate_limit(ip, None).unwrap().check("a"), RateLimitCheck::Wait(_)));
+		assert_eq!(limits.rate_limit(other_ip, None).unwrap().check("a"), RateLimitCheck::Allowed);
+	}
+
+	#[test]
+	fn api_key_uses_tier() {
+		let limits = SharedRateLimits::new(overrides(), NonZeroU32::new(1)).unwrap();
+		let ip = IpAddr::from([127, 0, 0, 1]);
+		let limit = limits.rate_limit(ip, Some("secret")).unwrap();
+
+		assert_eq!(limit.tier(), "premium");
+		for _ in 0..10 {
+			assert_eq!(limit.check("a"), RateLimitCheck::Allowed);
+		}
+		assert!(matches!(limit.check("a"), RateLimitCheck::Wait(_)));
+
+		// Unknown keys fall back to the bucket of the IP address.
+		assert_eq!(limits.rate_limit(ip, Some("unknown")).unwrap().tier(), DEFAULT_TIER);
+	}
+
+	#[test]
+	fn cost_above_capacity_is_rejected() {
+		let limits = SharedRateLimits::new(overrides(), NonZeroU32::new(4)).unwrap();
+		let limit = limits.rate_limit(IpAddr::from([127, 0, 0, 1]), None).unwrap();
+
+		assert_eq!(limit.check("state_getKeysPaged"), RateLimitCheck::InsufficientCapacity);
+	}
+
+	#[test]
+	fn anonymous_callers_are_not_limited_without_quota() {
+		let limits = SharedRateLimits::new(overrides(), None).unwrap();
+
+		assert!(limits.rate_limit(IpAddr::from([127, 0, 0, 1]), None).is_none());
+		assert!(limits.rate_limit(IpAddr::from([127, 0, 0, 1]), Some("secret")).is_some());
+	}
 }
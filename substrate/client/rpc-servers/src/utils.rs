@@ -280,6 +280,14 @@ pub(crate) fn get_proxy_ip<B>(req: &http::Request<B>) -> Option<IpAddr> {
 	None
 }
 
+/// Extracts the API key from the HTTP request.
+pub(crate) fn get_api_key<'a, B>(
+	req: &'a http::Request<B>,
+	header: &HeaderName,
+) -> Option<&'a str> {
+	req.headers().get(header).and_then(|v| v.to_str().ok()).map(str::trim)
+}
+
 /// Get the `deny_unsafe` setting based on the address and the RPC methods exposed by the interface.
 pub fn deny_unsafe(addr: &SocketAddr, methods: &RpcMethods) -> DenyUnsafe {
 	match (addr.ip().is_loopback(), methods) {
@@ -357,6 +365,16 @@ mod tests {
 		assert_eq!(Some(IpAddr::from_str("127.0.0.1").unwrap()), ip);
 	}
 
+	#[test]
+	fn api_key_from_header_works() {
+		let header = HeaderName::from_static("x-api-key");
+		let mut req = request();
+		assert!(get_api_key(&req, &header).is_none());
+
+		req.headers_mut().insert(&header, HeaderValue::from_static(" secret "));
+		assert_eq!(get_api_key(&req, &header), Some("secret"));
+	}
+
 	#[test]
 	fn ip_from_x_forwarded_works() {
 		let mut req = request();
@@ -204,9 +204,7 @@ where
 		let call_parameters = Bytes::from(parse_hex_param(call_parameters)?);
 
 		let result =
-			self.client
-				.executor()
-				.call(hash, &function, &call_parameters, CallContext::Offchain);
+			self.client.executor().call(hash, &function, &call_parameters, CallContext::Rpc);
 
 		Ok(match result {
 			Ok(result) => MethodResult::ok(hex_string(&result)),
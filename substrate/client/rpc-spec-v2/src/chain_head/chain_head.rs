@@ -518,7 +518,7 @@ where
 
 			let event = client
 				.executor()
-				.call(hash, &function, &call_parameters, CallContext::Offchain)
+				.call(hash, &function, &call_parameters, CallContext::Rpc)
 				.map(|result| {
 					FollowEvent::<Block::Hash>::OperationCallDone(OperationCallDone {
 						operation_id: operation_id.clone(),
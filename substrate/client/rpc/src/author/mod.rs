@@ -35,7 +35,7 @@ use sc_transaction_pool_api::{
 };
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_core::Bytes;
+use sp_core::{traits::CallContext, Bytes};
 use sp_keystore::{KeystoreExt, KeystorePtr};
 use sp_runtime::traits::Block as BlockT;
 use sp_session::SessionKeys;
@@ -79,6 +79,7 @@ where
 	fn rotate_keys_impl(&self, owner: Vec<u8>) -> Result<GeneratedSessionKeys> {
 		let best_block_hash = self.client.info().best_hash;
 		let mut runtime_api = self.client.runtime_api();
+		runtime_api.set_call_context(CallContext::Rpc);
 
 		runtime_api.register_extension(KeystoreExt::from(self.keystore.clone()));
 
@@ -171,9 +172,9 @@ where
 		check_if_safe(ext)?;
 
 		let best_block_hash = self.client.info().best_hash;
-		let keys = self
-			.client
-			.runtime_api()
+		let mut runtime_api = self.client.runtime_api();
+		runtime_api.set_call_context(CallContext::Rpc);
+		let keys = runtime_api
 			.decode_session_keys(best_block_hash, session_keys.to_vec())
 			.map_err(|e| Error::Client(Box::new(e)))?
 			.ok_or(Error::InvalidSessionKeys)?;
@@ -26,7 +26,7 @@ use jsonrpsee::Extensions;
 use sc_client_api::{BlockBackend, HeaderBackend};
 use sc_rpc_api::{check_if_safe, dev::error::Error};
 use sp_api::{ApiExt, Core, ProvideRuntimeApi};
-use sp_core::Encode;
+use sp_core::{traits::CallContext, Encode};
 use sp_runtime::{
 	generic::DigestItem,
 	traits::{Block as BlockT, Header},
@@ -99,6 +99,7 @@ where
 		let num_extrinsics = block.extrinsics().len() as u64;
 		let pre_root = *parent_header.state_root();
 		let mut runtime_api = self.client.runtime_api();
+		runtime_api.set_call_context(CallContext::Rpc);
 		runtime_api.record_proof();
 		runtime_api
 			.execute_block(parent_header.hash(), block.into())
@@ -38,7 +38,7 @@ use sc_client_api::{
 };
 use sc_rpc_api::state::ReadProof;
 use sc_tracing::block::TracingExecuteBlock;
-use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
+use sp_api::{ApiExt, CallApiAt, Metadata, ProvideRuntimeApi};
 use sp_blockchain::{
 	CachedHeaderMetadata, Error as ClientError, HeaderBackend, HeaderMetadata,
 	Result as ClientResult,
@@ -204,7 +204,7 @@ where
 			.and_then(|block| {
 				self.client
 					.executor()
-					.call(block, &method, &call_data, CallContext::Offchain)
+					.call(block, &method, &call_data, CallContext::Rpc)
 					.map(Into::into)
 			})
 			.map_err(client_err)
@@ -320,8 +320,9 @@ where
 
 	fn metadata(&self, block: Option<Block::Hash>) -> std::result::Result<Bytes, Error> {
 		self.block_or_best(block).map_err(client_err).and_then(|block| {
-			self.client
-				.runtime_api()
+			let mut runtime_api = self.client.runtime_api();
+			runtime_api.set_call_context(CallContext::Rpc);
+			runtime_api
 				.metadata(block)
 				.map(Into::into)
 				.map_err(|e| Error::Client(Box::new(e)))
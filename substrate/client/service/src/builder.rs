@@ -365,13 +365,23 @@ pub fn new_wasm_executor<H: HostFunctions>(config: &ExecutorConfiguration) -> Wa
 	let strategy = config
 		.default_heap_pages
 		.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |p| HeapAllocStrategy::Static { extra_pages: p as _ });
-	WasmExecutor::<H>::builder()
+	let mut builder = WasmExecutor::<H>::builder()
 		.with_execution_method(config.wasm_method)
 		.with_onchain_heap_alloc_strategy(strategy)
 		.with_offchain_heap_alloc_strategy(strategy)
 		.with_max_runtime_instances(config.max_runtime_instances)
-		.with_runtime_cache_size(config.runtime_cache_size)
-		.build()
+		.with_runtime_cache_size(config.runtime_cache_size);
+
+	if let Some(limit) = config.onchain_execution_limit {
+		builder = builder.with_onchain_execution_limit(limit);
+	}
+	if let Some(limit) = config.offchain_execution_limit {
+		builder = builder.with_offchain_execution_limit(limit);
+	}
+	if let Some(limit) = config.rpc_execution_limit {
+		builder = builder.with_rpc_execution_limit(limit);
+	}
+	builder.build()
 }
 
 /// Create an instance of default DB-backend backend.
@@ -22,7 +22,7 @@ pub use jsonrpsee::server::BatchRequestConfig as RpcBatchRequestConfig;
 use prometheus_endpoint::Registry;
 use sc_chain_spec::ChainSpec;
 pub use sc_client_db::{BlocksPruning, Database, DatabaseSource, PruningMode};
-pub use sc_executor::{WasmExecutionMethod, WasmtimeInstantiationStrategy};
+pub use sc_executor::{ExecutionLimit, WasmExecutionMethod, WasmtimeInstantiationStrategy};
 pub use sc_network::{
 	config::{
 		MultiaddrWithPeerId, NetworkConfiguration, NodeKeyConfig, NonDefaultSetConfig, ProtocolId,
@@ -33,8 +33,10 @@ pub use sc_network::{
 	},
 	Multiaddr,
 };
+pub use sc_offchain::{HostPattern, HostRateLimit, OutboundPolicy};
 pub use sc_rpc_server::{
-	IpNetwork, RpcEndpoint, RpcMethods, SubscriptionIdProvider as RpcSubscriptionIdProvider,
+	IpNetwork, RateLimitOverrides as RpcRateLimitOverrides, RpcEndpoint, RpcMethods,
+	SubscriptionIdProvider as RpcSubscriptionIdProvider,
 };
 pub use sc_telemetry::TelemetryEndpoints;
 pub use sc_transaction_pool::TransactionPoolOptions;
@@ -172,6 +174,10 @@ pub struct OffchainWorkerConfig {
 	pub enabled: bool,
 	/// allow writes from the runtime to the offchain worker database.
 	pub indexing_enabled: bool,
+	/// Allow offchain workers to open WebSocket connections.
+	pub websocket_enabled: bool,
+	/// Restrictions on the hosts offchain workers may contact.
+	pub outbound_policy: OutboundPolicy,
 }
 
 /// Configuration of the Prometheus endpoint.
@@ -342,6 +348,8 @@ pub struct RpcConfiguration {
 	pub rate_limit_trust_proxy_headers: bool,
 	/// RPC logger capacity (default: 1024).
 	pub request_logger_limit: u32,
+	/// RPC rate limit method costs and API key tiers.
+	pub rate_limit_overrides: Option<RpcRateLimitOverrides>,
 }
 
 /// Runtime executor configuration.
@@ -357,6 +365,19 @@ pub struct ExecutorConfiguration {
 	pub default_heap_pages: Option<u64>,
 	/// Maximum number of different runtime versions that can be cached.
 	pub runtime_cache_size: u8,
+	/// Limit for runtime calls requested through an RPC, e.g. `state_call`.
+	///
+	/// The default value is `None`, which means these calls are not limited.
+	pub rpc_execution_limit: Option<ExecutionLimit>,
+	/// Limit for offchain runtime calls, e.g. offchain workers.
+	///
+	/// The default value is `None`, which means these calls are not limited.
+	pub offchain_execution_limit: Option<ExecutionLimit>,
+	/// Limit for runtime calls made while building or importing a block.
+	///
+	/// Should only ever be [`ExecutionLimit::Fuel`]. The default value is `None`, which means
+	/// these calls are not limited.
+	pub onchain_execution_limit: Option<ExecutionLimit>,
 }
 
 impl Default for ExecutorConfiguration {
@@ -366,6 +387,9 @@ impl Default for ExecutorConfiguration {
 			max_runtime_instances: 8,
 			default_heap_pages: None,
 			runtime_cache_size: 2,
+			rpc_execution_limit: None,
+			offchain_execution_limit: None,
+			onchain_execution_limit: None,
 		}
 	}
 }
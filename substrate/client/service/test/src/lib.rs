@@ -257,6 +257,7 @@ fn node_config<E: ChainSpecExtension + Clone + 'static + Send + Sync>(
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
 			request_logger_limit: 1024,
+			rate_limit_overrides: None,
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
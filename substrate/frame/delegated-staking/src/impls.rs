@@ -97,6 +97,14 @@ impl<T: Config> DelegationInterface for Pallet<T> {
 	) -> sp_runtime::DispatchResult {
 		Pallet::<T>::do_slash(agent, delegator, value, maybe_reporter)
 	}
+
+	fn transfer_delegation(
+		source: Delegator<Self::AccountId>,
+		destination: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Pallet::<T>::do_transfer_delegation(source, destination, amount)
+	}
 }
 
 impl<T: Config> DelegationMigrator for Pallet<T> {
@@ -267,6 +267,13 @@ pub mod pallet {
 		Slashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
 		/// Unclaimed delegation funds migrated to delegator.
 		MigratedDelegation { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegated funds transferred from one delegator to another.
+		DelegationTransferred {
+			agent: T::AccountId,
+			source: T::AccountId,
+			destination: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Map of Delegators to their `Delegation`.
@@ -701,6 +708,73 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer `amount` of delegated funds from `source_delegator` to `destination_delegator`.
+	///
+	/// The held funds move along with the delegation, so the total delegated to the `Agent` stays
+	/// the same.
+	pub fn do_transfer_delegation(
+		source_delegator: Delegator<T::AccountId>,
+		destination_delegator: Delegator<T::AccountId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// get inner type
+		let source_delegator = source_delegator.get();
+		let destination_delegator = destination_delegator.get();
+		ensure!(source_delegator != destination_delegator, Error::<T>::InvalidDelegation);
+
+		let mut source_delegation =
+			Delegators::<T>::get(&source_delegator).ok_or(Error::<T>::NotDelegator)?;
+
+		// ensure source has enough funds to transfer.
+		ensure!(source_delegation.amount >= amount, Error::<T>::NotEnoughFunds);
+
+		let agent = source_delegation.agent.clone();
+		// destination can only add to an existing delegation to the same agent.
+		ensure!(
+			Delegation::<T>::can_delegate(&destination_delegator, &agent),
+			Error::<T>::InvalidDelegation
+		);
+
+		let destination_delegation = match Delegators::<T>::get(&destination_delegator) {
+			Some(mut delegation) => {
+				delegation.amount =
+					delegation.amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+				delegation
+			},
+			None => Delegation::<T>::new(&agent, amount),
+		};
+		// update destination delegation, providing for the account if it is a new delegator.
+		destination_delegation.update(&destination_delegator);
+
+		source_delegation.amount = source_delegation
+			.amount
+			.checked_sub(&amount)
+			.defensive_ok_or(Error::<T>::BadState)?;
+
+		// transfer the held amount in `source_delegator` to `destination_delegator`.
+		T::Currency::transfer_on_hold(
+			&HoldReason::StakingDelegation.into(),
+			&source_delegator,
+			&destination_delegator,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		// update source delegation.
+		source_delegation.update(&source_delegator);
+
+		Self::deposit_event(Event::<T>::DelegationTransferred {
+			agent,
+			source: source_delegator,
+			destination: destination_delegator,
+			amount,
+		});
+
+		Ok(())
+	}
+
 	/// Take slash `amount` from agent's `pending_slash`counter and apply it to `delegator` account.
 	pub fn do_slash(
 		agent: Agent<T::AccountId>,
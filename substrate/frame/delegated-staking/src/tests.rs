@@ -339,6 +339,79 @@ fn allow_full_amount_to_be_delegated() {
 	});
 }
 
+#[test]
+fn transfer_delegation_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let agent: AccountId = 200;
+		let other_agent: AccountId = 210;
+		let delegator: AccountId = 300;
+		let receiver: AccountId = 301;
+
+		fund(&agent, 1000);
+		fund(&other_agent, 1000);
+		assert_ok!(DelegatedStaking::register_agent(RawOrigin::Signed(agent).into(), 201));
+		assert_ok!(DelegatedStaking::register_agent(RawOrigin::Signed(other_agent).into(), 211));
+
+		fund(&delegator, 1000);
+		assert_ok!(DelegatedStaking::delegate_to_agent(
+			RawOrigin::Signed(delegator).into(),
+			agent,
+			500
+		));
+
+		// cannot transfer more than delegated.
+		assert_noop!(
+			<DelegatedStaking as DelegationInterface>::transfer_delegation(
+				Delegator::from(delegator),
+				Delegator::from(receiver),
+				501
+			),
+			Error::<T>::NotEnoughFunds
+		);
+
+		// partial transfer to a new delegator.
+		assert_ok!(<DelegatedStaking as DelegationInterface>::transfer_delegation(
+			Delegator::from(delegator),
+			Delegator::from(receiver),
+			200
+		));
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(delegator)), 300);
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(receiver)), 200);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::StakingDelegation.into(), &receiver),
+			200
+		);
+		assert_eq!(Delegators::<T>::get(receiver).unwrap().agent, agent);
+		// agent balance is unchanged.
+		assert_eq!(DelegatedStaking::stakeable_balance(Agent::from(agent)), 500);
+
+		// transfer the rest to an existing delegator cleans up the source.
+		assert_ok!(<DelegatedStaking as DelegationInterface>::transfer_delegation(
+			Delegator::from(delegator),
+			Delegator::from(receiver),
+			300
+		));
+		assert!(!DelegatedStaking::is_delegator(&delegator));
+		assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(receiver)), 500);
+
+		// cannot transfer to a delegator of another agent.
+		fund(&delegator, 1000);
+		assert_ok!(DelegatedStaking::delegate_to_agent(
+			RawOrigin::Signed(delegator).into(),
+			other_agent,
+			100
+		));
+		assert_noop!(
+			<DelegatedStaking as DelegationInterface>::transfer_delegation(
+				Delegator::from(receiver),
+				Delegator::from(delegator),
+				100
+			),
+			Error::<T>::InvalidDelegation
+		);
+	});
+}
+
 /// Integration tests with pallet-staking.
 mod staking_integration {
 	use super::*;
@@ -909,6 +909,61 @@ impl<AccountId: IdentifierT, Accuracy: PerThing128, Balancing: Get<Option<Balanc
 	}
 }
 
+/// A wrapper for [`sp_npos_elections::stv()`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::stv()`] for more info.
+///
+/// Note that this is a ranked method: the order of the votes of each voter is interpreted as their
+/// order of preference.
+pub struct Stv<AccountId, Accuracy>(core::marker::PhantomData<(AccountId, Accuracy)>);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128> NposSolver for Stv<AccountId, Accuracy> {
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(
+			Self::AccountId,
+			VoteWeight,
+			impl Clone + IntoIterator<Item = Self::AccountId>,
+		)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::stv(winners, targets, voters)
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::stv(voters, targets, vote_degree)
+	}
+}
+
+/// A wrapper for [`sp_npos_elections::approval_voting()`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::approval_voting()`] for more info.
+pub struct ApprovalVoting<AccountId, Accuracy>(core::marker::PhantomData<(AccountId, Accuracy)>);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128> NposSolver
+	for ApprovalVoting<AccountId, Accuracy>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(
+			Self::AccountId,
+			VoteWeight,
+			impl Clone + IntoIterator<Item = Self::AccountId>,
+		)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::approval_voting(winners, targets, voters)
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::approval_voting(voters, targets, vote_degree)
+	}
+}
+
 /// A voter, at the level of abstraction of this crate.
 pub type Voter<AccountId, Bound> = (AccountId, VoteWeight, BoundedVec<AccountId, Bound>);
 
@@ -214,7 +214,7 @@ impl<T: Config> ElectionProvider for OnChainExecution<T> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{ElectionProvider, PhragMMS, SequentialPhragmen};
+	use crate::{ElectionProvider, PhragMMS, SequentialPhragmen, Stv};
 	use frame_support::{assert_noop, derive_impl, parameter_types};
 	use sp_io::TestExternalities;
 	use sp_npos_elections::Support;
@@ -262,6 +262,7 @@ mod tests {
 
 	struct PhragmenParams;
 	struct PhragMMSParams;
+	struct StvParams;
 
 	parameter_types! {
 		pub static MaxWinnersPerPage: u32 = 10;
@@ -293,6 +294,17 @@ mod tests {
 		type Bounds = Bounds;
 	}
 
+	impl Config for StvParams {
+		type Sort = Sort;
+		type System = Runtime;
+		type Solver = Stv<AccountId, Perbill>;
+		type DataProvider = mock_data_provider::DataProvider;
+		type MaxWinnersPerPage = MaxWinnersPerPage;
+		type MaxBackersPerWinner = MaxBackersPerWinner;
+		type WeightInfo = ();
+		type Bounds = Bounds;
+	}
+
 	mod mock_data_provider {
 		use super::*;
 		use crate::{data_provider, DataProviderBounds, PageIndex, VoterOf};
@@ -432,4 +444,24 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn onchain_stv_works() {
+		TestExternalities::new_empty().execute_with(|| {
+			// the quota is 21: 10 is elected first with 40, and 3 carries 15 to 30 on top of the
+			// 20 of 2.
+			assert_eq!(
+				<OnChainExecution::<StvParams> as ElectionProvider>::elect(0).unwrap(),
+				vec![
+					(
+						10 as AccountId,
+						Support { total: 29, voters: vec![(1 as AccountId, 10), (3, 19)] }
+					),
+					(30, Support { total: 31, voters: vec![(2, 20), (3, 11)] })
+				]
+				.try_into()
+				.unwrap()
+			);
+		})
+	}
 }
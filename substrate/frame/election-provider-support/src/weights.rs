@@ -47,6 +47,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn phragmen(v: u32, t: u32, d: u32, ) -> Weight;
 	fn phragmms(v: u32, t: u32, d: u32, ) -> Weight;
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight;
+	fn approval_voting(v: u32, t: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_election_provider_support_benchmarking using the Substrate node and recommended hardware.
@@ -70,6 +72,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 6_649_000
 			.saturating_add(Weight::from_parts(1_711_424_000 as u64, 0).saturating_mul(d as u64))
 	}
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 98_000
+			.saturating_add(Weight::from_parts(24_618_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 196_000
+			.saturating_add(Weight::from_parts(9_742_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 8_912_000
+			.saturating_add(Weight::from_parts(1_932_117_000 as u64, 0).saturating_mul(d as u64))
+	}
+	fn approval_voting(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(2_846_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(41_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 1_104_000
+			.saturating_add(Weight::from_parts(214_533_000 as u64, 0).saturating_mul(d as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -92,4 +112,22 @@ impl WeightInfo for () {
 			// Standard Error: 6_649_000
 			.saturating_add(Weight::from_parts(1_711_424_000 as u64, 0).saturating_mul(d as u64))
 	}
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 98_000
+			.saturating_add(Weight::from_parts(24_618_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 196_000
+			.saturating_add(Weight::from_parts(9_742_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 8_912_000
+			.saturating_add(Weight::from_parts(1_932_117_000 as u64, 0).saturating_mul(d as u64))
+	}
+	fn approval_voting(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(2_846_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(41_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 1_104_000
+			.saturating_add(Weight::from_parts(214_533_000 as u64, 0).saturating_mul(d as u64))
+	}
 }
@@ -185,6 +185,34 @@ mod benchmarks {
 		assert_eq!(weight.consumed(), T::WeightInfo::set_service_head());
 	}
 
+	// Worst case for visiting a queue while servicing the queues by priority class: the queue is
+	// not scheduled yet and all other priority classes have ready queues.
+	#[benchmark]
+	fn schedule_visit() {
+		let origin: MessageOriginOf<T> = 0.into();
+		build_ring::<T>(&[origin.clone()]);
+		let class = T::QueuePriority::priority_class(&origin);
+		QueueScheduleFor::<T>::remove(&origin);
+		let classes = (0..=u8::MAX).rev().filter(|c| *c != class).map(|c| (c, 1)).collect();
+		ReadyClasses::<T>::put(BoundedVec::truncate_from(classes));
+		// Without any deficit the queue itself is not serviced.
+		let mut weight = WeightMeter::with_limit(T::WeightInfo::schedule_visit());
+
+		#[block]
+		{
+			MessageQueue::<T>::service_queue_scheduled(
+				origin.clone(),
+				class,
+				&mut weight,
+				Weight::MAX,
+				Weight::zero(),
+			);
+		}
+
+		assert!(QueueScheduleFor::<T>::contains_key(&origin));
+		assert_eq!(ReadyClasses::<T>::get().len(), 256);
+	}
+
 	#[benchmark]
 	fn reap_page() {
 		// Mock the storage to get a *cullable* but not *reapable* page.
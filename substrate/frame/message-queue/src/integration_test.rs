@@ -70,6 +70,7 @@ impl Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = AhmPrioritizer;
 	type QueuePausedQuery = ();
+	type QueuePriority = ();
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
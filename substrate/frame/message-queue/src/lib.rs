@@ -124,6 +124,19 @@
 //! to advance to the next *ready* queue and service it. This continues until there are no more
 //! queues on which it can make progress or not enough weight to check that.
 //!
+//! # Scenario: Weighted fair scheduling
+//!
+//! By default all *ready* queues are equal and each of them may use all the remaining weight when
+//! it is serviced. Runtimes can opt into scheduling through [`Config::QueuePriority`], which
+//! assigns every `MessageOrigin` a priority class and a weight share:
+//!
+//! Queues of a higher priority class are serviced before any queue of a lower class. Within a
+//! class the queues are serviced by deficit round robin: each time a queue is visited, its deficit
+//! grows by its share times the [`QueuePriority::quantum`] and the queue may consume up to its
+//! deficit before the next queue of the class is serviced. The deficit of a queue is kept across
+//! blocks while it is *ready* and dropped once it runs out of messages. The scheduling state can be
+//! inspected with the [`runtime_api::MessageQueueApi`].
+//!
 //! # Scenario: Overweight execution
 //!
 //! A permanently over-weight message which was skipped by the message processing will never be
@@ -200,6 +213,7 @@ mod benchmarking;
 mod integration_test;
 mod mock;
 pub mod mock_helpers;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -481,6 +495,67 @@ impl<Id> OnQueueChanged<Id> for () {
 	fn on_queue_changed(_: Id, _: QueueFootprint) {}
 }
 
+/// Scheduling parameters of the queues, used to share the service weight between them.
+///
+/// See the *Weighted fair scheduling* section of the [crate] docs.
+pub trait QueuePriority<Origin> {
+	/// The weight credited to a queue per unit of its share each time it is visited.
+	///
+	/// `None` disables the scheduling; the ready queues are then serviced round robin and each of
+	/// them may use all the remaining weight.
+	fn quantum() -> Option<Weight>;
+
+	/// The priority class of the queue of `origin`. Higher classes are serviced first.
+	///
+	/// The class is read when the queue becomes ready and kept until it is empty again.
+	fn priority_class(origin: &Origin) -> u8;
+
+	/// The share of the queue of `origin` in the weight available to its priority class.
+	fn weight_share(origin: &Origin) -> u32;
+}
+
+impl<Origin> QueuePriority<Origin> for () {
+	fn quantum() -> Option<Weight> {
+		None
+	}
+
+	fn priority_class(_: &Origin) -> u8 {
+		0
+	}
+
+	fn weight_share(_: &Origin) -> u32 {
+		1
+	}
+}
+
+/// The scheduling state of a ready queue.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Default)]
+pub struct QueueSchedule {
+	/// The priority class of the queue, as of when it became ready.
+	pub priority_class: u8,
+	/// The weight which the queue may still consume before yielding to the next queue of its
+	/// class.
+	pub deficit: Weight,
+}
+
+/// The scheduling state of a ready queue as returned by [`runtime_api::MessageQueueApi`].
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq)]
+pub struct QueueSchedulingInfo<MessageOrigin> {
+	/// The origin of the queue.
+	pub origin: MessageOrigin,
+	/// The priority class of the queue.
+	pub priority_class: u8,
+	/// The share of the queue in the weight available to its priority class.
+	pub weight_share: u32,
+	/// The weight which the queue may still consume before yielding to the next queue of its
+	/// class.
+	pub deficit: Weight,
+	/// The number of unprocessed messages in the queue.
+	pub message_count: u64,
+	/// Whether the queue is paused.
+	pub paused: bool,
+}
+
 /// Allows to force the processing head to a specific queue.
 pub trait ForceSetHead<O> {
 	/// Set the `ServiceHead` to `origin`.
@@ -545,6 +620,11 @@ pub mod pallet {
 		/// it that happen *within* the servicing will not be reflected.
 		type QueuePausedQuery: QueuePausedQuery<<Self::MessageProcessor as ProcessMessage>::Origin>;
 
+		/// Assigns the queues their priority class and weight share.
+		///
+		/// Use `()` to service all ready queues round robin without any scheduling.
+		type QueuePriority: QueuePriority<<Self::MessageProcessor as ProcessMessage>::Origin>;
+
 		/// The size of the page; this implies the maximum message size which can be sent.
 		///
 		/// A good value depends on the expected message sizes, their weights, the weight that is
@@ -665,6 +745,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>, OptionQuery>;
 
+	/// The scheduling state of the ready queues.
+	///
+	/// Only populated if the scheduling is enabled through [`Config::QueuePriority`].
+	#[pallet::storage]
+	pub type QueueScheduleFor<T: Config> =
+		StorageMap<_, Twox64Concat, MessageOriginOf<T>, QueueSchedule, OptionQuery>;
+
+	/// The number of ready queues per priority class, ordered by descending class.
+	#[pallet::storage]
+	pub type ReadyClasses<T: Config> =
+		StorageValue<_, BoundedVec<(u8, u32), ConstU32<256>>, ValueQuery>;
+
 	/// The map of page indices to pages.
 	#[pallet::storage]
 	pub type Pages<T: Config> = StorageDoubleMap<
@@ -851,6 +943,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
+		// Also done while the scheduling is disabled, to drop the state of queues which became
+		// ready while it was enabled.
+		Self::unschedule_queue(origin);
 		if origin == &neighbours.next {
 			debug_assert!(
 				origin == &neighbours.prev,
@@ -879,6 +974,75 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Start tracking the scheduling state of the ready queue `origin`.
+	///
+	/// Returns the state of the queue, which starts without any deficit.
+	fn schedule_ready_queue(origin: &MessageOriginOf<T>) -> QueueSchedule {
+		if let Some(schedule) = QueueScheduleFor::<T>::get(origin) {
+			return schedule
+		}
+		let schedule = QueueSchedule {
+			priority_class: T::QueuePriority::priority_class(origin),
+			deficit: Weight::zero(),
+		};
+		ReadyClasses::<T>::mutate(|classes| {
+			match classes.binary_search_by(|(class, _)| schedule.priority_class.cmp(class)) {
+				Ok(i) => classes[i].1.saturating_inc(),
+				Err(i) =>
+					if classes.try_insert(i, (schedule.priority_class, 1)).is_err() {
+						defensive!("There are at most 256 priority classes");
+					},
+			}
+		});
+		QueueScheduleFor::<T>::insert(origin, &schedule);
+		schedule
+	}
+
+	/// Stop tracking the scheduling state of `origin`, if it was tracked.
+	fn unschedule_queue(origin: &MessageOriginOf<T>) {
+		let Some(schedule) = QueueScheduleFor::<T>::take(origin) else { return };
+		ReadyClasses::<T>::mutate(|classes| {
+			match classes.binary_search_by(|(class, _)| schedule.priority_class.cmp(class)) {
+				Ok(i) => {
+					classes[i].1.saturating_dec();
+					if classes[i].1.is_zero() {
+						classes.remove(i);
+					}
+				},
+				Err(_) => {
+					defensive!("A scheduled queue must be counted in its priority class");
+				},
+			}
+		});
+	}
+
+	/// The scheduling state of all ready queues, in the order in which they are visited next.
+	pub fn scheduling_state() -> Vec<QueueSchedulingInfo<MessageOriginOf<T>>> {
+		let mut queues = Vec::new();
+		let Some(head) = ServiceHead::<T>::get() else { return queues };
+		let mut next = head.clone();
+		loop {
+			let book_state = BookStateFor::<T>::get(&next);
+			let schedule = QueueScheduleFor::<T>::get(&next).unwrap_or_else(|| QueueSchedule {
+				priority_class: T::QueuePriority::priority_class(&next),
+				deficit: Weight::zero(),
+			});
+			queues.push(QueueSchedulingInfo {
+				origin: next.clone(),
+				priority_class: schedule.priority_class,
+				weight_share: T::QueuePriority::weight_share(&next),
+				deficit: schedule.deficit,
+				message_count: book_state.message_count,
+				paused: T::QueuePausedQuery::is_paused(&next),
+			});
+			match book_state.ready_neighbours {
+				Some(neighbours) if neighbours.next != head => next = neighbours.next,
+				_ => break,
+			}
+		}
+		queues
+	}
+
 	/// Tries to bump the current `ServiceHead` to the next ready queue.
 	///
 	/// Returns the current head if it got be bumped and `None` otherwise.
@@ -1048,7 +1212,12 @@ impl<T: Config> Pallet<T> {
 		// Insert book state for current origin into the ready queue.
 		if book_state.ready_neighbours.is_none() {
 			match Self::ready_ring_knit(origin) {
-				Ok(neighbours) => book_state.ready_neighbours = Some(neighbours),
+				Ok(neighbours) => {
+					book_state.ready_neighbours = Some(neighbours);
+					if T::QueuePriority::quantum().is_some() {
+						Self::schedule_ready_queue(origin);
+					}
+				},
 				Err(()) => {
 					defensive!("Ring state invalid when knitting");
 				},
@@ -1433,6 +1602,25 @@ impl<T: Config> Pallet<T> {
 			ensure!(fp.ready_pages <= fp.pages, "There cannot be more ready than total pages");
 		}
 
+		// The scheduling state must be consistent even while the scheduling is disabled.
+		let classes = ReadyClasses::<T>::get();
+		ensure!(
+			classes.windows(2).all(|w| w[0].0 > w[1].0),
+			"Priority classes must be sorted in descending order"
+		);
+		ensure!(classes.iter().all(|(_, count)| *count > 0), "Empty priority classes are removed");
+		let scheduled = QueueScheduleFor::<T>::iter().try_fold(0u32, |count, (origin, _)| {
+			ensure!(
+				BookStateFor::<T>::get(&origin).ready_neighbours.is_some(),
+				"Only ready queues are scheduled"
+			);
+			Ok::<_, sp_runtime::TryRuntimeError>(count + 1)
+		})?;
+		ensure!(
+			classes.iter().map(|(_, count)| count).sum::<u32>() == scheduled,
+			"Every scheduled queue must be counted in its priority class"
+		);
+
 		//loop around this origin
 		let Some(starting_origin) = ServiceHead::<T>::get() else { return Ok(()) };
 
@@ -1640,6 +1828,10 @@ impl<T: Config> Pallet<T> {
 		});
 
 		match with_service_mutex(|| {
+			if let Some(quantum) = T::QueuePriority::quantum() {
+				return Self::service_queues_scheduled(&mut weight, overweight_limit, quantum)
+			}
+
 			let mut next = match Self::bump_service_head(&mut weight) {
 				Some(h) => h,
 				None => return weight.consumed(),
@@ -1675,6 +1867,121 @@ impl<T: Config> Pallet<T> {
 			Ok(w) => w,
 		}
 	}
+
+	/// Service the ready queues class by class, sharing the weight of a class between its queues
+	/// by deficit round robin.
+	fn service_queues_scheduled(
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		quantum: Weight,
+	) -> Weight {
+		let Some(head) = Self::bump_service_head(weight) else { return weight.consumed() };
+
+		let mut classes: Vec<u8> =
+			ReadyClasses::<T>::get().iter().map(|(class, _)| *class).collect();
+		// Queues which became ready while the scheduling was disabled are not counted yet.
+		let head_class = Self::schedule_ready_queue(&head).priority_class;
+		if !classes.contains(&head_class) {
+			classes.push(head_class);
+			classes.sort_by(|a, b| b.cmp(a));
+		}
+
+		for class in classes {
+			// Every class starts at the bumped head, unless it was serviced empty already.
+			let mut next = if BookStateFor::<T>::get(&head).ready_neighbours.is_some() {
+				head.clone()
+			} else {
+				match ServiceHead::<T>::get() {
+					Some(h) => h,
+					None => break,
+				}
+			};
+			// The last queue that did not make any progress.
+			// The class is done as soon as it arrives at this queue again without making any
+			// progress on other queues in between.
+			let mut last_no_progress = None;
+
+			loop {
+				let (progressed, n) = Self::service_queue_scheduled(
+					next.clone(),
+					class,
+					weight,
+					overweight_limit,
+					quantum,
+				);
+				next = match n {
+					Some(n) =>
+						if !progressed {
+							if last_no_progress == Some(n.clone()) {
+								break
+							}
+							if last_no_progress.is_none() {
+								last_no_progress = Some(next.clone())
+							}
+							n
+						} else {
+							last_no_progress = None;
+							n
+						},
+					None => break,
+				}
+			}
+		}
+		weight.consumed()
+	}
+
+	/// Visit the queue of `origin` while servicing the priority class `class`.
+	///
+	/// Queues of other classes and paused queues are skipped. Otherwise the deficit of the queue is
+	/// credited and the queue is serviced with up to its deficit. Returns whether the queue
+	/// processed any message, and the next ready queue.
+	///
+	/// A queue which can't afford its next message yet is credited on every visit, and keeps its
+	/// deficit for the next block.
+	fn service_queue_scheduled(
+		origin: MessageOriginOf<T>,
+		class: u8,
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		quantum: Weight,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		if weight.try_consume(Self::schedule_visit_weight()).is_err() {
+			return (false, None)
+		}
+
+		let Some(next_ready) = BookStateFor::<T>::get(&origin).ready_neighbours.map(|n| n.next)
+		else {
+			// The queue was serviced empty within this class.
+			return (false, None)
+		};
+		let mut schedule = Self::schedule_ready_queue(&origin);
+		// Paused queues don't accumulate any deficit.
+		if schedule.priority_class != class || T::QueuePausedQuery::is_paused(&origin) {
+			return (false, Some(next_ready))
+		}
+
+		// The deficit is capped so that queues cannot save up weight for bursts, while still being
+		// able to afford any message that is not overweight.
+		let credit = quantum.saturating_mul(T::QueuePriority::weight_share(&origin).into());
+		let max_deficit = credit.max(overweight_limit.saturating_add(Self::single_msg_overhead()));
+		let deficit = schedule.deficit.saturating_add(credit).min(max_deficit);
+
+		let mut meter = WeightMeter::with_limit(weight.remaining().min(deficit));
+		let (processed, _) = Self::service_queue(origin.clone(), &mut meter, overweight_limit);
+		weight.consume(meter.consumed());
+		schedule.deficit = deficit.saturating_sub(meter.consumed());
+
+		// Unknitting the queue dropped its scheduling state.
+		if BookStateFor::<T>::get(&origin).ready_neighbours.is_some() {
+			QueueScheduleFor::<T>::insert(&origin, schedule);
+		}
+		(processed, Some(next_ready))
+	}
+
+	/// The weight of visiting a queue while servicing the queues by priority class.
+	fn schedule_visit_weight() -> Weight {
+		T::WeightInfo::schedule_visit()
+	}
 }
 
 impl<T: Config> ForceSetHead<MessageOriginOf<T>> for Pallet<T> {
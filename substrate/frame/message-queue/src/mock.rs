@@ -54,6 +54,7 @@ impl Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = RecordingQueueChangeHandler;
 	type QueuePausedQuery = MockedQueuePauser;
+	type QueuePriority = MockedQueuePriority;
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
@@ -146,6 +147,12 @@ impl crate::weights::WeightInfo for MockedWeightInfo {
 			.copied()
 			.unwrap_or(DefaultWeightForCall::get())
 	}
+	fn schedule_visit() -> Weight {
+		WeightForCall::get()
+			.get("schedule_visit")
+			.copied()
+			.unwrap_or(DefaultWeightForCall::get())
+	}
 }
 
 parameter_types! {
@@ -303,6 +310,30 @@ impl QueuePausedQuery<MessageOrigin> for MockedQueuePauser {
 	}
 }
 
+parameter_types! {
+	/// The quantum of [`MockedQueuePriority`]; `None` disables the scheduling.
+	pub static SchedulingQuantum: Option<Weight> = None;
+	/// The priority classes of [`MockedQueuePriority`]; defaults to `0`.
+	pub static PriorityClasses: Vec<(MessageOrigin, u8)> = vec![];
+	/// The weight shares of [`MockedQueuePriority`]; defaults to `1`.
+	pub static WeightShares: Vec<(MessageOrigin, u32)> = vec![];
+}
+
+pub struct MockedQueuePriority;
+impl QueuePriority<MessageOrigin> for MockedQueuePriority {
+	fn quantum() -> Option<Weight> {
+		SchedulingQuantum::get()
+	}
+
+	fn priority_class(origin: &MessageOrigin) -> u8 {
+		PriorityClasses::get().iter().find(|(o, _)| o == origin).map_or(0, |(_, c)| *c)
+	}
+
+	fn weight_share(origin: &MessageOrigin) -> u32 {
+		WeightShares::get().iter().find(|(o, _)| o == origin).map_or(1, |(_, s)| *s)
+	}
+}
+
 /// Create new test externalities.
 ///
 /// Is generic since it is used by the unit test, integration tests and benchmarks.
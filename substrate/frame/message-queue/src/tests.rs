@@ -2220,3 +2220,139 @@ fn get_batches_footprints_works() {
 		check_get_batches_footprints(There, &[max_message_len], 1, 0, vec![1]);
 	});
 }
+
+#[test]
+fn scheduling_shares_weight_by_deficit_round_robin() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		SchedulingQuantum::set(Some(1.into_weight()));
+		WeightShares::set(vec![(Here, 2)]);
+		let msgs = || (0..6).map(|_| msg("a"));
+		MessageQueue::enqueue_messages(msgs(), Here);
+		MessageQueue::enqueue_messages(msgs(), There);
+
+		// `Here` gets twice the weight of `There`.
+		assert_eq!(MessageQueue::service_queues(6.into_weight()), 6.into_weight());
+		let origins = MessagesProcessed::take().into_iter().map(|(_, o)| o).collect::<Vec<_>>();
+		assert_eq!(origins, vec![Here, Here, There, Here, Here, There]);
+
+		// The unused deficits are kept for the next round, starting at the next queue.
+		assert_eq!(
+			MessageQueue::scheduling_state(),
+			vec![
+				QueueSchedulingInfo {
+					origin: There,
+					priority_class: 0,
+					weight_share: 1,
+					deficit: 1.into_weight(),
+					message_count: 4,
+					paused: false,
+				},
+				QueueSchedulingInfo {
+					origin: Here,
+					priority_class: 0,
+					weight_share: 2,
+					deficit: 2.into_weight(),
+					message_count: 2,
+					paused: false,
+				},
+			]
+		);
+
+		// Drained queues are no longer scheduled.
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 6.into_weight());
+		assert!(MessageQueue::scheduling_state().is_empty());
+		assert_eq!(QueueScheduleFor::<Test>::iter().count(), 0);
+		assert!(ReadyClasses::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn scheduling_services_higher_priority_classes_first() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		SchedulingQuantum::set(Some(1.into_weight()));
+		PriorityClasses::set(vec![(There, 1)]);
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y")].into_iter(), There);
+		assert_eq!(ReadyClasses::<Test>::get().into_inner(), vec![(1, 1), (0, 1)]);
+
+		// `Here` is the service head but `There` has the higher priority.
+		assert_eq!(MessageQueue::service_queues(3.into_weight()), 3.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("x"), There), (vmsg("y"), There), (vmsg("a"), Here)]
+		);
+		assert_eq!(ReadyClasses::<Test>::get().into_inner(), vec![(0, 1)]);
+	});
+}
+
+#[test]
+fn scheduling_picks_up_queues_that_were_ready_before() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y")].into_iter(), There);
+		assert_eq!(QueueScheduleFor::<Test>::iter().count(), 0);
+
+		SchedulingQuantum::set(Some(1.into_weight()));
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 4.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("a"), Here), (vmsg("x"), There), (vmsg("b"), Here), (vmsg("y"), There)]
+		);
+	});
+}
+
+#[test]
+fn scheduling_credits_queues_over_several_blocks() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		SchedulingQuantum::set(Some(1.into_weight()));
+		MessageQueue::enqueue_message(msg("weight=3"), Here);
+
+		// Crediting a queue is no progress: the servicing stops once the queue was visited twice
+		// without affording its message.
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), Weight::zero());
+		assert_eq!(QueueScheduleFor::<Test>::get(Here).unwrap().deficit, 2.into_weight());
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 3.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("weight=3"), Here)]);
+	});
+}
+
+#[test]
+fn scheduling_does_not_credit_paused_queues() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		SchedulingQuantum::set(Some(1.into_weight()));
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_message(msg("x"), There);
+		PausedQueues::set(vec![Here]);
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("x"), There)]);
+		assert_eq!(QueueScheduleFor::<Test>::get(Here).unwrap().deficit, Weight::zero());
+
+		PausedQueues::set(vec![]);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 2.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("a"), Here), (vmsg("b"), Here)]);
+	});
+}
+
+#[test]
+fn scheduling_state_is_dropped_while_disabled() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		SchedulingQuantum::set(Some(1.into_weight()));
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("x"), There);
+		assert_eq!(QueueScheduleFor::<Test>::iter().count(), 2);
+
+		// The queues are drained after the scheduling was disabled.
+		SchedulingQuantum::set(None);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 2.into_weight());
+		assert_eq!(QueueScheduleFor::<Test>::iter().count(), 0);
+		assert!(ReadyClasses::<Test>::get().is_empty());
+	});
+}
@@ -83,6 +83,7 @@ pub trait WeightInfo {
 	fn reap_page() -> Weight;
 	fn execute_overweight_page_removed() -> Weight;
 	fn execute_overweight_page_updated() -> Weight;
+	fn schedule_visit() -> Weight;
 }
 
 /// Weights for `pallet_message_queue` using the Substrate node and recommended hardware.
@@ -224,6 +225,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet, bounded by `bump_service_head` and the accesses to the scheduling
+	/// state of the visited queue and of its priority class.
+	fn schedule_visit() -> Weight {
+		<Self as WeightInfo>::bump_service_head()
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -364,4 +372,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet, bounded by `bump_service_head` and the accesses to the scheduling
+	/// state of the visited queue and of its priority class.
+	fn schedule_visit() -> Weight {
+		<Self as WeightInfo>::bump_service_head()
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(RocksDbWeight::get().reads_writes(3, 2))
+	}
 }
@@ -0,0 +1,227 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The XCM asset transactor of the `pallet-nfts` items.
+
+use super::*;
+use core::marker::PhantomData;
+use xcm_executor::{
+	traits::{ConvertLocation, Error as MatchError, TransactAsset},
+	AssetsInHolding,
+};
+
+/// A `pallet-nfts` item identified by an XCM asset.
+enum MatchedItem<CollectionId, ItemId> {
+	/// An item of a local collection.
+	Local(CollectionId, ItemId),
+	/// An item of a derivative collection.
+	Derivative(CollectionId, ItemId),
+	/// An item of a foreign collection having no derivative yet.
+	Foreign(ItemId),
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	fn match_item(
+		asset: &Asset,
+	) -> Result<(NonFungibleAsset, MatchedItem<T::CollectionId, T::ItemId>), XcmError> {
+		let Asset { id, fun: NonFungible(instance) } = asset else {
+			return Err(XcmError::AssetNotFound)
+		};
+		let item = T::ItemInstance::convert(instance).ok_or(XcmError::AssetNotFound)?;
+
+		let matched = if let Ok(collection) = T::Derivatives::get_derivative(id) {
+			MatchedItem::Derivative(collection, item)
+		} else if let Some(collection) = T::CollectionLocation::convert(&id.0) {
+			// A derivative must not be sent as if this chain was its reserve.
+			if T::Derivatives::get_original(&collection).is_ok() {
+				return Err(XcmError::AssetNotFound)
+			}
+			MatchedItem::Local(collection, item)
+		} else {
+			MatchedItem::Foreign(item)
+		};
+
+		Ok(((id.clone(), *instance), matched))
+	}
+
+	fn transfer_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		from: T::AccountId,
+		to: T::AccountId,
+	) -> DispatchResult {
+		pallet_nfts::Pallet::<T, I>::do_transfer(collection, item, to, |_, details| {
+			ensure!(details.owner == from, pallet_nfts::Error::<T, I>::NoPermission);
+			Ok(())
+		})
+	}
+
+	fn burn_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		owner: T::AccountId,
+	) -> DispatchResult {
+		pallet_nfts::Pallet::<T, I>::do_burn(collection, item, |details| {
+			ensure!(details.owner == owner, pallet_nfts::Error::<T, I>::NoPermission);
+			Ok(())
+		})?;
+		Self::clear_received_attributes(&collection, &item)
+	}
+}
+
+/// The [`TransactAsset`] implementation for the `pallet-nfts` items transferred by the
+/// [`Pallet`].
+///
+/// * The local items are withdrawn to the pallet account, deposited from it, and burned when
+///   checked out by a teleport. A local item checked in by a teleport is minted again on deposit.
+/// * The derivative items are burned on withdrawal and minted on deposit. The first item of a
+///   foreign collection creates its derivative collection.
+///
+/// The minted items get the data received by [`Pallet::receive_data`].
+pub struct NftsXcmAdapter<T, I, AccountIdConverter>(PhantomData<(T, I, AccountIdConverter)>);
+
+impl<T, I, AccountIdConverter> TransactAsset for NftsXcmAdapter<T, I, AccountIdConverter>
+where
+	T: Config<I>,
+	I: 'static,
+	AccountIdConverter: ConvertLocation<T::AccountId>,
+{
+	fn can_check_in(_origin: &Location, what: &Asset, _context: &XcmContext) -> XcmResult {
+		match Pallet::<T, I>::match_item(what)?.1 {
+			// A local item can only come back if it was teleported away.
+			MatchedItem::Local(collection, item)
+				if pallet_nfts::Item::<T, I>::contains_key(&collection, &item) =>
+				Err(XcmError::NotDepositable),
+			_ => Ok(()),
+		}
+	}
+
+	fn can_check_out(_dest: &Location, what: &Asset, _context: &XcmContext) -> XcmResult {
+		match Pallet::<T, I>::match_item(what)?.1 {
+			MatchedItem::Local(collection, item) => {
+				let owner =
+					pallet_nfts::Item::<T, I>::get(&collection, &item).map(|details| details.owner);
+				ensure!(owner == Some(Pallet::<T, I>::account_id()), XcmError::NotWithdrawable);
+				Ok(())
+			},
+			// Burned on withdrawal.
+			MatchedItem::Derivative(..) => Ok(()),
+			MatchedItem::Foreign(_) => Err(XcmError::AssetNotFound),
+		}
+	}
+
+	fn check_out(_dest: &Location, what: &Asset, _context: &XcmContext) {
+		if let Ok((_, MatchedItem::Local(collection, item))) = Pallet::<T, I>::match_item(what) {
+			if let Err(error) =
+				Pallet::<T, I>::burn_item(collection, item, Pallet::<T, I>::account_id())
+			{
+				tracing::error!(
+					target: LOG_TARGET,
+					?error,
+					?what,
+					"Failed to burn the teleported item",
+				);
+			}
+		}
+	}
+
+	fn deposit_asset(what: &Asset, who: &Location, context: Option<&XcmContext>) -> XcmResult {
+		tracing::trace!(target: LOG_TARGET, ?what, ?who, ?context, "deposit_asset");
+
+		let (original, matched) = Pallet::<T, I>::match_item(what)?;
+		let who = AccountIdConverter::convert_location(who)
+			.ok_or(MatchError::AccountIdConversionFailed)?;
+		// Only the data delivered by the same program is applied.
+		let topic = context.and_then(|context| context.topic);
+
+		match matched {
+			MatchedItem::Local(collection, item)
+				if pallet_nfts::Item::<T, I>::contains_key(&collection, &item) =>
+				Pallet::<T, I>::transfer_item(collection, item, Pallet::<T, I>::account_id(), who),
+			MatchedItem::Local(collection, item) =>
+				Pallet::<T, I>::mint_received(&original, topic, collection, item, who, false),
+			MatchedItem::Derivative(collection, item) =>
+				Pallet::<T, I>::mint_received(&original, topic, collection, item, who, true),
+			MatchedItem::Foreign(item) => {
+				// Only an item with its data may create a derivative collection.
+				ensure!(
+					topic.is_some_and(|topic| PendingData::<T, I>::contains_key((
+						original.clone(),
+						topic
+					))),
+					XcmError::AssetNotFound
+				);
+				Pallet::<T, I>::create_derivative_collection(&original.0).and_then(|collection| {
+					Pallet::<T, I>::mint_received(&original, topic, collection, item, who, true)
+				})
+			},
+		}
+		.map_err(|error| {
+			tracing::debug!(target: LOG_TARGET, ?error, ?what, "Failed to deposit the item");
+			XcmError::FailedToTransactAsset(error.into())
+		})
+	}
+
+	fn withdraw_asset(
+		what: &Asset,
+		who: &Location,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<AssetsInHolding, XcmError> {
+		tracing::trace!(target: LOG_TARGET, ?what, ?who, ?maybe_context, "withdraw_asset");
+
+		let who = AccountIdConverter::convert_location(who)
+			.ok_or(MatchError::AccountIdConversionFailed)?;
+
+		match Pallet::<T, I>::match_item(what)?.1 {
+			MatchedItem::Local(collection, item) =>
+				Pallet::<T, I>::transfer_item(collection, item, who, Pallet::<T, I>::account_id()),
+			MatchedItem::Derivative(collection, item) =>
+				Pallet::<T, I>::burn_item(collection, item, who),
+			MatchedItem::Foreign(_) => return Err(XcmError::AssetNotFound),
+		}
+		.map_err(|error| {
+			tracing::debug!(target: LOG_TARGET, ?error, ?what, "Failed to withdraw the item");
+			XcmError::FailedToTransactAsset(error.into())
+		})?;
+
+		Ok(what.clone().into())
+	}
+
+	fn internal_transfer_asset(
+		what: &Asset,
+		from: &Location,
+		to: &Location,
+		context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		tracing::trace!(target: LOG_TARGET, ?what, ?from, ?to, ?context, "internal_transfer_asset");
+
+		let (MatchedItem::Local(collection, item) | MatchedItem::Derivative(collection, item)) =
+			Pallet::<T, I>::match_item(what)?.1
+		else {
+			return Err(XcmError::AssetNotFound)
+		};
+		let from = AccountIdConverter::convert_location(from)
+			.ok_or(MatchError::AccountIdConversionFailed)?;
+		let to = AccountIdConverter::convert_location(to)
+			.ok_or(MatchError::AccountIdConversionFailed)?;
+
+		Pallet::<T, I>::transfer_item(collection, item, from, to)
+			.map_err(|error| XcmError::FailedToTransactAsset(error.into()))?;
+
+		Ok(what.clone().into())
+	}
+}
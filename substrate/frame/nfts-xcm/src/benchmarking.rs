@@ -0,0 +1,180 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the NFTs XCM pallet.
+
+use super::{Pallet as NftsXcm, *};
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+
+pub struct Pallet<T: Config<I>, I: 'static = ()>(NftsXcm<T, I>);
+
+/// The parameters of a transfer of an item owned by the account of the `origin`.
+pub struct TransferSetup<T: Config<I>, I: 'static = ()> {
+	pub origin: T::RuntimeOrigin,
+	pub collection: T::CollectionId,
+	pub item: T::ItemId,
+	pub dest: Location,
+	pub mode: TransferMode,
+	pub fees: Asset,
+	pub fees_transfer_type: TransferType,
+	pub delivery_fees: Asset,
+}
+
+pub trait Config<I: 'static = ()>: super::Config<I> {
+	/// An origin trusted to deliver the data of the returned item.
+	fn data_source() -> Result<(Self::RuntimeOrigin, NonFungibleAsset), BenchmarkError>;
+
+	/// Mints an item to the account of the origin and funds the account to transfer the item.
+	///
+	/// Implementation should also make sure the destination is reachable.
+	///
+	/// If `None`, the `transfer` benchmark defaults to `Weight::MAX`.
+	fn transfer_setup() -> Option<TransferSetup<Self, I>> {
+		None
+	}
+}
+
+/// A key of the given length, distinct for every `index`.
+fn key(index: u32, len: u32) -> Vec<u8> {
+	let mut key = vec![0; len as usize];
+	key.iter_mut().zip(index.to_le_bytes()).for_each(|(k, b)| *k = b);
+	key
+}
+
+/// The data of the largest size.
+fn max_data<T: Config<I>, I: 'static>() -> NftDataOf<T, I> {
+	let metadata = vec![0; T::StringLimit::get() as usize];
+	let attributes = (0..T::MaxAttributes::get())
+		.map(|i| {
+			let key = key(i, T::KeyLimit::get()).try_into().expect("the key has the max length");
+			let value = vec![0; T::ValueLimit::get() as usize];
+			(
+				AttributeOwner::ItemOwner,
+				key,
+				value.try_into().expect("the value has the max length"),
+			)
+		})
+		.collect::<Vec<_>>();
+	NftData {
+		metadata: Some(metadata.try_into().expect("the metadata has the max length")),
+		attributes: attributes.try_into().expect("the attributes have the max number"),
+	}
+}
+
+fn max_transfer_data<T: Config<I>, I: 'static>() -> TransferDataOf<T, I> {
+	TransferData { collection: max_data::<T, I>(), item: max_data::<T, I>() }
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn transfer() -> Result<(), BenchmarkError> {
+		let TransferSetup {
+			origin,
+			collection,
+			item,
+			dest,
+			mode,
+			fees,
+			fees_transfer_type,
+			delivery_fees,
+		} = T::transfer_setup()
+			.ok_or(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+
+		// The item and its collection carry the largest data, the received keys leave room for
+		// the prefix.
+		type Nfts<T, I> = pallet_nfts::Pallet<T, I>;
+		let key_len = T::KeyLimit::get().saturating_sub(11);
+		let metadata = vec![0; T::StringLimit::get() as usize];
+		let value = vec![0; T::ValueLimit::get() as usize];
+		<Nfts<T, I> as Mutate<_, ItemConfig>>::set_collection_metadata(
+			None,
+			&collection,
+			&metadata,
+		)?;
+		<Nfts<T, I> as Mutate<_, ItemConfig>>::set_item_metadata(
+			None,
+			&collection,
+			&item,
+			&metadata,
+		)?;
+		for i in 0..T::MaxAttributes::get() {
+			let key = AttributeOwner::ItemOwner.received_key(&key(i, key_len));
+			<Nfts<T, I> as Mutate<_, ItemConfig>>::set_collection_attribute(
+				&collection,
+				&key,
+				&value,
+			)?;
+			<Nfts<T, I> as Mutate<_, ItemConfig>>::set_attribute(&collection, &item, &key, &value)?;
+		}
+		let beneficiary: Location = AccountId32 { network: None, id: [1; 32] }.into();
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			collection,
+			item,
+			Box::new(dest.into()),
+			Box::new(beneficiary.into()),
+			mode,
+			Box::new(fees.into()),
+			Box::new(fees_transfer_type),
+			Box::new(delivery_fees.into()),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_data() -> Result<(), BenchmarkError> {
+		let (origin, original) = T::data_source()?;
+		let topic = [1; 32];
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(original.clone()),
+			Box::new(max_transfer_data::<T, I>()),
+			topic,
+		);
+
+		assert!(PendingData::<T, I>::contains_key((original, topic)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_pending_data(n: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		let (_, original) = T::data_source()?;
+		for i in 0..n {
+			let topic = i.using_encoded(sp_io::hashing::blake2_256);
+			PendingData::<T, I>::insert((original.clone(), topic), max_transfer_data::<T, I>());
+		}
+
+		#[block]
+		{
+			NftsXcm::<T, I>::on_finalize(Zero::zero());
+		}
+
+		assert_eq!(PendingData::<T, I>::iter().count(), 0);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
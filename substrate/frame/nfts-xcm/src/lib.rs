@@ -0,0 +1,690 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # NFTs XCM Pallet
+//!
+//! The `pallet-nfts-xcm` transfers `pallet-nfts` items to other chains over XCM together with
+//! their data: the metadata of the item and its collection and the attributes set by the
+//! collection and item owners.
+//!
+//! ## Overview
+//!
+//! XCM can only move the identity of a non-fungible asset, the data associated with it stays on the
+//! sending chain. This pallet carries the data along with the item within the same XCM program:
+//!
+//! 1. [`Pallet::transfer`] hands the item and the fees over to the pallet account, which executes a
+//!    program initiating the transfer to the destination with its origin preserved.
+//! 2. On the destination, the program dispatches [`Pallet::receive_data`] via `Transact`. The call
+//!    checks that the data comes from the pallet account of a chain trusted as a reserve or a
+//!    teleporter of the item and stores the data as pending for the topic of the program.
+//! 3. The program deposits the item to the beneficiary. The [`NftsXcmAdapter`] mints it with the
+//!    data pending for the topic of the program.
+//!
+//! The pending data only lives until the end of the block, it is needed by the program which
+//! delivered it and nothing else. Only the pallet account can deliver the data since it has no
+//! keys, the accounts of the users of the sending chain can't forge the data of the items.
+//!
+//! ### Transfer modes
+//!
+//! * [`TransferMode::Reserve`]: The original item is kept by the sovereign account of the
+//!   destination, which mints a derivative. When a derivative is sent back to its reserve, it is
+//!   burned and the original is released as is, so no data is sent in this case.
+//! * [`TransferMode::Teleport`]: The item is burned on the sending chain and minted on the
+//!   destination. When an original item is teleported back, it is minted again in its collection
+//!   with the data it carries.
+//!
+//! ### Derivative collections
+//!
+//! The items of foreign collections are minted into derivative collections. A derivative
+//! collection is created when the first item of its original collection arrives, it is owned by
+//! the pallet account and registered in the [`Config::Derivatives`] registry, which is usually an
+//! instance of `pallet-derivatives`. Only items accompanied by their data can create a derivative
+//! collection. The collection data carried along with every item keeps the derivative collection
+//! up to date, while the data of original collections is never overwritten.
+//!
+//! ### Received attributes
+//!
+//! The received attributes are stored in the `pallet-nfts` pallet namespace under the keys built by
+//! [`AttributeOwner::received_key`], so they can't be changed on this chain. They are sent along
+//! with the item again and take precedence over the attributes the item had before it left this
+//! chain.
+//!
+//! The prefixed keys must fit into the `KeyLimit` of `pallet-nfts`, an item carrying an attribute
+//! with a longer key can't be minted on arrival.
+//!
+//! ## Runtime setup
+//!
+//! The [`NftsXcmAdapter`] has to be a part of the `AssetTransactor` of the XCM executor, and the
+//! `IsReserve` and `IsTeleporter` filters have to accept the items of the chains the data is
+//! received from. The same filters usually make the [`Config::TrustedDataSources`]. The chains
+//! have to agree on the location of the pallet account, see [`Config::AccountToLocation`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchClass,
+	pallet_prelude::*,
+	traits::{tokens::nonfungibles_v2::Mutate, ContainsPair, Incrementable, PalletInfoAccess},
+	PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_derivatives::DerivativesRegistry;
+use pallet_nfts::{
+	AttributeNamespace, CollectionConfig, CollectionSetting, CollectionSettings, ItemConfig,
+};
+use sp_runtime::traits::{AccountIdConversion, Convert, MaybeEquivalence, TryConvert, Zero};
+use xcm::{
+	latest::{prelude::*, AssetTransferFilter},
+	VersionedAsset, VersionedLocation,
+};
+use xcm_builder::unique_instances::NonFungibleAsset;
+use xcm_executor::traits::{TransferType, WeightBounds};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+mod adapter;
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub use adapter::*;
+pub use types::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::nfts-xcm";
+
+/// The number of assets deposited to the beneficiary: the item and the remaining fees.
+const DEPOSITED_ASSETS: u32 = 2;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_nfts::Config<I> {
+		type WeightInfo: WeightInfo;
+
+		/// The pallet's id, used for deriving the account holding the items in transit and owning
+		/// the derivative collections.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Converts the XCM location of a local collection to its ID and back.
+		type CollectionLocation: MaybeEquivalence<Location, Self::CollectionId>;
+
+		/// Converts an XCM asset instance to an item ID and back.
+		type ItemInstance: MaybeEquivalence<AssetInstance, Self::ItemId>;
+
+		/// The registry of the derivative collections.
+		type Derivatives: DerivativesRegistry<AssetId, Self::CollectionId>;
+
+		/// An origin allowed to transfer items, converted to the location executing the transfer.
+		type ExecuteXcmOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = Location,
+		>;
+
+		/// An origin delivering the item data, converted to its location.
+		///
+		/// The data is only accepted from the pallet account of the sending chain.
+		type DataOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = Location,
+		>;
+
+		/// Converts the pallet account to its location.
+		///
+		/// The pallet account executes the transfer programs, so the destination sees it as the
+		/// origin of the data. The location must be the same on all the chains the items are
+		/// transferred between.
+		type AccountToLocation: for<'a> TryConvert<&'a Self::AccountId, Location>;
+
+		/// The chains trusted to deliver the data of an item.
+		///
+		/// Usually the same as the `IsReserve` and `IsTeleporter` filters of the XCM executor.
+		type TrustedDataSources: ContainsPair<Asset, Location>;
+
+		/// The XCM executor running the transfer programs.
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Means of measuring the weight consumed by the transfer programs.
+		type Weigher: WeightBounds<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// This chain's universal location.
+		type UniversalLocation: Get<InteriorLocation>;
+
+		/// The index of this pallet on the given destination chain.
+		type RemotePalletIndex: Convert<Location, Option<u8>>;
+
+		/// The maximum number of attributes carried along with an item or a collection.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+	}
+
+	/// The data of the items arriving in the current block, by the topic of the delivering XCM
+	/// program.
+	///
+	/// Stored by [`Pallet::receive_data`] and taken when the item is deposited by the program with
+	/// the same topic. Whatever is left is removed at the end of the block.
+	#[pallet::storage]
+	pub type PendingData<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(NonFungibleAsset, XcmHash),
+		TransferDataOf<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// An item was sent to another chain.
+		ItemSent {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			destination: Location,
+			mode: TransferMode,
+		},
+
+		/// The data of an arriving item was received.
+		DataReceived { original: NonFungibleAsset },
+
+		/// An item arrived from another chain and was minted.
+		ItemReceived { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+
+		/// A derivative collection was created for a foreign collection.
+		DerivativeCollectionCreated { original: AssetId, collection: T::CollectionId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The version of a versioned parameter isn't supported.
+		BadVersion,
+
+		/// The item doesn't exist.
+		UnknownItem,
+
+		/// The collection can't be identified over XCM.
+		UnknownCollection,
+
+		/// The item can't be identified over XCM.
+		UnknownInstance,
+
+		/// The item or its collection has more attributes than can be carried.
+		TooManyAttributes,
+
+		/// The fees can't be transferred using the given transfer type.
+		InvalidFeesTransferType,
+
+		/// The item can't be reanchored to the destination.
+		CannotReanchor,
+
+		/// The index of the pallet on the destination isn't known.
+		UnknownDestination,
+
+		/// The transfer program couldn't be weighed.
+		UnweighableMessage,
+
+		/// The local execution of the transfer program failed.
+		LocalExecutionIncomplete,
+
+		/// The origin isn't trusted to deliver the data of the item.
+		UntrustedDataSource,
+
+		/// The pallet account can't be converted to a location.
+		UnknownAccountLocation,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			// The data left here belongs to items that failed to arrive.
+			let removed = PendingData::<T, I>::clear(u32::MAX, None).unique;
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				<T as Config<I>>::WeightInfo::clear_pending_data(removed),
+				DispatchClass::Mandatory,
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Transfer an item to another chain along with its metadata and attributes.
+		///
+		/// The item, the `fees` and the `delivery_fees` are handed over from the origin to the
+		/// pallet account, which executes the transfer. The `fees` pay for the execution on the
+		/// destination, where the item and the remaining fees are deposited to the `beneficiary`.
+		///
+		/// - `collection`, `item`: The item to transfer.
+		/// - `dest`: The destination chain.
+		/// - `beneficiary`: The location receiving the item, relative to `dest`.
+		/// - `mode`: Whether the item is teleported or transferred using the reserve.
+		/// - `fees`: The asset paying for the execution on `dest`.
+		/// - `fees_transfer_type`: How the `fees` are moved to `dest`, a remote reserve isn't
+		///   supported.
+		/// - `delivery_fees`: The asset paying for the delivery of the transfer to `dest`, what is
+		///   left of it is returned to the origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: Box<VersionedLocation>,
+			beneficiary: Box<VersionedLocation>,
+			mode: TransferMode,
+			fees: Box<VersionedAsset>,
+			fees_transfer_type: Box<TransferType>,
+			delivery_fees: Box<VersionedAsset>,
+		) -> DispatchResult {
+			let origin = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let dest: Location = (*dest).try_into().map_err(|()| Error::<T, I>::BadVersion)?;
+			let beneficiary: Location =
+				(*beneficiary).try_into().map_err(|()| Error::<T, I>::BadVersion)?;
+			let fees: Asset = (*fees).try_into().map_err(|()| Error::<T, I>::BadVersion)?;
+			let delivery_fees: Asset =
+				(*delivery_fees).try_into().map_err(|()| Error::<T, I>::BadVersion)?;
+
+			ensure!(
+				pallet_nfts::Item::<T, I>::contains_key(&collection, &item),
+				Error::<T, I>::UnknownItem
+			);
+
+			let maybe_original = T::Derivatives::get_original(&collection).ok();
+			let is_derivative = maybe_original.is_some();
+			let id = match maybe_original {
+				Some(original) => original,
+				None => AssetId(
+					T::CollectionLocation::convert_back(&collection)
+						.ok_or(Error::<T, I>::UnknownCollection)?,
+				),
+			};
+			let instance =
+				T::ItemInstance::convert_back(&item).ok_or(Error::<T, I>::UnknownInstance)?;
+
+			let asset = Asset { id: id.clone(), fun: NonFungible(instance) };
+			let item_filter = Definite(asset.clone().into());
+			let item_transfer = match (mode, is_derivative) {
+				(TransferMode::Teleport, _) => AssetTransferFilter::Teleport(item_filter),
+				(TransferMode::Reserve, false) => AssetTransferFilter::ReserveDeposit(item_filter),
+				(TransferMode::Reserve, true) => AssetTransferFilter::ReserveWithdraw(item_filter),
+			};
+			let fees_filter = Definite(fees.clone().into());
+			let fees_transfer = match *fees_transfer_type {
+				TransferType::Teleport => AssetTransferFilter::Teleport(fees_filter),
+				TransferType::LocalReserve => AssetTransferFilter::ReserveDeposit(fees_filter),
+				TransferType::DestinationReserve =>
+					AssetTransferFilter::ReserveWithdraw(fees_filter),
+				TransferType::RemoteReserve(_) =>
+					return Err(Error::<T, I>::InvalidFeesTransferType.into()),
+			};
+
+			// The topic binds the data to the program delivering the item.
+			let topic = (
+				&asset,
+				&dest,
+				frame_system::Pallet::<T>::block_number(),
+				frame_system::Pallet::<T>::extrinsic_index(),
+			)
+				.using_encoded(sp_io::hashing::blake2_256);
+			let mut remote_xcm = vec![SetTopic(topic)];
+			// A derivative returning to its reserve releases the original, which has its data.
+			if mode == TransferMode::Teleport || !is_derivative {
+				remote_xcm.push(Self::receive_data_instruction(
+					&collection,
+					&item,
+					&id,
+					instance,
+					&dest,
+					topic,
+				)?);
+			}
+			remote_xcm
+				.push(DepositAsset { assets: Wild(AllCounted(DEPOSITED_ASSETS)), beneficiary });
+
+			let mut assets = Assets::new();
+			for asset in [fees, delivery_fees, asset] {
+				assets.push(asset);
+			}
+			let account = Self::account_location()?;
+			Self::execute(
+				origin.clone(),
+				Xcm(vec![TransferAsset { assets: assets.clone(), beneficiary: account.clone() }]),
+			)?;
+			// The delivery fees are taken from the holding, the rest of them is returned.
+			let message = Xcm(vec![
+				WithdrawAsset(assets),
+				InitiateTransfer {
+					destination: dest.clone(),
+					remote_fees: Some(fees_transfer),
+					preserve_origin: true,
+					assets: BoundedVec::truncate_from(vec![item_transfer]),
+					remote_xcm: Xcm(remote_xcm),
+				},
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary: origin },
+			]);
+			Self::execute(account, message)?;
+
+			Self::deposit_event(Event::ItemSent { collection, item, destination: dest, mode });
+			Ok(())
+		}
+
+		/// Store the data of an item arriving from another chain.
+		///
+		/// Dispatched by the XCM program delivering the item, the data is applied when the program
+		/// with the same `topic` deposits the item. The data must come from the pallet account of
+		/// a chain trusted to send the item.
+		///
+		/// - `original`: The item as identified by this chain.
+		/// - `data`: The data of the item and its collection.
+		/// - `topic`: The topic of the program delivering the item.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::receive_data())]
+		pub fn receive_data(
+			origin: OriginFor<T>,
+			original: Box<NonFungibleAsset>,
+			data: Box<TransferDataOf<T, I>>,
+			topic: XcmHash,
+		) -> DispatchResult {
+			let origin = T::DataOrigin::ensure_origin(origin)?;
+			let (id, instance) = *original;
+
+			let source = origin.chain_location();
+			let account = Self::account_location()?;
+			ensure!(
+				source.clone().appended_with(account).map_or(false, |sender| sender == origin),
+				Error::<T, I>::UntrustedDataSource
+			);
+			ensure!(
+				T::TrustedDataSources::contains(
+					&Asset { id: id.clone(), fun: NonFungible(instance) },
+					&source,
+				),
+				Error::<T, I>::UntrustedDataSource
+			);
+
+			PendingData::<T, I>::insert(((id.clone(), instance), topic), *data);
+
+			Self::deposit_event(Event::DataReceived { original: (id, instance) });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account holding the items in transit and owning the derivative collections.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The location of the pallet account.
+	pub fn account_location() -> Result<Location, DispatchError> {
+		T::AccountToLocation::try_convert(&Self::account_id())
+			.map_err(|_| Error::<T, I>::UnknownAccountLocation.into())
+	}
+
+	/// The `Transact` dispatching [`Pallet::receive_data`] with the data of the item on `dest`.
+	fn receive_data_instruction(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		id: &AssetId,
+		instance: AssetInstance,
+		dest: &Location,
+		topic: XcmHash,
+	) -> Result<Instruction<()>, DispatchError> {
+		let pallet_index =
+			T::RemotePalletIndex::convert(dest.clone()).ok_or(Error::<T, I>::UnknownDestination)?;
+		let id = id
+			.clone()
+			.reanchored(dest, &T::UniversalLocation::get())
+			.map_err(|_| Error::<T, I>::CannotReanchor)?;
+
+		let data = TransferData {
+			collection: Self::collection_data(collection)?,
+			item: Self::item_data(collection, item)?,
+		};
+		let call = Call::<T, I>::receive_data {
+			original: Box::new((id, instance)),
+			data: Box::new(data),
+			topic,
+		};
+
+		Ok(Transact {
+			origin_kind: OriginKind::Xcm,
+			fallback_max_weight: None,
+			call: (pallet_index, call).encode().into(),
+		})
+	}
+
+	fn execute(
+		origin: Location,
+		mut message: Xcm<<T as frame_system::Config>::RuntimeCall>,
+	) -> DispatchResult {
+		let weight = T::Weigher::weight(&mut message, Weight::MAX)
+			.map_err(|_| Error::<T, I>::UnweighableMessage)?;
+		let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+		let outcome =
+			T::XcmExecutor::prepare_and_execute(origin, message, &mut hash, weight, weight);
+		outcome.ensure_complete().map_err(|error| {
+			tracing::debug!(target: LOG_TARGET, ?error, "Failed to execute the transfer");
+			Error::<T, I>::LocalExecutionIncomplete
+		})?;
+
+		Ok(())
+	}
+
+	fn collection_data(collection: &T::CollectionId) -> Result<NftDataOf<T, I>, DispatchError> {
+		Ok(NftData {
+			metadata: pallet_nfts::CollectionMetadataOf::<T, I>::get(collection).map(|m| m.data),
+			attributes: Self::attributes(collection, None)?,
+		})
+	}
+
+	fn item_data(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<NftDataOf<T, I>, DispatchError> {
+		Ok(NftData {
+			metadata: pallet_nfts::ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data),
+			attributes: Self::attributes(collection, Some(*item))?,
+		})
+	}
+
+	/// The attributes carried along with the item or the collection.
+	///
+	/// The received attributes come first, the ones the item had before it left this chain are
+	/// only carried if they weren't received again.
+	fn attributes(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> Result<
+		BoundedVec<
+			(AttributeOwner, BoundedVec<u8, T::KeyLimit>, BoundedVec<u8, T::ValueLimit>),
+			T::MaxAttributes,
+		>,
+		DispatchError,
+	> {
+		let mut attributes: BoundedVec<_, T::MaxAttributes> = BoundedVec::new();
+		let received = pallet_nfts::Attribute::<T, I>::iter_prefix((
+			*collection,
+			maybe_item,
+			AttributeNamespace::Pallet,
+		))
+		.filter_map(|(key, (value, _))| {
+			let (owner, key) = AttributeOwner::from_received_key(&key)?;
+			Some((owner, BoundedVec::try_from(key).ok()?, value))
+		});
+		let set_by_owners = [AttributeOwner::CollectionOwner, AttributeOwner::ItemOwner]
+			.into_iter()
+			.flat_map(|owner| {
+				let prefix = (*collection, maybe_item, owner.namespace::<T::AccountId>());
+				pallet_nfts::Attribute::<T, I>::iter_prefix(prefix)
+					.map(move |(key, (value, _))| (owner, key, value))
+			});
+		for (owner, key, value) in received.chain(set_by_owners) {
+			if attributes.iter().any(|(o, k, _)| *o == owner && *k == key) {
+				continue
+			}
+			attributes
+				.try_push((owner, key, value))
+				.map_err(|_| Error::<T, I>::TooManyAttributes)?;
+		}
+		Ok(attributes)
+	}
+
+	/// Remove the attributes received along with the item, they travel with it.
+	fn clear_received_attributes(collection: &T::CollectionId, item: &T::ItemId) -> DispatchResult {
+		let keys: Vec<_> = pallet_nfts::Attribute::<T, I>::iter_key_prefix((
+			*collection,
+			Some(*item),
+			AttributeNamespace::Pallet,
+		))
+		.filter(|key| AttributeOwner::from_received_key(key).is_some())
+		.collect();
+		for key in keys {
+			<pallet_nfts::Pallet<T, I> as Mutate<_, ItemConfig>>::clear_attribute(
+				collection, item, &key,
+			)?;
+		}
+		Ok(())
+	}
+
+	fn apply_data(
+		collection: &T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		data: NftDataOf<T, I>,
+	) -> DispatchResult {
+		if let Some(metadata) = data.metadata {
+			match maybe_item {
+				Some(item) =>
+					<pallet_nfts::Pallet<T, I> as Mutate<_, ItemConfig>>::set_item_metadata(
+						None, collection, &item, &metadata,
+					)?,
+				None =>
+					<pallet_nfts::Pallet<T, I> as Mutate<_, ItemConfig>>::set_collection_metadata(
+						None, collection, &metadata,
+					)?,
+			}
+		}
+		for (owner, key, value) in data.attributes {
+			let key = owner.received_key(&key);
+			match maybe_item {
+				Some(item) => <pallet_nfts::Pallet<T, I> as Mutate<_, ItemConfig>>::set_attribute(
+					collection, &item, &key, &value,
+				)?,
+				None =>
+					<pallet_nfts::Pallet<T, I> as Mutate<_, ItemConfig>>::set_collection_attribute(
+						collection, &key, &value,
+					)?,
+			}
+		}
+		Ok(())
+	}
+
+	/// Create a derivative collection of the `original` collection, owned by the pallet account.
+	fn create_derivative_collection(original: &AssetId) -> Result<T::CollectionId, DispatchError> {
+		let collection = pallet_nfts::NextCollectionId::<T, I>::get()
+			.or(T::CollectionId::initial_value())
+			.ok_or(pallet_nfts::Error::<T, I>::UnknownCollection)?;
+		let owner = Self::account_id();
+		let config = CollectionConfig {
+			settings: CollectionSettings::from_disabled(CollectionSetting::DepositRequired.into()),
+			max_supply: None,
+			mint_settings: Default::default(),
+		};
+
+		pallet_nfts::Pallet::<T, I>::do_create_collection(
+			collection,
+			owner.clone(),
+			owner.clone(),
+			config,
+			Zero::zero(),
+			pallet_nfts::Event::ForceCreated { collection, owner },
+		)?;
+		pallet_nfts::NextCollectionId::<T, I>::set(collection.increment());
+		T::Derivatives::try_register_derivative(original, &collection)?;
+
+		Self::deposit_event(Event::DerivativeCollectionCreated {
+			original: original.clone(),
+			collection,
+		});
+		Ok(collection)
+	}
+
+	/// Mint an item arrived from another chain to the `owner` with the data received along with
+	/// it.
+	///
+	/// The collection data is applied to derivative collections only.
+	fn mint_received(
+		original: &NonFungibleAsset,
+		maybe_topic: Option<XcmHash>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		owner: T::AccountId,
+		is_derivative: bool,
+	) -> DispatchResult {
+		let settings = pallet_nfts::CollectionConfigOf::<T, I>::get(&collection)
+			.map(|config| config.mint_settings.default_item_settings)
+			.unwrap_or_default();
+		pallet_nfts::Pallet::<T, I>::do_mint(
+			collection,
+			item,
+			None,
+			owner.clone(),
+			ItemConfig { settings },
+			|_, _| Ok(()),
+		)?;
+
+		let maybe_data =
+			maybe_topic.and_then(|topic| PendingData::<T, I>::take((original.clone(), topic)));
+		if let Some(data) = maybe_data {
+			if is_derivative {
+				Self::apply_data(&collection, None, data.collection)?;
+			}
+			Self::apply_data(&collection, Some(item), data.item)?;
+		}
+
+		Self::deposit_event(Event::ItemReceived { collection, item, owner });
+		Ok(())
+	}
+}
+
+/// The index of this pallet on the destination is the same as here.
+///
+/// Suitable for chains sharing the runtime, or having the pallet at the same index.
+pub struct SamePalletIndex<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> Convert<Location, Option<u8>> for SamePalletIndex<T, I> {
+	fn convert(_dest: Location) -> Option<u8> {
+		<Pallet<T, I> as PalletInfoAccess>::index().try_into().ok()
+	}
+}
@@ -0,0 +1,241 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for NFTs XCM pallet.
+
+use super::*;
+use crate as pallet_nfts_xcm;
+
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{
+		tokens::asset_ops::common_ops::DisabledOps, AsEnsureOriginWithArg, ConstU32, ConstU64,
+		EnsureOrigin,
+	},
+};
+use frame_system::{EnsureNever, EnsureRoot, EnsureSigned, RawOrigin};
+use pallet_nfts::PalletFeatures;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{TryConvert, TryConvertInto},
+	BuildStorage,
+};
+use xcm_builder::{AsPrefixedGeneralIndex, FixedWeightBounds};
+use xcm_executor::traits::ConvertLocation;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = u64;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Nfts: pallet_nfts,
+		Derivatives: pallet_derivatives,
+		NftsXcm: pallet_nfts_xcm,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+/// Signs the offchain mints with the test signatures of the `u64` accounts.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftsHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nfts::BenchmarkHelper<u32, u32, UintAuthorityId, AccountId, TestSignature>
+	for NftsHelper
+{
+	fn collection(i: u16) -> u32 {
+		i.into()
+	}
+	fn item(i: u16) -> u32 {
+		i.into()
+	}
+	fn signer() -> (UintAuthorityId, AccountId) {
+		(UintAuthorityId(0), 0)
+	}
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = EnsureRoot<Self::AccountId>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type Features = Features;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = NftsHelper;
+	type BlockNumberProvider = System;
+}
+
+impl pallet_derivatives::Config for Test {
+	type WeightInfo = pallet_derivatives::TestWeightInfo;
+
+	type Original = AssetId;
+	type Derivative = u32;
+
+	type DerivativeExtra = ();
+
+	// The derivative collections are created by the `pallet-nfts-xcm` only.
+	type CreateOrigin = EnsureNever<AccountId>;
+	type CreateOp = DisabledOps<Self::Original>;
+
+	type DestroyOrigin = EnsureNever<AccountId>;
+	type DestroyOp = DisabledOps<Self::Original>;
+}
+
+/// The parachain sending the items to this chain.
+pub const SIBLING: u32 = 2000;
+
+parameter_types! {
+	pub const NftsXcmPalletId: PalletId = PalletId(*b"py/nfxcm");
+	pub NftsPalletLocation: Location = PalletInstance(2).into();
+	pub UniversalLocation: InteriorLocation = [Parachain(1000)].into();
+	pub UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub ThisChain: Location = Location::here();
+	pub SiblingChain: Location = Location::new(1, [Parachain(SIBLING)]);
+}
+
+/// Converts the `AccountIndex64` locations to accounts.
+pub struct AccountIndexAsAccountId;
+impl ConvertLocation<AccountId> for AccountIndexAsAccountId {
+	fn convert_location(location: &Location) -> Option<AccountId> {
+		match location.unpack() {
+			(0, [AccountIndex64 { index, .. }]) => Some(*index),
+			_ => None,
+		}
+	}
+}
+
+/// Converts the accounts to their `AccountIndex64` locations.
+pub struct AccountIdAsAccountIndex;
+impl<'a> TryConvert<&'a AccountId, Location> for AccountIdAsAccountIndex {
+	fn try_convert(who: &'a AccountId) -> Result<Location, &'a AccountId> {
+		Ok(account(*who))
+	}
+}
+
+/// Converts a signed origin to its `AccountIndex64` location, interior to `Prefix`.
+pub struct SignedAsAccountIndex<Prefix>(PhantomData<Prefix>);
+impl<Prefix: Get<Location>> EnsureOrigin<RuntimeOrigin> for SignedAsAccountIndex<Prefix> {
+	type Success = Location;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(RawOrigin::Signed(who)) => Prefix::get()
+				.pushed_with_interior(AccountIndex64 { network: None, index: who })
+				.map_err(|_| o),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(0))
+	}
+}
+
+/// Trusts the chains to send the items of their own collections and to teleport the local items.
+pub struct ItemsOfTheSender;
+impl ContainsPair<Asset, Location> for ItemsOfTheSender {
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		asset.id.0.starts_with(origin) || asset.id.0.parent_count() == 0
+	}
+}
+
+impl Config for Test {
+	type WeightInfo = ();
+	type PalletId = NftsXcmPalletId;
+	type CollectionLocation = AsPrefixedGeneralIndex<NftsPalletLocation, u32, TryConvertInto>;
+	type ItemInstance = TryConvertInto;
+	type Derivatives = Derivatives;
+	type ExecuteXcmOrigin = SignedAsAccountIndex<ThisChain>;
+	// Delivers the data as if it was sent by an account of the sibling chain.
+	type DataOrigin = SignedAsAccountIndex<SiblingChain>;
+	type AccountToLocation = AccountIdAsAccountIndex;
+	type TrustedDataSources = ItemsOfTheSender;
+	type XcmExecutor = ();
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, ConstU32<100>>;
+	type UniversalLocation = UniversalLocation;
+	type RemotePalletIndex = SamePalletIndex<Test>;
+	type MaxAttributes = ConstU32<4>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config for Test {
+	fn data_source() -> Result<(RuntimeOrigin, NonFungibleAsset), frame_benchmarking::BenchmarkError>
+	{
+		let id = AssetId(Location::new(1, [Parachain(SIBLING), GeneralIndex(7)]));
+		Ok((RuntimeOrigin::signed(NftsXcm::account_id()), (id, Index(1))))
+	}
+}
+
+pub type Adapter = NftsXcmAdapter<Test, (), AccountIndexAsAccountId>;
+
+pub fn account(index: AccountId) -> Location {
+	AccountIndex64 { network: None, index }.into()
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
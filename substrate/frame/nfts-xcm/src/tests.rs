@@ -0,0 +1,438 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for NFTs XCM pallet.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_nfts::MintSettings;
+use xcm_executor::traits::TransactAsset;
+
+macro_rules! bvec {
+	($( $x:tt )*) => {
+		vec![$( $x )*].try_into().unwrap()
+	}
+}
+
+fn context() -> XcmContext {
+	XcmContext::with_message_id([0; 32])
+}
+
+/// The topic of the program delivering the item.
+const TOPIC: XcmHash = [1; 32];
+
+/// The context of the program delivering the item.
+fn delivery() -> XcmContext {
+	XcmContext { origin: None, message_id: TOPIC, topic: Some(TOPIC) }
+}
+
+/// The origin of the data sent by the pallet account of the sibling chain.
+fn data_origin() -> RuntimeOrigin {
+	RuntimeOrigin::signed(NftsXcm::account_id())
+}
+
+fn local_item(collection: u32, item: u32) -> Asset {
+	(Location::new(0, [PalletInstance(2), GeneralIndex(collection.into())]), Index(item.into()))
+		.into()
+}
+
+fn foreign_item(para: u32, collection: u128, item: u128) -> Asset {
+	(Location::new(1, [Parachain(para), PalletInstance(50), GeneralIndex(collection)]), Index(item))
+		.into()
+}
+
+fn original(asset: &Asset) -> NonFungibleAsset {
+	let NonFungible(instance) = asset.fun else { unreachable!() };
+	(asset.id.clone(), instance)
+}
+
+fn transfer_data() -> TransferDataOf<Test, ()> {
+	TransferData {
+		collection: NftData {
+			metadata: Some(bvec![1, 2, 3]),
+			attributes: bvec![(AttributeOwner::CollectionOwner, bvec![1], bvec![10])],
+		},
+		item: NftData {
+			metadata: Some(bvec![4, 5, 6]),
+			attributes: bvec![
+				(AttributeOwner::CollectionOwner, bvec![2], bvec![20]),
+				(AttributeOwner::ItemOwner, bvec![3], bvec![30]),
+			],
+		},
+	}
+}
+
+fn attribute(
+	collection: u32,
+	maybe_item: Option<u32>,
+	owner: AttributeOwner,
+	key: u8,
+) -> Option<u8> {
+	let key: BoundedVec<u8, _> = bvec![key];
+	pallet_nfts::Attribute::<Test>::get((collection, maybe_item, owner.namespace(), key))
+		.map(|(value, _)| value[0])
+}
+
+fn received_attribute(
+	collection: u32,
+	maybe_item: Option<u32>,
+	owner: AttributeOwner,
+	key: u8,
+) -> Option<u8> {
+	let key: BoundedVec<u8, _> = owner.received_key(&[key]).try_into().unwrap();
+	pallet_nfts::Attribute::<Test>::get((collection, maybe_item, AttributeNamespace::Pallet, key))
+		.map(|(value, _)| value[0])
+}
+
+fn owner_of(collection: u32, item: u32) -> Option<AccountId> {
+	pallet_nfts::Item::<Test>::get(collection, item).map(|details| details.owner)
+}
+
+/// Creates the local collection `0` owned by the account `1` with the item `0` of the account `1`.
+fn create_local_item() {
+	assert_ok!(Nfts::create(
+		RuntimeOrigin::signed(1),
+		1,
+		pallet_nfts::CollectionConfig {
+			settings: CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: MintSettings::default(),
+		},
+	));
+	assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), 0, 0, 1, None));
+	assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(1), 0, 0, bvec![4, 5, 6]));
+	assert_ok!(Nfts::set_attribute(
+		RuntimeOrigin::signed(1),
+		0,
+		Some(0),
+		AttributeNamespace::CollectionOwner,
+		bvec![2],
+		bvec![20],
+	));
+}
+
+#[test]
+fn receive_data_requires_trusted_source() {
+	new_test_ext().execute_with(|| {
+		let trusted = foreign_item(SIBLING, 7, 1);
+		let untrusted = foreign_item(SIBLING + 1, 7, 1);
+
+		assert_noop!(
+			NftsXcm::receive_data(
+				data_origin(),
+				Box::new(original(&untrusted)),
+				Box::new(transfer_data()),
+				TOPIC,
+			),
+			Error::<Test>::UntrustedDataSource
+		);
+		assert_noop!(
+			NftsXcm::receive_data(
+				RuntimeOrigin::root(),
+				Box::new(original(&trusted)),
+				Box::new(transfer_data()),
+				TOPIC,
+			),
+			DispatchError::BadOrigin
+		);
+		// The accounts of the users of the sibling chain can't deliver the data.
+		assert_noop!(
+			NftsXcm::receive_data(
+				RuntimeOrigin::signed(1),
+				Box::new(original(&trusted)),
+				Box::new(transfer_data()),
+				TOPIC,
+			),
+			Error::<Test>::UntrustedDataSource
+		);
+
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&trusted)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+		assert_eq!(PendingData::<Test>::get((original(&trusted), TOPIC)), Some(transfer_data()));
+		System::assert_last_event(
+			Event::<Test>::DataReceived { original: original(&trusted) }.into(),
+		);
+	});
+}
+
+#[test]
+fn pending_data_is_removed_at_the_end_of_the_block() {
+	new_test_ext().execute_with(|| {
+		let item = foreign_item(SIBLING, 7, 1);
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+
+		NftsXcm::on_finalize(1);
+
+		assert_eq!(PendingData::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn local_item_is_kept_by_pallet_account_while_in_reserve_transfer() {
+	new_test_ext().execute_with(|| {
+		create_local_item();
+		let item = local_item(0, 0);
+
+		// Not in transit yet.
+		assert_noop!(
+			Adapter::can_check_out(&Location::new(1, [Parachain(SIBLING)]), &item, &context()),
+			XcmError::NotWithdrawable
+		);
+
+		assert_ok!(Adapter::withdraw_asset(&item, &account(1), None));
+		assert_eq!(owner_of(0, 0), Some(NftsXcm::account_id()));
+		// Nothing to release for another account.
+		assert!(Adapter::withdraw_asset(&item, &account(2), None).is_err());
+
+		// The item returns from the destination.
+		assert_noop!(
+			Adapter::can_check_in(&Location::new(1, [Parachain(SIBLING)]), &item, &context()),
+			XcmError::NotDepositable
+		);
+		assert_ok!(Adapter::deposit_asset(&item, &account(2), Some(&delivery())));
+
+		assert_eq!(owner_of(0, 0), Some(2));
+		assert_eq!(
+			pallet_nfts::ItemMetadataOf::<Test>::get(0, 0).map(|m| m.data.into_inner()),
+			Some(vec![4, 5, 6])
+		);
+		assert_eq!(attribute(0, Some(0), AttributeOwner::CollectionOwner, 2), Some(20));
+	});
+}
+
+#[test]
+fn teleported_local_item_is_burned_and_minted_back_with_data() {
+	new_test_ext().execute_with(|| {
+		create_local_item();
+		let item = local_item(0, 0);
+		let dest = Location::new(1, [Parachain(SIBLING)]);
+
+		assert_ok!(Adapter::withdraw_asset(&item, &account(1), None));
+		assert_ok!(Adapter::can_check_out(&dest, &item, &context()));
+		Adapter::check_out(&dest, &item, &context());
+
+		assert_eq!(owner_of(0, 0), None);
+
+		// The item is teleported back with its data.
+		assert_ok!(Adapter::can_check_in(&dest, &item, &context()));
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+		assert_ok!(Adapter::deposit_asset(&item, &account(2), Some(&delivery())));
+
+		assert_eq!(owner_of(0, 0), Some(2));
+		assert_eq!(
+			pallet_nfts::ItemMetadataOf::<Test>::get(0, 0).map(|m| m.data.into_inner()),
+			Some(vec![4, 5, 6])
+		);
+		assert_eq!(received_attribute(0, Some(0), AttributeOwner::ItemOwner, 3), Some(30));
+		// The data of the original collection isn't overwritten.
+		assert!(pallet_nfts::CollectionMetadataOf::<Test>::get(0).is_none());
+		assert_eq!(received_attribute(0, None, AttributeOwner::CollectionOwner, 1), None);
+		assert_eq!(PendingData::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn foreign_item_without_data_does_not_create_derivative_collection() {
+	new_test_ext().execute_with(|| {
+		let item = foreign_item(SIBLING, 7, 1);
+
+		assert_noop!(Adapter::deposit_asset(&item, &account(2), None), XcmError::AssetNotFound);
+		assert_noop!(
+			Adapter::deposit_asset(&item, &account(2), Some(&delivery())),
+			XcmError::AssetNotFound
+		);
+		assert!(Adapter::withdraw_asset(&item, &account(2), None).is_err());
+
+		// The data delivered by another program isn't applied.
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			[2; 32],
+		));
+		assert_noop!(
+			Adapter::deposit_asset(&item, &account(2), Some(&delivery())),
+			XcmError::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn foreign_item_is_minted_into_derivative_collection() {
+	new_test_ext().execute_with(|| {
+		let item = foreign_item(SIBLING, 7, 1);
+
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+		assert_ok!(Adapter::deposit_asset(&item, &account(2), Some(&delivery())));
+
+		let collection = Derivatives::get_derivative(&item.id).unwrap();
+		System::assert_has_event(
+			Event::<Test>::DerivativeCollectionCreated { original: item.id.clone(), collection }
+				.into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::ItemReceived { collection, item: 1, owner: 2 }.into(),
+		);
+		assert_eq!(
+			pallet_nfts::Collection::<Test>::get(collection).map(|details| details.owner),
+			Some(NftsXcm::account_id())
+		);
+		assert_eq!(owner_of(collection, 1), Some(2));
+		assert_eq!(
+			pallet_nfts::CollectionMetadataOf::<Test>::get(collection).map(|m| m.data.into_inner()),
+			Some(vec![1, 2, 3])
+		);
+		assert_eq!(
+			received_attribute(collection, None, AttributeOwner::CollectionOwner, 1),
+			Some(10)
+		);
+		assert_eq!(
+			pallet_nfts::ItemMetadataOf::<Test>::get(collection, 1).map(|m| m.data.into_inner()),
+			Some(vec![4, 5, 6])
+		);
+		assert_eq!(
+			received_attribute(collection, Some(1), AttributeOwner::CollectionOwner, 2),
+			Some(20)
+		);
+		assert_eq!(received_attribute(collection, Some(1), AttributeOwner::ItemOwner, 3), Some(30));
+		assert_eq!(PendingData::<Test>::iter().count(), 0);
+
+		// The derivative collection is used for the other items of the original collection.
+		let other = foreign_item(SIBLING, 7, 2);
+		assert_ok!(Adapter::deposit_asset(&other, &account(1), None));
+		assert_eq!(owner_of(collection, 2), Some(1));
+		assert_eq!(Derivatives::get_derivative(&other.id), Ok(collection));
+	});
+}
+
+#[test]
+fn derivative_is_burned_on_withdrawal() {
+	new_test_ext().execute_with(|| {
+		let item = foreign_item(SIBLING, 7, 1);
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+		assert_ok!(Adapter::deposit_asset(&item, &account(2), Some(&delivery())));
+		let collection = Derivatives::get_derivative(&item.id).unwrap();
+
+		// Only the owner's item can be withdrawn.
+		assert!(Adapter::withdraw_asset(&item, &account(1), None).is_err());
+		assert_ok!(Adapter::withdraw_asset(&item, &account(2), None));
+		assert_ok!(Adapter::can_check_out(
+			&Location::new(1, [Parachain(SIBLING)]),
+			&item,
+			&context()
+		));
+
+		assert_eq!(owner_of(collection, 1), None);
+		assert!(pallet_nfts::ItemMetadataOf::<Test>::get(collection, 1).is_none());
+		assert_eq!(
+			received_attribute(collection, Some(1), AttributeOwner::CollectionOwner, 2),
+			None
+		);
+		assert_eq!(received_attribute(collection, Some(1), AttributeOwner::ItemOwner, 3), None);
+		// The derivative collection keeps its data.
+		assert_eq!(
+			received_attribute(collection, None, AttributeOwner::CollectionOwner, 1),
+			Some(10)
+		);
+	});
+}
+
+#[test]
+fn derivative_collection_cannot_be_sent_as_local() {
+	new_test_ext().execute_with(|| {
+		let item = foreign_item(SIBLING, 7, 1);
+		assert_ok!(NftsXcm::receive_data(
+			data_origin(),
+			Box::new(original(&item)),
+			Box::new(transfer_data()),
+			TOPIC,
+		));
+		assert_ok!(Adapter::deposit_asset(&item, &account(2), Some(&delivery())));
+		let collection = Derivatives::get_derivative(&item.id).unwrap();
+
+		assert_noop!(
+			Adapter::withdraw_asset(&local_item(collection, 1), &account(2), None),
+			XcmError::AssetNotFound
+		);
+		assert_eq!(owner_of(collection, 1), Some(2));
+	});
+}
+
+#[test]
+fn transfer_checks_its_parameters() {
+	new_test_ext().execute_with(|| {
+		let dest: VersionedLocation = Location::new(1, [Parachain(SIBLING)]).into();
+		let beneficiary: VersionedLocation = account(2).into();
+		let fees: VersionedAsset = (Location::parent(), 100u128).into();
+
+		assert_noop!(
+			NftsXcm::transfer(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(dest.clone()),
+				Box::new(beneficiary.clone()),
+				TransferMode::Reserve,
+				Box::new(fees.clone()),
+				Box::new(TransferType::DestinationReserve),
+				Box::new(fees.clone()),
+			),
+			Error::<Test>::UnknownItem
+		);
+
+		create_local_item();
+
+		assert_noop!(
+			NftsXcm::transfer(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(dest),
+				Box::new(beneficiary),
+				TransferMode::Reserve,
+				Box::new(fees.clone()),
+				Box::new(TransferType::RemoteReserve(Location::parent().into())),
+				Box::new(fees),
+			),
+			Error::<Test>::InvalidFeesTransferType
+		);
+	});
+}
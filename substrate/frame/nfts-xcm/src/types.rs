@@ -0,0 +1,173 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the NFTs XCM pallet.
+
+use super::*;
+use codec::{Decode, DecodeAll, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::Get, BoundedVec, CloneNoBound, DebugNoBound, EqNoBound, PartialEqNoBound,
+};
+use pallet_nfts::AttributeNamespace;
+use scale_info::TypeInfo;
+
+/// How the item is moved to the destination chain.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum TransferMode {
+	/// The item is burned here and minted on the destination.
+	Teleport,
+	/// The original item is kept by the sovereign account of the destination, which mints a
+	/// derivative. A derivative is burned here and the original is released on the destination,
+	/// which must be the reserve of the item.
+	Reserve,
+}
+
+/// The namespace of an attribute carried along with an item.
+///
+/// Only the attributes set by the owners are carried: the attributes set by the pallet reflect the
+/// local state of the item and the ones set by the pre-approved accounts refer to local accounts.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum AttributeOwner {
+	/// An attribute set by the collection's owner.
+	CollectionOwner,
+	/// An attribute set by the item's owner.
+	ItemOwner,
+}
+
+/// The prefix of the keys of the attributes received along with the items.
+pub const RECEIVED_ATTRIBUTE_PREFIX: [u8; 8] = *b"nfts-xcm";
+
+impl AttributeOwner {
+	/// The `pallet-nfts` attribute namespace of the attributes set by this owner.
+	pub fn namespace<AccountId>(&self) -> AttributeNamespace<AccountId> {
+		match self {
+			Self::CollectionOwner => AttributeNamespace::CollectionOwner,
+			Self::ItemOwner => AttributeNamespace::ItemOwner,
+		}
+	}
+
+	/// The key of an attribute of this owner received along with an item.
+	///
+	/// The received attributes are stored in the `pallet-nfts` pallet namespace, the prefix keeps
+	/// them apart from the attributes set by `pallet-nfts` itself.
+	pub fn received_key(&self, key: &[u8]) -> Vec<u8> {
+		(RECEIVED_ATTRIBUTE_PREFIX, self, key).encode()
+	}
+
+	/// The owner and the key of a received attribute stored under `received_key`.
+	pub fn from_received_key(received_key: &[u8]) -> Option<(Self, Vec<u8>)> {
+		let (prefix, owner, key) =
+			<([u8; 8], Self, Vec<u8>)>::decode_all(&mut &received_key[..]).ok()?;
+		(prefix == RECEIVED_ATTRIBUTE_PREFIX).then_some((owner, key))
+	}
+}
+
+/// The metadata and the attributes of an item or a collection.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	DebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(StringLimit, KeyLimit, ValueLimit, MaxAttributes))]
+#[codec(mel_bound())]
+pub struct NftData<StringLimit, KeyLimit, ValueLimit, MaxAttributes>
+where
+	StringLimit: Get<u32>,
+	KeyLimit: Get<u32>,
+	ValueLimit: Get<u32>,
+	MaxAttributes: Get<u32>,
+{
+	/// The metadata, if set.
+	pub metadata: Option<BoundedVec<u8, StringLimit>>,
+	/// The attributes set by the owners.
+	pub attributes: BoundedVec<
+		(AttributeOwner, BoundedVec<u8, KeyLimit>, BoundedVec<u8, ValueLimit>),
+		MaxAttributes,
+	>,
+}
+
+/// The data carried along with a transferred item.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	DebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(StringLimit, KeyLimit, ValueLimit, MaxAttributes))]
+#[codec(mel_bound())]
+pub struct TransferData<StringLimit, KeyLimit, ValueLimit, MaxAttributes>
+where
+	StringLimit: Get<u32>,
+	KeyLimit: Get<u32>,
+	ValueLimit: Get<u32>,
+	MaxAttributes: Get<u32>,
+{
+	/// The data of the item's collection.
+	///
+	/// Applied to the derivative collection only, the data of original collections is never
+	/// overwritten.
+	pub collection: NftData<StringLimit, KeyLimit, ValueLimit, MaxAttributes>,
+	/// The data of the item.
+	pub item: NftData<StringLimit, KeyLimit, ValueLimit, MaxAttributes>,
+}
+
+pub type NftDataOf<T, I> = NftData<
+	<T as pallet_nfts::Config<I>>::StringLimit,
+	<T as pallet_nfts::Config<I>>::KeyLimit,
+	<T as pallet_nfts::Config<I>>::ValueLimit,
+	<T as Config<I>>::MaxAttributes,
+>;
+
+pub type TransferDataOf<T, I> = TransferData<
+	<T as pallet_nfts::Config<I>>::StringLimit,
+	<T as pallet_nfts::Config<I>>::KeyLimit,
+	<T as pallet_nfts::Config<I>>::ValueLimit,
+	<T as Config<I>>::MaxAttributes,
+>;
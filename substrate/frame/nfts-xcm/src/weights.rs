@@ -0,0 +1,88 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_nfts_xcm`.
+//!
+//! The weights are to be regenerated by running the benchmarks of the pallet, until then they are
+//! bounded by the storage accesses of the calls.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_nfts_xcm`.
+pub trait WeightInfo {
+	fn transfer() -> Weight;
+	fn receive_data() -> Weight;
+	fn clear_pending_data(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_nfts_xcm` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Not benchmarked yet, bounded by the two local XCM programs moving the item and the fees,
+	/// the reads of the item data and the delivery of the transfer.
+	fn transfer() -> Weight {
+		Weight::from_parts(500_000_000, 40_000)
+			.saturating_add(T::DbWeight::get().reads(40_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Not benchmarked yet, bounded by the insertion of the pending data.
+	fn receive_data() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, bounded by the removal of `n` pending data entries.
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_pending_data(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1_500)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Not benchmarked yet, bounded by the two local XCM programs moving the item and the fees,
+	/// the reads of the item data and the delivery of the transfer.
+	fn transfer() -> Weight {
+		Weight::from_parts(500_000_000, 40_000)
+			.saturating_add(RocksDbWeight::get().reads(40_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Not benchmarked yet, bounded by the insertion of the pending data.
+	fn receive_data() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, bounded by the removal of `n` pending data entries.
+	/// The range of component `n` is `[0, 100]`.
+	fn clear_pending_data(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1_500)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
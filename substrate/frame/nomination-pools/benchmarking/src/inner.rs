@@ -1173,5 +1173,35 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn transfer_points() {
+		let deposit_amount = Pools::<T>::depositor_min_bond() * 2u32.into();
+		let (_depositor, pool_account) = create_pool_account::<T>(0, deposit_amount, None);
+
+		// a member with enough stake to split with a new member.
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let join_amount = min_join_bond * 2u32.into();
+		let sender = create_funded_user_with_balance::<T>("sender", 0, join_amount * 2u32.into());
+		assert_ok!(Pools::<T>::join(RuntimeOrigin::Signed(sender.clone()).into(), join_amount, 1));
+
+		// worst case: the sender has pending rewards and the destination is a new member.
+		let reward_account = Pools::<T>::generate_reward_account(1);
+		assert_ok!(CurrencyOf::<T>::mint_into(&reward_account, min_join_bond));
+		let receiver: T::AccountId = account("receiver", 0, USER_SEED);
+		let receiver_lookup = T::Lookup::unlookup(receiver.clone());
+		let points = PoolMembers::<T>::get(&sender).unwrap().points / 2u32.into();
+
+		whitelist_account!(sender);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(sender.clone()), receiver_lookup, points);
+
+		assert_eq!(PoolMembers::<T>::get(&receiver).unwrap().points, points);
+		assert_eq!(
+			T::StakeAdapter::active_stake(Pool::from(pool_account)),
+			deposit_amount + join_amount
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
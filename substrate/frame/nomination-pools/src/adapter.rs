@@ -184,6 +184,14 @@ pub trait StakeStrategy {
 		num_slashing_spans: u32,
 	) -> DispatchResult;
 
+	/// Transfer `amount` of the stake of member `from` to member `to` of the same pool.
+	fn member_transfer(
+		pool_account: Pool<Self::AccountId>,
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
 	/// Dissolve the pool account.
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult;
 
@@ -312,6 +320,16 @@ impl<T: Config, Staking: StakingInterface<Balance = BalanceOf<T>, AccountId = T:
 		Ok(())
 	}
 
+	fn member_transfer(
+		_pool_account: Pool<Self::AccountId>,
+		_from: Member<Self::AccountId>,
+		_to: Member<Self::AccountId>,
+		_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// funds are held in the pool account, only the points of the members change.
+		Ok(())
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		defensive_assert!(
 			T::Currency::total_balance(&pool_account.clone().get()).is_zero(),
@@ -430,6 +448,15 @@ impl<
 		Delegation::withdraw_delegation(who.into(), pool_account.into(), amount, num_slashing_spans)
 	}
 
+	fn member_transfer(
+		_pool_account: Pool<Self::AccountId>,
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Delegation::transfer_delegation(from.into(), to.into(), amount)
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		Delegation::remove_agent(pool_account.into())
 	}
@@ -341,6 +341,18 @@
 //! in addition to the unbonding pools. For maintenance simplicity these are not implemented.
 //! Related: <https://github.com/paritytech/substrate/issues/10860>
 //!
+//! ### Transferring points
+//!
+//! A member can transfer some or all of their active points to another account in the same pool
+//! with [`Call::transfer_points`], without unbonding. Pending rewards of both accounts are paid out
+//! first, so accumulated rewards never move along with the points. Neither account may have a
+//! pending slash (see [`Pallet::api_member_pending_slash`]) and the depositor cannot transfer
+//! points.
+//!
+//! [`points::PoolPoints`] exposes the active points of each pool as a
+//! [`frame_support::traits::fungibles`] asset, which allows other pallets to inspect and
+//! account for staked positions.
+//!
 //! ### Limitations
 //!
 //! * PoolMembers cannot vote with their staked funds because they are transferred into the pools
@@ -402,6 +414,7 @@ mod tests;
 
 pub mod adapter;
 pub mod migration;
+pub mod points;
 pub mod weights;
 
 pub use pallet::*;
@@ -1976,6 +1989,15 @@ pub mod pallet {
 			max_members_per_pool: Option<u32>,
 			global_max_commission: Option<Perbill>,
 		},
+		/// A member has transferred `points` of their active stake, worth `balance`, to another
+		/// account in the same pool.
+		PointsTransferred {
+			pool_id: PoolId,
+			from: T::AccountId,
+			to: T::AccountId,
+			points: BalanceOf<T>,
+			balance: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -2066,6 +2088,12 @@ pub mod pallet {
 		/// Account is restricted from participation in pools. This may happen if the account is
 		/// staking in another way already.
 		Restricted,
+		/// The member has a pending slash that must be applied first, see [`Call::apply_slash`].
+		PendingSlash,
+		/// The member does not have enough active points.
+		InsufficientPoints,
+		/// Points cannot be transferred to the same account.
+		CannotTransferToSelf,
 	}
 
 	#[derive(Encode, Decode, DecodeWithMemTracking, PartialEq, TypeInfo, PalletError, Debug)]
@@ -3244,6 +3272,30 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Transfer `points` of the active stake of the caller to `dest` in the same pool.
+		///
+		/// Pending rewards of both the caller and `dest` are paid out before the transfer. If
+		/// `dest` is not a member yet, they join the pool, which must be open.
+		///
+		/// # Note
+		///
+		/// * The depositor cannot transfer points.
+		/// * Neither account can have a pending slash, see [`Call::apply_slash`].
+		/// * Both accounts must keep at least `MinJoinBond` of active stake, unless the caller
+		///   transfers all of their active points.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::transfer_points())]
+		pub fn transfer_points(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] points: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			Self::do_transfer_points(who, dest, points)
+		}
 	}
 
 	#[pallet::hooks]
@@ -3738,6 +3790,150 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer `points` of the active stake of `from` to `to`, who must either be a member of the
+	/// same pool or not be a member at all.
+	///
+	/// See [`Call::transfer_points`] for the conditions of a transfer.
+	pub fn do_transfer_points(
+		from: T::AccountId,
+		to: T::AccountId,
+		points: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(from != to, Error::<T>::CannotTransferToSelf);
+		ensure!(!points.is_zero(), Error::<T>::InsufficientPoints);
+		// ensure account is not restricted from joining the pool.
+		ensure!(!T::Filter::contains(&to), Error::<T>::Restricted);
+		// ensure neither account is in an un-migrated state.
+		ensure!(
+			!Self::api_member_needs_delegate_migration(from.clone()) &&
+				!Self::api_member_needs_delegate_migration(to.clone()),
+			Error::<T>::NotMigrated
+		);
+		// slashes must be applied before the stake changes hands.
+		ensure!(
+			Self::api_member_pending_slash(from.clone()).is_zero() &&
+				Self::api_member_pending_slash(to.clone()).is_zero(),
+			Error::<T>::PendingSlash
+		);
+
+		let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&from)?;
+		ensure!(from != bonded_pool.roles.depositor, Error::<T>::DoesNotHavePermission);
+		ensure!(!Self::api_pool_needs_delegate_migration(member.pool_id), Error::<T>::NotMigrated);
+		ensure!(member.active_points() >= points, Error::<T>::InsufficientPoints);
+
+		let maybe_dest_member = PoolMembers::<T>::get(&to);
+		let is_new_member = maybe_dest_member.is_none();
+		let mut dest_member = match maybe_dest_member {
+			Some(dest_member) => {
+				ensure!(
+					dest_member.pool_id == member.pool_id,
+					Error::<T>::AccountBelongsToOtherPool
+				);
+				dest_member
+			},
+			None => {
+				bonded_pool.ok_to_join()?;
+				bonded_pool.try_inc_members()?;
+				PoolMember::<T> {
+					pool_id: member.pool_id,
+					points: Zero::zero(),
+					last_recorded_reward_counter: reward_pool.last_recorded_reward_counter(),
+					unbonding_eras: Default::default(),
+				}
+			},
+		};
+
+		// both accounts must comply with the minimum bond, unless the sender fully exits.
+		let remaining_points = member.active_points().saturating_sub(points);
+		let dest_points = dest_member
+			.active_points()
+			.checked_add(&points)
+			.ok_or(Error::<T>::OverflowRisk)?;
+		let min_join_bond = MinJoinBond::<T>::get();
+		ensure!(
+			remaining_points.is_zero() ||
+				bonded_pool.points_to_balance(remaining_points) >= min_join_bond,
+			Error::<T>::MinimumBondNotMet
+		);
+		ensure!(
+			bonded_pool.points_to_balance(dest_points) >= min_join_bond,
+			Error::<T>::MinimumBondNotMet
+		);
+
+		// settle the pending rewards of both accounts, they never move along with the points.
+		Self::do_reward_payout(&from, &mut member, &mut bonded_pool, &mut reward_pool)?;
+		if dest_member.active_points().is_zero() {
+			// nothing to pay out, but the member must not be able to claim rewards accumulated
+			// before receiving the points.
+			let (current_reward_counter, _) = reward_pool.current_reward_counter(
+				bonded_pool.id,
+				bonded_pool.points,
+				bonded_pool.commission.current(),
+			)?;
+			dest_member.last_recorded_reward_counter = current_reward_counter;
+		} else {
+			Self::do_reward_payout(&to, &mut dest_member, &mut bonded_pool, &mut reward_pool)?;
+		}
+
+		let is_full_exit = remaining_points.is_zero() && member.unbonding_eras.is_empty();
+		let balance = bonded_pool.points_to_balance(points);
+		// a member leaving the pool hands over their whole delegation, rounding dust included.
+		let transfer_balance = if is_full_exit {
+			T::StakeAdapter::member_delegation_balance(Member::from(from.clone()))
+				.unwrap_or(balance)
+		} else {
+			balance
+		};
+		T::StakeAdapter::member_transfer(
+			Pool::from(bonded_pool.bonded_account()),
+			Member::from(from.clone()),
+			Member::from(to.clone()),
+			transfer_balance,
+		)?;
+
+		member.points = remaining_points;
+		dest_member.points = dest_points;
+		let pool_id = member.pool_id;
+
+		Self::deposit_event(Event::<T>::PointsTransferred {
+			pool_id,
+			from: from.clone(),
+			to: to.clone(),
+			points,
+			balance,
+		});
+
+		if is_new_member {
+			Self::deposit_event(Event::<T>::Bonded {
+				member: to.clone(),
+				pool_id,
+				bonded: balance,
+				joined: true,
+			});
+		}
+
+		if is_full_exit {
+			// remove any `ClaimPermission` associated with the member.
+			ClaimPermissions::<T>::remove(&from);
+			PoolMembers::<T>::remove(&from);
+			bonded_pool = bonded_pool.dec_members();
+
+			Self::deposit_event(Event::<T>::MemberRemoved {
+				pool_id,
+				member: from,
+				released_balance: Zero::zero(),
+			});
+		} else {
+			PoolMembers::<T>::insert(&from, member);
+		}
+
+		PoolMembers::<T>::insert(&to, dest_member);
+		bonded_pool.put();
+		RewardPools::<T>::insert(pool_id, reward_pool);
+
+		Ok(())
+	}
+
 	/// Slash member against the pending slash for the pool.
 	fn do_apply_slash(
 		member_account: &T::AccountId,
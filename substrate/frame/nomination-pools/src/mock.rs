@@ -362,6 +362,27 @@ impl DelegationInterface for DelegateMock {
 
 		Ok(())
 	}
+
+	fn transfer_delegation(
+		source: Delegator<Self::AccountId>,
+		destination: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		let mut delegators = DelegatorBalanceMap::get();
+		let source_balance = delegators
+			.get_mut(&source.get())
+			.ok_or(DispatchError::Other("not a delegator"))?;
+		*source_balance = source_balance
+			.checked_sub(amount)
+			.ok_or(DispatchError::Other("not enough funds"))?;
+		delegators
+			.entry(destination.get())
+			.and_modify(|b| *b += amount)
+			.or_insert(amount);
+		DelegatorBalanceMap::set(&delegators);
+
+		Ok(())
+	}
 }
 
 impl DelegateMock {
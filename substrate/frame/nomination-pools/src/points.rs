@@ -0,0 +1,161 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Representation of pool points as a [`fungibles`] asset.
+
+use crate::*;
+use frame_support::traits::{
+	fungibles,
+	tokens::{DepositConsequence, Provenance, WithdrawConsequence},
+};
+use sp_runtime::TokenError;
+
+/// Exposes the active points of pool members as one [`fungibles`] asset per pool.
+///
+/// The asset id is the [`PoolId`] and balances are denominated in points, which can be converted
+/// with [`Pallet::api_points_to_balance`]. Points are moved with [`fungibles::Mutate::transfer`],
+/// which goes through [`Pallet::do_transfer_points`] and settles pending rewards of both accounts.
+/// Points are only ever issued by bonding into a pool, so they cannot be minted or burned.
+///
+/// The points of the depositor and of members with a pending slash are not reducible.
+pub struct PoolPoints<T>(PhantomData<T>);
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for PoolPoints<T> {
+	type AssetId = PoolId;
+	type Balance = BalanceOf<T>;
+
+	fn total_issuance(pool_id: PoolId) -> BalanceOf<T> {
+		BondedPools::<T>::get(pool_id).map(|pool| pool.points).unwrap_or_default()
+	}
+
+	fn minimum_balance(_pool_id: PoolId) -> BalanceOf<T> {
+		Zero::zero()
+	}
+
+	fn total_balance(pool_id: PoolId, who: &T::AccountId) -> BalanceOf<T> {
+		Self::balance(pool_id, who)
+	}
+
+	fn balance(pool_id: PoolId, who: &T::AccountId) -> BalanceOf<T> {
+		PoolMembers::<T>::get(who)
+			.filter(|member| member.pool_id == pool_id)
+			.map(|member| member.active_points())
+			.unwrap_or_default()
+	}
+
+	fn reducible_balance(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		_preservation: Preservation,
+		_force: Fortitude,
+	) -> BalanceOf<T> {
+		let is_depositor =
+			BondedPools::<T>::get(pool_id).map_or(false, |pool| pool.roles.depositor == *who);
+		if is_depositor || !Pallet::<T>::api_member_pending_slash(who.clone()).is_zero() {
+			return Zero::zero()
+		}
+
+		Self::balance(pool_id, who)
+	}
+
+	fn can_deposit(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		_amount: BalanceOf<T>,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if !Self::asset_exists(pool_id) {
+			return DepositConsequence::UnknownAsset
+		}
+		// points are only ever issued by bonding into the pool.
+		if provenance == Provenance::Minted {
+			return DepositConsequence::Blocked
+		}
+
+		match PoolMembers::<T>::get(who) {
+			Some(member) if member.pool_id != pool_id => DepositConsequence::Blocked,
+			_ if T::Filter::contains(who) => DepositConsequence::Blocked,
+			_ => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		pool_id: PoolId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> WithdrawConsequence<BalanceOf<T>> {
+		if !Self::asset_exists(pool_id) {
+			return WithdrawConsequence::UnknownAsset
+		}
+
+		let reducible =
+			Self::reducible_balance(pool_id, who, Preservation::Expendable, Fortitude::Polite);
+		if amount > reducible {
+			return if amount > Self::balance(pool_id, who) {
+				WithdrawConsequence::BalanceLow
+			} else {
+				WithdrawConsequence::Frozen
+			}
+		}
+
+		WithdrawConsequence::Success
+	}
+
+	fn asset_exists(pool_id: PoolId) -> bool {
+		BondedPools::<T>::contains_key(pool_id)
+	}
+}
+
+impl<T: Config> fungibles::Unbalanced<T::AccountId> for PoolPoints<T> {
+	fn handle_dust(_dust: fungibles::Dust<T::AccountId, Self>) {
+		// the minimum balance is zero, so there is never any dust.
+		defensive!("pool points have no dust");
+	}
+
+	fn write_balance(
+		_pool_id: PoolId,
+		_who: &T::AccountId,
+		_amount: BalanceOf<T>,
+	) -> Result<Option<BalanceOf<T>>, DispatchError> {
+		Err(TokenError::Unsupported.into())
+	}
+
+	fn set_total_issuance(_pool_id: PoolId, _amount: BalanceOf<T>) {
+		// the issuance only changes by bonding into and unbonding from the pool.
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for PoolPoints<T> {
+	fn transfer(
+		pool_id: PoolId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T>,
+		preservation: Preservation,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		<Self as fungibles::Inspect<_>>::can_withdraw(pool_id, source, amount)
+			.into_result(preservation != Preservation::Expendable)?;
+		<Self as fungibles::Inspect<_>>::can_deposit(pool_id, dest, amount, Provenance::Extant)
+			.into_result()?;
+		if source == dest {
+			return Ok(amount)
+		}
+
+		Pallet::<T>::do_transfer_points(source.clone(), dest.clone(), amount)?;
+		Ok(amount)
+	}
+}
@@ -7741,3 +7741,243 @@ mod filter {
 		});
 	}
 }
+
+mod transfer_points {
+	use super::*;
+
+	#[test]
+	fn transfer_points_to_new_member_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// Given
+			deposit_rewards(30);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::MetadataUpdated { pool_id: 1, caller: 900 },
+					Event::Bonded { member: 20, pool_id: 1, bonded: 20, joined: true },
+				]
+			);
+			assert!(!PoolMembers::<Runtime>::contains_key(21));
+
+			// When
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10));
+
+			// Then
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::PaidOut { member: 20, pool_id: 1, payout: 20 },
+					Event::PointsTransferred {
+						pool_id: 1,
+						from: 20,
+						to: 21,
+						points: 10,
+						balance: 10
+					},
+					Event::Bonded { member: 21, pool_id: 1, bonded: 10, joined: true },
+				]
+			);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 10);
+			assert_eq!(PoolMembers::<Runtime>::get(21).unwrap().points, 10);
+			assert_eq!(member_delegation(20), 10);
+			assert_eq!(member_delegation(21), 10);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 3);
+			assert_eq!(TotalValueLocked::<T>::get(), 30);
+
+			// rewards accumulated before the transfer do not move along with the points.
+			assert_eq!(Pools::api_pending_rewards(21), Some(0));
+
+			// but new rewards are shared according to the new points.
+			deposit_rewards(30);
+			assert_eq!(Pools::api_pending_rewards(20), Some(10));
+			assert_eq!(Pools::api_pending_rewards(21), Some(10));
+		});
+	}
+
+	#[test]
+	fn transfer_all_points_removes_member() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (21, 10)])
+			.build_and_execute(|| {
+				// Given
+				let _ = pool_events_since_last_call();
+
+				// When
+				assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 20));
+
+				// Then
+				assert_eq!(
+					pool_events_since_last_call(),
+					vec![
+						Event::PointsTransferred {
+							pool_id: 1,
+							from: 20,
+							to: 21,
+							points: 20,
+							balance: 20
+						},
+						Event::MemberRemoved { pool_id: 1, member: 20, released_balance: 0 },
+					]
+				);
+				assert!(!PoolMembers::<Runtime>::contains_key(20));
+				assert_eq!(PoolMembers::<Runtime>::get(21).unwrap().points, 30);
+				assert_eq!(member_delegation(21), 30);
+				assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 2);
+			});
+	}
+
+	#[test]
+	fn transfer_points_errors_correctly() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 20, 10),
+				Error::<Runtime>::CannotTransferToSelf
+			);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 0),
+				Error::<Runtime>::InsufficientPoints
+			);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 21),
+				Error::<Runtime>::InsufficientPoints
+			);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(30), 21, 10),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+
+			// the depositor cannot transfer their points.
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(10), 21, 5),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+
+			// both accounts must keep the minimum join bond.
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 19),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 1),
+				Error::<Runtime>::MinimumBondNotMet
+			);
+
+			// restricted accounts cannot receive points.
+			add_to_restrict_list(&21);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10),
+				Error::<Runtime>::Restricted
+			);
+			remove_from_restrict_list(&21);
+
+			// new members can only receive points if the pool is open.
+			unsafe_set_state(1, PoolState::Blocked);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10),
+				Error::<Runtime>::NotOpen
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_points_to_other_pool_member_fails() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// Given
+			Currency::set_balance(&30, 100);
+			assert_ok!(Pools::create(RuntimeOrigin::signed(30), 10, 30, 30, 30));
+			assert_eq!(PoolMembers::<Runtime>::get(30).unwrap().pool_id, 2);
+
+			// Then
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 30, 10),
+				Error::<Runtime>::AccountBelongsToOtherPool
+			);
+		});
+	}
+
+	#[test]
+	fn pool_points_are_transferable_as_fungibles() {
+		use crate::points::PoolPoints;
+		use frame_support::traits::{
+			fungibles::{Inspect, Mutate},
+			tokens::{Fortitude, Precision, Preservation},
+		};
+		use sp_runtime::TokenError;
+
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// Given
+			assert_eq!(PoolPoints::<Runtime>::total_issuance(1), 30);
+			assert_eq!(PoolPoints::<Runtime>::balance(1, &20), 20);
+			// the depositor cannot move their points.
+			assert_eq!(
+				PoolPoints::<Runtime>::reducible_balance(
+					1,
+					&10,
+					Preservation::Expendable,
+					Fortitude::Polite
+				),
+				0
+			);
+
+			// When
+			assert_ok!(PoolPoints::<Runtime>::transfer(1, &20, &21, 10, Preservation::Expendable));
+
+			// Then
+			assert_eq!(PoolPoints::<Runtime>::balance(1, &20), 10);
+			assert_eq!(PoolPoints::<Runtime>::balance(1, &21), 10);
+			assert_eq!(PoolPoints::<Runtime>::total_issuance(1), 30);
+			assert_eq!(member_delegation(21), 10);
+
+			// points of unknown pools cannot be moved.
+			assert_noop!(
+				PoolPoints::<Runtime>::transfer(2, &20, &21, 5, Preservation::Expendable),
+				TokenError::UnknownAsset
+			);
+			assert_noop!(
+				PoolPoints::<Runtime>::transfer(1, &10, &21, 5, Preservation::Expendable),
+				TokenError::Frozen
+			);
+			// and points can neither be minted nor burned.
+			assert_noop!(PoolPoints::<Runtime>::mint_into(1, &20, 5), TokenError::Unsupported);
+			assert_noop!(
+				PoolPoints::<Runtime>::burn_from(
+					1,
+					&20,
+					5,
+					Preservation::Expendable,
+					Precision::Exact,
+					Fortitude::Polite
+				),
+				TokenError::Unsupported
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_points_with_pending_slash_fails() {
+		ExtBuilder::default()
+			.with_check(0)
+			.add_members(vec![(20, 20)])
+			.build_and_execute(|| {
+				// Given
+				StakingMock::slash_by(1, 15);
+				assert_eq!(Pools::api_member_pending_slash(20), 10);
+
+				// Then
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10),
+					Error::<Runtime>::PendingSlash
+				);
+
+				// When the slash is applied
+				assert_ok!(Pools::apply_slash(RuntimeOrigin::signed(10), 20));
+
+				// Then the points can be transferred
+				assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10));
+				assert_eq!(member_delegation(20), 5);
+				assert_eq!(member_delegation(21), 5);
+			});
+	}
+}
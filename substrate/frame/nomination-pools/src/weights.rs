@@ -75,6 +75,7 @@ pub trait WeightInfo {
 	fn apply_slash_fail() -> Weight;
 	fn pool_migrate() -> Weight;
 	fn migrate_delegation() -> Weight;
+	fn transfer_points() -> Weight;
 }
 
 /// Weights for `pallet_nomination_pools` using the Substrate node and recommended hardware.
@@ -729,6 +730,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(37_038_000, 27847)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 	}
+	/// Not benchmarked yet, bounded by the reward payout of both accounts and the transfer of
+	/// the delegation between them.
+	fn transfer_points() -> Weight {
+		<Self as WeightInfo>::claim_payout()
+			.saturating_mul(2)
+			.saturating_add(<Self as WeightInfo>::migrate_delegation())
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1382,4 +1390,11 @@ impl WeightInfo for () {
 		Weight::from_parts(37_038_000, 27847)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
+	/// Not benchmarked yet, bounded by the reward payout of both accounts and the transfer of
+	/// the delegation between them.
+	fn transfer_points() -> Weight {
+		<Self as WeightInfo>::claim_payout()
+			.saturating_mul(2)
+			.saturating_add(<Self as WeightInfo>::migrate_delegation())
+	}
 }
\ No newline at end of file
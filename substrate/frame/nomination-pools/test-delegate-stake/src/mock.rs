@@ -208,6 +208,18 @@ impl pallet_nomination_pools::adapter::StakeStrategy for MockAdapter {
 		DelegateStake::member_withdraw(who, pool_account, amount, num_slashing_spans)
 	}
 
+	fn member_transfer(
+		pool_account: Pool<Self::AccountId>,
+		from: Member<Self::AccountId>,
+		to: Member<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if LegacyAdapter::get() {
+			return TransferStake::member_transfer(pool_account, from, to, amount)
+		}
+		DelegateStake::member_transfer(pool_account, from, to, amount)
+	}
+
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		if LegacyAdapter::get() {
 			return TransferStake::dissolve(pool_account)
@@ -190,6 +190,7 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		rate_limit_whitelisted_ips: rpc_params.rpc_rate_limit_whitelisted_ips,
 		rate_limit_trust_proxy_headers: rpc_params.rpc_rate_limit_trust_proxy_headers,
 		request_logger_limit: if is_dev { 1024 * 1024 } else { 1024 },
+		rate_limit_overrides: rpc_params.rpc_rate_limit_overrides()?,
 	};
 
 	let prometheus_config =
@@ -29,9 +29,9 @@ use jsonrpsee::{
 	},
 };
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_core::Bytes;
+use sp_core::{traits::CallContext, Bytes};
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
 
@@ -97,7 +97,8 @@ where
 		encoded_xt: Bytes,
 		at: Option<Block::Hash>,
 	) -> RpcResult<RuntimeDispatchInfo<Balance, sp_weights::Weight>> {
-		let api = self.client.runtime_api();
+		let mut api = self.client.runtime_api();
+		api.set_call_context(CallContext::Rpc);
 		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
 
 		let encoded_len = encoded_xt.len() as u32;
@@ -130,7 +131,8 @@ where
 		encoded_xt: Bytes,
 		at: Option<Block::Hash>,
 	) -> RpcResult<FeeDetails<NumberOrHex>> {
-		let api = self.client.runtime_api();
+		let mut api = self.client.runtime_api();
+		api.set_call_context(CallContext::Rpc);
 		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
 
 		let encoded_len = encoded_xt.len() as u32;
@@ -194,6 +194,49 @@ impl TryFrom<u32> for HttpRequestStatus {
 	}
 }
 
+/// Opaque type for offchain WebSocket connections.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Hash))]
+pub struct WsConnectionId(pub u16);
+
+impl From<u16> for WsConnectionId {
+	fn from(value: u16) -> Self {
+		Self(value)
+	}
+}
+
+impl From<WsConnectionId> for u16 {
+	fn from(c: WsConnectionId) -> Self {
+		c.0
+	}
+}
+
+impl From<WsConnectionId> for u32 {
+	fn from(c: WsConnectionId) -> Self {
+		c.0 as u32
+	}
+}
+
+/// An error enum returned by the WebSocket methods.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encode, Decode)]
+pub enum WsError {
+	/// The requested action couldn't been completed within a deadline.
+	#[codec(index = 1)]
+	DeadlineReached,
+	/// There was an IO Error while processing the connection, for example the remote has closed
+	/// it.
+	///
+	/// The connection is now considered destroyed. To retry you need to open a new one.
+	#[codec(index = 2)]
+	IoError,
+	/// The ID of the connection is invalid in this context.
+	#[codec(index = 3)]
+	Invalid,
+	/// The node operator doesn't allow connecting to the requested host.
+	#[codec(index = 4)]
+	Forbidden,
+}
+
 /// A blob to hold information about the local node's network state
 /// without committing to its format.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
@@ -296,6 +339,8 @@ bitflags::bitflags! {
 		const NODE_AUTHORIZATION = 1 << 7;
 		/// Access time related functionality
 		const TIME = 1 << 8;
+		/// External WebSocket connections.
+		const WEBSOCKET = 1 << 9;
 	}
 }
 
@@ -429,6 +474,59 @@ pub trait Externalities: Send {
 		deadline: Option<Timestamp>,
 	) -> Result<usize, HttpError>;
 
+	/// Opens a WebSocket connection to the given URL.
+	///
+	/// Blocks until the handshake has completed or the deadline is reached. Passing `None` as a
+	/// deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The URL is malformed or the handshake failed.
+	/// - The node operator doesn't allow connecting to the host.
+	/// - The deadline is reached.
+	fn ws_connect(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError>;
+
+	/// Sends a message over the given WebSocket connection.
+	///
+	/// Messages that are valid UTF-8 are sent as text frames, all others as binary frames.
+	/// Passing `None` as a deadline blocks forever.
+	///
+	/// Returns an error if:
+	/// - The connection identifier is invalid.
+	/// - The deadline is reached.
+	/// - An I/O error has happened, for example the remote has closed the connection. The
+	///   connection is then considered invalid.
+	fn ws_send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), WsError>;
+
+	/// Receives the next message from the given WebSocket connection.
+	///
+	/// Text and binary messages are both returned as raw bytes. Passing `None` as a deadline
+	/// blocks forever.
+	///
+	/// Returns an error if:
+	/// - The connection identifier is invalid.
+	/// - The deadline is reached before a message arrived.
+	/// - An I/O error has happened, for example the remote has closed the connection. The
+	///   connection is then considered invalid.
+	fn ws_receive(
+		&mut self,
+		id: WsConnectionId,
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError>;
+
+	/// Closes the given WebSocket connection.
+	///
+	/// Does nothing if the connection identifier is invalid.
+	fn ws_close(&mut self, id: WsConnectionId);
+
 	/// Set the authorized nodes from runtime.
 	///
 	/// In a permissioned network, the connections between nodes need to reach a
@@ -511,6 +609,35 @@ impl<T: Externalities + ?Sized> Externalities for Box<T> {
 		(&mut **self).http_response_read_body(request_id, buffer, deadline)
 	}
 
+	fn ws_connect(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError> {
+		(&mut **self).ws_connect(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), WsError> {
+		(&mut **self).ws_send(id, message, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WsConnectionId,
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError> {
+		(&mut **self).ws_receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WsConnectionId) {
+		(&mut **self).ws_close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		(&mut **self).set_authorized_nodes(nodes, authorized_only)
 	}
@@ -618,6 +745,39 @@ impl<T: Externalities> Externalities for LimitedExternalities<T> {
 		self.externalities.http_response_read_body(request_id, buffer, deadline)
 	}
 
+	fn ws_connect(
+		&mut self,
+		uri: &str,
+		deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError> {
+		self.check(Capabilities::WEBSOCKET, "ws_connect");
+		self.externalities.ws_connect(uri, deadline)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<(), WsError> {
+		self.check(Capabilities::WEBSOCKET, "ws_send");
+		self.externalities.ws_send(id, message, deadline)
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WsConnectionId,
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError> {
+		self.check(Capabilities::WEBSOCKET, "ws_receive");
+		self.externalities.ws_receive(id, deadline)
+	}
+
+	fn ws_close(&mut self, id: WsConnectionId) {
+		self.check(Capabilities::WEBSOCKET, "ws_close");
+		self.externalities.ws_close(id)
+	}
+
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		self.check(Capabilities::NODE_AUTHORIZATION, "set_authorized_nodes");
 		self.externalities.set_authorized_nodes(nodes, authorized_only)
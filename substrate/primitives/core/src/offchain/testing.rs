@@ -24,7 +24,7 @@ use crate::{
 	offchain::{
 		self, storage::InMemOffchainStorage, HttpError, HttpRequestId as RequestId,
 		HttpRequestStatus as RequestStatus, OffchainOverlayedChange, OffchainStorage,
-		OpaqueNetworkState, StorageKind, Timestamp, TransactionPool,
+		OpaqueNetworkState, StorageKind, Timestamp, TransactionPool, WsConnectionId, WsError,
 	},
 	OpaquePeerId,
 };
@@ -58,6 +58,19 @@ pub struct PendingRequest {
 	pub response_headers: Vec<(String, String)>,
 }
 
+/// Open WebSocket connection.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct WsConnection {
+	/// URI the connection was opened to.
+	pub uri: String,
+	/// Messages sent by the offchain worker, in order.
+	pub sent: Vec<Vec<u8>>,
+	/// Messages that are yet to be received by the offchain worker.
+	pub incoming: VecDeque<Vec<u8>>,
+	/// Has the connection been closed by the offchain worker.
+	pub closed: bool,
+}
+
 /// Sharable "persistent" offchain storage for test.
 #[derive(Debug, Clone, Default)]
 pub struct TestPersistentOffchainDB {
@@ -134,6 +147,12 @@ pub struct OffchainState {
 	pub seed: [u8; 32],
 	/// A timestamp simulating the current time.
 	pub timestamp: Timestamp,
+	/// A list of WebSocket connections opened so far.
+	pub ws_connections: BTreeMap<WsConnectionId, WsConnection>,
+	/// Messages served to WebSocket connections, by URI.
+	///
+	/// Connecting to a URI that is not in this map fails with [`WsError::IoError`].
+	pub ws_feeds: BTreeMap<String, VecDeque<Vec<u8>>>,
 }
 
 impl OffchainState {
@@ -178,6 +197,17 @@ impl OffchainState {
 		}
 		self.expected_requests.push_front(expected);
 	}
+
+	/// Add a WebSocket feed.
+	///
+	/// Any connection to `uri` opened afterwards will receive `messages`, in order.
+	pub fn add_ws_feed(
+		&mut self,
+		uri: impl Into<String>,
+		messages: impl IntoIterator<Item = Vec<u8>>,
+	) {
+		self.ws_feeds.insert(uri.into(), messages.into_iter().collect());
+	}
 }
 
 impl Drop for OffchainState {
@@ -350,6 +380,54 @@ impl offchain::Externalities for TestOffchainExt {
 		}
 	}
 
+	fn ws_connect(
+		&mut self,
+		uri: &str,
+		_deadline: Option<Timestamp>,
+	) -> Result<WsConnectionId, WsError> {
+		let mut state = self.0.write();
+		let incoming = state.ws_feeds.get(uri).cloned().ok_or(WsError::IoError)?;
+		let id = WsConnectionId(state.ws_connections.len() as u16);
+		state
+			.ws_connections
+			.insert(id, WsConnection { uri: uri.into(), incoming, ..Default::default() });
+		Ok(id)
+	}
+
+	fn ws_send(
+		&mut self,
+		id: WsConnectionId,
+		message: &[u8],
+		_deadline: Option<Timestamp>,
+	) -> Result<(), WsError> {
+		let mut state = self.0.write();
+		match state.ws_connections.get_mut(&id) {
+			Some(conn) if !conn.closed => {
+				conn.sent.push(message.to_vec());
+				Ok(())
+			},
+			_ => Err(WsError::Invalid),
+		}
+	}
+
+	fn ws_receive(
+		&mut self,
+		id: WsConnectionId,
+		_deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, WsError> {
+		let mut state = self.0.write();
+		match state.ws_connections.get_mut(&id) {
+			Some(conn) if !conn.closed => conn.incoming.pop_front().ok_or(WsError::DeadlineReached),
+			_ => Err(WsError::Invalid),
+		}
+	}
+
+	fn ws_close(&mut self, id: WsConnectionId) {
+		if let Some(conn) = self.0.write().ws_connections.get_mut(&id) {
+			conn.closed = true;
+		}
+	}
+
 	fn set_authorized_nodes(&mut self, _nodes: Vec<OpaquePeerId>, _authorized_only: bool) {
 		unimplemented!()
 	}
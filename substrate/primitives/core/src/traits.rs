@@ -32,6 +32,11 @@ pub enum CallContext {
 	Offchain,
 	/// The call is happening in some on-chain context like building or importing a block.
 	Onchain,
+	/// The call was requested through an RPC, e.g. `state_call`.
+	///
+	/// Treated like [`Self::Offchain`], except that the executor may apply different execution
+	/// limits to it.
+	Rpc,
 }
 
 /// Code execution engine.
@@ -101,6 +101,7 @@ use sp_core::{
 	ecdsa, ed25519,
 	offchain::{
 		HttpError, HttpRequestId, HttpRequestStatus, OpaqueNetworkState, StorageKind, Timestamp,
+		WsConnectionId, WsError,
 	},
 	sr25519,
 	storage::StateVersion,
@@ -1767,6 +1768,55 @@ pub trait Offchain {
 			.expect("set_authorized_nodes can be called only in the offchain worker context")
 			.set_authorized_nodes(nodes, authorized_only)
 	}
+
+	/// Opens a WebSocket connection to the given URL.
+	///
+	/// Blocks until the handshake has completed. Passing `None` as deadline blocks forever.
+	///
+	/// Returns the id of the newly opened connection.
+	fn ws_connect(
+		&mut self,
+		uri: PassFatPointerAndRead<&str>,
+		deadline: PassFatPointerAndDecode<Option<Timestamp>>,
+	) -> AllocateAndReturnByCodec<Result<WsConnectionId, WsError>> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_connect can be called only in the offchain worker context")
+			.ws_connect(uri, deadline)
+	}
+
+	/// Sends a message over a WebSocket connection.
+	///
+	/// Passing `None` as deadline blocks forever.
+	fn ws_send(
+		&mut self,
+		id: PassAs<WsConnectionId, u16>,
+		message: PassFatPointerAndRead<&[u8]>,
+		deadline: PassFatPointerAndDecode<Option<Timestamp>>,
+	) -> AllocateAndReturnByCodec<Result<(), WsError>> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_send can be called only in the offchain worker context")
+			.ws_send(id, message, deadline)
+	}
+
+	/// Receives the next message from a WebSocket connection.
+	///
+	/// Passing `None` as deadline blocks forever.
+	fn ws_receive(
+		&mut self,
+		id: PassAs<WsConnectionId, u16>,
+		deadline: PassFatPointerAndDecode<Option<Timestamp>>,
+	) -> AllocateAndReturnByCodec<Result<Vec<u8>, WsError>> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_receive can be called only in the offchain worker context")
+			.ws_receive(id, deadline)
+	}
+
+	/// Closes a WebSocket connection.
+	fn ws_close(&mut self, id: PassAs<WsConnectionId, u16>) {
+		self.extension::<OffchainWorkerExt>()
+			.expect("ws_close can be called only in the offchain worker context")
+			.ws_close(id)
+	}
 }
 
 /// Wasm only interface that provides functions for calling into the allocator.
@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzzing for the approval voting method.
+//!
+//! Ensures that approval voting always produces a valid result, and compares its score and PJR
+//! against sequential phragmen on the same input.
+
+mod common;
+
+use common::*;
+use honggfuzz::fuzz;
+use rand::{self, SeedableRng};
+use sp_npos_elections::{
+	assignment_ratio_to_staked_normalized, pjr_check, seq_phragmen, to_supports, ElectionResult,
+	EvaluateSupport, VoteWeight,
+};
+use sp_runtime::{PerThing, Perbill};
+
+fn main() {
+	loop {
+		fuzz!(|data: (usize, usize, usize, u64)| {
+			let (mut target_count, mut voter_count, mut to_elect, seed) = data;
+			let rng = rand::rngs::SmallRng::seed_from_u64(seed);
+			target_count = to_range(target_count, 100, 200);
+			voter_count = to_range(voter_count, 100, 200);
+			to_elect = to_range(to_elect, 25, target_count);
+
+			println!(
+				"++ [voter_count: {} / target_count:{} / to_elect:{}]",
+				voter_count, target_count, to_elect,
+			);
+			let (result, candidates, voters, stake_of_tree) = generate_random_npos_result(
+				voter_count as u64,
+				target_count as u64,
+				to_elect,
+				rng,
+				ElectionType::ApprovalVoting,
+			);
+
+			let stake_of = |who: &AccountId| -> VoteWeight { *stake_of_tree.get(who).unwrap() };
+
+			// the result must be valid: no more than `to_elect` winners, and every voter fully
+			// distributes their stake among the winners.
+			assert!(result.winners.len() <= to_elect);
+			for assignment in result.assignments.iter() {
+				let total =
+					assignment.distribution.iter().map(|(_, p)| p.deconstruct()).sum::<u32>();
+				assert_eq!(total, Perbill::ACCURACY);
+				assert!(assignment
+					.distribution
+					.iter()
+					.all(|(target, _)| result.winners.iter().any(|(w, _)| w == target)));
+			}
+
+			let supports = {
+				let staked =
+					assignment_ratio_to_staked_normalized(result.assignments, &stake_of).unwrap();
+				to_supports(&staked)
+			};
+			let score = supports.evaluate();
+			let is_pjr = pjr_check(&supports, candidates.clone(), voters.clone()).is_ok();
+
+			let phragmen_score = {
+				let phragmen: ElectionResult<AccountId, Perbill> =
+					seq_phragmen(to_elect, candidates, voters, None).unwrap();
+				let staked =
+					assignment_ratio_to_staked_normalized(phragmen.assignments, &stake_of).unwrap();
+				to_supports(&staked).evaluate()
+			};
+
+			// approval voting is not proportional, thus it is not expected to be PJR. Report how
+			// often it is.
+			println!(
+				"{:?} vs seq-phragmen {:?} [better: {}, pjr: {}]",
+				score,
+				phragmen_score,
+				score.strict_better(phragmen_score),
+				is_pjr,
+			);
+		});
+	}
+}
@@ -21,7 +21,9 @@
 #![allow(dead_code)]
 
 use rand::{self, seq::SliceRandom, Rng, RngCore};
-use sp_npos_elections::{phragmms, seq_phragmen, BalancingConfig, ElectionResult, VoteWeight};
+use sp_npos_elections::{
+	approval_voting, phragmms, seq_phragmen, stv, BalancingConfig, ElectionResult, VoteWeight,
+};
 use sp_runtime::Perbill;
 use std::collections::{BTreeMap, HashSet};
 
@@ -40,6 +42,8 @@ pub fn to_range(x: usize, a: usize, b: usize) -> usize {
 pub enum ElectionType {
 	Phragmen(Option<BalancingConfig>),
 	Phragmms(Option<BalancingConfig>),
+	Stv,
+	ApprovalVoting,
 }
 
 pub type AccountId = u64;
@@ -162,6 +166,9 @@ pub fn generate_random_npos_result(
 				seq_phragmen(to_elect, candidates.clone(), voters.clone(), conf).unwrap(),
 			ElectionType::Phragmms(conf) =>
 				phragmms(to_elect, candidates.clone(), voters.clone(), conf).unwrap(),
+			ElectionType::Stv => stv(to_elect, candidates.clone(), voters.clone()).unwrap(),
+			ElectionType::ApprovalVoting =>
+				approval_voting(to_elect, candidates.clone(), voters.clone()).unwrap(),
 		},
 		candidates,
 		voters,
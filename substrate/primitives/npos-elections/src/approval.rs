@@ -0,0 +1,160 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the (weighted) approval voting method.
+//!
+//! The targets with the highest approval stake, i.e. the sum of the stake of all the voters who
+//! vote for them, are elected. Note that this method is not proportional: a majority of the stake
+//! can elect all the winners. It is mostly useful as a baseline to compare the other methods
+//! against, or for bodies where proportionality is not desired.
+
+use crate::{
+	setup_inputs, Assignment, ElectionResult, ExtendedBalance, IdentifierT, PerThing128, VoteWeight,
+};
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
+use core::cmp::Reverse;
+use sp_arithmetic::{traits::Zero, PerThing};
+
+/// Execute the approval voting method.
+///
+/// This offers a similar API to the other methods of this crate, namely:
+///
+/// - The resulting edge weight distribution is normalized (thus, safe to use for submission).
+/// - The accuracy can be configured via the generic type `P`.
+/// - The algorithm is a _best-effort_ to elect `to_elect`. If less candidates are provided, less
+///   winners are returned, without an error.
+///
+/// The winners are returned in decreasing order of approval stake. Ties are broken in favour of
+/// the target that appears first in `candidates`. Targets that no one votes for are never elected.
+///
+/// Each voter splits their stake equally among the winners they vote for.
+///
+/// This can only fail if the normalization fails. This can happen if for any of the resulting
+/// assignments, `assignment.distribution.map(|p| p.deconstruct()).sum()` fails to fit inside
+/// `UpperOf<P>`. A user of this crate may statically assert that this can never happen and safely
+/// `expect` this to return `Ok`.
+pub fn approval_voting<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	let (candidates, voters) = setup_inputs(candidates, voters);
+
+	let mut winners = candidates
+		.into_iter()
+		.filter(|c| !c.borrow().approval_stake.is_zero())
+		.collect::<Vec<_>>();
+	// this is a stable sort, thus ties keep the order of `candidates`.
+	winners.sort_by_key(|c| Reverse(c.borrow().approval_stake));
+	winners.truncate(to_elect);
+	for (round, winner) in winners.iter().enumerate() {
+		let mut winner = winner.borrow_mut();
+		winner.elected = true;
+		winner.round = round;
+	}
+
+	let mut backings = BTreeMap::<AccountId, ExtendedBalance>::new();
+	let mut assignments = Vec::with_capacity(voters.len());
+	for voter in voters {
+		let elected = voter
+			.edges
+			.iter()
+			.filter(|e| e.candidate.borrow().elected)
+			.map(|e| e.who.clone())
+			.collect::<Vec<_>>();
+		if elected.is_empty() {
+			continue
+		}
+
+		let elected_len = elected.len() as ExtendedBalance;
+		let distribution =
+			elected.into_iter().map(|who| (who, P::from_rational(1, elected_len))).collect();
+		let mut assignment = Assignment { who: voter.who, distribution };
+		assignment.try_normalize().map_err(|_| crate::Error::ArithmeticError)?;
+		for (target, stake) in assignment.clone().into_staked(voter.budget).distribution {
+			let backing = backings.entry(target).or_default();
+			*backing = backing.saturating_add(stake);
+		}
+		assignments.push(assignment);
+	}
+
+	let winners = winners
+		.into_iter()
+		.map(|w_ptr| {
+			let who = w_ptr.borrow().who.clone();
+			let backing = backings.get(&who).copied().unwrap_or_default();
+			(who, backing)
+		})
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn basic_election_works() {
+		let candidates = vec![1, 2, 3, 4];
+		let voters = vec![(10, 10, vec![1, 2]), (20, 20, vec![1, 3]), (30, 30, vec![2, 3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			approval_voting(2, candidates, voters).unwrap();
+
+		// approval stakes are 30, 40 and 50, and 4 has none.
+		assert_eq!(winners, vec![(3, 35), (2, 25)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment { who: 10u64, distribution: vec![(2, Perbill::one())] },
+				Assignment { who: 20, distribution: vec![(3, Perbill::one())] },
+				Assignment {
+					who: 30,
+					distribution: vec![
+						(2, Perbill::from_percent(50)),
+						(3, Perbill::from_percent(50))
+					],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn ties_are_broken_by_candidate_order() {
+		let candidates = vec![3, 1, 2];
+		let voters = vec![(10, 10, vec![1, 2, 3])];
+
+		let ElectionResult::<_, Perbill> { winners, .. } =
+			approval_voting(2, candidates, voters).unwrap();
+
+		assert_eq!(winners.iter().map(|(w, _)| *w).collect::<Vec<_>>(), vec![3, 1]);
+	}
+
+	#[test]
+	fn less_candidates_than_seats_works() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1]), (20, 20, vec![2])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			approval_voting(5, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(2, 20), (1, 10)]);
+		assert_eq!(assignments.len(), 2);
+	}
+}
@@ -22,6 +22,10 @@
 //! - [`ghragmms`](phragmms::phragmms()): Implements a hybrid approach inspired by Phragmén which is
 //!   executed faster but it can achieve a constant factor approximation of the maximin problem,
 //!   similar to that of the MMS algorithm.
+//! - [`stv`]: Implements the weighted Single Transferable Vote method. Unlike the others, this is a
+//!   ranked method, where the order of the votes of each voter is their order of preference.
+//! - [`approval_voting`]: Implements (weighted) approval voting. A fast, yet not proportional,
+//!   method that elects the targets with the highest approval stake.
 //! - [`balance`]: Implements the star balancing algorithm. This iterative process can push a
 //!   solution toward being more "balanced", which in turn can increase its score.
 //!
@@ -90,6 +94,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod approval;
 mod assignments;
 pub mod balancing;
 pub mod helpers;
@@ -98,8 +103,10 @@ pub mod phragmen;
 pub mod phragmms;
 pub mod pjr;
 pub mod reduce;
+pub mod stv;
 pub mod traits;
 
+pub use approval::*;
 pub use assignments::{Assignment, StakedAssignment};
 pub use balancing::*;
 pub use helpers::*;
@@ -107,6 +114,7 @@ pub use phragmen::*;
 pub use phragmms::*;
 pub use pjr::*;
 pub use reduce::reduce;
+pub use stv::*;
 pub use traits::{IdentifierT, PerThing128};
 
 /// The errors that might occur in this crate and `frame-election-provider-solution-type`.
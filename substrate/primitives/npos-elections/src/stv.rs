@@ -0,0 +1,402 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the weighted Single Transferable Vote (STV) method.
+//!
+//! Unlike the other methods of this crate, STV is a _ranked_ method: the order in which a voter
+//! provides their votes is interpreted as their order of preference, the first one being the most
+//! preferred. The weight of each voter is their stake.
+//!
+//! The election uses the Droop quota, `total_weight / (to_elect + 1) + 1`. In each round, the
+//! weight of every voter sits with their most preferred target which is neither elected nor
+//! excluded. Then:
+//!
+//! - If a target reaches the quota, the one with the most weight is elected. Its surplus is
+//!   transferred with the weighted inclusive Gregory method: every voter currently backing it keeps
+//!   `quota / tally` of their weight with it, and carries the rest to their next preference.
+//! - Otherwise, the target with the least weight is excluded, and its voters carry all of their
+//!   weight to their next preference.
+//!
+//! Once the number of remaining targets is not more than the number of seats left, they are all
+//! elected.
+
+use crate::{Assignment, ElectionResult, ExtendedBalance, IdentifierT, PerThing128, VoteWeight};
+use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
+use core::cmp::Ordering;
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::Zero, PerThing, Rounding,
+};
+
+/// The status of a target in an STV election.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Status {
+	/// Can still be elected.
+	Hopeful,
+	/// Has been elected.
+	Elected,
+	/// Has been excluded, and will never be elected.
+	Excluded,
+}
+
+/// A voter in an STV election.
+struct StvVoter<AccountId> {
+	/// Identifier.
+	who: AccountId,
+	/// The stake of this voter.
+	budget: ExtendedBalance,
+	/// Indices of the targets ranked by this voter, the most preferred first.
+	ranking: Vec<usize>,
+	/// Index in `ranking` of the target currently holding the weight of this voter.
+	position: usize,
+	/// The weight of this voter that has not been kept by any elected target yet.
+	weight: ExtendedBalance,
+	/// The weight kept by each of the elected targets, by target index.
+	kept: Vec<(usize, ExtendedBalance)>,
+}
+
+impl<AccountId> StvVoter<AccountId> {
+	/// The target currently holding the weight of this voter, if any.
+	fn current(&self) -> Option<usize> {
+		self.ranking.get(self.position).copied()
+	}
+
+	/// Move the weight of this voter to their most preferred hopeful target, if any is left.
+	fn advance(&mut self, status: &[Status]) {
+		while let Some(target) = self.current() {
+			if status[target] == Status::Hopeful {
+				break
+			}
+			self.position += 1;
+		}
+	}
+
+	/// Keep `amount` of the weight of this voter with `target`.
+	fn keep(&mut self, target: usize, amount: ExtendedBalance) {
+		self.weight = self.weight.saturating_sub(amount);
+		self.kept.push((target, amount));
+	}
+}
+
+/// Execute the weighted STV method.
+///
+/// This offers a similar API to the other methods of this crate, namely:
+///
+/// - The resulting edge weight distribution is normalized (thus, safe to use for submission).
+/// - The accuracy can be configured via the generic type `P`.
+/// - The algorithm is a _best-effort_ to elect `to_elect`. If less candidates are provided, less
+///   winners are returned, without an error.
+///
+/// The winners are returned in the order in which they are elected.
+///
+/// The stake of each voter is distributed among the winners in proportion to the weight that each
+/// of them kept. A voter whose weight ended up only with excluded targets, yet ranks some of the
+/// winners, splits their stake equally among those winners.
+///
+/// This can only fail if the normalization fails. This can happen if for any of the resulting
+/// assignments, `assignment.distribution.map(|p| p.deconstruct()).sum()` fails to fit inside
+/// `UpperOf<P>`. A user of this crate may statically assert that this can never happen and safely
+/// `expect` this to return `Ok`.
+pub fn stv<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	// used to cache and access candidates index.
+	let mut c_idx_cache = BTreeMap::<AccountId, usize>::new();
+	for (idx, who) in candidates.iter().enumerate() {
+		c_idx_cache.entry(who.clone()).or_insert(idx);
+	}
+
+	let mut approval_stake = vec![ExtendedBalance::zero(); candidates.len()];
+	let mut voters = voters
+		.into_iter()
+		.filter_map(|(who, voter_stake, votes)| {
+			let budget: ExtendedBalance = voter_stake.into();
+			let mut ranking = Vec::new();
+			for v in votes {
+				match c_idx_cache.get(&v) {
+					Some(idx) if !ranking.contains(idx) => ranking.push(*idx),
+					// duplicate vote, or vote for a non-candidate.
+					_ => continue,
+				}
+			}
+			if ranking.is_empty() {
+				return None
+			}
+			for idx in ranking.iter() {
+				approval_stake[*idx] = approval_stake[*idx].saturating_add(budget);
+			}
+			Some(StvVoter { who, budget, ranking, position: 0, weight: budget, kept: Vec::new() })
+		})
+		.collect::<Vec<_>>();
+
+	let total_weight = voters
+		.iter()
+		.fold(ExtendedBalance::zero(), |acc, voter| acc.saturating_add(voter.budget));
+	let quota = (total_weight / (to_elect as ExtendedBalance).saturating_add(1)).saturating_add(1);
+
+	// targets that no one votes for can never be elected.
+	let mut status = approval_stake
+		.iter()
+		.map(|stake| if stake.is_zero() { Status::Excluded } else { Status::Hopeful })
+		.collect::<Vec<_>>();
+
+	// orders targets by their current tally, then by their approval stake, and finally prefers
+	// the target that was provided first.
+	let compare = |tally: &[ExtendedBalance], a: &usize, b: &usize| -> Ordering {
+		(tally[*a], approval_stake[*a])
+			.cmp(&(tally[*b], approval_stake[*b]))
+			.then_with(|| b.cmp(a))
+	};
+
+	let mut winners = Vec::with_capacity(to_elect);
+	while winners.len() < to_elect {
+		let mut tally = vec![ExtendedBalance::zero(); candidates.len()];
+		for voter in voters.iter_mut() {
+			voter.advance(&status);
+			if let Some(target) = voter.current() {
+				tally[target] = tally[target].saturating_add(voter.weight);
+			}
+		}
+
+		let mut hopeful = (0..candidates.len())
+			.filter(|c| status[*c] == Status::Hopeful)
+			.collect::<Vec<_>>();
+		if hopeful.is_empty() {
+			break
+		}
+
+		if winners.len() + hopeful.len() <= to_elect {
+			// all the remaining targets are elected, and keep all the weight they currently hold.
+			hopeful.sort_by(|a, b| compare(&tally, b, a));
+			for target in hopeful {
+				voters
+					.iter_mut()
+					.filter(|voter| voter.current() == Some(target))
+					.for_each(|voter| voter.keep(target, voter.weight));
+				status[target] = Status::Elected;
+				winners.push(target);
+			}
+			break
+		}
+
+		let best = hopeful
+			.iter()
+			.copied()
+			.max_by(|a, b| compare(&tally, a, b))
+			.expect("hopeful is not empty; qed");
+		if tally[best] >= quota {
+			let best_tally = tally[best];
+			for voter in voters.iter_mut().filter(|voter| voter.current() == Some(best)) {
+				let kept = multiply_by_rational_with_rounding(
+					voter.weight,
+					quota,
+					best_tally,
+					Rounding::Down,
+				)
+				.unwrap_or(voter.weight);
+				voter.keep(best, kept);
+			}
+			status[best] = Status::Elected;
+			winners.push(best);
+		} else {
+			let worst = hopeful
+				.iter()
+				.copied()
+				.min_by(|a, b| compare(&tally, a, b))
+				.expect("hopeful is not empty; qed");
+			status[worst] = Status::Excluded;
+		}
+	}
+
+	let mut backings = BTreeMap::<AccountId, ExtendedBalance>::new();
+	let mut assignments = Vec::with_capacity(voters.len());
+	for voter in voters {
+		let kept = voter.kept.into_iter().filter(|(_, w)| !w.is_zero()).collect::<Vec<_>>();
+		let total_kept =
+			kept.iter().fold(ExtendedBalance::zero(), |acc, (_, w)| acc.saturating_add(*w));
+
+		let distribution = if total_kept.is_zero() {
+			let elected = voter
+				.ranking
+				.iter()
+				.filter(|c| status[**c] == Status::Elected)
+				.copied()
+				.collect::<Vec<_>>();
+			let elected_len = elected.len() as ExtendedBalance;
+			elected
+				.into_iter()
+				.map(|c| (candidates[c].clone(), P::from_rational(1, elected_len)))
+				.collect::<Vec<_>>()
+		} else {
+			kept.into_iter()
+				.map(|(c, w)| (candidates[c].clone(), P::from_rational(w, total_kept)))
+				.collect::<Vec<_>>()
+		};
+
+		if distribution.is_empty() {
+			continue
+		}
+
+		let mut assignment = Assignment { who: voter.who, distribution };
+		assignment.try_normalize().map_err(|_| crate::Error::ArithmeticError)?;
+		for (target, stake) in assignment.clone().into_staked(voter.budget).distribution {
+			let backing = backings.entry(target).or_default();
+			*backing = backing.saturating_add(stake);
+		}
+		assignments.push(assignment);
+	}
+
+	let winners = winners
+		.into_iter()
+		.map(|c| {
+			let who = candidates[c].clone();
+			let backing = backings.get(&who).copied().unwrap_or_default();
+			(who, backing)
+		})
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		approval_voting, assignment_ratio_to_staked_normalized, pjr_check, to_supports,
+		EvaluateSupport,
+	};
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn surplus_is_transferred() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![
+			(10, 30, vec![1, 2]),
+			(11, 30, vec![1]),
+			(20, 15, vec![2]),
+			(30, 10, vec![3]),
+			(40, 5, vec![3]),
+		];
+
+		// quota is 90 / 3 + 1 = 31. 1 is elected with 60, and both 10 and 11 keep 15 with it. 10
+		// carries 15 to 2, while the rest of the weight of 11 is exhausted. Then, 3 is excluded and
+		// 2 is elected.
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			stv(2, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(1, 45), (2, 30)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment {
+					who: 10u64,
+					distribution: vec![
+						(1, Perbill::from_percent(50)),
+						(2, Perbill::from_percent(50))
+					],
+				},
+				Assignment { who: 11, distribution: vec![(1, Perbill::one())] },
+				Assignment { who: 20, distribution: vec![(2, Perbill::one())] },
+			]
+		);
+	}
+
+	#[test]
+	fn exclusion_transfers_all_weight() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 40, vec![1]), (20, 35, vec![2]), (30, 25, vec![3, 2])];
+
+		// quota is 100 / 2 + 1 = 51. No one reaches it, so 3 is excluded and 30 moves to 2.
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			stv(1, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(2, 60)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment { who: 20u64, distribution: vec![(2, Perbill::one())] },
+				Assignment { who: 30, distribution: vec![(2, Perbill::one())] },
+			]
+		);
+	}
+
+	#[test]
+	fn remaining_targets_are_elected() {
+		let candidates = vec![1, 2, 3, 4];
+		// 4 has no votes, and is never elected.
+		let voters = vec![(10, 10, vec![1, 1, 5]), (20, 20, vec![2]), (30, 30, vec![3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			stv(4, candidates, voters).unwrap();
+
+		assert_eq!(winners, vec![(3, 30), (2, 20), (1, 10)]);
+		assert_eq!(assignments.len(), 3);
+	}
+
+	#[test]
+	fn voter_without_kept_weight_splits_among_ranked_winners() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 60, vec![1]), (20, 30, vec![2]), (30, 10, vec![3, 1])];
+
+		// quota is 100 / 3 + 1 = 34. 1 is elected, then 3 is excluded. The weight of 30 skips over
+		// the already elected 1 and is exhausted.
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			stv(2, candidates, voters).unwrap();
+
+		assert_eq!(winners.iter().map(|(w, _)| *w).collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(
+			assignments.iter().find(|a| a.who == 30).unwrap().distribution,
+			vec![(1, Perbill::one())]
+		);
+	}
+
+	#[test]
+	fn empty_inputs_work() {
+		let result = stv::<u32, Perbill>(2, vec![], Vec::<(u32, VoteWeight, Vec<u32>)>::new());
+		assert!(result.unwrap().winners.is_empty());
+
+		let result = stv::<u32, Perbill>(0, vec![1, 2], vec![(10, 10, vec![1, 2])]);
+		assert!(result.unwrap().winners.is_empty());
+	}
+
+	#[test]
+	fn stv_is_more_proportional_than_approval_voting() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 51, vec![1, 2]), (20, 50, vec![3])];
+		let stake_of =
+			|who: &u32| -> VoteWeight { voters.iter().find(|v| v.0 == *who).map(|v| v.1).unwrap() };
+
+		// the majority elects both of their targets.
+		let approval =
+			approval_voting::<_, Perbill>(2, candidates.clone(), voters.clone()).unwrap();
+		let approval_supports = to_supports(
+			&assignment_ratio_to_staked_normalized(approval.assignments, &stake_of).unwrap(),
+		);
+		assert_eq!(pjr_check(&approval_supports, candidates.clone(), voters.clone()), Err(3));
+
+		// while the minority gets their target elected with STV.
+		let stv_result = stv::<_, Perbill>(2, candidates.clone(), voters.clone()).unwrap();
+		assert_eq!(stv_result.winners, vec![(1, 51), (3, 50)]);
+		let stv_supports = to_supports(
+			&assignment_ratio_to_staked_normalized(stv_result.assignments, &stake_of).unwrap(),
+		);
+		assert!(pjr_check(&stv_supports, candidates, voters).is_ok());
+
+		assert!(stv_supports.evaluate().strict_better(approval_supports.evaluate()));
+	}
+}
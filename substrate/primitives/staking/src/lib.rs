@@ -664,6 +664,20 @@ pub trait DelegationInterface {
 		value: Self::Balance,
 		maybe_reporter: Option<Self::AccountId>,
 	) -> DispatchResult;
+
+	/// Transfer `amount` of the delegation of `source` to `destination`.
+	///
+	/// The `destination` must either not be a delegator yet or delegate to the same `Agent` as
+	/// `source`. The delegated funds move along with the delegation.
+	///
+	/// Not supported by default.
+	fn transfer_delegation(
+		_source: Delegator<Self::AccountId>,
+		_destination: Delegator<Self::AccountId>,
+		_amount: Self::Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("transfer_delegation is not supported"))
+	}
 }
 
 /// Trait to provide functionality for direct stakers to migrate to delegation agents.
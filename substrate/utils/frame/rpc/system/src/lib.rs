@@ -31,7 +31,7 @@ use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
 use sp_api::ApiExt;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
-use sp_core::{hexdisplay::HexDisplay, Bytes};
+use sp_core::{hexdisplay::HexDisplay, traits::CallContext, Bytes};
 use sp_runtime::{legacy, traits};
 
 pub use frame_system_rpc_runtime_api::AccountNonceApi;
@@ -99,7 +99,8 @@ where
 	Nonce: Clone + Display + Codec + Send + traits::AtLeast32Bit + 'static,
 {
 	async fn nonce(&self, account: AccountId) -> RpcResult<Nonce> {
-		let api = self.client.runtime_api();
+		let mut api = self.client.runtime_api();
+		api.set_call_context(CallContext::Rpc);
 		let best = self.client.info().best_hash;
 
 		let nonce = api.account_nonce(best, account.clone()).map_err(|e| {
@@ -120,7 +121,8 @@ where
 	) -> RpcResult<Bytes> {
 		sc_rpc_api::check_if_safe(ext)?;
 
-		let api = self.client.runtime_api();
+		let mut api = self.client.runtime_api();
+		api.set_call_context(CallContext::Rpc);
 		let best_hash = at.unwrap_or_else(||
 			// If the block hash is not supplied assume the best block.
 			self.client.info().best_hash);
@@ -160,6 +160,8 @@ pub fn new_full<Network: sc_network::NetworkBackend<Block, <Block as BlockT>::Ha
 				)),
 				network_provider: Arc::new(network.clone()),
 				enable_http_requests: true,
+				enable_websocket_connections: config.offchain_worker.websocket_enabled,
+				outbound_policy: config.offchain_worker.outbound_policy.clone(),
 				custom_extensions: |_| vec![],
 			})?;
 		task_manager.spawn_handle().spawn(
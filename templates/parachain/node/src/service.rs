@@ -317,6 +317,8 @@ pub async fn start_parachain_node(
 				network_provider: Arc::new(network.clone()),
 				is_validator: parachain_config.role.is_authority(),
 				enable_http_requests: false,
+				enable_websocket_connections: parachain_config.offchain_worker.websocket_enabled,
+				outbound_policy: parachain_config.offchain_worker.outbound_policy.clone(),
 				custom_extensions: move |_| vec![],
 			})?;
 		task_manager.spawn_handle().spawn(
@@ -404,6 +406,7 @@ pub async fn start_parachain_node(
 		import_queue: import_queue_service,
 		relay_chain_slot_duration,
 		recovery_handle: Box::new(overseer_handle.clone()),
+		pov_fallback_sources: Vec::new(),
 		sync_service: sync_service.clone(),
 		prometheus_registry: prometheus_registry.as_ref(),
 	})?;
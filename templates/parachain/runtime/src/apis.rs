@@ -85,6 +85,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::RelayStateKeysApi<Block> for Runtime {
+		fn relay_state_keys() -> Vec<Vec<u8>> {
+			Vec::new()
+		}
+	}
+
 	impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
 		fn can_build_upon(
 			included_hash: <Block as BlockT>::Hash,
@@ -555,6 +555,10 @@ pub use pallet_nfts;
 #[cfg(feature = "pallet-nfts-runtime-api")]
 pub use pallet_nfts_runtime_api;
 
+/// FRAME pallet transferring `pallet-nfts` items with their data over XCM.
+#[cfg(feature = "pallet-nfts-xcm")]
+pub use pallet_nfts_xcm;
+
 /// FRAME pallet for rewarding account freezing.
 #[cfg(feature = "pallet-nis")]
 pub use pallet_nis;